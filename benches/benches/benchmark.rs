@@ -1,12 +1,20 @@
-use criterion::{criterion_group, criterion_main, Criterion, ParameterizedBenchmark};
-
-use juniper::InputValue;
-use juniper_benchmarks as j;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use juniper::{
+    execute, execute_sync,
+    parser::parse_document_source,
+    validation::{visit_all_rules, ValidatorContext},
+    DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLTypeAsync, InputValue, RootNode,
+    ScalarValue, Variables,
+};
+use juniper_benchmarks::{
+    self as j,
+    fixtures::{deep, list, wide, MyScalarValue},
+};
 
 fn bench_sync_vs_async_users_flat_instant(c: &mut Criterion) {
-    const ASYNC_QUERY: &'static str = r#"
-        query Query($id: Int) {
-            users_async_instant(ids: [$id]!) {
+    const ASYNC_QUERY: &str = r#"
+        query Query($ids: [Int!]) {
+            usersAsyncInstant(ids: $ids) {
                 id
                 kind
                 username
@@ -15,75 +23,174 @@ fn bench_sync_vs_async_users_flat_instant(c: &mut Criterion) {
         }
     "#;
 
-    const SYNC_QUERY: &'static str = r#"
-    query Query($id: Int) {
-        users_sync_instant(ids: [$id]!) {
-            id
-            kind
-            username
-            email
+    const SYNC_QUERY: &str = r#"
+        query Query($ids: [Int!]) {
+            usersSyncInstant(ids: $ids) {
+                id
+                kind
+                username
+                email
+            }
         }
-    }
-"#;
-
-    c.bench(
-        "Sync vs Async - Users Flat - Instant",
-        ParameterizedBenchmark::new(
-            "Sync",
-            |b, count| {
-                let ids = (0..*count)
-                    .map(|x| InputValue::scalar(x as i32))
-                    .collect::<Vec<_>>();
-                let ids = InputValue::list(ids);
-                b.iter(|| {
-                    j::execute_sync(
-                        SYNC_QUERY,
-                        vec![("ids".to_owned(), ids.clone())].into_iter().collect(),
-                    )
-                })
+    "#;
+
+    let mut group = c.benchmark_group("Sync vs Async - Users Flat - Instant");
+    for count in [1, 10] {
+        let ids = InputValue::list((0..count).map(InputValue::scalar).collect());
+        let vars: Variables = [("ids".to_owned(), ids)].into_iter().collect();
+
+        group.bench_with_input(BenchmarkId::new("Sync", count), &vars, |b, vars| {
+            b.iter(|| j::execute_sync(SYNC_QUERY, vars.clone()))
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("Async - Single Thread", count),
+            &vars,
+            |b, vars| {
+                let rt = tokio::runtime::Builder::new_current_thread()
+                    .build()
+                    .unwrap();
+
+                b.iter(|| rt.block_on(j::execute(ASYNC_QUERY, vars.clone())))
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Async - Threadpool", count),
+            &vars,
+            |b, vars| {
+                let rt = tokio::runtime::Builder::new_multi_thread().build().unwrap();
+
+                b.iter(|| rt.block_on(j::execute(ASYNC_QUERY, vars.clone())))
             },
-            vec![1, 10],
-        )
-        .with_function("Async - Single Thread", |b, count| {
-            let mut rt = tokio::runtime::Builder::new()
-                .basic_scheduler()
-                .build()
-                .unwrap();
-
-            let ids = (0..*count)
-                .map(|x| InputValue::scalar(x as i32))
-                .collect::<Vec<_>>();
-            let ids = InputValue::list(ids);
+        );
+    }
+    group.finish();
+}
+
+/// Benchmarks parsing, validation and both sync and async execution of the
+/// provided `queries` against the provided `schema`.
+///
+/// Every query is identified by its size parameter, and every benchmark by
+/// the provided `scalar` name.
+fn bench_fixture<Q, S>(
+    c: &mut Criterion,
+    fixture: &str,
+    scalar: &str,
+    schema: &RootNode<'static, Q, EmptyMutation, EmptySubscription, S>,
+    queries: &[(usize, String)],
+) where
+    Q: GraphQLTypeAsync<S, Context = (), TypeInfo = ()> + Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let mut group = c.benchmark_group(format!("{fixture} - Parse"));
+    for (size, query) in queries {
+        group.bench_with_input(BenchmarkId::new(scalar, size), query, |b, query| {
+            b.iter(|| parse_document_source::<S>(query, &schema.schema).unwrap())
+        });
+    }
+    group.finish();
 
+    let mut group = c.benchmark_group(format!("{fixture} - Validate"));
+    for (size, query) in queries {
+        let document = parse_document_source::<S>(query, &schema.schema).unwrap();
+        group.bench_with_input(BenchmarkId::new(scalar, size), &document, |b, document| {
             b.iter(|| {
-                let f = j::execute(
-                    ASYNC_QUERY,
-                    vec![("ids".to_owned(), ids.clone())].into_iter().collect(),
-                );
-                rt.block_on(f)
+                let mut ctx = ValidatorContext::new(&schema.schema, document);
+                visit_all_rules(&mut ctx, document);
+                ctx.into_errors()
             })
-        })
-        .with_function("Async - Threadpool", |b, count| {
-            let mut rt = tokio::runtime::Builder::new()
-                .threaded_scheduler()
-                .build()
-                .unwrap();
-
-            let ids = (0..*count)
-                .map(|x| InputValue::scalar(x as i32))
-                .collect::<Vec<_>>();
-            let ids = InputValue::list(ids);
+        });
+    }
+    group.finish();
+
+    let vars = Variables::new();
 
+    let mut group = c.benchmark_group(format!("{fixture} - Execute Sync"));
+    for (size, query) in queries {
+        group.bench_with_input(BenchmarkId::new(scalar, size), query, |b, query| {
+            b.iter(|| execute_sync(query, None, schema, &vars, &()).unwrap())
+        });
+    }
+    group.finish();
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let mut group = c.benchmark_group(format!("{fixture} - Execute Async"));
+    for (size, query) in queries {
+        group.bench_with_input(BenchmarkId::new(scalar, size), query, |b, query| {
             b.iter(|| {
-                let f = j::execute(
-                    ASYNC_QUERY,
-                    vec![("ids".to_owned(), ids.clone())].into_iter().collect(),
-                );
-                rt.block_on(f)
+                rt.block_on(execute(query, None, schema, &vars, &()))
+                    .unwrap()
             })
-        }),
+        });
+    }
+    group.finish();
+}
+
+fn bench_deep(c: &mut Criterion) {
+    let queries = [4, 16, 64].map(|depth| (depth, deep::query(depth)));
+
+    bench_fixture(
+        c,
+        "Deep",
+        "DefaultScalarValue",
+        &deep::schema::<DefaultScalarValue>(),
+        &queries,
+    );
+    bench_fixture(
+        c,
+        "Deep",
+        "MyScalarValue",
+        &deep::schema::<MyScalarValue>(),
+        &queries,
+    );
+}
+
+fn bench_wide(c: &mut Criterion) {
+    let queries = [1, 4, 16].map(|aliases| (wide::FIELDS.len() * aliases, wide::query(aliases)));
+
+    bench_fixture(
+        c,
+        "Wide",
+        "DefaultScalarValue",
+        &wide::schema::<DefaultScalarValue>(),
+        &queries,
+    );
+    bench_fixture(
+        c,
+        "Wide",
+        "MyScalarValue",
+        &wide::schema::<MyScalarValue>(),
+        &queries,
+    );
+}
+
+fn bench_list(c: &mut Criterion) {
+    let queries = [10, 100, 1000].map(|count| (count, list::query(count)));
+
+    bench_fixture(
+        c,
+        "List",
+        "DefaultScalarValue",
+        &list::schema::<DefaultScalarValue>(),
+        &queries,
+    );
+    bench_fixture(
+        c,
+        "List",
+        "MyScalarValue",
+        &list::schema::<MyScalarValue>(),
+        &queries,
     );
 }
 
-criterion_group!(benches, bench_sync_vs_async_users_flat_instant);
+criterion_group!(
+    benches,
+    bench_sync_vs_async_users_flat_instant,
+    bench_deep,
+    bench_wide,
+    bench_list,
+);
 criterion_main!(benches);
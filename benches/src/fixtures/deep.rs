@@ -0,0 +1,55 @@
+//! Schema with deeply nested objects.
+
+use juniper::{graphql_object, EmptyMutation, EmptySubscription, RootNode, ScalarValue};
+
+/// Node of an endless chain of nested objects.
+pub struct Node {
+    depth: i32,
+}
+
+#[graphql_object]
+impl Node {
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn name(&self) -> String {
+        format!("node-{}", self.depth)
+    }
+
+    fn child(&self) -> Node {
+        Node {
+            depth: self.depth + 1,
+        }
+    }
+}
+
+pub struct Query;
+
+#[graphql_object]
+impl Query {
+    fn root() -> Node {
+        Node { depth: 0 }
+    }
+}
+
+pub type Schema<S> = RootNode<'static, Query, EmptyMutation, EmptySubscription, S>;
+
+/// Creates a new [`Schema`] with deeply nested objects.
+pub fn schema<S: ScalarValue>() -> Schema<S> {
+    RootNode::new_with_scalar_value(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+/// Creates a query selecting the [`Node`]s nested `depth` levels deep.
+pub fn query(depth: usize) -> String {
+    let mut query = String::from("{ root { ");
+    for _ in 0..depth {
+        query.push_str("depth name child { ");
+    }
+    query.push_str("depth name");
+    for _ in 0..depth {
+        query.push_str(" }");
+    }
+    query.push_str(" } }");
+    query
+}
@@ -0,0 +1,45 @@
+//! Schema with long lists of objects.
+
+use juniper::{
+    graphql_object, EmptyMutation, EmptySubscription, GraphQLObject, RootNode, ScalarValue,
+};
+
+#[derive(GraphQLObject)]
+pub struct Item {
+    id: i32,
+    name: String,
+    price: f64,
+    tags: Vec<String>,
+}
+
+impl Item {
+    fn new(id: i32) -> Self {
+        Self {
+            id,
+            name: format!("item-{id}"),
+            price: f64::from(id) * 1.5,
+            tags: vec!["new".into(), "sale".into(), format!("tag-{}", id % 10)],
+        }
+    }
+}
+
+pub struct Query;
+
+#[graphql_object]
+impl Query {
+    fn items(count: i32) -> Vec<Item> {
+        (0..count).map(Item::new).collect()
+    }
+}
+
+pub type Schema<S> = RootNode<'static, Query, EmptyMutation, EmptySubscription, S>;
+
+/// Creates a new [`Schema`] with long lists of objects.
+pub fn schema<S: ScalarValue>() -> Schema<S> {
+    RootNode::new_with_scalar_value(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+/// Creates a query selecting a list of `count` [`Item`]s.
+pub fn query(count: usize) -> String {
+    format!("{{ items(count: {count}) {{ id name price tags }} }}")
+}
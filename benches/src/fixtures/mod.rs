@@ -0,0 +1,17 @@
+//! Representative schemas and queries to benchmark [`juniper`] with.
+//!
+//! Every schema is generic over its [`ScalarValue`], so it may be benchmarked
+//! both with the [`DefaultScalarValue`] and the custom [`MyScalarValue`].
+//!
+//! These fixtures are public, so they may be reused for measuring performance
+//! regressions outside of this crate's benchmarks.
+//!
+//! [`DefaultScalarValue`]: juniper::DefaultScalarValue
+//! [`ScalarValue`]: juniper::ScalarValue
+
+pub mod deep;
+pub mod list;
+pub mod scalar;
+pub mod wide;
+
+pub use self::scalar::MyScalarValue;
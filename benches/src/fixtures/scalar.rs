@@ -0,0 +1,19 @@
+//! Custom [`ScalarValue`] to benchmark schemas with.
+
+use juniper::ScalarValue;
+use serde::{Deserialize, Serialize};
+
+/// Custom [`ScalarValue`], additionally supporting [`i64`] values.
+#[derive(Clone, Debug, Deserialize, PartialEq, ScalarValue, Serialize)]
+#[serde(untagged)]
+pub enum MyScalarValue {
+    #[value(as_float, as_int)]
+    Int(i32),
+    Long(i64),
+    #[value(as_float)]
+    Float(f64),
+    #[value(as_str, as_string, into_string)]
+    String(String),
+    #[value(as_bool)]
+    Boolean(bool),
+}
@@ -0,0 +1,62 @@
+//! Schema with objects having a lot of fields.
+
+use juniper::{
+    graphql_object, EmptyMutation, EmptySubscription, GraphQLObject, RootNode, ScalarValue,
+};
+
+macro_rules! wide_object {
+    ($($field:ident),* $(,)?) => {
+        #[derive(GraphQLObject)]
+        pub struct Wide {
+            $( $field: i32, )*
+        }
+
+        impl Wide {
+            fn new() -> Self {
+                let mut n = 0;
+                Self {
+                    $( $field: { n += 1; n }, )*
+                }
+            }
+        }
+
+        /// Names of all the [`Wide`] object fields.
+        pub const FIELDS: &[&str] = &[$( stringify!($field) ),*];
+    };
+}
+
+wide_object!(
+    f00, f01, f02, f03, f04, f05, f06, f07, f08, f09, f10, f11, f12, f13, f14, f15, f16, f17, f18,
+    f19, f20, f21, f22, f23, f24, f25, f26, f27, f28, f29, f30, f31, f32, f33, f34, f35, f36, f37,
+    f38, f39, f40, f41, f42, f43, f44, f45, f46, f47, f48, f49, f50, f51, f52, f53, f54, f55, f56,
+    f57, f58, f59, f60, f61, f62, f63,
+);
+
+pub struct Query;
+
+#[graphql_object]
+impl Query {
+    fn wide() -> Wide {
+        Wide::new()
+    }
+}
+
+pub type Schema<S> = RootNode<'static, Query, EmptyMutation, EmptySubscription, S>;
+
+/// Creates a new [`Schema`] with objects having a lot of fields.
+pub fn schema<S: ScalarValue>() -> Schema<S> {
+    RootNode::new_with_scalar_value(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+/// Creates a query selecting all the [`Wide`] object [`FIELDS`], each one
+/// `aliases` times under different aliases.
+pub fn query(aliases: usize) -> String {
+    let mut query = String::from("{ wide {");
+    for field in FIELDS {
+        for i in 0..aliases {
+            query.push_str(&format!(" {field}{i}: {field}"));
+        }
+    }
+    query.push_str(" } }");
+    query
+}
@@ -1,3 +1,5 @@
+pub mod fixtures;
+
 use juniper::{
     graphql_object, DefaultScalarValue, EmptyMutation, EmptySubscription, ExecutionError,
     FieldError, GraphQLEnum, GraphQLObject, RootNode, Value, Variables,
@@ -0,0 +1,42 @@
+//! Coerces arbitrary input values into Rust types, and arbitrary variables
+//! into the ones defined by an operation.
+
+#![no_main]
+
+use arbitrary::Unstructured;
+use juniper::{
+    integrations::arbitrary::arbitrary_variables,
+    tests::fixtures::starwars::schema::{Database, Episode, Query},
+    DefaultScalarValue, EmptyMutation, EmptySubscription, FromInputValue, InputValue, RootNode, ID,
+};
+use libfuzzer_sys::fuzz_target;
+
+const DOC: &str = r#"
+    query($id: String!, $episode: Episode, $name: String! = "2001") {
+        human(id: $id) { name }
+        hero(episode: $episode) { name }
+        droid(id: $name) { id }
+    }
+"#;
+
+fuzz_target!(|data: &[u8]| {
+    let mut u = Unstructured::new(data);
+
+    if let Ok(value) = u.arbitrary::<InputValue<DefaultScalarValue>>() {
+        let _ = i32::from_input_value(&value);
+        let _ = f64::from_input_value(&value);
+        let _ = String::from_input_value(&value);
+        let _ = ID::from_input_value(&value);
+        let _ = Option::<Vec<Option<Episode>>>::from_input_value(&value);
+    }
+
+    if let Ok(vars) = arbitrary_variables(&mut u) {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+
+        let _ = juniper::execute_sync(DOC, None, &schema, &vars, &Database::new());
+    }
+});
@@ -0,0 +1,20 @@
+//! Parses, validates and executes arbitrary GraphQL documents against the
+//! Star Wars test schema.
+
+#![no_main]
+
+use juniper::{
+    tests::fixtures::starwars::schema::{Database, Query},
+    EmptyMutation, EmptySubscription, RootNode, Variables,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|doc: &str| {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+
+    let _ = juniper::execute_sync(doc, None, &schema, &Variables::new(), &Database::new());
+});
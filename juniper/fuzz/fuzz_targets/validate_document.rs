@@ -0,0 +1,22 @@
+//! Validates structurally generated GraphQL documents against the Star Wars
+//! test schema.
+
+#![no_main]
+
+use juniper::{
+    tests::fixtures::starwars::schema::{Database, Query},
+    validation::{visit_all_rules, ValidatorContext},
+    DefaultScalarValue, Definition, EmptyMutation, EmptySubscription, RootNode,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|doc: Vec<Definition<'_, DefaultScalarValue>>| {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+
+    let mut ctx = ValidatorContext::new(&schema.schema, &doc);
+    visit_all_rules(&mut ctx, &doc);
+});
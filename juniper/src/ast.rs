@@ -1,9 +1,17 @@
-use std::{borrow::Cow, fmt, hash::Hash, slice, vec};
+use alloc::{
+    borrow::{Cow, ToOwned as _},
+    boxed::Box,
+    string::String,
+    vec,
+    vec::Vec,
+};
+use core::{fmt, hash::Hash, slice};
 
 use indexmap::IndexMap;
 
+#[cfg(feature = "std")]
+use crate::executor::Variables;
 use crate::{
-    executor::Variables,
     parser::Spanning,
     value::{DefaultScalarValue, ScalarValue},
 };
@@ -219,6 +227,17 @@ impl<'a> Type<'a> {
     pub fn is_non_null(&self) -> bool {
         matches!(*self, Type::NonNullNamed(_) | Type::NonNullList(..))
     }
+
+    /// Get the expected size of a list type, if it's known (like for the
+    /// `[T; N]` arrays).
+    ///
+    /// Only applies to list types; named types will return `None`.
+    pub fn list_size(&self) -> Option<usize> {
+        match *self {
+            Type::List(_, size) | Type::NonNullList(_, size) => size,
+            Type::Named(_) | Type::NonNullNamed(_) => None,
+        }
+    }
 }
 
 impl<'a> fmt::Display for Type<'a> {
@@ -274,7 +293,29 @@ impl<S> InputValue<S> {
     ///
     /// Similarly to [`InputValue::list`] it makes each key and value in the
     /// given hash map not contain any location information.
+    #[cfg(feature = "std")]
     pub fn object<K>(o: IndexMap<K, Self>) -> Self
+    where
+        K: AsRef<str> + Eq + Hash,
+    {
+        Self::from_index_map(o)
+    }
+
+    /// Construct aa [`Spanning::unlocated`] object.
+    ///
+    /// Similarly to [`InputValue::list`] it makes each key and value in the
+    /// given hash map not contain any location information.
+    #[cfg(not(feature = "std"))]
+    pub fn object<K, H>(o: IndexMap<K, Self, H>) -> Self
+    where
+        K: AsRef<str> + Eq + Hash,
+    {
+        Self::from_index_map(o)
+    }
+
+    /// Constructs a [`Spanning::unlocated`] object from the given [`IndexMap`]
+    /// with any hasher.
+    fn from_index_map<K, H>(o: IndexMap<K, Self, H>) -> Self
     where
         K: AsRef<str> + Eq + Hash,
     {
@@ -307,6 +348,7 @@ impl<S> InputValue<S> {
     /// [`InputValue::Object`] field) a default value can be used later, if it's
     /// provided. While on contrary, a single [`InputValue::List`] element
     /// cannot have a default value.
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn into_const(self, values: &Variables<S>) -> Option<Self>
     where
@@ -396,6 +438,7 @@ impl<S> InputValue<S> {
     ///
     /// This constructs a new [`IndexMap`] containing references to the keys
     /// and values of `self`.
+    #[cfg(feature = "std")]
     pub fn to_object_value(&self) -> Option<IndexMap<&str, &Self>> {
         match self {
             Self::Object(o) => Some(
@@ -0,0 +1,466 @@
+//! Batching and caching of data loaded by resolvers, solving the N+1 problem.
+//!
+//! Asynchronous resolvers of sibling fields (and of the items of a list) are
+//! polled concurrently by an [`Executor`], so a [`Loader`] shared between them
+//! collects all the keys requested during a single batch window, and loads
+//! them with a single [`BatchFn::load()`] call, instead of a separate one per
+//! resolver.
+//!
+//! Loaded values are cached by a [`Loader`] for its whole lifetime, so it
+//! should be created per request and stored in its [`Context`], making the
+//! cache never outlive the request and never leak data between the users.
+//!
+//! ```rust
+//! # use std::collections::HashMap;
+//! # use juniper::{
+//! #     async_trait, dataloader::{BatchFn, Loader}, graphql_object, Context, EmptyMutation,
+//! #     EmptySubscription, RootNode,
+//! # };
+//! #
+//! #[derive(Clone, Debug)]
+//! struct User {
+//!     id: i32,
+//!     name: String,
+//! }
+//!
+//! struct UserBatcher;
+//!
+//! #[async_trait]
+//! impl BatchFn<i32, User> for UserBatcher {
+//!     async fn load(&self, keys: &[i32]) -> HashMap<i32, User> {
+//!         // A single `SELECT ... WHERE id IN (...)` query goes here.
+//!         keys.iter()
+//!             .map(|&id| (id, User { id, name: format!("user{id}") }))
+//!             .collect()
+//!     }
+//! }
+//!
+//! struct Ctx {
+//!     users: Loader<i32, User, UserBatcher>,
+//! }
+//!
+//! impl Context for Ctx {}
+//!
+//! struct Post {
+//!     author_id: i32,
+//! }
+//!
+//! #[graphql_object(context = Ctx)]
+//! impl Post {
+//!     async fn author_name(&self, context: &Ctx) -> Option<String> {
+//!         context.users.load(self.author_id).await.map(|u| u.name)
+//!     }
+//! }
+//!
+//! struct Query;
+//!
+//! #[graphql_object(context = Ctx)]
+//! impl Query {
+//!     fn posts() -> Vec<Post> {
+//!         (1..=3).map(|author_id| Post { author_id }).collect()
+//!     }
+//! }
+//!
+//! // Created per request, along with its context.
+//! let ctx = Ctx {
+//!     users: Loader::new(UserBatcher),
+//! };
+//! # let _ = RootNode::new(Query, EmptyMutation::<Ctx>::new(), EmptySubscription::<Ctx>::new());
+//! ```
+//!
+//! [`Context`]: crate::Context
+//! [`Executor`]: crate::Executor
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    future::Future,
+    hash::Hash,
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll, Waker},
+};
+
+use async_trait::async_trait;
+use indexmap::IndexSet;
+
+/// Default maximum number of keys loaded by a single [`BatchFn::load()`]
+/// call.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+
+/// Default number of times a [`Loader`] yields to other resolvers before
+/// loading a batch.
+pub const DEFAULT_YIELD_COUNT: usize = 10;
+
+/// Function loading a batch of values by their keys.
+#[async_trait]
+pub trait BatchFn<K, V>: Send + Sync {
+    /// Loads the values of the provided `keys`.
+    ///
+    /// Keys missing in the returned [`HashMap`] are considered to have no
+    /// value.
+    async fn load(&self, keys: &[K]) -> HashMap<K, V>;
+}
+
+/// Loader of values by their keys, batching the keys requested concurrently
+/// into a single [`BatchFn::load()`] call, and caching the loaded values.
+///
+/// See the [module-level documentation](self) for details.
+pub struct Loader<K, V, F> {
+    batch_fn: F,
+    max_batch_size: usize,
+    yield_count: usize,
+    state: Mutex<State<K, V>>,
+}
+
+struct State<K, V> {
+    /// Values loaded already, with [`None`] for the keys having no value.
+    cache: HashMap<K, Option<V>>,
+
+    /// Keys requested, but not loading yet, in the order they're requested.
+    pending: IndexSet<K>,
+
+    /// Keys being loaded by a dispatched [`BatchFn::load()`] call.
+    loading: HashSet<K>,
+
+    /// Number of finished [`BatchFn::load()`] calls.
+    generation: u64,
+
+    /// Tasks waiting for the dispatched [`BatchFn::load()`] calls to finish.
+    wakers: Vec<Waker>,
+}
+
+impl<K, V, F> fmt::Debug for Loader<K, V, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Loader")
+            .field("max_batch_size", &self.max_batch_size)
+            .field("yield_count", &self.yield_count)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K, V, F> Loader<K, V, F>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    F: BatchFn<K, V>,
+{
+    /// Creates a new [`Loader`] loading values with the provided `batch_fn`.
+    #[must_use]
+    pub fn new(batch_fn: F) -> Self {
+        Self {
+            batch_fn,
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            yield_count: DEFAULT_YIELD_COUNT,
+            state: Mutex::new(State {
+                cache: HashMap::new(),
+                pending: IndexSet::new(),
+                loading: HashSet::new(),
+                generation: 0,
+                wakers: Vec::new(),
+            }),
+        }
+    }
+
+    /// Sets the maximum number of keys loaded by a single
+    /// [`BatchFn::load()`] call.
+    ///
+    /// Defaults to [`DEFAULT_MAX_BATCH_SIZE`].
+    ///
+    /// # Panics
+    ///
+    /// If the provided `size` is zero.
+    #[must_use]
+    pub fn with_max_batch_size(mut self, size: usize) -> Self {
+        assert!(size > 0, "maximum batch size must be positive");
+        self.max_batch_size = size;
+        self
+    }
+
+    /// Sets the number of times a [`Loader`] yields to other resolvers (so
+    /// they're able to request their keys) before loading a batch, defining
+    /// the batch window.
+    ///
+    /// Defaults to [`DEFAULT_YIELD_COUNT`].
+    #[must_use]
+    pub fn with_yield_count(mut self, count: usize) -> Self {
+        self.yield_count = count;
+        self
+    }
+
+    /// Loads the value of the provided `key`, batching it with the keys
+    /// requested concurrently.
+    ///
+    /// Returns [`None`] if the `key` has no value.
+    pub async fn load(&self, key: K) -> Option<V> {
+        if let Some(value) = self.enqueue(&key) {
+            return value;
+        }
+
+        for _ in 0..self.yield_count {
+            YieldNow(false).await;
+        }
+
+        loop {
+            let generation = {
+                let mut state = self.state.lock().unwrap();
+                if let Some(value) = state.cache.get(&key) {
+                    return value.clone();
+                }
+                if !state.loading.contains(&key) {
+                    // Re-requests the key, if the batch loading it was
+                    // cancelled.
+                    state.pending.insert(key.clone());
+                    None
+                } else {
+                    Some(state.generation)
+                }
+            };
+
+            match generation {
+                Some(generation) => {
+                    Loaded {
+                        loader: self,
+                        generation,
+                    }
+                    .await
+                }
+                None => self.dispatch(&key).await,
+            }
+        }
+    }
+
+    /// Loads the values of the provided `keys`, batching them with the keys
+    /// requested concurrently.
+    ///
+    /// Keys having no value are omitted in the returned [`HashMap`].
+    pub async fn load_many(&self, keys: impl IntoIterator<Item = K>) -> HashMap<K, V> {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        for key in &keys {
+            let _ = self.enqueue(key);
+        }
+        let values = futures::future::join_all(keys.iter().map(|k| self.load(k.clone()))).await;
+        keys.into_iter()
+            .zip(values)
+            .filter_map(|(k, v)| v.map(|v| (k, v)))
+            .collect()
+    }
+
+    /// Caches the provided `value` of the `key`, unless it's cached already.
+    pub fn prime(&self, key: K, value: V) {
+        self.state
+            .lock()
+            .unwrap()
+            .cache
+            .entry(key)
+            .or_insert(Some(value));
+    }
+
+    /// Removes the cached value of the provided `key`, so it's loaded again
+    /// the next time it's requested.
+    pub fn forget(&self, key: &K) {
+        self.state.lock().unwrap().cache.remove(key);
+    }
+
+    /// Removes all the cached values.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().cache.clear();
+    }
+
+    /// Returns the cached value of the provided `key`, if any, or requests it
+    /// to be loaded in the next batch otherwise.
+    fn enqueue(&self, key: &K) -> Option<Option<V>> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(value) = state.cache.get(key) {
+            return Some(value.clone());
+        }
+        if !state.loading.contains(key) {
+            state.pending.insert(key.clone());
+        }
+        None
+    }
+
+    /// Loads the batch of the pending keys containing the provided `key`.
+    async fn dispatch(&self, key: &K) {
+        let keys = {
+            let mut state = self.state.lock().unwrap();
+            let pos = match state.pending.get_index_of(key) {
+                Some(pos) => pos,
+                None => return,
+            };
+            // Splits the pending keys into batches not exceeding the size limit.
+            let start = pos - pos % self.max_batch_size;
+            let end = (start + self.max_batch_size).min(state.pending.len());
+            let keys = state.pending.drain(start..end).collect::<Vec<_>>();
+            state.loading.extend(keys.iter().cloned());
+            keys
+        };
+
+        let guard = Dispatched {
+            loader: self,
+            keys: &keys,
+        };
+        let mut values = self.batch_fn.load(&keys).await;
+        {
+            let mut state = self.state.lock().unwrap();
+            for k in &keys {
+                let value = values.remove(k);
+                state.cache.insert(k.clone(), value);
+            }
+        }
+        drop(guard);
+    }
+}
+
+/// Guard finishing a dispatched [`BatchFn::load()`] call, even if it was
+/// cancelled, so the tasks awaiting it aren't stuck.
+struct Dispatched<'l, K: Eq + Hash, V, F> {
+    loader: &'l Loader<K, V, F>,
+    keys: &'l [K],
+}
+
+impl<'l, K: Eq + Hash, V, F> Drop for Dispatched<'l, K, V, F> {
+    fn drop(&mut self) {
+        let mut state = self.loader.state.lock().unwrap();
+        for k in self.keys {
+            state.loading.remove(k);
+        }
+        state.generation += 1;
+        for waker in std::mem::take(&mut state.wakers) {
+            waker.wake();
+        }
+    }
+}
+
+/// [`Future`] resolving once a [`BatchFn::load()`] call dispatched after the
+/// provided `generation` is finished.
+struct Loaded<'l, K, V, F> {
+    loader: &'l Loader<K, V, F>,
+    generation: u64,
+}
+
+impl<'l, K, V, F> Future for Loaded<'l, K, V, F> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.loader.state.lock().unwrap();
+        if state.generation != self.generation {
+            return Poll::Ready(());
+        }
+        if !state.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+/// [`Future`] yielding to other tasks once.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use async_trait::async_trait;
+    use futures::future;
+
+    use super::{BatchFn, Loader};
+
+    #[derive(Clone, Default)]
+    struct Doubler(Arc<Mutex<Vec<Vec<i32>>>>);
+
+    impl Doubler {
+        fn batches(&self) -> Vec<Vec<i32>> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl BatchFn<i32, i32> for Doubler {
+        async fn load(&self, keys: &[i32]) -> HashMap<i32, i32> {
+            self.0.lock().unwrap().push(keys.to_vec());
+            keys.iter()
+                .filter(|&&k| k >= 0)
+                .map(|&k| (k, k * 2))
+                .collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn batches_concurrent_loads() {
+        let batcher = Doubler::default();
+        let loader = Loader::new(batcher.clone());
+
+        let values = future::join_all((0..3).map(|k| loader.load(k))).await;
+
+        assert_eq!(values, [Some(0), Some(2), Some(4)]);
+        assert_eq!(batcher.batches(), [vec![0, 1, 2]]);
+    }
+
+    #[tokio::test]
+    async fn caches_loaded_values() {
+        let batcher = Doubler::default();
+        let loader = Loader::new(batcher.clone());
+
+        assert_eq!(loader.load(1).await, Some(2));
+        assert_eq!(loader.load(-1).await, None);
+        assert_eq!(loader.load(1).await, Some(2));
+        assert_eq!(loader.load(-1).await, None);
+
+        assert_eq!(batcher.batches(), [vec![1], vec![-1]]);
+
+        loader.forget(&1);
+        assert_eq!(loader.load(1).await, Some(2));
+        assert_eq!(batcher.batches(), [vec![1], vec![-1], vec![1]]);
+    }
+
+    #[tokio::test]
+    async fn deduplicates_keys() {
+        let batcher = Doubler::default();
+        let loader = Loader::new(batcher.clone());
+
+        let values = future::join_all([1, 2, 1].into_iter().map(|k| loader.load(k))).await;
+
+        assert_eq!(values, [Some(2), Some(4), Some(2)]);
+        assert_eq!(batcher.batches(), [vec![1, 2]]);
+    }
+
+    #[tokio::test]
+    async fn splits_batches_exceeding_max_size() {
+        let batcher = Doubler::default();
+        let loader = Loader::new(batcher.clone()).with_max_batch_size(2);
+
+        let values = loader.load_many(0..5).await;
+
+        assert_eq!(values.len(), 5);
+        let mut batches = batcher.batches();
+        batches.sort();
+        assert_eq!(batches, [vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[tokio::test]
+    async fn uses_primed_values() {
+        let batcher = Doubler::default();
+        let loader = Loader::new(batcher.clone());
+        loader.prime(1, 10);
+
+        assert_eq!(loader.load(1).await, Some(10));
+        assert!(batcher.batches().is_empty());
+    }
+}
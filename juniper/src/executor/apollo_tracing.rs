@@ -0,0 +1,291 @@
+//! [Apollo tracing] of an operation execution.
+//!
+//! [Apollo tracing]: https://github.com/apollographql/apollo-tracing
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    executor::{
+        clock::{Clock, SystemClock},
+        extension::{Extension, FieldInfo, RequestInfo},
+    },
+    value::{Object, ScalarValue, Value},
+    GraphQLError,
+};
+
+/// Name of the response extension the [`ApolloTracing`] data is emitted under.
+pub const APOLLO_TRACING_EXTENSION: &str = "tracing";
+
+/// [`Extension`] collecting timings of an operation execution in the
+/// [Apollo tracing] format, consumable by [Apollo Studio] and similar tooling.
+///
+/// Collects the timings of a single request only, so a new [`ApolloTracing`]
+/// should be registered for every executed request, and its
+/// [`ApolloTracing::to_value()`] emitted under the
+/// [`APOLLO_TRACING_EXTENSION`] of the response.
+///
+/// HTTP integrations do this automatically for requests having a
+/// `"tracing": true` extension.
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use juniper::{
+/// #     graphql_object, ApolloTracing, EmptyMutation, EmptySubscription, ExecutionOptions,
+/// #     RootNode, Variables,
+/// # };
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn ping() -> bool {
+///         true
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+///
+/// let tracing = Arc::new(ApolloTracing::new());
+/// let options = ExecutionOptions::default().extension(Arc::clone(&tracing));
+/// juniper::execute_sync_with_options("{ ping }", None, &schema, &Variables::new(), &(), &options)
+///     .unwrap();
+///
+/// let trace = tracing.to_value::<juniper::DefaultScalarValue>();
+/// let resolvers = trace
+///     .as_object_value()
+///     .and_then(|o| o.get_field_value("execution"))
+///     .and_then(|e| e.as_object_value())
+///     .and_then(|e| e.get_field_value("resolvers"))
+///     .and_then(|r| r.as_list_value())
+///     .unwrap();
+/// assert_eq!(resolvers.len(), 1);
+/// ```
+///
+/// [Apollo Studio]: https://www.apollographql.com/docs/studio
+/// [Apollo tracing]: https://github.com/apollographql/apollo-tracing
+#[derive(Debug)]
+pub struct ApolloTracing {
+    clock: Arc<dyn Clock>,
+    trace: Mutex<Trace>,
+}
+
+#[derive(Debug, Default)]
+struct Trace {
+    start: Option<SystemTime>,
+    end: Option<SystemTime>,
+    parsed: Option<Duration>,
+    validated: Option<Duration>,
+    pending: Vec<Resolver>,
+    resolvers: Vec<Resolver>,
+}
+
+#[derive(Debug)]
+struct Resolver {
+    path: Vec<String>,
+    parent_type: String,
+    field_name: String,
+    return_type: String,
+    start_offset: Duration,
+    duration: Duration,
+}
+
+impl Default for ApolloTracing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApolloTracing {
+    /// Creates a new [`ApolloTracing`] measuring time with the [`SystemClock`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    /// Creates a new [`ApolloTracing`] measuring time with the provided
+    /// [`Clock`].
+    ///
+    /// Pass the [`ExecutionOptions::get_clock()`] here to have the timings
+    /// consistent with the rest of the execution.
+    ///
+    /// [`ExecutionOptions::get_clock()`]: crate::ExecutionOptions::get_clock
+    #[must_use]
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            trace: Mutex::default(),
+        }
+    }
+
+    /// Returns the collected timings in the [Apollo tracing] format.
+    ///
+    /// Durations and offsets are reported in nanoseconds. The ones not fitting
+    /// into an [`i32`] are reported as floats.
+    ///
+    /// [Apollo tracing]: https://github.com/apollographql/apollo-tracing
+    pub fn to_value<S: ScalarValue>(&self) -> Value<S> {
+        let trace = self.trace.lock().unwrap();
+        let start = trace.start.unwrap_or_else(|| self.clock.now());
+        let end = trace.end.unwrap_or_else(|| self.clock.now());
+        let parsed = trace.parsed.unwrap_or_default();
+        let validated = trace.validated.unwrap_or(parsed);
+
+        let resolvers = trace
+            .resolvers
+            .iter()
+            .map(|r| {
+                let mut obj = Object::with_capacity(6);
+                obj.add_field(
+                    "path",
+                    Value::list(r.path.iter().map(|p| Value::scalar(p.clone())).collect()),
+                );
+                obj.add_field("parentType", Value::scalar(r.parent_type.clone()));
+                obj.add_field("fieldName", Value::scalar(r.field_name.clone()));
+                obj.add_field("returnType", Value::scalar(r.return_type.clone()));
+                obj.add_field("startOffset", nanos(r.start_offset));
+                obj.add_field("duration", nanos(r.duration));
+                Value::object(obj)
+            })
+            .collect();
+        let mut execution = Object::with_capacity(1);
+        execution.add_field("resolvers", Value::list(resolvers));
+
+        let mut obj = Object::with_capacity(7);
+        obj.add_field("version", Value::scalar(1));
+        obj.add_field("startTime", Value::scalar(rfc3339(start)));
+        obj.add_field("endTime", Value::scalar(rfc3339(end)));
+        obj.add_field(
+            "duration",
+            nanos(end.duration_since(start).unwrap_or_default()),
+        );
+        obj.add_field("parsing", phase(Duration::ZERO, parsed));
+        obj.add_field("validation", phase(parsed, validated));
+        obj.add_field("execution", Value::object(execution));
+        Value::object(obj)
+    }
+
+    /// Returns the time elapsed since the start of the traced request.
+    fn offset(&self, trace: &Trace) -> Duration {
+        trace
+            .start
+            .map(|start| self.clock.elapsed_since(start))
+            .unwrap_or_default()
+    }
+}
+
+impl Extension for ApolloTracing {
+    fn request_start(&self, _: &RequestInfo<'_>) {
+        self.trace.lock().unwrap().start = Some(self.clock.now());
+    }
+
+    fn parse(&self, _: &RequestInfo<'_>, _: Option<&GraphQLError>) {
+        let mut trace = self.trace.lock().unwrap();
+        trace.parsed = Some(self.offset(&trace));
+    }
+
+    fn validate(&self, _: &RequestInfo<'_>, _: Option<&GraphQLError>) {
+        let mut trace = self.trace.lock().unwrap();
+        trace.validated = Some(self.offset(&trace));
+    }
+
+    fn field_start(&self, field: &FieldInfo<'_>) {
+        let mut trace = self.trace.lock().unwrap();
+        let start_offset = self.offset(&trace);
+        trace.pending.push(Resolver {
+            path: field.path.to_vec(),
+            parent_type: field.parent_type.into(),
+            field_name: field.field_name.into(),
+            return_type: field
+                .return_type
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            start_offset,
+            duration: Duration::ZERO,
+        });
+    }
+
+    fn field_end(&self, field: &FieldInfo<'_>, _: Option<&str>) {
+        let mut trace = self.trace.lock().unwrap();
+        let end_offset = self.offset(&trace);
+        // Items of a list share the same path, so the latest started one is
+        // considered to be ended.
+        if let Some(idx) = trace.pending.iter().rposition(|r| r.path == field.path) {
+            let mut resolver = trace.pending.remove(idx);
+            resolver.duration = end_offset.saturating_sub(resolver.start_offset);
+            trace.resolvers.push(resolver);
+        }
+    }
+
+    fn request_end(&self, _: &RequestInfo<'_>, _: Option<&GraphQLError>) {
+        self.trace.lock().unwrap().end = Some(self.clock.now());
+    }
+}
+
+/// Represents a phase of a request starting and ending at the provided offsets.
+fn phase<S: ScalarValue>(start: Duration, end: Duration) -> Value<S> {
+    let mut obj = Object::with_capacity(2);
+    obj.add_field("startOffset", nanos(start));
+    obj.add_field("duration", nanos(end.saturating_sub(start)));
+    Value::object(obj)
+}
+
+/// Represents the provided [`Duration`] in nanoseconds.
+fn nanos<S: ScalarValue>(duration: Duration) -> Value<S> {
+    let ns = duration.as_nanos();
+    i32::try_from(ns).map_or_else(|_| Value::scalar(ns as f64), Value::scalar)
+}
+
+/// Formats the provided [`SystemTime`] as an [RFC 3339] UTC timestamp with
+/// millisecond precision.
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+fn rfc3339(time: SystemTime) -> String {
+    let since_epoch = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = ((secs / 86_400) as i64, secs % 86_400);
+
+    // Civil date from days since the Unix epoch:
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60,
+        since_epoch.subsec_millis(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::rfc3339;
+
+    #[test]
+    fn formats_rfc3339() {
+        assert_eq!(rfc3339(SystemTime::UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            rfc3339(SystemTime::UNIX_EPOCH + Duration::from_millis(1_501_251_632_106)),
+            "2017-07-28T14:20:32.106Z",
+        );
+        assert_eq!(
+            rfc3339(SystemTime::UNIX_EPOCH + Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00.000Z",
+        );
+    }
+}
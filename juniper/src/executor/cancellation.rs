@@ -0,0 +1,173 @@
+//! Cooperative cancellation of an operation execution.
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+/// Handle signalling that an operation execution is not needed anymore (for
+/// example, because its client has disconnected).
+///
+/// Resolvers may observe it via [`Executor::cancellation_token()`] to abort
+/// expensive work early, and pending fields of an asynchronous execution are
+/// completed with a cancellation [`FieldError`] once it's cancelled.
+///
+/// Clones share the same cancellation state.
+///
+/// [`Executor::cancellation_token()`]: crate::Executor::cancellation_token
+/// [`FieldError`]: crate::FieldError
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<State>);
+
+#[derive(Default)]
+struct State {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl CancellationToken {
+    /// Creates a new [`CancellationToken`], not cancelled yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels this [`CancellationToken`], waking up everyone awaiting
+    /// [`CancellationToken::cancelled()`].
+    ///
+    /// Cancelling an already cancelled [`CancellationToken`] does nothing.
+    pub fn cancel(&self) {
+        if !self.0.cancelled.swap(true, Ordering::AcqRel) {
+            let wakers = std::mem::take(&mut *self.0.wakers.lock().unwrap());
+            for waker in wakers {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Indicates whether this [`CancellationToken`] has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns a [`Future`] resolving once this [`CancellationToken`] is
+    /// cancelled.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled(self)
+    }
+
+    /// Returns a [`CancelOnDrop`] guard cancelling this [`CancellationToken`]
+    /// once dropped, unless [disarmed](CancelOnDrop::disarm) before.
+    ///
+    /// Useful for cancelling an execution whenever the [`Future`] driving it
+    /// is dropped (like when a server drops a request handler of a closed
+    /// connection).
+    #[must_use]
+    pub fn cancel_on_drop(self) -> CancelOnDrop {
+        CancelOnDrop(Some(self))
+    }
+}
+
+/// [`Future`] returned by [`CancellationToken::cancelled()`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Cancelled<'t>(&'t CancellationToken);
+
+impl<'t> Future for Cancelled<'t> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let state = &(self.0).0;
+        if state.cancelled.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+
+        let mut wakers = state.wakers.lock().unwrap();
+        // Re-checked under the lock to not miss a concurrent cancellation.
+        if state.cancelled.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+/// Guard returned by [`CancellationToken::cancel_on_drop()`].
+#[derive(Debug)]
+pub struct CancelOnDrop(Option<CancellationToken>);
+
+impl CancelOnDrop {
+    /// Returns the [`CancellationToken`] cancelled by this guard.
+    pub fn token(&self) -> &CancellationToken {
+        self.0.as_ref().unwrap()
+    }
+
+    /// Disarms this guard, so it doesn't cancel its [`CancellationToken`]
+    /// anymore, and returns the [`CancellationToken`].
+    pub fn disarm(mut self) -> CancellationToken {
+        self.0.take().unwrap()
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(token) = &self.0 {
+            token.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{future, FutureExt as _};
+
+    use super::CancellationToken;
+
+    #[test]
+    fn cancels_clones() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        assert!(clone.cancelled().now_or_never().is_none());
+
+        token.cancel();
+
+        assert!(clone.is_cancelled());
+        assert_eq!(clone.cancelled().now_or_never(), Some(()));
+    }
+
+    #[tokio::test]
+    async fn wakes_awaiting_tasks() {
+        let token = CancellationToken::new();
+
+        future::join(token.cancelled(), async { token.cancel() }).await;
+
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn cancels_on_drop_unless_disarmed() {
+        let token = CancellationToken::new();
+        drop(token.clone().cancel_on_drop().disarm());
+        assert!(!token.is_cancelled());
+
+        drop(token.clone().cancel_on_drop());
+        assert!(token.is_cancelled());
+    }
+}
@@ -0,0 +1,88 @@
+//! Source of the current time used during execution.
+
+use std::{
+    fmt,
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+/// Source of the current time for the time-dependent features of an
+/// [`Executor`] (like timings of tracing).
+///
+/// Replace the default [`SystemClock`] with a [`MockClock`] (via
+/// [`ExecutionOptions::clock()`]) to drive such features deterministically in
+/// tests.
+///
+/// [`ExecutionOptions::clock()`]: crate::ExecutionOptions::clock
+/// [`Executor`]: crate::Executor
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+
+    /// Returns the [`Duration`] elapsed since the given `earlier` time.
+    ///
+    /// Returns [`Duration::ZERO`] if the `earlier` time is actually later than
+    /// the current one.
+    fn elapsed_since(&self, earlier: SystemTime) -> Duration {
+        self.now().duration_since(earlier).unwrap_or_default()
+    }
+}
+
+/// [`Clock`] reporting the current system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// [`Clock`] reporting the time it's explicitly set to.
+///
+/// ```rust
+/// # use std::time::{Duration, SystemTime};
+/// # use juniper::{Clock as _, MockClock};
+/// #
+/// let clock = MockClock::new(SystemTime::UNIX_EPOCH);
+/// clock.advance(Duration::from_secs(5));
+///
+/// assert_eq!(clock.now(), SystemTime::UNIX_EPOCH + Duration::from_secs(5));
+/// ```
+#[derive(Debug)]
+pub struct MockClock {
+    now: Mutex<SystemTime>,
+}
+
+impl MockClock {
+    /// Creates a new [`MockClock`] reporting the provided `now` time.
+    #[must_use]
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Sets the time reported by this [`MockClock`].
+    pub fn set(&self, now: SystemTime) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Advances the time reported by this [`MockClock`] by the given
+    /// `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(SystemTime::UNIX_EPOCH)
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap()
+    }
+}
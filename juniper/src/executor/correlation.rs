@@ -0,0 +1,227 @@
+//! Correlation of executed requests with the logs of their origin.
+
+use std::str::FromStr;
+
+/// Name of the [W3C Trace Context] HTTP header identifying the trace a request
+/// is part of.
+///
+/// [W3C Trace Context]: https://www.w3.org/TR/trace-context
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// Name of the de facto standard HTTP header carrying an ID of a request.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Name of the [`FieldError::extensions`] field the request ID is stamped
+/// into.
+///
+/// [`FieldError::extensions`]: crate::FieldError::extensions
+pub const REQUEST_ID_EXTENSION: &str = "requestId";
+
+/// Parsed [W3C Trace Context] `traceparent` header.
+///
+/// [W3C Trace Context]: https://www.w3.org/TR/trace-context
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceContext {
+    /// Lowercase hex-encoded 16-byte ID of the whole trace.
+    pub trace_id: String,
+
+    /// Lowercase hex-encoded 8-byte ID of the request in the trace.
+    pub parent_id: String,
+
+    /// Trace flags.
+    pub flags: u8,
+}
+
+impl TraceContext {
+    /// Indicates whether the caller may have recorded the trace.
+    pub fn is_sampled(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+}
+
+impl FromStr for TraceContext {
+    type Err = ();
+
+    /// Parses a `traceparent` header value of the `00` version (or of any
+    /// future version, ignoring its extra fields).
+    fn from_str(header: &str) -> Result<Self, Self::Err> {
+        fn is_hex(s: &str, len: usize) -> bool {
+            s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        }
+
+        let mut parts = header.trim().split('-');
+        let (version, trace_id, parent_id, flags) =
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(v), Some(t), Some(p), Some(f)) => (v, t, p, f),
+                _ => return Err(()),
+            };
+        if !is_hex(version, 2) || version == "ff" || (version == "00" && parts.next().is_some()) {
+            return Err(());
+        }
+        if !is_hex(trace_id, 32) || trace_id.bytes().all(|b| b == b'0') {
+            return Err(());
+        }
+        if !is_hex(parent_id, 16) || parent_id.bytes().all(|b| b == b'0') {
+            return Err(());
+        }
+        if !is_hex(flags, 2) {
+            return Err(());
+        }
+
+        Ok(Self {
+            trace_id: trace_id.into(),
+            parent_id: parent_id.into(),
+            flags: u8::from_str_radix(flags, 16).map_err(|_| ())?,
+        })
+    }
+}
+
+/// Identifiers correlating an executed request with the logs of its origin.
+///
+/// Usually extracted from the [`TRACEPARENT_HEADER`] and [`REQUEST_ID_HEADER`]
+/// HTTP headers by web server integrations, and provided to the [`Executor`]
+/// via [`ExecutionOptions::correlation()`].
+///
+/// [`ExecutionOptions::correlation()`]: crate::ExecutionOptions::correlation
+/// [`Executor`]: crate::Executor
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Correlation {
+    /// Explicitly provided ID of the request.
+    pub request_id: Option<String>,
+
+    /// Trace the request is part of.
+    pub trace_context: Option<TraceContext>,
+}
+
+impl Correlation {
+    /// Creates a new [`Correlation`] out of the provided [`TRACEPARENT_HEADER`]
+    /// and [`REQUEST_ID_HEADER`] values.
+    ///
+    /// Malformed `traceparent` and blank request IDs are ignored.
+    pub fn from_headers(traceparent: Option<&str>, request_id: Option<&str>) -> Self {
+        Self {
+            request_id: request_id
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(Into::into),
+            trace_context: traceparent.and_then(|h| h.parse().ok()),
+        }
+    }
+
+    /// Returns the ID of the request: the explicitly provided one, or the ID
+    /// of its trace otherwise.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id
+            .as_deref()
+            .or_else(|| self.trace_context.as_ref().map(|t| t.trace_id.as_str()))
+    }
+
+    /// Indicates whether this [`Correlation`] contains no identifiers.
+    pub fn is_empty(&self) -> bool {
+        self.request_id.is_none() && self.trace_context.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_object, graphql_value, DefaultScalarValue, EmptyMutation, EmptySubscription,
+        ExecutionError, ExecutionOptions, Executor, FieldResult, RootNode, Value, Variables,
+    };
+
+    use super::{Correlation, TraceContext};
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn parses_traceparent() {
+        assert_eq!(
+            TRACEPARENT.parse(),
+            Ok(TraceContext {
+                trace_id: "4bf92f3577b34da6a3ce929d0e0e4736".into(),
+                parent_id: "00f067aa0ba902b7".into(),
+                flags: 1,
+            }),
+        );
+        assert!(TRACEPARENT.parse::<TraceContext>().unwrap().is_sampled());
+    }
+
+    #[test]
+    fn rejects_malformed_traceparent() {
+        for header in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+        ] {
+            assert_eq!(header.parse::<TraceContext>(), Err(()), "{header}");
+        }
+    }
+
+    #[test]
+    fn falls_back_to_trace_id() {
+        let correlation = Correlation::from_headers(Some(TRACEPARENT), None);
+        assert_eq!(
+            correlation.request_id(),
+            Some("4bf92f3577b34da6a3ce929d0e0e4736"),
+        );
+
+        let correlation = Correlation::from_headers(Some(TRACEPARENT), Some("req-1"));
+        assert_eq!(correlation.request_id(), Some("req-1"));
+
+        let correlation = Correlation::from_headers(Some("invalid"), Some(" "));
+        assert!(correlation.is_empty());
+    }
+
+    struct Query;
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl Query {
+        fn request_id(executor: &Executor<()>) -> Option<String> {
+            executor.request_id().map(Into::into)
+        }
+
+        fn fail() -> FieldResult<Option<i32>> {
+            Err("boom".into())
+        }
+    }
+
+    fn execute(options: &ExecutionOptions) -> (Value, Vec<ExecutionError<DefaultScalarValue>>) {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        crate::execute_sync_with_options(
+            "{ requestId fail }",
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+            options,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn stamps_errors_with_request_id() {
+        let correlation = Correlation::from_headers(None, Some("req-1"));
+
+        let (data, errors) = execute(&ExecutionOptions::new().correlation(correlation.clone()));
+        assert_eq!(data, graphql_value!({"requestId": "req-1", "fail": null}));
+        assert_eq!(errors[0].error().extensions(), &graphql_value!(null));
+
+        let (_, errors) = execute(
+            &ExecutionOptions::new()
+                .correlation(correlation)
+                .stamp_request_id(true),
+        );
+        assert_eq!(
+            errors[0].error().extensions(),
+            &graphql_value!({"requestId": "req-1"}),
+        );
+    }
+}
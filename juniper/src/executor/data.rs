@@ -0,0 +1,129 @@
+//! Typed data available to resolvers regardless of their context.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+};
+
+/// Map of values keyed by their types, exposed to resolvers via
+/// [`Executor::data()`].
+///
+/// Allows libraries building on top of [Juniper] (like data loaders, caches or
+/// message brokers) to stash state for resolvers without requiring users to
+/// extend their context types.
+///
+/// Values may be inserted for a whole schema via [`RootNode::with_data()`],
+/// or for a single request via [`ExecutionOptions::data()`].
+///
+/// ```rust
+/// # use juniper::Data;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct PoolSize(u32);
+///
+/// let mut data = Data::new();
+/// data.insert(PoolSize(8));
+///
+/// assert_eq!(data.get::<PoolSize>(), Some(&PoolSize(8)));
+/// assert_eq!(data.get::<String>(), None);
+/// ```
+///
+/// [`ExecutionOptions::data()`]: crate::ExecutionOptions::data
+/// [`Executor::data()`]: crate::Executor::data
+/// [`RootNode::with_data()`]: crate::RootNode::with_data
+/// [Juniper]: https://docs.rs/juniper
+#[derive(Clone, Default)]
+pub struct Data(HashMap<TypeId, Arc<dyn Any + Send + Sync>>);
+
+impl fmt::Debug for Data {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Data")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Data {
+    /// Creates a new empty [`Data`] map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the provided `value` into this [`Data`] map, replacing the
+    /// value of the same type, if any.
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) {
+        self.insert_shared(Arc::new(value));
+    }
+
+    /// Inserts the provided shared `value` into this [`Data`] map, replacing
+    /// the value of the same type, if any.
+    pub fn insert_shared<T: Any + Send + Sync>(&mut self, value: Arc<T>) {
+        self.0.insert(TypeId::of::<T>(), value);
+    }
+
+    /// Returns the value of the `T` type, if any.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|v| v.downcast_ref())
+    }
+
+    /// Indicates whether this [`Data`] map contains a value of the `T` type.
+    pub fn contains<T: Any + Send + Sync>(&self) -> bool {
+        self.0.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Removes the value of the `T` type from this [`Data`] map, returning
+    /// whether there was any.
+    pub fn remove<T: Any + Send + Sync>(&mut self) -> bool {
+        self.0.remove(&TypeId::of::<T>()).is_some()
+    }
+
+    /// Returns the number of values in this [`Data`] map.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Indicates whether this [`Data`] map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::Data;
+
+    #[test]
+    fn keys_values_by_type() {
+        let mut data = Data::new();
+        data.insert(1_u32);
+        data.insert("str");
+        data.insert(2_u32);
+
+        assert_eq!(data.len(), 2);
+        assert_eq!(data.get::<u32>(), Some(&2));
+        assert_eq!(data.get::<&str>(), Some(&"str"));
+        assert_eq!(data.get::<i32>(), None);
+
+        assert!(data.remove::<u32>());
+        assert!(!data.remove::<u32>());
+        assert!(!data.contains::<u32>());
+    }
+
+    #[test]
+    fn shares_values_between_clones() {
+        let value = Arc::new(String::from("shared"));
+        let mut data = Data::new();
+        data.insert_shared(Arc::clone(&value));
+
+        let clone = data.clone();
+
+        assert!(std::ptr::eq(clone.get::<String>().unwrap(), &*value));
+    }
+}
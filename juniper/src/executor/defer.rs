@@ -0,0 +1,592 @@
+//! Incremental delivery of `@defer`red fragments.
+
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    sync::{Arc, Mutex},
+};
+
+use futures::{
+    future,
+    stream::{self, BoxStream, StreamExt as _},
+};
+
+use crate::{
+    ast::{Definition, Directive, Document, Fragment, OperationType, OwnedDocument, Selection},
+    parser::{SourcePosition, Spanning},
+    schema::model::RootNode,
+    types::{async_await::GraphQLTypeAsync, base::GraphQLType},
+    value::{DefaultScalarValue, Object, PathSegment, ScalarValue, Value},
+    GraphQLError,
+};
+
+use super::{
+    execute_validated_query_async_with_options, get_operation, ExecutionError, ExecutionOptions,
+    Executor, Variables,
+};
+
+/// Name of the directive marking a fragment to be delivered incrementally,
+/// after the initial payload of a response.
+pub const DEFER_DIRECTIVE: &str = "defer";
+
+/// Stream of [`IncrementalPayload`]s produced by [`execute_incremental()`].
+///
+/// The first item is always an [`IncrementalPayload::Initial`] one, followed
+/// by [`IncrementalPayload::Subsequent`] ones (if any), until the one having
+/// no next payloads.
+///
+/// [`execute_incremental()`]: crate::execute_incremental
+pub type IncrementalExecutionOutput<'a, S = DefaultScalarValue> =
+    BoxStream<'a, IncrementalPayload<S>>;
+
+/// Single payload of an incrementally delivered response.
+///
+/// See the [incremental delivery RFC][0] for details.
+///
+/// [0]: https://github.com/graphql/graphql-spec/pull/742
+#[derive(Debug, PartialEq)]
+pub enum IncrementalPayload<S = DefaultScalarValue> {
+    /// Initial payload, containing everything except the `@defer`red
+    /// fragments.
+    Initial {
+        /// Resolved data of the operation.
+        data: Value<S>,
+
+        /// Errors occurred while resolving the `data`.
+        errors: Vec<ExecutionError<S>>,
+
+        /// Indicates whether any [`IncrementalPayload::Subsequent`] ones
+        /// follow this payload.
+        has_next: bool,
+    },
+
+    /// Subsequent payload, delivering resolved `@defer`red fragments.
+    Subsequent {
+        /// Resolved `@defer`red fragments.
+        incremental: Vec<IncrementalResult<S>>,
+
+        /// Indicates whether any other payloads follow this one.
+        has_next: bool,
+    },
+}
+
+impl<S> IncrementalPayload<S> {
+    /// Indicates whether any other payloads follow this [`IncrementalPayload`].
+    pub fn has_next(&self) -> bool {
+        match self {
+            Self::Initial { has_next, .. } | Self::Subsequent { has_next, .. } => *has_next,
+        }
+    }
+}
+
+/// Resolved `@defer`red fragment, to be merged into the object located at its
+/// [`path`] in the response.
+///
+/// [`path`]: IncrementalResult::path
+#[derive(Debug, PartialEq)]
+pub struct IncrementalResult<S = DefaultScalarValue> {
+    /// Resolved fields of the `@defer`red fragment.
+    pub data: Value<S>,
+
+    /// Path to the object in the response the [`data`] belongs to.
+    ///
+    /// [`data`]: IncrementalResult::data
+    pub path: Vec<PathSegment>,
+
+    /// Label of the `@defer`red fragment, if specified.
+    pub label: Option<String>,
+
+    /// Errors occurred while resolving the [`data`].
+    ///
+    /// [`data`]: IncrementalResult::data
+    pub errors: Vec<ExecutionError<S>>,
+}
+
+/// Stage of an incremental execution, an operation is executed in.
+#[derive(Clone, Debug, Default)]
+pub(crate) enum DeferMode {
+    /// `@defer` directives are ignored, so all the fragments are resolved
+    /// inline.
+    #[default]
+    Inline,
+
+    /// Initial payload is being resolved, so the active `@defer`red fragments
+    /// are skipped and recorded for being resolved later.
+    Initial(Arc<Mutex<Vec<DeferredFragment>>>),
+
+    /// Subsequent payload is being resolved, so only the selections leading to
+    /// (or being part of) the `@defer`red fragment are resolved.
+    Subsequent(Arc<DeferTarget>),
+}
+
+/// `@defer`red fragment recorded while resolving the initial payload.
+#[derive(Clone, Debug)]
+pub(crate) struct DeferredFragment {
+    /// Position of the fragment in the document.
+    position: SourcePosition,
+
+    /// Label of the `@defer` directive, if any.
+    label: Option<String>,
+}
+
+/// `@defer`red fragment being resolved for a subsequent payload.
+#[derive(Debug)]
+pub(crate) struct DeferTarget {
+    /// Position of the fragment in the document.
+    position: SourcePosition,
+
+    /// Positions of the selections containing the fragment.
+    ancestors: HashSet<SourcePosition>,
+
+    /// Positions of the selections to be resolved: the fragment itself, its
+    /// ancestors and all its descendants.
+    included: HashSet<SourcePosition>,
+}
+
+impl<'r, 'a, CtxT, S> Executor<'r, 'a, CtxT, S>
+where
+    S: ScalarValue,
+{
+    /// Indicates whether the selection with the provided `directives` located
+    /// at the provided `position` should not be resolved at the current stage
+    /// of an incremental execution.
+    pub(crate) fn is_deferred(
+        &self,
+        directives: &Option<Vec<Spanning<Directive<S>>>>,
+        position: SourcePosition,
+    ) -> bool {
+        match &self.options.defer {
+            DeferMode::Inline => false,
+            DeferMode::Initial(deferred) => {
+                let defer = directives
+                    .iter()
+                    .flatten()
+                    .find(|d| d.item.name.item == DEFER_DIRECTIVE);
+                let defer = match defer {
+                    Some(d) => &d.item,
+                    None => return false,
+                };
+                let arg = |name: &str| {
+                    defer
+                        .arguments
+                        .as_ref()
+                        .and_then(|args| args.item.get(name))
+                        .and_then(|v| v.item.clone().into_const(self.variables))
+                };
+                if !arg("if").map_or(true, |v| v.convert::<bool>().unwrap_or(true)) {
+                    return false;
+                }
+
+                let mut deferred = deferred.lock().unwrap();
+                if deferred.iter().all(|d| d.position != position) {
+                    deferred.push(DeferredFragment {
+                        position,
+                        label: arg("label").and_then(|v| v.as_string_value().map(Into::into)),
+                    });
+                }
+                true
+            }
+            DeferMode::Subsequent(target) => !target.included.contains(&position),
+        }
+    }
+}
+
+/// Executes the provided validated `document`, delivering its `@defer`red
+/// fragments incrementally.
+///
+/// The initial payload is resolved before returning, while every `@defer`red
+/// fragment is resolved lazily on polling the returned stream, by re-executing
+/// the operation narrowed down to that fragment.
+///
+/// `@defer` directives are ignored in mutations, so their side effects are not
+/// performed twice.
+pub(crate) async fn execute_validated_query_incremental<'a, QueryT, MutationT, SubscriptionT, S>(
+    document: OwnedDocument<'a, S>,
+    operation_name: Option<&'a str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: Variables<S>,
+    context: &'a QueryT::Context,
+    options: ExecutionOptions,
+) -> Result<IncrementalExecutionOutput<'a, S>, GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync + 'a,
+{
+    let deferred = Arc::new(Mutex::new(Vec::new()));
+    let (data, errors) = {
+        let operation = get_operation(&document, operation_name)?;
+        let options = if operation.item.operation_type == OperationType::Mutation {
+            options.clone()
+        } else {
+            options.clone().defer(DeferMode::Initial(deferred.clone()))
+        };
+        root_node.mapped_errors(
+            execute_validated_query_async_with_options(
+                &document, operation, root_node, &variables, context, &options,
+            )
+            .await,
+        )?
+    };
+
+    let mut deferred = mem::take(&mut *deferred.lock().unwrap());
+    deferred.sort_by_key(|d| d.position);
+
+    let initial = IncrementalPayload::Initial {
+        data,
+        errors,
+        has_next: !deferred.is_empty(),
+    };
+    if deferred.is_empty() {
+        return Ok(stream::once(future::ready(initial)).boxed());
+    }
+
+    let state = (
+        document,
+        deferred.into_iter().peekable(),
+        variables,
+        options,
+    );
+    let subsequent = stream::unfold(Some(state), move |state| async move {
+        let (document, mut deferred, variables, options) = state?;
+
+        let mut incremental = Vec::new();
+        while incremental.is_empty() {
+            let fragment = match deferred.next() {
+                Some(f) => f,
+                None => break,
+            };
+            incremental = execute_deferred_fragment(
+                &document,
+                operation_name,
+                root_node,
+                &variables,
+                context,
+                &options,
+                fragment,
+            )
+            .await;
+        }
+
+        let has_next = deferred.peek().is_some();
+        let payload = IncrementalPayload::Subsequent {
+            incremental,
+            has_next,
+        };
+        let state = has_next.then_some((document, deferred, variables, options));
+        Some((payload, state))
+    });
+
+    Ok(stream::once(future::ready(initial))
+        .chain(subsequent)
+        .boxed())
+}
+
+/// Resolves the provided `@defer`red `fragment` of the operation, narrowing its
+/// execution down to the selections containing the `fragment`.
+async fn execute_deferred_fragment<'a, QueryT, MutationT, SubscriptionT, S>(
+    document: &Document<'a, S>,
+    operation_name: Option<&str>,
+    root_node: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    options: &ExecutionOptions,
+    fragment: DeferredFragment,
+) -> Vec<IncrementalResult<S>>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let operation = match get_operation(document, operation_name) {
+        Ok(op) => op,
+        Err(_) => return Vec::new(),
+    };
+    let fragments = fragments(document);
+    let target = Arc::new(DeferTarget::new(
+        fragment.position,
+        &operation.item.selection_set,
+        &fragments,
+    ));
+
+    let options = options.clone().defer(DeferMode::Subsequent(target.clone()));
+    match root_node.mapped_errors(
+        execute_validated_query_async_with_options(
+            document, operation, root_node, variables, context, &options,
+        )
+        .await,
+    ) {
+        Ok((data, errors)) => target.results(
+            &data,
+            errors,
+            &operation.item.selection_set,
+            &fragments,
+            fragment.label.as_deref(),
+        ),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Collects all the [`Fragment`] definitions of the provided `document` by
+/// their names.
+fn fragments<'d, 'a, S>(document: &'d Document<'a, S>) -> HashMap<&'a str, &'d Fragment<'a, S>> {
+    document
+        .iter()
+        .filter_map(|d| match d {
+            Definition::Fragment(f) => Some((f.item.name.item, &f.item)),
+            Definition::Operation(_) => None,
+        })
+        .collect()
+}
+
+impl DeferTarget {
+    /// Creates a new [`DeferTarget`] of the fragment located at the provided
+    /// `position` in the provided operation's `selection_set`.
+    fn new<S>(
+        position: SourcePosition,
+        selection_set: &[Selection<S>],
+        fragments: &HashMap<&str, &Fragment<S>>,
+    ) -> Self {
+        let mut target = Self {
+            position,
+            ancestors: HashSet::new(),
+            included: HashSet::new(),
+        };
+        target.collect(selection_set, fragments);
+        target.included.extend(target.ancestors.iter().copied());
+        target
+    }
+
+    /// Collects [`DeferTarget::ancestors`] and [`DeferTarget::included`]
+    /// positions of the provided `selection_set`, returning whether it contains
+    /// the fragment.
+    fn collect<S>(
+        &mut self,
+        selection_set: &[Selection<S>],
+        fragments: &HashMap<&str, &Fragment<S>>,
+    ) -> bool {
+        let mut contains = false;
+        for selection in selection_set {
+            let (position, selection_set) = match selection {
+                Selection::Field(f) => (f.start, f.item.selection_set.as_deref()),
+                Selection::FragmentSpread(s) => (
+                    s.start,
+                    fragments
+                        .get(s.item.name.item)
+                        .map(|f| &f.selection_set[..]),
+                ),
+                Selection::InlineFragment(f) => (f.start, Some(&f.item.selection_set[..])),
+            };
+            let selection_set = selection_set.unwrap_or_default();
+            if position == self.position {
+                self.included.insert(position);
+                include_all(selection_set, fragments, &mut self.included);
+                contains = true;
+            } else if self.collect(selection_set, fragments) {
+                self.ancestors.insert(position);
+                contains = true;
+            }
+        }
+        contains
+    }
+
+    /// Extracts [`IncrementalResult`]s of this [`DeferTarget`] out of the
+    /// provided `data` resolved for it.
+    ///
+    /// As error paths contain no list indices, the provided `errors` are
+    /// attached to the first result they may belong to.
+    fn results<S: ScalarValue>(
+        &self,
+        data: &Value<S>,
+        errors: Vec<ExecutionError<S>>,
+        selection_set: &[Selection<S>],
+        fragments: &HashMap<&str, &Fragment<S>>,
+        label: Option<&str>,
+    ) -> Vec<IncrementalResult<S>> {
+        let mut results = Vec::new();
+        self.extract(
+            data,
+            selection_set,
+            fragments,
+            &mut Vec::new(),
+            &mut results,
+        );
+
+        for e in errors {
+            let belongs_to = |r: &IncrementalResult<S>| {
+                let fields = r.path.iter().filter_map(|s| match s {
+                    PathSegment::Field(f) => Some(f),
+                    PathSegment::Index(_) => None,
+                });
+                let depth = fields.clone().count();
+                // Errors of the ancestors are reported in the initial payload
+                // already.
+                e.path().len() > depth && e.path().iter().zip(fields).all(|(a, b)| a == b)
+            };
+            if let Some(r) = results.iter_mut().find(|r| belongs_to(r)) {
+                r.errors.push(e);
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|r| IncrementalResult {
+                label: label.map(Into::into),
+                ..r
+            })
+            .collect()
+    }
+
+    /// Walks the provided `value` along the provided `selection_set`, pushing
+    /// the objects resolved for this [`DeferTarget`] into `results`.
+    fn extract<S: ScalarValue>(
+        &self,
+        value: &Value<S>,
+        selection_set: &[Selection<S>],
+        fragments: &HashMap<&str, &Fragment<S>>,
+        path: &mut Vec<PathSegment>,
+        results: &mut Vec<IncrementalResult<S>>,
+    ) {
+        let object = match value {
+            Value::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    path.push(PathSegment::Index(i));
+                    self.extract(item, selection_set, fragments, path, results);
+                    path.pop();
+                }
+                return;
+            }
+            Value::Object(o) => o,
+            Value::Null | Value::Scalar(_) => return,
+        };
+
+        for selection in selection_set {
+            match selection {
+                Selection::Field(f) if self.ancestors.contains(&f.start) => {
+                    let name = f.item.alias.as_ref().unwrap_or(&f.item.name).item;
+                    if let Some(v) = object.get_field_value(name) {
+                        path.push(PathSegment::Field(name.into()));
+                        let selection_set = f.item.selection_set.as_deref().unwrap_or_default();
+                        self.extract(v, selection_set, fragments, path, results);
+                        path.pop();
+                    }
+                }
+                Selection::FragmentSpread(s) if s.start == self.position => {
+                    if let Some(f) = fragments.get(s.item.name.item) {
+                        self.push_result(object, &f.selection_set, fragments, path, results);
+                    }
+                }
+                Selection::InlineFragment(f) if f.start == self.position => {
+                    self.push_result(object, &f.item.selection_set, fragments, path, results);
+                }
+                Selection::FragmentSpread(s) if self.ancestors.contains(&s.start) => {
+                    if let Some(f) = fragments.get(s.item.name.item) {
+                        self.extract(value, &f.selection_set, fragments, path, results);
+                    }
+                }
+                Selection::InlineFragment(f) if self.ancestors.contains(&f.start) => {
+                    self.extract(value, &f.item.selection_set, fragments, path, results);
+                }
+                Selection::Field(_)
+                | Selection::FragmentSpread(_)
+                | Selection::InlineFragment(_) => {}
+            }
+        }
+    }
+
+    /// Pushes the fields of the provided `object` belonging to the provided
+    /// `selection_set` of this [`DeferTarget`] into `results`.
+    ///
+    /// Nothing is pushed if there are no such fields (the fragment's type
+    /// condition doesn't match the `object`, for example).
+    fn push_result<S: ScalarValue>(
+        &self,
+        object: &Object<S>,
+        selection_set: &[Selection<S>],
+        fragments: &HashMap<&str, &Fragment<S>>,
+        path: &[PathSegment],
+        results: &mut Vec<IncrementalResult<S>>,
+    ) {
+        let mut names = HashSet::new();
+        response_names(selection_set, fragments, &mut names);
+
+        let data = object
+            .iter()
+            .filter(|(k, _)| names.contains(k.as_str()))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Object<S>>();
+        if data.field_count() > 0 {
+            results.push(IncrementalResult {
+                data: Value::Object(data),
+                path: path.to_vec(),
+                label: None,
+                errors: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Inserts positions of all the selections of the provided `selection_set`
+/// (including the nested ones) into `included`.
+fn include_all<S>(
+    selection_set: &[Selection<S>],
+    fragments: &HashMap<&str, &Fragment<S>>,
+    included: &mut HashSet<SourcePosition>,
+) {
+    for selection in selection_set {
+        match selection {
+            Selection::Field(f) => {
+                if included.insert(f.start) {
+                    let selection_set = f.item.selection_set.as_deref().unwrap_or_default();
+                    include_all(selection_set, fragments, included);
+                }
+            }
+            Selection::FragmentSpread(s) => {
+                if included.insert(s.start) {
+                    if let Some(f) = fragments.get(s.item.name.item) {
+                        include_all(&f.selection_set, fragments, included);
+                    }
+                }
+            }
+            Selection::InlineFragment(f) => {
+                if included.insert(f.start) {
+                    include_all(&f.item.selection_set, fragments, included);
+                }
+            }
+        }
+    }
+}
+
+/// Collects response names of all the fields of the provided `selection_set`
+/// (including the ones of its fragments) into `names`.
+fn response_names<'a, S>(
+    selection_set: &[Selection<'a, S>],
+    fragments: &HashMap<&str, &Fragment<'a, S>>,
+    names: &mut HashSet<&'a str>,
+) {
+    for selection in selection_set {
+        match selection {
+            Selection::Field(f) => {
+                names.insert(f.item.alias.as_ref().unwrap_or(&f.item.name).item);
+            }
+            Selection::FragmentSpread(s) => {
+                if let Some(f) = fragments.get(s.item.name.item) {
+                    response_names(&f.selection_set, fragments, names);
+                }
+            }
+            Selection::InlineFragment(f) => {
+                response_names(&f.item.selection_set, fragments, names);
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Handlers of custom executable directives.
+
+use std::{fmt, sync::Arc};
+
+use fnv::FnvHashMap;
+
+use crate::{
+    executor::{extension::FieldInfo, ExecutionResult},
+    types::base::Arguments,
+    value::DefaultScalarValue,
+};
+
+/// Handler of a custom executable directive, applied to every field the
+/// directive is specified on in an operation.
+///
+/// Handlers are registered along with the definitions of their directives via
+/// [`RootNode::with_directive()`], so the directives are exposed via
+/// introspection, and their locations and arguments are validated as for any
+/// other directive.
+///
+/// The handlers of the directives specified on the same field are called in
+/// the order of the directives on [`DirectiveHandler::before()`], and in the
+/// reverse order on [`DirectiveHandler::after()`].
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_object, graphql_value, graphql_vars, AppliedDirective, DirectiveHandler,
+/// #     DirectiveLocation, DirectiveType, EmptyMutation, EmptySubscription, ExecutionResult,
+/// #     RootNode, ScalarValue as _, Value,
+/// # };
+/// #
+/// struct Uppercase;
+///
+/// impl DirectiveHandler for Uppercase {
+///     fn after(&self, _: &AppliedDirective<'_>, result: ExecutionResult) -> ExecutionResult {
+///         Ok(match result? {
+///             Value::Scalar(s) => match s.as_str() {
+///                 Some(s) => Value::scalar(s.to_uppercase()),
+///                 None => Value::Scalar(s),
+///             },
+///             v => v,
+///         })
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn name() -> &'static str {
+///         "juniper"
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new())
+///     .with_directive(
+///         DirectiveType::new("uppercase", &[DirectiveLocation::Field], &[], false),
+///         Uppercase,
+///     );
+///
+/// assert_eq!(
+///     juniper::execute_sync("{ name @uppercase }", None, &schema, &graphql_vars! {}, &()),
+///     Ok((graphql_value!({"name": "JUNIPER"}), vec![])),
+/// );
+/// ```
+///
+/// [`RootNode::with_directive()`]: crate::RootNode::with_directive
+pub trait DirectiveHandler<S = DefaultScalarValue>: Send + Sync {
+    /// Called before resolving a field the directive is specified on.
+    ///
+    /// Returning a result short-circuits the field: it's not resolved at all,
+    /// and the returned result is used instead (without calling
+    /// [`DirectiveHandler::after()`] of this handler).
+    ///
+    /// Does nothing by default.
+    fn before(&self, directive: &AppliedDirective<'_, S>) -> Option<ExecutionResult<S>> {
+        let _ = directive;
+        None
+    }
+
+    /// Called once a field the directive is specified on is resolved, with
+    /// the `result` of resolving it, returning the transformed one.
+    ///
+    /// Passes the `result` through by default.
+    fn after(
+        &self,
+        directive: &AppliedDirective<'_, S>,
+        result: ExecutionResult<S>,
+    ) -> ExecutionResult<S> {
+        let _ = directive;
+        result
+    }
+}
+
+impl<S, T: DirectiveHandler<S> + ?Sized> DirectiveHandler<S> for Arc<T> {
+    fn before(&self, directive: &AppliedDirective<'_, S>) -> Option<ExecutionResult<S>> {
+        (**self).before(directive)
+    }
+
+    fn after(
+        &self,
+        directive: &AppliedDirective<'_, S>,
+        result: ExecutionResult<S>,
+    ) -> ExecutionResult<S> {
+        (**self).after(directive, result)
+    }
+}
+
+/// Directive specified on a field being resolved, as seen by a
+/// [`DirectiveHandler`].
+#[derive(Debug)]
+pub struct AppliedDirective<'a, S = DefaultScalarValue> {
+    /// Name of the directive.
+    pub name: &'a str,
+
+    /// Arguments of the directive, with the variables substituted and the
+    /// default values filled in.
+    pub arguments: &'a Arguments<'a, S>,
+
+    /// Field the directive is specified on.
+    pub field: &'a FieldInfo<'a>,
+}
+
+/// [`DirectiveHandler`]s registered in a schema, keyed by the names of their
+/// directives.
+pub(crate) struct DirectiveHandlers<S>(FnvHashMap<String, Arc<dyn DirectiveHandler<S>>>);
+
+impl<S> Default for DirectiveHandlers<S> {
+    fn default() -> Self {
+        Self(FnvHashMap::default())
+    }
+}
+
+impl<S> fmt::Debug for DirectiveHandlers<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+impl<S> DirectiveHandlers<S> {
+    /// Registers the provided `handler` of the `name`d directive, replacing
+    /// the existing one, if any.
+    pub(crate) fn insert(&mut self, name: &str, handler: Arc<dyn DirectiveHandler<S>>) {
+        self.0.insert(name.into(), handler);
+    }
+
+    /// Returns the handler of the `name`d directive, if any.
+    pub(crate) fn get(&self, name: &str) -> Option<&Arc<dyn DirectiveHandler<S>>> {
+        self.0.get(name)
+    }
+
+    /// Indicates whether there are no handlers registered.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
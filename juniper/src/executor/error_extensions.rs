@@ -0,0 +1,229 @@
+//! Typed construction of [`FieldError`]s and their `extensions`.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{
+    executor::{FieldError, IntoFieldError},
+    value::{DefaultScalarValue, Object, ScalarValue, Value},
+};
+
+/// Name of the [`FieldError::extensions`] field set by
+/// [`FieldErrorBuilder::code()`].
+pub const CODE_EXTENSION: &str = "code";
+
+/// Name of the [`FieldError::extensions`] field set by
+/// [`FieldErrorBuilder::detail()`].
+pub const DETAIL_EXTENSION: &str = "detail";
+
+/// `extensions` of a [`FieldError`], keyed by their names.
+///
+/// Unlike a raw [`Object`], the fields are always ordered by their names, so
+/// the serialized `extensions` are deterministic regardless of the order they
+/// were inserted in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorExtensions<S = DefaultScalarValue>(BTreeMap<String, Value<S>>);
+
+impl<S> Default for ErrorExtensions<S> {
+    fn default() -> Self {
+        Self(BTreeMap::new())
+    }
+}
+
+impl<S> ErrorExtensions<S> {
+    /// Creates new empty [`ErrorExtensions`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the `name`d field with the provided `value`, returning the
+    /// replaced value, if any.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<Value<S>>,
+    ) -> Option<Value<S>> {
+        self.0.insert(name.into(), value.into())
+    }
+
+    /// Returns the value of the `name`d field, if any.
+    pub fn get(&self, name: &str) -> Option<&Value<S>> {
+        self.0.get(name)
+    }
+
+    /// Removes the `name`d field, returning its value, if any.
+    pub fn remove(&mut self, name: &str) -> Option<Value<S>> {
+        self.0.remove(name)
+    }
+
+    /// Returns the number of fields.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Indicates whether there are no fields.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the fields ordered by their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value<S>)> {
+        self.0.iter()
+    }
+}
+
+impl<S, K: Into<String>, V: Into<Value<S>>> FromIterator<(K, V)> for ErrorExtensions<S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+impl<S> From<ErrorExtensions<S>> for Value<S> {
+    /// Converts the provided [`ErrorExtensions`] into a [`Value::Object`], or
+    /// into a [`Value::Null`] if they're empty (so no `extensions` are
+    /// emitted at all).
+    fn from(ext: ErrorExtensions<S>) -> Self {
+        if ext.is_empty() {
+            return Self::Null;
+        }
+        let mut obj = Object::with_capacity(ext.len());
+        for (k, v) in ext.0 {
+            obj.add_field(k, v);
+        }
+        Self::Object(obj)
+    }
+}
+
+/// Builder of a [`FieldError`], created via [`FieldError::builder()`].
+///
+/// ```rust
+/// # use juniper::{graphql_value, FieldError};
+/// #
+/// let err: FieldError = FieldError::builder()
+///     .message("User not found")
+///     .code("NOT_FOUND")
+///     .detail("No user with ID 42 exists")
+///     .extension("id", 42)
+///     .build();
+///
+/// assert_eq!(err.message(), "User not found");
+/// assert_eq!(
+///     err.extensions(),
+///     &graphql_value!({
+///         "code": "NOT_FOUND",
+///         "detail": "No user with ID 42 exists",
+///         "id": 42,
+///     }),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[must_use = "builders do nothing unless built"]
+pub struct FieldErrorBuilder<S = DefaultScalarValue> {
+    message: String,
+    extensions: ErrorExtensions<S>,
+}
+
+impl<S> Default for FieldErrorBuilder<S> {
+    fn default() -> Self {
+        Self {
+            message: String::new(),
+            extensions: ErrorExtensions::new(),
+        }
+    }
+}
+
+impl<S: ScalarValue> FieldErrorBuilder<S> {
+    /// Sets the `"message"` of the built [`FieldError`].
+    pub fn message(mut self, message: impl Display) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Sets the [`CODE_EXTENSION`] of the built [`FieldError`], identifying
+    /// its kind in a machine-readable way.
+    pub fn code(self, code: impl Into<String>) -> Self {
+        self.extension(CODE_EXTENSION, code.into())
+    }
+
+    /// Sets the [`DETAIL_EXTENSION`] of the built [`FieldError`], explaining
+    /// this specific occurrence of the error.
+    pub fn detail(self, detail: impl Display) -> Self {
+        self.extension(DETAIL_EXTENSION, detail.to_string())
+    }
+
+    /// Sets the `name`d extension of the built [`FieldError`] to the provided
+    /// `value`.
+    pub fn extension(mut self, name: impl Into<String>, value: impl Into<Value<S>>) -> Self {
+        self.extensions.insert(name, value);
+        self
+    }
+
+    /// Merges the provided [`ErrorExtensions`] into the ones of the built
+    /// [`FieldError`], replacing the existing fields with the same names.
+    pub fn extensions(mut self, extensions: ErrorExtensions<S>) -> Self {
+        self.extensions.0.extend(extensions.0);
+        self
+    }
+
+    /// Builds the [`FieldError`].
+    #[must_use]
+    pub fn build(self) -> FieldError<S> {
+        FieldError::new(self.message, self.extensions.into())
+    }
+}
+
+impl<S: ScalarValue> From<FieldErrorBuilder<S>> for FieldError<S> {
+    fn from(builder: FieldErrorBuilder<S>) -> Self {
+        builder.build()
+    }
+}
+
+impl<S: ScalarValue> IntoFieldError<S> for FieldErrorBuilder<S> {
+    fn into_field_error(self) -> FieldError<S> {
+        self.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graphql_value, FieldError, IntoFieldError as _, Value};
+
+    use super::ErrorExtensions;
+
+    #[test]
+    fn orders_extensions_by_name() {
+        let ext: ErrorExtensions = [("b", 2), ("c", 3), ("a", 1)].into_iter().collect();
+
+        assert_eq!(
+            serde_json::to_string(&Value::from(ext)).unwrap(),
+            r#"{"a":1,"b":2,"c":3}"#,
+        );
+    }
+
+    #[test]
+    fn omits_empty_extensions() {
+        let err: FieldError = FieldError::builder().message("Oops").build();
+
+        assert_eq!(err, FieldError::from("Oops"));
+        assert_eq!(err.extensions(), &Value::Null);
+    }
+
+    #[test]
+    fn merges_extensions() {
+        let err: FieldError = FieldError::builder()
+            .code("FORBIDDEN")
+            .extension("retry", false)
+            .extensions([("retry", true)].into_iter().collect())
+            .into_field_error();
+
+        assert_eq!(err.message(), "");
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "FORBIDDEN", "retry": true}),
+        );
+    }
+}
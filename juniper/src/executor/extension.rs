@@ -0,0 +1,180 @@
+//! Hooks into the lifecycle of an operation execution.
+
+use std::{fmt, sync::Arc};
+
+use crate::{ast::Type, GraphQLError};
+
+/// Extension of an operation execution, being notified about its lifecycle
+/// stages, so cross-cutting concerns (like logging, auditing or metrics) may
+/// be implemented without wrapping every resolver.
+///
+/// Extensions are registered via [`ExecutionOptions::extension()`] (or
+/// [`RootNode::with_extension()`] for a whole schema), and notified in the
+/// order of their registration on the start of a stage, and in the reverse
+/// order on its end.
+///
+/// All the hooks do nothing by default.
+///
+/// ```
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use juniper::{Extension, FieldInfo};
+/// #
+/// #[derive(Debug, Default)]
+/// struct FieldCounter(AtomicUsize);
+///
+/// impl Extension for FieldCounter {
+///     fn field_start(&self, _: &FieldInfo<'_>) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+/// ```
+///
+/// [`ExecutionOptions::extension()`]: crate::ExecutionOptions::extension
+/// [`RootNode::with_extension()`]: crate::RootNode::with_extension
+pub trait Extension: fmt::Debug + Send + Sync {
+    /// Called once a request is received, before parsing its document.
+    fn request_start(&self, request: &RequestInfo<'_>) {
+        let _ = request;
+    }
+
+    /// Called once the document of a request is parsed, with the parsing
+    /// `error`, if any.
+    fn parse(&self, request: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        let _ = (request, error);
+    }
+
+    /// Called once the operation of a request is validated, with the
+    /// validation `error`, if any.
+    ///
+    /// Not called if the parsing has failed.
+    fn validate(&self, request: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        let _ = (request, error);
+    }
+
+    /// Called before resolving a field.
+    fn field_start(&self, field: &FieldInfo<'_>) {
+        let _ = field;
+    }
+
+    /// Called once a field is resolved, with the message of its resolving
+    /// `error`, if any.
+    fn field_end(&self, field: &FieldInfo<'_>, error: Option<&str>) {
+        let _ = (field, error);
+    }
+
+    /// Called once a request is executed, with the `error` preventing its
+    /// execution, if any.
+    ///
+    /// Errors of the executed fields are reported via
+    /// [`Extension::field_end()`] instead.
+    fn request_end(&self, request: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        let _ = (request, error);
+    }
+}
+
+impl<T: Extension + ?Sized> Extension for Arc<T> {
+    fn request_start(&self, request: &RequestInfo<'_>) {
+        (**self).request_start(request)
+    }
+
+    fn parse(&self, request: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        (**self).parse(request, error)
+    }
+
+    fn validate(&self, request: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        (**self).validate(request, error)
+    }
+
+    fn field_start(&self, field: &FieldInfo<'_>) {
+        (**self).field_start(field)
+    }
+
+    fn field_end(&self, field: &FieldInfo<'_>, error: Option<&str>) {
+        (**self).field_end(field, error)
+    }
+
+    fn request_end(&self, request: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        (**self).request_end(request, error)
+    }
+}
+
+/// Request being executed, as seen by an [`Extension`].
+#[derive(Clone, Copy, Debug)]
+pub struct RequestInfo<'a> {
+    /// Source of the document of this request.
+    pub document_source: &'a str,
+
+    /// Name of the operation requested to be executed, if any.
+    pub operation_name: Option<&'a str>,
+
+    /// ID of this request, if known.
+    ///
+    /// See [`Correlation::request_id()`] for details.
+    ///
+    /// [`Correlation::request_id()`]: crate::Correlation::request_id
+    pub request_id: Option<&'a str>,
+}
+
+/// Field being resolved, as seen by an [`Extension`].
+#[derive(Clone, Copy, Debug)]
+pub struct FieldInfo<'a> {
+    /// Name of the type the field belongs to.
+    pub parent_type: &'a str,
+
+    /// Name of the field.
+    pub field_name: &'a str,
+
+    /// Declared type of the field, if it's known to the schema.
+    ///
+    /// Absent for the introspection fields (like `__typename`).
+    pub return_type: Option<&'a Type<'a>>,
+
+    /// Response path of the field, ending with its response name.
+    pub path: &'a [String],
+}
+
+/// Notifier of [`Extension`]s about the request-level stages of an execution.
+pub(crate) struct RequestHooks<'a> {
+    extensions: &'a [Arc<dyn Extension>],
+    request: RequestInfo<'a>,
+}
+
+impl<'a> RequestHooks<'a> {
+    /// Notifies the provided `extensions` about the start of the `request`.
+    pub(crate) fn start(extensions: &'a [Arc<dyn Extension>], request: RequestInfo<'a>) -> Self {
+        for ext in extensions {
+            ext.request_start(&request);
+        }
+        Self {
+            extensions,
+            request,
+        }
+    }
+
+    /// Notifies [`Extension`]s about the parsing `res`ult, and passes it
+    /// through.
+    pub(crate) fn parsed<T>(&self, res: Result<T, GraphQLError>) -> Result<T, GraphQLError> {
+        for ext in self.extensions.iter().rev() {
+            ext.parse(&self.request, res.as_ref().err());
+        }
+        res
+    }
+
+    /// Notifies [`Extension`]s about the validation `res`ult, and passes it
+    /// through.
+    pub(crate) fn validated<T>(&self, res: Result<T, GraphQLError>) -> Result<T, GraphQLError> {
+        for ext in self.extensions.iter().rev() {
+            ext.validate(&self.request, res.as_ref().err());
+        }
+        res
+    }
+
+    /// Notifies [`Extension`]s about the end of the request with the provided
+    /// execution `res`ult, and passes it through.
+    pub(crate) fn end<T>(self, res: Result<T, GraphQLError>) -> Result<T, GraphQLError> {
+        for ext in self.extensions.iter().rev() {
+            ext.request_end(&self.request, res.as_ref().err());
+        }
+        res
+    }
+}
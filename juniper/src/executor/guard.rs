@@ -0,0 +1,103 @@
+//! Declarative guards of fields.
+
+use futures::future::{self, BoxFuture};
+
+use crate::{
+    executor::FieldError,
+    types::base::Arguments,
+    value::{DefaultScalarValue, ScalarValue},
+};
+
+/// Value of the [`CODE_EXTENSION`] of the [`FieldError`] reported for fields
+/// denied by their [`Guard`]s.
+///
+/// [`CODE_EXTENSION`]: crate::executor::CODE_EXTENSION
+pub const FORBIDDEN_CODE: &str = "FORBIDDEN";
+
+/// Guard of a field, checked before resolving it, and denying the access to
+/// it if the check fails.
+///
+/// Guards are specified on fields via `#[graphql(guard = ...)]` attribute
+/// argument, accepting any expression evaluating to a [`Guard`]. A denied
+/// field is not resolved at all, and is completed with a [`FieldError`]
+/// having the [`FORBIDDEN_CODE`] instead.
+///
+/// Any `Fn(&Context) -> bool` function is a [`Guard`] checking the context
+/// only. Implement this trait directly to check the arguments of a field, or
+/// to check asynchronously.
+///
+/// ```rust
+/// # use juniper::{
+/// #     futures::future::BoxFuture, graphql_object, Arguments, Guard, ScalarValue,
+/// # };
+/// #
+/// struct Session {
+///     user_id: Option<i32>,
+/// }
+///
+/// impl juniper::Context for Session {}
+///
+/// fn is_signed_in(session: &Session) -> bool {
+///     session.user_id.is_some()
+/// }
+///
+/// /// Allows accessing the data of the signed in user only.
+/// struct IsOwner;
+///
+/// impl<S: ScalarValue> Guard<Session, S> for IsOwner {
+///     fn check<'a>(
+///         &'a self,
+///         session: &'a Session,
+///         arguments: &'a Arguments<'a, S>,
+///     ) -> BoxFuture<'a, bool> {
+///         let user_id = arguments.get::<i32>("userId").ok().flatten();
+///         Box::pin(async move { user_id.is_some() && user_id == session.user_id })
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Session)]
+/// impl Query {
+///     #[graphql(guard = is_signed_in)]
+///     fn me(context: &Session) -> Option<i32> {
+///         context.user_id
+///     }
+///
+///     #[graphql(guard = IsOwner)]
+///     fn balance(user_id: i32) -> f64 {
+///         100.0
+///     }
+/// }
+/// ```
+///
+/// Checks are polled only once in a synchronous execution, so a field is
+/// denied if its [`Guard`] doesn't complete immediately.
+pub trait Guard<CtxT: ?Sized, S = DefaultScalarValue>: Send + Sync {
+    /// Checks whether the field may be resolved with the provided `context`
+    /// and `arguments`.
+    fn check<'a>(
+        &'a self,
+        context: &'a CtxT,
+        arguments: &'a Arguments<'a, S>,
+    ) -> BoxFuture<'a, bool>;
+}
+
+impl<CtxT, S, F> Guard<CtxT, S> for F
+where
+    CtxT: ?Sized,
+    F: Fn(&CtxT) -> bool + Send + Sync,
+{
+    fn check<'a>(&'a self, context: &'a CtxT, _: &'a Arguments<'a, S>) -> BoxFuture<'a, bool> {
+        Box::pin(future::ready(self(context)))
+    }
+}
+
+/// Creates the [`FieldError`] reported for the `field_name`d field denied by
+/// its [`Guard`].
+pub(crate) fn forbidden<S: ScalarValue>(field_name: &str) -> FieldError<S> {
+    FieldError::builder()
+        .message(format!("Access to field `{field_name}` is forbidden"))
+        .code(FORBIDDEN_CODE)
+        .build()
+}
@@ -0,0 +1,81 @@
+//! Per-field metrics of an operation execution.
+
+use std::{fmt, sync::Arc, time::Duration};
+
+/// Instrumentation of an operation execution, receiving the [`FieldMetrics`]
+/// of every resolved field, so timings and outcomes may be fed into dashboards
+/// without a full-blown tracing integration.
+///
+/// Instrumentations are registered via [`ExecutionOptions::instrumentation()`]
+/// (or [`RootNode::with_instrumentation()`] for a whole schema), and are
+/// notified in the order of their registration. Durations are measured with
+/// the [`ExecutionOptions::get_clock()`].
+///
+/// ```
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use juniper::{FieldMetrics, FieldStatus, Instrumentation};
+/// #
+/// #[derive(Debug, Default)]
+/// struct ErrorCounter(AtomicUsize);
+///
+/// impl Instrumentation for ErrorCounter {
+///     fn field_resolved(&self, metrics: &FieldMetrics<'_>) {
+///         if metrics.status == FieldStatus::Error {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`ExecutionOptions::get_clock()`]: crate::ExecutionOptions::get_clock
+/// [`ExecutionOptions::instrumentation()`]: crate::ExecutionOptions::instrumentation
+/// [`RootNode::with_instrumentation()`]: crate::RootNode::with_instrumentation
+pub trait Instrumentation: fmt::Debug + Send + Sync {
+    /// Called once a field is resolved, whether successfully or not.
+    fn field_resolved(&self, metrics: &FieldMetrics<'_>);
+}
+
+impl<T: Instrumentation + ?Sized> Instrumentation for Arc<T> {
+    fn field_resolved(&self, metrics: &FieldMetrics<'_>) {
+        (**self).field_resolved(metrics)
+    }
+}
+
+/// Metrics of a resolved field, as seen by an [`Instrumentation`].
+#[derive(Clone, Copy, Debug)]
+pub struct FieldMetrics<'a> {
+    /// Name of the type the field belongs to.
+    pub parent_type: &'a str,
+
+    /// Name of the field.
+    pub field_name: &'a str,
+
+    /// Response path of the field, ending with its response name.
+    pub path: &'a [String],
+
+    /// Time it took to resolve the field, including its sub-selections.
+    pub duration: Duration,
+
+    /// Outcome of resolving the field.
+    pub status: FieldStatus,
+}
+
+/// Outcome of resolving a field.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FieldStatus {
+    /// Field has been resolved successfully.
+    Ok,
+
+    /// Field has been resolved into an error.
+    Error,
+}
+
+impl FieldStatus {
+    /// Returns the [`FieldStatus`] of the provided resolution `result`.
+    pub(crate) fn of<T, E>(result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self::Ok,
+            Err(_) => Self::Error,
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Live queries, re-executed whenever the data they depend on changes.
+
+use std::{
+    collections::BTreeSet,
+    fmt, mem,
+    sync::{Arc, Mutex},
+};
+
+use futures::{
+    channel::mpsc,
+    future::{self, FutureExt as _},
+    stream::{self, BoxStream, StreamExt as _},
+};
+
+use crate::{
+    ast::{OperationType, OwnedDocument},
+    schema::model::RootNode,
+    types::{async_await::GraphQLTypeAsync, base::GraphQLType, subscriptions::ExecutionOutput},
+    value::{DefaultScalarValue, ScalarValue, Value},
+    GraphQLError,
+};
+
+use super::{
+    execute_validated_query_async_with_options, get_operation, ExecutionOptions, Variables,
+};
+
+/// Stream of [`ExecutionOutput`]s produced by [`resolve_into_live_stream()`].
+///
+/// The first item is the result of the initial execution of a live query,
+/// followed by a new full result every time the query is re-executed because
+/// of its data being [invalidated](LiveQueryStore::watch).
+///
+/// [`resolve_into_live_stream()`]: crate::resolve_into_live_stream
+pub type LiveQueryStream<'a, S = DefaultScalarValue> = BoxStream<'a, ExecutionOutput<S>>;
+
+/// Source of invalidations of the data live queries depend on.
+///
+/// Data is identified by arbitrary string keys (like `User:42`), which
+/// resolvers declare their live query to depend on via
+/// [`Executor::live_dependency()`].
+///
+/// [`Executor::live_dependency()`]: crate::Executor::live_dependency
+pub trait LiveQueryStore: Send + Sync {
+    /// Returns a stream yielding every time the data identified by any of the
+    /// provided `keys` is invalidated.
+    ///
+    /// Once the returned stream ends, the live query watching it ends too.
+    fn watch(&self, keys: &[String]) -> BoxStream<'static, ()>;
+}
+
+/// [`LiveQueryStore`] keeping its watchers in memory, being invalidated
+/// explicitly via [`InMemoryLiveQueryStore::invalidate()`] (usually by the
+/// mutations changing the data).
+///
+/// ```rust
+/// # use futures::{FutureExt as _, StreamExt as _};
+/// # use juniper::{InMemoryLiveQueryStore, LiveQueryStore as _};
+/// #
+/// let store = InMemoryLiveQueryStore::new();
+/// let mut changes = store.watch(&["User:42".into()]);
+///
+/// store.invalidate("User:7");
+/// store.invalidate("User:42");
+///
+/// assert_eq!(changes.next().now_or_never(), Some(Some(())));
+/// ```
+#[derive(Default)]
+pub struct InMemoryLiveQueryStore {
+    watchers: Mutex<Vec<(Vec<String>, mpsc::UnboundedSender<()>)>>,
+}
+
+impl fmt::Debug for InMemoryLiveQueryStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemoryLiveQueryStore")
+            .field("watchers", &self.watchers.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl InMemoryLiveQueryStore {
+    /// Creates a new [`InMemoryLiveQueryStore`] without any watchers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Invalidates the data identified by the provided `key`, notifying all
+    /// the live queries depending on it.
+    pub fn invalidate(&self, key: &str) {
+        self.watchers.lock().unwrap().retain(|(keys, tx)| {
+            if keys.iter().any(|k| k == key) {
+                tx.unbounded_send(()).is_ok()
+            } else {
+                !tx.is_closed()
+            }
+        });
+    }
+}
+
+impl LiveQueryStore for InMemoryLiveQueryStore {
+    fn watch(&self, keys: &[String]) -> BoxStream<'static, ()> {
+        let (tx, rx) = mpsc::unbounded();
+        self.watchers.lock().unwrap().push((keys.to_vec(), tx));
+        rx.boxed()
+    }
+}
+
+/// Keys of the data a single execution of a live query depends on, declared
+/// by its resolvers.
+#[derive(Debug, Default)]
+pub(crate) struct LiveDependencies(Mutex<BTreeSet<String>>);
+
+impl LiveDependencies {
+    /// Declares the data identified by the provided `key` as a dependency.
+    pub(crate) fn insert(&self, key: String) {
+        self.0.lock().unwrap().insert(key);
+    }
+
+    /// Takes all the keys declared so far.
+    fn take(&self) -> Vec<String> {
+        mem::take(&mut *self.0.lock().unwrap())
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Executes the provided validated `document` as a live query, re-executing it
+/// every time the data it depends on is invalidated in the provided `store`.
+///
+/// Bursts of invalidations are coalesced into a single re-execution, and the
+/// ones happening while the query is being re-executed trigger another one.
+/// Re-executions resulting in the same data (and no errors) as the last
+/// emitted one are not emitted.
+pub(crate) async fn execute_validated_query_live<'a, QueryT, MutationT, SubscriptionT, S>(
+    document: OwnedDocument<'a, S>,
+    operation_name: Option<&'a str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: Variables<S>,
+    context: &'a QueryT::Context,
+    options: ExecutionOptions,
+    store: &'a dyn LiveQueryStore,
+) -> Result<LiveQueryStream<'a, S>, GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync + 'a,
+{
+    match get_operation(&document, operation_name)?
+        .item
+        .operation_type
+    {
+        OperationType::Query => {}
+        OperationType::Mutation => return Err(GraphQLError::NotQuery),
+        OperationType::Subscription => return Err(GraphQLError::IsSubscription),
+    }
+
+    let dependencies = Arc::new(LiveDependencies::default());
+    let options = options.live(dependencies.clone());
+
+    let (initial, keys) = execute_live_query(
+        &document,
+        operation_name,
+        root_node,
+        &variables,
+        context,
+        &options,
+        &dependencies,
+    )
+    .await?;
+
+    let state = LiveState {
+        document,
+        variables,
+        options,
+        dependencies,
+        last: initial.errors.is_empty().then(|| initial.data.clone()),
+        changes: store.watch(&keys),
+    };
+    let subsequent = stream::unfold(state, move |mut state| async move {
+        loop {
+            state.changes.next().await?;
+            drain_changes(&mut state.changes);
+
+            let (output, keys) = execute_live_query(
+                &state.document,
+                operation_name,
+                root_node,
+                &state.variables,
+                context,
+                &state.options,
+                &state.dependencies,
+            )
+            .await
+            .ok()?;
+
+            let changes = store.watch(&keys);
+            state.changes = if drain_changes(&mut state.changes) {
+                stream::once(future::ready(())).chain(changes).boxed()
+            } else {
+                changes
+            };
+
+            if output.errors.is_empty() && state.last.as_ref() == Some(&output.data) {
+                continue;
+            }
+            state.last = output.errors.is_empty().then(|| output.data.clone());
+            return Some((output, state));
+        }
+    });
+
+    Ok(stream::once(future::ready(initial))
+        .chain(subsequent)
+        .boxed())
+}
+
+/// Executes the live query once, returning its result along with the keys of
+/// the data it depends on.
+async fn execute_live_query<'a, QueryT, MutationT, SubscriptionT, S>(
+    document: &OwnedDocument<'a, S>,
+    operation_name: Option<&str>,
+    root_node: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    options: &ExecutionOptions,
+    dependencies: &LiveDependencies,
+) -> Result<(ExecutionOutput<S>, Vec<String>), GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let operation = get_operation(document, operation_name)?;
+    let (data, errors) = root_node.mapped_errors(
+        execute_validated_query_async_with_options(
+            document, operation, root_node, variables, context, options,
+        )
+        .await,
+    )?;
+    Ok((ExecutionOutput { data, errors }, dependencies.take()))
+}
+
+/// Consumes all the invalidations already pending in the provided `changes`,
+/// returning whether there were any.
+fn drain_changes(changes: &mut BoxStream<'static, ()>) -> bool {
+    let mut any = false;
+    while let Some(Some(())) = changes.next().now_or_never() {
+        any = true;
+    }
+    any
+}
+
+/// State of a [`LiveQueryStream`] between its re-executions.
+struct LiveState<'a, S> {
+    document: OwnedDocument<'a, S>,
+    variables: Variables<S>,
+    options: ExecutionOptions,
+    dependencies: Arc<LiveDependencies>,
+
+    /// Data of the last emitted result, if it has no errors.
+    last: Option<Value<S>>,
+
+    /// Invalidations of the data the last execution depends on.
+    changes: BoxStream<'static, ()>,
+}
@@ -1,12 +1,14 @@
 use std::collections::HashMap;
 
+use indexmap::IndexMap;
+
 use crate::{
-    ast::{Directive, Fragment, InputValue, Selection},
+    ast::{Directive, Fragment, FromInputValue, InputValue, Selection, ToInputValue},
     parser::Spanning,
     value::ScalarValue,
 };
 
-use super::Variables;
+use super::{FieldResult, IntoFieldError, Variables};
 
 /// An enum that describes if a field is available in all types of the interface
 /// or only in a certain subtype
@@ -64,6 +66,22 @@ where
     }
 }
 
+impl<'a, S: ScalarValue> ToInputValue<S> for LookAheadValue<'a, S> {
+    fn to_input_value(&self) -> InputValue<S> {
+        match self {
+            Self::Null => InputValue::Null,
+            Self::Scalar(s) => InputValue::Scalar((*s).clone()),
+            Self::Enum(e) => InputValue::enum_value(e),
+            Self::List(l) => InputValue::list(l.iter().map(Self::to_input_value).collect()),
+            Self::Object(o) => InputValue::object(
+                o.iter()
+                    .map(|(n, v)| (*n, v.to_input_value()))
+                    .collect::<IndexMap<_, _>>(),
+            ),
+        }
+    }
+}
+
 /// An argument passed into the query
 #[derive(Debug, Clone, PartialEq)]
 pub struct LookAheadArgument<'a, S: 'a> {
@@ -94,6 +112,20 @@ where
     pub fn value(&'a self) -> &LookAheadValue<'a, S> {
         &self.value
     }
+
+    /// Converts the value of the argument into the provided Rust type, the
+    /// same way it's done when resolving the field.
+    pub fn convert<T: FromInputValue<S>>(&self) -> Result<T, T::Error> {
+        self.value.to_input_value().convert()
+    }
+
+    /// Takes an [`OwnedLookAheadArgument`] snapshot of this argument.
+    pub fn to_owned(&self) -> OwnedLookAheadArgument<S> {
+        OwnedLookAheadArgument {
+            name: self.name.into(),
+            value: self.value.to_input_value(),
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -175,32 +207,39 @@ where
                             .unwrap_or(false),
                         ("skip", &None) => false,
                         ("include", &None) => true,
-                        (_, _) => unreachable!(),
+                        (_, _) => true,
                     }
                 })
             })
             .unwrap_or(true)
     }
 
+    #[cfg(test)]
     pub(super) fn build_from_selection(
         s: &'a Selection<'a, S>,
         vars: &'a Variables<S>,
         fragments: &'a HashMap<&'a str, Fragment<'a, S>>,
     ) -> Option<LookAheadSelection<'a, S>> {
-        Self::build_from_selection_with_parent(s, None, vars, fragments)
+        Self::build_from_selection_with_parent(s, None, vars, fragments, true)
     }
 
+    /// Builds a [`LookAheadSelection`] out of the provided selection, either
+    /// evaluating its `@skip`/`@include` directives against the `vars`, or
+    /// keeping all the selected fields regardless of them.
     pub(super) fn build_from_selection_with_parent(
         s: &'a Selection<'a, S>,
         parent: Option<&mut Self>,
         vars: &'a Variables<S>,
         fragments: &'a HashMap<&'a str, Fragment<'a, S>>,
+        evaluate_directives: bool,
     ) -> Option<LookAheadSelection<'a, S>> {
+        let should_include =
+            |directives| !evaluate_directives || Self::should_include(directives, vars);
         let empty: &[Selection<S>] = &[];
         match *s {
             Selection::Field(ref field) => {
                 let field = &field.item;
-                let include = Self::should_include(field.directives.as_ref(), vars);
+                let include = should_include(field.directives.as_ref());
                 if !include {
                     return None;
                 }
@@ -235,6 +274,7 @@ where
                         Some(&mut ret),
                         vars,
                         fragments,
+                        evaluate_directives,
                     );
                     assert!(s.is_none());
                 }
@@ -249,7 +289,7 @@ where
                 }
             }
             Selection::FragmentSpread(ref fragment) => {
-                let include = Self::should_include(fragment.item.directives.as_ref(), vars);
+                let include = should_include(fragment.item.directives.as_ref());
                 if !include {
                     return None;
                 }
@@ -261,13 +301,18 @@ where
                             Some(parent),
                             vars,
                             fragments,
+                            evaluate_directives,
                         );
                         assert!(s.is_none());
                     }
                 } else {
                     for c in f.selection_set.iter() {
                         let s = LookAheadSelection::build_from_selection_with_parent(
-                            c, None, vars, fragments,
+                            c,
+                            None,
+                            vars,
+                            fragments,
+                            evaluate_directives,
                         );
                         assert!(s.is_some());
                     }
@@ -275,7 +320,7 @@ where
                 None
             }
             Selection::InlineFragment(ref inline) if parent.is_some() => {
-                let include = Self::should_include(inline.item.directives.as_ref(), vars);
+                let include = should_include(inline.item.directives.as_ref());
                 if !include {
                     return None;
                 }
@@ -286,6 +331,7 @@ where
                         Some(parent),
                         vars,
                         fragments,
+                        evaluate_directives,
                     );
                     assert!(s.is_none());
                     if let Some(c) = inline.item.type_condition.as_ref().map(|t| t.item) {
@@ -319,6 +365,34 @@ where
             arguments: self.arguments.clone(),
         }
     }
+
+    /// Takes an [`OwnedLookAheadSelection`] snapshot of this selection, free
+    /// of any borrows of the executed operation.
+    ///
+    /// Unlike a [`LookAheadSelection`], the snapshot may be sent to spawned
+    /// tasks or stored in batch functions of data loaders.
+    pub fn to_owned(&self) -> OwnedLookAheadSelection<S> {
+        OwnedLookAheadSelection {
+            name: self.name.into(),
+            alias: self.alias.map(Into::into),
+            arguments: self
+                .arguments
+                .iter()
+                .map(LookAheadArgument::to_owned)
+                .collect(),
+            children: self
+                .children
+                .iter()
+                .map(|c| OwnedChildSelection {
+                    inner: c.inner.to_owned(),
+                    applies_for: match c.applies_for {
+                        Applies::All => None,
+                        Applies::OnlyType(t) => Some(t.into()),
+                    },
+                })
+                .collect(),
+        }
+    }
 }
 
 /// A selection performed by a query on a concrete type
@@ -362,6 +436,26 @@ pub trait LookAheadMethods<'sel, S> {
         self.arguments().iter().find(|a| a.name == name)
     }
 
+    /// Get the value of the top level argument with a given name from the
+    /// current selection, converted into the provided Rust type
+    ///
+    /// Returns `Ok(None)` if the argument is not specified in the query, as
+    /// default values of arguments are not looked ahead.
+    ///
+    /// If the conversion fails, the returned error has the `name` of the
+    /// argument prepended to its [input path](crate::FieldError::input_path).
+    fn argument_value<T>(&self, name: &str) -> FieldResult<Option<T>, S>
+    where
+        S: ScalarValue,
+        T: FromInputValue<S>,
+        T::Error: IntoFieldError<S>,
+    {
+        self.argument(name)
+            .map(LookAheadArgument::convert::<T>)
+            .transpose()
+            .map_err(|e| e.into_field_error().prepend_input_path(name))
+    }
+
     /// Get the (possibly aliased) names of the top level children for the current selection
     fn child_names(&self) -> Vec<&'sel str>;
 
@@ -435,6 +529,160 @@ impl<'a, S> LookAheadMethods<'a, S> for LookAheadSelection<'a, S> {
     }
 }
 
+/// An [owned](LookAheadSelection::to_owned) argument passed into the query.
+///
+/// Its value is kept as an [`InputValue`] with all the variables resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedLookAheadArgument<S> {
+    name: String,
+    value: InputValue<S>,
+}
+
+impl<S> OwnedLookAheadArgument<S>
+where
+    S: ScalarValue,
+{
+    /// The argument's name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value of the argument
+    pub fn value(&self) -> &InputValue<S> {
+        &self.value
+    }
+
+    /// Converts the value of the argument into the provided Rust type, the
+    /// same way it's done when resolving the field.
+    pub fn convert<T: FromInputValue<S>>(&self) -> Result<T, T::Error> {
+        self.value.convert()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct OwnedChildSelection<S> {
+    inner: OwnedLookAheadSelection<S>,
+    /// Name of the type the child selection only applies to, if any.
+    applies_for: Option<String>,
+}
+
+/// An [owned](LookAheadSelection::to_owned) snapshot of a selection performed
+/// by a query.
+///
+/// Provides the same methods as [`LookAheadMethods`], not bound to the
+/// lifetime of the executed operation, so it's [`Send`] and `'static`
+/// whenever its [`ScalarValue`] is.
+///
+/// ```rust
+/// # use juniper::{graphql_object, Executor, OwnedLookAheadSelection, ScalarValue};
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn fields<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> Vec<String> {
+///         let selection: OwnedLookAheadSelection<S> = executor.look_ahead().to_owned();
+///         // The `selection` may be moved into a spawned task here.
+///         selection.child_names().into_iter().map(Into::into).collect()
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedLookAheadSelection<S> {
+    name: String,
+    alias: Option<String>,
+    arguments: Vec<OwnedLookAheadArgument<S>>,
+    children: Vec<OwnedChildSelection<S>>,
+}
+
+impl<S> OwnedLookAheadSelection<S>
+where
+    S: ScalarValue,
+{
+    /// Get the (potentially aliased) name of the field represented by the current selection
+    pub fn field_name(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Get the the child selection for a given field
+    /// If a child has an alias, it will only match if the alias matches `name`
+    pub fn select_child(&self, name: &str) -> Option<&Self> {
+        self.children
+            .iter()
+            .find(|c| c.inner.field_name() == name)
+            .map(|c| &c.inner)
+    }
+
+    /// Check if a given child selection with a name exists
+    /// If a child has an alias, it will only match if the alias matches `name`
+    pub fn has_child(&self, name: &str) -> bool {
+        self.select_child(name).is_some()
+    }
+
+    /// Does the current node have any arguments?
+    pub fn has_arguments(&self) -> bool {
+        !self.arguments.is_empty()
+    }
+
+    /// Does the current node have any children?
+    pub fn has_children(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    /// Get the top level arguments for the current selection
+    pub fn arguments(&self) -> &[OwnedLookAheadArgument<S>] {
+        &self.arguments
+    }
+
+    /// Get the top level argument with a given name from the current selection
+    pub fn argument(&self, name: &str) -> Option<&OwnedLookAheadArgument<S>> {
+        self.arguments.iter().find(|a| a.name == name)
+    }
+
+    /// Get the value of the top level argument with a given name from the
+    /// current selection, converted into the provided Rust type
+    ///
+    /// See [`LookAheadMethods::argument_value()`] for details.
+    pub fn argument_value<T>(&self, name: &str) -> FieldResult<Option<T>, S>
+    where
+        T: FromInputValue<S>,
+        T::Error: IntoFieldError<S>,
+    {
+        self.argument(name)
+            .map(OwnedLookAheadArgument::convert::<T>)
+            .transpose()
+            .map_err(|e| e.into_field_error().prepend_input_path(name))
+    }
+
+    /// Get the (possibly aliased) names of the top level children for the current selection
+    pub fn child_names(&self) -> Vec<&str> {
+        self.children.iter().map(|c| c.inner.field_name()).collect()
+    }
+
+    /// Get an iterator over the children for the current selection
+    pub fn children(&self) -> Vec<&Self> {
+        self.children.iter().map(|c| &c.inner).collect()
+    }
+
+    /// Convert a eventually type independent selection into one for a concrete type
+    pub fn for_explicit_type(&self, type_name: &str) -> Self {
+        Self {
+            name: self.name.clone(),
+            alias: self.alias.clone(),
+            arguments: self.arguments.clone(),
+            children: self
+                .children
+                .iter()
+                .filter(|c| c.applies_for.as_deref().map_or(true, |t| t == type_name))
+                .map(|c| OwnedChildSelection {
+                    inner: c.inner.for_explicit_type(type_name),
+                    applies_for: None,
+                })
+                .collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -446,6 +694,7 @@ mod tests {
         schema::model::SchemaType,
         validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
         value::{DefaultScalarValue, ScalarValue},
+        GraphQLEnum,
     };
 
     use super::*;
@@ -728,6 +977,50 @@ query Hero {
         }
     }
 
+    #[test]
+    fn check_query_with_typed_argument() {
+        #[derive(Debug, GraphQLEnum, PartialEq)]
+        enum Episode {
+            NewHope,
+            Empire,
+            Jedi,
+        }
+
+        let docs = parse_document_source::<DefaultScalarValue>(
+            "
+query Hero {
+    hero(episode: EMPIRE) {
+        name(uppercase: true)
+    }
+}
+",
+        )
+        .unwrap();
+        let fragments = extract_fragments(&docs);
+
+        if let crate::ast::Definition::Operation(ref op) = docs[0] {
+            let vars = graphql_vars! {};
+            let look_ahead = LookAheadSelection::build_from_selection(
+                &op.item.selection_set[0],
+                &vars,
+                &fragments,
+            )
+            .unwrap();
+
+            assert_eq!(
+                look_ahead.argument_value::<Episode>("episode"),
+                Ok(Some(Episode::Empire)),
+            );
+            assert_eq!(look_ahead.argument_value::<Episode>("unknown"), Ok(None));
+            assert!(look_ahead.argument_value::<i32>("episode").is_err());
+
+            let name = look_ahead.select_child("name").unwrap();
+            assert_eq!(name.argument_value::<bool>("uppercase"), Ok(Some(true)));
+        } else {
+            panic!("No Operation found");
+        }
+    }
+
     #[test]
     fn check_query_with_variable() {
         let docs = parse_document_source::<DefaultScalarValue>(
@@ -952,6 +1245,49 @@ query Hero {
         }
     }
 
+    #[test]
+    fn check_query_with_directives_on_variables() {
+        let docs = parse_document_source::<DefaultScalarValue>(
+            "
+query Hero($withName: Boolean!) {
+    hero {
+        id
+        name @include(if: $withName)
+        appearsIn @skip(if: $withName)
+    }
+}",
+        )
+        .unwrap();
+        let fragments = extract_fragments(&docs);
+
+        if let crate::ast::Definition::Operation(ref op) = docs[0] {
+            let vars = graphql_vars! {"withName": true};
+
+            let look_ahead = LookAheadSelection::build_from_selection(
+                &op.item.selection_set[0],
+                &vars,
+                &fragments,
+            )
+            .unwrap();
+            assert_eq!(look_ahead.child_names(), vec!["id", "name"]);
+
+            let raw_look_ahead = LookAheadSelection::build_from_selection_with_parent(
+                &op.item.selection_set[0],
+                None,
+                &vars,
+                &fragments,
+                false,
+            )
+            .unwrap();
+            assert_eq!(
+                raw_look_ahead.child_names(),
+                vec!["id", "name", "appearsIn"]
+            );
+        } else {
+            panic!("No Operation found");
+        }
+    }
+
     #[test]
     fn check_query_with_inline_fragments() {
         let docs = parse_document_source::<DefaultScalarValue>(
@@ -1541,4 +1877,59 @@ query Hero {
             panic!("No Operation found");
         }
     }
+
+    #[test]
+    fn check_owned_snapshot() {
+        let docs = parse_document_source::<DefaultScalarValue>(
+            "
+query Hero($episode: Episode) {
+    hero(episode: $episode) {
+        name
+        ... on Droid {
+            primaryFunction
+        }
+        ... on Human {
+            height
+        }
+    }
+}",
+        )
+        .unwrap();
+        let fragments = extract_fragments(&docs);
+
+        if let crate::ast::Definition::Operation(ref op) = docs[0] {
+            let vars = graphql_vars! {"episode": JEDI};
+            let look_ahead = LookAheadSelection::build_from_selection(
+                &op.item.selection_set[0],
+                &vars,
+                &fragments,
+            )
+            .unwrap();
+
+            let owned = look_ahead.to_owned();
+            let owned = std::thread::spawn(move || owned).join().unwrap();
+
+            assert_eq!(owned.field_name(), "hero");
+            assert!(owned.has_arguments());
+            assert_eq!(
+                owned.argument("episode").unwrap().value(),
+                &InputValue::enum_value("JEDI"),
+            );
+            assert_eq!(
+                owned.child_names(),
+                vec!["name", "primaryFunction", "height"],
+            );
+            assert!(owned.select_child("height").is_some());
+            assert!(!owned.select_child("name").unwrap().has_children());
+
+            let human = owned.for_explicit_type("Human");
+            assert_eq!(human.child_names(), vec!["name", "height"]);
+            assert_eq!(
+                human.child_names(),
+                look_ahead.for_explicit_type("Human").child_names(),
+            );
+        } else {
+            panic!("No Operation found");
+        }
+    }
 }
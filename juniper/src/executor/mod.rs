@@ -1,50 +1,101 @@
 //! Resolve the document to values
 
 use std::{
+    any::{self, Any},
     borrow::Cow,
     cmp::Ordering,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{Debug, Display},
-    sync::{Arc, RwLock},
+    future::Future,
+    mem,
+    sync::{Arc, Mutex, RwLock},
+    time::SystemTime,
 };
 
 use fnv::FnvHashMap;
-use futures::Stream;
+use futures::{
+    future::{self, Either},
+    Stream,
+};
 
 use crate::{
     ast::{
-        Definition, Document, Fragment, FromInputValue, InputValue, Operation, OperationType,
-        Selection, ToInputValue, Type,
+        Definition, Directive, Document, Fragment, FromInputValue, InputValue, Operation,
+        OperationType, Selection, ToInputValue, Type,
     },
     parser::{SourcePosition, Spanning},
     schema::{
         meta::{
             Argument, DeprecationStatus, EnumMeta, EnumValue, Field, InputObjectMeta,
-            InterfaceMeta, ListMeta, MetaType, NullableMeta, ObjectMeta, PlaceholderMeta,
+            InterfaceMeta, ListMeta, MetaType, Metadata, NullableMeta, ObjectMeta, PlaceholderMeta,
             ScalarMeta, UnionMeta,
         },
-        model::{RootNode, SchemaType, TypeType},
+        model::{DirectiveType, RootNode, SchemaError, SchemaType, TypeType},
     },
     types::{
         async_await::{GraphQLTypeAsync, GraphQLValueAsync},
-        base::{GraphQLType, GraphQLValue},
+        base::{Arguments, GraphQLType, GraphQLValue},
         name::Name,
         subscriptions::{GraphQLSubscriptionType, GraphQLSubscriptionValue},
     },
-    value::{DefaultScalarValue, ParseScalarValue, ScalarValue, Value},
+    value::{DefaultScalarValue, Object, ParseScalarValue, ScalarValue, Value},
     GraphQLError,
 };
 
+pub use crate::value::PathSegment;
+
+#[cfg(feature = "async-std")]
+pub use self::timer::AsyncStdTimer;
+#[cfg(feature = "tokio")]
+pub use self::timer::TokioTimer;
 pub use self::{
+    apollo_tracing::{ApolloTracing, APOLLO_TRACING_EXTENSION},
+    cancellation::{CancelOnDrop, CancellationToken, Cancelled},
+    clock::{Clock, MockClock, SystemClock},
+    correlation::{
+        Correlation, TraceContext, REQUEST_ID_EXTENSION, REQUEST_ID_HEADER, TRACEPARENT_HEADER,
+    },
+    data::Data,
+    defer::{IncrementalExecutionOutput, IncrementalPayload, IncrementalResult, DEFER_DIRECTIVE},
+    directive::{AppliedDirective, DirectiveHandler},
+    error_extensions::{ErrorExtensions, FieldErrorBuilder, CODE_EXTENSION, DETAIL_EXTENSION},
+    extension::{Extension, FieldInfo, RequestInfo},
+    guard::{Guard, FORBIDDEN_CODE},
+    instrumentation::{FieldMetrics, FieldStatus, Instrumentation},
+    live::{InMemoryLiveQueryStore, LiveQueryStore, LiveQueryStream},
     look_ahead::{
         Applies, ChildSelection, ConcreteLookAheadSelection, LookAheadArgument, LookAheadMethods,
-        LookAheadSelection, LookAheadValue,
+        LookAheadSelection, LookAheadValue, OwnedLookAheadArgument, OwnedLookAheadSelection,
     },
+    options::{ExecutionOptions, NullPropagation, DRY_RUN_DIRECTIVE},
     owned_executor::OwnedExecutor,
+    response_extensions::ResponseExtensions,
+    rng::{Rng, SplitMixRng},
+    timer::{runtime_timer, Timer},
+};
+pub(crate) use self::{
+    defer::execute_validated_query_incremental, directive::DirectiveHandlers,
+    extension::RequestHooks, guard::forbidden, live::execute_validated_query_live,
 };
 
+mod apollo_tracing;
+mod cancellation;
+mod clock;
+mod correlation;
+mod data;
+mod defer;
+mod directive;
+mod error_extensions;
+mod extension;
+mod guard;
+mod instrumentation;
+mod live;
 mod look_ahead;
+mod options;
 mod owned_executor;
+mod response_extensions;
+mod rng;
+mod timer;
 
 /// A type registry used to build schemas
 ///
@@ -54,8 +105,32 @@ mod owned_executor;
 pub struct Registry<'r, S = DefaultScalarValue> {
     /// Currently registered types
     pub types: FnvHashMap<Name, MetaType<'r, S>>,
+
+    /// Currently registered custom directives
+    pub directives: FnvHashMap<String, DirectiveType<'r, S>>,
+
+    /// Types registered via [`Registry::get_or_register_lazy()`], which
+    /// metadata is still to be constructed.
+    lazy_types: Vec<(Name, LazyMetaFn<'r, S>)>,
+
+    /// Rust types registered under each type name, tracked only when
+    /// detecting conflicting types via [`Registry::detect_conflicts()`].
+    type_origins: Option<FnvHashMap<Name, Vec<&'static str>>>,
+
+    /// First detected conflict between the types registered under the same
+    /// name.
+    conflict: Option<SchemaError>,
+
+    /// Indicator whether the types are currently registered as input types
+    /// (of arguments or input object fields), so their
+    /// [`GraphQLType::input_name()`] and [`GraphQLType::input_meta()`] should
+    /// be used.
+    is_input: bool,
 }
 
+/// Deferred construction of the [`MetaType`] of a lazily registered type.
+type LazyMetaFn<'r, S> = Box<dyn FnOnce(&mut Registry<'r, S>) -> MetaType<'r, S> + 'r>;
+
 #[allow(missing_docs)]
 #[derive(Clone)]
 pub enum FieldPath<'a> {
@@ -81,8 +156,15 @@ where
     context: &'a CtxT,
     errors: &'r RwLock<Vec<ExecutionError<S>>>,
     field_path: Arc<FieldPath<'a>>,
+    options: &'r ExecutionOptions,
+    spread_fragments: Option<SpreadFragments>,
 }
 
+/// Names of the fragments already spread into the value being resolved, shared
+/// by all the [`Executor`]s resolving the same value, so every fragment hits
+/// the resolvers of that value only once.
+pub(crate) type SpreadFragments = Arc<Mutex<HashSet<String>>>;
+
 /// Error type for errors that occur during query execution
 ///
 /// All execution errors contain the source position in the query of the field
@@ -203,6 +285,13 @@ impl<S> FieldError<S> {
         }
     }
 
+    /// Starts building a new [`FieldError`] with typed `extensions`.
+    ///
+    /// See [`FieldErrorBuilder`] for details.
+    pub fn builder() -> FieldErrorBuilder<S> {
+        FieldErrorBuilder::default()
+    }
+
     /// Returns `"message"` field of this [`FieldError`].
     #[must_use]
     pub fn message(&self) -> &str {
@@ -239,8 +328,109 @@ impl<S> FieldError<S> {
             extensions: self.extensions,
         }
     }
+
+    /// Returns the path to the input value this [`FieldError`] has occurred
+    /// at, if any.
+    ///
+    /// The path is stored in the `"inputPath"` field of the
+    /// [`FieldError::extensions`], and is populated when the input value fails
+    /// a validation (like the one specified via `#[graphql(validate = ...)]`
+    /// attribute on a [`GraphQLInputObject`] derive), or cannot be coerced as
+    /// an item of a list or a field of an input object. The path consists of
+    /// the names of input fields and arguments, and the indices of list items.
+    ///
+    /// [`GraphQLInputObject`]: crate::GraphQLInputObject
+    #[must_use]
+    pub fn input_path(&self) -> Option<&[Value<S>]> {
+        self.extensions
+            .as_object_value()?
+            .get_field_value(INPUT_PATH_EXTENSION)?
+            .as_list_value()
+            .map(Vec::as_slice)
+    }
+
+    /// Marks this [`FieldError`] as occurred at the input value, so its
+    /// [`FieldError::input_path`] is populated with the names of input fields
+    /// and arguments the input value is nested in.
+    ///
+    /// Does nothing if this [`FieldError`] is already marked, or its
+    /// [`FieldError::extensions`] are not an object.
+    #[must_use]
+    pub fn at_input_value(mut self) -> Self {
+        if self.extensions.is_null() {
+            self.extensions = Value::Object(Object::with_capacity(1));
+        }
+        if let Some(ext) = self.extensions.as_mut_object_value() {
+            if !ext.contains_field(INPUT_PATH_EXTENSION) {
+                ext.add_field(INPUT_PATH_EXTENSION, Value::list(vec![]));
+            }
+        }
+        self
+    }
+
+    /// Prepends the given input field (or argument) `name` to the
+    /// [`FieldError::input_path`] of this [`FieldError`].
+    ///
+    /// Does nothing if this [`FieldError`] wasn't
+    /// [marked as occurred at the input value](FieldError::at_input_value).
+    #[must_use]
+    pub fn prepend_input_path(self, name: &str) -> Self
+    where
+        S: ScalarValue,
+    {
+        self.prepend_input_path_segment(Value::scalar(name.to_owned()))
+    }
+
+    /// Prepends the given `index` of a list item to the
+    /// [`FieldError::input_path`] of this [`FieldError`].
+    ///
+    /// Does nothing if this [`FieldError`] wasn't
+    /// [marked as occurred at the input value](FieldError::at_input_value).
+    #[must_use]
+    pub fn prepend_input_index(self, index: usize) -> Self
+    where
+        S: ScalarValue,
+    {
+        self.prepend_input_path_segment(Value::scalar(index as i32))
+    }
+
+    fn prepend_input_path_segment(mut self, segment: Value<S>) -> Self {
+        if let Some(Value::List(path)) = self
+            .extensions
+            .as_mut_object_value()
+            .and_then(|ext| ext.get_mut_field_value(INPUT_PATH_EXTENSION))
+        {
+            path.insert(0, segment);
+        }
+        self
+    }
+
+    /// Stamps the provided `request_id` into the `"requestId"` field of the
+    /// [`FieldError::extensions`] of this [`FieldError`].
+    ///
+    /// Does nothing if this [`FieldError`] is already stamped, or its
+    /// [`FieldError::extensions`] are not an object.
+    #[must_use]
+    pub fn with_request_id(mut self, request_id: &str) -> Self
+    where
+        S: ScalarValue,
+    {
+        if self.extensions.is_null() {
+            self.extensions = Value::Object(Object::with_capacity(1));
+        }
+        if let Some(ext) = self.extensions.as_mut_object_value() {
+            if !ext.contains_field(REQUEST_ID_EXTENSION) {
+                ext.add_field(REQUEST_ID_EXTENSION, Value::scalar(request_id.to_owned()));
+            }
+        }
+        self
+    }
 }
 
+/// Name of the [`FieldError::extensions`] field containing the
+/// [`FieldError::input_path`].
+const INPUT_PATH_EXTENSION: &str = "inputPath";
+
 /// The result of resolving the value of a field of type `T`
 pub type FieldResult<T, S = DefaultScalarValue> = Result<T, FieldError<S>>;
 
@@ -551,6 +741,11 @@ where
     ///
     /// This can be used to connect different types, e.g. from different Rust
     /// libraries, that require different context types.
+    ///
+    /// To resolve a value with an owned context instead (e.g. one derived
+    /// asynchronously in a resolver), wrap the value into a [`WithContext`].
+    ///
+    /// [`WithContext`]: crate::WithContext
     pub fn replaced_context<'b, NewCtxT>(
         &'b self,
         ctx: &'b NewCtxT,
@@ -565,6 +760,8 @@ where
             context: ctx,
             errors: self.errors,
             field_path: self.field_path.clone(),
+            options: self.options,
+            spread_fragments: self.spread_fragments.clone(),
         }
     }
 
@@ -597,6 +794,8 @@ where
                 location,
                 Arc::clone(&self.field_path),
             )),
+            options: self.options,
+            spread_fragments: None,
         }
     }
 
@@ -619,9 +818,25 @@ where
             context: self.context,
             errors: self.errors,
             field_path: self.field_path.clone(),
+            options: self.options,
+            spread_fragments: self.spread_fragments.clone(),
         }
     }
 
+    /// Shares the provided [`SpreadFragments`] of the value being resolved
+    /// with this [`Executor`].
+    #[must_use]
+    pub(crate) fn with_spread_fragments(mut self, spread: SpreadFragments) -> Self {
+        self.spread_fragments = Some(spread);
+        self
+    }
+
+    /// Returns the [`SpreadFragments`] of the value being resolved, if any
+    /// have been spread already.
+    pub(crate) fn spread_fragments(&self) -> Option<&SpreadFragments> {
+        self.spread_fragments.as_ref()
+    }
+
     /// `Executor`'s current selection set
     pub(crate) fn current_selection_set(&self) -> Option<&[Selection<'a, S>]> {
         self.current_selection_set
@@ -650,6 +865,486 @@ where
         self.variables
     }
 
+    /// The options the current operation is executed with
+    pub fn options(&self) -> &'r ExecutionOptions {
+        self.options
+    }
+
+    /// The [`Clock`] time-dependent features should use
+    pub fn clock(&self) -> &'r dyn Clock {
+        &**self.options.get_clock()
+    }
+
+    /// The [`Rng`] features requiring randomness should use
+    pub fn rng(&self) -> &'r dyn Rng {
+        &**self.options.get_rng()
+    }
+
+    /// Whether the current field is a root field of a mutation executed in
+    /// dry-run mode
+    ///
+    /// Resolvers performing side effects should only validate their input
+    /// when this returns `true`. Query and subscription operations, as well as
+    /// the fields nested into the mutation root fields, are never dry-run.
+    pub fn is_dry_run(&self) -> bool {
+        self.options.is_dry_run()
+            && matches!(
+                &*self.field_path,
+                FieldPath::Field(_, _, parent) if matches!(**parent, FieldPath::Root(_)),
+            )
+    }
+
+    /// The ID of the request the current operation is executed for, if known
+    pub fn request_id(&self) -> Option<&'r str> {
+        self.options.get_correlation()?.request_id()
+    }
+
+    /// The trace the current operation is executed as part of, if known
+    pub fn trace_context(&self) -> Option<&'r TraceContext> {
+        self.options.get_correlation()?.trace_context.as_ref()
+    }
+
+    /// The [`CancellationToken`] of the current operation, if it may be
+    /// cancelled
+    ///
+    /// Resolvers performing expensive work should check it (or await its
+    /// [`CancellationToken::cancelled()`]) to abort early once the result is
+    /// not needed anymore.
+    pub fn cancellation_token(&self) -> Option<&'r CancellationToken> {
+        self.options.get_cancellation_token()
+    }
+
+    /// Whether the current operation has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token()
+            .map_or(false, CancellationToken::is_cancelled)
+    }
+
+    /// Returns the value of the `T` type from the [`Data`] of the current
+    /// operation, if any.
+    ///
+    /// See [`ExecutionOptions::data()`] and [`RootNode::with_data()`] for
+    /// details.
+    pub fn data<T: Any + Send + Sync>(&self) -> Option<&'r T> {
+        self.options.get_data().get()
+    }
+
+    /// Stashes the `name`d entry with the provided `value` into the
+    /// `extensions` object of the response, replacing the one stashed
+    /// before, if any.
+    ///
+    /// Returns `false` if the entry can't be delivered, because no
+    /// [`ResponseExtensions`] are registered in the [`ExecutionOptions`] (see
+    /// [`ExecutionOptions::response_extensions()`] for details).
+    pub fn add_response_extension(&self, name: impl Into<String>, value: Value<S>) -> bool
+    where
+        S: Send,
+    {
+        match self.options.get_response_extensions() {
+            Some(extensions) => {
+                extensions.insert(name, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Declares the data identified by the provided `key` (like `User:42`) as
+    /// a dependency of the current live query, so the query is re-executed
+    /// once that data is invalidated in its [`LiveQueryStore`].
+    ///
+    /// Returns `false` if the current operation is not executed as a live
+    /// query (see [`resolve_into_live_stream()`] for details).
+    ///
+    /// [`resolve_into_live_stream()`]: crate::resolve_into_live_stream
+    pub fn live_dependency(&self, key: impl Into<String>) -> bool {
+        match self.options.get_live() {
+            Some(dependencies) => {
+                dependencies.insert(key.into());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stamps the request ID into the provided `error`, if requested by the
+    /// [`ExecutionOptions`].
+    fn correlate_error(&self, error: FieldError<S>) -> FieldError<S> {
+        match self.request_id() {
+            Some(id) if self.options.stamps_errors_with_request_id() => error.with_request_id(id),
+            _ => error,
+        }
+    }
+
+    /// Resolves a field via the provided `fut`ure, unless the current
+    /// operation is [cancelled](ExecutionOptions::cancellation_token) or its
+    /// [timeout](ExecutionOptions::timeout) elapses first.
+    pub(crate) async fn interruptible<F>(&self, fut: F) -> ExecutionResult<S>
+    where
+        F: Future<Output = ExecutionResult<S>>,
+    {
+        let fut = async {
+            match self.options.get_cancellation_token() {
+                Some(token) => {
+                    futures::pin_mut!(fut);
+                    match future::select(fut, token.cancelled()).await {
+                        Either::Left((res, _)) => res,
+                        Either::Right(_) => Err(FieldError::from("Execution cancelled")),
+                    }
+                }
+                None => fut.await,
+            }
+        };
+        match &self.options.deadline {
+            Some(deadline) => deadline
+                .run(fut)
+                .await
+                .unwrap_or_else(|| Err(FieldError::from("Execution timed out"))),
+            None => fut.await,
+        }
+    }
+
+    /// Resolves the current field via the provided `resolve` function,
+    /// notifying the [`Extension`]s around it and reporting its metrics to the
+    /// [`Instrumentation`]s.
+    pub(crate) fn hooked(
+        &self,
+        parent_type: &str,
+        field_name: &str,
+        resolve: impl FnOnce() -> ExecutionResult<S>,
+    ) -> ExecutionResult<S> {
+        if self.options.get_instrumentations().is_empty() {
+            return self.hooked_extensions(parent_type, field_name, resolve);
+        }
+
+        let start = self.clock().now();
+        let res = self.hooked_extensions(parent_type, field_name, resolve);
+        self.report_field_metrics(parent_type, field_name, start, &res);
+        res
+    }
+
+    /// Resolves the current field via the provided `resolve` function,
+    /// notifying the [`Extension`]s around it.
+    fn hooked_extensions(
+        &self,
+        parent_type: &str,
+        field_name: &str,
+        resolve: impl FnOnce() -> ExecutionResult<S>,
+    ) -> ExecutionResult<S> {
+        let extensions = self.options.get_extensions();
+        if extensions.is_empty() {
+            return resolve();
+        }
+
+        let mut path = Vec::new();
+        self.field_path.construct_path(&mut path);
+        let field = FieldInfo {
+            parent_type,
+            field_name,
+            return_type: self.field_return_type(parent_type, field_name),
+            path: &path,
+        };
+
+        for ext in extensions {
+            ext.field_start(&field);
+        }
+        let res = resolve();
+        for ext in extensions.iter().rev() {
+            ext.field_end(&field, res.as_ref().err().map(FieldError::message));
+        }
+        res
+    }
+
+    /// Resolves the current field via the provided `fut`ure, notifying the
+    /// [`Extension`]s around it and reporting its metrics to the
+    /// [`Instrumentation`]s.
+    pub(crate) async fn hooked_async<F>(
+        &self,
+        parent_type: &str,
+        field_name: &str,
+        fut: F,
+    ) -> ExecutionResult<S>
+    where
+        F: Future<Output = ExecutionResult<S>>,
+    {
+        if self.options.get_instrumentations().is_empty() {
+            return self
+                .hooked_extensions_async(parent_type, field_name, fut)
+                .await;
+        }
+
+        let start = self.clock().now();
+        let res = self
+            .hooked_extensions_async(parent_type, field_name, fut)
+            .await;
+        self.report_field_metrics(parent_type, field_name, start, &res);
+        res
+    }
+
+    /// Resolves the current field via the provided `fut`ure, notifying the
+    /// [`Extension`]s around it.
+    async fn hooked_extensions_async<F>(
+        &self,
+        parent_type: &str,
+        field_name: &str,
+        fut: F,
+    ) -> ExecutionResult<S>
+    where
+        F: Future<Output = ExecutionResult<S>>,
+    {
+        let extensions = self.options.get_extensions();
+        if extensions.is_empty() {
+            return fut.await;
+        }
+
+        let mut path = Vec::new();
+        self.field_path.construct_path(&mut path);
+        let field = FieldInfo {
+            parent_type,
+            field_name,
+            return_type: self.field_return_type(parent_type, field_name),
+            path: &path,
+        };
+
+        for ext in extensions {
+            ext.field_start(&field);
+        }
+        let res = fut.await;
+        for ext in extensions.iter().rev() {
+            ext.field_end(&field, res.as_ref().err().map(FieldError::message));
+        }
+        res
+    }
+
+    /// Reports the [`FieldMetrics`] of the current field, resolved since the
+    /// provided `start` time, to the [`Instrumentation`]s.
+    fn report_field_metrics(
+        &self,
+        parent_type: &str,
+        field_name: &str,
+        start: SystemTime,
+        res: &ExecutionResult<S>,
+    ) {
+        let mut path = Vec::new();
+        self.field_path.construct_path(&mut path);
+        let metrics = FieldMetrics {
+            parent_type,
+            field_name,
+            path: &path,
+            duration: self.clock().elapsed_since(start),
+            status: FieldStatus::of(res),
+        };
+
+        for instrumentation in self.options.get_instrumentations() {
+            instrumentation.field_resolved(&metrics);
+        }
+    }
+
+    /// Resolves the current field via the provided `resolve` function,
+    /// applying the [`DirectiveHandler`]s of the provided `directives`
+    /// specified on it.
+    pub(crate) fn directed(
+        &self,
+        directives: &Option<Vec<Spanning<Directive<S>>>>,
+        parent_type: &str,
+        field_name: &str,
+        resolve: impl FnOnce() -> ExecutionResult<S>,
+    ) -> ExecutionResult<S> {
+        let handled = self.handled_directives(directives);
+        if handled.is_empty() {
+            return resolve();
+        }
+
+        let mut path = Vec::new();
+        self.field_path.construct_path(&mut path);
+        let field = FieldInfo {
+            parent_type,
+            field_name,
+            return_type: self.field_return_type(parent_type, field_name),
+            path: &path,
+        };
+        let applied = handled
+            .iter()
+            .map(|(name, args, _)| AppliedDirective {
+                name,
+                arguments: args,
+                field: &field,
+            })
+            .collect::<Vec<_>>();
+
+        let mut res = None;
+        let mut entered = 0;
+        for (directive, (_, _, handler)) in applied.iter().zip(&handled) {
+            res = handler.before(directive);
+            if res.is_some() {
+                break;
+            }
+            entered += 1;
+        }
+        let mut res = res.unwrap_or_else(resolve);
+        for (directive, (_, _, handler)) in applied.iter().zip(&handled).take(entered).rev() {
+            res = handler.after(directive, res);
+        }
+        res
+    }
+
+    /// Resolves the current field via the future created by the provided
+    /// `resolve` function, applying the [`DirectiveHandler`]s of the provided
+    /// `directives` specified on it.
+    ///
+    /// The future is created only if the field isn't short-circuited by the
+    /// [`DirectiveHandler`]s, as creating it may already resolve the field.
+    pub(crate) async fn directed_async<F>(
+        &self,
+        directives: &Option<Vec<Spanning<Directive<'_, S>>>>,
+        parent_type: &str,
+        field_name: &str,
+        resolve: impl FnOnce() -> F,
+    ) -> ExecutionResult<S>
+    where
+        F: Future<Output = ExecutionResult<S>>,
+    {
+        let handled = self.handled_directives(directives);
+        if handled.is_empty() {
+            return resolve().await;
+        }
+
+        let mut path = Vec::new();
+        self.field_path.construct_path(&mut path);
+        let field = FieldInfo {
+            parent_type,
+            field_name,
+            return_type: self.field_return_type(parent_type, field_name),
+            path: &path,
+        };
+        let applied = handled
+            .iter()
+            .map(|(name, args, _)| AppliedDirective {
+                name,
+                arguments: args,
+                field: &field,
+            })
+            .collect::<Vec<_>>();
+
+        let mut res = None;
+        let mut entered = 0;
+        for (directive, (_, _, handler)) in applied.iter().zip(&handled) {
+            res = handler.before(directive);
+            if res.is_some() {
+                break;
+            }
+            entered += 1;
+        }
+        let mut res = match res {
+            Some(res) => res,
+            None => resolve().await,
+        };
+        for (directive, (_, _, handler)) in applied.iter().zip(&handled).take(entered).rev() {
+            res = handler.after(directive, res);
+        }
+        res
+    }
+
+    /// Collects the provided `directives` having a [`DirectiveHandler`]
+    /// registered in the schema, along with their arguments.
+    #[allow(clippy::type_complexity)]
+    fn handled_directives<'d>(
+        &self,
+        directives: &'d Option<Vec<Spanning<Directive<S>>>>,
+    ) -> Vec<(&'d str, Arguments<'d, S>, &'a Arc<dyn DirectiveHandler<S>>)>
+    where
+        'a: 'd,
+    {
+        let handlers = &self.schema.directive_handlers;
+        if handlers.is_empty() {
+            return vec![];
+        }
+        directives
+            .iter()
+            .flatten()
+            .filter_map(|d| {
+                let name = d.item.name.item;
+                let handler = handlers.get(name)?;
+                let meta_args = &self.schema.directive_by_name(name)?.arguments;
+                let args = d
+                    .item
+                    .arguments
+                    .iter()
+                    .flat_map(|m| &m.item.items)
+                    .filter_map(|(k, v)| {
+                        v.item
+                            .clone()
+                            .into_const(self.variables)
+                            .map(|v| (k.item, v))
+                    })
+                    .collect();
+                Some((name, Arguments::with_defaults(args, meta_args), handler))
+            })
+            .collect()
+    }
+
+    /// Checks that the current field isn't nested deeper than the
+    /// [maximum recursion depth](ExecutionOptions::max_recursion_depth).
+    pub(crate) fn check_recursion_depth(&self) -> Result<(), FieldError<S>> {
+        let mut path = &*self.field_path;
+        for _ in 0..self.options.get_max_recursion_depth() {
+            match path {
+                FieldPath::Root(_) => return Ok(()),
+                FieldPath::Field(_, _, parent) => path = parent.as_ref(),
+            }
+        }
+        match path {
+            FieldPath::Root(_) => Ok(()),
+            FieldPath::Field(..) => Err(FieldError::from("Maximum recursion depth exceeded")),
+        }
+    }
+
+    /// Indicates whether the execution has already failed in the
+    /// [strict](NullPropagation::Strict) null propagation mode, so the
+    /// remaining fields shouldn't be resolved at all.
+    pub(crate) fn has_failed_strictly(&self) -> bool {
+        self.options.get_null_propagation() == NullPropagation::Strict
+            && !self.errors.read().unwrap().is_empty()
+    }
+
+    /// Reports the `field_name` field of the `parent_type` being made `null`
+    /// by an error in its subtree, if [detailed](NullPropagation::Detailed)
+    /// null propagation is enabled.
+    pub(crate) fn push_nulled_error_at(
+        &self,
+        parent_type: &str,
+        field_name: &str,
+        location: SourcePosition,
+    ) {
+        if self.options.get_null_propagation() == NullPropagation::Detailed {
+            self.push_error_at(
+                FieldError::from(format!(
+                    "Cannot return null for non-nullable field {parent_type}.{field_name}",
+                )),
+                location,
+            );
+        }
+    }
+
+    /// Looks up the declared type of the `field_name` field of the
+    /// `parent_type` in the schema.
+    fn field_return_type(&self, parent_type: &str, field_name: &str) -> Option<&Type<'a>> {
+        self.schema
+            .concrete_type_by_name(parent_type)
+            .and_then(|t| t.field_by_name(field_name))
+            .map(|f| &f.field_type)
+    }
+
+    /// Indicates whether the selections of the current selection set (or the
+    /// items of the current list) should be resolved one after another, as
+    /// required for the root fields of a mutation, or for the whole mutation
+    /// if [requested](ExecutionOptions::serial_mutation_subtree).
+    pub(crate) fn resolves_serially(&self) -> bool {
+        self.options.serial_root
+            && (self.options.resolves_mutation_subtree_serially()
+                || matches!(*self.field_path, FieldPath::Root(_)))
+    }
+
     #[doc(hidden)]
     pub fn fragment_by_name<'s>(&'s self, name: &str) -> Option<&'s Fragment<'a, S>> {
         self.fragments.get(name)
@@ -670,6 +1365,7 @@ where
         let mut path = Vec::new();
         self.field_path.construct_path(&mut path);
 
+        let error = self.correlate_error(error);
         let mut errors = self.errors.write().unwrap();
 
         errors.push(ExecutionError {
@@ -687,7 +1383,7 @@ where
         ExecutionError {
             location: *self.location(),
             path,
-            error,
+            error: self.correlate_error(error),
         }
     }
 
@@ -695,7 +1391,25 @@ where
     ///
     /// This allows seeing the whole selection and perform operations
     /// affecting the children.
+    ///
+    /// The `@skip` and `@include` directives are evaluated against the
+    /// variables of the operation, so the selection contains only the fields
+    /// to be actually resolved. Use [`Executor::raw_look_ahead()`] to see all
+    /// the selected fields instead.
     pub fn look_ahead(&'a self) -> LookAheadSelection<'a, S> {
+        self.build_look_ahead(true)
+    }
+
+    /// Construct a lookahead selection for the current selection, without
+    /// evaluating the `@skip` and `@include` directives.
+    ///
+    /// Unlike [`Executor::look_ahead()`], the selection contains all the
+    /// fields specified in the operation, including the ones to be skipped.
+    pub fn raw_look_ahead(&'a self) -> LookAheadSelection<'a, S> {
+        self.build_look_ahead(false)
+    }
+
+    fn build_look_ahead(&'a self, evaluate_directives: bool) -> LookAheadSelection<'a, S> {
         let field_name = match *self.field_path {
             FieldPath::Field(x, ..) => x,
             FieldPath::Root(_) => unreachable!(),
@@ -716,7 +1430,13 @@ where
                     }
                 });
                 if let Some(p) = found_field {
-                    LookAheadSelection::build_from_selection(p, self.variables, self.fragments)
+                    LookAheadSelection::build_from_selection_with_parent(
+                        p,
+                        None,
+                        self.variables,
+                        self.fragments,
+                        evaluate_directives,
+                    )
                 } else {
                     None
                 }
@@ -739,6 +1459,7 @@ where
                             Some(&mut ret),
                             self.variables,
                             self.fragments,
+                            evaluate_directives,
                         );
                     }
                 }
@@ -764,6 +1485,7 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: Arc::clone(&self.field_path),
+            options: self.options.clone(),
         }
     }
 }
@@ -810,6 +1532,17 @@ impl<S> ExecutionError<S> {
     pub fn path(&self) -> &[String] {
         &self.path
     }
+
+    /// Maps the [`FieldError`] of this [`ExecutionError`] with the given
+    /// function, keeping its location and path.
+    #[must_use]
+    pub fn map_error(self, f: impl FnOnce(FieldError<S>) -> FieldError<S>) -> Self {
+        Self {
+            location: self.location,
+            path: self.path,
+            error: f(self.error),
+        }
+    }
 }
 
 /// Create new `Executor` and start query/mutation execution.
@@ -821,6 +1554,33 @@ pub fn execute_validated_query<'b, QueryT, MutationT, SubscriptionT, S>(
     variables: &Variables<S>,
     context: &QueryT::Context,
 ) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+{
+    execute_validated_query_with_options(
+        document,
+        operation,
+        root_node,
+        variables,
+        context,
+        root_node.execution_options(),
+    )
+}
+
+/// Create new `Executor` and start query/mutation execution with the provided
+/// [`ExecutionOptions`] instead of the schema's default ones.
+/// Returns `IsSubscription` error if subscription is passed.
+pub fn execute_validated_query_with_options<'b, QueryT, MutationT, SubscriptionT, S>(
+    document: &'b Document<S>,
+    operation: &'b Spanning<Operation<S>>,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    options: &ExecutionOptions,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
 where
     S: ScalarValue,
     QueryT: GraphQLType<S>,
@@ -850,6 +1610,7 @@ where
             .collect::<HashMap<String, InputValue<S>>>()
     });
 
+    let options = options.for_operation(&operation.item);
     let errors = RwLock::new(Vec::new());
     let value;
 
@@ -889,6 +1650,8 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            options: &options,
+            spread_fragments: None,
         };
 
         value = match operation.item.operation_type {
@@ -903,7 +1666,52 @@ where
     let mut errors = errors.into_inner().unwrap();
     errors.sort();
 
-    Ok((value, errors))
+    Ok((completed_value(value, &errors, &options), errors))
+}
+
+/// Discards the resolved `value` of an operation if its execution has failed
+/// in the [strict](NullPropagation::Strict) null propagation mode.
+fn completed_value<S>(
+    value: Value<S>,
+    errors: &[ExecutionError<S>],
+    options: &ExecutionOptions,
+) -> Value<S> {
+    if options.get_null_propagation() == NullPropagation::Strict && !errors.is_empty() {
+        Value::null()
+    } else {
+        value
+    }
+}
+
+/// Resolves the provided leaf `value` (like a scalar or an enum) outside of any
+/// operation, with an [`Executor`] over the provided `schema`.
+///
+/// No selection set is provided for resolving, so `value` is not expected to
+/// have any fields.
+pub(crate) fn resolve_standalone_value<T, S>(
+    value: &T,
+    info: &T::TypeInfo,
+    schema: &SchemaType<S>,
+) -> ExecutionResult<S>
+where
+    T: GraphQLValue<S, Context = ()> + ?Sized,
+    S: ScalarValue,
+{
+    let errors = RwLock::new(Vec::new());
+    let executor = Executor {
+        fragments: &HashMap::new(),
+        variables: &Variables::new(),
+        current_selection_set: None,
+        parent_selection_set: None,
+        current_type: schema.query_type(),
+        schema,
+        context: &(),
+        errors: &errors,
+        field_path: Arc::new(FieldPath::Root(SourcePosition::new_origin())),
+        options: &ExecutionOptions::default(),
+        spread_fragments: None,
+    };
+    value.resolve(info, None, &executor)
 }
 
 /// Create new `Executor` and start asynchronous query execution.
@@ -915,6 +1723,45 @@ pub async fn execute_validated_query_async<'a, 'b, QueryT, MutationT, Subscripti
     variables: &Variables<S>,
     context: &QueryT::Context,
 ) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    execute_validated_query_async_with_options(
+        document,
+        operation,
+        root_node,
+        variables,
+        context,
+        root_node.execution_options(),
+    )
+    .await
+}
+
+/// Create new `Executor` and start asynchronous query execution with the
+/// provided [`ExecutionOptions`] instead of the schema's default ones.
+/// Returns `IsSubscription` error if subscription is passed.
+pub async fn execute_validated_query_async_with_options<
+    'a,
+    'b,
+    QueryT,
+    MutationT,
+    SubscriptionT,
+    S,
+>(
+    document: &'b Document<'a, S>,
+    operation: &'b Spanning<Operation<'_, S>>,
+    root_node: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    options: &ExecutionOptions,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
 where
     QueryT: GraphQLTypeAsync<S>,
     QueryT::TypeInfo: Sync,
@@ -948,6 +1795,7 @@ where
             .collect::<HashMap<String, InputValue<S>>>()
     });
 
+    let options = ExecutionOptions::start_deadline(options.for_operation(&operation.item));
     let errors = RwLock::new(Vec::new());
     let value;
 
@@ -987,6 +1835,8 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            options: &options,
+            spread_fragments: None,
         };
 
         value = match operation.item.operation_type {
@@ -1007,7 +1857,7 @@ where
     let mut errors = errors.into_inner().unwrap();
     errors.sort();
 
-    Ok((value, errors))
+    Ok((completed_value(value, &errors, &options), errors))
 }
 
 #[doc(hidden)]
@@ -1059,6 +1909,51 @@ pub async fn resolve_validated_subscription<
     variables: &Variables<S>,
     context: &'r QueryT::Context,
 ) -> Result<(Value<ValuesStream<'r, S>>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    'r: 'exec_ref,
+    'd: 'r,
+    'op: 'd,
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync + 'r,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = QueryT::Context>,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    resolve_validated_subscription_with_options(
+        document,
+        operation,
+        root_node,
+        variables,
+        context,
+        root_node.execution_options(),
+    )
+    .await
+}
+
+/// Initialize new `Executor` and start resolving subscription into stream
+/// asynchronously with the provided [`ExecutionOptions`] instead of the
+/// schema's default ones.
+/// Returns `NotSubscription` error if query or mutation is passed
+pub async fn resolve_validated_subscription_with_options<
+    'r,
+    'exec_ref,
+    'd,
+    'op,
+    QueryT,
+    MutationT,
+    SubscriptionT,
+    S,
+>(
+    document: &Document<'d, S>,
+    operation: &Spanning<Operation<'op, S>>,
+    root_node: &'r RootNode<'r, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &'r QueryT::Context,
+    options: &ExecutionOptions,
+) -> Result<(Value<ValuesStream<'r, S>>, Vec<ExecutionError<S>>), GraphQLError>
 where
     'r: 'exec_ref,
     'd: 'r,
@@ -1095,6 +1990,7 @@ where
             .collect::<HashMap<String, InputValue<S>>>()
     });
 
+    let options = options.for_operation(&operation.item);
     let errors = RwLock::new(Vec::new());
     let value;
 
@@ -1133,6 +2029,8 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            options: &options,
+            spread_fragments: None,
         };
 
         value = match operation.item.operation_type {
@@ -1154,7 +2052,40 @@ where
 impl<'r, S: 'r> Registry<'r, S> {
     /// Constructs a new [`Registry`] out of the given `types`.
     pub fn new(types: FnvHashMap<Name, MetaType<'r, S>>) -> Self {
-        Self { types }
+        Self {
+            types,
+            directives: FnvHashMap::default(),
+            lazy_types: Vec::new(),
+            type_origins: None,
+            conflict: None,
+            is_input: false,
+        }
+    }
+
+    /// Enables detection of different Rust types registered in this
+    /// [`Registry`] under the same name, but defining different types.
+    ///
+    /// The first detected conflict is returned by [`Registry::take_conflict()`].
+    pub(crate) fn detect_conflicts(&mut self) {
+        self.type_origins.get_or_insert_with(FnvHashMap::default);
+    }
+
+    /// Takes the first conflict between the types registered under the same
+    /// name, detected after [`Registry::detect_conflicts()`] was called.
+    pub(crate) fn take_conflict(&mut self) -> Option<SchemaError> {
+        self.conflict.take()
+    }
+
+    /// Registers the provided custom `directive` definition in this
+    /// [`Registry`], so the schema built out of it exposes the `directive` via
+    /// introspection and in its schema language representation.
+    ///
+    /// Allows [`GraphQLType::meta()`] implementations to declare the
+    /// directives they apply to the types and fields they register. Overwrites
+    /// any previously registered directive of the same name, but never a
+    /// built-in one (like `@skip` or `@deprecated`).
+    pub fn register_directive(&mut self, directive: DirectiveType<'r, S>) {
+        self.directives.insert(directive.name.clone(), directive);
     }
 
     /// Returns a [`Type`] instance for the given [`GraphQLType`], registered in
@@ -1167,19 +2098,155 @@ impl<'r, S: 'r> Registry<'r, S> {
         T: GraphQLType<S> + ?Sized,
         S: ScalarValue,
     {
-        if let Some(name) = T::name(info) {
+        if let Some(name) = self.type_name::<T>(info) {
             let validated_name = name.parse::<Name>().unwrap();
             if !self.types.contains_key(name) {
+                self.record_origin::<T>(&validated_name);
                 self.insert_placeholder(
                     validated_name.clone(),
                     Type::NonNullNamed(Cow::Owned(name.into())),
                 );
-                let meta = T::meta(info, self);
+                let meta = self.type_meta::<T>(info);
                 self.types.insert(validated_name, meta);
+            } else {
+                self.check_conflict::<T>(validated_name, info);
             }
             self.types[name].as_type()
         } else {
-            T::meta(info, self).as_type()
+            self.type_meta::<T>(info).as_type()
+        }
+    }
+
+    /// Returns [`GraphQLType::name()`] of the given [`GraphQLType`], or its
+    /// [`GraphQLType::input_name()`] if it's registered as an input type.
+    fn type_name<'i, T>(&self, info: &'i T::TypeInfo) -> Option<&'i str>
+    where
+        T: GraphQLType<S> + ?Sized,
+        S: ScalarValue,
+    {
+        if self.is_input {
+            T::input_name(info)
+        } else {
+            T::name(info)
+        }
+    }
+
+    /// Returns [`GraphQLType::meta()`] of the given [`GraphQLType`], or its
+    /// [`GraphQLType::input_meta()`] if it's registered as an input type.
+    fn type_meta<T>(&mut self, info: &T::TypeInfo) -> MetaType<'r, S>
+    where
+        T: GraphQLType<S> + ?Sized,
+        S: ScalarValue,
+    {
+        if self.is_input {
+            T::input_meta(info, self)
+        } else {
+            T::meta(info, self)
+        }
+    }
+
+    /// Returns a [`Type`] instance for the given [`GraphQLType`] registered in
+    /// this [`Registry`] either as an input type or as an output one, as
+    /// specified by `is_input`.
+    fn get_type_as<T>(&mut self, info: &T::TypeInfo, is_input: bool) -> Type<'r>
+    where
+        T: GraphQLType<S> + ?Sized,
+        S: ScalarValue,
+    {
+        let was_input = mem::replace(&mut self.is_input, is_input);
+        let ty = self.get_type::<T>(info);
+        self.is_input = was_input;
+        ty
+    }
+
+    /// Returns a [`Type`] instance for the given [`GraphQLType`], deferring
+    /// its registration in this [`Registry`] until the schema is finalized.
+    ///
+    /// Unlike [`Registry::get_type()`], doesn't construct the metadata of the
+    /// type right away, but registers a placeholder for it, resolved once all
+    /// the eagerly registered types are. This allows [`GraphQLType::meta()`]
+    /// implementations of types with non-trivial [`GraphQLValue::TypeInfo`]s to
+    /// reference each other (making cyclic dynamic schemas expressible)
+    /// without recursing into each other's metadata construction.
+    ///
+    /// # Panics
+    ///
+    /// If the given [`GraphQLType`] is not named.
+    pub fn get_or_register_lazy<T>(&mut self, info: &T::TypeInfo) -> Type<'r>
+    where
+        T: GraphQLType<S> + ?Sized + 'r,
+        T::TypeInfo: Clone + 'r,
+        S: ScalarValue + 'r,
+    {
+        let name = self
+            .type_name::<T>(info)
+            .expect("Lazily registered types must be named");
+        let validated_name = name.parse::<Name>().unwrap();
+        if !self.types.contains_key(name) {
+            self.record_origin::<T>(&validated_name);
+            self.insert_placeholder(
+                validated_name.clone(),
+                Type::NonNullNamed(Cow::Owned(name.into())),
+            );
+            let (info, is_input) = (info.clone(), self.is_input);
+            self.lazy_types.push((
+                validated_name,
+                Box::new(move |registry| {
+                    let was_input = mem::replace(&mut registry.is_input, is_input);
+                    let meta = registry.type_meta::<T>(&info);
+                    registry.is_input = was_input;
+                    meta
+                }),
+            ));
+        }
+        self.types[name].as_type()
+    }
+
+    /// Records the Rust type `T` as the one registered under the provided
+    /// `name`, if conflicts detection is enabled.
+    fn record_origin<T: ?Sized>(&mut self, name: &Name) {
+        if let Some(origins) = &mut self.type_origins {
+            origins.insert(name.clone(), vec![any::type_name::<T>()]);
+        }
+    }
+
+    /// Checks whether the Rust type `T` defines the same type as the one
+    /// already registered under the provided `name`, if conflicts detection is
+    /// enabled.
+    ///
+    /// Each Rust type is checked once only, and only after the type
+    /// registered under the `name` is fully constructed.
+    fn check_conflict<T>(&mut self, name: Name, info: &T::TypeInfo)
+    where
+        T: GraphQLType<S> + ?Sized,
+        S: ScalarValue,
+    {
+        let origin = any::type_name::<T>();
+        if let Some(origins) = &mut self.type_origins {
+            let known = origins.entry(name.clone()).or_default();
+            if known.contains(&origin) || matches!(self.types[&name], MetaType::Placeholder(_)) {
+                return;
+            }
+            known.push(origin);
+        } else {
+            return;
+        }
+
+        let meta = self.type_meta::<T>(info);
+        if self.conflict.is_none() {
+            self.conflict = SchemaError::conflicting_type(&self.types[&name], &meta);
+        }
+    }
+
+    /// Constructs the metadata of all the types registered via
+    /// [`Registry::get_or_register_lazy()`], including the ones registered
+    /// lazily while doing so.
+    pub(crate) fn resolve_lazy_types(&mut self) {
+        while let Some((name, meta_fn)) = self.lazy_types.pop() {
+            if matches!(self.types.get(&name), Some(MetaType::Placeholder(_))) {
+                let meta = meta_fn(self);
+                self.types.insert(name, meta);
+            }
         }
     }
 
@@ -1193,8 +2260,10 @@ impl<'r, S: 'r> Registry<'r, S> {
             name: smartstring::SmartString::from(name),
             description: None,
             arguments: None,
-            field_type: self.get_type::<T>(info),
+            field_type: self.get_type_as::<T>(info, false),
             deprecation_status: DeprecationStatus::Current,
+            complexity: None,
+            metadata: Metadata::new(),
         }
     }
 
@@ -1212,8 +2281,34 @@ impl<'r, S: 'r> Registry<'r, S> {
             name: name.into(),
             description: None,
             arguments: None,
-            field_type: self.get_type::<I>(info),
+            field_type: self.get_type_as::<I>(info, false),
             deprecation_status: DeprecationStatus::Current,
+            complexity: None,
+            metadata: Metadata::new(),
+        }
+    }
+
+    /// Creates a [`Field`] with the provided `name`, which type is registered
+    /// lazily.
+    ///
+    /// See [`Registry::get_or_register_lazy()`] for details.
+    pub fn field_lazy<T>(&mut self, name: &str, info: &T::TypeInfo) -> Field<'r, S>
+    where
+        T: GraphQLType<S> + ?Sized + 'r,
+        T::TypeInfo: Clone + 'r,
+        S: ScalarValue + 'r,
+    {
+        let was_input = mem::replace(&mut self.is_input, false);
+        let field_type = self.get_or_register_lazy::<T>(info);
+        self.is_input = was_input;
+        Field {
+            name: smartstring::SmartString::from(name),
+            description: None,
+            arguments: None,
+            field_type,
+            deprecation_status: DeprecationStatus::Current,
+            complexity: None,
+            metadata: Metadata::new(),
         }
     }
 
@@ -1223,7 +2318,7 @@ impl<'r, S: 'r> Registry<'r, S> {
         T: GraphQLType<S> + FromInputValue<S>,
         S: ScalarValue,
     {
-        Argument::new(name, self.get_type::<T>(info))
+        Argument::new(name, self.get_type_as::<T>(info, true))
     }
 
     /// Creates an [`Argument`] with the provided default `value`.
@@ -1237,7 +2332,54 @@ impl<'r, S: 'r> Registry<'r, S> {
         T: GraphQLType<S> + ToInputValue<S> + FromInputValue<S>,
         S: ScalarValue,
     {
-        Argument::new(name, self.get_type::<T>(info)).default_value(value.to_input_value())
+        Argument::new(name, self.get_type_as::<T>(info, true)).default_value(value.to_input_value())
+    }
+
+    /// Annotates the already registered type of the provided `type_name` with
+    /// the provided typed `value`, retrievable via
+    /// [`MetaType::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type. Returns
+    /// `false` if there is no such named type registered (yet).
+    pub fn annotate<T>(&mut self, type_name: &str, value: T) -> bool
+    where
+        T: Any + Send + Sync,
+    {
+        match self
+            .types
+            .get_mut(type_name)
+            .and_then(MetaType::metadata_mut)
+        {
+            Some(metadata) => {
+                metadata.insert(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Annotates the `field_name`d field of the already registered object or
+    /// interface type of the provided `type_name` with the provided typed
+    /// `value`, retrievable via [`Field::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type. Returns
+    /// `false` if there is no such field registered (yet).
+    pub fn annotate_field<T>(&mut self, type_name: &str, field_name: &str, value: T) -> bool
+    where
+        T: Any + Send + Sync,
+    {
+        let fields = match self.types.get_mut(type_name) {
+            Some(MetaType::Object(ObjectMeta { fields, .. }))
+            | Some(MetaType::Interface(InterfaceMeta { fields, .. })) => fields,
+            _ => return false,
+        };
+        match fields.iter_mut().find(|f| f.name == field_name) {
+            Some(field) => {
+                field.metadata.insert(value);
+                true
+            }
+            None => false,
+        }
     }
 
     fn insert_placeholder(&mut self, name: Name, of_type: Type<'r>) {
@@ -1357,7 +2499,7 @@ impl<'r, S: 'r> Registry<'r, S> {
         T::Error: IntoFieldError<S>,
         S: ScalarValue,
     {
-        let name = T::name(info).expect("Input object types must be named. Implement name()");
+        let name = T::input_name(info).expect("Input object types must be named. Implement name()");
 
         InputObjectMeta::new::<T>(Cow::Owned(name.into()), args)
     }
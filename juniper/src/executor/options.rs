@@ -0,0 +1,558 @@
+//! Options tuning the execution of an operation.
+
+use std::{any::Any, borrow::Cow, sync::Arc, time::Duration};
+
+use crate::ast::{Operation, OperationType};
+
+use super::{
+    cancellation::CancellationToken,
+    clock::{Clock, SystemClock},
+    correlation::Correlation,
+    data::Data,
+    defer::DeferMode,
+    extension::Extension,
+    instrumentation::Instrumentation,
+    live::LiveDependencies,
+    response_extensions::ResponseExtensions,
+    rng::{Rng, SplitMixRng},
+    timer::{runtime_timer, Deadline, Timer},
+};
+
+/// Name of the directive marking an operation to be executed in
+/// [dry-run mode](ExecutionOptions::dry_run).
+pub const DRY_RUN_DIRECTIVE: &str = "dryRun";
+
+/// Behavior of an operation execution on field errors, set via
+/// [`ExecutionOptions::null_propagation()`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NullPropagation {
+    /// Field errors are propagated as the [spec][0] requires: an errored (or
+    /// `null`) non-nullable field makes its parent `null`, up to the nearest
+    /// nullable ancestor. Only the originating error is reported.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Handling-Field-Errors
+    #[default]
+    Bubble,
+
+    /// Field errors are propagated up to the nearest nullable ancestor (as
+    /// with [`NullPropagation::Bubble`]), but every non-nullable field made
+    /// `null` along the way is reported with its own error, so clients are
+    /// able to tell exactly which parts of a response are missing.
+    Detailed,
+
+    /// The first field error fails the whole operation: the remaining fields
+    /// are not resolved anymore, and the `data` of the response is `null`.
+    Strict,
+}
+
+/// Options used by an [`Executor`] while executing an operation.
+///
+/// Default options can be set for a whole schema via
+/// [`RootNode::with_execution_options()`], and overridden for a single request
+/// via [`execute_with_options()`] and friends.
+///
+/// [`Executor`]: crate::Executor
+/// [`RootNode::with_execution_options()`]: crate::RootNode::with_execution_options
+/// [`execute_with_options()`]: crate::execute_with_options
+#[derive(Clone, Debug)]
+pub struct ExecutionOptions {
+    dry_run: bool,
+    concurrent_fields: bool,
+    pub(super) serial_root: bool,
+    serial_mutation_subtree: bool,
+    clock: Arc<dyn Clock>,
+    rng: Arc<dyn Rng>,
+    timer: Option<Arc<dyn Timer>>,
+    timeout: Option<Duration>,
+    pub(super) deadline: Option<Deadline>,
+    correlation: Option<Arc<Correlation>>,
+    stamp_request_id: bool,
+    source_ranges: bool,
+    response_extensions: Option<Arc<ResponseExtensions>>,
+    cancellation: Option<CancellationToken>,
+    max_recursion_depth: usize,
+    null_propagation: NullPropagation,
+    introspection: Option<bool>,
+    extensions: Vec<Arc<dyn Extension>>,
+    instrumentations: Vec<Arc<dyn Instrumentation>>,
+    data: Data,
+    pub(super) defer: DeferMode,
+    live: Option<Arc<LiveDependencies>>,
+}
+
+impl Default for ExecutionOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            concurrent_fields: false,
+            serial_root: false,
+            serial_mutation_subtree: false,
+            clock: Arc::new(SystemClock),
+            rng: Arc::new(SplitMixRng::default()),
+            timer: runtime_timer(),
+            timeout: None,
+            deadline: None,
+            correlation: None,
+            stamp_request_id: false,
+            source_ranges: false,
+            response_extensions: None,
+            cancellation: None,
+            max_recursion_depth: 256,
+            null_propagation: NullPropagation::default(),
+            introspection: None,
+            extensions: vec![],
+            instrumentations: vec![],
+            data: Data::default(),
+            defer: DeferMode::default(),
+            live: None,
+        }
+    }
+}
+
+impl ExecutionOptions {
+    /// Creates new [`ExecutionOptions`] with all the options set to their
+    /// defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether mutations should be executed in dry-run mode.
+    ///
+    /// In dry-run mode, root mutation fields declaring a `validate_only`
+    /// function (via `#[graphql(validate_only = path::to::fn)]`) are resolved
+    /// with it instead of their regular resolver, so clients are able to
+    /// pre-validate complex mutations without causing any side effects. Fields
+    /// without a `validate_only` function are resolved as usual, and may check
+    /// [`Executor::is_dry_run()`] by themselves. Queries and subscriptions are
+    /// not affected by this mode.
+    ///
+    /// A single mutation may also be executed in dry-run mode by marking it
+    /// with the `@dryRun` directive, once it's enabled in a schema via
+    /// [`RootNode::enable_dry_run_directive()`].
+    ///
+    /// [`Executor::is_dry_run()`]: crate::Executor::is_dry_run
+    /// [`RootNode::enable_dry_run_directive()`]: crate::RootNode::enable_dry_run_directive
+    #[must_use]
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Indicates whether dry-run mode is enabled.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Sets whether sibling fields should be resolved concurrently.
+    ///
+    /// By default, the fragments having a type condition are resolved one
+    /// after another, before their sibling fields. Enabling this option makes
+    /// them resolved concurrently with all their siblings, so the latency of a
+    /// wide selection is bound by its slowest resolver rather than by a sum of
+    /// them.
+    ///
+    /// The root fields of a mutation are always resolved serially, as required
+    /// by the [spec][0], regardless of this option.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Mutation
+    #[must_use]
+    pub fn concurrent_fields(mut self, concurrent: bool) -> Self {
+        self.concurrent_fields = concurrent;
+        self
+    }
+
+    /// Indicates whether sibling fields are resolved concurrently.
+    pub fn resolves_fields_concurrently(&self) -> bool {
+        self.concurrent_fields
+    }
+
+    /// Sets whether all the fields of a mutation (not only its root ones)
+    /// should be resolved one after another, in the order of their
+    /// declaration in the operation.
+    ///
+    /// The [spec][0] requires serial execution of the root fields of a
+    /// mutation only, while the fields nested into them (and the items of
+    /// lists) are resolved concurrently. Enable this option when the side
+    /// effects of the nested resolvers rely on their ordering too.
+    ///
+    /// Has no effect on queries and subscriptions, or on a synchronous
+    /// execution (which is always serial).
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Mutation
+    #[must_use]
+    pub fn serial_mutation_subtree(mut self, serial: bool) -> Self {
+        self.serial_mutation_subtree = serial;
+        self
+    }
+
+    /// Indicates whether all the fields of a mutation are resolved one after
+    /// another.
+    pub fn resolves_mutation_subtree_serially(&self) -> bool {
+        self.serial_mutation_subtree
+    }
+
+    /// Sets the [`Clock`] to be used by time-dependent features.
+    ///
+    /// Defaults to the [`SystemClock`].
+    ///
+    /// [`SystemClock`]: crate::SystemClock
+    #[must_use]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Sets the shared [`Clock`] to be used by time-dependent features.
+    ///
+    /// Useful for keeping a handle to a [`MockClock`] to advance it in tests.
+    ///
+    /// [`MockClock`]: crate::MockClock
+    #[must_use]
+    pub fn shared_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the [`Clock`] to be used by time-dependent features.
+    pub fn get_clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Sets the [`Rng`] to be used by features requiring randomness.
+    ///
+    /// Defaults to a randomly seeded [`SplitMixRng`].
+    ///
+    /// [`SplitMixRng`]: crate::SplitMixRng
+    #[must_use]
+    pub fn rng(mut self, rng: impl Rng + 'static) -> Self {
+        self.rng = Arc::new(rng);
+        self
+    }
+
+    /// Returns the [`Rng`] to be used by features requiring randomness.
+    pub fn get_rng(&self) -> &Arc<dyn Rng> {
+        &self.rng
+    }
+
+    /// Sets the [`Timer`] to be used by time-based features.
+    ///
+    /// Defaults to the [`runtime_timer()`], if any.
+    ///
+    /// [`runtime_timer()`]: crate::runtime_timer
+    #[must_use]
+    pub fn timer(mut self, timer: impl Timer + 'static) -> Self {
+        self.timer = Some(Arc::new(timer));
+        self
+    }
+
+    /// Returns the [`Timer`] to be used by time-based features, if any.
+    pub fn get_timer(&self) -> Option<&Arc<dyn Timer>> {
+        self.timer.as_ref()
+    }
+
+    /// Sets the maximum duration of an asynchronous execution of an operation.
+    ///
+    /// Once the `timeout` elapses, all the resolvers still running are
+    /// cancelled, and their fields are completed with a timeout
+    /// [`FieldError`], so the already resolved data is returned along with
+    /// them, instead of holding the whole response.
+    ///
+    /// Has no effect on a synchronous execution.
+    ///
+    /// # Warning
+    ///
+    /// The `timeout` is enforced by a [`Timer`] only, and is __silently
+    /// ignored__ without one. A [`Timer`] is available by default only if the
+    /// `tokio` or `async-std` [Cargo feature] is enabled (see
+    /// [`runtime_timer()`]), otherwise it should be set explicitly via
+    /// [`ExecutionOptions::timer()`]. Use [`ExecutionOptions::get_timer()`] to
+    /// check whether the `timeout` is going to be enforced.
+    ///
+    /// [`FieldError`]: crate::FieldError
+    /// [`runtime_timer()`]: crate::runtime_timer
+    /// [Cargo feature]: https://doc.rust-lang.org/cargo/reference/features.html
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the maximum duration of an asynchronous execution of an
+    /// operation, if any.
+    pub fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Sets the [`Correlation`] of the executed request with the logs of its
+    /// origin, exposed to resolvers via [`Executor::request_id()`] and
+    /// [`Executor::trace_context()`].
+    ///
+    /// [`Executor::request_id()`]: crate::Executor::request_id
+    /// [`Executor::trace_context()`]: crate::Executor::trace_context
+    #[must_use]
+    pub fn correlation(mut self, correlation: Correlation) -> Self {
+        self.correlation = (!correlation.is_empty()).then(|| Arc::new(correlation));
+        self
+    }
+
+    /// Returns the [`Correlation`] of the executed request, if any.
+    pub fn get_correlation(&self) -> Option<&Correlation> {
+        self.correlation.as_deref()
+    }
+
+    /// Sets whether the `extensions` of every [`FieldError`] should be
+    /// stamped with the [request ID](Correlation::request_id), so errors
+    /// reported by clients can be matched to the server logs.
+    ///
+    /// [`FieldError`]: crate::FieldError
+    #[must_use]
+    pub fn stamp_request_id(mut self, stamp: bool) -> Self {
+        self.stamp_request_id = stamp;
+        self
+    }
+
+    /// Indicates whether the `extensions` of every [`FieldError`] are stamped
+    /// with the [request ID](Correlation::request_id).
+    ///
+    /// [`FieldError`]: crate::FieldError
+    pub fn stamps_errors_with_request_id(&self) -> bool {
+        self.stamp_request_id
+    }
+
+    /// Sets whether the locations of the errors in a [`GraphQLResponse`] are
+    /// serialized along with their byte offsets in the input source and, if
+    /// known, their end positions.
+    ///
+    /// See [`GraphQLResponse::with_source_ranges()`] for details.
+    ///
+    /// [`GraphQLResponse`]: crate::http::GraphQLResponse
+    /// [`GraphQLResponse::with_source_ranges()`]: crate::http::GraphQLResponse::with_source_ranges
+    #[must_use]
+    pub fn source_ranges(mut self, enabled: bool) -> Self {
+        self.source_ranges = enabled;
+        self
+    }
+
+    /// Indicates whether the locations of the errors in a [`GraphQLResponse`]
+    /// are serialized along with their source ranges.
+    ///
+    /// [`GraphQLResponse`]: crate::http::GraphQLResponse
+    pub fn includes_source_ranges(&self) -> bool {
+        self.source_ranges
+    }
+
+    /// Sets the [`ResponseExtensions`] collecting the entries of the
+    /// `extensions` object of the response, stashed by resolvers via
+    /// [`Executor::add_response_extension()`].
+    ///
+    /// [`Executor::add_response_extension()`]: crate::Executor::add_response_extension
+    #[must_use]
+    pub fn response_extensions(mut self, extensions: Arc<ResponseExtensions>) -> Self {
+        self.response_extensions = Some(extensions);
+        self
+    }
+
+    /// Returns the [`ResponseExtensions`] of the executed request, if any.
+    pub fn get_response_extensions(&self) -> Option<&ResponseExtensions> {
+        self.response_extensions.as_deref()
+    }
+
+    /// Sets the [`CancellationToken`] of the executed operation, exposed to
+    /// resolvers via [`Executor::cancellation_token()`].
+    ///
+    /// Once it's cancelled, all the resolvers of an asynchronous execution
+    /// still running are dropped, and their fields are completed with a
+    /// cancellation [`FieldError`]. Resolvers of a synchronous execution are
+    /// expected to check [`Executor::is_cancelled()`] by themselves.
+    ///
+    /// Should be set per request, as every execution sharing the same
+    /// [`ExecutionOptions`] is cancelled along.
+    ///
+    /// [`Executor::cancellation_token()`]: crate::Executor::cancellation_token
+    /// [`Executor::is_cancelled()`]: crate::Executor::is_cancelled
+    /// [`FieldError`]: crate::FieldError
+    #[must_use]
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Returns the [`CancellationToken`] of the executed operation, if any.
+    pub fn get_cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    /// Sets the maximum depth of fields nested into each other an operation
+    /// is executed to.
+    ///
+    /// Execution of a field nested deeper is not even started, and the field is
+    /// completed with a [`FieldError`] instead, so a pathologically deep
+    /// response cannot overflow the stack of the executing thread.
+    ///
+    /// Defaults to `256`. Consider lowering it when executing on threads with
+    /// small stacks, or limiting the depth of accepted operations upfront via
+    /// [`RootNode::with_max_depth()`].
+    ///
+    /// [`FieldError`]: crate::FieldError
+    /// [`RootNode::with_max_depth()`]: crate::RootNode::with_max_depth
+    #[must_use]
+    pub fn max_recursion_depth(mut self, depth: usize) -> Self {
+        self.max_recursion_depth = depth;
+        self
+    }
+
+    /// Returns the maximum depth of fields nested into each other an
+    /// operation is executed to.
+    pub fn get_max_recursion_depth(&self) -> usize {
+        self.max_recursion_depth
+    }
+
+    /// Sets the [`NullPropagation`] behavior on field errors.
+    ///
+    /// Defaults to [`NullPropagation::Bubble`], as the [spec][0] requires.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Handling-Field-Errors
+    #[must_use]
+    pub fn null_propagation(mut self, mode: NullPropagation) -> Self {
+        self.null_propagation = mode;
+        self
+    }
+
+    /// Returns the [`NullPropagation`] behavior on field errors.
+    pub fn get_null_propagation(&self) -> NullPropagation {
+        self.null_propagation
+    }
+
+    /// Sets whether operations are allowed to select the `__schema` and
+    /// `__type` introspection fields, overriding the
+    /// [`RootNode::disable_introspection()`] setting of the schema.
+    ///
+    /// Useful for letting trusted clients (like internal tooling)
+    /// introspect a schema having introspection disabled in production, or
+    /// the other way around.
+    ///
+    /// [`RootNode::disable_introspection()`]: crate::RootNode::disable_introspection
+    #[must_use]
+    pub fn introspection(mut self, enabled: bool) -> Self {
+        self.introspection = Some(enabled);
+        self
+    }
+
+    /// Returns whether introspection is explicitly enabled or disabled by
+    /// these [`ExecutionOptions`], if it is.
+    pub fn get_introspection(&self) -> Option<bool> {
+        self.introspection
+    }
+
+    /// Registers the provided [`Extension`], being notified about the
+    /// lifecycle of every executed operation.
+    ///
+    /// [`Extension`]s are notified in the order of their registration.
+    #[must_use]
+    pub fn extension(mut self, extension: impl Extension + 'static) -> Self {
+        self.extensions.push(Arc::new(extension));
+        self
+    }
+
+    /// Returns the registered [`Extension`]s.
+    pub fn get_extensions(&self) -> &[Arc<dyn Extension>] {
+        &self.extensions
+    }
+
+    /// Registers the provided [`Instrumentation`], receiving the metrics of
+    /// every resolved field.
+    ///
+    /// [`Instrumentation`]s are notified in the order of their registration.
+    #[must_use]
+    pub fn instrumentation(mut self, instrumentation: impl Instrumentation + 'static) -> Self {
+        self.instrumentations.push(Arc::new(instrumentation));
+        self
+    }
+
+    /// Returns the registered [`Instrumentation`]s.
+    pub fn get_instrumentations(&self) -> &[Arc<dyn Instrumentation>] {
+        &self.instrumentations
+    }
+
+    /// Inserts the provided `value` into the [`Data`] exposed to resolvers via
+    /// [`Executor::data()`], replacing the value of the same type, if any.
+    ///
+    /// [`Executor::data()`]: crate::Executor::data
+    #[must_use]
+    pub fn data<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.data.insert(value);
+        self
+    }
+
+    /// Returns the [`Data`] exposed to resolvers.
+    pub fn get_data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Returns the [`Data`] exposed to resolvers for modification.
+    pub fn data_mut(&mut self) -> &mut Data {
+        &mut self.data
+    }
+
+    /// Sets the stage of an incremental execution, `@defer`red fragments are
+    /// resolved in.
+    #[must_use]
+    pub(super) fn defer(mut self, mode: DeferMode) -> Self {
+        self.defer = mode;
+        self
+    }
+
+    /// Sets the [`LiveDependencies`] collecting the keys of the data a live
+    /// query depends on.
+    #[must_use]
+    pub(super) fn live(mut self, dependencies: Arc<LiveDependencies>) -> Self {
+        self.live = Some(dependencies);
+        self
+    }
+
+    /// Returns the [`LiveDependencies`] of the executed live query, if any.
+    pub(super) fn get_live(&self) -> Option<&LiveDependencies> {
+        self.live.as_deref()
+    }
+
+    /// Merges these [`ExecutionOptions`] with the ones requested by the
+    /// provided [`Operation`] via its directives.
+    pub(crate) fn for_operation<S>(&self, operation: &Operation<S>) -> Cow<'_, Self> {
+        let requests_dry_run = operation
+            .directives
+            .iter()
+            .flatten()
+            .any(|d| d.item.name.item == DRY_RUN_DIRECTIVE);
+        let is_mutation = operation.operation_type == OperationType::Mutation;
+        // Only mutations may be dry-run, while `@dryRun` directive on other
+        // operations is rejected by validation.
+        let dry_run = is_mutation && (self.dry_run || requests_dry_run);
+
+        if dry_run != self.dry_run || (is_mutation && !self.serial_root) {
+            let mut options = self.clone();
+            options.dry_run = dry_run;
+            options.serial_root = is_mutation;
+            Cow::Owned(options)
+        } else {
+            Cow::Borrowed(self)
+        }
+    }
+
+    /// Starts the [`Deadline`] of an operation execution, if a
+    /// [timeout](ExecutionOptions::timeout) is set.
+    ///
+    /// The timeout is ignored if there is no [`Timer`] to enforce it, as
+    /// documented on [`ExecutionOptions::timeout()`].
+    pub(super) fn start_deadline(options: Cow<'_, Self>) -> Cow<'_, Self> {
+        match (options.timeout, &options.timer) {
+            (Some(timeout), Some(timer)) if options.deadline.is_none() => {
+                let deadline = Deadline::start(&**timer, timeout);
+                let mut options = options.into_owned();
+                options.deadline = Some(deadline);
+                Cow::Owned(options)
+            }
+            _ => options,
+        }
+    }
+}
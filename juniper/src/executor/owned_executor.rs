@@ -5,7 +5,7 @@ use std::{
 
 use crate::{
     ast::Fragment,
-    executor::FieldPath,
+    executor::{ExecutionOptions, FieldPath},
     parser::SourcePosition,
     schema::model::{SchemaType, TypeType},
     ExecutionError, Executor, Selection, Variables,
@@ -23,6 +23,7 @@ pub struct OwnedExecutor<'a, CtxT, S> {
     pub(super) context: &'a CtxT,
     pub(super) errors: RwLock<Vec<ExecutionError<S>>>,
     pub(super) field_path: Arc<FieldPath<'a>>,
+    pub(super) options: ExecutionOptions,
 }
 
 impl<'a, CtxT, S> Clone for OwnedExecutor<'a, CtxT, S>
@@ -40,6 +41,7 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: self.field_path.clone(),
+            options: self.options.clone(),
         }
     }
 }
@@ -68,6 +70,7 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: self.field_path.clone(),
+            options: self.options.clone(),
         }
     }
 
@@ -106,6 +109,7 @@ where
                 location,
                 Arc::clone(&self.field_path),
             )),
+            options: self.options.clone(),
         }
     }
 
@@ -121,6 +125,8 @@ where
             context: self.context,
             errors: &self.errors,
             field_path: Arc::clone(&self.field_path),
+            options: &self.options,
+            spread_fragments: None,
         }
     }
 }
@@ -0,0 +1,81 @@
+//! Response-level `extensions` stashed by resolvers.
+
+use std::{any::Any, fmt, sync::Mutex};
+
+use crate::value::{Object, ScalarValue, Value};
+
+/// Collector of the entries of the `extensions` object of a response, stashed
+/// by resolvers via [`Executor::add_response_extension()`].
+///
+/// Executing a [`GraphQLRequest`] collects its entries automatically into the
+/// returned [`GraphQLResponse`]. Operations executed directly should register
+/// a [`ResponseExtensions`] via [`ExecutionOptions::response_extensions()`],
+/// and [take](ResponseExtensions::take) its entries once done. Should be
+/// registered per request, as every execution sharing it stashes its entries
+/// along.
+///
+/// ```rust
+/// # use juniper::{graphql_value, DefaultScalarValue, ResponseExtensions};
+/// #
+/// let extensions = ResponseExtensions::new();
+/// extensions.insert::<DefaultScalarValue>("cost", graphql_value!({"total": 3}));
+///
+/// let object = extensions.take::<DefaultScalarValue>();
+/// assert_eq!(object.get_field_value("cost"), Some(&graphql_value!({"total": 3})));
+/// assert_eq!(extensions.take::<DefaultScalarValue>().field_count(), 0);
+/// ```
+///
+/// [`ExecutionOptions::response_extensions()`]: crate::ExecutionOptions::response_extensions
+/// [`Executor::add_response_extension()`]: crate::Executor::add_response_extension
+/// [`GraphQLRequest`]: crate::http::GraphQLRequest
+/// [`GraphQLResponse`]: crate::http::GraphQLResponse
+#[derive(Default)]
+pub struct ResponseExtensions {
+    /// Stashed entries, with their values being type-erased [`Value`]s, so
+    /// this collector doesn't depend on the [`ScalarValue`] of an operation.
+    entries: Mutex<Vec<(String, Box<dyn Any + Send>)>>,
+}
+
+impl fmt::Debug for ResponseExtensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseExtensions")
+            .field("len", &self.entries.lock().unwrap().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResponseExtensions {
+    /// Creates a new empty [`ResponseExtensions`] collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stashes the `name`d entry with the provided `value`.
+    ///
+    /// An entry stashed multiple times ends up with its last `value`.
+    pub fn insert<S>(&self, name: impl Into<String>, value: Value<S>)
+    where
+        S: ScalarValue + Send,
+    {
+        self.entries
+            .lock()
+            .unwrap()
+            .push((name.into(), Box::new(value)));
+    }
+
+    /// Takes all the entries stashed so far as an [`Object`], leaving this
+    /// collector empty.
+    ///
+    /// Entries stashed with a [`ScalarValue`] other than `S` are skipped.
+    pub fn take<S: ScalarValue>(&self) -> Object<S> {
+        let entries = std::mem::take(&mut *self.entries.lock().unwrap());
+        let mut object = Object::with_capacity(entries.len());
+        for (name, value) in entries {
+            if let Ok(value) = value.downcast::<Value<S>>() {
+                object.add_field(name, *value);
+            }
+        }
+        object
+    }
+}
@@ -0,0 +1,94 @@
+//! Source of randomness used during execution.
+
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    hash::{BuildHasher as _, Hasher as _},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Source of randomness for the features of an [`Executor`] requiring it
+/// (like retry jitter).
+///
+/// Replace the default randomly seeded [`SplitMixRng`] with a
+/// [`SplitMixRng::with_seed()`] one (via [`ExecutionOptions::rng()`]) to drive
+/// such features deterministically in tests.
+///
+/// [`ExecutionOptions::rng()`]: crate::ExecutionOptions::rng
+/// [`Executor`]: crate::Executor
+pub trait Rng: fmt::Debug + Send + Sync {
+    /// Returns the next random [`u64`].
+    fn next_u64(&self) -> u64;
+
+    /// Returns the next random [`f64`] in the `[0, 1)` range.
+    fn next_f64(&self) -> f64 {
+        // Take the 53 most significant bits to fill the mantissa.
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Thread-safe [`Rng`] implementing the [SplitMix64][1] algorithm.
+///
+/// It's fast and has a reasonable quality for non-cryptographic purposes.
+///
+/// [1]: https://prng.di.unimi.it/splitmix64.c
+#[derive(Debug)]
+pub struct SplitMixRng {
+    state: AtomicU64,
+}
+
+impl SplitMixRng {
+    /// Creates a new [`SplitMixRng`] producing the same sequence of numbers
+    /// for the same `seed`.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+}
+
+impl Default for SplitMixRng {
+    /// Creates a new randomly seeded [`SplitMixRng`].
+    fn default() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(0);
+        Self::with_seed(hasher.finish())
+    }
+}
+
+impl Rng for SplitMixRng {
+    fn next_u64(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(0x9e37_79b9_7f4a_7c15, Ordering::Relaxed)
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Rng as _, SplitMixRng};
+
+    #[test]
+    fn is_deterministic_for_seed() {
+        let (a, b) = (SplitMixRng::with_seed(42), SplitMixRng::with_seed(42));
+
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn produces_f64_in_unit_range() {
+        let rng = SplitMixRng::with_seed(7);
+
+        for _ in 0..1000 {
+            let n = rng.next_f64();
+            assert!((0.0..1.0).contains(&n), "{n} is out of `[0, 1)` range");
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Runtime-agnostic timers used by time-based features.
+
+use std::{fmt, future::Future, sync::Arc, time::Duration};
+
+use futures::future::{self, BoxFuture, Either, FutureExt as _, Shared};
+
+/// Source of delays for the time-based features (like keep-alives of
+/// subscriptions), abstracting them from a concrete async runtime.
+///
+/// Implementations for [Tokio] and [`async-std`] runtimes are provided behind
+/// the `tokio` and `async-std` [Cargo features] respectively, and one of them
+/// is used by default (see [`runtime_timer()`]). Implement this trait to use
+/// any other async runtime.
+///
+/// [`async-std`]: https://docs.rs/async-std
+/// [Cargo features]: https://doc.rust-lang.org/cargo/reference/features.html
+/// [Tokio]: https://docs.rs/tokio
+pub trait Timer: fmt::Debug + Send + Sync {
+    /// Returns a [`Future`] resolving once the provided `duration` elapses.
+    ///
+    /// [`Future`]: std::future::Future
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+impl<T: Timer + ?Sized> Timer for Arc<T> {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        (**self).sleep(duration)
+    }
+}
+
+/// [`Timer`] backed by the [Tokio] runtime.
+///
+/// [Tokio]: https://docs.rs/tokio
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioTimer;
+
+#[cfg(feature = "tokio")]
+impl Timer for TokioTimer {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// [`Timer`] backed by the [`async-std`] runtime.
+///
+/// [`async-std`]: https://docs.rs/async-std
+#[cfg(feature = "async-std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-std")))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsyncStdTimer;
+
+#[cfg(feature = "async-std")]
+impl Timer for AsyncStdTimer {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(async_std::task::sleep(duration))
+    }
+}
+
+/// Returns the [`Timer`] of the async runtime enabled via [Cargo features], if
+/// any.
+///
+/// [`TokioTimer`] is preferred over [`AsyncStdTimer`] in case both the `tokio`
+/// and `async-std` [Cargo features] are enabled.
+///
+/// [Cargo features]: https://doc.rust-lang.org/cargo/reference/features.html
+#[must_use]
+pub fn runtime_timer() -> Option<Arc<dyn Timer>> {
+    #[cfg(feature = "tokio")]
+    {
+        Some(Arc::new(TokioTimer))
+    }
+    #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+    {
+        Some(Arc::new(AsyncStdTimer))
+    }
+    #[cfg(not(any(feature = "async-std", feature = "tokio")))]
+    {
+        None
+    }
+}
+
+/// Deadline of an operation execution, shared by all its resolvers.
+#[derive(Clone)]
+pub(crate) struct Deadline(Shared<BoxFuture<'static, ()>>);
+
+impl fmt::Debug for Deadline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Deadline").finish_non_exhaustive()
+    }
+}
+
+impl Deadline {
+    /// Starts a new [`Deadline`] elapsing after the provided `timeout`.
+    pub(crate) fn start(timer: &dyn Timer, timeout: Duration) -> Self {
+        Self(timer.sleep(timeout).shared())
+    }
+
+    /// Runs the provided `fut`ure until it completes, or this [`Deadline`]
+    /// elapses, in which case the `fut`ure is dropped and [`None`] returned.
+    pub(crate) async fn run<F: Future>(&self, fut: F) -> Option<F::Output> {
+        futures::pin_mut!(fut);
+        match future::select(fut, self.0.clone()).await {
+            Either::Left((out, _)) => Some(out),
+            Either::Right(_) => None,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::time::{Duration, SystemTime};
+
+use serde_json::json;
+
+use crate::{
+    graphql_object,
+    http::GraphQLRequest,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    ExecutionOptions, InputValue, MockClock,
+};
+
+struct User;
+
+#[graphql_object]
+impl User {
+    fn name() -> &'static str {
+        "user"
+    }
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn me() -> User {
+        User
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    let clock = MockClock::new(SystemTime::UNIX_EPOCH + Duration::from_millis(1_501_251_632_106));
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+        .with_execution_options(ExecutionOptions::default().clock(clock))
+}
+
+fn request(tracing: bool) -> GraphQLRequest {
+    let mut req = GraphQLRequest::new("{ me { name } }".into(), None, None);
+    req.extensions = Some(InputValue::object(
+        [("tracing", InputValue::scalar(tracing))]
+            .into_iter()
+            .collect(),
+    ));
+    req
+}
+
+#[tokio::test]
+async fn emits_tracing_extension_when_requested() {
+    let schema = schema();
+
+    let resp = request(true).execute(&schema, &()).await;
+
+    assert!(resp.is_ok());
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        json!({
+            "data": {"me": {"name": "user"}},
+            "extensions": {
+                "tracing": {
+                    "version": 1,
+                    "startTime": "2017-07-28T14:20:32.106Z",
+                    "endTime": "2017-07-28T14:20:32.106Z",
+                    "duration": 0,
+                    "parsing": {"startOffset": 0, "duration": 0},
+                    "validation": {"startOffset": 0, "duration": 0},
+                    "execution": {
+                        "resolvers": [{
+                            "path": ["me", "name"],
+                            "parentType": "User",
+                            "fieldName": "name",
+                            "returnType": "String!",
+                            "startOffset": 0,
+                            "duration": 0,
+                        }, {
+                            "path": ["me"],
+                            "parentType": "Query",
+                            "fieldName": "me",
+                            "returnType": "User!",
+                            "startOffset": 0,
+                            "duration": 0,
+                        }],
+                    },
+                },
+            },
+        }),
+    );
+}
+
+#[test]
+fn emits_tracing_extension_synchronously() {
+    let schema = schema();
+
+    let resp = request(true).execute_sync(&schema, &());
+
+    let json = serde_json::to_value(&resp).unwrap();
+    assert_eq!(json["extensions"]["tracing"]["version"], json!(1));
+    assert_eq!(
+        json["extensions"]["tracing"]["execution"]["resolvers"]
+            .as_array()
+            .map(Vec::len),
+        Some(2),
+    );
+}
+
+#[tokio::test]
+async fn omits_tracing_extension_unless_requested() {
+    let schema = schema();
+
+    let resp = request(false).execute(&schema, &()).await;
+
+    assert_eq!(resp.extensions().field_count(), 0);
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        json!({"data": {"me": {"name": "user"}}}),
+    );
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use futures::future;
+
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    parser::SourcePosition,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    CancellationToken, ExecutionError, ExecutionOptions, Executor, FieldError, ScalarValue,
+};
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn fast() -> i32 {
+        1
+    }
+
+    async fn slow() -> Option<i32> {
+        future::pending::<()>().await;
+        Some(2)
+    }
+
+    fn cancelled<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> bool {
+        executor.is_cancelled()
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn reports_cancelled_fields() {
+    const DOC: &str = "{ fast slow }";
+
+    let schema = schema();
+    let token = CancellationToken::new();
+    let options = ExecutionOptions::new().cancellation_token(token.clone());
+
+    let (res, _) = tokio::time::timeout(
+        Duration::from_secs(2),
+        future::join(
+            crate::execute_with_options(DOC, None, &schema, &graphql_vars! {}, &(), &options),
+            async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                token.cancel();
+            },
+        ),
+    )
+    .await
+    .expect("execution should not hang");
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!({"fast": 1, "slow": null}),
+            vec![ExecutionError::new(
+                SourcePosition::new(7, 0, 7),
+                &["slow"],
+                FieldError::from("Execution cancelled"),
+            )],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn exposes_cancellation_to_resolvers() {
+    const DOC: &str = "{ cancelled }";
+
+    let schema = schema();
+
+    assert_eq!(
+        crate::execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+        Ok((graphql_value!({"cancelled": false}), vec![])),
+    );
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let options = ExecutionOptions::new().cancellation_token(token);
+
+    assert_eq!(
+        crate::execute_sync_with_options(DOC, None, &schema, &graphql_vars! {}, &(), &options),
+        Ok((graphql_value!({"cancelled": true}), vec![])),
+    );
+}
@@ -0,0 +1,229 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use crate::{
+    graphql_object, graphql_value, graphql_vars, schema::model::RootNode,
+    types::scalars::EmptySubscription, ExecutionOptions,
+};
+
+#[derive(Default)]
+struct Context {
+    started: AtomicUsize,
+    resolved: Mutex<Vec<&'static str>>,
+}
+
+impl crate::Context for Context {}
+
+impl Context {
+    /// Waits until the provided number of resolvers is started.
+    async fn rendezvous(&self, count: usize) {
+        self.started.fetch_add(1, Ordering::SeqCst);
+        while self.started.load(Ordering::SeqCst) < count {
+            tokio::task::yield_now().await;
+        }
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    async fn a(ctx: &Context) -> i32 {
+        ctx.rendezvous(2).await;
+        1
+    }
+
+    async fn b(ctx: &Context) -> i32 {
+        ctx.rendezvous(2).await;
+        2
+    }
+}
+
+struct Mutation;
+
+#[graphql_object(context = Context)]
+impl Mutation {
+    async fn slow(ctx: &Context) -> bool {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        ctx.resolved.lock().unwrap().push("slow");
+        true
+    }
+
+    async fn fast(ctx: &Context) -> bool {
+        ctx.resolved.lock().unwrap().push("fast");
+        true
+    }
+
+    fn batch() -> Batch {
+        Batch
+    }
+
+    fn steps() -> Vec<Step> {
+        vec![Step("slow", 50), Step("fast", 0)]
+    }
+}
+
+struct Batch;
+
+#[graphql_object(context = Context)]
+impl Batch {
+    async fn slow(ctx: &Context) -> bool {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        ctx.resolved.lock().unwrap().push("slow");
+        true
+    }
+
+    async fn fast(ctx: &Context) -> bool {
+        ctx.resolved.lock().unwrap().push("fast");
+        true
+    }
+}
+
+struct Step(&'static str, u64);
+
+#[graphql_object(context = Context)]
+impl Step {
+    async fn run(&self, ctx: &Context) -> bool {
+        tokio::time::sleep(Duration::from_millis(self.1)).await;
+        ctx.resolved.lock().unwrap().push(self.0);
+        true
+    }
+}
+
+fn schema() -> RootNode<'static, Query, Mutation, EmptySubscription<Context>> {
+    RootNode::new(Query, Mutation, EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn resolves_fragments_concurrently() {
+    const DOC: &str = r#"{
+        ... on Query { a }
+        ... on Query { b }
+    }"#;
+
+    let schema = schema();
+    let ctx = Context::default();
+    let options = ExecutionOptions::new().concurrent_fields(true);
+
+    let res = tokio::time::timeout(
+        Duration::from_secs(5),
+        crate::execute_with_options(DOC, None, &schema, &graphql_vars! {}, &ctx, &options),
+    )
+    .await
+    .expect("fragments are not resolved concurrently");
+
+    assert_eq!(res, Ok((graphql_value!({"a": 1, "b": 2}), vec![])));
+}
+
+#[tokio::test]
+async fn resolves_mutation_fields_serially() {
+    const DOC: &str = r#"mutation {
+        slow
+        fast
+    }"#;
+
+    let schema = schema();
+    let ctx = Context::default();
+    let options = ExecutionOptions::new().concurrent_fields(true);
+
+    let res =
+        crate::execute_with_options(DOC, None, &schema, &graphql_vars! {}, &ctx, &options).await;
+
+    assert_eq!(
+        res,
+        Ok((graphql_value!({"slow": true, "fast": true}), vec![]))
+    );
+    assert_eq!(*ctx.resolved.lock().unwrap(), ["slow", "fast"]);
+}
+
+#[tokio::test]
+async fn resolves_nested_mutation_fields_concurrently_by_default() {
+    const DOC: &str = r#"mutation {
+        batch { slow fast }
+    }"#;
+
+    let schema = schema();
+    let ctx = Context::default();
+
+    let res = crate::execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await;
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!({"batch": {"slow": true, "fast": true}}),
+            vec![]
+        )),
+    );
+    assert_eq!(*ctx.resolved.lock().unwrap(), ["fast", "slow"]);
+}
+
+#[tokio::test]
+async fn resolves_nested_mutation_fields_serially_if_requested() {
+    const DOC: &str = r#"mutation {
+        batch { slow fast }
+    }"#;
+
+    let schema = schema();
+    let ctx = Context::default();
+    let options = ExecutionOptions::new().serial_mutation_subtree(true);
+
+    let res =
+        crate::execute_with_options(DOC, None, &schema, &graphql_vars! {}, &ctx, &options).await;
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!({"batch": {"slow": true, "fast": true}}),
+            vec![]
+        )),
+    );
+    assert_eq!(*ctx.resolved.lock().unwrap(), ["slow", "fast"]);
+}
+
+#[tokio::test]
+async fn resolves_mutation_list_items_serially_if_requested() {
+    const DOC: &str = r#"mutation {
+        steps { run }
+    }"#;
+
+    let schema = schema();
+    let ctx = Context::default();
+    let options = ExecutionOptions::new()
+        .concurrent_fields(true)
+        .serial_mutation_subtree(true);
+
+    let res =
+        crate::execute_with_options(DOC, None, &schema, &graphql_vars! {}, &ctx, &options).await;
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!({"steps": [{"run": true}, {"run": true}]}),
+            vec![]
+        )),
+    );
+    assert_eq!(*ctx.resolved.lock().unwrap(), ["slow", "fast"]);
+}
+
+#[tokio::test]
+async fn resolves_query_fields_concurrently_regardless_of_serial_mutation_subtree() {
+    const DOC: &str = r#"{ a b }"#;
+
+    let schema = schema();
+    let ctx = Context::default();
+    let options = ExecutionOptions::new().serial_mutation_subtree(true);
+
+    let res = tokio::time::timeout(
+        Duration::from_secs(5),
+        crate::execute_with_options(DOC, None, &schema, &graphql_vars! {}, &ctx, &options),
+    )
+    .await
+    .expect("query fields are not resolved concurrently");
+
+    assert_eq!(res, Ok((graphql_value!({"a": 1, "b": 2}), vec![])));
+}
@@ -0,0 +1,156 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    ast::{InputValue, Type},
+    graphql_object, graphql_value, graphql_vars,
+    schema::{
+        meta::Argument,
+        model::{DirectiveLocation, DirectiveType, RootNode},
+    },
+    types::scalars::{EmptyMutation, EmptySubscription},
+    AppliedDirective, DirectiveHandler, ExecutionResult, GraphQLError, ScalarValue as _, Value,
+};
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn name() -> &'static str {
+        "juniper"
+    }
+
+    fn expensive() -> i32 {
+        panic!("must not be resolved")
+    }
+}
+
+struct Uppercase;
+
+impl DirectiveHandler for Uppercase {
+    fn after(&self, _: &AppliedDirective<'_>, result: ExecutionResult) -> ExecutionResult {
+        Ok(match result? {
+            Value::Scalar(s) => match s.as_str() {
+                Some(s) => Value::scalar(s.to_uppercase()),
+                None => Value::Scalar(s),
+            },
+            v => v,
+        })
+    }
+}
+
+/// Short-circuits fields with the `value` argument, recording the paths of
+/// the short-circuited fields.
+#[derive(Default)]
+struct Stub(Mutex<Vec<Vec<String>>>);
+
+impl DirectiveHandler for Stub {
+    fn before(&self, directive: &AppliedDirective<'_>) -> Option<ExecutionResult> {
+        self.0.lock().unwrap().push(directive.field.path.to_vec());
+        let value = directive.arguments.get::<i32>("value");
+        Some(value.map(|v| Value::scalar(v.unwrap_or_default())))
+    }
+}
+
+fn schema(stub: Arc<Stub>) -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+        .with_directive(
+            DirectiveType::new("uppercase", &[DirectiveLocation::Field], &[], false),
+            Uppercase,
+        )
+        .with_directive(
+            DirectiveType::new(
+                "stub",
+                &[DirectiveLocation::Field],
+                &[Argument::new("value", Type::Named("Int".into()))
+                    .default_value(InputValue::scalar(42))],
+                false,
+            ),
+            stub,
+        )
+}
+
+#[tokio::test]
+async fn transforms_resolved_values() {
+    let schema = schema(Arc::default());
+
+    let res = crate::execute(
+        "{ name @uppercase plain: name }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .await;
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!({"name": "JUNIPER", "plain": "juniper"}),
+            vec![]
+        )),
+    );
+}
+
+#[test]
+fn short_circuits_fields() {
+    let stub = Arc::new(Stub::default());
+    let schema = schema(Arc::clone(&stub));
+
+    let res = crate::execute_sync(
+        "query($v: Int) { a: expensive @stub b: expensive @stub(value: $v) }",
+        None,
+        &schema,
+        &graphql_vars! {"v": 7},
+        &(),
+    );
+
+    assert_eq!(res, Ok((graphql_value!({"a": 42, "b": 7}), vec![])));
+    assert_eq!(
+        *stub.0.lock().unwrap(),
+        [vec!["a".to_owned()], vec!["b".to_owned()]],
+    );
+}
+
+#[tokio::test]
+async fn skips_handlers_of_short_circuited_fields() {
+    let schema = schema(Arc::default());
+
+    let res = crate::execute(
+        "{ expensive @uppercase @stub(value: 1) }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .await;
+
+    assert_eq!(res, Ok((graphql_value!({"expensive": 1}), vec![])));
+}
+
+#[test]
+fn rejects_misplaced_directives() {
+    let schema = schema(Arc::default());
+
+    let res = crate::execute_sync(
+        "query @uppercase { name }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    );
+
+    assert!(
+        matches!(res, Err(GraphQLError::ValidationError(ref errs)) if errs.len() == 1),
+        "{res:?}",
+    );
+}
+
+#[test]
+fn registers_directives_in_schema() {
+    let schema = schema(Arc::default());
+
+    let stub = schema.schema.directive_by_name("stub").unwrap();
+    assert_eq!(stub.locations, [DirectiveLocation::Field]);
+    assert_eq!(stub.arguments.len(), 1);
+    assert!(schema.schema.directive_by_name("uppercase").is_some());
+}
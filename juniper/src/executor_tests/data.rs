@@ -0,0 +1,79 @@
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    ExecutionOptions, Executor, ScalarValue,
+};
+
+struct Greeting(&'static str);
+
+struct Name(&'static str);
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn greeting<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> Option<String> {
+        let greeting = executor.data::<Greeting>()?;
+        let name = executor.data::<Name>().map_or("stranger", |n| n.0);
+        Some(format!("{}, {name}!", greeting.0))
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+        .with_data(Greeting("Hello"))
+}
+
+#[tokio::test]
+async fn exposes_schema_data() {
+    let schema = schema();
+
+    let res = crate::execute(r"{ greeting }", None, &schema, &graphql_vars! {}, &())
+        .await
+        .expect("Execution failed");
+
+    assert_eq!(
+        res,
+        (graphql_value!({"greeting": "Hello, stranger!"}), vec![])
+    );
+}
+
+#[tokio::test]
+async fn exposes_request_data_along_with_schema_data() {
+    let schema = schema();
+    let options = schema.execution_options().clone().data(Name("Juniper"));
+
+    let res = crate::execute_with_options(
+        r"{ greeting }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+        &options,
+    )
+    .await
+    .expect("Execution failed");
+
+    assert_eq!(
+        res,
+        (graphql_value!({"greeting": "Hello, Juniper!"}), vec![])
+    );
+}
+
+#[test]
+fn lacks_data_not_inserted() {
+    let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+
+    let res = crate::execute_sync_with_options(
+        r"{ greeting }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+        &ExecutionOptions::new().data(Name("Juniper")),
+    )
+    .expect("Execution failed");
+
+    assert_eq!(res, (graphql_value!({"greeting": null}), vec![]));
+}
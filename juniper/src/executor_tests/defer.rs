@@ -0,0 +1,186 @@
+use futures::StreamExt as _;
+
+use crate::{
+    executor::{IncrementalPayload, IncrementalResult, PathSegment, Variables},
+    graphql_object, graphql_value,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    value::DefaultScalarValue,
+};
+
+struct Human {
+    name: &'static str,
+    friends: &'static [&'static str],
+}
+
+#[graphql_object]
+impl Human {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn home_planet(&self) -> &str {
+        "Tatooine"
+    }
+
+    fn friends(&self) -> Vec<Human> {
+        self.friends
+            .iter()
+            .map(|&name| Human { name, friends: &[] })
+            .collect()
+    }
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn hero() -> Human {
+        Human {
+            name: "Luke Skywalker",
+            friends: &["Han Solo", "Leia Organa"],
+        }
+    }
+}
+
+async fn run_query(query: &str) -> Vec<IncrementalPayload<DefaultScalarValue>> {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    )
+    .enable_defer_directive();
+
+    crate::execute_incremental(query, None, &schema, &Variables::new(), &())
+        .await
+        .expect("Execution failed")
+        .collect()
+        .await
+}
+
+fn path(segments: &[&str]) -> Vec<PathSegment> {
+    segments
+        .iter()
+        .map(|s| match s.parse() {
+            Ok(i) => PathSegment::Index(i),
+            Err(_) => PathSegment::Field((*s).into()),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn defers_inline_fragment() {
+    let payloads = run_query(
+        r#"{
+            hero {
+                name
+                ... @defer {
+                    homePlanet
+                }
+            }
+        }"#,
+    )
+    .await;
+
+    assert_eq!(
+        payloads,
+        vec![
+            IncrementalPayload::Initial {
+                data: graphql_value!({"hero": {"name": "Luke Skywalker"}}),
+                errors: vec![],
+                has_next: true,
+            },
+            IncrementalPayload::Subsequent {
+                incremental: vec![IncrementalResult {
+                    data: graphql_value!({"homePlanet": "Tatooine"}),
+                    path: path(&["hero"]),
+                    label: None,
+                    errors: vec![],
+                }],
+                has_next: false,
+            },
+        ],
+    );
+}
+
+#[tokio::test]
+async fn defers_fragments_in_order_with_labels() {
+    let payloads = run_query(
+        r#"{
+            hero {
+                ...HeroPlanet @defer(label: "planet")
+                friends {
+                    ... @defer(label: "friends") {
+                        name
+                    }
+                }
+            }
+        }
+
+        fragment HeroPlanet on Human {
+            homePlanet
+        }"#,
+    )
+    .await;
+
+    assert_eq!(
+        payloads,
+        vec![
+            IncrementalPayload::Initial {
+                data: graphql_value!({"hero": {"friends": [{}, {}]}}),
+                errors: vec![],
+                has_next: true,
+            },
+            IncrementalPayload::Subsequent {
+                incremental: vec![IncrementalResult {
+                    data: graphql_value!({"homePlanet": "Tatooine"}),
+                    path: path(&["hero"]),
+                    label: Some("planet".into()),
+                    errors: vec![],
+                }],
+                has_next: true,
+            },
+            IncrementalPayload::Subsequent {
+                incremental: vec![
+                    IncrementalResult {
+                        data: graphql_value!({"name": "Han Solo"}),
+                        path: path(&["hero", "friends", "0"]),
+                        label: Some("friends".into()),
+                        errors: vec![],
+                    },
+                    IncrementalResult {
+                        data: graphql_value!({"name": "Leia Organa"}),
+                        path: path(&["hero", "friends", "1"]),
+                        label: Some("friends".into()),
+                        errors: vec![],
+                    },
+                ],
+                has_next: false,
+            },
+        ],
+    );
+}
+
+#[tokio::test]
+async fn resolves_inline_if_disabled() {
+    let payloads = run_query(
+        r#"{
+            hero {
+                name
+                ... @defer(if: false) {
+                    homePlanet
+                }
+            }
+        }"#,
+    )
+    .await;
+
+    assert_eq!(
+        payloads,
+        vec![IncrementalPayload::Initial {
+            data: graphql_value!({"hero": {"name": "Luke Skywalker", "homePlanet": "Tatooine"}}),
+            errors: vec![],
+            has_next: false,
+        }],
+    );
+}
@@ -0,0 +1,133 @@
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    GraphQLError,
+};
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn echo(value: i32) -> i32 {
+        value
+    }
+}
+
+fn schema(capacity: usize) -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+        .with_document_cache(capacity)
+}
+
+#[test]
+fn reuses_cached_documents() {
+    let schema = schema(2);
+    let doc = "query($v: Int!) { echo(value: $v) }";
+
+    for v in 0..3 {
+        assert_eq!(
+            crate::execute_sync(doc, None, &schema, &graphql_vars! {"v": (v)}, &()),
+            Ok((graphql_value!({"echo": v}), vec![])),
+        );
+    }
+    assert_eq!(schema.document_cache().unwrap().len(), 1);
+
+    // Variables are still validated on every execution.
+    assert!(matches!(
+        crate::execute_sync(doc, None, &schema, &graphql_vars! {"v": "one"}, &()),
+        Err(GraphQLError::ValidationError(_)),
+    ));
+}
+
+#[tokio::test]
+async fn evicts_least_recently_used_documents() {
+    let schema = schema(2);
+    let cache = schema.document_cache().unwrap();
+
+    for doc in ["{ a: echo(value: 1) }", "{ b: echo(value: 2) }"] {
+        let res = crate::execute(doc, None, &schema, &graphql_vars! {}, &()).await;
+        assert!(res.is_ok(), "failed: {res:?}");
+    }
+    assert_eq!(cache.len(), 2);
+
+    // Uses the first document again, so the second one is evicted next.
+    let res = crate::execute(
+        "{ a: echo(value: 1) }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .await;
+    assert!(res.is_ok(), "failed: {res:?}");
+    let res = crate::execute(
+        "{ c: echo(value: 3) }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .await;
+    assert!(res.is_ok(), "failed: {res:?}");
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn caches_validation_outcome() {
+    let schema = schema(4);
+    let doc = "{ echo(value: 1) unknown }";
+
+    let first = crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &());
+    let second = crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &());
+
+    assert!(matches!(first, Err(GraphQLError::ValidationError(_))));
+    assert_eq!(first, second);
+    assert_eq!(schema.document_cache().unwrap().len(), 1);
+}
+
+#[test]
+fn does_not_cache_unparsable_documents() {
+    let schema = schema(4);
+
+    assert!(matches!(
+        crate::execute_sync("{ echo(", None, &schema, &graphql_vars! {}, &()),
+        Err(GraphQLError::ParseError(_)),
+    ));
+    assert!(schema.document_cache().unwrap().is_empty());
+}
+
+#[test]
+fn evicts_documents_in_recency_order() {
+    use std::sync::Arc;
+
+    let schema = schema(2);
+    let cache = schema.document_cache().unwrap();
+    let parse = |doc| cache.get_or_parse(doc, &schema.schema).unwrap();
+
+    let a = parse("{ a: echo(value: 1) }");
+    let b = parse("{ b: echo(value: 2) }");
+    assert!(Arc::ptr_eq(&a, &parse("{ a: echo(value: 1) }")));
+
+    // `b` is the least recently used one now, so is evicted by `c`.
+    let c = parse("{ c: echo(value: 3) }");
+    assert_eq!(cache.len(), 2);
+    assert!(Arc::ptr_eq(&a, &parse("{ a: echo(value: 1) }")));
+    assert!(Arc::ptr_eq(&c, &parse("{ c: echo(value: 3) }")));
+    assert!(!Arc::ptr_eq(&b, &parse("{ b: echo(value: 2) }")));
+
+    // Re-parsed `b` evicted `a`, being the least recently used one then.
+    assert!(!Arc::ptr_eq(&a, &parse("{ a: echo(value: 1) }")));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn holds_nothing_with_zero_capacity() {
+    let schema = schema(0);
+
+    let res = crate::execute_sync("{ echo(value: 1) }", None, &schema, &graphql_vars! {}, &());
+    assert_eq!(res, Ok((graphql_value!({"echo": 1}), vec![])));
+    assert!(schema.document_cache().unwrap().is_empty());
+}
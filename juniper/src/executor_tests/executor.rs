@@ -1101,3 +1101,347 @@ mod named_operations {
         assert_eq!(err, GraphQLError::UnknownOperationName);
     }
 }
+
+mod injected_clock_and_rng {
+    use std::{
+        sync::Arc,
+        time::{Duration, SystemTime},
+    };
+
+    use crate::{
+        graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        ExecutionOptions, Executor, MockClock, Rng as _, ScalarValue, SplitMixRng,
+    };
+
+    struct Schema;
+
+    #[graphql_object(scalar = S: ScalarValue)]
+    impl Schema {
+        fn now<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> i32 {
+            executor
+                .clock()
+                .now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_secs() as i32
+        }
+
+        fn random<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> String {
+            executor.rng().next_u64().to_string()
+        }
+    }
+
+    #[tokio::test]
+    async fn uses_injected_clock() {
+        let clock = Arc::new(MockClock::new(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(10),
+        ));
+        let schema = RootNode::new(
+            Schema,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        )
+        .with_execution_options(ExecutionOptions::new().shared_clock(clock.clone()));
+        let doc = r"{ now }";
+        let vars = graphql_vars! {};
+
+        let (res, errs) = crate::execute(doc, None, &schema, &vars, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(errs, []);
+        assert_eq!(res, graphql_value!({"now": 10}));
+
+        clock.advance(Duration::from_secs(5));
+
+        let (res, errs) = crate::execute(doc, None, &schema, &vars, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(errs, []);
+        assert_eq!(res, graphql_value!({"now": 15}));
+    }
+
+    #[tokio::test]
+    async fn uses_injected_rng() {
+        let schema = RootNode::new(
+            Schema,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let doc = r"{ random }";
+        let vars = graphql_vars! {};
+
+        let expected = SplitMixRng::with_seed(3).next_u64().to_string();
+        let options = ExecutionOptions::new().rng(SplitMixRng::with_seed(3));
+
+        let (res, errs) = crate::execute_with_options(doc, None, &schema, &vars, &(), &options)
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(errs, []);
+        assert_eq!(res, graphql_value!({ "random": expected }));
+    }
+}
+
+mod recursion_depth {
+    use crate::{
+        graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        ExecutionOptions,
+    };
+
+    struct Node;
+
+    #[graphql_object]
+    impl Node {
+        fn id() -> i32 {
+            1
+        }
+
+        fn child() -> Option<Node> {
+            Some(Node)
+        }
+    }
+
+    fn schema() -> RootNode<'static, Node, EmptyMutation, EmptySubscription> {
+        RootNode::new(Node, EmptyMutation::new(), EmptySubscription::new())
+            .with_execution_options(ExecutionOptions::new().max_recursion_depth(3))
+    }
+
+    #[tokio::test]
+    async fn resolves_fields_within_limit() {
+        let schema = schema();
+        let doc = r"{ child { child { id } } }";
+        let vars = graphql_vars! {};
+
+        let (res, errs) = crate::execute(doc, None, &schema, &vars, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(errs, []);
+        assert_eq!(res, graphql_value!({"child": {"child": {"id": 1}}}));
+    }
+
+    #[tokio::test]
+    async fn errors_on_fields_beyond_limit() {
+        let schema = schema();
+        let doc = r"{ child { child { child { id } } } }";
+        let vars = graphql_vars! {};
+
+        let (res, errs) = crate::execute(doc, None, &schema, &vars, &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(res, graphql_value!({"child": {"child": {"child": null}}}));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].path(), ["child", "child", "child", "id"]);
+        assert_eq!(
+            errs[0].error().message(),
+            "Maximum recursion depth exceeded",
+        );
+    }
+
+    #[test]
+    fn errors_on_fields_beyond_limit_synchronously() {
+        let schema = schema();
+        let doc = r"{ child { child { child { id } } } }";
+        let vars = graphql_vars! {};
+
+        let (res, errs) =
+            crate::execute_sync(doc, None, &schema, &vars, &()).expect("Execution failed");
+
+        assert_eq!(res, graphql_value!({"child": {"child": {"child": null}}}));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(
+            errs[0].error().message(),
+            "Maximum recursion depth exceeded",
+        );
+    }
+}
+
+mod mapped_errors {
+    use std::pin::Pin;
+
+    use futures::{stream, StreamExt as _};
+
+    use crate::{
+        executor::IncrementalPayload, graphql_object, graphql_subscription, graphql_value,
+        graphql_vars, parser::SourcePosition, schema::model::RootNode,
+        types::scalars::EmptyMutation, ExecutionError, FieldError, GraphQLError,
+        InMemoryLiveQueryStore, Value,
+    };
+
+    fn internal_error() -> FieldError {
+        FieldError::new(
+            "connection to 10.0.0.1 refused",
+            graphql_value!({"code": "DB"}),
+        )
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn secret() -> Result<Option<i32>, FieldError> {
+            Err(internal_error())
+        }
+
+        fn nested() -> Query {
+            Query
+        }
+    }
+
+    type SecretStream = Pin<Box<dyn futures::Stream<Item = Result<i32, FieldError>> + Send>>;
+
+    struct Subscription;
+
+    #[graphql_subscription]
+    impl Subscription {
+        async fn secrets() -> SecretStream {
+            Box::pin(stream::iter(vec![Ok(1), Err(internal_error())]))
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation, Subscription> {
+        RootNode::new(Query, EmptyMutation::new(), Subscription)
+            .enable_defer_directive()
+            .map_errors(|err| {
+                err.map_error(|e| FieldError::new("Internal server error", e.extensions().clone()))
+            })
+    }
+
+    fn redacted() -> FieldError {
+        FieldError::new("Internal server error", graphql_value!({"code": "DB"}))
+    }
+
+    fn expected() -> ExecutionError<crate::DefaultScalarValue> {
+        ExecutionError::new(SourcePosition::new(2, 0, 2), &["secret"], redacted())
+    }
+
+    #[tokio::test]
+    async fn maps_execution_errors() {
+        let schema = schema();
+
+        let res = crate::execute(r"{ secret }", None, &schema, &graphql_vars! {}, &()).await;
+
+        assert_eq!(
+            res,
+            Ok((graphql_value!({"secret": null}), vec![expected()])),
+        );
+    }
+
+    #[test]
+    fn maps_execution_errors_synchronously() {
+        let schema = schema();
+
+        let res = crate::execute_sync(r"{ secret }", None, &schema, &graphql_vars! {}, &());
+
+        assert_eq!(
+            res,
+            Ok((graphql_value!({"secret": null}), vec![expected()])),
+        );
+    }
+
+    #[tokio::test]
+    async fn maps_deferred_fragments_errors() {
+        const DOC: &str = r"{
+            secret
+            nested {
+                ... @defer {
+                    secret
+                }
+            }
+        }";
+
+        let schema = schema();
+
+        let payloads = crate::execute_incremental(DOC, None, &schema, &graphql_vars! {}, &())
+            .await
+            .expect("Execution failed")
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(payloads.len(), 2);
+        match &payloads[0] {
+            IncrementalPayload::Initial { errors, .. } => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].error(), &redacted());
+            }
+            p => panic!("expected initial payload, found: {p:?}"),
+        }
+        match &payloads[1] {
+            IncrementalPayload::Subsequent { incremental, .. } => {
+                assert_eq!(incremental.len(), 1);
+                assert_eq!(incremental[0].errors.len(), 1);
+                assert_eq!(incremental[0].errors[0].error(), &redacted());
+            }
+            p => panic!("expected subsequent payload, found: {p:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn maps_subscription_events_errors() {
+        let schema = schema();
+
+        let (res, errs) = crate::resolve_into_stream(
+            "subscription { secrets }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        )
+        .await
+        .expect("Subscription failed");
+        assert!(errs.is_empty());
+
+        let mut streams = match res {
+            Value::Object(obj) => obj.into_iter(),
+            _ => panic!("expected object of streams"),
+        };
+        let (name, stream) = streams.next().expect("no stream");
+        assert_eq!(name, "secrets");
+        let events = match stream {
+            Value::Scalar(stream) => stream.collect::<Vec<_>>().await,
+            _ => panic!("expected stream"),
+        };
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0], Ok(graphql_value!(1)));
+        assert_eq!(
+            events[1].as_ref().map_err(ExecutionError::error),
+            Err(&redacted()),
+        );
+    }
+
+    #[tokio::test]
+    async fn maps_live_query_errors() {
+        let (schema, store) = (schema(), InMemoryLiveQueryStore::new());
+
+        let mut stream = crate::resolve_into_live_stream(
+            r"{ secret }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+            &store,
+        )
+        .await
+        .expect("Execution failed");
+
+        let output = stream.next().await.expect("no output");
+        assert_eq!(output.errors, [expected()]);
+    }
+
+    #[test]
+    fn keeps_request_errors() {
+        let schema = schema();
+
+        let res = crate::execute_sync(r"{ unknown", None, &schema, &graphql_vars! {}, &());
+
+        assert!(matches!(res, Err(GraphQLError::ParseError(_))));
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    graphql_object, graphql_vars,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    Extension, FieldError, FieldInfo, GraphQLError, RequestInfo,
+};
+
+#[derive(Debug, Default)]
+struct Recorder(Mutex<Vec<String>>);
+
+impl Recorder {
+    fn record(&self, event: String) {
+        self.0.lock().unwrap().push(event);
+    }
+
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl Extension for Recorder {
+    fn request_start(&self, request: &RequestInfo<'_>) {
+        self.record(format!("request_start {}", request.document_source));
+    }
+
+    fn parse(&self, _: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        self.record(format!("parse {}", error.is_none()));
+    }
+
+    fn validate(&self, _: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        self.record(format!("validate {}", error.is_none()));
+    }
+
+    fn field_start(&self, field: &FieldInfo<'_>) {
+        self.record(format!(
+            "field_start {}.{} {}",
+            field.parent_type,
+            field.field_name,
+            field.path.join("."),
+        ));
+    }
+
+    fn field_end(&self, field: &FieldInfo<'_>, error: Option<&str>) {
+        self.record(format!(
+            "field_end {} {}",
+            field.path.join("."),
+            error.unwrap_or("ok"),
+        ));
+    }
+
+    fn request_end(&self, _: &RequestInfo<'_>, error: Option<&GraphQLError>) {
+        self.record(format!("request_end {}", error.is_none()));
+    }
+}
+
+struct User;
+
+#[graphql_object]
+impl User {
+    fn name() -> &'static str {
+        "user"
+    }
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn me() -> User {
+        User
+    }
+
+    fn fail() -> Result<Option<i32>, FieldError> {
+        Err("denied".into())
+    }
+}
+
+fn schema(recorder: Arc<Recorder>) -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new()).with_extension(recorder)
+}
+
+#[tokio::test]
+async fn notifies_about_lifecycle() {
+    const DOC: &str = "{ me { username: name } fail }";
+
+    let recorder = Arc::new(Recorder::default());
+    let schema = schema(recorder.clone());
+
+    crate::execute(DOC, None, &schema, &graphql_vars! {}, &())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        recorder.take(),
+        [
+            "request_start { me { username: name } fail }",
+            "parse true",
+            "validate true",
+            "field_start Query.me me",
+            "field_start User.name me.username",
+            "field_end me.username ok",
+            "field_end me ok",
+            "field_start Query.fail fail",
+            "field_end fail denied",
+            "request_end true",
+        ],
+    );
+}
+
+#[test]
+fn notifies_about_failed_requests() {
+    let recorder = Arc::new(Recorder::default());
+    let schema = schema(recorder.clone());
+
+    assert!(crate::execute_sync("{ unknown }", None, &schema, &graphql_vars! {}, &()).is_err());
+    assert_eq!(
+        recorder.take(),
+        [
+            "request_start { unknown }",
+            "parse true",
+            "validate false",
+            "request_end false",
+        ],
+    );
+
+    assert!(crate::execute_sync("{ me", None, &schema, &graphql_vars! {}, &()).is_err());
+    assert_eq!(
+        recorder.take(),
+        ["request_start { me", "parse false", "request_end false"],
+    );
+}
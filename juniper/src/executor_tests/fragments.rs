@@ -0,0 +1,144 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    Context,
+};
+
+#[derive(Default)]
+struct Counter {
+    hits: AtomicUsize,
+}
+
+impl Context for Counter {}
+
+struct User {
+    id: i32,
+}
+
+#[graphql_object(context = Counter)]
+impl User {
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    fn name(&self, context: &Counter) -> String {
+        context.hits.fetch_add(1, Ordering::SeqCst);
+        format!("user{}", self.id)
+    }
+
+    async fn name_async(&self, context: &Counter) -> String {
+        context.hits.fetch_add(1, Ordering::SeqCst);
+        format!("user{}", self.id)
+    }
+
+    fn friend(&self) -> User {
+        User { id: self.id + 1 }
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Counter)]
+impl Query {
+    fn user() -> User {
+        User { id: 1 }
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation<Counter>, EmptySubscription<Counter>> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+const DOC: &str = r#"{
+    user {
+        ...A
+        ...B
+        ...Name
+        ... on User { ...Name }
+        friend { ...Name ...A }
+    }
+}
+
+fragment A on User { id ...Name }
+fragment B on User { ...Name }
+fragment Name on User { name nameAsync }"#;
+
+fn expected() -> crate::Value {
+    graphql_value!({
+        "user": {
+            "id": 1,
+            "name": "user1",
+            "nameAsync": "user1",
+            "friend": {
+                "name": "user2",
+                "nameAsync": "user2",
+                "id": 2,
+            },
+        },
+    })
+}
+
+#[test]
+fn resolves_repeated_fragment_spreads_once() {
+    let ctx = Counter::default();
+    // Async fields cannot be resolved synchronously.
+    let doc = DOC.replace(" nameAsync", "");
+
+    assert_eq!(
+        crate::execute_sync(&doc, None, &schema(), &graphql_vars! {}, &ctx),
+        Ok((
+            graphql_value!({
+                "user": {
+                    "id": 1,
+                    "name": "user1",
+                    "friend": {"name": "user2", "id": 2},
+                },
+            }),
+            vec![],
+        )),
+    );
+    assert_eq!(ctx.hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn resolves_repeated_fragment_spreads_once_async() {
+    let ctx = Counter::default();
+
+    assert_eq!(
+        crate::execute(DOC, None, &schema(), &graphql_vars! {}, &ctx).await,
+        Ok((expected(), vec![])),
+    );
+    assert_eq!(ctx.hits.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn resolves_same_fragment_for_every_list_item() {
+    struct Query;
+
+    #[graphql_object(context = Counter)]
+    impl Query {
+        fn users() -> Vec<User> {
+            (1..=3).map(|id| User { id }).collect()
+        }
+    }
+
+    let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+    let doc = r#"{ users { ...N ...N } } fragment N on User { name }"#;
+    let ctx = Counter::default();
+
+    assert_eq!(
+        crate::execute(doc, None, &schema, &graphql_vars! {}, &ctx).await,
+        Ok((
+            graphql_value!({"users": [
+                {"name": "user1"},
+                {"name": "user2"},
+                {"name": "user3"},
+            ]}),
+            vec![],
+        )),
+    );
+    assert_eq!(ctx.hits.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,148 @@
+use futures::future::{self, BoxFuture};
+
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    parser::SourcePosition,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    Arguments, DefaultScalarValue, ExecutionError, FieldError, Guard, ScalarValue,
+};
+
+struct Session {
+    user_id: Option<i32>,
+}
+
+impl crate::Context for Session {}
+
+fn is_signed_in(session: &Session) -> bool {
+    session.user_id.is_some()
+}
+
+struct IsOwner;
+
+impl<S: ScalarValue> Guard<Session, S> for IsOwner {
+    fn check<'a>(
+        &'a self,
+        session: &'a Session,
+        arguments: &'a Arguments<'a, S>,
+    ) -> BoxFuture<'a, bool> {
+        let user_id = arguments.get::<i32>("userId").ok().flatten();
+        Box::pin(async move { user_id.is_some() && user_id == session.user_id })
+    }
+}
+
+struct Never;
+
+impl<S> Guard<Session, S> for Never {
+    fn check<'a>(&'a self, _: &'a Session, _: &'a Arguments<'a, S>) -> BoxFuture<'a, bool> {
+        Box::pin(future::pending())
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Session)]
+impl Query {
+    #[graphql(guard = is_signed_in)]
+    fn me(#[graphql(context)] session: &Session) -> Option<i32> {
+        session.user_id
+    }
+
+    #[graphql(guard = IsOwner)]
+    async fn balance(user_id: i32) -> i32 {
+        user_id * 100
+    }
+
+    #[graphql(guard = Never)]
+    fn pending() -> i32 {
+        panic!("must not be resolved")
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation<Session>, EmptySubscription<Session>> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+fn forbidden(pos: usize, field: &str) -> ExecutionError<DefaultScalarValue> {
+    ExecutionError::new(
+        SourcePosition::new(pos, 0, pos),
+        &[field],
+        FieldError::new(
+            format!("Access to field `{field}` is forbidden"),
+            graphql_value!({"code": "FORBIDDEN"}),
+        ),
+    )
+}
+
+#[tokio::test]
+async fn resolves_allowed_fields() {
+    let schema = schema();
+    let session = Session { user_id: Some(1) };
+
+    let res = crate::execute(
+        "{ me balance(userId: 1) }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &session,
+    )
+    .await;
+
+    assert_eq!(res, Ok((graphql_value!({"me": 1, "balance": 100}), vec![])));
+}
+
+#[tokio::test]
+async fn denies_fields_failing_guards() {
+    let schema = schema();
+    let session = Session { user_id: None };
+
+    let res = crate::execute(
+        "{ me balance(userId: 1) }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &session,
+    )
+    .await;
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!(null),
+            vec![forbidden(2, "me"), forbidden(5, "balance")],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn checks_guards_against_arguments() {
+    let schema = schema();
+    let session = Session { user_id: Some(1) };
+
+    let res = crate::execute(
+        "{ balance(userId: 2) }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &session,
+    )
+    .await;
+
+    assert_eq!(
+        res,
+        Ok((graphql_value!(null), vec![forbidden(2, "balance")]))
+    );
+}
+
+#[test]
+fn denies_fields_with_pending_guards_synchronously() {
+    let schema = schema();
+    let session = Session { user_id: Some(1) };
+
+    let res = crate::execute_sync("{ me pending }", None, &schema, &graphql_vars! {}, &session);
+
+    assert_eq!(
+        res,
+        Ok((graphql_value!(null), vec![forbidden(5, "pending")]))
+    );
+}
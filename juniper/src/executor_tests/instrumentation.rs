@@ -0,0 +1,122 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    graphql_object, graphql_vars,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    ExecutionOptions, FieldError, FieldMetrics, FieldStatus, Instrumentation, MockClock,
+};
+
+#[derive(Debug, Default)]
+struct Recorder(Mutex<Vec<(String, Duration, FieldStatus)>>);
+
+impl Recorder {
+    fn take(&self) -> Vec<(String, Duration, FieldStatus)> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl Instrumentation for Recorder {
+    fn field_resolved(&self, metrics: &FieldMetrics<'_>) {
+        self.0.lock().unwrap().push((
+            format!(
+                "{}.{} {}",
+                metrics.parent_type,
+                metrics.field_name,
+                metrics.path.join("."),
+            ),
+            metrics.duration,
+            metrics.status,
+        ));
+    }
+}
+
+struct Clock(Arc<MockClock>);
+
+impl crate::Context for Clock {}
+
+struct User;
+
+#[graphql_object(context = Clock)]
+impl User {
+    fn name(#[graphql(context)] clock: &Clock) -> &'static str {
+        clock.0.advance(Duration::from_millis(2));
+        "user"
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Clock)]
+impl Query {
+    async fn me(#[graphql(context)] clock: &Clock) -> User {
+        clock.0.advance(Duration::from_millis(3));
+        User
+    }
+
+    fn fail() -> Result<Option<i32>, FieldError> {
+        Err("denied".into())
+    }
+}
+
+fn schema(
+    recorder: Arc<Recorder>,
+    clock: Arc<MockClock>,
+) -> RootNode<'static, Query, EmptyMutation<Clock>, EmptySubscription<Clock>> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new()).with_execution_options(
+        ExecutionOptions::default()
+            .shared_clock(clock)
+            .instrumentation(recorder),
+    )
+}
+
+#[tokio::test]
+async fn reports_field_metrics() {
+    let recorder = Arc::new(Recorder::default());
+    let clock = Arc::new(MockClock::default());
+    let schema = schema(recorder.clone(), clock.clone());
+
+    crate::execute(
+        "{ me { username: name } fail }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &Clock(clock),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        recorder.take(),
+        [
+            (
+                "User.name me.username".into(),
+                Duration::from_millis(2),
+                FieldStatus::Ok,
+            ),
+            (
+                "Query.me me".into(),
+                Duration::from_millis(5),
+                FieldStatus::Ok
+            ),
+            ("Query.fail fail".into(), Duration::ZERO, FieldStatus::Error),
+        ],
+    );
+}
+
+#[test]
+fn reports_field_metrics_synchronously() {
+    let recorder = Arc::new(Recorder::default());
+    let clock = Arc::new(MockClock::default());
+    let schema = schema(recorder.clone(), clock.clone());
+
+    crate::execute_sync("{ fail }", None, &schema, &graphql_vars! {}, &Clock(clock)).unwrap();
+
+    assert_eq!(
+        recorder.take(),
+        [("Query.fail fail".into(), Duration::ZERO, FieldStatus::Error)],
+    );
+}
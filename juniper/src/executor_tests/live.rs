@@ -0,0 +1,165 @@
+use std::sync::Mutex;
+
+use futures::{FutureExt as _, StreamExt as _};
+
+use crate::{
+    graphql_object, graphql_value, graphql_vars, schema::model::RootNode,
+    types::scalars::EmptySubscription, Context, DefaultScalarValue, Executor, GraphQLError,
+    InMemoryLiveQueryStore,
+};
+
+struct Database {
+    todos: Mutex<Vec<String>>,
+    visits: Mutex<i32>,
+}
+
+impl Context for Database {}
+
+struct Query;
+
+#[graphql_object(context = Database, scalar = DefaultScalarValue)]
+impl Query {
+    fn todos(executor: &Executor<'_, '_, Database>) -> Vec<String> {
+        executor.live_dependency("Todos");
+        executor.context().todos.lock().unwrap().clone()
+    }
+
+    fn visits(executor: &Executor<'_, '_, Database>) -> i32 {
+        executor.live_dependency("Visits");
+        *executor.context().visits.lock().unwrap()
+    }
+
+    fn is_live(executor: &Executor<'_, '_, Database>) -> bool {
+        executor.live_dependency("Nothing")
+    }
+}
+
+struct Mutation;
+
+#[graphql_object(context = Database)]
+impl Mutation {
+    fn add_todo(todo: String, context: &Database) -> bool {
+        context.todos.lock().unwrap().push(todo);
+        true
+    }
+}
+
+fn schema() -> RootNode<'static, Query, Mutation, EmptySubscription<Database>> {
+    RootNode::new(Query, Mutation, EmptySubscription::new())
+}
+
+fn database() -> Database {
+    Database {
+        todos: Mutex::new(vec!["write tests".into()]),
+        visits: Mutex::new(0),
+    }
+}
+
+#[tokio::test]
+async fn re_executes_on_invalidation() {
+    let (schema, db, store) = (schema(), database(), InMemoryLiveQueryStore::new());
+
+    let mut stream =
+        crate::resolve_into_live_stream("{ todos }", None, &schema, &graphql_vars! {}, &db, &store)
+            .await
+            .unwrap();
+
+    let initial = stream.next().await.unwrap();
+    assert_eq!(initial.data, graphql_value!({"todos": ["write tests"]}));
+    assert!(initial.errors.is_empty());
+
+    db.todos.lock().unwrap().push("ship it".into());
+    store.invalidate("Todos");
+
+    let updated = stream.next().await.unwrap();
+    assert_eq!(
+        updated.data,
+        graphql_value!({"todos": ["write tests", "ship it"]}),
+    );
+}
+
+#[tokio::test]
+async fn ignores_unrelated_invalidations() {
+    let (schema, db, store) = (schema(), database(), InMemoryLiveQueryStore::new());
+
+    let mut stream =
+        crate::resolve_into_live_stream("{ todos }", None, &schema, &graphql_vars! {}, &db, &store)
+            .await
+            .unwrap();
+    stream.next().await.unwrap();
+
+    *db.visits.lock().unwrap() += 1;
+    store.invalidate("Visits");
+
+    assert!(stream.next().now_or_never().is_none());
+}
+
+#[tokio::test]
+async fn skips_unchanged_results() {
+    let (schema, db, store) = (schema(), database(), InMemoryLiveQueryStore::new());
+
+    let mut stream = crate::resolve_into_live_stream(
+        "{ todos visits }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &db,
+        &store,
+    )
+    .await
+    .unwrap();
+    stream.next().await.unwrap();
+
+    store.invalidate("Todos");
+    assert!(stream.next().now_or_never().is_none());
+
+    *db.visits.lock().unwrap() += 1;
+    store.invalidate("Visits");
+    assert_eq!(
+        stream.next().await.unwrap().data,
+        graphql_value!({"todos": ["write tests"], "visits": 1}),
+    );
+}
+
+#[tokio::test]
+async fn declares_dependencies_only_when_live() {
+    let (schema, db, store) = (schema(), database(), InMemoryLiveQueryStore::new());
+
+    let mut stream = crate::resolve_into_live_stream(
+        "{ isLive }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &db,
+        &store,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        stream.next().await.unwrap().data,
+        graphql_value!({"isLive": true}),
+    );
+    assert_eq!(
+        crate::execute("{ isLive }", None, &schema, &graphql_vars! {}, &db).await,
+        Ok((graphql_value!({"isLive": false}), vec![])),
+    );
+}
+
+#[tokio::test]
+async fn rejects_mutations() {
+    let (schema, db, store) = (schema(), database(), InMemoryLiveQueryStore::new());
+
+    let res = crate::resolve_into_live_stream(
+        r#"mutation { addTodo(todo: "twice") }"#,
+        None,
+        &schema,
+        &graphql_vars! {},
+        &db,
+        &store,
+    )
+    .await;
+
+    assert!(matches!(res, Err(GraphQLError::NotQuery)));
+    assert_eq!(db.todos.lock().unwrap().len(), 1);
+}
@@ -1,7 +1,23 @@
+mod apollo_tracing;
+mod cancellation;
+mod concurrency;
+mod custom_directives;
+mod data;
+mod defer;
 mod directives;
+mod document_cache;
 mod enums;
 mod executor;
+mod extensions;
+mod fragments;
+mod guards;
+mod instrumentation;
 mod introspection;
+mod live;
+mod null_propagation;
+mod response_extensions;
+mod skip_if;
+mod timeout;
 mod variables;
 
 mod interfaces_unions;
@@ -0,0 +1,120 @@
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    parser::SourcePosition,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    DefaultScalarValue, ExecutionError, ExecutionOptions, FieldError, NullPropagation,
+};
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn outer() -> Option<Outer> {
+        Some(Outer)
+    }
+
+    fn other() -> i32 {
+        1
+    }
+}
+
+struct Outer;
+
+#[graphql_object]
+impl Outer {
+    fn inner() -> Inner {
+        Inner
+    }
+}
+
+struct Inner;
+
+#[graphql_object]
+impl Inner {
+    fn fail() -> Result<i32, FieldError> {
+        Err("Failed".into())
+    }
+}
+
+const QUERY: &str = "{ outer { inner { fail } } other }";
+
+fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+fn field_error() -> ExecutionError<DefaultScalarValue> {
+    ExecutionError::new(
+        SourcePosition::new(18, 0, 18),
+        &["outer", "inner", "fail"],
+        FieldError::from("Failed"),
+    )
+}
+
+#[tokio::test]
+async fn bubbles_nulls_by_default() {
+    let schema = schema();
+
+    let res = crate::execute(QUERY, None, &schema, &graphql_vars! {}, &()).await;
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!({"outer": null, "other": 1}),
+            vec![field_error()],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn reports_every_nulled_field_in_detailed_mode() {
+    let schema = schema();
+    let options = ExecutionOptions::new().null_propagation(NullPropagation::Detailed);
+    let expected = Ok((
+        graphql_value!({"outer": null, "other": 1}),
+        vec![
+            ExecutionError::new(
+                SourcePosition::new(10, 0, 10),
+                &["outer", "inner"],
+                FieldError::from("Cannot return null for non-nullable field Outer.inner"),
+            ),
+            field_error(),
+        ],
+    ));
+
+    let res =
+        crate::execute_with_options(QUERY, None, &schema, &graphql_vars! {}, &(), &options).await;
+    assert_eq!(res, expected);
+
+    let res =
+        crate::execute_sync_with_options(QUERY, None, &schema, &graphql_vars! {}, &(), &options);
+    assert_eq!(res, expected);
+}
+
+#[tokio::test]
+async fn fails_whole_operation_in_strict_mode() {
+    let schema = schema();
+    let options = ExecutionOptions::new().null_propagation(NullPropagation::Strict);
+
+    let res =
+        crate::execute_with_options(QUERY, None, &schema, &graphql_vars! {}, &(), &options).await;
+
+    assert_eq!(res, Ok((graphql_value!(null), vec![field_error()])));
+}
+
+#[test]
+fn stops_resolving_on_first_error_in_strict_mode() {
+    let schema = schema();
+    let options = ExecutionOptions::new().null_propagation(NullPropagation::Strict);
+
+    let res = crate::execute_sync_with_options(
+        "{ outer { inner { fail } } again: outer { inner { fail } } }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+        &options,
+    );
+
+    assert_eq!(res, Ok((graphql_value!(null), vec![field_error()])));
+}
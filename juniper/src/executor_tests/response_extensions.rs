@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    http::GraphQLRequest,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    DefaultScalarValue, ExecutionOptions, Executor, ResponseExtensions,
+};
+
+struct Query;
+
+#[graphql_object(scalar = DefaultScalarValue)]
+impl Query {
+    fn answer(executor: &Executor<'_, '_, (), DefaultScalarValue>) -> bool {
+        executor.add_response_extension("cost", graphql_value!({"answer": 1}))
+    }
+
+    fn question(executor: &Executor<'_, '_, (), DefaultScalarValue>) -> bool {
+        executor.add_response_extension("hint", graphql_value!("ask nicely"))
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn serializes_extensions_stashed_by_resolvers() {
+    let schema = schema();
+
+    let resp = GraphQLRequest::new("{ answer question }".into(), None, None)
+        .execute(&schema, &())
+        .await;
+
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        json!({
+            "data": {"answer": true, "question": true},
+            "extensions": {"cost": {"answer": 1}, "hint": "ask nicely"},
+        }),
+    );
+}
+
+#[test]
+fn serializes_extensions_stashed_by_resolvers_synchronously() {
+    let schema = schema();
+
+    let resp = GraphQLRequest::new("{ answer }".into(), None, None).execute_sync(&schema, &());
+
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        json!({
+            "data": {"answer": true},
+            "extensions": {"cost": {"answer": 1}},
+        }),
+    );
+}
+
+#[tokio::test]
+async fn collects_extensions_of_direct_executions() {
+    let schema = schema();
+    let extensions = Arc::new(ResponseExtensions::new());
+
+    let res = crate::execute_with_options(
+        "{ answer }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+        &ExecutionOptions::new().response_extensions(Arc::clone(&extensions)),
+    )
+    .await;
+
+    assert_eq!(res, Ok((graphql_value!({"answer": true}), vec![])));
+    assert_eq!(
+        extensions
+            .take::<DefaultScalarValue>()
+            .get_field_value("cost"),
+        Some(&graphql_value!({"answer": 1})),
+    );
+}
+
+#[tokio::test]
+async fn drops_extensions_without_collector() {
+    let schema = schema();
+
+    let res = crate::execute("{ answer }", None, &schema, &graphql_vars! {}, &()).await;
+
+    assert_eq!(res, Ok((graphql_value!({"answer": false}), vec![])));
+}
@@ -0,0 +1,134 @@
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    parser::SourcePosition,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    DefaultScalarValue, ExecutionError, FieldError,
+};
+
+struct Flags {
+    beta: bool,
+}
+
+impl crate::Context for Flags {}
+
+fn beta_disabled(flags: &Flags) -> bool {
+    !flags.beta
+}
+
+struct Query;
+
+#[graphql_object(context = Flags)]
+impl Query {
+    fn stable() -> i32 {
+        1
+    }
+
+    #[graphql(skip_if = beta_disabled)]
+    fn preview() -> Option<i32> {
+        Some(2)
+    }
+
+    #[graphql(skip_if = |f: &Flags| !f.beta, skip_error = "Beta is disabled")]
+    async fn experiment() -> i32 {
+        3
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation<Flags>, EmptySubscription<Flags>> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn resolves_fields_not_skipped() {
+    let schema = schema();
+
+    let res = crate::execute(
+        "{ stable preview experiment }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &Flags { beta: true },
+    )
+    .await;
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!({"stable": 1, "preview": 2, "experiment": 3}),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn hides_skipped_fields() {
+    let schema = schema();
+
+    let res = crate::execute(
+        "{ stable preview }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &Flags { beta: false },
+    )
+    .await;
+
+    assert_eq!(
+        res,
+        Ok((graphql_value!({"stable": 1, "preview": null}), vec![])),
+    );
+}
+
+#[tokio::test]
+async fn errors_skipped_fields_with_skip_error() {
+    let schema = schema();
+
+    let res = crate::execute(
+        "{ experiment }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &Flags { beta: false },
+    )
+    .await;
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!(null),
+            vec![ExecutionError::new(
+                SourcePosition::new(2, 0, 2),
+                &["experiment"],
+                FieldError::<DefaultScalarValue>::from("Beta is disabled"),
+            )],
+        )),
+    );
+}
+
+#[test]
+fn hides_skipped_fields_synchronously() {
+    let schema = schema();
+
+    let res = crate::execute_sync(
+        "{ stable preview }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &Flags { beta: false },
+    );
+
+    assert_eq!(
+        res,
+        Ok((graphql_value!({"stable": 1, "preview": null}), vec![])),
+    );
+}
+
+#[test]
+fn registers_skipped_fields() {
+    let schema = schema();
+
+    let query = schema.schema.concrete_type_by_name("Query").unwrap();
+    assert!(query.field_by_name("preview").is_some());
+    assert!(query.field_by_name("experiment").is_some());
+}
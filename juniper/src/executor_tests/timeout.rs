@@ -0,0 +1,111 @@
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+
+use crate::{
+    graphql_object, graphql_value, graphql_vars,
+    parser::SourcePosition,
+    schema::model::RootNode,
+    types::scalars::{EmptyMutation, EmptySubscription},
+    ExecutionError, ExecutionOptions, FieldError, Timer,
+};
+
+#[derive(Debug)]
+struct TestTimer;
+
+impl Timer for TestTimer {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+struct Item;
+
+#[graphql_object]
+impl Item {
+    fn id() -> i32 {
+        1
+    }
+
+    async fn slow() -> Option<i32> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Some(2)
+    }
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn fast() -> i32 {
+        1
+    }
+
+    async fn slow() -> Option<i32> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Some(2)
+    }
+
+    fn item() -> Item {
+        Item
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn reports_timed_out_fields() {
+    const DOC: &str = "{ fast slow item { id slow } }";
+
+    let schema = schema();
+    let options = ExecutionOptions::new()
+        .timer(TestTimer)
+        .timeout(Duration::from_millis(50));
+
+    let res = tokio::time::timeout(
+        Duration::from_secs(2),
+        crate::execute_with_options(DOC, None, &schema, &graphql_vars! {}, &(), &options),
+    )
+    .await
+    .expect("execution should not hang");
+
+    assert_eq!(
+        res,
+        Ok((
+            graphql_value!({
+                "fast": 1,
+                "slow": null,
+                "item": {"id": 1, "slow": null},
+            }),
+            vec![
+                ExecutionError::new(
+                    SourcePosition::new(7, 0, 7),
+                    &["slow"],
+                    FieldError::from("Execution timed out"),
+                ),
+                ExecutionError::new(
+                    SourcePosition::new(22, 0, 22),
+                    &["item", "slow"],
+                    FieldError::from("Execution timed out"),
+                ),
+            ],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn completes_before_timeout() {
+    const DOC: &str = "{ fast item { id } }";
+
+    let schema = schema();
+    let options = ExecutionOptions::new()
+        .timer(TestTimer)
+        .timeout(Duration::from_secs(5));
+
+    assert_eq!(
+        crate::execute_with_options(DOC, None, &schema, &graphql_vars! {}, &(), &options).await,
+        Ok((graphql_value!({"fast": 1, "item": {"id": 1}}), vec![])),
+    );
+}
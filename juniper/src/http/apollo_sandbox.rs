@@ -0,0 +1,203 @@
+//! Utility module to generate an [Apollo Sandbox] interface
+//!
+//! [Apollo Sandbox]: https://www.apollographql.com/docs/graphos/explorer/sandbox
+
+use std::fmt::Write as _;
+
+use super::js_string;
+
+/// URL of the [Apollo Sandbox] embedding script loaded by the generated page.
+///
+/// [Apollo Sandbox]: https://www.apollographql.com/docs/graphos/explorer/sandbox
+pub const EMBEDDABLE_SANDBOX_SCRIPT_URL: &str =
+    "https://embeddable-sandbox.cdn.apollographql.com/_latest/embeddable-sandbox.umd.production.min.js";
+
+/// Options of an [Apollo Sandbox] interface generated by
+/// [`apollo_sandbox_source()`].
+///
+/// ```
+/// # use juniper::http::apollo_sandbox::ApolloSandboxOptions;
+/// let options = ApolloSandboxOptions::new()
+///     .initial_document("{ hero { name } }")
+///     .header("Authorization", "Bearer token")
+///     .include_cookies(true);
+/// ```
+///
+/// [Apollo Sandbox]: https://www.apollographql.com/docs/graphos/explorer/sandbox
+#[derive(Clone, Debug)]
+pub struct ApolloSandboxOptions {
+    initial_document: Option<String>,
+    headers: Vec<(String, String)>,
+    include_cookies: bool,
+    endpoint_is_editable: bool,
+    poll_for_schema_updates: bool,
+}
+
+impl Default for ApolloSandboxOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApolloSandboxOptions {
+    /// Creates new [`ApolloSandboxOptions`] with the defaults of
+    /// [Apollo Sandbox] itself, except for the endpoint being fixed.
+    ///
+    /// [Apollo Sandbox]: https://www.apollographql.com/docs/graphos/explorer/sandbox
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            initial_document: None,
+            headers: Vec::new(),
+            include_cookies: false,
+            endpoint_is_editable: false,
+            poll_for_schema_updates: true,
+        }
+    }
+
+    /// Sets the operation the editor is pre-filled with.
+    #[must_use]
+    pub fn initial_document(mut self, document: impl Into<String>) -> Self {
+        self.initial_document = Some(document.into());
+        self
+    }
+
+    /// Adds a header sent with every operation executed from the interface.
+    ///
+    /// The header can still be changed or removed by the user.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets whether cookies are sent with the operations (disabled by
+    /// default).
+    #[must_use]
+    pub fn include_cookies(mut self, yes: bool) -> Self {
+        self.include_cookies = yes;
+        self
+    }
+
+    /// Sets whether the user is allowed to change the GraphQL endpoint
+    /// (disabled by default).
+    #[must_use]
+    pub fn endpoint_is_editable(mut self, yes: bool) -> Self {
+        self.endpoint_is_editable = yes;
+        self
+    }
+
+    /// Sets whether the schema is periodically re-introspected (enabled by
+    /// default).
+    #[must_use]
+    pub fn poll_for_schema_updates(mut self, yes: bool) -> Self {
+        self.poll_for_schema_updates = yes;
+        self
+    }
+}
+
+/// Generate the HTML source to show an [Apollo Sandbox] interface
+///
+/// The page embeds the sandbox from Apollo's CDN (see
+/// [`EMBEDDABLE_SANDBOX_SCRIPT_URL`]), so no Apollo account is required to use
+/// it. For example:
+///
+/// ```
+/// # use juniper::http::apollo_sandbox::{apollo_sandbox_source, ApolloSandboxOptions};
+/// let sandbox = apollo_sandbox_source("/graphql", &ApolloSandboxOptions::new());
+/// ```
+///
+/// Relative endpoint URLs are resolved against the page's location.
+///
+/// [Apollo Sandbox]: https://www.apollographql.com/docs/graphos/explorer/sandbox
+pub fn apollo_sandbox_source(graphql_endpoint_url: &str, options: &ApolloSandboxOptions) -> String {
+    let mut headers = String::from("{");
+    for (i, (name, value)) in options.headers.iter().enumerate() {
+        if i > 0 {
+            headers.push(',');
+        }
+        write!(headers, "{}:{}", js_string(name), js_string(value)).unwrap();
+    }
+    headers.push('}');
+
+    let document = options
+        .initial_document
+        .as_deref()
+        .map_or_else(|| "undefined".into(), js_string);
+
+    format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>GraphQL</title>
+    <style>
+        html, body, #sandbox {{
+            height: 100%;
+            margin: 0;
+            overflow: hidden;
+            width: 100%;
+        }}
+    </style>
+</head>
+<body>
+    <div id="sandbox"></div>
+    <script src="{script_url}"></script>
+    <script>
+        new window.EmbeddedSandbox({{
+            target: '#sandbox',
+            initialEndpoint: new URL({graphql_url}, location.href).href,
+            includeCookies: {include_cookies},
+            endpointIsEditable: {endpoint_is_editable},
+            initialState: {{
+                document: {document},
+                headers: {headers},
+                pollForSchemaUpdates: {poll_for_schema_updates},
+            }},
+        }});
+    </script>
+</body>
+</html>
+"#,
+        script_url = EMBEDDABLE_SANDBOX_SCRIPT_URL,
+        graphql_url = js_string(graphql_endpoint_url),
+        include_cookies = options.include_cookies,
+        endpoint_is_editable = options.endpoint_is_editable,
+        document = document,
+        headers = headers,
+        poll_for_schema_updates = options.poll_for_schema_updates,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apollo_sandbox_source, ApolloSandboxOptions};
+
+    #[test]
+    fn renders_options() {
+        let html = apollo_sandbox_source(
+            "/graphql",
+            &ApolloSandboxOptions::new()
+                .initial_document("{ hero { name } }")
+                .header("Authorization", "Bearer token")
+                .header("X-Tenant", "1")
+                .include_cookies(true),
+        );
+
+        assert!(html.contains(r#"initialEndpoint: new URL('/graphql', location.href).href"#));
+        assert!(html.contains("includeCookies: true"));
+        assert!(html.contains("endpointIsEditable: false"));
+        assert!(html.contains("document: '{ hero { name } }'"));
+        assert!(html.contains("headers: {'Authorization':'Bearer token','X-Tenant':'1'}"));
+        assert!(html.contains("pollForSchemaUpdates: true"));
+    }
+
+    #[test]
+    fn renders_defaults() {
+        let html = apollo_sandbox_source("http://localhost:8080/graphql", &Default::default());
+
+        assert!(html.contains("document: undefined"));
+        assert!(html.contains("headers: {}"));
+        assert!(html.contains("pollForSchemaUpdates: true"));
+    }
+}
@@ -0,0 +1,558 @@
+//! Opt-in cache of whole responses to identical query requests.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    executor::{Extension, FieldInfo},
+    value::{DefaultScalarValue, ScalarValue},
+    GraphQLType, GraphQLTypeAsync, RootNode, Value,
+};
+
+use super::{GraphQLRequest, GraphQLResponse};
+
+/// Cache hint of a field, limiting how long responses containing it may be
+/// cached for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CacheHint {
+    max_age: Duration,
+    private: bool,
+}
+
+impl CacheHint {
+    /// Creates a new [`CacheHint`] allowing to cache the field for the provided
+    /// `max_age`.
+    #[must_use]
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            private: false,
+        }
+    }
+
+    /// Marks the field as private, so responses containing it are cached only
+    /// within a [`CacheKey`] scope (like the one of a user).
+    #[must_use]
+    pub fn private(mut self) -> Self {
+        self.private = true;
+        self
+    }
+
+    /// Returns the maximum age of the field.
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Indicates whether the field is private.
+    pub fn is_private(&self) -> bool {
+        self.private
+    }
+}
+
+/// Key identifying cached responses: the document, the operation name and the
+/// variables of a request, along with an optional cache scope.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CacheKey {
+    document: String,
+    operation_name: Option<String>,
+    variables: String,
+    scope: Option<String>,
+}
+
+impl CacheKey {
+    /// Creates a new [`CacheKey`] of the provided `request` within the
+    /// provided `scope`.
+    pub fn new<S: ScalarValue>(request: &GraphQLRequest<S>, scope: Option<&str>) -> Self {
+        Self {
+            document: request.query.clone(),
+            operation_name: request.operation_name.clone(),
+            variables: request
+                .variables
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            scope: scope.map(Into::into),
+        }
+    }
+}
+
+/// Response stored in a [`CacheStorage`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CachedResponse<S = DefaultScalarValue> {
+    /// Data of the response.
+    pub data: Value<S>,
+
+    /// Time this response expires at.
+    pub expires_at: SystemTime,
+}
+
+/// Storage of a [`ResponseCache`].
+///
+/// Expired responses are never served, so storages are free to keep them for
+/// as long as they want.
+pub trait CacheStorage<S = DefaultScalarValue>: Send + Sync {
+    /// Returns the response stored under the provided `key`, if any.
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse<S>>;
+
+    /// Stores the provided `response` under the provided `key`.
+    fn insert(&self, key: CacheKey, response: CachedResponse<S>);
+}
+
+/// In-memory [`CacheStorage`] evicting the least recently used responses once
+/// its capacity is exceeded.
+pub struct LruCacheStorage<S = DefaultScalarValue> {
+    capacity: usize,
+    state: Mutex<LruState<S>>,
+}
+
+struct LruState<S> {
+    entries: HashMap<CacheKey, (CachedResponse<S>, u64)>,
+    recency: BTreeMap<u64, CacheKey>,
+    tick: u64,
+}
+
+impl<S> fmt::Debug for LruCacheStorage<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LruCacheStorage")
+            .field("capacity", &self.capacity)
+            .field("len", &self.state.lock().unwrap().entries.len())
+            .finish()
+    }
+}
+
+impl<S> LruCacheStorage<S> {
+    /// Creates a new [`LruCacheStorage`] holding up to `capacity` responses.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(LruState {
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+}
+
+impl<S> LruState<S> {
+    /// Marks the entry of the provided `key` as the most recently used one.
+    fn touch(&mut self, key: &CacheKey) -> Option<&CachedResponse<S>> {
+        self.tick += 1;
+        let (response, tick) = self.entries.get_mut(key)?;
+        let key = self.recency.remove(tick)?;
+        *tick = self.tick;
+        self.recency.insert(self.tick, key);
+        Some(response)
+    }
+}
+
+impl<S: Clone + Send> CacheStorage<S> for LruCacheStorage<S> {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse<S>> {
+        self.state.lock().unwrap().touch(key).cloned()
+    }
+
+    fn insert(&self, key: CacheKey, response: CachedResponse<S>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.tick += 1;
+        let tick = state.tick;
+        if let Some((_, old)) = state.entries.insert(key.clone(), (response, tick)) {
+            state.recency.remove(&old);
+        }
+        state.recency.insert(tick, key);
+
+        while state.entries.len() > self.capacity {
+            let oldest = *state.recency.keys().next().unwrap();
+            let lru = state.recency.remove(&oldest).unwrap();
+            state.entries.remove(&lru);
+        }
+    }
+}
+
+/// Cache of whole responses, short-circuiting the execution of identical
+/// query requests.
+///
+/// A response is cached only if it has no errors and has resolved at least one
+/// field with a [`CacheHint`], for the smallest [`CacheHint::max_age()`] among
+/// the resolved fields. Responses resolving [private](CacheHint::private)
+/// fields are cached only within a scope. Mutations and requests with
+/// `extensions` (like tracing) are never cached.
+///
+/// Expiration is measured with the [`Clock`] of the [`RootNode`]'s
+/// [`ExecutionOptions`].
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use juniper::{
+/// #     graphql_object,
+/// #     http::{cache::{CacheHint, LruCacheStorage, ResponseCache}, GraphQLRequest},
+/// #     EmptyMutation, EmptySubscription, RootNode,
+/// # };
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn motd() -> &'static str {
+///         "Hello"
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let cache = ResponseCache::new(LruCacheStorage::new(1024))
+///     .hint("Query", "motd", CacheHint::new(Duration::from_secs(60)));
+///
+/// let request = GraphQLRequest::new("{ motd }".into(), None, None);
+/// let response = cache.execute(&request, &schema, &(), None).await;
+/// # assert!(response.is_ok());
+/// # }
+/// ```
+///
+/// [`Clock`]: crate::Clock
+/// [`ExecutionOptions`]: crate::ExecutionOptions
+pub struct ResponseCache<S = DefaultScalarValue> {
+    storage: Arc<dyn CacheStorage<S>>,
+    hints: Arc<HashMap<(String, String), CacheHint>>,
+}
+
+impl<S> fmt::Debug for ResponseCache<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("hints", &self.hints)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> ResponseCache<S>
+where
+    S: ScalarValue + Send + Sync,
+{
+    /// Creates a new [`ResponseCache`] backed by the provided `storage`.
+    #[must_use]
+    pub fn new(storage: impl CacheStorage<S> + 'static) -> Self {
+        Self {
+            storage: Arc::new(storage),
+            hints: Arc::new(HashMap::new()),
+        }
+    }
+
+    /// Attaches the provided [`CacheHint`] to the `field_name` field of the
+    /// `type_name` type.
+    #[must_use]
+    pub fn hint(
+        mut self,
+        type_name: impl Into<String>,
+        field_name: impl Into<String>,
+        hint: CacheHint,
+    ) -> Self {
+        Arc::make_mut(&mut self.hints).insert((type_name.into(), field_name.into()), hint);
+        self
+    }
+
+    /// Executes the provided `request`, unless its response is cached already
+    /// within the provided `scope`.
+    ///
+    /// This is a caching wrapper around [`GraphQLRequest::execute()`].
+    pub async fn execute<'a, QueryT, MutationT, SubscriptionT>(
+        &self,
+        request: &'a GraphQLRequest<S>,
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+        scope: Option<&str>,
+    ) -> GraphQLResponse<S>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+        SubscriptionT::TypeInfo: Sync,
+    {
+        if request.extensions.is_some() {
+            return request.execute(root_node, context).await;
+        }
+
+        let clock = root_node.execution_options().get_clock().clone();
+        let key = CacheKey::new(request, scope);
+        if let Some(cached) = self.storage.get(&key) {
+            if clock.now() < cached.expires_at {
+                return GraphQLResponse::from_result(Ok((cached.data, vec![])));
+            }
+        }
+
+        let recorder = Arc::new(HintRecorder {
+            hints: self.hints.clone(),
+            mutation_type: root_node.schema.mutation_type_name.clone(),
+            state: Mutex::default(),
+        });
+        let options = request
+            .execution_options(root_node)
+            .extension(recorder.clone());
+        let res = crate::execute_with_options(
+            &request.query,
+            request.operation_name.as_deref(),
+            root_node,
+            &request.variables(),
+            context,
+            &options,
+        )
+        .await;
+
+        if let Ok((data, errors)) = &res {
+            let state = recorder.state.lock().unwrap();
+            let cacheable =
+                errors.is_empty() && !state.mutation && (scope.is_some() || !state.private);
+            if let (true, Some(max_age)) = (cacheable, state.max_age) {
+                if !max_age.is_zero() {
+                    self.storage.insert(
+                        key,
+                        CachedResponse {
+                            data: data.clone(),
+                            expires_at: clock.now() + max_age,
+                        },
+                    );
+                }
+            }
+        }
+        GraphQLResponse::from_result(res)
+    }
+}
+
+/// [`Extension`] collecting the [`CacheHint`]s of the resolved fields.
+#[derive(Debug)]
+struct HintRecorder {
+    hints: Arc<HashMap<(String, String), CacheHint>>,
+    mutation_type: Option<String>,
+    state: Mutex<HintState>,
+}
+
+#[derive(Debug, Default)]
+struct HintState {
+    max_age: Option<Duration>,
+    private: bool,
+    mutation: bool,
+}
+
+impl Extension for HintRecorder {
+    fn field_start(&self, field: &FieldInfo<'_>) {
+        let mut state = self.state.lock().unwrap();
+        if self.mutation_type.as_deref() == Some(field.parent_type) {
+            state.mutation = true;
+        }
+        let key = (field.parent_type.to_owned(), field.field_name.to_owned());
+        if let Some(hint) = self.hints.get(&key) {
+            state.max_age = Some(match state.max_age {
+                Some(age) => age.min(hint.max_age),
+                None => hint.max_age,
+            });
+            state.private |= hint.private;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::{
+        graphql_object, graphql_value, EmptyMutation, EmptySubscription, ExecutionOptions,
+        InputValue, MockClock,
+    };
+
+    use super::*;
+
+    struct Context {
+        calls: AtomicUsize,
+    }
+
+    impl crate::Context for Context {}
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn motd(ctx: &Context) -> i32 {
+            ctx.calls.fetch_add(1, Ordering::SeqCst) as i32
+        }
+
+        fn me(ctx: &Context) -> i32 {
+            ctx.calls.fetch_add(1, Ordering::SeqCst) as i32
+        }
+
+        fn uncached(ctx: &Context) -> i32 {
+            ctx.calls.fetch_add(1, Ordering::SeqCst) as i32
+        }
+
+        fn echo(value: i32) -> i32 {
+            value
+        }
+    }
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+
+    fn schema(clock: Arc<MockClock>) -> Schema {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .with_execution_options(ExecutionOptions::default().shared_clock(clock))
+    }
+
+    fn cache() -> ResponseCache {
+        ResponseCache::new(LruCacheStorage::new(16))
+            .hint("Query", "motd", CacheHint::new(Duration::from_secs(60)))
+            .hint("Query", "echo", CacheHint::new(Duration::from_secs(60)))
+            .hint(
+                "Query",
+                "me",
+                CacheHint::new(Duration::from_secs(10)).private(),
+            )
+    }
+
+    fn request(query: &str) -> GraphQLRequest {
+        GraphQLRequest::new(query.into(), None, None)
+    }
+
+    async fn data(
+        cache: &ResponseCache,
+        schema: &Schema,
+        ctx: &Context,
+        req: &GraphQLRequest,
+        scope: Option<&str>,
+    ) -> Value {
+        let res = cache.execute(req, schema, ctx, scope).await;
+        res.result.unwrap().0
+    }
+
+    #[tokio::test]
+    async fn caches_hinted_responses_until_expired() {
+        let clock = Arc::new(MockClock::default());
+        let schema = schema(clock.clone());
+        let cache = cache();
+        let ctx = Context {
+            calls: AtomicUsize::new(0),
+        };
+        let req = request("{ motd }");
+
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req, None).await,
+            graphql_value!({"motd": 0}),
+        );
+        clock.advance(Duration::from_secs(59));
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req, None).await,
+            graphql_value!({"motd": 0}),
+        );
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req, None).await,
+            graphql_value!({"motd": 1}),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_smallest_max_age() {
+        let clock = Arc::new(MockClock::default());
+        let schema = schema(clock.clone());
+        let cache = cache();
+        let ctx = Context {
+            calls: AtomicUsize::new(0),
+        };
+
+        let req = request("{ motd me }");
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req, Some("user")).await,
+            graphql_value!({"motd": 0, "me": 1}),
+        );
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req, Some("user")).await,
+            graphql_value!({"motd": 2, "me": 3}),
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_unhinted_and_private_unscoped_responses() {
+        let clock = Arc::new(MockClock::default());
+        let schema = schema(clock);
+        let cache = cache();
+        let ctx = Context {
+            calls: AtomicUsize::new(0),
+        };
+
+        for req in [request("{ uncached }"), request("{ me }")] {
+            let first = data(&cache, &schema, &ctx, &req, None).await;
+            let second = data(&cache, &schema, &ctx, &req, None).await;
+            assert_ne!(first, second);
+        }
+    }
+
+    #[tokio::test]
+    async fn keys_by_variables_and_scope() {
+        let clock = Arc::new(MockClock::default());
+        let schema = schema(clock);
+        let cache = cache();
+        let ctx = Context {
+            calls: AtomicUsize::new(0),
+        };
+
+        let req = |value: i32| {
+            GraphQLRequest::new(
+                "query($v: Int!) { echo(value: $v) }".into(),
+                None,
+                Some(InputValue::object(
+                    [("v", InputValue::scalar(value))].into_iter().collect(),
+                )),
+            )
+        };
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req(1), None).await,
+            graphql_value!({"echo": 1}),
+        );
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req(2), None).await,
+            graphql_value!({"echo": 2}),
+        );
+
+        let req = request("{ me }");
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req, Some("alice")).await,
+            graphql_value!({"me": 0}),
+        );
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req, Some("bob")).await,
+            graphql_value!({"me": 1}),
+        );
+        assert_eq!(
+            data(&cache, &schema, &ctx, &req, Some("alice")).await,
+            graphql_value!({"me": 0}),
+        );
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let storage = LruCacheStorage::<DefaultScalarValue>::new(2);
+        let key = |q: &str| CacheKey::new(&request(q), None);
+        let response = |n: i32| CachedResponse {
+            data: graphql_value!(n),
+            expires_at: SystemTime::UNIX_EPOCH,
+        };
+
+        storage.insert(key("a"), response(1));
+        storage.insert(key("b"), response(2));
+        assert!(storage.get(&key("a")).is_some());
+        storage.insert(key("c"), response(3));
+
+        assert_eq!(storage.get(&key("a")), Some(response(1)));
+        assert_eq!(storage.get(&key("b")), None);
+        assert_eq!(storage.get(&key("c")), Some(response(3)));
+    }
+}
@@ -0,0 +1,60 @@
+//! [ETag]-based conditional responses for queries executed via GET requests.
+//!
+//! [ETag]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/ETag
+
+use std::hash::Hasher as _;
+
+use fnv::FnvHasher;
+
+/// Computes a strong [ETag] of the provided serialized response `body`.
+///
+/// The ETag is derived from the length and the [FNV-1a] hash of the `body`,
+/// so identical responses always have identical ETags, regardless of the
+/// process or machine they're rendered on. This makes it suitable for being
+/// validated by CDNs and shared caches.
+///
+/// [ETag]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/ETag
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler–Noll–Vo_hash_function
+pub fn etag(body: &[u8]) -> String {
+    let mut hasher = FnvHasher::default();
+    hasher.write(body);
+    format!("\"{:x}-{:016x}\"", body.len(), hasher.finish())
+}
+
+/// Indicates whether the provided `If-None-Match` header value matches the
+/// provided `etag`, so a `304 Not Modified` response should be returned
+/// instead of the full one.
+///
+/// As required by [RFC 7232], weak comparison is used, so `W/` prefixes are
+/// ignored.
+///
+/// [RFC 7232]: https://www.rfc-editor.org/rfc/rfc7232#section-3.2
+pub fn if_none_match(header: &str, etag: &str) -> bool {
+    let etag = etag.trim_start_matches("W/");
+    header
+        .split(',')
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{etag, if_none_match};
+
+    #[test]
+    fn etag_is_stable() {
+        assert_eq!(etag(br#"{"data":null}"#), etag(br#"{"data":null}"#));
+        assert_ne!(etag(br#"{"data":null}"#), etag(br#"{"data":1}"#));
+        assert!(etag(b"").starts_with('"') && etag(b"").ends_with('"'));
+    }
+
+    #[test]
+    fn matches_if_none_match() {
+        let tag = etag(br#"{"data":null}"#);
+
+        assert!(if_none_match(&tag, &tag));
+        assert!(if_none_match(&format!("\"other\", W/{tag}"), &tag));
+        assert!(if_none_match("*", &tag));
+        assert!(!if_none_match("\"other\"", &tag));
+    }
+}
@@ -1,5 +1,110 @@
 //! Utility module to generate a GraphiQL interface
 
+use std::fmt::Write as _;
+
+use super::js_string;
+
+/// Protocol used by a GraphiQL interface to execute subscriptions over a
+/// WebSocket connection.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SubscriptionTransport {
+    /// Legacy [`graphql-ws` protocol][1] of the `subscriptions-transport-ws`
+    /// library.
+    ///
+    /// [1]: https://github.com/apollographql/subscriptions-transport-ws/blob/v0.11.0/PROTOCOL.md
+    #[default]
+    GraphQLWs,
+
+    /// [`graphql-transport-ws` protocol][1] of the `graphql-ws` library.
+    ///
+    /// [1]: https://github.com/enisdenjo/graphql-ws/blob/v5.11.2/PROTOCOL.md
+    GraphQLTransportWs,
+}
+
+/// Options of a GraphiQL interface generated by
+/// [`graphiql_source_with_options()`].
+///
+/// ```
+/// # use juniper::http::graphiql::{GraphiQLOptions, SubscriptionTransport};
+/// let options = GraphiQLOptions::new()
+///     .subscriptions_endpoint("/subscriptions")
+///     .subscription_transport(SubscriptionTransport::GraphQLTransportWs)
+///     .header("Authorization", "Bearer token")
+///     .default_query("{ hero { name } }")
+///     .editor_theme("dracula")
+///     .explorer_plugin(true);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GraphiQLOptions {
+    subscriptions_endpoint: Option<String>,
+    subscription_transport: SubscriptionTransport,
+    headers: Vec<(String, String)>,
+    default_query: Option<String>,
+    editor_theme: Option<String>,
+    explorer_plugin: bool,
+}
+
+impl GraphiQLOptions {
+    /// Creates new [`GraphiQLOptions`] without subscriptions support.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables subscriptions, executing them over a WebSocket connection to
+    /// the provided endpoint.
+    ///
+    /// A relative endpoint URL is resolved against the page's location.
+    #[must_use]
+    pub fn subscriptions_endpoint(mut self, url: impl Into<String>) -> Self {
+        self.subscriptions_endpoint = Some(url.into());
+        self
+    }
+
+    /// Sets the [`SubscriptionTransport`] to execute subscriptions with
+    /// ([`SubscriptionTransport::GraphQLWs`] by default).
+    #[must_use]
+    pub fn subscription_transport(mut self, transport: SubscriptionTransport) -> Self {
+        self.subscription_transport = transport;
+        self
+    }
+
+    /// Adds a header the headers editor is pre-filled with, so it's sent with
+    /// every operation unless the user changes or removes it.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the query the editor is pre-filled with when no query has been
+    /// persisted by the browser yet.
+    #[must_use]
+    pub fn default_query(mut self, query: impl Into<String>) -> Self {
+        self.default_query = Some(query.into());
+        self
+    }
+
+    /// Sets the [CodeMirror theme][1] of the editors (`graphiql` by default).
+    ///
+    /// [1]: https://codemirror.net/5/demo/theme.html
+    #[must_use]
+    pub fn editor_theme(mut self, theme: impl Into<String>) -> Self {
+        self.editor_theme = Some(theme.into());
+        self
+    }
+
+    /// Sets whether the [explorer plugin][1] is enabled, allowing to build
+    /// queries by selecting fields (disabled by default).
+    ///
+    /// [1]: https://github.com/graphql/graphiql/tree/main/packages/graphiql-plugin-explorer
+    #[must_use]
+    pub fn explorer_plugin(mut self, yes: bool) -> Self {
+        self.explorer_plugin = yes;
+        self
+    }
+}
+
 /// Generate the HTML source to show a GraphiQL interface
 ///
 /// The subscriptions endpoint URL can optionally be provided. For example:
@@ -8,16 +113,33 @@
 /// # use juniper::http::graphiql::graphiql_source;
 /// let graphiql = graphiql_source("/graphql", Some("ws://localhost:8080/subscriptions"));
 /// ```
+///
+/// Use [`graphiql_source_with_options()`] to configure the interface further.
 pub fn graphiql_source(
     graphql_endpoint_url: &str,
     subscriptions_endpoint_url: Option<&str>,
 ) -> String {
-    let subscriptions_endpoint = if let Some(sub_url) = subscriptions_endpoint_url {
-        sub_url
-    } else {
-        ""
-    };
+    let mut options = GraphiQLOptions::new();
+    if let Some(url) = subscriptions_endpoint_url {
+        options = options.subscriptions_endpoint(url);
+    }
+    graphiql_source_with_options(graphql_endpoint_url, &options)
+}
 
+/// Generate the HTML source to show a GraphiQL interface configured with the
+/// provided [`GraphiQLOptions`]
+///
+/// ```
+/// # use juniper::http::graphiql::{graphiql_source_with_options, GraphiQLOptions};
+/// let graphiql = graphiql_source_with_options(
+///     "/graphql",
+///     &GraphiQLOptions::new().default_query("{ hero { name } }"),
+/// );
+/// ```
+pub fn graphiql_source_with_options(
+    graphql_endpoint_url: &str,
+    options: &GraphiQLOptions,
+) -> String {
     let stylesheet_source = r#"
     <style>
         html, body, #app {
@@ -28,57 +150,131 @@ pub fn graphiql_source(
         }
     </style>
     "#;
-    let fetcher_source = r#"
-    <script>
-        if (usingSubscriptions) {
-            var subscriptionEndpoint = normalizeSubscriptionEndpoint(GRAPHQL_URL, GRAPHQL_SUBSCRIPTIONS_URL);
-            var subscriptionsClient = new window.SubscriptionsTransportWs.SubscriptionClient(subscriptionEndpoint, { reconnect: true });
+
+    let mut extra_stylesheets = String::new();
+    let mut extra_scripts = String::new();
+    if let Some(theme) = &options.editor_theme {
+        let theme = theme.replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "");
+        writeln!(
+            extra_stylesheets,
+            r#"    <link rel="stylesheet" type="text/css" href="//unpkg.com/codemirror@5.65.12/theme/{theme}.css">"#,
+        )
+        .unwrap();
+    }
+    if options.explorer_plugin {
+        extra_stylesheets.push_str(
+            r#"    <link rel="stylesheet" type="text/css" href="//unpkg.com/@graphiql/plugin-explorer@0.1.20/dist/style.css">"#,
+        );
+        extra_stylesheets.push('\n');
+        extra_scripts.push_str(
+            r#"    <script src="//unpkg.com/@graphiql/plugin-explorer@0.1.20/dist/graphiql-plugin-explorer.umd.js"></script>"#,
+        );
+        extra_scripts.push('\n');
+    }
+
+    let subscriptions_client = match options.subscription_transport {
+        SubscriptionTransport::GraphQLWs => {
+            if options.subscriptions_endpoint.is_some() {
+                extra_scripts.push_str(
+                    r#"    <script src="//unpkg.com/subscriptions-transport-ws@0.11.0/browser/client.js"></script>"#,
+                );
+                extra_scripts.push('\n');
+            }
+            "fetcherOptions.legacyClient = new window.SubscriptionsTransportWs.SubscriptionClient(subscriptionEndpoint, { reconnect: true });"
+        }
+        SubscriptionTransport::GraphQLTransportWs => {
+            if options.subscriptions_endpoint.is_some() {
+                extra_scripts.push_str(
+                    r#"    <script src="//unpkg.com/graphql-ws@5.11.2/umd/graphql-ws.min.js"></script>"#,
+                );
+                extra_scripts.push('\n');
+            }
+            "fetcherOptions.wsClient = window.graphqlWs.createClient({ url: subscriptionEndpoint, lazy: true });"
         }
+    };
 
-        function normalizeSubscriptionEndpoint(endpoint, subscriptionEndpoint) {
-            if (subscriptionEndpoint) {
-                if (subscriptionEndpoint.startsWith('/')) {
+    let mut headers = String::new();
+    for (name, value) in &options.headers {
+        if !headers.is_empty() {
+            headers.push(',');
+        }
+        write!(headers, "{}:{}", js_string(name), js_string(value)).unwrap();
+    }
+    let headers = if headers.is_empty() {
+        "undefined".into()
+    } else {
+        format!("JSON.stringify({{{headers}}}, null, 2)")
+    };
+
+    let fetcher_source = format!(
+        r#"
+    <script>
+        function normalizeSubscriptionEndpoint(endpoint, subscriptionEndpoint) {{
+            if (subscriptionEndpoint) {{
+                if (subscriptionEndpoint.startsWith('/')) {{
                     const secure =
                         endpoint.includes('https') || location.href.includes('https')
                         ? 's'
                         : ''
-                    return `ws${secure}://${location.host}${subscriptionEndpoint}`
-                } else {
+                    return `ws${{secure}}://${{location.host}}${{subscriptionEndpoint}}`
+                }} else {{
                     return subscriptionEndpoint.replace(/^http/, 'ws')
-                }
-            }
+                }}
+            }}
             return null
-        }
+        }}
 
-        function graphQLFetcher(params) {
-            return fetch(GRAPHQL_URL, {
-                method: 'post',
-                headers: {
-                    'Accept': 'application/json',
-                    'Content-Type': 'application/json',
-                },
-                credentials: 'include',
-                body: JSON.stringify(params)
-            }).then(function (response) {
-                return response.text();
-            }).then(function (body) {
-                try {
-                    return JSON.parse(body);
-                } catch (error) {
-                    return body;
-                }
-            });
-        }
+        var fetcherOptions = {{
+            url: GRAPHQL_URL,
+            fetch: function (url, init) {{
+                return window.fetch(url, Object.assign({{ credentials: 'include' }}, init));
+            }},
+        }};
+        if (usingSubscriptions) {{
+            var subscriptionEndpoint = normalizeSubscriptionEndpoint(GRAPHQL_URL, GRAPHQL_SUBSCRIPTIONS_URL);
+            {subscriptions_client}
+        }}
+
+        var props = {{
+            fetcher: GraphiQL.createFetcher(fetcherOptions),
+            defaultQuery: {default_query},
+            headers: {headers},
+            editorTheme: {editor_theme},
+        }};
 
-        var fetcher = usingSubscriptions ? window.GraphiQLSubscriptionsFetcher.graphQLFetcher(subscriptionsClient, graphQLFetcher) : graphQLFetcher;
+        var usingExplorer = {explorer_plugin};
 
-        ReactDOM.render(
-            React.createElement(GraphiQL, {
-                fetcher,
-            }),
-            document.querySelector('#app'));
+        function App() {{
+            if (!usingExplorer) {{
+                return React.createElement(GraphiQL, props);
+            }}
+            var [query, setQuery] = React.useState(props.defaultQuery);
+            var explorerPlugin = GraphiQLPluginExplorer.useExplorerPlugin({{
+                query: query,
+                onEdit: setQuery,
+            }});
+            return React.createElement(GraphiQL, Object.assign({{}}, props, {{
+                query: query,
+                onEditQuery: setQuery,
+                plugins: [explorerPlugin],
+            }}));
+        }}
+
+        ReactDOM.createRoot(document.querySelector('#app')).render(React.createElement(App));
     </script>
-    "#;
+    "#,
+        subscriptions_client = subscriptions_client,
+        default_query = options
+            .default_query
+            .as_deref()
+            .map_or_else(|| "undefined".into(), js_string),
+        headers = headers,
+        editor_theme = options
+            .editor_theme
+            .as_deref()
+            .map_or_else(|| "undefined".into(), js_string),
+        explorer_plugin = options.explorer_plugin,
+    );
 
     format!(
         r#"
@@ -87,27 +283,90 @@ pub fn graphiql_source(
 <head>
     <title>GraphQL</title>
     {stylesheet_source}
-    <link rel="stylesheet" type="text/css" href="//cdn.jsdelivr.net/npm/graphiql@0.17.5/graphiql.min.css">
+    <link rel="stylesheet" type="text/css" href="//unpkg.com/graphiql@2.4.7/graphiql.min.css">
+{extra_stylesheets}
 </head>
 <body>
     <div id="app"></div>
-    <script src="//cdnjs.cloudflare.com/ajax/libs/fetch/2.0.3/fetch.js"></script>
-    <script src="//unpkg.com/subscriptions-transport-ws@0.8.3/browser/client.js"></script>
-    <script src="//unpkg.com/graphiql-subscriptions-fetcher@0.0.2/browser/client.js"></script>
-    <script src="//cdnjs.cloudflare.com/ajax/libs/react/16.10.2/umd/react.production.min.js"></script>
-    <script src="//cdnjs.cloudflare.com/ajax/libs/react-dom/16.10.2/umd/react-dom.production.min.js"></script>
-    <script src="//cdn.jsdelivr.net/npm/graphiql@0.17.5/graphiql.min.js"></script>
-    <script>var GRAPHQL_URL = '{graphql_url}';</script>
+    <script src="//unpkg.com/react@18.2.0/umd/react.production.min.js"></script>
+    <script src="//unpkg.com/react-dom@18.2.0/umd/react-dom.production.min.js"></script>
+    <script src="//unpkg.com/graphiql@2.4.7/graphiql.min.js"></script>
+{extra_scripts}
+    <script>var GRAPHQL_URL = {graphql_url};</script>
     <script>var usingSubscriptions = {using_subscriptions};</script>
-    <script>var GRAPHQL_SUBSCRIPTIONS_URL = '{graphql_subscriptions_url}';</script>
+    <script>var GRAPHQL_SUBSCRIPTIONS_URL = {graphql_subscriptions_url};</script>
     {fetcher_source}
 </body>
 </html>
 "#,
-        graphql_url = graphql_endpoint_url,
+        graphql_url = js_string(graphql_endpoint_url),
         stylesheet_source = stylesheet_source,
+        extra_stylesheets = extra_stylesheets,
+        extra_scripts = extra_scripts,
         fetcher_source = fetcher_source,
-        graphql_subscriptions_url = subscriptions_endpoint,
-        using_subscriptions = subscriptions_endpoint_url.is_some(),
+        graphql_subscriptions_url = js_string(
+            options
+                .subscriptions_endpoint
+                .as_deref()
+                .unwrap_or_default()
+        ),
+        using_subscriptions = options.subscriptions_endpoint.is_some(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::js_string, graphiql_source, graphiql_source_with_options, GraphiQLOptions,
+        SubscriptionTransport,
+    };
+
+    #[test]
+    fn escapes_js_strings() {
+        assert_eq!(js_string("/graphql"), "'/graphql'");
+        assert_eq!(
+            js_string("a'b\\c\n</script>"),
+            r"'a\'b\\c\n\u003c/script\u003e'",
+        );
+    }
+
+    #[test]
+    fn renders_endpoints() {
+        let html = graphiql_source("/graphql", Some("/subscriptions"));
+
+        assert!(html.contains("<script>var GRAPHQL_URL = '/graphql';</script>"));
+        assert!(html.contains("<script>var usingSubscriptions = true;</script>"));
+        assert!(html.contains("<script>var GRAPHQL_SUBSCRIPTIONS_URL = '/subscriptions';</script>"));
+        assert!(html.contains("subscriptions-transport-ws"));
+        assert!(html.contains("fetcherOptions.legacyClient"));
+        assert!(html.contains("graphiql@2.4.7"));
+
+        let html = graphiql_source("/graphql", None);
+
+        assert!(html.contains("<script>var usingSubscriptions = false;</script>"));
+        assert!(!html.contains("subscriptions-transport-ws@"));
+    }
+
+    #[test]
+    fn renders_options() {
+        let html = graphiql_source_with_options(
+            "/graphql",
+            &GraphiQLOptions::new()
+                .subscriptions_endpoint("ws://localhost:8080/subscriptions")
+                .subscription_transport(SubscriptionTransport::GraphQLTransportWs)
+                .header("Authorization", "Bearer token")
+                .default_query("{ hero { name } }")
+                .editor_theme("dracula")
+                .explorer_plugin(true),
+        );
+
+        assert!(html.contains("graphql-ws@5.11.2/umd/graphql-ws.min.js"));
+        assert!(html.contains("fetcherOptions.wsClient"));
+        assert!(html.contains("headers: JSON.stringify({'Authorization':'Bearer token'}, null, 2)"));
+        assert!(html.contains("defaultQuery: '{ hero { name } }'"));
+        assert!(html.contains("editorTheme: 'dracula'"));
+        assert!(html.contains("codemirror@5.65.12/theme/dracula.css"));
+        assert!(html.contains("graphiql-plugin-explorer.umd.js"));
+        assert!(html.contains("var usingExplorer = true;"));
+    }
+}
@@ -1,7 +1,15 @@
 //! Utilities for building HTTP endpoints in a library-agnostic manner
 
+pub mod apollo_sandbox;
+pub mod cache;
+pub mod etag;
 pub mod graphiql;
+pub mod multipart;
 pub mod playground;
+pub mod problem;
+pub mod variables;
+
+use std::sync::Arc;
 
 use serde::{
     de,
@@ -11,8 +19,12 @@ use serde::{
 
 use crate::{
     ast::InputValue,
-    executor::{ExecutionError, ValuesStream},
-    value::{DefaultScalarValue, ScalarValue},
+    executor::{
+        ApolloTracing, CancellationToken, Correlation, ExecutionError, ExecutionOptions,
+        IncrementalExecutionOutput, ResponseExtensions, ValuesStream, APOLLO_TRACING_EXTENSION,
+    },
+    integrations::serde::ErrorLocations,
+    value::{DefaultScalarValue, Object, ScalarValue},
     FieldError, GraphQLError, GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync, RootNode,
     Value, Variables,
 };
@@ -42,6 +54,23 @@ where
         serialize = "InputValue<S>: Serialize",
     ))]
     pub variables: Option<InputValue<S>>,
+
+    /// Optional implementation-specific extensions of this request.
+    ///
+    /// Recognized extensions:
+    /// - `"dryRun": true` executes the operation in
+    ///   [dry-run mode](crate::ExecutionOptions::dry_run).
+    /// - `"tracing": true` emits the [Apollo tracing](crate::ApolloTracing) of
+    ///   the operation under the `"tracing"` extension of its response.
+    #[serde(
+        bound(
+            deserialize = "InputValue<S>: Deserialize<'de>",
+            serialize = "InputValue<S>: Serialize",
+        ),
+        default = "Option::default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub extensions: Option<InputValue<S>>,
 }
 
 impl<S> GraphQLRequest<S>
@@ -76,7 +105,59 @@ where
             query,
             operation_name,
             variables,
+            extensions: None,
+        }
+    }
+
+    /// Returns the [`ExecutionOptions`] to execute this request with against
+    /// the provided [`RootNode`], taking into account the `extensions` of this
+    /// request.
+    pub fn execution_options<QueryT, MutationT, SubscriptionT>(
+        &self,
+        root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    ) -> ExecutionOptions
+    where
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+    {
+        let mut options = root_node.execution_options().clone();
+        if let Some(dry_run) = self.extension_flag("dryRun") {
+            options = options.dry_run(dry_run);
+        }
+        options
+    }
+
+    /// Returns the boolean value of the `name`d extension of this request, if
+    /// any.
+    fn extension_flag(&self, name: &str) -> Option<bool> {
+        self.extensions
+            .as_ref()
+            .and_then(|e| e.to_object_value())
+            .and_then(|ext| ext.get(name).copied())
+            .and_then(|v| v.as_scalar())
+            .and_then(|s| s.as_bool())
+    }
+
+    /// Registers an [`ApolloTracing`] in the provided [`ExecutionOptions`], if
+    /// it's requested by the `extensions` of this request.
+    fn traced(&self, options: ExecutionOptions) -> (ExecutionOptions, Option<Arc<ApolloTracing>>) {
+        if self.extension_flag("tracing") != Some(true) {
+            return (options, None);
         }
+        let tracing = Arc::new(ApolloTracing::with_clock(options.get_clock().clone()));
+        (options.extension(Arc::clone(&tracing)), Some(tracing))
+    }
+
+    /// Registers fresh [`ResponseExtensions`] in the provided
+    /// [`ExecutionOptions`], collecting the response `extensions` stashed by
+    /// resolvers while executing this request.
+    fn collecting(options: ExecutionOptions) -> (ExecutionOptions, Arc<ResponseExtensions>) {
+        let extensions = Arc::new(ResponseExtensions::new());
+        (
+            options.response_extensions(Arc::clone(&extensions)),
+            extensions,
+        )
     }
 
     /// Execute a GraphQL request synchronously using the specified schema and context
@@ -94,13 +175,43 @@ where
         MutationT: GraphQLType<S, Context = QueryT::Context>,
         SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
     {
-        GraphQLResponse(crate::execute_sync(
+        self.execute_sync_correlated(root_node, context, &Correlation::default())
+    }
+
+    /// Execute a GraphQL request synchronously using the specified schema and
+    /// context, correlated with the logs of its origin via the provided
+    /// [`Correlation`]
+    ///
+    /// See [`ExecutionOptions::correlation()`] for details.
+    pub fn execute_sync_correlated<QueryT, MutationT, SubscriptionT>(
+        &self,
+        root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+        context: &QueryT::Context,
+        correlation: &Correlation,
+    ) -> GraphQLResponse<S>
+    where
+        S: ScalarValue,
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S, Context = QueryT::Context>,
+        SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+    {
+        let (options, extensions) = Self::collecting(
+            self.execution_options(root_node)
+                .correlation(correlation.clone()),
+        );
+        let (options, tracing) = self.traced(options);
+        let res = crate::execute_sync_with_options(
             &self.query,
             self.operation_name.as_deref(),
             root_node,
             &self.variables(),
             context,
-        ))
+            &options,
+        );
+        GraphQLResponse::from_result(res)
+            .with_extensions(extensions.take())
+            .with_tracing(tracing.as_deref())
+            .with_source_ranges(options.includes_source_ranges())
     }
 
     /// Execute a GraphQL request using the specified schema and context
@@ -122,10 +233,120 @@ where
         SubscriptionT::TypeInfo: Sync,
         S: ScalarValue + Send + Sync,
     {
+        self.execute_correlated(root_node, context, &Correlation::default())
+            .await
+    }
+
+    /// Execute a GraphQL request using the specified schema and context,
+    /// correlated with the logs of its origin via the provided [`Correlation`]
+    ///
+    /// See [`ExecutionOptions::correlation()`] for details.
+    pub async fn execute_correlated<'a, QueryT, MutationT, SubscriptionT>(
+        &'a self,
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+        correlation: &Correlation,
+    ) -> GraphQLResponse<S>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+        SubscriptionT::TypeInfo: Sync,
+        S: ScalarValue + Send + Sync,
+    {
+        let options = self
+            .execution_options(root_node)
+            .correlation(correlation.clone());
+        self.execute_with(root_node, context, options).await
+    }
+
+    /// Execute a GraphQL request using the specified schema and context,
+    /// correlated with the logs of its origin via the provided [`Correlation`]
+    /// and cancelled along with the provided [`CancellationToken`]
+    ///
+    /// See [`ExecutionOptions::cancellation_token()`] for details.
+    pub async fn execute_cancellable<'a, QueryT, MutationT, SubscriptionT>(
+        &'a self,
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+        correlation: &Correlation,
+        cancellation: &CancellationToken,
+    ) -> GraphQLResponse<S>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+        SubscriptionT::TypeInfo: Sync,
+        S: ScalarValue + Send + Sync,
+    {
+        let options = self
+            .execution_options(root_node)
+            .correlation(correlation.clone())
+            .cancellation_token(cancellation.clone());
+        self.execute_with(root_node, context, options).await
+    }
+
+    async fn execute_with<'a, QueryT, MutationT, SubscriptionT>(
+        &'a self,
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+        options: ExecutionOptions,
+    ) -> GraphQLResponse<S>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+        SubscriptionT::TypeInfo: Sync,
+        S: ScalarValue + Send + Sync,
+    {
+        let (options, extensions) = Self::collecting(options);
+        let (options, tracing) = self.traced(options);
         let op = self.operation_name.as_deref();
         let vars = &self.variables();
-        let res = crate::execute(&self.query, op, root_node, vars, context).await;
-        GraphQLResponse(res)
+        let res =
+            crate::execute_with_options(&self.query, op, root_node, vars, context, &options).await;
+        GraphQLResponse::from_result(res)
+            .with_extensions(extensions.take())
+            .with_tracing(tracing.as_deref())
+            .with_source_ranges(options.includes_source_ranges())
+    }
+
+    /// Execute a GraphQL request using the specified schema and context,
+    /// delivering its `@defer`red fragments incrementally
+    ///
+    /// This is a simple wrapper around the `execute_incremental` function
+    /// exposed at the top level of this crate. Errors preventing the execution
+    /// are returned as a regular [`GraphQLResponse`].
+    pub async fn execute_incremental<'a, QueryT, MutationT, SubscriptionT>(
+        &'a self,
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+    ) -> Result<IncrementalExecutionOutput<'a, S>, GraphQLResponse<S>>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+        SubscriptionT::TypeInfo: Sync,
+        S: ScalarValue + Send + Sync,
+    {
+        let op = self.operation_name.as_deref();
+        let vars = &self.variables();
+        let options = &self.execution_options(root_node);
+        crate::execute_incremental_with_options(&self.query, op, root_node, vars, context, options)
+            .await
+            .map_err(|e| GraphQLResponse::from_result(Err(e)))
     }
 }
 
@@ -163,9 +384,11 @@ where
 /// to JSON and send it over the wire. Use the `is_ok` method to determine
 /// whether to send a 200 or 400 HTTP status code.
 #[derive(Debug)]
-pub struct GraphQLResponse<S = DefaultScalarValue>(
-    Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>,
-);
+pub struct GraphQLResponse<S = DefaultScalarValue> {
+    result: Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>,
+    extensions: Object<S>,
+    source_ranges: bool,
+}
 
 impl<S> GraphQLResponse<S>
 where
@@ -173,12 +396,69 @@ where
 {
     /// Constructs new `GraphQLResponse` using the given result
     pub fn from_result(r: Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>) -> Self {
-        Self(r)
+        Self {
+            result: r,
+            extensions: Object::with_capacity(0),
+            source_ranges: false,
+        }
     }
 
     /// Constructs an error response outside of the normal execution flow
     pub fn error(error: FieldError<S>) -> Self {
-        GraphQLResponse(Ok((Value::null(), vec![ExecutionError::at_origin(error)])))
+        Self::from_result(Ok((Value::null(), vec![ExecutionError::at_origin(error)])))
+    }
+
+    /// Adds the `name`d extension with the provided `value` to this response,
+    /// replacing the existing one, if any.
+    ///
+    /// Extensions are serialized under the `"extensions"` key of the response.
+    #[must_use]
+    pub fn with_extension(mut self, name: impl AsRef<str>, value: Value<S>) -> Self {
+        self.extensions.add_field(name.as_ref(), value);
+        self
+    }
+
+    /// Returns the extensions of this response.
+    pub fn extensions(&self) -> &Object<S> {
+        &self.extensions
+    }
+
+    /// Adds all the provided `extensions` to this response, replacing the
+    /// existing ones with the same names.
+    #[must_use]
+    pub fn with_extensions(mut self, extensions: Object<S>) -> Self {
+        for (name, value) in extensions {
+            self.extensions.add_field(name, value);
+        }
+        self
+    }
+
+    /// Sets whether the `"locations"` of the errors in this response are
+    /// serialized along with their source ranges, so tooling is able to
+    /// highlight the exact erroneous items of the query.
+    ///
+    /// Every location is extended with the `"offset"` of its byte in the query,
+    /// and, if known (for parse errors only), the `"end"` location pointing
+    /// right after the erroneous item:
+    /// ```json
+    /// {"line": 1, "column": 3, "offset": 2, "end": {"line": 1, "column": 8, "offset": 7}}
+    /// ```
+    ///
+    /// Is set from [`ExecutionOptions::source_ranges()`] when executing a
+    /// [`GraphQLRequest`].
+    #[must_use]
+    pub fn with_source_ranges(mut self, enabled: bool) -> Self {
+        self.source_ranges = enabled;
+        self
+    }
+
+    /// Adds the timings collected by the provided [`ApolloTracing`] (if any)
+    /// to this response.
+    fn with_tracing(self, tracing: Option<&ApolloTracing>) -> Self {
+        match tracing {
+            Some(t) => self.with_extension(APOLLO_TRACING_EXTENSION, t.to_value()),
+            None => self,
+        }
     }
 
     /// Was the request successful or not?
@@ -186,7 +466,7 @@ where
     /// Note that there still might be errors in the response even though it's
     /// considered OK. This is by design in GraphQL.
     pub fn is_ok(&self) -> bool {
-        self.0.is_ok()
+        self.result.is_ok()
     }
 }
 
@@ -200,27 +480,27 @@ where
     where
         S: ser::Serializer,
     {
-        match self.0 {
+        let mut map = serializer.serialize_map(None)?;
+        match self.result {
             Ok((ref res, ref err)) => {
-                let mut map = serializer.serialize_map(None)?;
-
                 map.serialize_key("data")?;
                 map.serialize_value(res)?;
 
                 if !err.is_empty() {
                     map.serialize_key("errors")?;
-                    map.serialize_value(err)?;
+                    map.serialize_value(&ErrorLocations::new(&err[..], self.source_ranges))?;
                 }
-
-                map.end()
             }
             Err(ref err) => {
-                let mut map = serializer.serialize_map(Some(1))?;
                 map.serialize_key("errors")?;
-                map.serialize_value(err)?;
-                map.end()
+                map.serialize_value(&ErrorLocations::new(err, self.source_ranges))?;
             }
         }
+        if self.extensions.field_count() > 0 {
+            map.serialize_key("extensions")?;
+            map.serialize_value(&self.extensions)?;
+        }
+        map.end()
     }
 }
 
@@ -269,18 +549,40 @@ where
         root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
         context: &QueryT::Context,
     ) -> GraphQLBatchResponse<S>
+    where
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S, Context = QueryT::Context>,
+        SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+    {
+        self.execute_sync_correlated(root_node, context, &Correlation::default())
+    }
+
+    /// Execute a GraphQL batch request synchronously using the specified schema
+    /// and context, correlating all its operations via the provided
+    /// [`Correlation`]
+    ///
+    /// This is a simple wrapper around the `execute_sync_correlated` function
+    /// exposed in GraphQLRequest.
+    pub fn execute_sync_correlated<'a, QueryT, MutationT, SubscriptionT>(
+        &'a self,
+        root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
+        context: &QueryT::Context,
+        correlation: &Correlation,
+    ) -> GraphQLBatchResponse<S>
     where
         QueryT: GraphQLType<S>,
         MutationT: GraphQLType<S, Context = QueryT::Context>,
         SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
     {
         match *self {
-            Self::Single(ref req) => {
-                GraphQLBatchResponse::Single(req.execute_sync(root_node, context))
-            }
+            Self::Single(ref req) => GraphQLBatchResponse::Single(req.execute_sync_correlated(
+                root_node,
+                context,
+                correlation,
+            )),
             Self::Batch(ref reqs) => GraphQLBatchResponse::Batch(
                 reqs.iter()
-                    .map(|req| req.execute_sync(root_node, context))
+                    .map(|req| req.execute_sync_correlated(root_node, context, correlation))
                     .collect(),
             ),
         }
@@ -295,6 +597,31 @@ where
         root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
         context: &'a QueryT::Context,
     ) -> GraphQLBatchResponse<S>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = QueryT::Context>,
+        SubscriptionT::TypeInfo: Sync,
+        S: Send + Sync,
+    {
+        self.execute_correlated(root_node, context, &Correlation::default())
+            .await
+    }
+
+    /// Executes a GraphQL request using the specified schema and context,
+    /// correlating all its operations via the provided [`Correlation`]
+    ///
+    /// This is a simple wrapper around the `execute_correlated` function
+    /// exposed in GraphQLRequest
+    pub async fn execute_correlated<'a, QueryT, MutationT, SubscriptionT>(
+        &'a self,
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+        correlation: &Correlation,
+    ) -> GraphQLBatchResponse<S>
     where
         QueryT: GraphQLTypeAsync<S>,
         QueryT::TypeInfo: Sync,
@@ -307,12 +634,15 @@ where
     {
         match self {
             Self::Single(req) => {
-                let resp = req.execute(root_node, context).await;
+                let resp = req
+                    .execute_correlated(root_node, context, correlation)
+                    .await;
                 GraphQLBatchResponse::Single(resp)
             }
             Self::Batch(reqs) => {
                 let resps = futures::future::join_all(
-                    reqs.iter().map(|req| req.execute(root_node, context)),
+                    reqs.iter()
+                        .map(|req| req.execute_correlated(root_node, context, correlation)),
                 )
                 .await;
                 GraphQLBatchResponse::Batch(resps)
@@ -320,6 +650,46 @@ where
         }
     }
 
+    /// Executes a GraphQL request using the specified schema and context,
+    /// correlating all its operations via the provided [`Correlation`] and
+    /// cancelling them along with the provided [`CancellationToken`]
+    ///
+    /// This is a simple wrapper around the `execute_cancellable` function
+    /// exposed in GraphQLRequest
+    pub async fn execute_cancellable<'a, QueryT, MutationT, SubscriptionT>(
+        &'a self,
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+        correlation: &Correlation,
+        cancellation: &CancellationToken,
+    ) -> GraphQLBatchResponse<S>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = QueryT::Context>,
+        SubscriptionT::TypeInfo: Sync,
+        S: Send + Sync,
+    {
+        match self {
+            Self::Single(req) => {
+                let resp = req
+                    .execute_cancellable(root_node, context, correlation, cancellation)
+                    .await;
+                GraphQLBatchResponse::Single(resp)
+            }
+            Self::Batch(reqs) => {
+                let resps = futures::future::join_all(reqs.iter().map(|req| {
+                    req.execute_cancellable(root_node, context, correlation, cancellation)
+                }))
+                .await;
+                GraphQLBatchResponse::Batch(resps)
+            }
+        }
+    }
+
     /// The operation names of the request.
     pub fn operation_names(&self) -> Vec<Option<&str>> {
         match self {
@@ -357,6 +727,32 @@ impl<S: ScalarValue> GraphQLBatchResponse<S> {
     }
 }
 
+/// Encodes the provided `s`tring as a single-quoted JavaScript string literal,
+/// safe to be placed inside a `<script>` element of a generated HTML page.
+pub(crate) fn js_string(s: &str) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('\'');
+    for c in s.chars() {
+        match c {
+            '\'' => out.push_str("\\'"),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            // Prevents closing the `<script>` element or opening a comment.
+            '<' | '>' | '&' | '\u{2028}' | '\u{2029}' => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
+            }
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
 #[cfg(feature = "expose-test-schema")]
 #[allow(missing_docs)]
 pub mod tests {
@@ -0,0 +1,1036 @@
+//! Support of the [GraphQL multipart request specification][0], allowing
+//! files to be uploaded along with a GraphQL operation.
+//!
+//! A `multipart/form-data` request body is parsed incrementally by a
+//! [`MultipartBody`], which is fed with the body chunks as they arrive, so it
+//! suits both blocking and asynchronous HTTP integrations. The uploaded files
+//! are written into temporary files instead of being buffered in memory, and
+//! each of them is replaced in the operation variables with a placeholder,
+//! which is accepted by the [`Upload`] scalar.
+//!
+//! Resolvers turn an [`Upload`] into a readable [`UploadValue`] via the
+//! [`Uploads`] of the request (usually passed to them as a part of the
+//! context):
+//!
+//! ```rust
+//! # use std::io::Read as _;
+//! # use juniper::{http::multipart::{MultipartParser, Upload}, DefaultScalarValue, FromInputValue as _};
+//! #
+//! let body = "--xyz\r\n\
+//!     Content-Disposition: form-data; name=\"operations\"\r\n\r\n\
+//!     {\"query\": \"mutation ($file: Upload!) { upload(file: $file) }\", \
+//!      \"variables\": {\"file\": null}}\r\n\
+//!     --xyz\r\n\
+//!     Content-Disposition: form-data; name=\"map\"\r\n\r\n\
+//!     {\"0\": [\"variables.file\"]}\r\n\
+//!     --xyz\r\n\
+//!     Content-Disposition: form-data; name=\"0\"; filename=\"a.txt\"\r\n\
+//!     Content-Type: text/plain\r\n\r\n\
+//!     Hello, world!\r\n\
+//!     --xyz--\r\n";
+//!
+//! let (request, uploads) = MultipartParser::new()
+//!     .max_file_size(1024)
+//!     .parse::<DefaultScalarValue>("multipart/form-data; boundary=xyz", body.as_bytes())
+//!     .unwrap();
+//!
+//! let request = match request {
+//!     juniper::http::GraphQLBatchRequest::Single(req) => req,
+//!     _ => unreachable!(),
+//! };
+//! let upload = Upload::from_input_value(&request.variables()["file"]).unwrap();
+//! let mut file = uploads.open(&upload).unwrap().unwrap();
+//! assert_eq!(file.filename(), Some("a.txt"));
+//!
+//! let mut content = String::new();
+//! file.read_to_string(&mut content).unwrap();
+//! assert_eq!(content, "Hello, world!");
+//! ```
+//!
+//! [0]: https://github.com/jaydenseric/graphql-multipart-request-spec
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt, fs,
+    io::{self, Read, Write as _},
+    mem,
+    path::{Path, PathBuf},
+    process, str,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    ast::InputValue,
+    http::{
+        variables::{VariablesError, VariablesParser},
+        GraphQLBatchRequest, GraphQLRequest,
+    },
+    value::{DefaultScalarValue, ScalarValue, Value},
+    GraphQLScalar,
+};
+
+/// Prefix of the placeholders uploaded files are replaced with.
+const PLACEHOLDER_PREFIX: &str = "juniper-upload:";
+
+/// Maximum size of the headers of a single part of a multipart body.
+const MAX_HEADERS_SIZE: usize = 8 * 1024;
+
+/// Size of the chunks a [`MultipartParser::parse()`] reads a body in.
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Indicates whether the provided `Content-Type` header value describes a
+/// `multipart/form-data` body.
+#[must_use]
+pub fn is_multipart(content_type: &str) -> bool {
+    content_type.split(';').next().map_or(false, |t| {
+        t.trim().eq_ignore_ascii_case("multipart/form-data")
+    })
+}
+
+/// Scalar of a file uploaded via a multipart request.
+///
+/// This is an input-only scalar: clients send `null` in its place, which is
+/// replaced with a placeholder of the file while the request is parsed. Use
+/// [`Uploads::open()`] to read the file.
+#[derive(Clone, Debug, Eq, GraphQLScalar, PartialEq)]
+#[graphql(parse_token(String))]
+pub struct Upload(String);
+
+impl Upload {
+    fn to_output<S: ScalarValue>(&self) -> Value<S> {
+        Value::scalar(self.0.clone())
+    }
+
+    fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, String> {
+        v.as_string_value()
+            .filter(|s| s.starts_with(PLACEHOLDER_PREFIX))
+            .map(|s| Self(s.into()))
+            .ok_or_else(|| format!("Expected an uploaded file, found: {v}"))
+    }
+}
+
+/// Options of parsing multipart requests.
+///
+/// By default, neither the size nor the number of uploaded files is limited.
+#[derive(Clone, Debug, Default)]
+pub struct MultipartParser {
+    max_file_size: Option<u64>,
+    max_files: Option<usize>,
+    max_field_size: Option<usize>,
+    upload_dir: Option<PathBuf>,
+}
+
+impl MultipartParser {
+    /// Creates a new [`MultipartParser`] with no limits set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of a single uploaded file in bytes.
+    #[must_use]
+    pub fn max_file_size(mut self, max: u64) -> Self {
+        self.max_file_size = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of files uploaded in a single request.
+    #[must_use]
+    pub fn max_files(mut self, max: usize) -> Self {
+        self.max_files = Some(max);
+        self
+    }
+
+    /// Sets the maximum size in bytes of the `operations` and `map` fields,
+    /// which are kept in memory.
+    #[must_use]
+    pub fn max_field_size(mut self, max: usize) -> Self {
+        self.max_field_size = Some(max);
+        self
+    }
+
+    /// Sets the directory uploaded files are written into.
+    ///
+    /// Defaults to the [temporary directory](std::env::temp_dir) of the
+    /// system.
+    #[must_use]
+    pub fn upload_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.upload_dir = Some(dir.into());
+        self
+    }
+
+    /// Starts parsing a multipart body with the provided `Content-Type` header
+    /// value.
+    ///
+    /// # Errors
+    ///
+    /// If the `content_type` isn't `multipart/form-data` or has no boundary.
+    pub fn begin(&self, content_type: &str) -> Result<MultipartBody, MultipartError> {
+        if !is_multipart(content_type) {
+            return Err(MultipartError::Syntax("not a `multipart/form-data` body"));
+        }
+        let boundary = content_type
+            .split(';')
+            .skip(1)
+            .filter_map(|param| param.split_once('='))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("boundary"))
+            .map(|(_, value)| value.trim().trim_matches('"'))
+            .filter(|b| !b.is_empty())
+            .ok_or(MultipartError::Syntax("missing boundary"))?;
+
+        Ok(MultipartBody {
+            options: self.clone(),
+            delimiter: format!("\r\n--{boundary}").into_bytes(),
+            // The first delimiter isn't preceded by a line break, so it's
+            // prepended to find all the delimiters in the same way.
+            buf: b"\r\n".to_vec(),
+            state: State::Preamble,
+            operations: None,
+            map: None,
+            uploads: Uploads::new(),
+        })
+    }
+
+    /// Parses a whole multipart body read from the provided `reader`.
+    ///
+    /// # Errors
+    ///
+    /// See [`MultipartBody::feed()`] and [`MultipartBody::finish()`].
+    pub fn parse<S: ScalarValue>(
+        &self,
+        content_type: &str,
+        mut reader: impl Read,
+    ) -> Result<(GraphQLBatchRequest<S>, Uploads), MultipartError> {
+        let mut body = self.begin(content_type)?;
+        let mut chunk = vec![0; READ_CHUNK_SIZE];
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => body.feed(&chunk[..n])?,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        body.finish()
+    }
+}
+
+/// Multipart body being parsed incrementally.
+///
+/// Created by [`MultipartParser::begin()`].
+#[derive(Debug)]
+pub struct MultipartBody {
+    options: MultipartParser,
+    delimiter: Vec<u8>,
+    buf: Vec<u8>,
+    state: State,
+    operations: Option<Vec<u8>>,
+    map: Option<HashMap<String, Vec<String>>>,
+    uploads: Uploads,
+}
+
+/// State of a [`MultipartBody`] being parsed.
+#[derive(Debug)]
+enum State {
+    /// Skipping everything before the first delimiter.
+    Preamble,
+    /// Right after a delimiter, which is followed either by a line break or
+    /// by `--` ending the body.
+    Delimiter,
+    /// Reading the headers of a part.
+    Headers,
+    /// Reading the `operations` or `map` field.
+    Field { name: Field, data: Vec<u8> },
+    /// Writing an uploaded file.
+    File { index: usize, file: fs::File },
+    /// Skipping a part not referenced by the `map`.
+    Skip,
+    /// Skipping everything after the closing delimiter.
+    Epilogue,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Field {
+    Operations,
+    Map,
+}
+
+impl MultipartBody {
+    /// Feeds the next `chunk` of the body.
+    ///
+    /// # Errors
+    ///
+    /// If the body is malformed, exceeds the limits of its
+    /// [`MultipartParser`], or writing an uploaded file fails.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), MultipartError> {
+        self.buf.extend_from_slice(chunk);
+        while self.step()? {}
+        Ok(())
+    }
+
+    /// Finishes parsing the body, returning the parsed request along with its
+    /// uploaded files.
+    ///
+    /// # Errors
+    ///
+    /// If the body is incomplete, misses the `operations` or `map` field, or
+    /// the `map` doesn't match the `operations` or the uploaded files.
+    pub fn finish<S: ScalarValue>(
+        self,
+    ) -> Result<(GraphQLBatchRequest<S>, Uploads), MultipartError> {
+        if !matches!(self.state, State::Epilogue) {
+            return Err(MultipartError::Syntax("unexpected end of body"));
+        }
+        let operations = self
+            .operations
+            .ok_or(MultipartError::MissingField("operations"))?;
+        let map = self.map.ok_or(MultipartError::MissingField("map"))?;
+
+        let (mut operations, _) = VariablesParser::new()
+            .parse::<S>(operations.as_slice())
+            .map_err(|e| MultipartError::Json("operations", e))?;
+        for (key, paths) in &map {
+            let index = self.uploads.index(key).ok_or_else(|| {
+                MultipartError::Invalid(format!("no file is uploaded for `map` key `{key}`"))
+            })?;
+            for path in paths {
+                let value = locate(&mut operations, path).ok_or_else(|| {
+                    MultipartError::Invalid(format!("`map` path `{path}` isn't in `operations`"))
+                })?;
+                *value = InputValue::scalar(self.uploads.placeholder(index));
+            }
+        }
+
+        let request = match operations {
+            InputValue::List(items) => GraphQLBatchRequest::Batch(
+                items
+                    .into_iter()
+                    .map(|item| operation(item.item))
+                    .collect::<Result<_, _>>()?,
+            ),
+            value => GraphQLBatchRequest::Single(operation(value)?),
+        };
+        Ok((request, self.uploads))
+    }
+
+    /// Performs a single parsing step over the buffered data, returning
+    /// whether more steps could be done without feeding more data.
+    fn step(&mut self) -> Result<bool, MultipartError> {
+        match self.state {
+            State::Preamble => match find(&self.buf, &self.delimiter) {
+                Some(pos) => {
+                    self.buf.drain(..pos + self.delimiter.len());
+                    self.state = State::Delimiter;
+                    Ok(true)
+                }
+                None => {
+                    self.buf.drain(..self.safe_len());
+                    Ok(false)
+                }
+            },
+            State::Delimiter => {
+                if self.buf.len() < 2 {
+                    return Ok(false);
+                }
+                if self.buf.starts_with(b"--") {
+                    self.buf.clear();
+                    self.state = State::Epilogue;
+                    Ok(false)
+                } else if self.buf.starts_with(b"\r\n") {
+                    // The line break is kept, so the headers always start
+                    // with one, even if there are none.
+                    self.state = State::Headers;
+                    Ok(true)
+                } else {
+                    Err(MultipartError::Syntax("invalid delimiter"))
+                }
+            }
+            State::Headers => match find(&self.buf, b"\r\n\r\n") {
+                Some(pos) => {
+                    // Without any headers the line breaks overlap.
+                    let headers = str::from_utf8(self.buf.get(2..pos).unwrap_or_default())
+                        .map_err(|_| MultipartError::Syntax("invalid part headers"))?;
+                    let headers = PartHeaders::parse(headers)?;
+                    self.buf.drain(..pos + 4);
+                    self.state = self.start_part(headers)?;
+                    Ok(true)
+                }
+                None if self.buf.len() > MAX_HEADERS_SIZE => {
+                    Err(MultipartError::Syntax("part headers are too large"))
+                }
+                None => Ok(false),
+            },
+            State::Field { .. } | State::File { .. } | State::Skip => {
+                let (len, found) = match find(&self.buf, &self.delimiter) {
+                    Some(pos) => (pos, true),
+                    None => (self.safe_len(), false),
+                };
+                let data = self.buf.drain(..len).collect::<Vec<_>>();
+                self.write_part(&data)?;
+                if found {
+                    self.buf.drain(..self.delimiter.len());
+                    self.finish_part()?;
+                    self.state = State::Delimiter;
+                }
+                Ok(found)
+            }
+            State::Epilogue => {
+                self.buf.clear();
+                Ok(false)
+            }
+        }
+    }
+
+    /// Returns the length of the buffered data that can't be a part of a
+    /// delimiter.
+    fn safe_len(&self) -> usize {
+        self.buf.len().saturating_sub(self.delimiter.len() - 1)
+    }
+
+    fn start_part(&mut self, headers: PartHeaders) -> Result<State, MultipartError> {
+        match headers.name.as_str() {
+            "operations" => Ok(State::Field {
+                name: Field::Operations,
+                data: vec![],
+            }),
+            "map" => Ok(State::Field {
+                name: Field::Map,
+                data: vec![],
+            }),
+            name => {
+                let map = self.map.as_ref().ok_or_else(|| {
+                    MultipartError::Invalid(
+                        "`operations` and `map` fields must precede files".into(),
+                    )
+                })?;
+                if !map.contains_key(name) {
+                    return Ok(State::Skip);
+                }
+                if let Some(max) = self.options.max_files {
+                    if self.uploads.len() >= max {
+                        return Err(MultipartError::TooManyFiles { max });
+                    }
+                }
+                let dir = self
+                    .options
+                    .upload_dir
+                    .clone()
+                    .unwrap_or_else(std::env::temp_dir);
+                let (index, file) = self.uploads.create(&dir, headers)?;
+                Ok(State::File { index, file })
+            }
+        }
+    }
+
+    fn write_part(&mut self, data: &[u8]) -> Result<(), MultipartError> {
+        match &mut self.state {
+            State::Field { data: field, .. } => {
+                field.extend_from_slice(data);
+                if let Some(max) = self.options.max_field_size {
+                    if field.len() > max {
+                        return Err(MultipartError::FieldTooLarge { max });
+                    }
+                }
+            }
+            State::File { index, file } => {
+                let uploaded = &mut self.uploads.files[*index];
+                uploaded.size += data.len() as u64;
+                if let Some(max) = self.options.max_file_size {
+                    if uploaded.size > max {
+                        return Err(MultipartError::FileTooLarge { max });
+                    }
+                }
+                file.write_all(data)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish_part(&mut self) -> Result<(), MultipartError> {
+        match mem::replace(&mut self.state, State::Delimiter) {
+            State::Field {
+                name: Field::Operations,
+                data,
+            } => self.operations = Some(data),
+            State::Field {
+                name: Field::Map,
+                data,
+            } => self.map = Some(parse_map(&data)?),
+            State::File { mut file, .. } => file.flush()?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Headers of a single part of a multipart body.
+struct PartHeaders {
+    name: String,
+    filename: Option<String>,
+    content_type: Option<String>,
+}
+
+impl PartHeaders {
+    fn parse(headers: &str) -> Result<Self, MultipartError> {
+        let (mut name, mut filename, mut content_type) = (None, None, None);
+        for line in headers.split("\r\n") {
+            let (header, value) = match line.split_once(':') {
+                Some(h) => h,
+                None => continue,
+            };
+            let header = header.trim();
+            if header.eq_ignore_ascii_case("content-disposition") {
+                for param in value.split(';').skip(1) {
+                    if let Some((key, value)) = param.split_once('=') {
+                        let value = value.trim().trim_matches('"').to_owned();
+                        match key.trim() {
+                            "name" => name = Some(value),
+                            "filename" => filename = Some(value),
+                            _ => {}
+                        }
+                    }
+                }
+            } else if header.eq_ignore_ascii_case("content-type") {
+                content_type = Some(value.trim().to_owned());
+            }
+        }
+        Ok(Self {
+            name: name.ok_or(MultipartError::Syntax("part has no name"))?,
+            filename,
+            content_type,
+        })
+    }
+}
+
+/// Parses the `map` field into file keys with the paths of their variables.
+fn parse_map(data: &[u8]) -> Result<HashMap<String, Vec<String>>, MultipartError> {
+    let (map, _) = VariablesParser::new()
+        .parse::<DefaultScalarValue>(data)
+        .map_err(|e| MultipartError::Json("map", e))?;
+    let invalid = || MultipartError::Invalid("`map` must be an object of string lists".into());
+    map.to_object_value()
+        .ok_or_else(invalid)?
+        .into_iter()
+        .map(|(key, paths)| {
+            let paths = match paths {
+                InputValue::List(paths) => paths
+                    .iter()
+                    .map(|p| p.item.as_string_value().map(str::to_owned))
+                    .collect::<Option<Vec<_>>>(),
+                _ => None,
+            };
+            paths.map(|p| (key.to_owned(), p)).ok_or_else(invalid)
+        })
+        .collect()
+}
+
+/// Locates the value at the provided dot-separated `path` (like
+/// `0.variables.files.1`) in the `operations`.
+fn locate<'v, S>(operations: &'v mut InputValue<S>, path: &str) -> Option<&'v mut InputValue<S>> {
+    path.split('.')
+        .try_fold(operations, |value, segment| match value {
+            InputValue::Object(fields) => fields
+                .iter_mut()
+                .find(|(k, _)| k.item == segment)
+                .map(|(_, v)| &mut v.item),
+            InputValue::List(items) => items
+                .get_mut(segment.parse::<usize>().ok()?)
+                .map(|v| &mut v.item),
+            _ => None,
+        })
+}
+
+/// Converts a single operation of the `operations` field into a
+/// [`GraphQLRequest`].
+fn operation<S: ScalarValue>(value: InputValue<S>) -> Result<GraphQLRequest<S>, MultipartError> {
+    let invalid = |msg: &str| MultipartError::Invalid(format!("invalid `operations`: {msg}"));
+
+    let fields = match value {
+        InputValue::Object(fields) => fields,
+        _ => return Err(invalid("operation must be an object")),
+    };
+    let (mut query, mut operation_name, mut variables, mut extensions) = (None, None, None, None);
+    for (key, value) in fields {
+        let value = value.item;
+        match key.item.as_str() {
+            "query" => match value.as_string_value() {
+                Some(s) => query = Some(s.to_owned()),
+                None => return Err(invalid("`query` must be a string")),
+            },
+            "operationName" => {
+                operation_name = match value.as_string_value() {
+                    Some(s) => Some(s.to_owned()),
+                    None if value.is_null() => None,
+                    None => return Err(invalid("`operationName` must be a string or null")),
+                }
+            }
+            "variables" => variables = Some(value).filter(|v| !v.is_null()),
+            "extensions" => extensions = Some(value).filter(|v| !v.is_null()),
+            _ => {}
+        }
+    }
+
+    let query = query.ok_or_else(|| invalid("missing `query`"))?;
+    let mut request = GraphQLRequest::new(query, operation_name, variables);
+    request.extensions = extensions;
+    Ok(request)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Error of parsing a multipart body.
+#[derive(Debug)]
+pub enum MultipartError {
+    /// Writing an uploaded file or reading the body failed.
+    Io(io::Error),
+
+    /// The body isn't a valid `multipart/form-data` one.
+    Syntax(&'static str),
+
+    /// The `operations` or `map` field exceeds the
+    /// [maximum size](MultipartParser::max_field_size).
+    FieldTooLarge {
+        /// Maximum size of the field in bytes.
+        max: usize,
+    },
+
+    /// An uploaded file exceeds the
+    /// [maximum size](MultipartParser::max_file_size).
+    FileTooLarge {
+        /// Maximum size of a file in bytes.
+        max: u64,
+    },
+
+    /// The number of uploaded files exceeds the
+    /// [maximum](MultipartParser::max_files).
+    TooManyFiles {
+        /// Maximum number of files.
+        max: usize,
+    },
+
+    /// The named field is missing.
+    MissingField(&'static str),
+
+    /// The named field isn't valid JSON.
+    Json(&'static str, VariablesError),
+
+    /// The fields don't comply with the specification.
+    Invalid(String),
+}
+
+impl From<io::Error> for MultipartError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl fmt::Display for MultipartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Failed to read multipart body: {e}"),
+            Self::Syntax(message) => write!(f, "Invalid multipart body: {message}"),
+            Self::FieldTooLarge { max } => {
+                write!(f, "Multipart field exceeds the maximum size of {max} bytes")
+            }
+            Self::FileTooLarge { max } => {
+                write!(f, "Uploaded file exceeds the maximum size of {max} bytes")
+            }
+            Self::TooManyFiles { max } => {
+                write!(f, "Number of uploaded files exceeds the maximum of {max}")
+            }
+            Self::MissingField(name) => write!(f, "Missing `{name}` multipart field"),
+            Self::Json(name, e) => write!(f, "Invalid `{name}` multipart field: {e}"),
+            Self::Invalid(message) => write!(f, "Invalid multipart request: {message}"),
+        }
+    }
+}
+
+impl Error for MultipartError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Json(_, e) => Some(e),
+            Self::Syntax(_)
+            | Self::FieldTooLarge { .. }
+            | Self::FileTooLarge { .. }
+            | Self::TooManyFiles { .. }
+            | Self::MissingField(_)
+            | Self::Invalid(_) => None,
+        }
+    }
+}
+
+/// Files uploaded via a multipart request.
+///
+/// The files are removed once this [`Uploads`] is dropped, so it should be
+/// kept alive until the operation is executed.
+#[derive(Debug)]
+pub struct Uploads {
+    id: String,
+    files: Vec<UploadedFile>,
+}
+
+#[derive(Debug)]
+struct UploadedFile {
+    key: String,
+    path: PathBuf,
+    filename: Option<String>,
+    content_type: Option<String>,
+    size: u64,
+}
+
+impl Default for Uploads {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Uploads {
+    /// Creates an empty [`Uploads`], to be used for requests without files.
+    #[must_use]
+    pub fn new() -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.subsec_nanos());
+        Self {
+            id: format!(
+                "{:x}-{:x}-{nanos:x}",
+                process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed),
+            ),
+            files: vec![],
+        }
+    }
+
+    /// Returns the number of uploaded files.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Indicates whether no files have been uploaded.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Opens the file of the provided [`Upload`] for reading.
+    ///
+    /// Returns [`None`] if the [`Upload`] doesn't belong to these
+    /// [`Uploads`].
+    pub fn open(&self, upload: &Upload) -> Option<io::Result<UploadValue>> {
+        self.get(&upload.0).map(|f| {
+            fs::File::open(&f.path).map(|file| UploadValue {
+                filename: f.filename.clone(),
+                content_type: f.content_type.clone(),
+                size: f.size,
+                file,
+            })
+        })
+    }
+
+    fn index(&self, key: &str) -> Option<usize> {
+        self.files.iter().position(|f| f.key == key)
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("{PLACEHOLDER_PREFIX}{}/{index}", self.id)
+    }
+
+    fn get(&self, value: &str) -> Option<&UploadedFile> {
+        let index = value
+            .strip_prefix(PLACEHOLDER_PREFIX)?
+            .strip_prefix(&self.id)?
+            .strip_prefix('/')?;
+        self.files.get(index.parse::<usize>().ok()?)
+    }
+
+    fn create(&mut self, dir: &Path, headers: PartHeaders) -> io::Result<(usize, fs::File)> {
+        let index = self.files.len();
+        let path = dir.join(format!("juniper-upload-{}-{index}", self.id));
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        self.files.push(UploadedFile {
+            key: headers.name,
+            path,
+            filename: headers.filename,
+            content_type: headers.content_type,
+            size: 0,
+        });
+        Ok((index, file))
+    }
+}
+
+impl Drop for Uploads {
+    fn drop(&mut self) {
+        for f in &self.files {
+            // Nothing reasonable could be done about a file failed to be
+            // removed, so it's left to the system cleaning temporary files.
+            let _ = fs::remove_file(&f.path);
+        }
+    }
+}
+
+/// Readable handle of an uploaded file, streaming its contents from disk.
+#[derive(Debug)]
+pub struct UploadValue {
+    filename: Option<String>,
+    content_type: Option<String>,
+    size: u64,
+    file: fs::File,
+}
+
+impl UploadValue {
+    /// Returns the name of the file, as provided by the client.
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    /// Returns the content type of the file, as provided by the client.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Converts this [`UploadValue`] into the underlying [`fs::File`].
+    pub fn into_file(self) -> fs::File {
+        self.file
+    }
+}
+
+impl Read for UploadValue {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.file.read(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read as _;
+
+    use crate::{
+        graphql_input_value, graphql_object,
+        http::{GraphQLBatchRequest, GraphQLRequest},
+        DefaultScalarValue, EmptyMutation, EmptySubscription, FromInputValue, RootNode,
+    };
+
+    use super::{MultipartError, MultipartParser, Upload, Uploads};
+
+    const CONTENT_TYPE: &str = "multipart/form-data; boundary=xyz";
+
+    fn body(operations: &str, map: &str, files: &[(&str, &str)]) -> String {
+        let mut body = format!(
+            "--xyz\r\n\
+             Content-Disposition: form-data; name=\"operations\"\r\n\r\n\
+             {operations}\r\n\
+             --xyz\r\n\
+             Content-Disposition: form-data; name=\"map\"\r\n\r\n\
+             {map}\r\n",
+        );
+        for (key, content) in files {
+            body.push_str(&format!(
+                "--xyz\r\n\
+                 Content-Disposition: form-data; name=\"{key}\"; filename=\"{key}.txt\"\r\n\
+                 Content-Type: text/plain\r\n\r\n\
+                 {content}\r\n",
+            ));
+        }
+        body.push_str("--xyz--\r\n");
+        body
+    }
+
+    fn single(req: GraphQLBatchRequest) -> GraphQLRequest {
+        match req {
+            GraphQLBatchRequest::Single(req) => req,
+            GraphQLBatchRequest::Batch(_) => panic!("expected single request"),
+        }
+    }
+
+    fn read(uploads: &Uploads, upload: &Upload) -> String {
+        let mut content = String::new();
+        uploads
+            .open(upload)
+            .expect("unknown upload")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+
+    #[test]
+    fn replaces_files_with_uploads() {
+        let body = body(
+            r#"{"query": "{ a }", "variables": {"file": null, "files": [null, null]}}"#,
+            r#"{"0": ["variables.file"], "1": ["variables.files.0"], "2": ["variables.files.1"]}"#,
+            &[("0", "first"), ("1", "second\r\n--xy"), ("2", "")],
+        );
+
+        // Feed the body byte by byte to check that delimiters are found across
+        // chunks.
+        let parser = MultipartParser::new();
+        let mut multipart = parser.begin(CONTENT_TYPE).unwrap();
+        for byte in body.as_bytes() {
+            multipart.feed(&[*byte]).unwrap();
+        }
+        let (req, uploads) = multipart.finish::<DefaultScalarValue>().unwrap();
+        let vars = single(req).variables();
+
+        assert_eq!(uploads.len(), 3);
+
+        let file = Upload::from_input_value(&vars["file"]).unwrap();
+        assert_eq!(read(&uploads, &file), "first");
+        let value = uploads.open(&file).unwrap().unwrap();
+        assert_eq!(value.filename(), Some("0.txt"));
+        assert_eq!(value.content_type(), Some("text/plain"));
+        assert_eq!(value.size(), 5);
+
+        let files = vars["files"].to_list_value().unwrap();
+        let second = Upload::from_input_value(files[0]).unwrap();
+        assert_eq!(read(&uploads, &second), "second\r\n--xy");
+        let third = Upload::from_input_value(files[1]).unwrap();
+        assert_eq!(read(&uploads, &third), "");
+    }
+
+    #[test]
+    fn parses_batched_operations() {
+        let body = body(
+            r#"[{"query": "{ a }"}, {"query": "{ b }", "variables": {"file": null}}]"#,
+            r#"{"0": ["1.variables.file"]}"#,
+            &[("0", "content")],
+        );
+
+        let (req, uploads) = MultipartParser::new()
+            .parse::<DefaultScalarValue>(CONTENT_TYPE, body.as_bytes())
+            .unwrap();
+
+        match req {
+            GraphQLBatchRequest::Batch(reqs) => {
+                assert_eq!(reqs.len(), 2);
+                let upload = Upload::from_input_value(&reqs[1].variables()["file"]).unwrap();
+                assert_eq!(read(&uploads, &upload), "content");
+            }
+            GraphQLBatchRequest::Single(_) => panic!("expected batch request"),
+        }
+    }
+
+    #[test]
+    fn removes_files_on_drop() {
+        let dir = std::env::temp_dir().join("juniper-multipart-removes-files-on-drop");
+        std::fs::create_dir_all(&dir).unwrap();
+        let body = body(
+            r#"{"query": "{ a }", "variables": {"file": null}}"#,
+            r#"{"0": ["variables.file"]}"#,
+            &[("0", "content")],
+        );
+
+        let (_, uploads) = MultipartParser::new()
+            .upload_dir(&dir)
+            .parse::<DefaultScalarValue>(CONTENT_TYPE, body.as_bytes())
+            .unwrap();
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        drop(uploads);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn rejects_too_large_file() {
+        let body = body(
+            r#"{"query": "{ a }", "variables": {"file": null}}"#,
+            r#"{"0": ["variables.file"]}"#,
+            &[("0", "content")],
+        );
+
+        let err = MultipartParser::new()
+            .max_file_size(3)
+            .parse::<DefaultScalarValue>(CONTENT_TYPE, body.as_bytes())
+            .unwrap_err();
+
+        assert!(matches!(err, MultipartError::FileTooLarge { max: 3 }));
+    }
+
+    #[test]
+    fn rejects_missing_file() {
+        let body = body(
+            r#"{"query": "{ a }", "variables": {"file": null}}"#,
+            r#"{"0": ["variables.file"]}"#,
+            &[],
+        );
+
+        let err = MultipartParser::new()
+            .parse::<DefaultScalarValue>(CONTENT_TYPE, body.as_bytes())
+            .unwrap_err();
+
+        assert!(matches!(err, MultipartError::Invalid(_)), "{err}");
+    }
+
+    #[test]
+    fn rejects_truncated_body() {
+        let body = body(r#"{"query": "{ a }"}"#, "{}", &[]);
+
+        let err = MultipartParser::new()
+            .parse::<DefaultScalarValue>(CONTENT_TYPE, &body.as_bytes()[..body.len() - 10])
+            .unwrap_err();
+
+        assert!(matches!(err, MultipartError::Syntax(_)), "{err}");
+    }
+
+    #[test]
+    fn upload_rejects_arbitrary_strings() {
+        assert!(
+            <Upload as FromInputValue>::from_input_value(&graphql_input_value!("/etc/passwd"))
+                .is_err()
+        );
+    }
+
+    struct Context {
+        uploads: Uploads,
+    }
+
+    impl crate::Context for Context {}
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn size(file: Upload, context: &Context) -> i32 {
+            context.uploads.open(&file).unwrap().unwrap().size() as i32
+        }
+    }
+
+    #[test]
+    fn resolves_uploads() {
+        let body = body(
+            r#"{"query": "query ($file: Upload!) { size(file: $file) }", "variables": {"file": null}}"#,
+            r#"{"0": ["variables.file"]}"#,
+            &[("0", "content")],
+        );
+        let (req, uploads) = MultipartParser::new()
+            .parse::<DefaultScalarValue>(CONTENT_TYPE, body.as_bytes())
+            .unwrap();
+
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        );
+        let resp = req.execute_sync(&schema, &Context { uploads });
+
+        assert!(resp.is_ok());
+        assert_eq!(
+            serde_json::to_value(&resp).unwrap(),
+            serde_json::json!({"data": {"size": 7}}),
+        );
+    }
+}
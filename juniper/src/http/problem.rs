@@ -0,0 +1,216 @@
+//! Rendering of request-level errors as [RFC 7807] problem details.
+//!
+//! [RFC 7807]: https://www.rfc-editor.org/rfc/rfc7807
+
+use serde::Serialize;
+
+/// Media type of a [`ProblemDetails`] JSON body.
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Kind of an error preventing a GraphQL request from being executed at all.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RequestErrorKind {
+    /// HTTP method of the request is not supported.
+    MethodNotAllowed,
+
+    /// `Content-Type` of the request is missing or not supported.
+    UnsupportedContentType,
+
+    /// Request couldn't be parsed (malformed body, JSON or query parameters).
+    MalformedRequest,
+
+    /// Persisted query referred by the request is not known to the server.
+    PersistedQueryNotFound,
+}
+
+impl RequestErrorKind {
+    /// Returns the HTTP status code of this [`RequestErrorKind`].
+    pub fn status(self) -> u16 {
+        match self {
+            Self::MethodNotAllowed => 405,
+            Self::UnsupportedContentType => 415,
+            Self::MalformedRequest => 400,
+            Self::PersistedQueryNotFound => 404,
+        }
+    }
+
+    /// Returns the canonical reason phrase of the [HTTP status](Self::status)
+    /// of this [`RequestErrorKind`].
+    pub fn reason(self) -> &'static str {
+        match self {
+            Self::MethodNotAllowed => "Method Not Allowed",
+            Self::UnsupportedContentType => "Unsupported Media Type",
+            Self::MalformedRequest => "Bad Request",
+            Self::PersistedQueryNotFound => "Not Found",
+        }
+    }
+
+    /// Returns the short human-readable summary of this [`RequestErrorKind`].
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::MethodNotAllowed => "Method not allowed",
+            Self::UnsupportedContentType => "Unsupported content type",
+            Self::MalformedRequest => "Malformed request",
+            Self::PersistedQueryNotFound => "Persisted query not found",
+        }
+    }
+
+    /// Returns the identifier of this [`RequestErrorKind`], used as the last
+    /// segment of its problem type URI.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::MethodNotAllowed => "method-not-allowed",
+            Self::UnsupportedContentType => "unsupported-content-type",
+            Self::MalformedRequest => "malformed-request",
+            Self::PersistedQueryNotFound => "persisted-query-not-found",
+        }
+    }
+}
+
+/// [RFC 7807] problem details describing a request-level error.
+///
+/// [RFC 7807]: https://www.rfc-editor.org/rfc/rfc7807
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ProblemDetails {
+    /// URI identifying the problem type.
+    #[serde(rename = "type")]
+    pub type_uri: String,
+
+    /// Short human-readable summary of the problem type.
+    pub title: String,
+
+    /// HTTP status code of the response.
+    pub status: u16,
+
+    /// Human-readable explanation specific to this occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    /// URI identifying this specific occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+}
+
+/// Options of rendering request-level errors as [RFC 7807]
+/// `application/problem+json` bodies by HTTP integrations.
+///
+/// By default, problem details are rendered only for the requests accepting
+/// `application/problem+json` (see [`ProblemJson::is_accepted()`]), and their
+/// type URIs are `about:blank`, so their titles are the [reason phrases] of
+/// their HTTP statuses, as required by [RFC 7807].
+///
+/// [reason phrases]: RequestErrorKind::reason
+/// [RFC 7807]: https://www.rfc-editor.org/rfc/rfc7807
+#[derive(Clone, Debug, Default)]
+pub struct ProblemJson {
+    type_base_uri: Option<String>,
+    always: bool,
+}
+
+impl ProblemJson {
+    /// Creates new [`ProblemJson`] options with all the options set to their
+    /// defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base URI of the problem type URIs.
+    ///
+    /// Type URI of every [`RequestErrorKind`] is its [`slug`] appended to
+    /// this base URI, so `https://example.com/problems/` results in
+    /// `https://example.com/problems/malformed-request`, for example. Titles
+    /// of such problem types are their [`title`]s.
+    ///
+    /// [`slug`]: RequestErrorKind::slug
+    /// [`title`]: RequestErrorKind::title
+    #[must_use]
+    pub fn type_base_uri(mut self, uri: impl Into<String>) -> Self {
+        self.type_base_uri = Some(uri.into());
+        self
+    }
+
+    /// Sets whether problem details should be rendered regardless of the
+    /// `Accept` header of a request.
+    #[must_use]
+    pub fn always(mut self, always: bool) -> Self {
+        self.always = always;
+        self
+    }
+
+    /// Indicates whether problem details should be rendered for a request with
+    /// the provided `Accept` header value.
+    pub fn is_accepted(&self, accept: Option<&str>) -> bool {
+        self.always
+            || accept.map_or(false, |accept| {
+                accept.split(',').any(|range| {
+                    let mut params = range.split(';').map(str::trim);
+                    let media_type = params.next().unwrap_or_default();
+                    let rejected = params.any(|p| {
+                        p.strip_prefix("q=")
+                            .and_then(|q| q.parse::<f32>().ok())
+                            .map_or(false, |q| q <= 0.0)
+                    });
+                    media_type.eq_ignore_ascii_case(PROBLEM_JSON_CONTENT_TYPE) && !rejected
+                })
+            })
+    }
+
+    /// Creates [`ProblemDetails`] of an error of the provided `kind` with the
+    /// provided `detail`.
+    pub fn problem(&self, kind: RequestErrorKind, detail: impl Into<String>) -> ProblemDetails {
+        let detail = detail.into();
+        let (type_uri, title) = match &self.type_base_uri {
+            Some(base) => (format!("{base}{}", kind.slug()), kind.title()),
+            // `about:blank` type requires the title to be the same as the
+            // reason phrase of the HTTP status.
+            None => ("about:blank".into(), kind.reason()),
+        };
+        ProblemDetails {
+            type_uri,
+            title: title.into(),
+            status: kind.status(),
+            detail: (!detail.is_empty()).then_some(detail),
+            instance: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProblemJson, RequestErrorKind};
+
+    #[test]
+    fn accepts_problem_json() {
+        let problem = ProblemJson::new();
+
+        assert!(problem.is_accepted(Some("application/problem+json")));
+        assert!(problem.is_accepted(Some("application/json, application/problem+json;q=0.9")));
+        assert!(!problem.is_accepted(Some("application/problem+json;q=0")));
+        assert!(!problem.is_accepted(Some("application/json")));
+        assert!(!problem.is_accepted(None));
+        assert!(ProblemJson::new().always(true).is_accepted(None));
+    }
+
+    #[test]
+    fn renders_problem_details() {
+        let problem = ProblemJson::new()
+            .type_base_uri("https://example.com/problems/")
+            .problem(RequestErrorKind::MalformedRequest, "expected value");
+
+        assert_eq!(
+            serde_json::to_string(&problem).unwrap(),
+            r#"{"type":"https://example.com/problems/malformed-request","title":"Malformed request","status":400,"detail":"expected value"}"#,
+        );
+    }
+
+    #[test]
+    fn defaults_to_blank_type() {
+        let problem = ProblemJson::new().problem(RequestErrorKind::UnsupportedContentType, "");
+
+        assert_eq!(
+            serde_json::to_string(&problem).unwrap(),
+            r#"{"type":"about:blank","title":"Unsupported Media Type","status":415}"#,
+        );
+    }
+}
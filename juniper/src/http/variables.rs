@@ -0,0 +1,812 @@
+//! Streaming parsing of request variables, bounding their size and spilling
+//! large string scalars to temporary files.
+//!
+//! Deserializing a request via [`serde`] keeps the whole body in memory, and
+//! coercing its variables copies every string at least once more, which adds
+//! up quickly for megabyte-scale inputs (like base64-encoded files). The
+//! [`VariablesParser`] reads the variables directly from an [`io::Read`]
+//! instead, rejecting them once they exceed the
+//! [maximum size](VariablesParser::max_size), and writing the strings longer
+//! than the [spill threshold](VariablesParser::spill_threshold) into temporary
+//! files as they're read.
+//!
+//! A spilled string is replaced in the parsed variables with a short
+//! placeholder, which resolvers turn into a reader via the
+//! [`SpilledStrings`] of the request (usually passed to them as a part of the
+//! context):
+//!
+//! ```rust
+//! # use std::io::Read as _;
+//! # use juniper::{http::variables::VariablesParser, DefaultScalarValue};
+//! #
+//! let body = format!(r#"{{"query": "{{ a }}", "variables": {{"file": "{}"}}}}"#, "A".repeat(100));
+//!
+//! let (request, spilled) = VariablesParser::new()
+//!     .max_size(1024)
+//!     .spill_threshold(16)
+//!     .parse_request::<DefaultScalarValue>(body.as_bytes())
+//!     .unwrap();
+//!
+//! let vars = request.variables();
+//! let placeholder = vars["file"].as_string_value().unwrap();
+//! let mut content = String::new();
+//! spilled.open(placeholder).unwrap().unwrap().read_to_string(&mut content).unwrap();
+//! assert_eq!(content, "A".repeat(100));
+//! ```
+
+use std::{
+    error::Error,
+    fmt, fs,
+    io::{self, BufReader, Read, Write as _},
+    path::{Path, PathBuf},
+    process, str,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use indexmap::IndexMap;
+use serde::de;
+
+use crate::{ast::InputValue, http::GraphQLRequest, value::ScalarValue};
+
+/// Maximum number of lists and objects nested into each other, guarding the
+/// parser against stack overflows.
+const MAX_NESTING: usize = 128;
+
+/// Size of the chunks spilled strings are written in.
+const SPILL_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Prefix of the placeholders spilled strings are replaced with.
+const PLACEHOLDER_PREFIX: &str = "juniper-spilled:";
+
+/// Streaming parser of request variables.
+///
+/// By default, neither the size of variables is limited, nor any strings are
+/// spilled, so the parsed variables are the same as the ones deserialized via
+/// [`serde`].
+#[derive(Clone, Debug, Default)]
+pub struct VariablesParser {
+    max_size: Option<usize>,
+    spill_threshold: Option<usize>,
+    spill_dir: Option<PathBuf>,
+}
+
+impl VariablesParser {
+    /// Creates a new [`VariablesParser`] with no limits set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of variables in bytes, as they're sent by a
+    /// client.
+    ///
+    /// Variables exceeding it are rejected with a
+    /// [`VariablesError::TooLarge`] as soon as the first excess byte is read.
+    #[must_use]
+    pub fn max_size(mut self, max: usize) -> Self {
+        self.max_size = Some(max);
+        self
+    }
+
+    /// Sets the length in bytes (after unescaping) above which string
+    /// scalars of variables are spilled to temporary files.
+    #[must_use]
+    pub fn spill_threshold(mut self, threshold: usize) -> Self {
+        self.spill_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the directory spilled strings are written into.
+    ///
+    /// Defaults to the [temporary directory](std::env::temp_dir) of the
+    /// system.
+    #[must_use]
+    pub fn spill_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.spill_dir = Some(dir.into());
+        self
+    }
+
+    /// Parses variables being the only JSON value read from the provided
+    /// `reader`.
+    ///
+    /// # Errors
+    ///
+    /// If the read variables aren't valid JSON, exceed the
+    /// [maximum size](VariablesParser::max_size), or reading or spilling
+    /// them fails.
+    pub fn parse<S: ScalarValue>(
+        &self,
+        reader: impl Read,
+    ) -> Result<(InputValue<S>, SpilledStrings), VariablesError> {
+        let mut parser = Parser::new(reader, self);
+        parser.skip_whitespace()?;
+        parser.budget = self.max_size.map(|max| (max, max));
+        let variables = parser.value(true)?;
+        parser.budget = None;
+        parser.end()?;
+        Ok((variables, parser.spilled))
+    }
+
+    /// Parses a whole [`GraphQLRequest`] read from the provided `reader`,
+    /// applying the limits of this [`VariablesParser`] to its variables only.
+    ///
+    /// # Errors
+    ///
+    /// If the read request isn't a valid JSON-encoded [`GraphQLRequest`], its
+    /// variables exceed the [maximum size](VariablesParser::max_size), or
+    /// reading or spilling them fails.
+    pub fn parse_request<S: ScalarValue>(
+        &self,
+        reader: impl Read,
+    ) -> Result<(GraphQLRequest<S>, SpilledStrings), VariablesError> {
+        let mut parser = Parser::new(reader, self);
+        let (mut query, mut operation_name, mut variables, mut extensions) =
+            (None, None, None, None);
+
+        parser.skip_whitespace()?;
+        parser.expect(b'{', "expected `{`")?;
+        parser.skip_whitespace()?;
+        if parser.peek()? == Some(b'}') {
+            parser.next()?;
+        } else {
+            loop {
+                parser.skip_whitespace()?;
+                parser.expect(b'"', "expected key")?;
+                let key = parser.string(false)?;
+                parser.skip_whitespace()?;
+                parser.expect(b':', "expected `:`")?;
+                match key.as_str() {
+                    "query" => {
+                        let value = parser.value::<S>(false)?;
+                        match value.as_string_value() {
+                            Some(s) => query = Some(s.to_owned()),
+                            None => return Err(parser.syntax("`query` must be a string")),
+                        }
+                    }
+                    "operationName" => {
+                        let value = parser.value::<S>(false)?;
+                        operation_name = match value.as_string_value() {
+                            Some(s) => Some(s.to_owned()),
+                            None if value.is_null() => None,
+                            None => {
+                                return Err(
+                                    parser.syntax("`operationName` must be a string or null")
+                                )
+                            }
+                        };
+                    }
+                    "variables" => {
+                        parser.skip_whitespace()?;
+                        parser.budget = self.max_size.map(|max| (max, max));
+                        variables = Some(parser.value(true)?).filter(|v| !v.is_null());
+                        parser.budget = None;
+                    }
+                    "extensions" => {
+                        extensions = Some(parser.value(false)?).filter(|v| !v.is_null());
+                    }
+                    _ => {
+                        parser.value::<S>(false)?;
+                    }
+                }
+                parser.skip_whitespace()?;
+                match parser.next()? {
+                    Some(b',') => {}
+                    Some(b'}') => break,
+                    _ => return Err(parser.syntax("expected `,` or `}`")),
+                }
+            }
+        }
+        parser.end()?;
+
+        let query = query.ok_or_else(|| parser.syntax("missing `query`"))?;
+        let mut request = GraphQLRequest::new(query, operation_name, variables);
+        request.extensions = extensions;
+        Ok((request, parser.spilled))
+    }
+}
+
+/// Error of parsing variables with a [`VariablesParser`].
+#[derive(Debug)]
+pub enum VariablesError {
+    /// Reading variables or spilling their strings failed.
+    Io(io::Error),
+
+    /// Variables exceed the [maximum size](VariablesParser::max_size).
+    TooLarge {
+        /// Maximum size of variables in bytes.
+        max: usize,
+    },
+
+    /// Variables aren't valid JSON.
+    Syntax {
+        /// Offset in bytes the error has been detected at.
+        offset: usize,
+
+        /// Description of the error.
+        message: &'static str,
+    },
+}
+
+impl From<io::Error> for VariablesError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl fmt::Display for VariablesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Failed to read variables: {e}"),
+            Self::TooLarge { max } => write!(f, "Variables exceed the maximum size of {max} bytes"),
+            Self::Syntax { offset, message } => {
+                write!(f, "Invalid JSON at byte {offset}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for VariablesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::TooLarge { .. } | Self::Syntax { .. } => None,
+        }
+    }
+}
+
+/// String scalars of variables spilled to temporary files by a
+/// [`VariablesParser`].
+///
+/// The files are removed once this [`SpilledStrings`] is dropped, so it
+/// should be kept alive until the operation is executed.
+#[derive(Debug)]
+pub struct SpilledStrings {
+    id: String,
+    files: Vec<SpilledFile>,
+}
+
+#[derive(Debug)]
+struct SpilledFile {
+    path: PathBuf,
+    len: u64,
+}
+
+impl SpilledStrings {
+    fn new() -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.subsec_nanos());
+        Self {
+            id: format!(
+                "{:x}-{:x}-{nanos:x}",
+                process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed),
+            ),
+            files: vec![],
+        }
+    }
+
+    /// Returns the number of spilled strings.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Indicates whether no strings have been spilled.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Indicates whether the provided string `value` is a placeholder of a
+    /// string spilled by this [`SpilledStrings`].
+    pub fn is_spilled(&self, value: &str) -> bool {
+        self.get(value).is_some()
+    }
+
+    /// Returns the length in bytes of the string spilled in place of the
+    /// provided placeholder `value`, if any.
+    pub fn size(&self, value: &str) -> Option<u64> {
+        self.get(value).map(|f| f.len)
+    }
+
+    /// Opens a reader of the string spilled in place of the provided
+    /// placeholder `value`.
+    ///
+    /// Returns [`None`] if the `value` isn't a placeholder of this
+    /// [`SpilledStrings`], so regular (short enough) strings may be handled
+    /// separately.
+    pub fn open(&self, value: &str) -> Option<io::Result<fs::File>> {
+        self.get(value).map(|f| fs::File::open(&f.path))
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("{PLACEHOLDER_PREFIX}{}/{index}", self.id)
+    }
+
+    fn get(&self, value: &str) -> Option<&SpilledFile> {
+        let index = value
+            .strip_prefix(PLACEHOLDER_PREFIX)?
+            .strip_prefix(&self.id)?
+            .strip_prefix('/')?;
+        self.files.get(index.parse::<usize>().ok()?)
+    }
+
+    fn create(&mut self, dir: &Path) -> io::Result<fs::File> {
+        let path = dir.join(format!("juniper-spill-{}-{}", self.id, self.files.len()));
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        self.files.push(SpilledFile { path, len: 0 });
+        Ok(file)
+    }
+}
+
+impl Drop for SpilledStrings {
+    fn drop(&mut self) {
+        for f in &self.files {
+            // Nothing reasonable could be done about a file failed to be
+            // removed, so it's left to the system cleaning temporary files.
+            let _ = fs::remove_file(&f.path);
+        }
+    }
+}
+
+struct Parser<'p, R> {
+    input: io::Bytes<BufReader<R>>,
+    peeked: Option<u8>,
+    offset: usize,
+    /// Remaining and maximum number of bytes allowed to be read, if limited.
+    budget: Option<(usize, usize)>,
+    nesting: usize,
+    options: &'p VariablesParser,
+    spilled: SpilledStrings,
+}
+
+impl<'p, R: Read> Parser<'p, R> {
+    fn new(reader: R, options: &'p VariablesParser) -> Self {
+        Self {
+            input: BufReader::new(reader).bytes(),
+            peeked: None,
+            offset: 0,
+            budget: None,
+            nesting: 0,
+            options,
+            spilled: SpilledStrings::new(),
+        }
+    }
+
+    fn syntax(&self, message: &'static str) -> VariablesError {
+        VariablesError::Syntax {
+            offset: self.offset,
+            message,
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, VariablesError> {
+        if self.peeked.is_none() {
+            self.peeked = self.input.next().transpose()?;
+        }
+        Ok(self.peeked)
+    }
+
+    fn next(&mut self) -> Result<Option<u8>, VariablesError> {
+        let byte = self.peek()?;
+        if byte.is_some() {
+            if let Some((remaining, max)) = &mut self.budget {
+                if *remaining == 0 {
+                    return Err(VariablesError::TooLarge { max: *max });
+                }
+                *remaining -= 1;
+            }
+            self.peeked = None;
+            self.offset += 1;
+        }
+        Ok(byte)
+    }
+
+    fn expect(&mut self, byte: u8, message: &'static str) -> Result<(), VariablesError> {
+        match self.next()? {
+            Some(b) if b == byte => Ok(()),
+            _ => Err(self.syntax(message)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> Result<(), VariablesError> {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek()? {
+            self.next()?;
+        }
+        Ok(())
+    }
+
+    /// Checks that nothing but whitespace is left to be read.
+    fn end(&mut self) -> Result<(), VariablesError> {
+        self.skip_whitespace()?;
+        match self.peek()? {
+            None => Ok(()),
+            Some(_) => Err(self.syntax("trailing characters")),
+        }
+    }
+
+    fn value<S: ScalarValue>(&mut self, spill: bool) -> Result<InputValue<S>, VariablesError> {
+        self.skip_whitespace()?;
+        match self.peek()? {
+            Some(b'n') => self.literal("null").map(|_| InputValue::Null),
+            Some(b't') => self.literal("true").map(|_| InputValue::scalar(true)),
+            Some(b'f') => self.literal("false").map(|_| InputValue::scalar(false)),
+            Some(b'"') => {
+                self.next()?;
+                self.string(spill).map(InputValue::scalar)
+            }
+            Some(b'[') => self.list(spill),
+            Some(b'{') => self.object(spill),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.syntax("expected value")),
+            None => Err(self.syntax("unexpected end of input")),
+        }
+    }
+
+    fn literal(&mut self, literal: &'static str) -> Result<(), VariablesError> {
+        for &byte in literal.as_bytes() {
+            self.expect(byte, "invalid literal")?;
+        }
+        Ok(())
+    }
+
+    fn nest(&mut self) -> Result<(), VariablesError> {
+        self.nesting += 1;
+        if self.nesting > MAX_NESTING {
+            return Err(self.syntax("too deeply nested"));
+        }
+        Ok(())
+    }
+
+    fn list<S: ScalarValue>(&mut self, spill: bool) -> Result<InputValue<S>, VariablesError> {
+        self.next()?;
+        self.nest()?;
+        let mut items = vec![];
+        self.skip_whitespace()?;
+        if self.peek()? == Some(b']') {
+            self.next()?;
+        } else {
+            loop {
+                items.push(self.value(spill)?);
+                self.skip_whitespace()?;
+                match self.next()? {
+                    Some(b',') => {}
+                    Some(b']') => break,
+                    _ => return Err(self.syntax("expected `,` or `]`")),
+                }
+            }
+        }
+        self.nesting -= 1;
+        Ok(InputValue::list(items))
+    }
+
+    fn object<S: ScalarValue>(&mut self, spill: bool) -> Result<InputValue<S>, VariablesError> {
+        self.next()?;
+        self.nest()?;
+        let mut fields = IndexMap::new();
+        self.skip_whitespace()?;
+        if self.peek()? == Some(b'}') {
+            self.next()?;
+        } else {
+            loop {
+                self.skip_whitespace()?;
+                self.expect(b'"', "expected key")?;
+                let key = self.string(false)?;
+                self.skip_whitespace()?;
+                self.expect(b':', "expected `:`")?;
+                fields.insert(key, self.value(spill)?);
+                self.skip_whitespace()?;
+                match self.next()? {
+                    Some(b',') => {}
+                    Some(b'}') => break,
+                    _ => return Err(self.syntax("expected `,` or `}`")),
+                }
+            }
+        }
+        self.nesting -= 1;
+        Ok(InputValue::object(fields))
+    }
+
+    fn number<S: ScalarValue>(&mut self) -> Result<InputValue<S>, VariablesError> {
+        let mut digits = String::new();
+        while let Some(b @ (b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')) = self.peek()? {
+            self.next()?;
+            digits.push(char::from(b));
+        }
+
+        let scalar = if digits.contains(['.', 'e', 'E']) {
+            digits.parse::<f64>().ok().map(deserialize_scalar::<S, _>)
+        } else if digits.starts_with('-') {
+            match digits.parse::<i64>() {
+                Ok(n) => Some(deserialize_scalar::<S, _>(n)),
+                Err(_) => digits.parse::<f64>().ok().map(deserialize_scalar::<S, _>),
+            }
+        } else {
+            match digits.parse::<u64>() {
+                Ok(n) => Some(deserialize_scalar::<S, _>(n)),
+                Err(_) => digits.parse::<f64>().ok().map(deserialize_scalar::<S, _>),
+            }
+        };
+        match scalar {
+            Some(Ok(s)) => Ok(InputValue::Scalar(s)),
+            _ => Err(self.syntax("invalid number")),
+        }
+    }
+
+    /// Reads the rest of a string, whose opening quote has already been read.
+    ///
+    /// If `spill` is allowed and the string turns out to be longer than the
+    /// spill threshold, it's written into a temporary file, and its
+    /// placeholder is returned instead.
+    fn string(&mut self, spill: bool) -> Result<String, VariablesError> {
+        let threshold = self.options.spill_threshold.filter(|_| spill);
+        let mut buf = Vec::new();
+        let mut file: Option<fs::File> = None;
+
+        loop {
+            match self.next()? {
+                Some(b'"') => break,
+                Some(b'\\') => self.escape(&mut buf)?,
+                Some(b) if b < 0x20 => return Err(self.syntax("control character in string")),
+                Some(b) => buf.push(b),
+                None => return Err(self.syntax("unterminated string")),
+            }
+
+            if let Some(threshold) = threshold {
+                if file.is_none() && buf.len() > threshold {
+                    let dir = self
+                        .options
+                        .spill_dir
+                        .clone()
+                        .unwrap_or_else(std::env::temp_dir);
+                    file = Some(self.spilled.create(&dir)?);
+                }
+                if let Some(f) = &mut file {
+                    if buf.len() >= SPILL_CHUNK_SIZE {
+                        self.spill_chunk(f, &mut buf, false)?;
+                    }
+                }
+            }
+        }
+
+        match &mut file {
+            Some(f) => {
+                self.spill_chunk(f, &mut buf, true)?;
+                Ok(self.spilled.placeholder(self.spilled.len() - 1))
+            }
+            None => String::from_utf8(buf).map_err(|_| self.syntax("invalid UTF-8")),
+        }
+    }
+
+    /// Writes the valid UTF-8 prefix of the provided `buf` into the provided
+    /// spill `file`, keeping in the `buf` a character split by the chunk
+    /// boundary, unless it's the `last` chunk.
+    fn spill_chunk(
+        &mut self,
+        file: &mut fs::File,
+        buf: &mut Vec<u8>,
+        last: bool,
+    ) -> Result<(), VariablesError> {
+        let valid = match str::from_utf8(buf) {
+            Ok(_) => buf.len(),
+            Err(e) if e.error_len().is_none() && !last => e.valid_up_to(),
+            Err(_) => return Err(self.syntax("invalid UTF-8")),
+        };
+        file.write_all(&buf[..valid])?;
+        buf.drain(..valid);
+        if let Some(f) = self.spilled.files.last_mut() {
+            f.len += valid as u64;
+        }
+        Ok(())
+    }
+
+    /// Reads an escape sequence, whose backslash has already been read, and
+    /// appends the unescaped character to the provided `buf`.
+    fn escape(&mut self, buf: &mut Vec<u8>) -> Result<(), VariablesError> {
+        let byte = match self.next()? {
+            Some(b @ (b'"' | b'\\' | b'/')) => b,
+            Some(b'b') => b'\x08',
+            Some(b'f') => b'\x0c',
+            Some(b'n') => b'\n',
+            Some(b'r') => b'\r',
+            Some(b't') => b'\t',
+            Some(b'u') => {
+                let mut code = u32::from(self.hex4()?);
+                if (0xD800..0xDC00).contains(&code) {
+                    self.expect(b'\\', "unpaired surrogate")?;
+                    self.expect(b'u', "unpaired surrogate")?;
+                    let low = u32::from(self.hex4()?);
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.syntax("unpaired surrogate"));
+                    }
+                    code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                }
+                let c = char::from_u32(code).ok_or_else(|| self.syntax("unpaired surrogate"))?;
+                buf.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                return Ok(());
+            }
+            _ => return Err(self.syntax("invalid escape sequence")),
+        };
+        buf.push(byte);
+        Ok(())
+    }
+
+    fn hex4(&mut self) -> Result<u16, VariablesError> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self
+                .next()?
+                .and_then(|b| char::from(b).to_digit(16))
+                .ok_or_else(|| self.syntax("invalid unicode escape"))?;
+            code = code * 16 + digit as u16;
+        }
+        Ok(code)
+    }
+}
+
+fn deserialize_scalar<'de, S, T>(n: T) -> Result<S, de::value::Error>
+where
+    S: ScalarValue,
+    T: de::IntoDeserializer<'de, de::value::Error>,
+{
+    S::deserialize(n.into_deserializer())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read as _;
+
+    use crate::{graphql_input_value, DefaultScalarValue};
+
+    use super::{VariablesError, VariablesParser};
+
+    #[test]
+    fn parses_variables() {
+        let (vars, spilled) = VariablesParser::new()
+            .parse::<DefaultScalarValue>(
+                r#" {"a": [1, -2, 3.5, 1e2], "b": {"c": null, "d": true}, "e": "\"é😀\n"} "#
+                    .as_bytes(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            vars,
+            graphql_input_value!({
+                "a": [1, -2, 3.5, 100.0],
+                "b": {"c": null, "d": true},
+                "e": "\"é😀\n",
+            }),
+        );
+        assert!(spilled.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        for (input, offset) in [
+            (r#"{"a": }"#, 6),
+            (r#"{"a": 1"#, 7),
+            (r#"[1, 2] 3"#, 7),
+            (r#""\ud83d""#, 8),
+        ] {
+            let res = VariablesParser::new().parse::<DefaultScalarValue>(input.as_bytes());
+
+            assert!(
+                matches!(res, Err(VariablesError::Syntax { offset: o, .. }) if o == offset),
+                "{input}: {res:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_too_large_variables() {
+        let parser = VariablesParser::new().max_size(10);
+
+        assert!(parser
+            .parse::<DefaultScalarValue>(r#"{"a": "b"}"#.as_bytes())
+            .is_ok());
+        assert!(matches!(
+            parser.parse::<DefaultScalarValue>(r#"{"a": "bc"}"#.as_bytes()),
+            Err(VariablesError::TooLarge { max: 10 }),
+        ));
+    }
+
+    #[test]
+    fn spills_long_strings() {
+        let long = "é".repeat(10_000);
+        let input = format!(r#"{{"short": "abc", "long": ["{long}"]}}"#);
+
+        let (vars, spilled) = VariablesParser::new()
+            .spill_threshold(3)
+            .parse::<DefaultScalarValue>(input.as_bytes())
+            .unwrap();
+
+        assert_eq!(spilled.len(), 1);
+        let vars = vars.to_object_value().unwrap();
+        assert_eq!(vars["short"], &graphql_input_value!("abc"));
+        let placeholder = vars["long"].to_list_value().unwrap()[0]
+            .as_string_value()
+            .unwrap();
+        assert!(!spilled.is_spilled("abc"));
+        assert_eq!(spilled.size(placeholder), Some(long.len() as u64));
+
+        let mut content = String::new();
+        spilled
+            .open(placeholder)
+            .unwrap()
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, long);
+    }
+
+    #[test]
+    fn removes_spilled_strings_on_drop() {
+        let dir = std::env::temp_dir().join(format!("juniper-spill-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let (_, spilled) = VariablesParser::new()
+            .spill_threshold(0)
+            .spill_dir(&dir)
+            .parse::<DefaultScalarValue>(r#"["a", "b"]"#.as_bytes())
+            .unwrap();
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+
+        drop(spilled);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+        std::fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn parses_requests() {
+        let (request, spilled) = VariablesParser::new()
+            .max_size(16)
+            .spill_threshold(4)
+            .parse_request::<DefaultScalarValue>(
+                r#"{
+                    "query": "query Q($a: String) { a(a: $a) }",
+                    "operationName": "Q",
+                    "variables": {"a": "bcdef"},
+                    "extensions": {"tracing": true},
+                    "ignored": [{"long string": "not spilled"}]
+                }"#
+                .as_bytes(),
+            )
+            .unwrap();
+
+        assert_eq!(request.query, "query Q($a: String) { a(a: $a) }");
+        assert_eq!(request.operation_name.as_deref(), Some("Q"));
+        assert_eq!(
+            request.extensions,
+            Some(graphql_input_value!({"tracing": true}))
+        );
+        assert_eq!(spilled.len(), 1);
+        let vars = request.variables();
+        assert!(spilled.is_spilled(vars["a"].as_string_value().unwrap()));
+    }
+
+    #[test]
+    fn limits_only_variables_of_requests() {
+        let parser = VariablesParser::new().max_size(8);
+
+        assert!(parser
+            .parse_request::<DefaultScalarValue>(
+                r#"{"query": "{ a }", "variables": {"a": 1}}"#.as_bytes()
+            )
+            .is_ok());
+        assert!(matches!(
+            parser.parse_request::<DefaultScalarValue>(
+                r#"{"query": "{ a }", "variables": {"a": 100}}"#.as_bytes()
+            ),
+            Err(VariablesError::TooLarge { max: 8 }),
+        ));
+        assert!(matches!(
+            parser.parse_request::<DefaultScalarValue>(r#"{"variables": null}"#.as_bytes()),
+            Err(VariablesError::Syntax { .. }),
+        ));
+    }
+}
@@ -0,0 +1,342 @@
+//! [`Arbitrary`] implementations for [`InputValue`]s, GraphQL documents and
+//! [`Variables`], allowing to fuzz custom scalars, input objects and
+//! validators with structured inputs.
+//!
+//! Generated documents are syntactically valid and use names from a small
+//! fixed vocabulary (resembling the Star Wars schema used in `juniper` tests),
+//! so they're likely to pass parsing and reach deeper levels of validation and
+//! execution, rather than being rejected early.
+//!
+//! See the `fuzz/` directory of the `juniper` crate for ready-to-use
+//! [`cargo fuzz`] targets, runnable as:
+//! ```bash
+//! cd juniper/
+//! cargo +nightly fuzz run validate_document
+//! ```
+//!
+//! [`cargo fuzz`]: https://github.com/rust-fuzz/cargo-fuzz
+
+use std::borrow::Cow;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    ast::{
+        Arguments, Directive, Field, Fragment, FragmentSpread, InlineFragment, VariableDefinition,
+        VariableDefinitions,
+    },
+    DefaultScalarValue, Definition, InputValue, Operation, OperationType, Selection, Spanning,
+    Type, Variables,
+};
+
+/// Maximum nesting depth of the generated values and selection sets.
+const MAX_DEPTH: usize = 6;
+
+/// Vocabulary of names for fields, arguments, variables and fragments.
+const NAMES: &[&str] = &[
+    "id",
+    "name",
+    "friends",
+    "appearsIn",
+    "homePlanet",
+    "primaryFunction",
+    "hero",
+    "human",
+    "droid",
+    "episode",
+    "first",
+    "if",
+    "reason",
+    "__typename",
+    "__schema",
+    "__type",
+    "fields",
+    "kind",
+    "ofType",
+];
+
+/// Vocabulary of names for types.
+const TYPE_NAMES: &[&str] = &[
+    "Int",
+    "Float",
+    "String",
+    "Boolean",
+    "ID",
+    "Query",
+    "Character",
+    "Human",
+    "Droid",
+    "Episode",
+    "__Schema",
+    "__Type",
+];
+
+/// Vocabulary of names for enum values.
+const ENUM_NAMES: &[&str] = &["NEW_HOPE", "EMPIRE", "JEDI", "OBJECT", "SCALAR"];
+
+/// Vocabulary of names for directives.
+const DIRECTIVE_NAMES: &[&str] = &["include", "skip", "deprecated", "specifiedBy"];
+
+impl<'a> Arbitrary<'a> for DefaultScalarValue {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=3_u8)? {
+            0 => Self::Int(u.arbitrary()?),
+            1 => Self::Float(u.arbitrary()?),
+            2 => Self::String(u.arbitrary()?),
+            _ => Self::Boolean(u.arbitrary()?),
+        })
+    }
+}
+
+impl<'a, S: Arbitrary<'a>> Arbitrary<'a> for InputValue<S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        input_value(u, 0)
+    }
+}
+
+impl<'a, S: Arbitrary<'a>> Arbitrary<'a> for Definition<'a, S> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.arbitrary()? {
+            Self::Operation(Spanning::unlocated(operation(u)?))
+        } else {
+            Self::Fragment(Spanning::unlocated(fragment(u)?))
+        })
+    }
+}
+
+/// Generates arbitrary [`Variables`] named from the fixed vocabulary.
+///
+/// Unlike the [`Arbitrary`] implementation of [`Variables`] (provided for any
+/// [`HashMap`]), the generated names are always valid GraphQL names, so they
+/// may match the variables defined by an operation.
+///
+/// [`HashMap`]: std::collections::HashMap
+pub fn arbitrary_variables<'a, S: Arbitrary<'a>>(u: &mut Unstructured<'a>) -> Result<Variables<S>> {
+    let len = u.int_in_range(0..=4_usize)?;
+    (0..len)
+        .map(|_| Ok((name(u)?.into(), input_value(u, 0)?)))
+        .collect()
+}
+
+/// Chooses a name from the given `vocabulary`.
+fn choose(u: &mut Unstructured<'_>, vocabulary: &[&'static str]) -> Result<&'static str> {
+    u.choose(vocabulary).copied()
+}
+
+/// Chooses a name of a field, an argument, a variable or a fragment.
+fn name(u: &mut Unstructured<'_>) -> Result<&'static str> {
+    choose(u, NAMES)
+}
+
+/// Generates a value with the provided function, or [`None`].
+fn optional<'a, T>(
+    u: &mut Unstructured<'a>,
+    f: impl FnOnce(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<Option<T>> {
+    Ok(if u.arbitrary()? { Some(f(u)?) } else { None })
+}
+
+/// Generates a list of `min..=max` values with the provided function.
+fn list<'a, T>(
+    u: &mut Unstructured<'a>,
+    min: usize,
+    max: usize,
+    mut f: impl FnMut(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<Vec<T>> {
+    let len = u.int_in_range(min..=max)?;
+    (0..len).map(|_| f(u)).collect()
+}
+
+fn input_value<'a, S: Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    depth: usize,
+) -> Result<InputValue<S>> {
+    let max_kind = if depth < MAX_DEPTH { 5 } else { 3 };
+    Ok(match u.int_in_range(0..=max_kind)? {
+        0 => InputValue::Null,
+        1 => InputValue::Scalar(u.arbitrary()?),
+        2 => InputValue::Enum(choose(u, ENUM_NAMES)?.into()),
+        3 => InputValue::Variable(name(u)?.into()),
+        4 => InputValue::List(list(u, 0, 4, |u| {
+            input_value(u, depth + 1).map(Spanning::unlocated)
+        })?),
+        _ => InputValue::Object(list(u, 0, 4, |u| {
+            Ok((
+                Spanning::unlocated(name(u)?.into()),
+                Spanning::unlocated(input_value(u, depth + 1)?),
+            ))
+        })?),
+    })
+}
+
+fn type_literal<'a>(u: &mut Unstructured<'a>, depth: usize) -> Result<Type<'a>> {
+    let max_kind = if depth < MAX_DEPTH { 3 } else { 1 };
+    Ok(match u.int_in_range(0..=max_kind)? {
+        0 => Type::Named(Cow::Borrowed(choose(u, TYPE_NAMES)?)),
+        1 => Type::NonNullNamed(Cow::Borrowed(choose(u, TYPE_NAMES)?)),
+        2 => Type::List(Box::new(type_literal(u, depth + 1)?), None),
+        _ => Type::NonNullList(Box::new(type_literal(u, depth + 1)?), None),
+    })
+}
+
+fn arguments<'a, S: Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    depth: usize,
+) -> Result<Spanning<Arguments<'a, S>>> {
+    let items = list(u, 1, 3, |u| {
+        Ok((
+            Spanning::unlocated(name(u)?),
+            Spanning::unlocated(input_value(u, depth)?),
+        ))
+    })?;
+    Ok(Spanning::unlocated(Arguments { items }))
+}
+
+fn directives<'a, S: Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    depth: usize,
+) -> Result<Option<Vec<Spanning<Directive<'a, S>>>>> {
+    optional(u, |u| {
+        list(u, 1, 2, |u| {
+            Ok(Spanning::unlocated(Directive {
+                name: Spanning::unlocated(choose(u, DIRECTIVE_NAMES)?),
+                arguments: optional(u, |u| arguments(u, depth))?,
+            }))
+        })
+    })
+}
+
+fn selection_set<'a, S: Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    depth: usize,
+) -> Result<Vec<Selection<'a, S>>> {
+    list(u, 1, 4, |u| selection(u, depth))
+}
+
+fn selection<'a, S: Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    depth: usize,
+) -> Result<Selection<'a, S>> {
+    let max_kind = if depth < MAX_DEPTH { 2 } else { 0 };
+    Ok(match u.int_in_range(0..=max_kind)? {
+        0 => Selection::Field(Spanning::unlocated(Field {
+            alias: optional(u, |u| name(u).map(Spanning::unlocated))?,
+            name: Spanning::unlocated(name(u)?),
+            arguments: optional(u, |u| arguments(u, depth))?,
+            directives: directives(u, depth)?,
+            selection_set: if depth < MAX_DEPTH {
+                optional(u, |u| selection_set(u, depth + 1))?
+            } else {
+                None
+            },
+        })),
+        1 => Selection::FragmentSpread(Spanning::unlocated(FragmentSpread {
+            name: Spanning::unlocated(name(u)?),
+            directives: directives(u, depth)?,
+        })),
+        _ => Selection::InlineFragment(Spanning::unlocated(InlineFragment {
+            type_condition: optional(u, |u| choose(u, TYPE_NAMES).map(Spanning::unlocated))?,
+            directives: directives(u, depth)?,
+            selection_set: selection_set(u, depth + 1)?,
+        })),
+    })
+}
+
+fn operation<'a, S: Arbitrary<'a>>(u: &mut Unstructured<'a>) -> Result<Operation<'a, S>> {
+    let operation_type = u
+        .choose(&[
+            OperationType::Query,
+            OperationType::Mutation,
+            OperationType::Subscription,
+        ])?
+        .clone();
+    let variable_definitions = optional(u, |u| {
+        let items = list(u, 1, 3, |u| {
+            Ok((
+                Spanning::unlocated(name(u)?),
+                VariableDefinition {
+                    var_type: Spanning::unlocated(type_literal(u, 0)?),
+                    default_value: optional(u, |u| input_value(u, 0).map(Spanning::unlocated))?,
+                    directives: directives(u, 0)?,
+                },
+            ))
+        })?;
+        Ok(Spanning::unlocated(VariableDefinitions { items }))
+    })?;
+
+    Ok(Operation {
+        operation_type,
+        name: optional(u, |u| name(u).map(Spanning::unlocated))?,
+        variable_definitions,
+        directives: directives(u, 0)?,
+        selection_set: selection_set(u, 0)?,
+    })
+}
+
+fn fragment<'a, S: Arbitrary<'a>>(u: &mut Unstructured<'a>) -> Result<Fragment<'a, S>> {
+    Ok(Fragment {
+        name: Spanning::unlocated(name(u)?),
+        type_condition: Spanning::unlocated(choose(u, TYPE_NAMES)?),
+        directives: directives(u, 0)?,
+        selection_set: selection_set(u, 0)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use arbitrary::Unstructured;
+
+    use crate::{
+        schema::model::RootNode,
+        tests::fixtures::starwars::schema::{Database, Query},
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::{visit_all_rules, ValidatorContext},
+        DefaultScalarValue, Definition, InputValue, SplitMixRng,
+    };
+
+    use super::arbitrary_variables;
+
+    fn data(seed: u64) -> Vec<u8> {
+        use crate::Rng as _;
+
+        let rng = SplitMixRng::with_seed(seed);
+        (0..4096).map(|_| rng.next_u64() as u8).collect()
+    }
+
+    #[test]
+    fn generates_validatable_documents() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+
+        for seed in 0..64 {
+            let data = data(seed);
+            let mut u = Unstructured::new(&data);
+
+            let doc = u
+                .arbitrary::<Vec<Definition<'_, DefaultScalarValue>>>()
+                .unwrap_or_else(|e| panic!("failed to generate document: {e}"));
+            let mut ctx = ValidatorContext::new(&schema.schema, &doc);
+            visit_all_rules(&mut ctx, &doc);
+        }
+    }
+
+    #[test]
+    fn generates_input_values_and_variables() {
+        for seed in 0..64 {
+            let data = data(seed);
+            let mut u = Unstructured::new(&data);
+
+            u.arbitrary::<InputValue<DefaultScalarValue>>()
+                .unwrap_or_else(|e| panic!("failed to generate `InputValue`: {e}"));
+
+            let vars = arbitrary_variables::<DefaultScalarValue>(&mut u)
+                .unwrap_or_else(|e| panic!("failed to generate `Variables`: {e}"));
+            assert!(vars
+                .keys()
+                .all(|name| super::NAMES.contains(&name.as_str())));
+        }
+    }
+}
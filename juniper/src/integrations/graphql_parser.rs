@@ -0,0 +1,687 @@
+//! Conversions between the executable documents (and their parts) parsed by
+//! juniper, and the AST of [graphql-parser](https://docs.rs/graphql-parser)
+//! crate.
+//!
+//! Allows reusing the tooling built around [`graphql_parser`] AST (linters,
+//! printers, etc.) with the documents parsed by juniper, and executing the
+//! documents built or transformed by such tooling.
+//!
+//! Whole documents are converted with [`to_parser_document()`] and
+//! [`from_parser_document()`], while their parts implement [`From`] and
+//! [`TryFrom`] conversions.
+//!
+//! Conversions are lossy in the following ways:
+//! - [`graphql_parser`] AST doesn't support directives on variable
+//!   definitions, so they're omitted;
+//! - [`graphql_parser`] AST has no end positions of its nodes (and no byte
+//!   offsets at all), so nodes converted from it are unlocated.
+//!
+//! ```rust
+//! # use juniper::{
+//! #     integrations::graphql_parser::{from_parser_document, to_parser_document},
+//! #     DefaultScalarValue,
+//! # };
+//! #
+//! let external = graphql_parser::parse_query::<&str>("{ hero { name } }").unwrap();
+//!
+//! let doc = from_parser_document::<DefaultScalarValue, _>(&external).unwrap();
+//! // ...execute, validate or transform the `doc`...
+//! let external = to_parser_document::<_, String>(&doc);
+//!
+//! assert_eq!(
+//!     external.to_string(),
+//!     "query {\n  hero {\n    name\n  }\n}\n",
+//! );
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use graphql_parser::{
+    query::{
+        Definition as ExternalDefinition, Directive as ExternalDirective,
+        Document as ExternalDocument, Field as ExternalField,
+        FragmentDefinition as ExternalFragment, FragmentSpread as ExternalFragmentSpread,
+        InlineFragment as ExternalInlineFragment, Mutation as ExternalMutation,
+        Number as ExternalNumber, OperationDefinition as ExternalOperation, Query as ExternalQuery,
+        Selection as ExternalSelection, SelectionSet as ExternalSelectionSet,
+        Subscription as ExternalSubscription, Text, Type as ExternalType,
+        TypeCondition as ExternalTypeCondition, Value as ExternalValue,
+        VariableDefinition as ExternalVariableDefinition,
+    },
+    Pos,
+};
+use indexmap::IndexMap;
+
+use crate::{
+    ast::{
+        Arguments, Definition, Directive, Document, Field, Fragment, FragmentSpread,
+        InlineFragment, InputValue, Operation, OperationType, Selection, Type, VariableDefinition,
+        VariableDefinitions,
+    },
+    parser::{SourcePosition, Spanning},
+    value::ScalarValue,
+};
+
+/// Error of converting [`graphql_parser`] AST into juniper's one.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FromParserAstError {
+    /// `Int` value doesn't fit into a signed 32-bit integer, as required by
+    /// the [spec](https://spec.graphql.org/October2021#sec-Int).
+    IntOutOfRange(ExternalNumber),
+}
+
+impl fmt::Display for FromParserAstError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IntOutOfRange(n) => match n.as_i64() {
+                Some(n) => write!(f, "`Int` value `{n}` doesn't fit into 32 bits"),
+                None => write!(f, "`Int` value doesn't fit into 32 bits"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for FromParserAstError {}
+
+/// Converts the provided juniper's executable `doc`ument into a
+/// [`graphql_parser`] one.
+pub fn to_parser_document<'a, S, T>(doc: &'a Document<'a, S>) -> ExternalDocument<'a, T>
+where
+    S: ScalarValue,
+    T: Text<'a>,
+{
+    ExternalDocument {
+        definitions: doc.iter().map(Into::into).collect(),
+    }
+}
+
+/// Converts the provided [`graphql_parser`] executable `doc`ument into a
+/// juniper's one, borrowing names from it.
+///
+/// # Errors
+///
+/// If the `doc`ument contains values not representable in juniper.
+pub fn from_parser_document<'a, 'b, S, T>(
+    doc: &'a ExternalDocument<'b, T>,
+) -> Result<Vec<Definition<'a, S>>, FromParserAstError>
+where
+    S: ScalarValue,
+    T: Text<'b>,
+{
+    doc.definitions.iter().map(TryFrom::try_from).collect()
+}
+
+impl<'a, S, T> From<&'a Definition<'a, S>> for ExternalDefinition<'a, T>
+where
+    S: ScalarValue,
+    T: Text<'a>,
+{
+    fn from(def: &'a Definition<'a, S>) -> Self {
+        match def {
+            Definition::Operation(op) => Self::Operation(operation_to_parser(op)),
+            Definition::Fragment(frag) => Self::Fragment(ExternalFragment {
+                position: pos(&frag.start),
+                name: frag.item.name.item.into(),
+                type_condition: ExternalTypeCondition::On(frag.item.type_condition.item.into()),
+                directives: directives_to_parser(&frag.item.directives),
+                selection_set: selection_set_to_parser(&frag.item.selection_set, frag),
+            }),
+        }
+    }
+}
+
+impl<'a, 'b, S, T> TryFrom<&'a ExternalDefinition<'b, T>> for Definition<'a, S>
+where
+    S: ScalarValue,
+    T: Text<'b>,
+{
+    type Error = FromParserAstError;
+
+    fn try_from(def: &'a ExternalDefinition<'b, T>) -> Result<Self, Self::Error> {
+        Ok(match def {
+            ExternalDefinition::Operation(op) => {
+                Self::Operation(Spanning::unlocated(operation_from_parser(op)?))
+            }
+            ExternalDefinition::Fragment(frag) => Self::Fragment(Spanning::unlocated(Fragment {
+                name: Spanning::unlocated(frag.name.as_ref()),
+                type_condition: Spanning::unlocated(type_condition_from_parser(
+                    &frag.type_condition,
+                )),
+                directives: directives_from_parser(&frag.directives)?,
+                selection_set: selection_set_from_parser(&frag.selection_set)?,
+            })),
+        })
+    }
+}
+
+impl<'a, S, T> From<&'a Selection<'a, S>> for ExternalSelection<'a, T>
+where
+    S: ScalarValue,
+    T: Text<'a>,
+{
+    fn from(sel: &'a Selection<'a, S>) -> Self {
+        match sel {
+            Selection::Field(field) => Self::Field(ExternalField {
+                position: pos(&field.start),
+                alias: field.item.alias.as_ref().map(|a| a.item.into()),
+                name: field.item.name.item.into(),
+                arguments: arguments_to_parser(&field.item.arguments),
+                directives: directives_to_parser(&field.item.directives),
+                selection_set: selection_set_to_parser(
+                    field.item.selection_set.as_deref().unwrap_or_default(),
+                    field,
+                ),
+            }),
+            Selection::FragmentSpread(spread) => Self::FragmentSpread(ExternalFragmentSpread {
+                position: pos(&spread.start),
+                fragment_name: spread.item.name.item.into(),
+                directives: directives_to_parser(&spread.item.directives),
+            }),
+            Selection::InlineFragment(frag) => Self::InlineFragment(ExternalInlineFragment {
+                position: pos(&frag.start),
+                type_condition: frag
+                    .item
+                    .type_condition
+                    .as_ref()
+                    .map(|t| ExternalTypeCondition::On(t.item.into())),
+                directives: directives_to_parser(&frag.item.directives),
+                selection_set: selection_set_to_parser(&frag.item.selection_set, frag),
+            }),
+        }
+    }
+}
+
+impl<'a, 'b, S, T> TryFrom<&'a ExternalSelection<'b, T>> for Selection<'a, S>
+where
+    S: ScalarValue,
+    T: Text<'b>,
+{
+    type Error = FromParserAstError;
+
+    fn try_from(sel: &'a ExternalSelection<'b, T>) -> Result<Self, Self::Error> {
+        Ok(match sel {
+            ExternalSelection::Field(field) => Self::Field(Spanning::unlocated(Field {
+                alias: field
+                    .alias
+                    .as_ref()
+                    .map(|a| Spanning::unlocated(a.as_ref())),
+                name: Spanning::unlocated(field.name.as_ref()),
+                arguments: arguments_from_parser(&field.arguments)?,
+                directives: directives_from_parser(&field.directives)?,
+                selection_set: (!field.selection_set.items.is_empty())
+                    .then(|| selection_set_from_parser(&field.selection_set))
+                    .transpose()?,
+            })),
+            ExternalSelection::FragmentSpread(spread) => {
+                Self::FragmentSpread(Spanning::unlocated(FragmentSpread {
+                    name: Spanning::unlocated(spread.fragment_name.as_ref()),
+                    directives: directives_from_parser(&spread.directives)?,
+                }))
+            }
+            ExternalSelection::InlineFragment(frag) => {
+                Self::InlineFragment(Spanning::unlocated(InlineFragment {
+                    type_condition: frag
+                        .type_condition
+                        .as_ref()
+                        .map(|t| Spanning::unlocated(type_condition_from_parser(t))),
+                    directives: directives_from_parser(&frag.directives)?,
+                    selection_set: selection_set_from_parser(&frag.selection_set)?,
+                }))
+            }
+        })
+    }
+}
+
+impl<'a, S, T> From<&'a InputValue<S>> for ExternalValue<'a, T>
+where
+    S: ScalarValue,
+    T: Text<'a>,
+{
+    fn from(v: &'a InputValue<S>) -> Self {
+        match v {
+            InputValue::Null => Self::Null,
+            InputValue::Scalar(s) => {
+                if let Some(i) = s.as_int() {
+                    Self::Int(i.into())
+                } else if let Some(f) = s.as_float() {
+                    Self::Float(f)
+                } else if let Some(b) = s.as_bool() {
+                    Self::Boolean(b)
+                } else {
+                    Self::String(s.as_string().unwrap_or_else(|| s.to_string()))
+                }
+            }
+            InputValue::Enum(e) => Self::Enum(e.as_str().into()),
+            InputValue::Variable(name) => Self::Variable(name.as_str().into()),
+            InputValue::List(items) => Self::List(items.iter().map(|i| (&i.item).into()).collect()),
+            InputValue::Object(fields) => Self::Object(
+                fields
+                    .iter()
+                    .map(|(k, v)| (k.item.as_str().into(), (&v.item).into()))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+        }
+    }
+}
+
+impl<'b, S, T> TryFrom<&ExternalValue<'b, T>> for InputValue<S>
+where
+    S: ScalarValue,
+    T: Text<'b>,
+{
+    type Error = FromParserAstError;
+
+    fn try_from(v: &ExternalValue<'b, T>) -> Result<Self, Self::Error> {
+        Ok(match v {
+            ExternalValue::Null => Self::null(),
+            ExternalValue::Int(n) => Self::scalar(
+                n.as_i64()
+                    .and_then(|n| i32::try_from(n).ok())
+                    .ok_or_else(|| FromParserAstError::IntOutOfRange(n.clone()))?,
+            ),
+            ExternalValue::Float(f) => Self::scalar(*f),
+            ExternalValue::String(s) => Self::scalar(s.clone()),
+            ExternalValue::Boolean(b) => Self::scalar(*b),
+            ExternalValue::Enum(e) => Self::enum_value(e),
+            ExternalValue::Variable(name) => Self::variable(name),
+            ExternalValue::List(items) => Self::list(
+                items
+                    .iter()
+                    .map(TryFrom::try_from)
+                    .collect::<Result<_, _>>()?,
+            ),
+            ExternalValue::Object(fields) => Self::object(
+                fields
+                    .iter()
+                    .map(|(k, v)| Ok((k.as_ref(), v.try_into()?)))
+                    .collect::<Result<IndexMap<_, _>, _>>()?,
+            ),
+        })
+    }
+}
+
+impl<'a, T> From<&'a Type<'a>> for ExternalType<'a, T>
+where
+    T: Text<'a>,
+{
+    fn from(ty: &'a Type<'a>) -> Self {
+        match ty {
+            Type::Named(name) => Self::NamedType(name.as_ref().into()),
+            Type::List(item, _) => Self::ListType(Box::new((&**item).into())),
+            Type::NonNullNamed(name) => {
+                Self::NonNullType(Box::new(Self::NamedType(name.as_ref().into())))
+            }
+            Type::NonNullList(item, _) => {
+                Self::NonNullType(Box::new(Self::ListType(Box::new((&**item).into()))))
+            }
+        }
+    }
+}
+
+impl<'a, 'b, T> From<&'a ExternalType<'b, T>> for Type<'a>
+where
+    T: Text<'b>,
+{
+    fn from(ty: &'a ExternalType<'b, T>) -> Self {
+        match ty {
+            ExternalType::NamedType(name) => Self::Named(name.as_ref().into()),
+            ExternalType::ListType(item) => Self::List(Box::new((&**item).into()), None),
+            ExternalType::NonNullType(ty) => match &**ty {
+                ExternalType::NamedType(name) => Self::NonNullNamed(name.as_ref().into()),
+                ExternalType::ListType(item) => Self::NonNullList(Box::new((&**item).into()), None),
+                // Doubly non-null types are not valid GraphQL, so are collapsed.
+                ExternalType::NonNullType(_) => (&**ty).into(),
+            },
+        }
+    }
+}
+
+/// Converts the provided [`SourcePosition`] into a one-based [`Pos`].
+fn pos(p: &SourcePosition) -> Pos {
+    Pos {
+        line: p.line() + 1,
+        column: p.column() + 1,
+    }
+}
+
+/// Converts the provided `op`eration into a [`graphql_parser`] one.
+fn operation_to_parser<'a, S, T>(op: &'a Spanning<Operation<'a, S>>) -> ExternalOperation<'a, T>
+where
+    S: ScalarValue,
+    T: Text<'a>,
+{
+    let position = pos(&op.start);
+    let name = op.item.name.as_ref().map(|n| n.item.into());
+    let variable_definitions = op
+        .item
+        .variable_definitions
+        .as_ref()
+        .map(|defs| {
+            defs.item
+                .iter()
+                .map(|(name, def)| ExternalVariableDefinition {
+                    position: pos(&name.start),
+                    name: name.item.into(),
+                    var_type: (&def.var_type.item).into(),
+                    default_value: def.default_value.as_ref().map(|v| (&v.item).into()),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let directives = directives_to_parser(&op.item.directives);
+    let selection_set = selection_set_to_parser(&op.item.selection_set, op);
+
+    match op.item.operation_type {
+        OperationType::Query => ExternalOperation::Query(ExternalQuery {
+            position,
+            name,
+            variable_definitions,
+            directives,
+            selection_set,
+        }),
+        OperationType::Mutation => ExternalOperation::Mutation(ExternalMutation {
+            position,
+            name,
+            variable_definitions,
+            directives,
+            selection_set,
+        }),
+        OperationType::Subscription => ExternalOperation::Subscription(ExternalSubscription {
+            position,
+            name,
+            variable_definitions,
+            directives,
+            selection_set,
+        }),
+    }
+}
+
+/// Converts the provided [`graphql_parser`] `op`eration into a juniper's one.
+fn operation_from_parser<'a, 'b, S, T>(
+    op: &'a ExternalOperation<'b, T>,
+) -> Result<Operation<'a, S>, FromParserAstError>
+where
+    S: ScalarValue,
+    T: Text<'b>,
+{
+    let (operation_type, name, variable_definitions, directives, selection_set) = match op {
+        ExternalOperation::SelectionSet(set) => (OperationType::Query, None, &[][..], &[][..], set),
+        ExternalOperation::Query(q) => (
+            OperationType::Query,
+            q.name.as_ref(),
+            &q.variable_definitions[..],
+            &q.directives[..],
+            &q.selection_set,
+        ),
+        ExternalOperation::Mutation(m) => (
+            OperationType::Mutation,
+            m.name.as_ref(),
+            &m.variable_definitions[..],
+            &m.directives[..],
+            &m.selection_set,
+        ),
+        ExternalOperation::Subscription(s) => (
+            OperationType::Subscription,
+            s.name.as_ref(),
+            &s.variable_definitions[..],
+            &s.directives[..],
+            &s.selection_set,
+        ),
+    };
+
+    let variable_definitions = if variable_definitions.is_empty() {
+        None
+    } else {
+        Some(Spanning::unlocated(VariableDefinitions {
+            items: variable_definitions
+                .iter()
+                .map(|def| {
+                    Ok((
+                        Spanning::unlocated(def.name.as_ref()),
+                        VariableDefinition {
+                            var_type: Spanning::unlocated((&def.var_type).into()),
+                            default_value: def
+                                .default_value
+                                .as_ref()
+                                .map(|v| v.try_into().map(Spanning::unlocated))
+                                .transpose()?,
+                            directives: None,
+                        },
+                    ))
+                })
+                .collect::<Result<_, FromParserAstError>>()?,
+        }))
+    };
+
+    Ok(Operation {
+        operation_type,
+        name: name.map(|n| Spanning::unlocated(n.as_ref())),
+        variable_definitions,
+        directives: directives_from_parser(directives)?,
+        selection_set: selection_set_from_parser(selection_set)?,
+    })
+}
+
+/// Converts the provided `selection_set` of the `parent` node into a
+/// [`graphql_parser`] one.
+fn selection_set_to_parser<'a, S, T, P>(
+    selection_set: &'a [Selection<'a, S>],
+    parent: &Spanning<P>,
+) -> ExternalSelectionSet<'a, T>
+where
+    S: ScalarValue,
+    T: Text<'a>,
+{
+    ExternalSelectionSet {
+        span: (pos(&parent.start), pos(&parent.end)),
+        items: selection_set.iter().map(Into::into).collect(),
+    }
+}
+
+/// Converts the provided [`graphql_parser`] `selection_set` into a juniper's
+/// one.
+fn selection_set_from_parser<'a, 'b, S, T>(
+    selection_set: &'a ExternalSelectionSet<'b, T>,
+) -> Result<Vec<Selection<'a, S>>, FromParserAstError>
+where
+    S: ScalarValue,
+    T: Text<'b>,
+{
+    selection_set.items.iter().map(TryFrom::try_from).collect()
+}
+
+/// Converts the provided [`graphql_parser`] type condition into a juniper's
+/// one.
+fn type_condition_from_parser<'a, 'b, T>(cond: &'a ExternalTypeCondition<'b, T>) -> &'a str
+where
+    T: Text<'b>,
+{
+    match cond {
+        ExternalTypeCondition::On(name) => name.as_ref(),
+    }
+}
+
+/// Converts the provided `arguments` into [`graphql_parser`] ones.
+fn arguments_to_parser<'a, S, T>(
+    arguments: &'a Option<Spanning<Arguments<'a, S>>>,
+) -> Vec<(T::Value, ExternalValue<'a, T>)>
+where
+    S: ScalarValue,
+    T: Text<'a>,
+{
+    arguments
+        .iter()
+        .flat_map(|args| &args.item.items)
+        .map(|(name, value)| (name.item.into(), (&value.item).into()))
+        .collect()
+}
+
+/// Converts the provided [`graphql_parser`] `arguments` into juniper's ones.
+fn arguments_from_parser<'a, 'b, S, T>(
+    arguments: &'a [(T::Value, ExternalValue<'b, T>)],
+) -> Result<Option<Spanning<Arguments<'a, S>>>, FromParserAstError>
+where
+    S: ScalarValue,
+    T: Text<'b>,
+{
+    if arguments.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Spanning::unlocated(Arguments {
+        items: arguments
+            .iter()
+            .map(|(name, value)| {
+                Ok((
+                    Spanning::unlocated(name.as_ref()),
+                    Spanning::unlocated(value.try_into()?),
+                ))
+            })
+            .collect::<Result<_, FromParserAstError>>()?,
+    })))
+}
+
+/// Converts the provided `directives` into [`graphql_parser`] ones.
+fn directives_to_parser<'a, S, T>(
+    directives: &'a Option<Vec<Spanning<Directive<'a, S>>>>,
+) -> Vec<ExternalDirective<'a, T>>
+where
+    S: ScalarValue,
+    T: Text<'a>,
+{
+    directives
+        .iter()
+        .flatten()
+        .map(|d| ExternalDirective {
+            position: pos(&d.start),
+            name: d.item.name.item.into(),
+            arguments: arguments_to_parser(&d.item.arguments),
+        })
+        .collect()
+}
+
+/// Converts the provided [`graphql_parser`] `directives` into juniper's ones.
+fn directives_from_parser<'a, 'b, S, T>(
+    directives: &'a [ExternalDirective<'b, T>],
+) -> Result<Option<Vec<Spanning<Directive<'a, S>>>>, FromParserAstError>
+where
+    S: ScalarValue,
+    T: Text<'b>,
+{
+    if directives.is_empty() {
+        return Ok(None);
+    }
+    directives
+        .iter()
+        .map(|d| {
+            Ok(Spanning::unlocated(Directive {
+                name: Spanning::unlocated(d.name.as_ref()),
+                arguments: arguments_from_parser(&d.arguments)?,
+            }))
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use graphql_parser::query::{parse_query, Type as ExternalType};
+
+    use crate::{
+        ast::{Definition, InputValue, OwnedDocument, Selection, Type},
+        parser::parse_document_source,
+        schema::model::SchemaType,
+        validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
+        DefaultScalarValue,
+    };
+
+    use super::{from_parser_document, to_parser_document, FromParserAstError};
+
+    const QUERY: &str = "\
+query Hero($episode: Episode = EMPIRE, $ids: [ID!]!) @live {
+  hero(episode: $episode) {
+    ... on Droid @include(if: true) {
+      primaryFunction
+    }
+    ...HumanFields
+    friendsOf: friends(filter: {first: 3, ids: $ids, name: \"R2\"}) {
+      name
+    }
+  }
+}
+
+fragment HumanFields on Human {
+  homePlanet
+}
+";
+
+    fn parse_document(s: &str) -> OwnedDocument<DefaultScalarValue> {
+        parse_document_source(
+            s,
+            &SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn round_trips_through_parser_ast() {
+        let doc = parse_document(QUERY);
+
+        let external = to_parser_document::<_, String>(&doc);
+        assert_eq!(external.to_string(), QUERY);
+
+        let back = from_parser_document::<DefaultScalarValue, _>(&external).unwrap();
+        assert_eq!(to_parser_document::<_, String>(&back).to_string(), QUERY);
+    }
+
+    #[test]
+    fn converts_values() {
+        let external =
+            parse_query::<&str>(r#"{ a(v: [1, 1.5, "s", true, null, E, {k: $v}]) }"#).unwrap();
+
+        let doc = from_parser_document::<DefaultScalarValue, _>(&external).unwrap();
+
+        let value = match &doc[0] {
+            Definition::Operation(op) => match &op.item.selection_set[0] {
+                Selection::Field(f) => &f.item.arguments.as_ref().unwrap().item.items[0].1.item,
+                _ => panic!("expected field"),
+            },
+            _ => panic!("expected operation"),
+        };
+        assert_eq!(
+            *value,
+            InputValue::list(vec![
+                InputValue::scalar(1),
+                InputValue::scalar(1.5),
+                InputValue::scalar("s"),
+                InputValue::scalar(true),
+                InputValue::null(),
+                InputValue::enum_value("E"),
+                InputValue::object(
+                    vec![("k", InputValue::variable("v"))]
+                        .into_iter()
+                        .collect::<indexmap::IndexMap<_, _>>(),
+                ),
+            ]),
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_int() {
+        let external = parse_query::<&str>("{ a(v: 4294967296) }").unwrap();
+
+        assert!(matches!(
+            from_parser_document::<DefaultScalarValue, _>(&external),
+            Err(FromParserAstError::IntOutOfRange(_)),
+        ));
+    }
+
+    #[test]
+    fn converts_types() {
+        let ty = Type::NonNullList(Box::new(Type::NonNullNamed("ID".into())), None);
+
+        let external = ExternalType::<&str>::from(&ty);
+
+        assert_eq!(external.to_string(), "[ID!]!");
+        assert_eq!(Type::from(&external), ty);
+    }
+}
@@ -1,5 +1,7 @@
 //! Provides GraphQLType implementations for some external types
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 #[cfg(feature = "bigdecimal")]
 pub mod bigdecimal;
 #[cfg(feature = "bson")]
@@ -8,6 +10,8 @@ pub mod bson;
 pub mod chrono;
 #[cfg(feature = "chrono-tz")]
 pub mod chrono_tz;
+#[cfg(feature = "graphql-parser")]
+pub mod graphql_parser;
 #[cfg(feature = "rust_decimal")]
 pub mod rust_decimal;
 #[doc(hidden)]
@@ -18,3 +22,5 @@ pub mod time;
 pub mod url;
 #[cfg(feature = "uuid")]
 pub mod uuid;
+#[cfg(feature = "validator")]
+pub mod validator;
@@ -9,70 +9,210 @@ use serde::{
 
 use crate::{
     ast::InputValue,
-    executor::ExecutionError,
+    executor::{ExecutionError, IncrementalPayload, IncrementalResult, PathSegment},
     parser::{ParseError, SourcePosition, Spanning},
     validation::RuleError,
-    DefaultScalarValue, GraphQLError, Object, Value,
+    value::Patch,
+    GraphQLError, Object, Value,
 };
 
 impl<T: Serialize> Serialize for ExecutionError<T> {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ErrorLocations::new(self, false).serialize(ser)
+    }
+}
+
+impl<'a, T: Serialize> Serialize for ErrorLocations<'a, ExecutionError<T>> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let (err, ranges) = (self.error, self.ranges);
+
         let mut map = ser.serialize_map(Some(4))?;
 
         map.serialize_key("message")?;
-        map.serialize_value(self.error().message())?;
+        map.serialize_value(err.error().message())?;
 
-        let locations = vec![self.location()];
+        let locations = [Location::new(err.location(), None, ranges)];
         map.serialize_key("locations")?;
         map.serialize_value(&locations)?;
 
         map.serialize_key("path")?;
-        map.serialize_value(self.path())?;
+        map.serialize_value(err.path())?;
 
-        if !self.error().extensions().is_null() {
+        if !err.error().extensions().is_null() {
             map.serialize_key("extensions")?;
-            map.serialize_value(self.error().extensions())?;
+            map.serialize_value(err.error().extensions())?;
         }
 
         map.end()
     }
 }
 
+impl<'a, T: Serialize> Serialize for ErrorLocations<'a, [ExecutionError<T>]> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.collect_seq(
+            self.error
+                .iter()
+                .map(|e| ErrorLocations::new(e, self.ranges)),
+        )
+    }
+}
+
 impl Serialize for GraphQLError {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ErrorLocations::new(self, false).serialize(ser)
+    }
+}
+
+impl<'a> Serialize for ErrorLocations<'a, GraphQLError> {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
         #[derive(Serialize)]
         struct Helper {
             message: &'static str,
         }
 
-        match self {
-            Self::ParseError(e) => [e].serialize(ser),
-            Self::ValidationError(es) => es.serialize(ser),
-            Self::NoOperationProvided => [Helper {
+        match self.error {
+            GraphQLError::ParseError(e) => [ErrorLocations::new(e, self.ranges)].serialize(ser),
+            GraphQLError::ValidationError(es) => {
+                ser.collect_seq(es.iter().map(|e| ErrorLocations::new(e, self.ranges)))
+            }
+            GraphQLError::NoOperationProvided => [Helper {
                 message: "Must provide an operation",
             }]
             .serialize(ser),
-            Self::MultipleOperationsProvided => [Helper {
+            GraphQLError::MultipleOperationsProvided => [Helper {
                 message: "Must provide operation name \
                           if query contains multiple operations",
             }]
             .serialize(ser),
-            Self::UnknownOperationName => [Helper {
+            GraphQLError::UnknownOperationName => [Helper {
                 message: "Unknown operation",
             }]
             .serialize(ser),
-            Self::IsSubscription => [Helper {
+            GraphQLError::IsSubscription => [Helper {
                 message: "Expected query, got subscription",
             }]
             .serialize(ser),
-            Self::NotSubscription => [Helper {
+            GraphQLError::NotSubscription => [Helper {
                 message: "Expected subscription, got query",
             }]
             .serialize(ser),
+            GraphQLError::NotQuery => [Helper {
+                message: "Expected query, got mutation",
+            }]
+            .serialize(ser),
+            GraphQLError::ComplexityLimitExceeded { complexity, max } => {
+                #[derive(Serialize)]
+                #[serde(rename_all = "camelCase")]
+                struct Extensions {
+                    code: &'static str,
+                    complexity: usize,
+                    max_complexity: usize,
+                }
+
+                #[derive(Serialize)]
+                struct Error {
+                    message: String,
+                    extensions: Extensions,
+                }
+
+                [Error {
+                    message: self.error.to_string(),
+                    extensions: Extensions {
+                        code: "COMPLEXITY_LIMIT_EXCEEDED",
+                        complexity: *complexity,
+                        max_complexity: *max,
+                    },
+                }]
+                .serialize(ser)
+            }
         }
     }
 }
 
+impl<T: Serialize> Serialize for IncrementalPayload<T> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(None)?;
+
+        match self {
+            Self::Initial { data, errors, .. } => {
+                map.serialize_key("data")?;
+                map.serialize_value(data)?;
+
+                if !errors.is_empty() {
+                    map.serialize_key("errors")?;
+                    map.serialize_value(errors)?;
+                }
+            }
+            Self::Subsequent { incremental, .. } => {
+                map.serialize_key("incremental")?;
+                map.serialize_value(incremental)?;
+            }
+        }
+
+        map.serialize_key("hasNext")?;
+        map.serialize_value(&self.has_next())?;
+
+        map.end()
+    }
+}
+
+impl<T: Serialize> Serialize for IncrementalResult<T> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(None)?;
+
+        map.serialize_key("data")?;
+        map.serialize_value(&self.data)?;
+
+        map.serialize_key("path")?;
+        map.serialize_value(&self.path)?;
+
+        if let Some(label) = &self.label {
+            map.serialize_key("label")?;
+            map.serialize_value(label)?;
+        }
+
+        if !self.errors.is_empty() {
+            map.serialize_key("errors")?;
+            map.serialize_value(&self.errors)?;
+        }
+
+        map.end()
+    }
+}
+
+impl Serialize for PathSegment {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Field(f) => ser.serialize_str(f),
+            Self::Index(i) => ser.serialize_u64(*i as u64),
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(None)?;
+
+        let (op, value) = match self {
+            Self::Add { value, .. } => ("add", Some(value)),
+            Self::Remove { .. } => ("remove", None),
+            Self::Replace { value, .. } => ("replace", Some(value)),
+        };
+        map.serialize_key("op")?;
+        map.serialize_value(op)?;
+
+        map.serialize_key("path")?;
+        map.serialize_value(self.path())?;
+
+        if let Some(value) = value {
+            map.serialize_key("value")?;
+            map.serialize_value(value)?;
+        }
+
+        map.end()
+    }
+}
+
 impl<'de, S: Deserialize<'de>> Deserialize<'de> for InputValue<S> {
     fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
         struct Visitor<S: ?Sized>(PhantomData<S>);
@@ -214,14 +354,26 @@ impl<T: Serialize> Serialize for InputValue<T> {
 }
 
 impl Serialize for RuleError {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ErrorLocations::new(self, false).serialize(ser)
+    }
+}
+
+impl<'a> Serialize for ErrorLocations<'a, RuleError> {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
         let mut map = ser.serialize_map(Some(2))?;
 
         map.serialize_key("message")?;
-        map.serialize_value(self.message())?;
-
+        map.serialize_value(self.error.message())?;
+
+        let locations = self
+            .error
+            .locations()
+            .iter()
+            .map(|pos| Location::new(pos, None, self.ranges))
+            .collect::<Vec<_>>();
         map.serialize_key("locations")?;
-        map.serialize_value(self.locations())?;
+        map.serialize_value(&locations)?;
 
         map.end()
     }
@@ -244,18 +396,24 @@ impl Serialize for SourcePosition {
 }
 
 impl Serialize for Spanning<ParseError> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ErrorLocations::new(self, false).serialize(ser)
+    }
+}
+
+impl<'a> Serialize for ErrorLocations<'a, Spanning<ParseError>> {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
         let mut map = ser.serialize_map(Some(2))?;
 
-        let msg = self.item.to_string();
+        let msg = self.error.item.to_string();
         map.serialize_key("message")?;
         map.serialize_value(&msg)?;
 
-        let mut loc = IndexMap::new();
-        loc.insert("line".to_owned(), self.start.line() + 1);
-        loc.insert("column".to_owned(), self.start.column() + 1);
-
-        let locations = vec![loc];
+        let locations = [Location::new(
+            &self.error.start,
+            Some(&self.error.end),
+            self.ranges,
+        )];
         map.serialize_key("locations")?;
         map.serialize_value(&locations)?;
 
@@ -263,6 +421,67 @@ impl Serialize for Spanning<ParseError> {
     }
 }
 
+/// Wrapper of an error, serializing its `"locations"` either as usual, or
+/// along with the byte offsets in the input source and, if known, the end
+/// positions of the erroneous items, in case `ranges` are requested.
+///
+/// See [`GraphQLResponse::with_source_ranges()`] for details.
+///
+/// [`GraphQLResponse::with_source_ranges()`]: crate::http::GraphQLResponse::with_source_ranges
+pub(crate) struct ErrorLocations<'a, T: ?Sized> {
+    error: &'a T,
+    ranges: bool,
+}
+
+impl<'a, T: ?Sized> ErrorLocations<'a, T> {
+    /// Wraps the provided `error`, serializing the source ranges of its
+    /// locations if `ranges` is `true`.
+    pub(crate) fn new(error: &'a T, ranges: bool) -> Self {
+        Self { error, ranges }
+    }
+}
+
+/// Location of an error in the input source.
+struct Location<'a> {
+    start: &'a SourcePosition,
+    end: Option<&'a SourcePosition>,
+    ranges: bool,
+}
+
+impl<'a> Location<'a> {
+    fn new(start: &'a SourcePosition, end: Option<&'a SourcePosition>, ranges: bool) -> Self {
+        Self { start, end, ranges }
+    }
+}
+
+impl<'a> Serialize for Location<'a> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        if !self.ranges {
+            return self.start.serialize(ser);
+        }
+
+        let mut map = ser.serialize_map(Some(if self.end.is_some() { 4 } else { 3 }))?;
+
+        let line = self.start.line() + 1;
+        map.serialize_key("line")?;
+        map.serialize_value(&line)?;
+
+        let column = self.start.column() + 1;
+        map.serialize_key("column")?;
+        map.serialize_value(&column)?;
+
+        map.serialize_key("offset")?;
+        map.serialize_value(&self.start.index())?;
+
+        if let Some(end) = self.end {
+            map.serialize_key("end")?;
+            map.serialize_value(&Location::new(end, None, true))?;
+        }
+
+        map.end()
+    }
+}
+
 impl<T: Serialize> Serialize for Object<T> {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
         let mut map = ser.serialize_map(Some(self.field_count()))?;
@@ -285,80 +504,23 @@ impl<T: Serialize> Serialize for Value<T> {
     }
 }
 
-impl<'de> Deserialize<'de> for DefaultScalarValue {
-    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
-        struct Visitor;
-
-        impl<'de> de::Visitor<'de> for Visitor {
-            type Value = DefaultScalarValue;
-
-            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                f.write_str("a valid input value")
-            }
-
-            fn visit_bool<E: de::Error>(self, b: bool) -> Result<Self::Value, E> {
-                Ok(DefaultScalarValue::Boolean(b))
-            }
-
-            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Self::Value, E> {
-                if n >= i64::from(i32::MIN) && n <= i64::from(i32::MAX) {
-                    Ok(DefaultScalarValue::Int(n.try_into().unwrap()))
-                } else {
-                    // Browser's `JSON.stringify()` serializes all numbers
-                    // having no fractional part as integers (no decimal point),
-                    // so we must parse large integers as floating point,
-                    // otherwise we would error on transferring large floating
-                    // point numbers.
-                    // TODO: Use `FloatToInt` conversion once stabilized:
-                    //       https://github.com/rust-lang/rust/issues/67057
-                    Ok(DefaultScalarValue::Float(n as f64))
-                }
-            }
-
-            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Self::Value, E> {
-                if n <= u64::try_from(i32::MAX).unwrap() {
-                    self.visit_i64(n.try_into().unwrap())
-                } else {
-                    // Browser's `JSON.stringify()` serializes all numbers
-                    // having no fractional part as integers (no decimal point),
-                    // so we must parse large integers as floating point,
-                    // otherwise we would error on transferring large floating
-                    // point numbers.
-                    // TODO: Use `FloatToInt` conversion once stabilized:
-                    //       https://github.com/rust-lang/rust/issues/67057
-                    Ok(DefaultScalarValue::Float(n as f64))
-                }
-            }
-
-            fn visit_f64<E: de::Error>(self, f: f64) -> Result<Self::Value, E> {
-                Ok(DefaultScalarValue::Float(f))
-            }
-
-            fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
-                self.visit_string(s.into())
-            }
-
-            fn visit_string<E: de::Error>(self, s: String) -> Result<Self::Value, E> {
-                Ok(DefaultScalarValue::String(s))
-            }
-        }
-
-        de.deserialize_any(Visitor)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use serde_json::{from_str, to_string};
 
     use crate::{
         ast::InputValue,
-        graphql_input_value,
+        graphql_input_value, graphql_value,
+        parser::{ParseError, SourcePosition, Spanning},
+        validation::RuleError,
         value::{DefaultScalarValue, Object},
         FieldError, Value,
     };
 
-    use super::{ExecutionError, GraphQLError};
+    use super::{
+        ErrorLocations, ExecutionError, GraphQLError, IncrementalPayload, IncrementalResult, Patch,
+        PathSegment,
+    };
 
     #[test]
     fn int() {
@@ -389,6 +551,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn source_ranges() {
+        let parse_err = GraphQLError::ParseError(Spanning::start_end(
+            &SourcePosition::new(14, 1, 2),
+            &SourcePosition::new(19, 1, 7),
+            ParseError::UnexpectedToken("query".into()),
+        ));
+        assert_eq!(
+            to_string(&parse_err).unwrap(),
+            r#"[{"message":"Unexpected \"query\"","locations":[{"line":2,"column":3}]}]"#,
+        );
+        assert_eq!(
+            to_string(&ErrorLocations::new(&parse_err, true)).unwrap(),
+            r#"[{"message":"Unexpected \"query\"","locations":[{"line":2,"column":3,"offset":14,"end":{"line":2,"column":8,"offset":19}}]}]"#,
+        );
+
+        let validation_err = GraphQLError::ValidationError(vec![RuleError::new(
+            "Unknown field",
+            &[SourcePosition::new(6, 0, 4)],
+        )]);
+        assert_eq!(
+            to_string(&ErrorLocations::new(&validation_err, true)).unwrap(),
+            r#"[{"message":"Unknown field","locations":[{"line":1,"column":5,"offset":6}]}]"#,
+        );
+
+        let execution_errs = [ExecutionError::at_origin(
+            FieldError::<DefaultScalarValue>::from("foo error"),
+        )];
+        assert_eq!(
+            to_string(&ErrorLocations::new(&execution_errs[..], true)).unwrap(),
+            r#"[{"message":"foo error","locations":[{"line":1,"column":1,"offset":0}],"path":[]}]"#,
+        );
+    }
+
     #[test]
     fn error_extensions() {
         let mut obj: Object<DefaultScalarValue> = Object::with_capacity(1);
@@ -402,4 +598,51 @@ mod tests {
             r#"{"message":"foo error","locations":[{"line":1,"column":1}],"path":[],"extensions":{"foo":"bar"}}"#,
         );
     }
+
+    #[test]
+    fn incremental_payloads() {
+        assert_eq!(
+            to_string(&IncrementalPayload::<DefaultScalarValue>::Initial {
+                data: graphql_value!({"hero": {"name": "R2-D2"}}),
+                errors: vec![],
+                has_next: true,
+            })
+            .unwrap(),
+            r#"{"data":{"hero":{"name":"R2-D2"}},"hasNext":true}"#,
+        );
+        assert_eq!(
+            to_string(&IncrementalPayload::<DefaultScalarValue>::Subsequent {
+                incremental: vec![IncrementalResult {
+                    data: graphql_value!({"name": "Luke Skywalker"}),
+                    path: vec![
+                        PathSegment::Field("hero".into()),
+                        PathSegment::Field("friends".into()),
+                        PathSegment::Index(0),
+                    ],
+                    label: Some("friend".into()),
+                    errors: vec![],
+                }],
+                has_next: false,
+            })
+            .unwrap(),
+            r#"{"incremental":[{"data":{"name":"Luke Skywalker"},"path":["hero","friends",0],"label":"friend"}],"hasNext":false}"#,
+        );
+    }
+
+    #[test]
+    fn patches() {
+        assert_eq!(
+            to_string(&[
+                Patch::<DefaultScalarValue>::Replace {
+                    path: vec![PathSegment::Field("hero".into()), PathSegment::Index(1)],
+                    value: graphql_value!({"name": "Leia"}),
+                },
+                Patch::Remove {
+                    path: vec![PathSegment::Field("droid".into())],
+                },
+            ])
+            .unwrap(),
+            r#"[{"op":"replace","path":["hero",1],"value":{"name":"Leia"}},{"op":"remove","path":["droid"]}]"#,
+        );
+    }
 }
@@ -0,0 +1,79 @@
+//! Integration with [validator](https://github.com/Keats/validator) crate,
+//! allowing to use its [`ValidationErrors`] as [`FieldError`]s.
+//!
+//! Useful in combination with the `validate` attribute's argument of
+//! [`GraphQLInputObject`] derive:
+//! ```ignore
+//! #[derive(GraphQLInputObject, Validate)]
+//! #[graphql(validate = validator::Validate::validate)]
+//! struct NewUser {
+//!     #[validate(email)]
+//!     email: String,
+//! }
+//! ```
+//!
+//! [`GraphQLInputObject`]: crate::GraphQLInputObject
+
+use validator::ValidationErrors;
+
+use crate::{FieldError, IntoFieldError, Object, ScalarValue, Value};
+
+impl<S: ScalarValue> IntoFieldError<S> for ValidationErrors {
+    fn into_field_error(self) -> FieldError<S> {
+        let mut fields = Object::with_capacity(self.field_errors().len());
+        for (field, errors) in self.field_errors() {
+            let errors = errors
+                .iter()
+                .map(|e| {
+                    let code = e.code.to_string();
+                    match &e.message {
+                        Some(msg) => graphql_value!({"code": code, "message": msg.to_string()}),
+                        None => graphql_value!({ "code": code }),
+                    }
+                })
+                .collect();
+            fields.add_field(field, Value::list(errors));
+        }
+
+        FieldError::new(
+            "Validation failed",
+            graphql_value!({ "validationErrors": (Value::Object(fields)) }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use validator::{ValidationError, ValidationErrors};
+
+    use crate::{graphql_value, DefaultScalarValue, FieldError, IntoFieldError as _};
+
+    #[test]
+    fn converts_field_errors() {
+        let mut errors = ValidationErrors::new();
+        errors.add("email", ValidationError::new("email"));
+        let mut too_long = ValidationError::new("length");
+        too_long.message = Some(Cow::Borrowed("Name is too long"));
+        errors.add("name", too_long);
+
+        let err: FieldError<DefaultScalarValue> = errors.into_field_error();
+
+        assert_eq!(err.message(), "Validation failed");
+        let fields = err
+            .extensions()
+            .as_object_value()
+            .and_then(|ext| ext.get_field_value("validationErrors"))
+            .and_then(|v| v.as_object_value())
+            .unwrap();
+        assert_eq!(
+            fields.get_field_value("email"),
+            Some(&graphql_value!([{"code": "email"}])),
+        );
+        assert_eq!(
+            fields.get_field_value("name"),
+            Some(&graphql_value!([{"code": "length", "message": "Name is too long"}])),
+        );
+    }
+}
@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 // Due to `schema_introspection` test.
 #![cfg_attr(test, recursion_limit = "256")]
 #![warn(missing_docs)]
@@ -9,12 +10,16 @@
 extern crate core;
 extern crate self as juniper;
 
-use std::fmt;
-
 // These are required by the code generated via the `juniper_codegen` macros.
 #[doc(hidden)]
+pub extern crate alloc;
+#[doc(hidden)]
 pub use {async_trait::async_trait, futures, serde, static_assertions as sa};
 
+#[cfg(feature = "std")]
+use std::{fmt, sync::Arc};
+
+#[cfg(feature = "std")]
 #[doc(inline)]
 pub use futures::future::{BoxFuture, LocalBoxFuture};
 
@@ -30,35 +35,55 @@ pub use juniper_codegen::{
 #[macro_use]
 pub mod macros;
 mod ast;
+#[cfg(feature = "dataloader")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dataloader")))]
+pub mod dataloader;
+#[cfg(feature = "std")]
 pub mod executor;
+#[cfg(feature = "std")]
 mod introspection;
 pub mod parser;
+#[cfg(feature = "std")]
 pub(crate) mod schema;
+#[cfg(feature = "std")]
+pub mod testing;
+#[cfg(feature = "std")]
 mod types;
+#[cfg(feature = "std")]
 mod util;
+#[cfg(feature = "std")]
 pub mod validation;
-mod value;
+pub mod value;
 // This needs to be public until docs have support for private modules:
 // https://github.com/rust-lang/cargo/issues/1520
+#[cfg(feature = "std")]
 pub mod http;
+#[cfg(feature = "std")]
 pub mod integrations;
 
-#[cfg(all(test, not(feature = "expose-test-schema")))]
+#[cfg(all(test, feature = "std", not(feature = "expose-test-schema")))]
 mod tests;
 #[cfg(feature = "expose-test-schema")]
 pub mod tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod executor_tests;
 
 // Needs to be public because macros use it.
+#[cfg(feature = "std")]
 pub use crate::util::to_camel_case;
 
+#[cfg(feature = "std")]
 use crate::{
-    executor::{execute_validated_query, get_operation},
+    ast::OwnedDocument,
+    executor::{execute_validated_query_with_options, get_operation, RequestHooks},
     introspection::{INTROSPECTION_QUERY, INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS},
     parser::parse_document_source,
-    validation::{validate_input_values, visit_all_rules, ValidatorContext},
+    schema::document_cache::CachedDocument,
+    validation::{
+        operation_complexity, validate_input_values, visit_all_rules, visit_operation_rules,
+        OperationRules, ValidatorContext,
+    },
 };
 
 pub use crate::{
@@ -66,21 +91,46 @@ pub use crate::{
         Definition, Document, FromInputValue, InputValue, Operation, OperationType, Selection,
         ToInputValue, Type,
     },
+    parser::{ParseError, ScalarToken, Spanning},
+    value::{DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+};
+
+#[cfg(feature = "std")]
+pub use crate::{
     executor::{
-        Applies, Context, ExecutionError, ExecutionResult, Executor, FieldError, FieldResult,
-        FromContext, IntoFieldError, IntoResolvable, LookAheadArgument, LookAheadMethods,
-        LookAheadSelection, LookAheadValue, OwnedExecutor, Registry, ValuesStream, Variables,
+        runtime_timer, ApolloTracing, AppliedDirective, Applies, CancellationToken, Clock, Context,
+        Correlation, Data, DirectiveHandler, ErrorExtensions, ExecutionError, ExecutionOptions,
+        ExecutionResult, Executor, Extension, FieldError, FieldErrorBuilder, FieldInfo,
+        FieldMetrics, FieldResult, FieldStatus, FromContext, Guard, InMemoryLiveQueryStore,
+        IncrementalExecutionOutput, IncrementalPayload, IncrementalResult, Instrumentation,
+        IntoFieldError, IntoResolvable, LiveQueryStore, LiveQueryStream, LookAheadArgument,
+        LookAheadMethods, LookAheadSelection, LookAheadValue, MockClock, NullPropagation,
+        OwnedExecutor, OwnedLookAheadArgument, OwnedLookAheadSelection, PathSegment, Registry,
+        RequestInfo, ResponseExtensions, Rng, SplitMixRng, SystemClock, Timer, TraceContext,
+        ValuesStream, Variables, APOLLO_TRACING_EXTENSION,
     },
     introspection::IntrospectionFormat,
     macros::helper::subscription::{ExtractTypeFromStream, IntoFieldResult},
-    parser::{ParseError, ScalarToken, Spanning},
     schema::{
+        diff::{diff_schemas, Criticality, SchemaChange, SchemaChangeKind},
+        document_cache::DocumentCache,
+        dynamic::{
+            DynamicArgument, DynamicEnum, DynamicField, DynamicInputObject, DynamicInterface,
+            DynamicObject, DynamicRootNode, DynamicScalar, DynamicSchema, DynamicSchemaBuilder,
+            DynamicSchemaError, DynamicTypeInfo, DynamicUnion, DynamicValue, ResolverContext,
+        },
+        merge::{
+            MergedObject, MergedObjectInfo, MergedRootNode, MergedSubscription,
+            MergedSubscriptionInfo, SchemaMergeError, SchemaMerger,
+        },
         meta,
-        model::{RootNode, SchemaType},
+        model::{DirectiveLocation, DirectiveType, RootNode, SchemaError, SchemaType},
+        visitor::SchemaVisitor,
     },
     types::{
         async_await::{GraphQLTypeAsync, GraphQLValueAsync},
         base::{Arguments, GraphQLType, GraphQLValue, TypeKind},
+        context::WithContext,
         marker::{self, GraphQLInterface, GraphQLObject, GraphQLUnion},
         nullable::Nullable,
         scalars::{EmptyMutation, EmptySubscription, ID},
@@ -90,10 +140,15 @@ pub use crate::{
         },
     },
     validation::RuleError,
-    value::{DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
 };
 
+#[cfg(feature = "async-std")]
+pub use crate::executor::AsyncStdTimer;
+#[cfg(feature = "tokio")]
+pub use crate::executor::TokioTimer;
+
 /// An error that prevented query execution
+#[cfg(feature = "std")]
 #[derive(Debug, PartialEq)]
 #[allow(missing_docs)]
 pub enum GraphQLError {
@@ -104,8 +159,11 @@ pub enum GraphQLError {
     UnknownOperationName,
     IsSubscription,
     NotSubscription,
+    NotQuery,
+    ComplexityLimitExceeded { complexity: usize, max: usize },
 }
 
+#[cfg(feature = "std")]
 impl fmt::Display for GraphQLError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -121,10 +179,16 @@ impl fmt::Display for GraphQLError {
             Self::UnknownOperationName => write!(f, "Unknown operation name"),
             Self::IsSubscription => write!(f, "Operation is a subscription"),
             Self::NotSubscription => write!(f, "Operation is not a subscription"),
+            Self::NotQuery => write!(f, "Operation is not a query"),
+            Self::ComplexityLimitExceeded { complexity, max } => write!(
+                f,
+                "Operation complexity of {complexity} exceeds the maximum of {max}",
+            ),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for GraphQLError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -134,12 +198,15 @@ impl std::error::Error for GraphQLError {
             | Self::MultipleOperationsProvided
             | Self::UnknownOperationName
             | Self::IsSubscription
-            | Self::NotSubscription => None,
+            | Self::NotSubscription
+            | Self::NotQuery
+            | Self::ComplexityLimitExceeded { .. } => None,
         }
     }
 }
 
 /// Execute a query synchronously in a provided schema
+#[cfg(feature = "std")]
 pub fn execute_sync<'a, S, QueryT, MutationT, SubscriptionT>(
     document_source: &'a str,
     operation_name: Option<&str>,
@@ -153,32 +220,50 @@ where
     MutationT: GraphQLType<S, Context = QueryT::Context>,
     SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
 {
-    let document = parse_document_source(document_source, &root_node.schema)?;
-
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    let operation = get_operation(&document, operation_name)?;
-
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
+    execute_sync_with_options(
+        document_source,
+        operation_name,
+        root_node,
+        variables,
+        context,
+        root_node.execution_options(),
+    )
+}
 
-    execute_validated_query(&document, operation, root_node, variables, context)
+/// Execute a query synchronously in a provided schema with the provided
+/// [`ExecutionOptions`] instead of the schema's default ones
+#[cfg(feature = "std")]
+pub fn execute_sync_with_options<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&str>,
+    root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    options: &ExecutionOptions,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+{
+    let hooks = request_hooks(document_source, operation_name, options);
+    let res = (|| {
+        let parsed = hooks.parsed(ParsedDocument::parse(document_source, root_node))?;
+        let document = parsed.document();
+        let operation = hooks.validated(parsed.validate(root_node).and_then(|()| {
+            validate_operation_inputs(document, operation_name, root_node, variables, options)
+        }))?;
+
+        execute_validated_query_with_options(
+            document, operation, root_node, variables, context, options,
+        )
+    })();
+    hooks.end(root_node.mapped_errors(res))
 }
 
 /// Execute a query in a provided schema
+#[cfg(feature = "std")]
 pub async fn execute<'a, S, QueryT, MutationT, SubscriptionT>(
     document_source: &'a str,
     operation_name: Option<&str>,
@@ -196,33 +281,319 @@ where
     SubscriptionT::TypeInfo: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let document = parse_document_source(document_source, &root_node.schema)?;
+    execute_with_options(
+        document_source,
+        operation_name,
+        root_node,
+        variables,
+        context,
+        root_node.execution_options(),
+    )
+    .await
+}
 
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
+/// Execute a query in a provided schema with the provided [`ExecutionOptions`]
+/// instead of the schema's default ones
+#[cfg(feature = "std")]
+pub async fn execute_with_options<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    options: &ExecutionOptions,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let hooks = request_hooks(document_source, operation_name, options);
+    let res = async {
+        let parsed = hooks.parsed(ParsedDocument::parse(document_source, root_node))?;
+        let document = parsed.document();
+        let operation = hooks.validated(parsed.validate(root_node).and_then(|()| {
+            validate_operation_inputs(document, operation_name, root_node, variables, options)
+        }))?;
+
+        executor::execute_validated_query_async_with_options(
+            document, operation, root_node, variables, context, options,
+        )
+        .await
+    }
+    .await;
+    hooks.end(root_node.mapped_errors(res))
+}
 
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
+/// Execute a query in a provided schema, delivering its `@defer`red fragments
+/// incrementally
+///
+/// See [`execute_incremental_with_options()`] for details.
+#[cfg(feature = "std")]
+pub async fn execute_incremental<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&'a str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &'a QueryT::Context,
+) -> Result<IncrementalExecutionOutput<'a, S>, GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    execute_incremental_with_options(
+        document_source,
+        operation_name,
+        root_node,
+        variables,
+        context,
+        root_node.execution_options(),
+    )
+    .await
+}
+
+/// Execute a query in a provided schema with the provided [`ExecutionOptions`],
+/// delivering its `@defer`red fragments incrementally
+///
+/// The returned stream yields an [`IncrementalPayload::Initial`] one with all
+/// the data except the `@defer`red fragments first, followed by an
+/// [`IncrementalPayload::Subsequent`] one for every `@defer`red fragment.
+/// Every `@defer`red fragment is resolved only once the stream is polled for
+/// it.
+///
+/// `@defer` directives should be enabled in the schema via
+/// [`RootNode::enable_defer_directive()`] to be used in queries. They're
+/// ignored in mutations, so the mutation is resolved in a single payload.
+#[cfg(feature = "std")]
+pub async fn execute_incremental_with_options<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&'a str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &'a QueryT::Context,
+    options: &ExecutionOptions,
+) -> Result<IncrementalExecutionOutput<'a, S>, GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let hooks = request_hooks(document_source, operation_name, options);
+    let res = async {
+        let document = hooks.parsed(
+            parse_document_source(document_source, &root_node.schema).map_err(Into::into),
+        )?;
+        hooks.validated(
+            validate_operation(&document, operation_name, root_node, variables, options).map(drop),
+        )?;
+
+        executor::execute_validated_query_incremental(
+            document,
+            operation_name,
+            root_node,
+            variables.clone(),
+            context,
+            options.clone(),
+        )
+        .await
+    }
+    .await;
+    hooks.end(res)
+}
+
+/// Validates the provided `document` to be executed against the provided
+/// `root_node` with the provided `variables` and `options`, and returns its
+/// operation to be executed.
+#[cfg(feature = "std")]
+fn validate_operation<'b, 'd, S, QueryT, MutationT, SubscriptionT>(
+    document: &'b Document<'d, S>,
+    operation_name: Option<&str>,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    options: &ExecutionOptions,
+) -> Result<&'b Spanning<Operation<'d, S>>, GraphQLError>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+{
+    validate_document(document, &root_node.schema).map_err(GraphQLError::ValidationError)?;
+    validate_operation_inputs(document, operation_name, root_node, variables, options)
+}
+
+/// Validates the provided `document` against the rules of the provided
+/// `schema`.
+///
+/// The outcome depends on nothing but the `document` and the `schema`, so may
+/// be cached (see [`DocumentCache`]).
+#[cfg(feature = "std")]
+fn validate_document<S: ScalarValue>(
+    document: &Document<S>,
+    schema: &SchemaType<S>,
+) -> Result<(), Vec<RuleError>> {
+    let mut ctx = ValidatorContext::new(schema, document);
+    visit_all_rules(&mut ctx, document);
+
+    let errors = ctx.into_errors();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(())
+}
+
+/// Validates the provided `document`, already validated via
+/// [`validate_document()`], to be executed against the provided `root_node`
+/// with the provided `variables` and `options`, and returns its operation to
+/// be executed.
+#[cfg(feature = "std")]
+fn validate_operation_inputs<'b, 'd, S, QueryT, MutationT, SubscriptionT>(
+    document: &'b Document<'d, S>,
+    operation_name: Option<&str>,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    options: &ExecutionOptions,
+) -> Result<&'b Spanning<Operation<'d, S>>, GraphQLError>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+{
+    let operation = get_operation(document, operation_name)?;
+
+    let mut errors = validate_input_values(variables, operation, &root_node.schema);
+    let introspection = options
+        .get_introspection()
+        .unwrap_or(!root_node.is_introspection_disabled());
+    let mut ctx = ValidatorContext::new(&root_node.schema, document);
+    visit_operation_rules(
+        &mut ctx,
+        document,
+        operation,
+        variables,
+        OperationRules {
+            max_depth: root_node.max_depth(),
+            pagination_bounds: root_node.pagination_bounds(),
+            introspection,
+            introspection_limits: root_node.introspection_limits(),
+            operation_limits: root_node.operation_limits(),
+        },
+    );
+    errors.extend(ctx.into_errors());
+
+    if !errors.is_empty() {
+        return Err(GraphQLError::ValidationError(errors));
+    }
+
+    if let Some(max) = root_node.max_complexity() {
+        let complexity = operation_complexity(document, operation, variables, &root_node.schema);
+        if complexity > max {
+            return Err(GraphQLError::ComplexityLimitExceeded { complexity, max });
         }
     }
 
-    let operation = get_operation(&document, operation_name)?;
+    Ok(operation)
+}
+
+/// Document parsed from a source string.
+#[cfg(feature = "std")]
+enum ParsedDocument<'a, S> {
+    /// Document parsed right away.
+    Parsed(OwnedDocument<'a, S>),
+
+    /// Document taken from the [`DocumentCache`] of a [`RootNode`], along
+    /// with the outcome of its validation.
+    Cached(Arc<CachedDocument<S>>),
+}
 
+#[cfg(feature = "std")]
+impl<'a, S: ScalarValue> ParsedDocument<'a, S> {
+    /// Parses the provided `document_source`, taking it from the
+    /// [`DocumentCache`] of the provided `root_node`, if it has one.
+    fn parse<QueryT, MutationT, SubscriptionT>(
+        document_source: &'a str,
+        root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    ) -> Result<Self, GraphQLError>
+    where
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
     {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
+        match root_node.document_cache() {
+            Some(cache) => cache
+                .get_or_parse(document_source, &root_node.schema)
+                .map(Self::Cached),
+            None => parse_document_source(document_source, &root_node.schema)
+                .map(Self::Parsed)
+                .map_err(Into::into),
+        }
+    }
 
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
+    /// Returns the parsed document.
+    fn document(&self) -> &Document<'_, S> {
+        match self {
+            Self::Parsed(doc) => doc,
+            Self::Cached(cached) => cached.document(),
         }
     }
 
-    executor::execute_validated_query_async(&document, operation, root_node, variables, context)
-        .await
+    /// Validates the parsed document against the rules of the schema of the
+    /// provided `root_node`, unless the outcome is cached already.
+    fn validate<QueryT, MutationT, SubscriptionT>(
+        &self,
+        root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    ) -> Result<(), GraphQLError>
+    where
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+    {
+        match self {
+            Self::Parsed(doc) => {
+                validate_document(doc, &root_node.schema).map_err(GraphQLError::ValidationError)
+            }
+            Self::Cached(cached) => cached.validation(),
+        }
+    }
+}
+
+/// Notifies the [`Extension`]s registered in the provided `options` about the
+/// start of a request.
+#[cfg(feature = "std")]
+fn request_hooks<'a>(
+    document_source: &'a str,
+    operation_name: Option<&'a str>,
+    options: &'a ExecutionOptions,
+) -> RequestHooks<'a> {
+    RequestHooks::start(
+        options.get_extensions(),
+        RequestInfo {
+            document_source,
+            operation_name,
+            request_id: options.get_correlation().and_then(Correlation::request_id),
+        },
+    )
 }
 
 /// Resolve subscription into `ValuesStream`
+#[cfg(feature = "std")]
 pub async fn resolve_into_stream<'a, S, QueryT, MutationT, SubscriptionT>(
     document_source: &'a str,
     operation_name: Option<&str>,
@@ -242,32 +613,79 @@ where
 {
     let document: crate::ast::OwnedDocument<'a, S> =
         parse_document_source(document_source, &root_node.schema)?;
+    let operation = validate_operation(
+        &document,
+        operation_name,
+        root_node,
+        variables,
+        root_node.execution_options(),
+    )?;
+
+    let (streams, errors) = root_node.mapped_errors(
+        executor::resolve_validated_subscription(
+            &document, operation, root_node, variables, context,
+        )
+        .await,
+    )?;
+    Ok((root_node.mapped_stream_errors(streams), errors))
+}
 
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    let operation = get_operation(&document, operation_name)?;
-
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    executor::resolve_validated_subscription(&document, operation, root_node, variables, context)
+/// Resolve a query into a stream of its results, re-executing it as a live
+/// query whenever the data it depends on changes
+///
+/// Resolvers declare the data their query depends on via
+/// [`Executor::live_dependency()`], and the query is re-executed every time
+/// any of that data is invalidated in the provided [`LiveQueryStore`],
+/// yielding a new full result (unless it's the same as the last one).
+///
+/// Only queries may be executed as live ones, as re-executing a mutation
+/// would repeat its side effects.
+#[cfg(feature = "std")]
+pub async fn resolve_into_live_stream<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&'a str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &'a QueryT::Context,
+    store: &'a dyn LiveQueryStore,
+) -> Result<LiveQueryStream<'a, S>, GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let options = root_node.execution_options();
+    let hooks = request_hooks(document_source, operation_name, options);
+    let res = async {
+        let document = hooks.parsed(
+            parse_document_source(document_source, &root_node.schema).map_err(Into::into),
+        )?;
+        hooks.validated(
+            validate_operation(&document, operation_name, root_node, variables, options).map(drop),
+        )?;
+
+        executor::execute_validated_query_live(
+            document,
+            operation_name,
+            root_node,
+            variables.clone(),
+            context,
+            options.clone(),
+            store,
+        )
         .await
+    }
+    .await;
+    hooks.end(res)
 }
 
 /// Execute the reference introspection query in the provided schema
+#[cfg(feature = "std")]
 pub fn introspect<'a, S, QueryT, MutationT, SubscriptionT>(
     root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
     context: &QueryT::Context,
@@ -279,7 +697,9 @@ where
     MutationT: GraphQLType<S, Context = QueryT::Context>,
     SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
 {
-    execute_sync(
+    // Introspecting a schema on the server side is trusted, so is allowed
+    // even if the schema has introspection disabled.
+    execute_sync_with_options(
         match format {
             IntrospectionFormat::All => INTROSPECTION_QUERY,
             IntrospectionFormat::WithoutDescriptions => INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS,
@@ -288,9 +708,11 @@ where
         root_node,
         &Variables::new(),
         context,
+        &root_node.execution_options().clone().introspection(true),
     )
 }
 
+#[cfg(feature = "std")]
 impl From<Spanning<ParseError>> for GraphQLError {
     fn from(err: Spanning<ParseError>) -> Self {
         Self::ParseError(err)
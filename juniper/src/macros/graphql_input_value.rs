@@ -93,14 +93,14 @@ macro_rules! graphql_input_value {
 
     // Done with trailing comma.
     (@@array [$($elems:expr,)*]) => {
-        $crate::InputValue::list(vec![
+        $crate::InputValue::list($crate::alloc::vec![
             $( $elems, )*
         ])
     };
 
     // Done without trailing comma.
     (@@array [$($elems:expr),*]) => {
-        $crate::InputValue::list(vec![
+        $crate::InputValue::list($crate::alloc::vec![
             $( $elems, )*
         ])
     };
@@ -353,12 +353,12 @@ macro_rules! graphql_input_value {
     };
 
     ({}$(,)?) => {
-        $crate::InputValue::parsed_object(vec![])
+        $crate::InputValue::parsed_object($crate::alloc::vec![])
     };
 
     ({ $($map:tt)+ }$(,)?) => {
         $crate::InputValue::parsed_object({
-            let mut object = vec![];
+            let mut object = $crate::alloc::vec![];
             $crate::graphql_input_value!(@@object object () ($($map)*) ($($map)*));
             object
         })
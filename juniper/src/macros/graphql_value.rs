@@ -52,14 +52,14 @@ macro_rules! graphql_value {
 
     // Done with trailing comma.
     (@array [$($elems:expr,)*]) => {
-        $crate::Value::list(vec![
+        $crate::Value::list($crate::alloc::vec![
             $( $elems, )*
         ])
     };
 
     // Done without trailing comma.
     (@array [$($elems:expr),*]) => {
-        $crate::Value::list(vec![
+        $crate::Value::list($crate::alloc::vec![
             $( $crate::graphql_value!($elems), )*
         ])
     };
@@ -1,12 +1,19 @@
 //! Helper traits and definitions for macros.
 
+pub mod serde_scalar;
 pub mod subscription;
 
 use std::fmt;
 
-use futures::future::{self, BoxFuture};
+use futures::future::{self, BoxFuture, FutureExt as _};
 
-use crate::FieldError;
+use crate::{
+    executor::forbidden,
+    macros::reflect,
+    meta::{Argument, Field, MetaType},
+    Arguments, DefaultScalarValue, FieldError, GraphQLType, GraphQLValue, GraphQLValueAsync, Guard,
+    InputValue, IntoFieldError, Registry, ScalarValue,
+};
 
 /// This trait is used by [`graphql_scalar!`] macro to retrieve [`Error`] type
 /// from a [`Result`].
@@ -24,6 +31,38 @@ impl<T, E> ExtractError for Result<T, E> {
     type Error = E;
 }
 
+/// This trait is used by `#[graphql(map_err = ...)]` attribute argument to
+/// retrieve [`Ok`] type from a [`Result`].
+///
+/// [`Ok`]: Result::Ok
+pub trait ExtractOk {
+    /// Extracted [`Ok`] type of this [`Result`].
+    ///
+    /// [`Ok`]: Result::Ok
+    type Ok;
+}
+
+impl<T, E> ExtractOk for Result<T, E> {
+    type Ok = T;
+}
+
+/// [`Result`] of a resolver returning `R`, with its error converted by the
+/// `#[graphql(map_err = ...)]` attribute argument.
+pub type MapErr<R, S> = Result<<R as ExtractOk>::Ok, FieldError<S>>;
+
+/// Converts the error of the provided resolver's `res`ult with the `map_err`
+/// function of the `#[graphql(map_err = ...)]` attribute argument, and then
+/// into a [`FieldError`].
+pub fn map_err<T, E, F, S>(
+    res: Result<T, E>,
+    map_err: impl FnOnce(E) -> F,
+) -> MapErr<Result<T, E>, S>
+where
+    F: IntoFieldError<S>,
+{
+    res.map_err(|e| map_err(e).into_field_error())
+}
+
 /// Wraps `msg` with [`Display`] implementation into opaque [`Send`] [`Future`]
 /// which immediately resolves into [`FieldError`].
 pub fn err_fut<'ok, D, Ok, S>(msg: D) -> BoxFuture<'ok, Result<Ok, FieldError<S>>>
@@ -53,3 +92,215 @@ where
 {
     Box::pin(future::err(err_unnamed_type(name)))
 }
+
+/// Checks the provided `guard` of the `field_name`d field, returning the
+/// standard [`FieldError`] if it denies the access.
+pub async fn check_guard<G, CtxT, S>(
+    guard: G,
+    context: &CtxT,
+    arguments: &Arguments<'_, S>,
+    field_name: &str,
+) -> Result<(), FieldError<S>>
+where
+    G: Guard<CtxT, S>,
+    CtxT: ?Sized,
+    S: ScalarValue,
+{
+    if guard.check(context, arguments).await {
+        Ok(())
+    } else {
+        Err(forbidden(field_name))
+    }
+}
+
+/// Checks the provided `guard` of the `field_name`d field synchronously,
+/// returning the standard [`FieldError`] if it denies the access, or doesn't
+/// complete immediately.
+pub fn check_guard_sync<G, CtxT, S>(
+    guard: &G,
+    context: &CtxT,
+    arguments: &Arguments<'_, S>,
+    field_name: &str,
+) -> Result<(), FieldError<S>>
+where
+    G: Guard<CtxT, S>,
+    CtxT: ?Sized,
+    S: ScalarValue,
+{
+    match guard.check(context, arguments).now_or_never() {
+        Some(true) => Ok(()),
+        Some(false) | None => Err(forbidden(field_name)),
+    }
+}
+
+/// Checks the provided `skip_if` `predicate` of a field over the `context`,
+/// returning whether the field should be hidden at execution time.
+pub fn is_skipped<F, CtxT>(predicate: F, context: &CtxT) -> bool
+where
+    F: FnOnce(&CtxT) -> bool,
+    CtxT: ?Sized,
+{
+    predicate(context)
+}
+
+/// Upcasting of an implementer of a [GraphQL interface][1] into a trait object
+/// of [`GraphQLValue`] and [`GraphQLValueAsync`].
+///
+/// Used by `#[graphql_interface(dyn = ...)]` macro as a supertrait of the
+/// interface trait, so its trait objects may be resolved via dynamic dispatch.
+/// Implemented automatically for all [`GraphQLValueAsync`] types.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+pub trait AsDynGraphQLValue<S = DefaultScalarValue>
+where
+    S: ScalarValue + Send + Sync,
+{
+    /// [`GraphQLValue::Context`] of this implementer.
+    type Context: Sync;
+
+    /// [`GraphQLValue::TypeInfo`] of this implementer.
+    type TypeInfo: Sync;
+
+    /// Upcasts this implementer into a [`GraphQLValue`] trait object.
+    fn as_dyn_graphql_value(
+        &self,
+    ) -> &(dyn GraphQLValue<S, Context = Self::Context, TypeInfo = Self::TypeInfo> + Send + Sync);
+
+    /// Upcasts this implementer into a [`GraphQLValueAsync`] trait object.
+    fn as_dyn_graphql_value_async(
+        &self,
+    ) -> &(dyn GraphQLValueAsync<S, Context = Self::Context, TypeInfo = Self::TypeInfo> + Send);
+}
+
+impl<S, T> AsDynGraphQLValue<S> for T
+where
+    T: GraphQLValueAsync<S> + Send,
+    T::Context: Sync,
+    T::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn as_dyn_graphql_value(
+        &self,
+    ) -> &(dyn GraphQLValue<S, Context = Self::Context, TypeInfo = Self::TypeInfo> + Send + Sync)
+    {
+        self
+    }
+
+    fn as_dyn_graphql_value_async(
+        &self,
+    ) -> &(dyn GraphQLValueAsync<S, Context = Self::Context, TypeInfo = Self::TypeInfo> + Send)
+    {
+        self
+    }
+}
+
+/// Returns [`Field`]s of the [GraphQL object][1] represented by the `T` type,
+/// which should be flattened into another [GraphQL object][1].
+///
+/// # Panics
+///
+/// If the `T` type isn't a [GraphQL object][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub fn flattened_fields<'r, T, S>(
+    info: &T::TypeInfo,
+    registry: &mut Registry<'r, S>,
+) -> Vec<Field<'r, S>>
+where
+    T: GraphQLType<S> + ?Sized,
+    S: ScalarValue + 'r,
+{
+    match T::meta(info, registry) {
+        MetaType::Object(meta) => meta
+            .fields
+            .into_iter()
+            .filter(|f| !f.name.starts_with("__"))
+            .collect(),
+        _ => panic!("Only GraphQL objects can be flattened into a GraphQL object"),
+    }
+}
+
+/// Extends the provided `fields` of a [GraphQL object][1] `T` with the
+/// [inherited fields][2] of the [GraphQL interface][3] `I` it implements, which
+/// aren't present in the `fields` already.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: reflect::InheritedFields
+/// [3]: https://spec.graphql.org/October2021#sec-Interfaces
+pub fn inherit_fields<'r, I, T, S>(
+    fields: &mut Vec<Field<'r, S>>,
+    info: &I::TypeInfo,
+    registry: &mut Registry<'r, S>,
+) where
+    I: reflect::InheritedFields<S, T> + GraphQLType<S> + ?Sized,
+    T: GraphQLValue<S> + ?Sized,
+    S: ScalarValue + 'r,
+{
+    if I::NAMES.is_empty() {
+        return;
+    }
+    if let MetaType::Interface(meta) = I::meta(info, registry) {
+        for field in meta.fields {
+            if I::NAMES.contains(&field.name.as_str())
+                && !fields.iter().any(|f| f.name == field.name)
+            {
+                fields.push(field);
+            }
+        }
+    }
+}
+
+/// Returns [`Argument`]s of the [GraphQL input object][1] represented by the
+/// `T` type, which should be flattened into another [GraphQL input object][1].
+///
+/// # Panics
+///
+/// If the `T` type isn't a [GraphQL input object][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+pub fn flattened_input_fields<'r, T, S>(
+    info: &T::TypeInfo,
+    registry: &mut Registry<'r, S>,
+) -> Vec<Argument<'r, S>>
+where
+    T: GraphQLType<S> + ?Sized,
+    S: ScalarValue + 'r,
+{
+    match T::input_meta(info, registry) {
+        MetaType::InputObject(meta) => meta.input_fields,
+        _ => panic!("Only GraphQL input objects can be flattened into a GraphQL input object"),
+    }
+}
+
+/// [GraphQL input object][1] represented by the same Rust type as a
+/// [GraphQL object][2], so it's exposed via [`GraphQLType::input_name()`] and
+/// [`GraphQLType::input_meta()`] of the [GraphQL object][2].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
+pub trait SharedInputObject<S: ScalarValue> {
+    /// Name of this [GraphQL input object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+    const NAME: &'static str;
+
+    /// Returns [`MetaType`] representing this [GraphQL input object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+    fn meta<'r>(info: &(), registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r;
+}
+
+/// Moves all the fields of the `other` [`InputValue::Object`] into the `into`
+/// one.
+///
+/// Does nothing if any of the provided [`InputValue`]s is not an object.
+pub fn extend_input_object<S>(into: &mut InputValue<S>, other: InputValue<S>) {
+    if let (InputValue::Object(fields), InputValue::Object(other)) = (into, other) {
+        fields.extend(other);
+    }
+}
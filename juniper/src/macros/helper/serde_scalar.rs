@@ -0,0 +1,556 @@
+//! Resolvers of a [GraphQL scalar][0] defined with `with = serde` attribute
+//! argument, round-tripping its values through their [`Serialize`] and
+//! [`Deserialize`] implementations.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Scalars
+//! [`Deserialize`]: serde::Deserialize
+
+use std::marker::PhantomData;
+
+use serde::{
+    de::{
+        self,
+        value::{Error, MapDeserializer, SeqDeserializer},
+        DeserializeOwned, IntoDeserializer, Visitor,
+    },
+    forward_to_deserialize_any,
+    ser::{self, Serialize},
+};
+
+use crate::{
+    ast::InputValue,
+    parser::ScalarToken,
+    value::{Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+};
+
+/// Converts the provided value into a [`Value`] via its [`Serialize`]
+/// implementation.
+///
+/// # Panics
+///
+/// If the [`Serialize`] implementation of `T` fails.
+pub fn to_output<T, S>(v: &T) -> Value<S>
+where
+    T: Serialize + ?Sized,
+    S: ScalarValue,
+{
+    v.serialize(ValueSerializer(PhantomData))
+        .unwrap_or_else(|e| panic!("Failed to serialize GraphQL scalar: {e}"))
+}
+
+/// Parses the provided [`InputValue`] via the [`Deserialize`] implementation of
+/// `T`.
+///
+/// [`Deserialize`]: serde::Deserialize
+pub fn from_input<T, S>(v: &InputValue<S>) -> Result<T, String>
+where
+    T: DeserializeOwned,
+    S: ScalarValue,
+{
+    T::deserialize(InputValueDeserializer(v)).map_err(|e| e.to_string())
+}
+
+/// Parses the provided [`ScalarToken`] as a `String`, `Int` or `Float`
+/// [GraphQL scalar][0].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Scalars
+pub fn parse_token<S: ScalarValue>(token: ScalarToken<'_>) -> ParseScalarResult<S> {
+    <String as ParseScalarValue<S>>::from_str(token)
+        .or_else(|_| <i32 as ParseScalarValue<S>>::from_str(token))
+        .or_else(|_| <f64 as ParseScalarValue<S>>::from_str(token))
+}
+
+/// [`ser::Serializer`] of a [`Value`].
+struct ValueSerializer<S>(PhantomData<S>);
+
+impl<S: ScalarValue> ser::Serializer for ValueSerializer<S> {
+    type Ok = Value<S>;
+    type Error = Error;
+    type SerializeSeq = SerializeList<S>;
+    type SerializeTuple = SerializeList<S>;
+    type SerializeTupleStruct = SerializeList<S>;
+    type SerializeTupleVariant = SerializeList<S>;
+    type SerializeMap = SerializeObject<S>;
+    type SerializeStruct = SerializeObject<S>;
+    type SerializeStructVariant = SerializeObject<S>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(i32::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(i32::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value<S>, Error> {
+        Ok(i32::try_from(v).map_or_else(|_| Value::scalar(v as f64), Value::scalar))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(i32::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(i32::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value<S>, Error> {
+        Ok(i32::try_from(v).map_or_else(|_| Value::scalar(f64::from(v)), Value::scalar))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value<S>, Error> {
+        Ok(i32::try_from(v).map_or_else(|_| Value::scalar(v as f64), Value::scalar))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(f64::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value<S>, Error> {
+        Ok(Value::list(
+            v.iter().map(|b| Value::scalar(i32::from(*b))).collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Value<S>, Error> {
+        Ok(Value::null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, v: &T) -> Result<Value<S>, Error> {
+        v.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value<S>, Error> {
+        Ok(Value::null())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value<S>, Error> {
+        Ok(Value::null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Value<S>, Error> {
+        Ok(Value::scalar(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        v: &T,
+    ) -> Result<Value<S>, Error> {
+        v.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        v: &T,
+    ) -> Result<Value<S>, Error> {
+        let mut obj = Object::with_capacity(1);
+        obj.add_field(variant, v.serialize(self)?);
+        Ok(Value::object(obj))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList<S>, Error> {
+        Ok(SerializeList::new(len.unwrap_or_default(), None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList<S>, Error> {
+        Ok(SerializeList::new(len, None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<SerializeList<S>, Error> {
+        Ok(SerializeList::new(len, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeList<S>, Error> {
+        Ok(SerializeList::new(len, Some(variant)))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeObject<S>, Error> {
+        Ok(SerializeObject::new(len.unwrap_or_default(), None))
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<SerializeObject<S>, Error> {
+        Ok(SerializeObject::new(len, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeObject<S>, Error> {
+        Ok(SerializeObject::new(len, Some(variant)))
+    }
+}
+
+/// Wraps the provided [`Value`] into an object with the single `variant`
+/// field, if any.
+fn wrap_variant<S>(v: Value<S>, variant: Option<&'static str>) -> Value<S> {
+    match variant {
+        Some(variant) => {
+            let mut obj = Object::with_capacity(1);
+            obj.add_field(variant, v);
+            Value::object(obj)
+        }
+        None => v,
+    }
+}
+
+/// [`ser::SerializeSeq`] (and similar) of a list [`Value`].
+struct SerializeList<S> {
+    /// Already serialized items of the list.
+    items: Vec<Value<S>>,
+
+    /// Name of the enum variant this list represents, if any.
+    variant: Option<&'static str>,
+}
+
+impl<S> SerializeList<S> {
+    fn new(len: usize, variant: Option<&'static str>) -> Self {
+        Self {
+            items: Vec::with_capacity(len),
+            variant,
+        }
+    }
+}
+
+impl<S: ScalarValue> ser::SerializeSeq for SerializeList<S> {
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<(), Error> {
+        self.items.push(v.serialize(ValueSerializer(PhantomData))?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        Ok(wrap_variant(Value::list(self.items), self.variant))
+    }
+}
+
+impl<S: ScalarValue> ser::SerializeTuple for SerializeList<S> {
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, v)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl<S: ScalarValue> ser::SerializeTupleStruct for SerializeList<S> {
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, v)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl<S: ScalarValue> ser::SerializeTupleVariant for SerializeList<S> {
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, v)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// [`ser::SerializeMap`] (and similar) of an object [`Value`].
+struct SerializeObject<S> {
+    /// Already serialized fields of the object.
+    object: Object<S>,
+
+    /// Already serialized key of the field, which value is still to be
+    /// serialized.
+    key: Option<String>,
+
+    /// Name of the enum variant this object represents, if any.
+    variant: Option<&'static str>,
+}
+
+impl<S> SerializeObject<S> {
+    fn new(len: usize, variant: Option<&'static str>) -> Self {
+        Self {
+            object: Object::with_capacity(len),
+            key: None,
+            variant,
+        }
+    }
+}
+
+impl<S: ScalarValue> ser::SerializeMap for SerializeObject<S> {
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        let key = match key.serialize(ValueSerializer::<S>(PhantomData))? {
+            Value::Scalar(s) => s.as_str().map_or_else(|| s.to_string(), str::to_owned),
+            _ => return Err(ser::Error::custom("object keys must be scalars")),
+        };
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ser::Error::custom("object value serialized before its key"))?;
+        self.object
+            .add_field(key, v.serialize(ValueSerializer(PhantomData))?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        Ok(wrap_variant(Value::object(self.object), self.variant))
+    }
+}
+
+impl<S: ScalarValue> ser::SerializeStruct for SerializeObject<S> {
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        v: &T,
+    ) -> Result<(), Error> {
+        self.object
+            .add_field(key, v.serialize(ValueSerializer(PhantomData))?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+impl<S: ScalarValue> ser::SerializeStructVariant for SerializeObject<S> {
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        v: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeStruct::serialize_field(self, key, v)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+/// [`de::Deserializer`] of an [`InputValue`].
+struct InputValueDeserializer<'a, S>(&'a InputValue<S>);
+
+impl<'de, S: ScalarValue> IntoDeserializer<'de, Error> for InputValueDeserializer<'de, S> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de, S: ScalarValue> de::Deserializer<'de> for InputValueDeserializer<'de, S> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            InputValue::Null => visitor.visit_unit(),
+            InputValue::Scalar(s) => {
+                if let Some(b) = s.as_bool() {
+                    visitor.visit_bool(b)
+                } else if let Some(i) = s.as_int() {
+                    visitor.visit_i32(i)
+                } else if let Some(f) = s.as_float() {
+                    visitor.visit_f64(f)
+                } else if let Some(s) = s.as_str() {
+                    visitor.visit_borrowed_str(s)
+                } else {
+                    visitor.visit_string(s.to_string())
+                }
+            }
+            InputValue::Enum(e) => visitor.visit_borrowed_str(e),
+            InputValue::Variable(name) => Err(de::Error::custom(format!(
+                "unexpected unresolved variable `${name}`",
+            ))),
+            InputValue::List(items) => {
+                let mut seq =
+                    SeqDeserializer::new(items.iter().map(|i| InputValueDeserializer(&i.item)));
+                let v = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(v)
+            }
+            InputValue::Object(fields) => {
+                let mut map = MapDeserializer::new(
+                    fields
+                        .iter()
+                        .map(|(k, v)| (k.item.as_str(), InputValueDeserializer(&v.item))),
+                );
+                let v = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(v)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            InputValue::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0 {
+            InputValue::Object(fields) => match fields.as_slice() {
+                [(variant, value)] => visitor.visit_enum(EnumDeserializer {
+                    variant: variant.item.as_str(),
+                    value: Some(&value.item),
+                }),
+                _ => Err(de::Error::custom(
+                    "expected an object with a single field for an enum",
+                )),
+            },
+            InputValue::Enum(variant) => visitor.visit_enum(EnumDeserializer::<S> {
+                variant: variant.as_str(),
+                value: None,
+            }),
+            InputValue::Scalar(s) => match s.as_str() {
+                Some(variant) => visitor.visit_enum(EnumDeserializer::<S> {
+                    variant,
+                    value: None,
+                }),
+                None => Err(de::Error::custom("expected a string for an enum")),
+            },
+            _ => de::Deserializer::deserialize_any(self, visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// [`de::EnumAccess`] and [`de::VariantAccess`] of an [`InputValue`].
+struct EnumDeserializer<'a, S> {
+    /// Name of the enum variant.
+    variant: &'a str,
+
+    /// [`InputValue`] of the enum variant content, if any.
+    value: Option<&'a InputValue<S>>,
+}
+
+impl<'de, S: ScalarValue> de::EnumAccess<'de> for EnumDeserializer<'de, S> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Error> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, S: ScalarValue> de::VariantAccess<'de> for EnumDeserializer<'de, S> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self.value {
+            None | Some(InputValue::Null) => Ok(()),
+            Some(_) => Err(de::Error::custom("expected a unit enum variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        match self.value {
+            Some(v) => seed.deserialize(InputValueDeserializer(v)),
+            None => Err(de::Error::custom("expected a newtype enum variant")),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Some(v) => de::Deserializer::deserialize_any(InputValueDeserializer(v), visitor),
+            None => Err(de::Error::custom("expected a tuple enum variant")),
+        }
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Some(v) => de::Deserializer::deserialize_any(InputValueDeserializer(v), visitor),
+            None => Err(de::Error::custom("expected a struct enum variant")),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! [`merge_objects!`] macro implementation.
+//!
+//! [`merge_objects!`]: merge_objects
+
+/// Declares a [GraphQL object][1] merging the fields of several other
+/// [GraphQL objects][1].
+///
+/// Allows to split the fields of a single [GraphQL object][1] (like a large
+/// `Query`) across several `#[graphql_object]` blocks living in different
+/// modules or crates, and to merge them at schema build time.
+///
+/// Expands into a tuple struct deriving [`GraphQLObject`], with each merged
+/// type being a [flattened][2] public field of it. Any attributes (like
+/// `#[graphql(name = ..., context = ...)]` or `#[derive(Default)]`) are passed
+/// to the struct as is. The merged types should have the same [`Context`], and
+/// their fields should have different names.
+///
+/// ```rust
+/// # use juniper::{
+/// #     execute, graphql_value, graphql_vars, merge_objects, EmptyMutation, EmptySubscription,
+/// #     RootNode,
+/// # };
+/// #
+/// mod users {
+///     # use juniper::graphql_object;
+///     #
+///     #[derive(Default)]
+///     pub struct UsersQuery;
+///
+///     #[graphql_object]
+///     impl UsersQuery {
+///         fn users_count() -> i32 {
+///             2
+///         }
+///     }
+/// }
+///
+/// mod posts {
+///     # use juniper::graphql_object;
+///     #
+///     #[derive(Default)]
+///     pub struct PostsQuery;
+///
+///     #[graphql_object]
+///     impl PostsQuery {
+///         fn posts_count() -> i32 {
+///             3
+///         }
+///     }
+/// }
+///
+/// merge_objects! {
+///     /// Root of all the queries.
+///     #[derive(Default)]
+///     #[graphql(name = "Query")]
+///     pub struct Query(users::UsersQuery, posts::PostsQuery);
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let schema = RootNode::new(
+///     Query::default(),
+///     EmptyMutation::<()>::new(),
+///     EmptySubscription::<()>::new(),
+/// );
+///
+/// assert_eq!(
+///     execute("{ usersCount postsCount }", None, &schema, &graphql_vars! {}, &()).await,
+///     Ok((graphql_value!({"usersCount": 2, "postsCount": 3}), vec![])),
+/// );
+/// # }
+/// ```
+///
+/// [`Context`]: crate::Context
+/// [`GraphQLObject`]: derive@crate::GraphQLObject
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: derive@crate::GraphQLObject#flattening
+#[macro_export]
+macro_rules! merge_objects {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($($ty:ty),+ $(,)?);
+    ) => {
+        #[derive($crate::GraphQLObject)]
+        $(#[$attr])*
+        $vis struct $name($(#[graphql(flatten)] pub $ty),+);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        execute, graphql_value, graphql_vars, Context, EmptyMutation, EmptySubscription, RootNode,
+    };
+
+    pub struct Tenant(String);
+
+    impl Context for Tenant {}
+
+    mod users {
+        use crate::graphql_object;
+
+        use super::Tenant;
+
+        pub struct UsersQuery;
+
+        #[graphql_object(context = Tenant)]
+        impl UsersQuery {
+            fn user(id: i32, context: &Tenant) -> String {
+                format!("{}-user-{id}", context.0)
+            }
+        }
+    }
+
+    mod posts {
+        use crate::graphql_object;
+
+        use super::Tenant;
+
+        pub struct PostsQuery;
+
+        #[graphql_object(context = Tenant)]
+        impl PostsQuery {
+            async fn posts_count() -> i32 {
+                3
+            }
+        }
+    }
+
+    merge_objects! {
+        #[graphql(name = "Query", context = Tenant)]
+        struct Query(users::UsersQuery, posts::PostsQuery);
+    }
+
+    #[tokio::test]
+    async fn merges_fields() {
+        let schema = RootNode::new(
+            Query(users::UsersQuery, posts::PostsQuery),
+            EmptyMutation::<Tenant>::new(),
+            EmptySubscription::<Tenant>::new(),
+        );
+
+        let query = schema.schema.concrete_type_by_name("Query").unwrap();
+        assert!(query.field_by_name("user").is_some());
+        assert!(query.field_by_name("postsCount").is_some());
+        assert!(schema.schema.concrete_type_by_name("UsersQuery").is_none());
+
+        assert_eq!(
+            execute(
+                "{ user(id: 1) postsCount }",
+                None,
+                &schema,
+                &graphql_vars! {},
+                &Tenant("ctx".into()),
+            )
+            .await,
+            Ok((
+                graphql_value!({"user": "ctx-user-1", "postsCount": 3}),
+                vec![],
+            )),
+        );
+    }
+}
@@ -1,7 +1,9 @@
 //! Declarative macros and helper definitions for procedural macros.
 
+#[cfg(feature = "std")]
 #[doc(hidden)]
 pub mod helper;
+#[cfg(feature = "std")]
 #[doc(hidden)]
 #[macro_use]
 pub mod reflect;
@@ -10,5 +12,9 @@ pub mod reflect;
 mod graphql_input_value;
 #[macro_use]
 mod graphql_value;
+#[cfg(feature = "std")]
 #[macro_use]
 mod graphql_vars;
+#[cfg(feature = "std")]
+#[macro_use]
+mod merge_objects;
@@ -103,6 +103,14 @@ pub trait BaseSubTypes<S> {
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     const NAMES: Types;
+
+    /// Indicator whether there may be other sub-types than the listed in the
+    /// [`BaseSubTypes::NAMES`], being unknown at compile time (like the
+    /// implementers of a [GraphQL interface][1] resolved via dynamic dispatch,
+    /// which may be defined in other crates).
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    const OPEN: bool = false;
 }
 
 impl<'a, S, T: BaseSubTypes<S> + ?Sized> BaseSubTypes<S> for &'a T {
@@ -281,18 +289,21 @@ pub type Arguments = &'static [(Name, Type, WrappedValue)];
 /// Alias for a `const`-hashed [`Name`] used in a `const` context.
 pub type FieldName = u128;
 
-/// [GraphQL object][1] or [interface][2] [field arguments][3] [`Names`].
+/// [GraphQL object][1], [interface][2] or [input object][4]
+/// [field arguments][3] [`Names`].
 ///
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
 /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
 /// [3]: https://spec.graphql.org/October2021#sec-Language.Arguments
+/// [4]: https://spec.graphql.org/October2021#sec-Input-Objects
 pub trait Fields<S> {
-    /// [`Names`] of the [GraphQL object][1] or [interface][2]
-    /// [field arguments][3].
+    /// [`Names`] of the [GraphQL object][1], [interface][2] or
+    /// [input object][4] [field arguments][3].
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
     /// [3]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    /// [4]: https://spec.graphql.org/October2021#sec-Input-Objects
     const NAMES: Names;
 }
 
@@ -309,10 +320,11 @@ pub trait Implements<S> {
 /// Stores meta information of a [GraphQL field][1]:
 /// - [`Context`] and [`TypeInfo`].
 /// - Return type's [`TYPE`], [`SUB_TYPES`] and [`WRAPPED_VALUE`].
-/// - [`ARGUMENTS`].
+/// - [`ARGUMENTS`] and [`DEFAULTED_ARGUMENTS`].
 ///
 /// [`ARGUMENTS`]: Self::ARGUMENTS
 /// [`Context`]: Self::Context
+/// [`DEFAULTED_ARGUMENTS`]: Self::DEFAULTED_ARGUMENTS
 /// [`SUB_TYPES`]: Self::SUB_TYPES
 /// [`TYPE`]: Self::TYPE
 /// [`TypeInfo`]: Self::TypeInfo
@@ -349,6 +361,13 @@ pub trait FieldMeta<S, const N: FieldName> {
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
     const ARGUMENTS: Arguments;
+
+    /// [`Names`] of the [`ARGUMENTS`] having a [default value][1], and so not
+    /// being required even if non-nullable.
+    ///
+    /// [`ARGUMENTS`]: Self::ARGUMENTS
+    /// [1]: https://spec.graphql.org/October2021#DefaultValue
+    const DEFAULTED_ARGUMENTS: Names = &[];
 }
 
 /// Synchronous field of a [GraphQL object][1] or [interface][2].
@@ -394,6 +413,45 @@ pub trait AsyncField<S, const N: FieldName>: FieldMeta<S, N> {
     ) -> BoxFuture<'b, ExecutionResult<S>>;
 }
 
+/// Fields of a [GraphQL interface][1] having a default implementation, being
+/// inherited by its implementer `T` unless the implementer defines them on its
+/// own.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+pub trait InheritedFields<S: ScalarValue, T: GraphQLValue<S> + ?Sized> {
+    /// [`Names`] of the inherited fields.
+    const NAMES: Names;
+
+    /// Resolves the [`Value`] of the inherited `field` of the provided
+    /// implementer `obj` synchronously.
+    ///
+    /// [`Value`]: crate::Value
+    fn resolve_field(
+        obj: &T,
+        info: &T::TypeInfo,
+        field: &str,
+        args: &FieldArguments<S>,
+        executor: &Executor<T::Context, S>,
+    ) -> ExecutionResult<S>;
+}
+
+/// Asynchronous counterpart of the [`InheritedFields`].
+pub trait AsyncInheritedFields<S: ScalarValue, T: GraphQLValue<S> + ?Sized>:
+    InheritedFields<S, T>
+{
+    /// Resolves the [`Value`] of the inherited `field` of the provided
+    /// implementer `obj` asynchronously.
+    ///
+    /// [`Value`]: crate::Value
+    fn resolve_field_async<'b>(
+        obj: &'b T,
+        info: &'b T::TypeInfo,
+        field: &'b str,
+        args: &'b FieldArguments<S>,
+        executor: &'b Executor<T::Context, S>,
+    ) -> BoxFuture<'b, ExecutionResult<S>>;
+}
+
 /// Non-cryptographic hash with good dispersion to use as a [`str`](prim@str) in
 /// `const` generics. See [spec] for more info.
 ///
@@ -470,6 +528,24 @@ pub const fn str_exists_in_arr(val: &str, arr: &[&str]) -> bool {
     false
 }
 
+/// Returns the first [`str`](prim@str) occurring more than once in the given
+/// `arr`, if any.
+#[must_use]
+pub const fn str_duplicate_in_arr<'a>(arr: &[&'a str]) -> Option<&'a str> {
+    let mut i = 0;
+    while i < arr.len() {
+        let mut j = i + 1;
+        while j < arr.len() {
+            if str_eq(arr[i], arr[j]) {
+                return Some(arr[i]);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
 /// Compares strings in a `const` context.
 ///
 /// As there is no `const impl Trait` and `l == r` calls [`Eq`], we have to
@@ -504,10 +580,12 @@ macro_rules! assert_implemented_for {
     ($scalar: ty, $implementor: ty $(, $interfaces: ty)* $(,)?) => {
         const _: () = {
             $({
-                let is_present = $crate::macros::reflect::str_exists_in_arr(
-                    <$implementor as ::juniper::macros::reflect::BaseType<$scalar>>::NAME,
-                    <$interfaces as ::juniper::macros::reflect::BaseSubTypes<$scalar>>::NAMES,
-                );
+                let is_present =
+                    <$interfaces as ::juniper::macros::reflect::BaseSubTypes<$scalar>>::OPEN
+                        || $crate::macros::reflect::str_exists_in_arr(
+                            <$implementor as ::juniper::macros::reflect::BaseType<$scalar>>::NAME,
+                            <$interfaces as ::juniper::macros::reflect::BaseSubTypes<$scalar>>::NAMES,
+                        );
                 if !is_present {
                     const MSG: &str = $crate::const_concat!(
                         "Failed to implement interface `",
@@ -715,6 +793,11 @@ macro_rules! assert_field_args {
                     $scalar,
                     { $crate::checked_hash!(FIELD_NAME, $impl_ty, $scalar, ERR_PREFIX) },
                 >>::ARGUMENTS;
+            const IMPL_DEFAULTED_ARGS: ::juniper::macros::reflect::Names =
+                <$impl_ty as $crate::macros::reflect::FieldMeta<
+                    $scalar,
+                    { $crate::checked_hash!(FIELD_NAME, $impl_ty, $scalar, ERR_PREFIX) },
+                >>::DEFAULTED_ARGUMENTS;
 
             struct Error {
                 cause: Cause,
@@ -785,7 +868,15 @@ macro_rules! assert_field_args {
                     let (impl_name, impl_type, impl_wrapped_val) = IMPL_ARGS[impl_i];
                     impl_i += 1;
 
-                    if impl_wrapped_val % 10 == 2 {
+                    // Additional arguments are allowed as long as they're not
+                    // required, being either nullable or having a default
+                    // value.
+                    if impl_wrapped_val % 10 == 2
+                        || $crate::macros::reflect::str_exists_in_arr(
+                            impl_name,
+                            IMPL_DEFAULTED_ARGS,
+                        )
+                    {
                         continue;
                     }
 
@@ -849,7 +940,7 @@ macro_rules! assert_field_args {
                             IMPL_ARG_NAME,
                             "` of type `",
                             IMPL_TYPE_FORMATTED,
-                            "` isn't present on the interface and so has to be nullable."
+                            "` isn't present on the interface and so has to be nullable or have a default value."
                         )
                     }
                 };
@@ -861,6 +952,65 @@ macro_rules! assert_field_args {
     };
 }
 
+/// Asserts that all the [`Fields::NAMES`] of the `$ty` are unique, which is
+/// not ensured at macro expansion time for the fields flattened from other
+/// types.
+#[macro_export]
+macro_rules! assert_unique_fields {
+    ($ty: ty, $scalar: ty $(,)?) => {
+        const _: () = {
+            const DUPLICATE: ::std::option::Option<&str> =
+                $crate::macros::reflect::str_duplicate_in_arr(
+                    <$ty as $crate::macros::reflect::Fields<$scalar>>::NAMES,
+                );
+            if DUPLICATE.is_some() {
+                const FIELD_NAME: &str = match DUPLICATE {
+                    ::std::option::Option::Some(name) => name,
+                    ::std::option::Option::None => "",
+                };
+                const MSG: &str = $crate::const_concat!(
+                    "Field `",
+                    FIELD_NAME,
+                    "` is defined more than once on `",
+                    <$ty as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                    "`.",
+                );
+                ::std::panic!("{}", MSG);
+            }
+        };
+    };
+}
+
+/// Concatenates `const` [`Names`] in a `const` context.
+#[macro_export]
+macro_rules! const_concat_names {
+    ($($names: expr),* $(,)?) => {{
+        const LEN: usize = 0 $(+ $names.len())*;
+        const CNT: usize = {
+            let names: &[$crate::macros::reflect::Names] = &[$($names),*];
+            names.len()
+        };
+        const fn concat(
+            input: [$crate::macros::reflect::Names; CNT],
+        ) -> [$crate::macros::reflect::Name; LEN] {
+            let mut names = [""; LEN];
+            let (mut i, mut name) = (0, 0);
+            while i < CNT {
+                let mut n = 0;
+                while n < input[i].len() {
+                    names[name] = input[i][n];
+                    name += 1;
+                    n += 1;
+                }
+                i += 1;
+            }
+            names
+        }
+        const CON: [$crate::macros::reflect::Name; LEN] = concat([$($names),*]);
+        &CON
+    }};
+}
+
 /// Concatenates `const` [`str`](prim@str)s in a `const` context.
 #[macro_export]
 macro_rules! const_concat {
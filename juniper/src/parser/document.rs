@@ -31,6 +31,52 @@ where
     parse_document(&mut parser, schema)
 }
 
+/// Parses the provided `s`ource document, recovering from syntax errors.
+///
+/// Unlike [`parse_document_source()`], doesn't stop at the first error, but
+/// skips the source it fails to tokenize, and the rest of any definition it
+/// fails to parse, continuing with the next one. Returns all the successfully
+/// parsed definitions along with all the encountered errors, which is useful
+/// for tooling (like IDEs) showing complete feedback on a document.
+///
+/// The returned document is valid only if there are no errors.
+pub fn parse_document_source_recovering<'a, 'b, S>(
+    s: &'a str,
+    schema: &'b SchemaType<'b, S>,
+) -> (OwnedDocument<'a, S>, Vec<Spanning<ParseError>>)
+where
+    S: ScalarValue,
+{
+    let mut lexer = Lexer::new(s);
+    let (parser, errors) = Parser::new_recovering(&mut lexer);
+    let mut errors = errors
+        .into_iter()
+        .map(|e| e.map(ParseError::LexerError))
+        .collect::<Vec<_>>();
+
+    let start = parser.peek().start;
+    let mut defs = Vec::new();
+    for mut parser in parser.split_definitions() {
+        match parse_definition(&mut parser, schema) {
+            Ok(def) if parser.peek().item == Token::EndOfFile => defs.push(def),
+            Ok(_) => errors.push(
+                parser
+                    .next_token()
+                    .map_or_else(|e| e, |t| t.map(ParseError::unexpected_token)),
+            ),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    if defs.is_empty() && errors.is_empty() {
+        errors.push(Spanning::zero_width(
+            &start,
+            ParseError::UnexpectedEndOfFile,
+        ));
+    }
+    (defs, errors)
+}
+
 fn parse_document<'a, 'b, S>(
     parser: &mut Parser<'a>,
     schema: &'b SchemaType<'b, S>,
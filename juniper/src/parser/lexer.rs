@@ -1,4 +1,5 @@
-use std::{
+use alloc::{borrow::ToOwned as _, format, string::String};
+use core::{
     char, fmt,
     iter::{Iterator, Peekable},
     result::Result,
@@ -108,6 +109,18 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Skips the character at the provided `position`, if this [`Lexer`] is
+    /// currently at it.
+    ///
+    /// Allows to continue tokenizing after an error, without returning the
+    /// same error again.
+    #[doc(hidden)]
+    pub fn skip_char_at(&mut self, position: &SourcePosition) {
+        if !self.has_reached_eof && self.position == *position {
+            self.next_char();
+        }
+    }
+
     fn peek_char(&mut self) -> Option<(usize, char)> {
         assert!(self.position.index() <= self.length);
         assert!(!self.has_reached_eof);
@@ -122,11 +135,7 @@ impl<'a> Lexer<'a> {
         let next = self.iterator.next();
 
         if let Some((_, ch)) = next {
-            if ch == '\n' {
-                self.position.advance_line();
-            } else {
-                self.position.advance_col();
-            }
+            self.position.advance_char(ch);
         }
 
         next
@@ -544,4 +553,5 @@ impl fmt::Display for LexerError {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for LexerError {}
@@ -1,16 +1,24 @@
 //! Query parser and language utilities
 #![allow(clippy::module_inception)]
 
+#[cfg(feature = "std")]
 mod document;
 mod lexer;
 mod parser;
+#[cfg(feature = "std")]
+mod printer;
 mod utils;
+#[cfg(feature = "std")]
 mod value;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
 
-pub use self::document::parse_document_source;
+#[cfg(feature = "std")]
+pub use self::{
+    document::{parse_document_source, parse_document_source_recovering},
+    printer::{format_document, format_document_minified},
+};
 
 pub use self::{
     lexer::{Lexer, LexerError, ScalarToken, Token},
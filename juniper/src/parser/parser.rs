@@ -1,4 +1,7 @@
-use std::{error::Error, fmt, result::Result};
+use alloc::vec::Vec;
+use core::{fmt, mem, result::Result};
+#[cfg(feature = "std")]
+use std::error::Error;
 
 use smartstring::alias::String;
 
@@ -33,6 +36,7 @@ impl fmt::Display for ParseError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for ParseError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
@@ -48,7 +52,7 @@ impl ParseError {
     /// Creates a [`ParseError::UnexpectedToken`] out of the provided [`Token`].
     #[must_use]
     pub fn unexpected_token(token: Token<'_>) -> Self {
-        use std::fmt::Write as _;
+        use core::fmt::Write as _;
 
         let mut s = String::new();
         // PANIC: Unwrapping is OK here, as it may panic only on allocation
@@ -89,6 +93,88 @@ impl<'a> Parser<'a> {
         Ok(Parser { tokens })
     }
 
+    /// Creates a new [`Parser`] out of the provided [`Lexer`], skipping the
+    /// source it fails to tokenize, and returning the [`LexerError`]s
+    /// encountered.
+    #[doc(hidden)]
+    pub fn new_recovering(lexer: &mut Lexer<'a>) -> (Parser<'a>, Vec<Spanning<LexerError>>) {
+        let mut tokens = Vec::new();
+        let mut errors = Vec::new();
+
+        while let Some(res) = lexer.next() {
+            match res {
+                Ok(s) => tokens.push(s),
+                Err(e) => {
+                    lexer.skip_char_at(&e.start);
+                    errors.push(e);
+                }
+            }
+        }
+
+        (Parser { tokens }, errors)
+    }
+
+    /// Splits the remaining tokens of this [`Parser`] into separate [`Parser`]s
+    /// of each top-level definition, so a syntax error in one of them doesn't
+    /// prevent parsing the others.
+    ///
+    /// A definition ends with the curly brace closing its outermost selection
+    /// set, or with a stray closing curly brace. Closing curly braces also
+    /// close any parentheses and brackets left open inside them, so a missing
+    /// closing one doesn't swallow the following definitions.
+    #[doc(hidden)]
+    pub fn split_definitions(mut self) -> Vec<Parser<'a>> {
+        // PANIC: Unwrapping is OK here, as the `Lexer` always emits
+        //        `Token::EndOfFile` last.
+        let eof = self.tokens.pop().unwrap();
+
+        let mut parsers = Vec::new();
+        let mut tokens = Vec::new();
+        let mut open = Vec::new();
+        for token in self.tokens {
+            let ends = match token.item {
+                Token::ParenOpen | Token::BracketOpen | Token::CurlyOpen => {
+                    open.push(token.item);
+                    false
+                }
+                Token::ParenClose | Token::BracketClose => {
+                    let opening = if token.item == Token::ParenClose {
+                        Token::ParenOpen
+                    } else {
+                        Token::BracketOpen
+                    };
+                    if open.last() == Some(&opening) {
+                        open.pop();
+                    }
+                    false
+                }
+                Token::CurlyClose => {
+                    while let Some(t) = open.pop() {
+                        if t == Token::CurlyOpen {
+                            break;
+                        }
+                    }
+                    open.is_empty()
+                }
+                _ => false,
+            };
+            let end = token.end;
+            tokens.push(token);
+            if ends {
+                tokens.push(Spanning::zero_width(&end, Token::EndOfFile));
+                parsers.push(Parser {
+                    tokens: mem::take(&mut tokens),
+                });
+            }
+        }
+        if !tokens.is_empty() {
+            tokens.push(eof);
+            parsers.push(Parser { tokens });
+        }
+
+        parsers
+    }
+
     #[doc(hidden)]
     pub fn peek(&self) -> &Spanning<Token<'a>> {
         &self.tokens[0]
@@ -0,0 +1,401 @@
+use crate::{
+    ast::{
+        Arguments, Definition, Directive, Document, Fragment, InputValue, Operation, OperationType,
+        Selection, VariableDefinitions,
+    },
+    parser::Spanning,
+    value::ScalarValue,
+};
+
+/// Prints the provided executable `doc`ument back to the canonical GraphQL
+/// text.
+///
+/// Selections are put on separate lines indented by two spaces, definitions
+/// are separated by an empty line, and all the insignificant characters
+/// (comments, commas, extra whitespace) are normalized, so equivalent
+/// documents are printed identically. Useful for logging normalized
+/// operations, or building persisted operations tooling.
+///
+/// ```rust
+/// # use juniper::{
+/// #     parser::{format_document, parse_document_source},
+/// #     DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode,
+/// # };
+/// #
+/// # struct Query;
+/// #
+/// # #[juniper::graphql_object]
+/// # impl Query {
+/// #     fn hero(episode: Option<String>) -> Option<String> { episode }
+/// # }
+/// #
+/// # let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// #
+/// let doc = parse_document_source::<DefaultScalarValue>(
+///     "query Hero($ep: String = \"EMPIRE\") { hero(episode: $ep), }",
+///     &schema.schema,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     format_document(&doc),
+///     "query Hero($ep: String = \"EMPIRE\") {\n  hero(episode: $ep)\n}",
+/// );
+/// ```
+pub fn format_document<S: ScalarValue>(doc: &Document<'_, S>) -> String {
+    let mut printer = Printer::new(false);
+    printer.document(doc);
+    printer.out
+}
+
+/// Prints the provided executable `doc`ument back to GraphQL text, omitting
+/// all the insignificant whitespace.
+///
+/// See [`format_document()`] for the pretty-printed variant.
+///
+/// ```rust
+/// # use juniper::{
+/// #     parser::{format_document_minified, parse_document_source},
+/// #     DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode,
+/// # };
+/// #
+/// # struct Query;
+/// #
+/// # #[juniper::graphql_object]
+/// # impl Query {
+/// #     fn hero(episode: Option<String>) -> Option<String> { episode }
+/// # }
+/// #
+/// # let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// #
+/// let doc = parse_document_source::<DefaultScalarValue>(
+///     "query Hero($ep: String = \"EMPIRE\") { hero(episode: $ep), }",
+///     &schema.schema,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     format_document_minified(&doc),
+///     "query Hero($ep:String=\"EMPIRE\"){hero(episode:$ep)}",
+/// );
+/// ```
+pub fn format_document_minified<S: ScalarValue>(doc: &Document<'_, S>) -> String {
+    let mut printer = Printer::new(true);
+    printer.document(doc);
+    printer.out
+}
+
+/// Printer of an executable document into GraphQL text.
+struct Printer {
+    /// Indicator whether all the insignificant whitespace is omitted.
+    minified: bool,
+
+    /// Current indentation level.
+    indent: usize,
+
+    /// Printed text.
+    out: String,
+}
+
+impl Printer {
+    fn new(minified: bool) -> Self {
+        Self {
+            minified,
+            indent: 0,
+            out: String::new(),
+        }
+    }
+
+    /// Prints the provided `s`eparator, or its minified variant.
+    fn sep(&mut self, s: &str, minified: &str) {
+        self.out.push_str(if self.minified { minified } else { s });
+    }
+
+    fn document<S: ScalarValue>(&mut self, doc: &Document<'_, S>) {
+        for (i, def) in doc.iter().enumerate() {
+            if i > 0 {
+                self.sep("\n\n", " ");
+            }
+            match def {
+                Definition::Operation(op) => self.operation(&op.item),
+                Definition::Fragment(frag) => self.fragment(&frag.item),
+            }
+        }
+    }
+
+    fn operation<S: ScalarValue>(&mut self, op: &Operation<'_, S>) {
+        let is_shorthand = op.operation_type == OperationType::Query
+            && op.name.is_none()
+            && op.variable_definitions.is_none()
+            && op.directives.is_none();
+        if !is_shorthand {
+            self.out.push_str(match op.operation_type {
+                OperationType::Query => "query",
+                OperationType::Mutation => "mutation",
+                OperationType::Subscription => "subscription",
+            });
+            if let Some(name) = &op.name {
+                self.out.push(' ');
+                self.out.push_str(name.item);
+            }
+            if let Some(defs) = &op.variable_definitions {
+                self.variable_definitions(&defs.item);
+            }
+            self.directives(&op.directives);
+            self.sep(" ", "");
+        }
+        self.selection_set(&op.selection_set);
+    }
+
+    fn fragment<S: ScalarValue>(&mut self, frag: &Fragment<'_, S>) {
+        self.out.push_str("fragment ");
+        self.out.push_str(frag.name.item);
+        self.out.push_str(" on ");
+        self.out.push_str(frag.type_condition.item);
+        self.directives(&frag.directives);
+        self.sep(" ", "");
+        self.selection_set(&frag.selection_set);
+    }
+
+    fn variable_definitions<S: ScalarValue>(&mut self, defs: &VariableDefinitions<'_, S>) {
+        self.out.push('(');
+        for (i, (name, def)) in defs.iter().enumerate() {
+            if i > 0 {
+                self.sep(", ", ",");
+            }
+            self.out.push('$');
+            self.out.push_str(name.item);
+            self.sep(": ", ":");
+            self.out.push_str(&def.var_type.item.to_string());
+            if let Some(default) = &def.default_value {
+                self.sep(" = ", "=");
+                self.value(&default.item);
+            }
+            self.directives(&def.directives);
+        }
+        self.out.push(')');
+    }
+
+    fn selection_set<S: ScalarValue>(&mut self, selection_set: &[Selection<'_, S>]) {
+        self.out.push('{');
+        self.indent += 1;
+        for (i, sel) in selection_set.iter().enumerate() {
+            if self.minified {
+                if i > 0 {
+                    self.out.push(' ');
+                }
+            } else {
+                self.newline();
+            }
+            self.selection(sel);
+        }
+        self.indent -= 1;
+        if !self.minified {
+            self.newline();
+        }
+        self.out.push('}');
+    }
+
+    fn selection<S: ScalarValue>(&mut self, sel: &Selection<'_, S>) {
+        match sel {
+            Selection::Field(field) => {
+                let field = &field.item;
+                if let Some(alias) = &field.alias {
+                    self.out.push_str(alias.item);
+                    self.sep(": ", ":");
+                }
+                self.out.push_str(field.name.item);
+                self.arguments(&field.arguments);
+                self.directives(&field.directives);
+                if let Some(selection_set) = &field.selection_set {
+                    self.sep(" ", "");
+                    self.selection_set(selection_set);
+                }
+            }
+            Selection::FragmentSpread(spread) => {
+                self.out.push_str("...");
+                self.out.push_str(spread.item.name.item);
+                self.directives(&spread.item.directives);
+            }
+            Selection::InlineFragment(frag) => {
+                self.out.push_str("...");
+                if let Some(cond) = &frag.item.type_condition {
+                    self.sep(" on ", "on ");
+                    self.out.push_str(cond.item);
+                }
+                self.directives(&frag.item.directives);
+                self.sep(" ", "");
+                self.selection_set(&frag.item.selection_set);
+            }
+        }
+    }
+
+    fn arguments<S: ScalarValue>(&mut self, args: &Option<Spanning<Arguments<'_, S>>>) {
+        let args = match args {
+            Some(args) => args,
+            None => return,
+        };
+        self.out.push('(');
+        for (i, (name, value)) in args.item.iter().enumerate() {
+            if i > 0 {
+                self.sep(", ", ",");
+            }
+            self.out.push_str(name.item);
+            self.sep(": ", ":");
+            self.value(&value.item);
+        }
+        self.out.push(')');
+    }
+
+    fn directives<S: ScalarValue>(&mut self, directives: &Option<Vec<Spanning<Directive<'_, S>>>>) {
+        for directive in directives.iter().flatten() {
+            self.sep(" @", "@");
+            self.out.push_str(directive.item.name.item);
+            self.arguments(&directive.item.arguments);
+        }
+    }
+
+    fn value<S: ScalarValue>(&mut self, value: &InputValue<S>) {
+        match value {
+            InputValue::Null => self.out.push_str("null"),
+            InputValue::Scalar(s) => {
+                if let Some(i) = s.as_int() {
+                    self.out.push_str(&i.to_string());
+                } else if let Some(f) = s.as_float() {
+                    // `Debug` always prints a fractional part or an exponent,
+                    // so the value is not re-parsed as an `Int`.
+                    self.out.push_str(&format!("{f:?}"));
+                } else if let Some(b) = s.as_bool() {
+                    self.out.push_str(if b { "true" } else { "false" });
+                } else if let Some(s) = s.as_str() {
+                    self.string(s);
+                } else {
+                    self.string(&s.to_string());
+                }
+            }
+            InputValue::Enum(e) => self.out.push_str(e),
+            InputValue::Variable(v) => {
+                self.out.push('$');
+                self.out.push_str(v);
+            }
+            InputValue::List(items) => {
+                self.out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        self.sep(", ", ",");
+                    }
+                    self.value(&item.item);
+                }
+                self.out.push(']');
+            }
+            InputValue::Object(fields) => {
+                self.out.push('{');
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        self.sep(", ", ",");
+                    }
+                    self.out.push_str(&name.item);
+                    self.sep(": ", ":");
+                    self.value(&value.item);
+                }
+                self.out.push('}');
+            }
+        }
+    }
+
+    /// Prints the provided string as a quoted and escaped GraphQL string.
+    fn string(&mut self, s: &str) {
+        self.out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => self.out.push_str("\\\""),
+                '\\' => self.out.push_str("\\\\"),
+                '\n' => self.out.push_str("\\n"),
+                '\r' => self.out.push_str("\\r"),
+                '\t' => self.out.push_str("\\t"),
+                '\u{8}' => self.out.push_str("\\b"),
+                '\u{c}' => self.out.push_str("\\f"),
+                c if c.is_control() => self.out.push_str(&format!("\\u{:04X}", c as u32)),
+                c => self.out.push(c),
+            }
+        }
+        self.out.push('"');
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        for _ in 0..self.indent {
+            self.out.push_str("  ");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::parse_document_source,
+        schema::model::SchemaType,
+        validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
+        DefaultScalarValue,
+    };
+
+    use super::{format_document, format_document_minified};
+
+    const FORMATTED: &str = r#"query Q($id: ID!, $flags: [Boolean!] = [true, false] @dir) @live {
+  alias: field(id: $id, input: {name: "a \"b\"\n", count: 1, ratio: 1.0, kind: RED}) {
+    ... on Dog @include(if: true) {
+      name
+    }
+    ...Frag
+    ... {
+      nested
+    }
+  }
+}
+
+fragment Frag on Dog {
+  barks
+}
+
+{
+  other
+}"#;
+
+    fn parse(s: &str) -> String {
+        let schema = SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &());
+        let doc = parse_document_source::<DefaultScalarValue>(s, &schema).unwrap();
+        format_document(&doc)
+    }
+
+    #[test]
+    fn pretty_prints() {
+        assert_eq!(parse(FORMATTED), FORMATTED);
+        assert_eq!(
+            parse(
+                "# comment\n query Q ( $id:ID! , $flags :[Boolean!]=[true false]@dir )@live{ \
+                 alias:field(id:$id,input:{name:\"a \\\"b\\\"\\n\" count:1 ratio:1.0 kind:RED})\
+                 {...on Dog@include(if:true){name}...Frag ...{nested}}} \
+                 fragment Frag on Dog{barks},{other}",
+            ),
+            FORMATTED,
+        );
+    }
+
+    #[test]
+    fn prints_minified() {
+        let schema = SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &());
+        let doc = parse_document_source::<DefaultScalarValue>(FORMATTED, &schema).unwrap();
+
+        let minified = format_document_minified(&doc);
+
+        assert_eq!(
+            minified,
+            "query Q($id:ID!,$flags:[Boolean!]=[true,false]@dir)@live{\
+             alias:field(id:$id,input:{name:\"a \\\"b\\\"\\n\",count:1,ratio:1.0,kind:RED})\
+             {...on Dog@include(if:true){name} ...Frag ...{nested}}} \
+             fragment Frag on Dog{barks} {other}",
+        );
+        let reparsed = parse_document_source::<DefaultScalarValue>(&minified, &schema).unwrap();
+        assert_eq!(format_document(&reparsed), FORMATTED);
+    }
+}
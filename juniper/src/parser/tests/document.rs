@@ -1,7 +1,10 @@
 use crate::{
     ast::{Arguments, Definition, Field, Operation, OperationType, OwnedDocument, Selection},
     graphql_input_value,
-    parser::{document::parse_document_source, ParseError, SourcePosition, Spanning, Token},
+    parser::{
+        document::{parse_document_source, parse_document_source_recovering},
+        LexerError, ParseError, SourcePosition, Spanning, Token,
+    },
     schema::model::SchemaType,
     types::scalars::{EmptyMutation, EmptySubscription},
     validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
@@ -147,6 +150,63 @@ fn errors() {
     );
 }
 
+#[test]
+fn recovers_from_errors() {
+    let (doc, errors) = parse_document_source_recovering::<DefaultScalarValue>(
+        "{ a(x: 1 }\n{ b } %\n{ c }\nfragment F on { d }",
+        &SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &()),
+    );
+
+    assert_eq!(
+        errors,
+        vec![
+            Spanning::zero_width(
+                &SourcePosition::new(17, 1, 6),
+                ParseError::LexerError(LexerError::UnknownCharacter('%')),
+            ),
+            Spanning::start_end(
+                &SourcePosition::new(9, 0, 9),
+                &SourcePosition::new(10, 0, 10),
+                ParseError::unexpected_token(Token::CurlyClose),
+            ),
+            Spanning::start_end(
+                &SourcePosition::new(39, 3, 14),
+                &SourcePosition::new(40, 3, 15),
+                ParseError::unexpected_token(Token::CurlyOpen),
+            ),
+        ],
+    );
+    assert_eq!(
+        doc.iter()
+            .map(|def| match def {
+                Definition::Operation(op) => match &op.item.selection_set[0] {
+                    Selection::Field(f) => f.item.name.item,
+                    _ => panic!("expected field"),
+                },
+                Definition::Fragment(_) => panic!("expected operation"),
+            })
+            .collect::<Vec<_>>(),
+        ["b", "c"],
+    );
+}
+
+#[test]
+fn recovering_reports_empty_document() {
+    let (doc, errors) = parse_document_source_recovering::<DefaultScalarValue>(
+        "  ",
+        &SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &()),
+    );
+
+    assert!(doc.is_empty());
+    assert_eq!(
+        errors,
+        vec![Spanning::zero_width(
+            &SourcePosition::new(2, 0, 2),
+            ParseError::UnexpectedEndOfFile,
+        )],
+    );
+}
+
 #[test]
 fn issue_427_panic_is_not_expected() {
     struct QueryWithoutFloat;
@@ -327,12 +327,25 @@ fn string_errors() {
     assert_eq!(
         tokenize_error(r#""\uɠ^A"#),
         Spanning::zero_width(
-            &SourcePosition::new(5, 0, 5),
+            &SourcePosition::new(6, 0, 5),
             LexerError::UnterminatedString
         )
     );
 }
 
+#[test]
+fn byte_offsets() {
+    let mut lexer = Lexer::new("\"ɠ€\"\n  name");
+
+    let string = lexer.next().unwrap().unwrap();
+    assert_eq!(string.range(), 0..7);
+
+    let name = lexer.next().unwrap().unwrap();
+    assert_eq!(name.item, Token::Name("name"));
+    assert_eq!(name.start, SourcePosition::new(10, 1, 2));
+    assert_eq!(name.range(), 10..14);
+}
+
 #[test]
 fn numbers() {
     fn assert_float_token_eq(
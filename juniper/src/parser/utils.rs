@@ -1,4 +1,5 @@
-use std::fmt;
+use alloc::vec::Vec;
+use core::{fmt, ops::Range};
 
 /// A reference to a line and column in an input source file
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
@@ -90,6 +91,14 @@ impl<T> Spanning<T> {
         }
     }
 
+    /// Returns the range of bytes in the input source the spanned item
+    /// occupies.
+    ///
+    /// Index the original source with it to access the spanned item.
+    pub fn range(&self) -> Range<usize> {
+        self.start.index()..self.end.index()
+    }
+
     /// Modifies the contents of the spanned item in case `f` returns [`Some`],
     /// or returns [`None`] otherwise.
     pub fn and_then<O, F: Fn(T) -> Option<O>>(self, f: F) -> Option<Spanning<O>> {
@@ -104,6 +113,7 @@ impl<T: fmt::Display> fmt::Display for Spanning<T> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<T: std::error::Error> std::error::Error for Spanning<T> {}
 
 impl SourcePosition {
@@ -136,7 +146,17 @@ impl SourcePosition {
         self.col = 0;
     }
 
-    /// The index of the character in the input source
+    #[doc(hidden)]
+    pub fn advance_char(&mut self, ch: char) {
+        if ch == '\n' {
+            self.advance_line();
+        } else {
+            self.advance_col();
+        }
+        self.index += ch.len_utf8() - 1;
+    }
+
+    /// The byte offset of the character in the input source
     ///
     /// Zero-based index. Take a substring of the original source starting at
     /// this index to access the item pointed to by this `SourcePosition`.
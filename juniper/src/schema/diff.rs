@@ -0,0 +1,699 @@
+//! Diffing of schemas, classifying the changes by their impact on clients.
+
+use std::{collections::BTreeSet, fmt};
+
+use crate::{
+    ast::Type,
+    schema::{
+        meta::{Argument, DeprecationStatus, EnumValue, Field, MetaType},
+        model::SchemaType,
+    },
+    value::ScalarValue,
+};
+
+/// Impact of a [`SchemaChange`] on the existing clients of a schema.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Criticality {
+    /// Existing operations keep working as before.
+    Safe,
+
+    /// Existing operations keep being valid, but may behave differently (get
+    /// an enum value they don't expect, for example).
+    Dangerous,
+
+    /// Existing operations may become invalid.
+    Breaking,
+}
+
+/// Single change between two schemas, as returned by [`diff_schemas()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaChange {
+    /// Path of the changed schema element, like `Type`, `Type.field`,
+    /// `Type.field(argument)` or `Enum.VALUE`.
+    pub path: String,
+
+    /// What has been changed.
+    pub kind: SchemaChangeKind,
+}
+
+impl SchemaChange {
+    /// Returns the [`Criticality`] of this [`SchemaChange`].
+    pub fn criticality(&self) -> Criticality {
+        self.kind.criticality()
+    }
+
+    /// Indicates whether this [`SchemaChange`] may make existing operations
+    /// invalid.
+    pub fn is_breaking(&self) -> bool {
+        self.criticality() == Criticality::Breaking
+    }
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.path, self.kind)
+    }
+}
+
+/// Kind of a [`SchemaChange`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaChangeKind {
+    /// Type has been added.
+    TypeAdded,
+
+    /// Type has been removed.
+    TypeRemoved,
+
+    /// Type has become of another kind (an object becoming an interface, for
+    /// example).
+    TypeKindChanged,
+
+    /// Field of an object or an interface has been added.
+    FieldAdded,
+
+    /// Field of an object or an interface has been removed.
+    FieldRemoved,
+
+    /// Type of a field of an object or an interface has been changed.
+    FieldTypeChanged {
+        /// Old type of the field.
+        old: String,
+        /// New type of the field.
+        new: String,
+        /// Whether the new type is a subtype of the old one (being non-null
+        /// rather than nullable, for example), so the change is safe.
+        narrowed: bool,
+    },
+
+    /// Field or an enum value has been deprecated.
+    Deprecated,
+
+    /// Field or an enum value has been undeprecated.
+    Undeprecated,
+
+    /// Argument of a field has been added.
+    ArgumentAdded {
+        /// Whether the argument is non-null without a default value, so it
+        /// must be provided.
+        required: bool,
+    },
+
+    /// Argument of a field has been removed.
+    ArgumentRemoved,
+
+    /// Type of an argument of a field, or of an input object field has been
+    /// changed.
+    InputTypeChanged {
+        /// Old input type.
+        old: String,
+        /// New input type.
+        new: String,
+        /// Whether the new type accepts all the values of the old one (being
+        /// nullable rather than non-null, for example), so the change is
+        /// safe.
+        widened: bool,
+    },
+
+    /// Default value of an argument of a field, or of an input object field
+    /// has been changed.
+    DefaultValueChanged,
+
+    /// Field of an input object has been added.
+    InputFieldAdded {
+        /// Whether the field is non-null without a default value, so it must
+        /// be provided.
+        required: bool,
+    },
+
+    /// Field of an input object has been removed.
+    InputFieldRemoved,
+
+    /// Value of an enum has been added.
+    EnumValueAdded,
+
+    /// Value of an enum has been removed.
+    EnumValueRemoved,
+
+    /// Member of a union has been added.
+    UnionMemberAdded(String),
+
+    /// Member of a union has been removed.
+    UnionMemberRemoved(String),
+
+    /// Object or interface has started implementing an interface.
+    InterfaceAdded(String),
+
+    /// Object or interface has stopped implementing an interface.
+    InterfaceRemoved(String),
+}
+
+impl SchemaChangeKind {
+    /// Returns the [`Criticality`] of this [`SchemaChangeKind`].
+    pub fn criticality(&self) -> Criticality {
+        match self {
+            Self::TypeRemoved
+            | Self::TypeKindChanged
+            | Self::FieldRemoved
+            | Self::ArgumentRemoved
+            | Self::InputFieldRemoved
+            | Self::EnumValueRemoved
+            | Self::UnionMemberRemoved(_)
+            | Self::InterfaceRemoved(_)
+            | Self::ArgumentAdded { required: true }
+            | Self::InputFieldAdded { required: true }
+            | Self::FieldTypeChanged {
+                narrowed: false, ..
+            }
+            | Self::InputTypeChanged { widened: false, .. } => Criticality::Breaking,
+            Self::DefaultValueChanged
+            | Self::EnumValueAdded
+            | Self::UnionMemberAdded(_)
+            | Self::InterfaceAdded(_) => Criticality::Dangerous,
+            Self::TypeAdded
+            | Self::FieldAdded
+            | Self::Deprecated
+            | Self::Undeprecated
+            | Self::ArgumentAdded { required: false }
+            | Self::InputFieldAdded { required: false }
+            | Self::FieldTypeChanged { narrowed: true, .. }
+            | Self::InputTypeChanged { widened: true, .. } => Criticality::Safe,
+        }
+    }
+}
+
+impl fmt::Display for SchemaChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeAdded => f.write_str("type added"),
+            Self::TypeRemoved => f.write_str("type removed"),
+            Self::TypeKindChanged => f.write_str("type kind changed"),
+            Self::FieldAdded => f.write_str("field added"),
+            Self::FieldRemoved => f.write_str("field removed"),
+            Self::FieldTypeChanged { old, new, .. } | Self::InputTypeChanged { old, new, .. } => {
+                write!(f, "type changed from `{old}` to `{new}`")
+            }
+            Self::Deprecated => f.write_str("deprecated"),
+            Self::Undeprecated => f.write_str("undeprecated"),
+            Self::ArgumentAdded { required: true } => f.write_str("required argument added"),
+            Self::ArgumentAdded { required: false } => f.write_str("optional argument added"),
+            Self::ArgumentRemoved => f.write_str("argument removed"),
+            Self::DefaultValueChanged => f.write_str("default value changed"),
+            Self::InputFieldAdded { required: true } => f.write_str("required input field added"),
+            Self::InputFieldAdded { required: false } => f.write_str("optional input field added"),
+            Self::InputFieldRemoved => f.write_str("input field removed"),
+            Self::EnumValueAdded => f.write_str("enum value added"),
+            Self::EnumValueRemoved => f.write_str("enum value removed"),
+            Self::UnionMemberAdded(name) => write!(f, "union member `{name}` added"),
+            Self::UnionMemberRemoved(name) => write!(f, "union member `{name}` removed"),
+            Self::InterfaceAdded(name) => write!(f, "interface `{name}` added"),
+            Self::InterfaceRemoved(name) => write!(f, "interface `{name}` removed"),
+        }
+    }
+}
+
+/// Computes the [`SchemaChange`]s between the `old` and the `new` schemas,
+/// so a deployment of the `new` one can be gated on its compatibility with
+/// the existing clients.
+///
+/// The [`SchemaChange`]s are ordered by the names of the changed types.
+///
+/// [`RootNode::diff()`] is a shortcut for diffing the schemas of two
+/// [`RootNode`]s.
+///
+/// [`RootNode`]: crate::RootNode
+/// [`RootNode::diff()`]: crate::RootNode::diff
+///
+/// ```rust
+/// # use juniper::{Criticality, EmptyMutation, EmptySubscription, RootNode};
+/// #
+/// mod v1 {
+/// #   use juniper::graphql_object;
+///     pub struct Query;
+///
+///     #[graphql_object]
+///     impl Query {
+///         fn name() -> &'static str { "v1" }
+///         fn version() -> &'static str { "1" }
+///     }
+/// }
+///
+/// mod v2 {
+/// #   use juniper::graphql_object;
+///     pub struct Query;
+///
+///     #[graphql_object]
+///     impl Query {
+///         fn name(uppercase: bool) -> &'static str { if uppercase { "V2" } else { "v2" } }
+///     }
+/// }
+///
+/// let old = RootNode::new(v1::Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// let new = RootNode::new(v2::Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+///
+/// let changes = old.diff(&new);
+/// let breaking = changes.iter().filter(|c| c.is_breaking()).map(ToString::to_string);
+///
+/// assert_eq!(
+///     breaking.collect::<Vec<_>>(),
+///     [
+///         "`Query.name(uppercase)`: required argument added",
+///         "`Query.version`: field removed",
+///     ],
+/// );
+/// assert!(changes.iter().all(|c| c.criticality() == Criticality::Breaking));
+/// ```
+pub fn diff_schemas<S: ScalarValue>(
+    old: &SchemaType<'_, S>,
+    new: &SchemaType<'_, S>,
+) -> Vec<SchemaChange> {
+    let mut changes = Changes::default();
+
+    let names = old
+        .types
+        .values()
+        .chain(new.types.values())
+        .filter_map(MetaType::name)
+        .filter(|name| !name.starts_with("__"))
+        .collect::<BTreeSet<_>>();
+    for name in names {
+        match (
+            old.concrete_type_by_name(name),
+            new.concrete_type_by_name(name),
+        ) {
+            (Some(old), Some(new)) => changes.diff_types(name, old, new),
+            (Some(_), None) => changes.push(name, SchemaChangeKind::TypeRemoved),
+            (None, Some(_)) => changes.push(name, SchemaChangeKind::TypeAdded),
+            (None, None) => {}
+        }
+    }
+
+    changes.0
+}
+
+/// Accumulator of [`SchemaChange`]s.
+#[derive(Default)]
+struct Changes(Vec<SchemaChange>);
+
+impl Changes {
+    fn push(&mut self, path: impl Into<String>, kind: SchemaChangeKind) {
+        self.0.push(SchemaChange {
+            path: path.into(),
+            kind,
+        });
+    }
+
+    fn diff_types<S: ScalarValue>(&mut self, name: &str, old: &MetaType<S>, new: &MetaType<S>) {
+        match (old, new) {
+            (MetaType::Scalar(_), MetaType::Scalar(_)) => {}
+            (MetaType::Object(old), MetaType::Object(new)) => {
+                self.diff_interfaces(name, &old.interface_names, &new.interface_names);
+                self.diff_fields(name, &old.fields, &new.fields);
+            }
+            (MetaType::Interface(old), MetaType::Interface(new)) => {
+                self.diff_interfaces(name, &old.interface_names, &new.interface_names);
+                self.diff_fields(name, &old.fields, &new.fields);
+            }
+            (MetaType::Union(old), MetaType::Union(new)) => {
+                for member in &old.of_type_names {
+                    if !new.of_type_names.contains(member) {
+                        let kind = SchemaChangeKind::UnionMemberRemoved(member.clone());
+                        self.push(name, kind);
+                    }
+                }
+                for member in &new.of_type_names {
+                    if !old.of_type_names.contains(member) {
+                        let kind = SchemaChangeKind::UnionMemberAdded(member.clone());
+                        self.push(name, kind);
+                    }
+                }
+            }
+            (MetaType::Enum(old), MetaType::Enum(new)) => {
+                self.diff_enum_values(name, &old.values, &new.values);
+            }
+            (MetaType::InputObject(old), MetaType::InputObject(new)) => {
+                self.diff_input_fields(name, &old.input_fields, &new.input_fields);
+            }
+            _ => self.push(name, SchemaChangeKind::TypeKindChanged),
+        }
+    }
+
+    fn diff_interfaces(&mut self, name: &str, old: &[String], new: &[String]) {
+        for iface in old {
+            if !new.contains(iface) {
+                self.push(name, SchemaChangeKind::InterfaceRemoved(iface.clone()));
+            }
+        }
+        for iface in new {
+            if !old.contains(iface) {
+                self.push(name, SchemaChangeKind::InterfaceAdded(iface.clone()));
+            }
+        }
+    }
+
+    fn diff_fields<S: ScalarValue>(&mut self, name: &str, old: &[Field<S>], new: &[Field<S>]) {
+        for old_field in old.iter().filter(|f| !f.is_builtin()) {
+            let path = format!("{name}.{}", old_field.name);
+            let new_field = match new.iter().find(|f| f.name == old_field.name) {
+                Some(f) => f,
+                None => {
+                    self.push(path, SchemaChangeKind::FieldRemoved);
+                    continue;
+                }
+            };
+
+            if old_field.field_type != new_field.field_type {
+                let kind = SchemaChangeKind::FieldTypeChanged {
+                    old: old_field.field_type.to_string(),
+                    new: new_field.field_type.to_string(),
+                    narrowed: is_narrowed(&old_field.field_type, &new_field.field_type),
+                };
+                self.push(&path, kind);
+            }
+            self.diff_deprecation(
+                &path,
+                &old_field.deprecation_status,
+                &new_field.deprecation_status,
+            );
+
+            let old_args = old_field.arguments.as_deref().unwrap_or_default();
+            let new_args = new_field.arguments.as_deref().unwrap_or_default();
+            for old_arg in old_args {
+                let arg_path = format!("{path}({})", old_arg.name);
+                match new_args.iter().find(|a| a.name == old_arg.name) {
+                    Some(new_arg) => self.diff_input_values(&arg_path, old_arg, new_arg),
+                    None => self.push(arg_path, SchemaChangeKind::ArgumentRemoved),
+                }
+            }
+            for new_arg in new_args {
+                if !old_args.iter().any(|a| a.name == new_arg.name) {
+                    let kind = SchemaChangeKind::ArgumentAdded {
+                        required: is_required(new_arg),
+                    };
+                    self.push(format!("{path}({})", new_arg.name), kind);
+                }
+            }
+        }
+        for new_field in new.iter().filter(|f| !f.is_builtin()) {
+            if !old.iter().any(|f| f.name == new_field.name) {
+                let path = format!("{name}.{}", new_field.name);
+                self.push(path, SchemaChangeKind::FieldAdded);
+            }
+        }
+    }
+
+    fn diff_input_fields<S: ScalarValue>(
+        &mut self,
+        name: &str,
+        old: &[Argument<S>],
+        new: &[Argument<S>],
+    ) {
+        for old_field in old {
+            let path = format!("{name}.{}", old_field.name);
+            match new.iter().find(|f| f.name == old_field.name) {
+                Some(new_field) => self.diff_input_values(&path, old_field, new_field),
+                None => self.push(path, SchemaChangeKind::InputFieldRemoved),
+            }
+        }
+        for new_field in new {
+            if !old.iter().any(|f| f.name == new_field.name) {
+                let kind = SchemaChangeKind::InputFieldAdded {
+                    required: is_required(new_field),
+                };
+                self.push(format!("{name}.{}", new_field.name), kind);
+            }
+        }
+    }
+
+    /// Diffs the same argument or input object field of the `old` and the
+    /// `new` schemas.
+    fn diff_input_values<S: ScalarValue>(
+        &mut self,
+        path: &str,
+        old: &Argument<S>,
+        new: &Argument<S>,
+    ) {
+        if old.arg_type != new.arg_type {
+            let kind = SchemaChangeKind::InputTypeChanged {
+                old: old.arg_type.to_string(),
+                new: new.arg_type.to_string(),
+                widened: is_narrowed(&new.arg_type, &old.arg_type),
+            };
+            self.push(path, kind);
+        }
+        let default_changed = match (&old.default_value, &new.default_value) {
+            (Some(old), Some(new)) => !old.unlocated_eq(new),
+            (None, None) => false,
+            _ => true,
+        };
+        if default_changed {
+            self.push(path, SchemaChangeKind::DefaultValueChanged);
+        }
+        self.diff_deprecation(path, &old.deprecation_status, &new.deprecation_status);
+    }
+
+    fn diff_enum_values(&mut self, name: &str, old: &[EnumValue], new: &[EnumValue]) {
+        for old_value in old {
+            let path = format!("{name}.{}", old_value.name);
+            match new.iter().find(|v| v.name == old_value.name) {
+                Some(new_value) => self.diff_deprecation(
+                    &path,
+                    &old_value.deprecation_status,
+                    &new_value.deprecation_status,
+                ),
+                None => self.push(path, SchemaChangeKind::EnumValueRemoved),
+            }
+        }
+        for new_value in new {
+            if !old.iter().any(|v| v.name == new_value.name) {
+                let path = format!("{name}.{}", new_value.name);
+                self.push(path, SchemaChangeKind::EnumValueAdded);
+            }
+        }
+    }
+
+    fn diff_deprecation(&mut self, path: &str, old: &DeprecationStatus, new: &DeprecationStatus) {
+        match (old.is_deprecated(), new.is_deprecated()) {
+            (false, true) => self.push(path, SchemaChangeKind::Deprecated),
+            (true, false) => self.push(path, SchemaChangeKind::Undeprecated),
+            _ => {}
+        }
+    }
+}
+
+/// Indicates whether the provided argument or input object field must be
+/// provided.
+fn is_required<S>(arg: &Argument<S>) -> bool {
+    arg.arg_type.is_non_null() && arg.default_value.is_none()
+}
+
+/// Indicates whether the `new` type is a subtype of the `old` one, only
+/// differing in having some of its nullable types non-null.
+fn is_narrowed(old: &Type<'_>, new: &Type<'_>) -> bool {
+    match (old, new) {
+        (Type::NonNullNamed(old) | Type::Named(old), Type::NonNullNamed(new))
+        | (Type::Named(old), Type::Named(new)) => old == new,
+        (Type::NonNullList(old, _) | Type::List(old, _), Type::NonNullList(new, _))
+        | (Type::List(old, _), Type::List(new, _)) => is_narrowed(old, new),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_object, EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject, RootNode,
+    };
+
+    use super::{diff_schemas, Criticality, SchemaChange, SchemaChangeKind};
+
+    mod old {
+        use crate::{graphql_object, GraphQLEnum, GraphQLInputObject};
+
+        #[derive(GraphQLEnum)]
+        pub enum Color {
+            Red,
+            Green,
+        }
+
+        #[derive(GraphQLInputObject)]
+        pub struct Filter {
+            pub color: Option<Color>,
+            pub name: String,
+        }
+
+        pub struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn count(filter: Option<Filter>) -> Option<i32> {
+                filter.map(|_| 1)
+            }
+
+            fn colors(limit: i32) -> Vec<Color> {
+                let _ = limit;
+                vec![Color::Red]
+            }
+
+            fn removed() -> bool {
+                true
+            }
+        }
+    }
+
+    #[derive(GraphQLEnum)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Filter {
+        color: Option<Color>,
+        name: Option<String>,
+        exact: bool,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn count(filter: Option<Filter>) -> i32 {
+            i32::from(filter.is_some())
+        }
+
+        fn colors(limit: Option<i32>, offset: Option<i32>) -> Vec<Color> {
+            let _ = (limit, offset);
+            vec![Color::Blue]
+        }
+
+        #[graphql(deprecated = "Use `count`.")]
+        fn added() -> bool {
+            true
+        }
+    }
+
+    fn change(path: &str, kind: SchemaChangeKind) -> SchemaChange {
+        SchemaChange {
+            path: path.into(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn classifies_changes() {
+        let old = RootNode::new(
+            old::Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let new = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let changes = diff_schemas(&old.schema, &new.schema);
+
+        assert_eq!(
+            changes,
+            [
+                change("Color.BLUE", SchemaChangeKind::EnumValueAdded),
+                change(
+                    "Filter.name",
+                    SchemaChangeKind::InputTypeChanged {
+                        old: "String!".into(),
+                        new: "String".into(),
+                        widened: true,
+                    },
+                ),
+                change(
+                    "Filter.exact",
+                    SchemaChangeKind::InputFieldAdded { required: true },
+                ),
+                change(
+                    "Query.count",
+                    SchemaChangeKind::FieldTypeChanged {
+                        old: "Int".into(),
+                        new: "Int!".into(),
+                        narrowed: true,
+                    },
+                ),
+                change(
+                    "Query.colors(limit)",
+                    SchemaChangeKind::InputTypeChanged {
+                        old: "Int!".into(),
+                        new: "Int".into(),
+                        widened: true,
+                    },
+                ),
+                change(
+                    "Query.colors(offset)",
+                    SchemaChangeKind::ArgumentAdded { required: false },
+                ),
+                change("Query.removed", SchemaChangeKind::FieldRemoved),
+                change("Query.added", SchemaChangeKind::FieldAdded),
+            ],
+        );
+        assert_eq!(
+            changes
+                .iter()
+                .map(SchemaChange::criticality)
+                .collect::<Vec<_>>(),
+            [
+                Criticality::Dangerous,
+                Criticality::Safe,
+                Criticality::Breaking,
+                Criticality::Safe,
+                Criticality::Safe,
+                Criticality::Safe,
+                Criticality::Breaking,
+                Criticality::Safe,
+            ],
+        );
+    }
+
+    #[test]
+    fn reverse_changes_are_breaking() {
+        let old = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let new = RootNode::new(
+            old::Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let breaking = diff_schemas(&old.schema, &new.schema)
+            .into_iter()
+            .filter(SchemaChange::is_breaking)
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            breaking,
+            [
+                "`Color.BLUE`: enum value removed",
+                "`Filter.name`: type changed from `String` to `String!`",
+                "`Filter.exact`: input field removed",
+                "`Query.count`: type changed from `Int!` to `Int`",
+                "`Query.colors(limit)`: type changed from `Int` to `Int!`",
+                "`Query.colors(offset)`: argument removed",
+                "`Query.added`: field removed",
+            ],
+        );
+    }
+
+    #[test]
+    fn same_schemas_have_no_changes() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert!(diff_schemas(&schema.schema, &schema.schema).is_empty());
+    }
+}
@@ -0,0 +1,280 @@
+//! LRU cache of parsed and validated documents.
+
+use std::{
+    fmt,
+    hash::{Hash as _, Hasher as _},
+    sync::{Arc, Mutex},
+};
+
+use fnv::{FnvHashMap, FnvHasher};
+use ouroboros::self_referencing;
+
+use crate::{
+    ast::{Document, OwnedDocument},
+    parser::parse_document_source,
+    schema::model::SchemaType,
+    validate_document,
+    validation::RuleError,
+    value::ScalarValue,
+    GraphQLError,
+};
+
+/// LRU cache of the documents executed against a [`RootNode`], allowing
+/// repeated identical operations to skip their parsing and validation.
+///
+/// Documents are keyed by a hash of their source string. Only documents
+/// parsed successfully are cached, along with the outcome of their validation
+/// against the schema. Validation depending on the variables, the operation
+/// being executed or the [`ExecutionOptions`] is still performed on every
+/// execution.
+///
+/// Set up via [`RootNode::with_document_cache()`].
+///
+/// [`ExecutionOptions`]: crate::ExecutionOptions
+/// [`RootNode`]: crate::RootNode
+/// [`RootNode::with_document_cache()`]: crate::RootNode::with_document_cache
+pub struct DocumentCache<S> {
+    capacity: usize,
+    entries: Mutex<Entries<S>>,
+}
+
+impl<S> fmt::Debug for DocumentCache<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DocumentCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<S> DocumentCache<S> {
+    /// Creates a new empty [`DocumentCache`] holding at most `capacity`
+    /// documents, evicting the least recently used ones once full.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Returns the maximum number of documents held by this [`DocumentCache`].
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of documents currently held by this
+    /// [`DocumentCache`].
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().slots.len()
+    }
+
+    /// Indicates whether this [`DocumentCache`] holds no documents.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Evicts all the documents held by this [`DocumentCache`].
+    pub fn clear(&self) {
+        *self.entries.lock().unwrap() = Entries::default();
+    }
+}
+
+impl<S: ScalarValue> DocumentCache<S> {
+    /// Returns the cached document parsed from the provided `source`, parsing
+    /// and validating it against the provided `schema` on a cache miss.
+    pub(crate) fn get_or_parse(
+        &self,
+        source: &str,
+        schema: &SchemaType<S>,
+    ) -> Result<Arc<CachedDocument<S>>, GraphQLError> {
+        let key = {
+            let mut hasher = FnvHasher::default();
+            source.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        if let Some(cached) = self.entries.lock().unwrap().touch(key, source) {
+            return Ok(cached);
+        }
+
+        // Parsed outside of the lock, so concurrent misses don't wait for each
+        // other. Whichever finishes last wins the entry.
+        let cached = Arc::new(CachedDocument::parse(source.into(), schema)?);
+
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key, cached.clone(), self.capacity);
+
+        Ok(cached)
+    }
+}
+
+/// Documents held by a [`DocumentCache`], linked in a doubly linked list from
+/// the least recently used one, so both looking up and evicting them take
+/// constant time.
+struct Entries<S> {
+    /// Indices of the `nodes` holding the documents with the given keys.
+    slots: FnvHashMap<u64, usize>,
+
+    /// Nodes of the list. Never shrinks, as the slot of an evicted node is
+    /// reused for the inserted one.
+    nodes: Vec<Node<S>>,
+
+    /// Index of the least recently used node.
+    head: Option<usize>,
+
+    /// Index of the most recently used node.
+    tail: Option<usize>,
+}
+
+impl<S> Default for Entries<S> {
+    fn default() -> Self {
+        Self {
+            slots: FnvHashMap::default(),
+            nodes: Vec::new(),
+            head: None,
+            tail: None,
+        }
+    }
+}
+
+/// Node of the [`Entries`] list.
+struct Node<S> {
+    key: u64,
+    document: Arc<CachedDocument<S>>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl<S> Entries<S> {
+    /// Returns the document held for the provided `key`, marking it as the
+    /// most recently used one.
+    ///
+    /// Returns [`None`] if there is no such document, or it has been parsed
+    /// from another `source` with the same hash.
+    fn touch(&mut self, key: u64, source: &str) -> Option<Arc<CachedDocument<S>>> {
+        let idx = *self.slots.get(&key)?;
+        if self.nodes[idx].document.source() != source {
+            return None;
+        }
+
+        self.unlink(idx);
+        self.push_back(idx);
+
+        Some(self.nodes[idx].document.clone())
+    }
+
+    /// Holds the provided `document` under the provided `key` as the most
+    /// recently used one, evicting the least recently used document if there
+    /// are already `capacity` ones held.
+    fn insert(&mut self, key: u64, document: Arc<CachedDocument<S>>, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+
+        let idx = if let Some(&idx) = self.slots.get(&key) {
+            self.unlink(idx);
+            self.nodes[idx].document = document;
+            idx
+        } else if self.nodes.len() < capacity {
+            self.nodes.push(Node {
+                key,
+                document,
+                prev: None,
+                next: None,
+            });
+            self.slots.insert(key, self.nodes.len() - 1);
+            self.nodes.len() - 1
+        } else {
+            let idx = self.head.expect("full `Entries` must have a head");
+            self.unlink(idx);
+            let node = &mut self.nodes[idx];
+            self.slots.remove(&node.key);
+            node.key = key;
+            node.document = document;
+            self.slots.insert(key, idx);
+            idx
+        };
+
+        self.push_back(idx);
+    }
+
+    /// Detaches the node at the provided `idx` from the list.
+    fn unlink(&mut self, idx: usize) {
+        let (prev, next) = (self.nodes[idx].prev.take(), self.nodes[idx].next.take());
+        match prev {
+            Some(prev) => self.nodes[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    /// Attaches the detached node at the provided `idx` to the end of the list,
+    /// as the most recently used one.
+    fn push_back(&mut self, idx: usize) {
+        self.nodes[idx].prev = self.tail;
+        match self.tail {
+            Some(tail) => self.nodes[tail].next = Some(idx),
+            None => self.head = Some(idx),
+        }
+        self.tail = Some(idx);
+    }
+}
+
+/// Document held by a [`DocumentCache`] along with the source it's parsed
+/// from.
+pub(crate) struct CachedDocument<S> {
+    /// Document along with the source it borrows.
+    parsed: ParsedSource<S>,
+
+    /// Outcome of validating the document against the schema.
+    validation: Result<(), Vec<RuleError>>,
+}
+
+/// Source string owning the document parsed from it.
+#[self_referencing]
+struct ParsedSource<S> {
+    source: Arc<str>,
+
+    #[borrows(source)]
+    #[covariant]
+    document: OwnedDocument<'this, S>,
+}
+
+impl<S: ScalarValue> CachedDocument<S> {
+    /// Parses the provided `source` and validates it against the provided
+    /// `schema`.
+    fn parse(source: Arc<str>, schema: &SchemaType<S>) -> Result<Self, GraphQLError> {
+        let parsed = ParsedSource::try_new(source, |source| {
+            parse_document_source(source, schema).map_err(GraphQLError::from)
+        })?;
+        let validation = parsed.with_document(|document| validate_document(document, schema));
+
+        Ok(Self { parsed, validation })
+    }
+}
+
+impl<S> CachedDocument<S> {
+    /// Returns the cached document.
+    pub(crate) fn document(&self) -> &Document<'_, S> {
+        self.parsed.borrow_document()
+    }
+
+    /// Returns the source string the cached document is parsed from.
+    fn source(&self) -> &str {
+        self.parsed.borrow_source()
+    }
+
+    /// Returns the outcome of validating the cached document against the
+    /// schema.
+    pub(crate) fn validation(&self) -> Result<(), GraphQLError> {
+        self.validation
+            .clone()
+            .map_err(GraphQLError::ValidationError)
+    }
+}
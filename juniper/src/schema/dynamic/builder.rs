@@ -0,0 +1,620 @@
+//! Building a [`DynamicSchema`] programmatically.
+
+use std::{borrow::Cow, fmt, sync::Arc};
+
+use fnv::FnvHashMap;
+use indexmap::{map::Entry, IndexMap};
+
+use crate::{
+    ast::InputValue,
+    executor::FieldResult,
+    schema::meta::EnumValue,
+    types::base::Arguments,
+    value::{DefaultScalarValue, ScalarValue, Value},
+};
+
+use super::{
+    ArgumentDef, DynamicRootNode, DynamicSchema, DynamicSchemaError, EnumDef, FieldDef,
+    InputObjectDef, ObjectDef, Resolver, ResolverContext, ScalarDef, TypeDef, TypeRef, UnionDef,
+};
+
+/// Builder of a [`DynamicSchema`], registering its types at runtime.
+///
+/// Serves the cases where the schema is known only at runtime, but isn't
+/// written in the schema language: plugin systems, or schemas derived from a
+/// database structure, for example.
+///
+/// Types are referred to with the schema language notation (like
+/// `[String!]!`).
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_value, graphql_vars, DynamicArgument, DynamicField, DynamicObject,
+/// #     DynamicSchemaBuilder,
+/// # };
+/// #
+/// struct Database;
+///
+/// let schema = DynamicSchemaBuilder::<Database>::new("Query")
+///     .object(
+///         DynamicObject::new("Query")
+///             .field(DynamicField::new("user", "User").argument(DynamicArgument::new("id", "ID!")))
+///             .resolver("user", |_, args| {
+///                 let id = args.get::<String>("id")?.unwrap_or_default();
+///                 Ok(graphql_value!({"id": id, "name": "John"}))
+///             }),
+///     )
+///     .object(
+///         DynamicObject::new("User")
+///             .field(DynamicField::new("id", "ID!"))
+///             .field(DynamicField::new("name", "String!")),
+///     )
+///     .into_root_node()
+///     .unwrap();
+///
+/// let res = juniper::execute_sync(
+///     r#"{ user(id: "1") { name } }"#,
+///     None,
+///     &schema,
+///     &graphql_vars! {},
+///     &Database,
+/// );
+///
+/// assert_eq!(res, Ok((graphql_value!({"user": {"name": "John"}}), vec![])));
+/// ```
+pub struct DynamicSchemaBuilder<C, S = DefaultScalarValue> {
+    schema: DynamicSchema<C, S>,
+    error: Option<DynamicSchemaError>,
+}
+
+impl<C, S> DynamicSchemaBuilder<C, S>
+where
+    S: ScalarValue,
+{
+    /// Creates a new [`DynamicSchemaBuilder`] of a schema with the `query`
+    /// named root query type.
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            schema: DynamicSchema {
+                types: IndexMap::new(),
+                query: query.into(),
+                mutation: None,
+                resolvers: FnvHashMap::default(),
+            },
+            error: None,
+        }
+    }
+
+    /// Sets the `name`d object type as the root mutation type of the built
+    /// schema.
+    #[must_use]
+    pub fn mutation(mut self, name: impl Into<String>) -> Self {
+        self.schema.mutation = Some(name.into());
+        self
+    }
+
+    /// Registers the provided [`DynamicScalar`] type.
+    #[must_use]
+    pub fn scalar(self, scalar: DynamicScalar) -> Self {
+        self.register(TypeDef::Scalar(scalar.def))
+    }
+
+    /// Registers the provided [`DynamicEnum`] type.
+    #[must_use]
+    pub fn enum_type(self, enum_type: DynamicEnum) -> Self {
+        self.register(TypeDef::Enum(enum_type.def))
+    }
+
+    /// Registers the provided [`DynamicObject`] type, along with the
+    /// resolvers of its fields.
+    #[must_use]
+    pub fn object(mut self, object: DynamicObject<C, S>) -> Self {
+        if !object.resolvers.is_empty() {
+            self.schema
+                .resolvers
+                .entry(object.def.name.clone())
+                .or_default()
+                .extend(object.resolvers);
+        }
+        self.register(TypeDef::Object(object.def))
+    }
+
+    /// Registers the provided [`DynamicInterface`] type.
+    #[must_use]
+    pub fn interface(self, interface: DynamicInterface<S>) -> Self {
+        self.register(TypeDef::Interface(interface.def))
+    }
+
+    /// Registers the provided [`DynamicUnion`] type.
+    #[must_use]
+    pub fn union(self, union: DynamicUnion) -> Self {
+        self.register(TypeDef::Union(union.def))
+    }
+
+    /// Registers the provided [`DynamicInputObject`] type.
+    #[must_use]
+    pub fn input_object(self, input_object: DynamicInputObject<S>) -> Self {
+        self.register(TypeDef::InputObject(input_object.def))
+    }
+
+    /// Builds the [`DynamicSchema`], which may have more resolvers registered
+    /// via [`DynamicSchema::resolver()`].
+    ///
+    /// # Errors
+    ///
+    /// If a type is registered more than once.
+    pub fn build(self) -> Result<DynamicSchema<C, S>, DynamicSchemaError> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.schema),
+        }
+    }
+
+    /// Builds a [`DynamicRootNode`] executing operations against the built
+    /// [`DynamicSchema`].
+    ///
+    /// # Errors
+    ///
+    /// If [`DynamicSchemaBuilder::build()`] or
+    /// [`DynamicSchema::into_root_node()`] fails.
+    pub fn into_root_node(self) -> Result<DynamicRootNode<C, S>, DynamicSchemaError> {
+        self.build()?.into_root_node()
+    }
+
+    /// Registers the provided type definition, remembering the first
+    /// duplicate one.
+    fn register(mut self, def: TypeDef<S>) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+        match self.schema.types.entry(def.name().into()) {
+            Entry::Occupied(e) => {
+                self.error = Some(DynamicSchemaError::DuplicateType(e.key().clone()));
+            }
+            Entry::Vacant(e) => {
+                e.insert(def);
+            }
+        }
+        self
+    }
+}
+
+/// Parses the provided type reference, keeping it as a type name if it's
+/// malformed, so it's reported by [`DynamicSchema::into_root_node()`].
+fn type_ref(ty: &str) -> TypeRef {
+    TypeRef::parse(ty).unwrap_or_else(|| TypeRef::Named(ty.into()))
+}
+
+/// Scalar type of a [`DynamicSchemaBuilder`].
+///
+/// Its values are passed through as they are.
+#[derive(Debug)]
+pub struct DynamicScalar {
+    def: ScalarDef,
+}
+
+impl DynamicScalar {
+    /// Creates a new `name`d [`DynamicScalar`].
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            def: ScalarDef {
+                name: name.into(),
+                description: None,
+                specified_by_url: None,
+            },
+        }
+    }
+
+    /// Sets the `description` of this [`DynamicScalar`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.def.description = Some(description.into());
+        self
+    }
+
+    /// Sets the [specification URL][0] of this [`DynamicScalar`].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec--specifiedBy
+    #[must_use]
+    pub fn specified_by_url(mut self, url: impl Into<Cow<'static, str>>) -> Self {
+        self.def.specified_by_url = Some(url.into());
+        self
+    }
+}
+
+/// Enum type of a [`DynamicSchemaBuilder`].
+#[derive(Debug)]
+pub struct DynamicEnum {
+    def: EnumDef,
+}
+
+impl DynamicEnum {
+    /// Creates a new `name`d [`DynamicEnum`] without values.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            def: EnumDef {
+                name: name.into(),
+                description: None,
+                values: Vec::new(),
+            },
+        }
+    }
+
+    /// Sets the `description` of this [`DynamicEnum`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.def.description = Some(description.into());
+        self
+    }
+
+    /// Adds the provided `value` to this [`DynamicEnum`].
+    #[must_use]
+    pub fn value(mut self, value: EnumValue) -> Self {
+        self.def.values.push(value);
+        self
+    }
+}
+
+/// Object type of a [`DynamicSchemaBuilder`], along with the resolvers of its
+/// fields.
+pub struct DynamicObject<C, S = DefaultScalarValue> {
+    def: ObjectDef<S>,
+    resolvers: FnvHashMap<String, Resolver<C, S>>,
+}
+
+impl<C, S: fmt::Debug> fmt::Debug for DynamicObject<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicObject")
+            .field("def", &self.def)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C, S> DynamicObject<C, S>
+where
+    S: ScalarValue,
+{
+    /// Creates a new `name`d [`DynamicObject`] without fields.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            def: ObjectDef {
+                name: name.into(),
+                description: None,
+                interfaces: Vec::new(),
+                fields: Vec::new(),
+            },
+            resolvers: FnvHashMap::default(),
+        }
+    }
+
+    /// Sets the `description` of this [`DynamicObject`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.def.description = Some(description.into());
+        self
+    }
+
+    /// Makes this [`DynamicObject`] implement the `name`d interface.
+    #[must_use]
+    pub fn implements(mut self, name: impl Into<String>) -> Self {
+        self.def.interfaces.push(name.into());
+        self
+    }
+
+    /// Adds the provided `field` to this [`DynamicObject`].
+    #[must_use]
+    pub fn field(mut self, field: DynamicField<S>) -> Self {
+        self.def.fields.push(field.def);
+        self
+    }
+
+    /// Registers the provided `resolver` function for the `field_name`d field
+    /// of this [`DynamicObject`].
+    ///
+    /// Fields without a registered resolver are resolved as described in the
+    /// [`DynamicSchema`] docs.
+    #[must_use]
+    pub fn resolver<F>(mut self, field_name: impl Into<String>, resolver: F) -> Self
+    where
+        F: Fn(&ResolverContext<'_, C, S>, &Arguments<S>) -> FieldResult<Value<S>, S>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resolvers.insert(field_name.into(), Arc::new(resolver));
+        self
+    }
+}
+
+/// Interface type of a [`DynamicSchemaBuilder`].
+#[derive(Debug)]
+pub struct DynamicInterface<S = DefaultScalarValue> {
+    def: ObjectDef<S>,
+}
+
+impl<S> DynamicInterface<S> {
+    /// Creates a new `name`d [`DynamicInterface`] without fields.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            def: ObjectDef {
+                name: name.into(),
+                description: None,
+                interfaces: Vec::new(),
+                fields: Vec::new(),
+            },
+        }
+    }
+
+    /// Sets the `description` of this [`DynamicInterface`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.def.description = Some(description.into());
+        self
+    }
+
+    /// Makes this [`DynamicInterface`] implement the `name`d interface.
+    #[must_use]
+    pub fn implements(mut self, name: impl Into<String>) -> Self {
+        self.def.interfaces.push(name.into());
+        self
+    }
+
+    /// Adds the provided `field` to this [`DynamicInterface`].
+    #[must_use]
+    pub fn field(mut self, field: DynamicField<S>) -> Self {
+        self.def.fields.push(field.def);
+        self
+    }
+}
+
+/// Union type of a [`DynamicSchemaBuilder`].
+#[derive(Debug)]
+pub struct DynamicUnion {
+    def: UnionDef,
+}
+
+impl DynamicUnion {
+    /// Creates a new `name`d [`DynamicUnion`] without members.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            def: UnionDef {
+                name: name.into(),
+                description: None,
+                members: Vec::new(),
+            },
+        }
+    }
+
+    /// Sets the `description` of this [`DynamicUnion`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.def.description = Some(description.into());
+        self
+    }
+
+    /// Adds the `name`d object type to the members of this [`DynamicUnion`].
+    #[must_use]
+    pub fn member(mut self, name: impl Into<String>) -> Self {
+        self.def.members.push(name.into());
+        self
+    }
+}
+
+/// Input object type of a [`DynamicSchemaBuilder`].
+#[derive(Debug)]
+pub struct DynamicInputObject<S = DefaultScalarValue> {
+    def: InputObjectDef<S>,
+}
+
+impl<S> DynamicInputObject<S> {
+    /// Creates a new `name`d [`DynamicInputObject`] without fields.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            def: InputObjectDef {
+                name: name.into(),
+                description: None,
+                fields: Vec::new(),
+            },
+        }
+    }
+
+    /// Sets the `description` of this [`DynamicInputObject`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.def.description = Some(description.into());
+        self
+    }
+
+    /// Adds the provided `field` to this [`DynamicInputObject`].
+    #[must_use]
+    pub fn field(mut self, field: DynamicArgument<S>) -> Self {
+        self.def.fields.push(field.def);
+        self
+    }
+}
+
+/// Field of a [`DynamicObject`] or a [`DynamicInterface`].
+#[derive(Debug)]
+pub struct DynamicField<S = DefaultScalarValue> {
+    def: FieldDef<S>,
+}
+
+impl<S> DynamicField<S> {
+    /// Creates a new `name`d [`DynamicField`] of the provided `ty`pe (like
+    /// `[String!]!`).
+    pub fn new(name: impl Into<String>, ty: &str) -> Self {
+        Self {
+            def: FieldDef {
+                name: name.into(),
+                description: None,
+                arguments: Vec::new(),
+                ty: type_ref(ty),
+                deprecated: None,
+            },
+        }
+    }
+
+    /// Sets the `description` of this [`DynamicField`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.def.description = Some(description.into());
+        self
+    }
+
+    /// Adds the provided `argument` to this [`DynamicField`].
+    #[must_use]
+    pub fn argument(mut self, argument: DynamicArgument<S>) -> Self {
+        self.def.arguments.push(argument.def);
+        self
+    }
+
+    /// Marks this [`DynamicField`] as deprecated, with the optional `reason`.
+    #[must_use]
+    pub fn deprecated(mut self, reason: Option<&str>) -> Self {
+        self.def.deprecated = Some(reason.map(Into::into));
+        self
+    }
+}
+
+/// Argument of a [`DynamicField`], or a field of a [`DynamicInputObject`].
+#[derive(Debug)]
+pub struct DynamicArgument<S = DefaultScalarValue> {
+    def: ArgumentDef<S>,
+}
+
+impl<S> DynamicArgument<S> {
+    /// Creates a new `name`d [`DynamicArgument`] of the provided `ty`pe (like
+    /// `[String!]!`).
+    pub fn new(name: impl Into<String>, ty: &str) -> Self {
+        Self {
+            def: ArgumentDef {
+                name: name.into(),
+                description: None,
+                ty: type_ref(ty),
+                default: None,
+            },
+        }
+    }
+
+    /// Sets the `description` of this [`DynamicArgument`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.def.description = Some(description.into());
+        self
+    }
+
+    /// Sets the `default` value of this [`DynamicArgument`].
+    #[must_use]
+    pub fn default_value(mut self, default: InputValue<S>) -> Self {
+        self.def.default = Some(default);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        execute_sync, graphql_value, graphql_vars, meta::EnumValue, DynamicSchemaError,
+        DynamicValue, InputValue,
+    };
+
+    use super::{
+        DynamicArgument, DynamicEnum, DynamicField, DynamicInputObject, DynamicInterface,
+        DynamicObject, DynamicScalar, DynamicSchemaBuilder, DynamicUnion,
+    };
+
+    #[test]
+    fn builds_executable_schema() {
+        let schema = DynamicSchemaBuilder::<()>::new("Query")
+            .scalar(DynamicScalar::new("Url").description("URL string"))
+            .enum_type(
+                DynamicEnum::new("Color")
+                    .value(EnumValue::new("RED"))
+                    .value(EnumValue::new("GREEN").deprecated(None)),
+            )
+            .interface(DynamicInterface::new("Named").field(DynamicField::new("name", "String!")))
+            .object(
+                DynamicObject::new("Pet")
+                    .implements("Named")
+                    .field(DynamicField::new("name", "String!"))
+                    .field(DynamicField::new("color", "Color!"))
+                    .field(DynamicField::new("photo", "Url")),
+            )
+            .object(
+                DynamicObject::new("Owner")
+                    .implements("Named")
+                    .field(DynamicField::new("name", "String!")),
+            )
+            .union(DynamicUnion::new("Entity").member("Pet").member("Owner"))
+            .input_object(
+                DynamicInputObject::new("Filter")
+                    .field(DynamicArgument::new("color", "Color"))
+                    .field(
+                        DynamicArgument::new("limit", "Int").default_value(InputValue::scalar(2)),
+                    ),
+            )
+            .object(
+                DynamicObject::new("Query")
+                    .field(
+                        DynamicField::new("entities", "[Entity!]!")
+                            .argument(DynamicArgument::new("filter", "Filter!")),
+                    )
+                    .resolver("entities", |_, args| {
+                        assert!(args.get::<DynamicValue<()>>("filter")?.is_some());
+                        Ok(graphql_value!([
+                            {"__typename": "Pet", "name": "Rex", "color": "RED", "photo": "a.png"},
+                            {"__typename": "Owner", "name": "Ann"},
+                        ]))
+                    }),
+            )
+            .into_root_node()
+            .unwrap();
+
+        let res = execute_sync(
+            r#"{
+                entities(filter: {color: RED}) {
+                    ... on Named { name }
+                    ... on Pet { color photo }
+                }
+            }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        );
+
+        assert_eq!(
+            res,
+            Ok((
+                graphql_value!({"entities": [
+                    {"name": "Rex", "color": "RED", "photo": "a.png"},
+                    {"name": "Ann"},
+                ]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_schemas() {
+        assert!(matches!(
+            DynamicSchemaBuilder::<()>::new("Query")
+                .object(DynamicObject::new("Query").field(DynamicField::new("a", "Int")))
+                .scalar(DynamicScalar::new("Query"))
+                .build(),
+            Err(DynamicSchemaError::DuplicateType(name)) if name == "Query",
+        ));
+        assert!(matches!(
+            DynamicSchemaBuilder::<()>::new("Query")
+                .object(DynamicObject::new("Query").field(DynamicField::new("a", "[Int")))
+                .into_root_node(),
+            Err(DynamicSchemaError::MalformedType(ty)) if ty == "[Int",
+        ));
+        assert!(matches!(
+            DynamicSchemaBuilder::<()>::new("Query")
+                .object(DynamicObject::new("Query").field(DynamicField::new("a", "Int!!")))
+                .into_root_node(),
+            Err(DynamicSchemaError::MalformedType(_)),
+        ));
+    }
+}
@@ -0,0 +1,1019 @@
+//! Schemas defined at runtime, rather than via Rust types.
+
+mod builder;
+#[cfg(feature = "schema-language")]
+mod sdl;
+
+use std::{borrow::Cow, error::Error, fmt, marker::PhantomData, sync::Arc};
+
+use fnv::FnvHashMap;
+use indexmap::IndexMap;
+
+pub use self::builder::{
+    DynamicArgument, DynamicEnum, DynamicField, DynamicInputObject, DynamicInterface,
+    DynamicObject, DynamicScalar, DynamicSchemaBuilder, DynamicUnion,
+};
+
+use crate::{
+    ast::{FromInputValue, InputValue, Selection},
+    executor::{ExecutionResult, Executor, FieldError, FieldResult, Registry},
+    parser::ScalarToken,
+    schema::{
+        meta::{Argument, EnumValue, Field, MetaType},
+        model::RootNode,
+    },
+    types::{
+        async_await::{resolve_selection_set_into_async, GraphQLValueAsync},
+        base::{resolve_selection_set_into, Arguments, GraphQLType, GraphQLValue},
+        scalars::{EmptySubscription, ID},
+    },
+    value::{DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+    BoxFuture,
+};
+
+/// Name of the mutation type of a [`DynamicSchema`] without any mutations.
+///
+/// Is the same one [`EmptyMutation`] uses, so the type is omitted from the
+/// schema.
+///
+/// [`EmptyMutation`]: crate::EmptyMutation
+const EMPTY_MUTATION: &str = "_EmptyMutation";
+
+/// [`RootNode`] of a [`DynamicSchema`].
+pub type DynamicRootNode<C, S = DefaultScalarValue> =
+    RootNode<'static, DynamicValue<C, S>, DynamicValue<C, S>, EmptySubscription<C>, S>;
+
+/// Function resolving a field of a [`DynamicSchema`].
+type Resolver<C, S> = Arc<
+    dyn Fn(&ResolverContext<'_, C, S>, &Arguments<S>) -> FieldResult<Value<S>, S> + Send + Sync,
+>;
+
+/// Schema defined at runtime (in the schema language, for example), with its
+/// fields resolved by the functions registered via
+/// [`DynamicSchema::resolver()`].
+///
+/// Serves schema-first and gateway use cases, where the schema isn't known
+/// at compile time.
+///
+/// Values of the schema are represented as [`Value`]s. Fields without a
+/// registered resolver are resolved with the same-named field of their parent
+/// [`Value::Object`] (or `null`, if there is none). Values of interfaces and
+/// unions should have a `__typename` field naming their concrete object type.
+///
+/// Subscriptions aren't supported.
+///
+/// ```rust
+/// # use juniper::{graphql_value, graphql_vars, DynamicSchema, Value};
+/// #
+/// struct Database;
+///
+/// let schema = DynamicSchema::<Database>::parse(r#"
+///     type Query {
+///         user(id: ID!): User
+///     }
+///
+///     type User {
+///         id: ID!
+///         name: String!
+///     }
+/// "#)
+/// .unwrap()
+/// .resolver("Query", "user", |_, args| {
+///     let id = args.get::<String>("id")?.unwrap_or_default();
+///     Ok(graphql_value!({"id": id, "name": "John"}))
+/// })
+/// .into_root_node()
+/// .unwrap();
+///
+/// let res = juniper::execute_sync(
+///     r#"{ user(id: "1") { name } }"#,
+///     None,
+///     &schema,
+///     &graphql_vars! {},
+///     &Database,
+/// );
+///
+/// assert_eq!(res, Ok((graphql_value!({"user": {"name": "John"}}), vec![])));
+/// ```
+pub struct DynamicSchema<C, S = DefaultScalarValue> {
+    types: IndexMap<String, TypeDef<S>>,
+    query: String,
+    mutation: Option<String>,
+    resolvers: FnvHashMap<String, FnvHashMap<String, Resolver<C, S>>>,
+}
+
+impl<C, S: fmt::Debug> fmt::Debug for DynamicSchema<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicSchema")
+            .field("types", &self.types)
+            .field("query", &self.query)
+            .field("mutation", &self.mutation)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C, S> DynamicSchema<C, S>
+where
+    S: ScalarValue,
+{
+    /// Registers the provided `resolver` function for the `field_name`d field
+    /// of the `type_name`d object type.
+    ///
+    /// Replaces any resolver registered for the same field before.
+    #[must_use]
+    pub fn resolver<F>(mut self, type_name: &str, field_name: &str, resolver: F) -> Self
+    where
+        F: Fn(&ResolverContext<'_, C, S>, &Arguments<S>) -> FieldResult<Value<S>, S>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resolvers
+            .entry(type_name.into())
+            .or_default()
+            .insert(field_name.into(), Arc::new(resolver));
+        self
+    }
+
+    /// Builds a [`DynamicRootNode`] executing operations against this
+    /// [`DynamicSchema`].
+    ///
+    /// # Errors
+    ///
+    /// If this [`DynamicSchema`] refers to undefined types, uses types of a
+    /// wrong kind somewhere, or has resolvers registered for undefined fields.
+    pub fn into_root_node(self) -> Result<DynamicRootNode<C, S>, DynamicSchemaError> {
+        self.check()?;
+
+        let mutation = self.mutation.clone();
+        let schema = Arc::new(self);
+        let info = |name: &str| DynamicTypeInfo {
+            schema: schema.clone(),
+            ty: TypeRef::NonNull(Box::new(TypeRef::Named(name.into()))),
+        };
+
+        Ok(RootNode::new_with_info(
+            DynamicValue::new(Value::Object(Object::with_capacity(0))),
+            DynamicValue::new(Value::Object(Object::with_capacity(0))),
+            EmptySubscription::new(),
+            info(&schema.query),
+            info(mutation.as_deref().unwrap_or(EMPTY_MUTATION)),
+            (),
+        ))
+    }
+
+    /// Checks this [`DynamicSchema`] to be executable.
+    fn check(&self) -> Result<(), DynamicSchemaError> {
+        self.expect_kind(&self.query, &[TypeKind::Object])?;
+        if let Some(mutation) = &self.mutation {
+            self.expect_kind(mutation, &[TypeKind::Object])?;
+        }
+
+        let output = &[
+            TypeKind::Scalar,
+            TypeKind::Enum,
+            TypeKind::Object,
+            TypeKind::Interface,
+            TypeKind::Union,
+        ];
+        let input = &[TypeKind::Scalar, TypeKind::Enum, TypeKind::InputObject];
+
+        for def in self.types.values() {
+            match def {
+                TypeDef::Scalar(_) | TypeDef::Enum(_) => {}
+                TypeDef::Object(ObjectDef {
+                    interfaces, fields, ..
+                })
+                | TypeDef::Interface(ObjectDef {
+                    interfaces, fields, ..
+                }) => {
+                    for name in interfaces {
+                        self.expect_kind(name, &[TypeKind::Interface])?;
+                    }
+                    for field in fields {
+                        self.expect_kind(field.ty.innermost_name(), output)?;
+                        for arg in &field.arguments {
+                            self.expect_kind(arg.ty.innermost_name(), input)?;
+                        }
+                    }
+                }
+                TypeDef::Union(union) => {
+                    for name in &union.members {
+                        self.expect_kind(name, &[TypeKind::Object])?;
+                    }
+                }
+                TypeDef::InputObject(input_object) => {
+                    for field in &input_object.fields {
+                        self.expect_kind(field.ty.innermost_name(), input)?;
+                    }
+                }
+            }
+        }
+
+        for (type_name, fields) in &self.resolvers {
+            for field_name in fields.keys() {
+                match self.types.get(type_name) {
+                    Some(TypeDef::Object(obj)) if obj.field(field_name).is_some() => {}
+                    _ => {
+                        return Err(DynamicSchemaError::UnknownField {
+                            type_name: type_name.clone(),
+                            field_name: field_name.clone(),
+                        })
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the `name`d type to be defined and of one of the `expected`
+    /// kinds.
+    fn expect_kind(&self, name: &str, expected: &[TypeKind]) -> Result<(), DynamicSchemaError> {
+        let kind = match self.types.get(name) {
+            Some(def) => def.kind(),
+            None if is_builtin_scalar(name) => TypeKind::Scalar,
+            None if TypeRef::parse(name) != Some(TypeRef::Named(name.into())) => {
+                return Err(DynamicSchemaError::MalformedType(name.into()))
+            }
+            None => return Err(DynamicSchemaError::UnknownType(name.into())),
+        };
+        if !expected.contains(&kind) {
+            return Err(DynamicSchemaError::WrongTypeKind(name.into()));
+        }
+        Ok(())
+    }
+}
+
+/// Error of building a [`DynamicSchema`].
+#[derive(Debug)]
+pub enum DynamicSchemaError {
+    /// Schema language document is malformed.
+    #[cfg(feature = "schema-language")]
+    Parse(graphql_parser::schema::ParseError),
+
+    /// Definition isn't supported by a [`DynamicSchema`].
+    Unsupported(String),
+
+    /// Type of this name is defined more than once.
+    DuplicateType(String),
+
+    /// Type reference isn't a valid schema language type (like `[String!]!`).
+    MalformedType(String),
+
+    /// Type of this name is referred to, but isn't defined.
+    UnknownType(String),
+
+    /// Type of this name is used where a type of another kind is expected
+    /// (like an input object as the type of a field).
+    WrongTypeKind(String),
+
+    /// Resolver is registered for an undefined field.
+    UnknownField {
+        /// Name of the type the field is resolved on.
+        type_name: String,
+
+        /// Name of the undefined field.
+        field_name: String,
+    },
+}
+
+impl fmt::Display for DynamicSchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "schema-language")]
+            Self::Parse(e) => write!(f, "{e}"),
+            Self::Unsupported(what) => write!(f, "{what} isn't supported"),
+            Self::DuplicateType(name) => write!(f, "Type `{name}` is defined more than once"),
+            Self::MalformedType(ty) => write!(f, "Malformed type `{ty}`"),
+            Self::UnknownType(name) => write!(f, "Unknown type `{name}`"),
+            Self::WrongTypeKind(name) => write!(f, "Type `{name}` can't be used here"),
+            Self::UnknownField {
+                type_name,
+                field_name,
+            } => write!(
+                f,
+                "Resolver is registered for unknown field `{field_name}` of `{type_name}` object",
+            ),
+        }
+    }
+}
+
+impl Error for DynamicSchemaError {}
+
+/// Context of resolving a field of a [`DynamicSchema`], provided to its
+/// resolver.
+pub struct ResolverContext<'r, C, S = DefaultScalarValue> {
+    parent: &'r Value<S>,
+    context: &'r C,
+    type_name: &'r str,
+    field_name: &'r str,
+}
+
+impl<'r, C, S> ResolverContext<'r, C, S> {
+    /// Returns the value the field is resolved on.
+    pub fn parent(&self) -> &'r Value<S> {
+        self.parent
+    }
+
+    /// Returns the context of the executed operation.
+    pub fn context(&self) -> &'r C {
+        self.context
+    }
+
+    /// Returns the name of the object type the field is resolved on.
+    pub fn type_name(&self) -> &'r str {
+        self.type_name
+    }
+
+    /// Returns the name of the resolved field.
+    pub fn field_name(&self) -> &'r str {
+        self.field_name
+    }
+}
+
+/// Value of any type of a [`DynamicSchema`].
+pub struct DynamicValue<C, S = DefaultScalarValue> {
+    value: Value<S>,
+    _context: PhantomData<fn() -> C>,
+}
+
+impl<C, S: fmt::Debug> fmt::Debug for DynamicValue<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DynamicValue").field(&self.value).finish()
+    }
+}
+
+impl<C, S: Clone> Clone for DynamicValue<C, S> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<C, S> DynamicValue<C, S> {
+    /// Wraps the provided [`Value`].
+    pub fn new(value: Value<S>) -> Self {
+        Self {
+            value,
+            _context: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped [`Value`].
+    pub fn value(&self) -> &Value<S> {
+        &self.value
+    }
+
+    /// Unwraps the wrapped [`Value`].
+    pub fn into_inner(self) -> Value<S> {
+        self.value
+    }
+}
+
+impl<C, S: ScalarValue> DynamicValue<C, S> {
+    /// Returns the items of this list value.
+    fn items(&self) -> FieldResult<Vec<Self>, S> {
+        self.value
+            .as_list_value()
+            .map(|items| items.iter().cloned().map(Self::new).collect())
+            .ok_or_else(|| "Expected a list".into())
+    }
+
+    /// Resolves the value of the `field_name`d field on this value, returning
+    /// it along with the type info of the field.
+    fn field_value(
+        &self,
+        info: &DynamicTypeInfo<C, S>,
+        field_name: &str,
+        args: &Arguments<S>,
+        context: &C,
+    ) -> FieldResult<(Self, DynamicTypeInfo<C, S>), S> {
+        let type_name = info.named();
+        let field = info
+            .schema
+            .types
+            .get(type_name)
+            .and_then(TypeDef::as_object)
+            .and_then(|obj| obj.field(field_name))
+            .ok_or_else(|| format!("Unknown field `{field_name}` of `{type_name}` type"))?;
+
+        let resolver = info
+            .schema
+            .resolvers
+            .get(type_name)
+            .and_then(|fields| fields.get(field_name));
+        let value = match resolver {
+            Some(resolve) => resolve(
+                &ResolverContext {
+                    parent: &self.value,
+                    context,
+                    type_name,
+                    field_name,
+                },
+                args,
+            )?,
+            None => self
+                .value
+                .as_object_value()
+                .and_then(|obj| obj.get_field_value(field_name))
+                .cloned()
+                .unwrap_or_else(Value::null),
+        };
+
+        Ok((Self::new(value), info.of(field.ty.clone())))
+    }
+}
+
+impl<C, S> GraphQLType<S> for DynamicValue<C, S>
+where
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        match &info.ty {
+            TypeRef::NonNull(ty) => match &**ty {
+                TypeRef::Named(name) => Some(name),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let name = match &info.ty {
+            TypeRef::NonNull(ty) => match &**ty {
+                TypeRef::Named(name) => name,
+                TypeRef::List(item) => {
+                    return registry
+                        .build_list_type::<Self>(&info.of((**item).clone()), None)
+                        .into_meta()
+                }
+                TypeRef::NonNull(_) => unreachable!("doubly non-null type"),
+            },
+            ty => {
+                let ty = TypeRef::NonNull(Box::new(ty.clone()));
+                return registry
+                    .build_nullable_type::<Self>(&info.of(ty))
+                    .into_meta();
+            }
+        };
+
+        let def = match info.schema.types.get(name.as_str()) {
+            Some(def) => def,
+            None => {
+                return match name.as_str() {
+                    "Int" => <i32 as GraphQLType<S>>::meta(&(), registry),
+                    "Float" => <f64 as GraphQLType<S>>::meta(&(), registry),
+                    "String" => <String as GraphQLType<S>>::meta(&(), registry),
+                    "Boolean" => <bool as GraphQLType<S>>::meta(&(), registry),
+                    "ID" => <ID as GraphQLType<S>>::meta(&(), registry),
+                    EMPTY_MUTATION => registry.build_object_type::<Self>(info, &[]).into_meta(),
+                    _ => unreachable!("unknown type `{name}` in checked `DynamicSchema`"),
+                }
+            }
+        };
+
+        match def {
+            TypeDef::Scalar(scalar) => {
+                let mut meta = registry.build_scalar_type::<Self>(info);
+                if let Some(desc) = &scalar.description {
+                    meta = meta.description(desc);
+                }
+                if let Some(url) = &scalar.specified_by_url {
+                    meta = meta.specified_by_url(url.clone());
+                }
+                meta.into_meta()
+            }
+            TypeDef::Enum(enum_def) => {
+                let mut meta = registry.build_enum_type::<Self>(info, &enum_def.values);
+                if let Some(desc) = &enum_def.description {
+                    meta = meta.description(desc);
+                }
+                meta.into_meta()
+            }
+            TypeDef::Object(obj) => {
+                let fields = obj
+                    .fields
+                    .iter()
+                    .map(|f| field_meta(f, info, registry))
+                    .collect::<Vec<_>>();
+                let interfaces = obj
+                    .interfaces
+                    .iter()
+                    .map(|i| registry.get_type::<Self>(&info.of_named(i)))
+                    .collect::<Vec<_>>();
+                let mut meta = registry
+                    .build_object_type::<Self>(info, &fields)
+                    .interfaces(&interfaces);
+                if let Some(desc) = &obj.description {
+                    meta = meta.description(desc);
+                }
+                meta.into_meta()
+            }
+            TypeDef::Interface(iface) => {
+                let fields = iface
+                    .fields
+                    .iter()
+                    .map(|f| field_meta(f, info, registry))
+                    .collect::<Vec<_>>();
+                let interfaces = iface
+                    .interfaces
+                    .iter()
+                    .map(|i| registry.get_type::<Self>(&info.of_named(i)))
+                    .collect::<Vec<_>>();
+                let mut meta = registry
+                    .build_interface_type::<Self>(info, &fields)
+                    .interfaces(&interfaces);
+                if let Some(desc) = &iface.description {
+                    meta = meta.description(desc);
+                }
+                meta.into_meta()
+            }
+            TypeDef::Union(union) => {
+                let members = union
+                    .members
+                    .iter()
+                    .map(|m| registry.get_type::<Self>(&info.of_named(m)))
+                    .collect::<Vec<_>>();
+                let mut meta = registry.build_union_type::<Self>(info, &members);
+                if let Some(desc) = &union.description {
+                    meta = meta.description(desc);
+                }
+                meta.into_meta()
+            }
+            TypeDef::InputObject(input) => {
+                let fields = input
+                    .fields
+                    .iter()
+                    .map(|f| argument_meta(f, info, registry))
+                    .collect::<Vec<_>>();
+                let mut meta = registry.build_input_object_type::<Self>(info, &fields);
+                if let Some(desc) = &input.description {
+                    meta = meta.description(desc);
+                }
+                meta.into_meta()
+            }
+        }
+    }
+}
+
+/// Builds the [`Field`] of the provided `field` definition.
+fn field_meta<'r, C, S>(
+    field: &FieldDef<S>,
+    info: &DynamicTypeInfo<C, S>,
+    registry: &mut Registry<'r, S>,
+) -> Field<'r, S>
+where
+    S: ScalarValue + 'r,
+{
+    let mut meta = registry.field::<DynamicValue<C, S>>(&field.name, &info.of(field.ty.clone()));
+    for arg in &field.arguments {
+        meta = meta.argument(argument_meta(arg, info, registry));
+    }
+    if let Some(desc) = &field.description {
+        meta = meta.description(desc);
+    }
+    if let Some(reason) = &field.deprecated {
+        meta = meta.deprecated(reason.as_deref());
+    }
+    meta
+}
+
+/// Builds the [`Argument`] of the provided `arg`ument definition.
+fn argument_meta<'r, C, S>(
+    arg: &ArgumentDef<S>,
+    info: &DynamicTypeInfo<C, S>,
+    registry: &mut Registry<'r, S>,
+) -> Argument<'r, S>
+where
+    S: ScalarValue + 'r,
+{
+    let mut meta = registry.arg::<DynamicValue<C, S>>(&arg.name, &info.of(arg.ty.clone()));
+    if let Some(desc) = &arg.description {
+        meta = meta.description(desc);
+    }
+    if let Some(default) = &arg.default {
+        meta = meta.default_value(default.clone());
+    }
+    meta
+}
+
+impl<C, S> GraphQLValue<S> for DynamicValue<C, S>
+where
+    S: ScalarValue,
+{
+    type Context = C;
+    type TypeInfo = DynamicTypeInfo<C, S>;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        let name = info.named();
+        match info.schema.types.get(name) {
+            Some(TypeDef::Interface(_) | TypeDef::Union(_)) => self
+                .value
+                .as_object_value()
+                .and_then(|obj| obj.get_field_value("__typename"))
+                .and_then(Value::as_scalar)
+                .and_then(ScalarValue::as_str)
+                .unwrap_or(name)
+                .into(),
+            _ => name.into(),
+        }
+    }
+
+    fn resolve_into_type(
+        &self,
+        info: &Self::TypeInfo,
+        type_name: &str,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        self.resolve(&info.of_named(type_name), selection_set, executor)
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field_name: &str,
+        args: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        let (value, info) = self.field_value(info, field_name, args, executor.context())?;
+        executor.resolve(&info, &value)
+    }
+
+    fn resolve(
+        &self,
+        info: &Self::TypeInfo,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        let name = match &info.ty {
+            TypeRef::NonNull(ty) => match &**ty {
+                TypeRef::Named(name) => name,
+                TypeRef::List(item) => {
+                    let info = info.of((**item).clone());
+                    return self.items()?.resolve(&info, selection_set, executor);
+                }
+                TypeRef::NonNull(_) => unreachable!("doubly non-null type"),
+            },
+            ty => {
+                if self.value.is_null() {
+                    return Ok(Value::null());
+                }
+                let info = info.of(TypeRef::NonNull(Box::new(ty.clone())));
+                return self.resolve(&info, selection_set, executor);
+            }
+        };
+
+        if !info.schema.is_composite(name) {
+            return Ok(self.value.clone());
+        }
+        let selection_set = selection_set.ok_or("Composite types must have a selection set")?;
+        let mut res = Object::with_capacity(selection_set.len());
+        Ok(
+            if resolve_selection_set_into(self, info, selection_set, executor, &mut res) {
+                Value::Object(res)
+            } else {
+                Value::null()
+            },
+        )
+    }
+}
+
+impl<C, S> GraphQLValueAsync<S> for DynamicValue<C, S>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_into_type_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        type_name: &str,
+        selection_set: Option<&'a [Selection<'a, S>]>,
+        executor: &'a Executor<'a, 'a, Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        let info = info.of_named(type_name);
+        Box::pin(async move { self.resolve_async(&info, selection_set, executor).await })
+    }
+
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field_name: &'a str,
+        args: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(async move {
+            let (value, info) = self.field_value(info, field_name, args, executor.context())?;
+            executor.resolve_async(&info, &value).await
+        })
+    }
+
+    fn resolve_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        selection_set: Option<&'a [Selection<S>]>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(async move {
+            let name = match &info.ty {
+                TypeRef::NonNull(ty) => match &**ty {
+                    TypeRef::Named(name) => name,
+                    TypeRef::List(item) => {
+                        let (items, info) = (self.items()?, info.of((**item).clone()));
+                        return items.resolve_async(&info, selection_set, executor).await;
+                    }
+                    TypeRef::NonNull(_) => unreachable!("doubly non-null type"),
+                },
+                ty => {
+                    if self.value.is_null() {
+                        return Ok(Value::null());
+                    }
+                    let info = info.of(TypeRef::NonNull(Box::new(ty.clone())));
+                    return self.resolve_async(&info, selection_set, executor).await;
+                }
+            };
+
+            if !info.schema.is_composite(name) {
+                return Ok(self.value.clone());
+            }
+            let selection_set = selection_set.ok_or("Composite types must have a selection set")?;
+            Ok(resolve_selection_set_into_async(self, info, selection_set, executor).await)
+        })
+    }
+}
+
+impl<C, S> FromInputValue<S> for DynamicValue<C, S>
+where
+    S: ScalarValue,
+{
+    type Error = FieldError<S>;
+
+    fn from_input_value(v: &InputValue<S>) -> Result<Self, Self::Error> {
+        fn convert<S: ScalarValue>(v: &InputValue<S>) -> Result<Value<S>, FieldError<S>> {
+            Ok(match v {
+                InputValue::Null => Value::null(),
+                InputValue::Scalar(s) => Value::Scalar(s.clone()),
+                InputValue::Enum(e) => Value::scalar(e.clone()),
+                InputValue::Variable(name) => {
+                    return Err(format!("Unresolved variable `${name}`").into())
+                }
+                InputValue::List(items) => Value::list(
+                    items
+                        .iter()
+                        .map(|i| convert(&i.item))
+                        .collect::<Result<_, _>>()?,
+                ),
+                InputValue::Object(fields) => Value::object(
+                    fields
+                        .iter()
+                        .map(|(k, v)| Ok((k.item.clone(), convert(&v.item)?)))
+                        .collect::<Result<_, FieldError<S>>>()?,
+                ),
+            })
+        }
+
+        convert(v).map(Self::new)
+    }
+}
+
+impl<C, S> ParseScalarValue<S> for DynamicValue<C, S>
+where
+    S: ScalarValue,
+{
+    fn from_str(value: ScalarToken<'_>) -> ParseScalarResult<S> {
+        match value {
+            ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(value),
+            ScalarToken::Int(_) => <i32 as ParseScalarValue<S>>::from_str(value)
+                .or_else(|_| <f64 as ParseScalarValue<S>>::from_str(value)),
+            ScalarToken::Float(_) => <f64 as ParseScalarValue<S>>::from_str(value),
+        }
+    }
+}
+
+/// Type info of a [`DynamicValue`], referring to the type of the value in its
+/// [`DynamicSchema`].
+pub struct DynamicTypeInfo<C, S = DefaultScalarValue> {
+    schema: Arc<DynamicSchema<C, S>>,
+    ty: TypeRef,
+}
+
+impl<C, S> fmt::Debug for DynamicTypeInfo<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicTypeInfo")
+            .field("ty", &self.ty)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C, S> DynamicTypeInfo<C, S> {
+    /// Returns the type info of the provided `ty`pe in the same schema.
+    fn of(&self, ty: TypeRef) -> Self {
+        Self {
+            schema: self.schema.clone(),
+            ty,
+        }
+    }
+
+    /// Returns the type info of the `name`d type in the same schema.
+    fn of_named(&self, name: &str) -> Self {
+        self.of(TypeRef::NonNull(Box::new(TypeRef::Named(name.into()))))
+    }
+
+    /// Returns the name of this non-null named type.
+    fn named(&self) -> &str {
+        match &self.ty {
+            TypeRef::NonNull(ty) => match &**ty {
+                TypeRef::Named(name) => name,
+                _ => unreachable!("not a named type"),
+            },
+            _ => unreachable!("not a non-null type"),
+        }
+    }
+}
+
+impl<C, S> DynamicSchema<C, S> {
+    /// Indicates whether the `name`d type is an object, interface or union.
+    fn is_composite(&self, name: &str) -> bool {
+        matches!(
+            self.types.get(name),
+            Some(TypeDef::Object(_) | TypeDef::Interface(_) | TypeDef::Union(_)),
+        )
+    }
+}
+
+/// Indicates whether the `name`d type is one of the scalars built into
+/// GraphQL.
+fn is_builtin_scalar(name: &str) -> bool {
+    matches!(name, "Int" | "Float" | "String" | "Boolean" | "ID")
+}
+
+/// Reference to a type of a [`DynamicSchema`], as written in the schema
+/// language.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TypeRef {
+    /// Nullable named type.
+    Named(String),
+
+    /// Nullable list type.
+    List(Box<TypeRef>),
+
+    /// Non-null type.
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    /// Parses a [`TypeRef`] written in the schema language (like `[String!]!`).
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if let Some(ty) = s.strip_suffix('!') {
+            return match Self::parse(ty)? {
+                Self::NonNull(_) => None,
+                ty => Some(Self::NonNull(Box::new(ty))),
+            };
+        }
+        if let Some(item) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            return Some(Self::List(Box::new(Self::parse(item)?)));
+        }
+        let is_name = s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        is_name.then(|| Self::Named(s.into()))
+    }
+
+    /// Returns the name of the named type wrapped into this one.
+    pub(crate) fn innermost_name(&self) -> &str {
+        match self {
+            Self::Named(name) => name,
+            Self::List(ty) | Self::NonNull(ty) => ty.innermost_name(),
+        }
+    }
+}
+
+/// Kind of a [`TypeDef`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TypeKind {
+    Scalar,
+    Enum,
+    Object,
+    Interface,
+    Union,
+    InputObject,
+}
+
+/// Definition of a named type of a [`DynamicSchema`].
+#[derive(Debug)]
+pub(crate) enum TypeDef<S> {
+    Scalar(ScalarDef),
+    Enum(EnumDef),
+    Object(ObjectDef<S>),
+    Interface(ObjectDef<S>),
+    Union(UnionDef),
+    InputObject(InputObjectDef<S>),
+}
+
+impl<S> TypeDef<S> {
+    /// Returns the name of this type.
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            Self::Scalar(ScalarDef { name, .. })
+            | Self::Enum(EnumDef { name, .. })
+            | Self::Object(ObjectDef { name, .. })
+            | Self::Interface(ObjectDef { name, .. })
+            | Self::Union(UnionDef { name, .. })
+            | Self::InputObject(InputObjectDef { name, .. }) => name,
+        }
+    }
+
+    fn kind(&self) -> TypeKind {
+        match self {
+            Self::Scalar(_) => TypeKind::Scalar,
+            Self::Enum(_) => TypeKind::Enum,
+            Self::Object(_) => TypeKind::Object,
+            Self::Interface(_) => TypeKind::Interface,
+            Self::Union(_) => TypeKind::Union,
+            Self::InputObject(_) => TypeKind::InputObject,
+        }
+    }
+
+    /// Returns the definition of this object or interface type.
+    fn as_object(&self) -> Option<&ObjectDef<S>> {
+        match self {
+            Self::Object(obj) | Self::Interface(obj) => Some(obj),
+            _ => None,
+        }
+    }
+}
+
+/// Definition of a scalar type.
+#[derive(Debug)]
+pub(crate) struct ScalarDef {
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) specified_by_url: Option<Cow<'static, str>>,
+}
+
+/// Definition of an enum type.
+#[derive(Debug)]
+pub(crate) struct EnumDef {
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) values: Vec<EnumValue>,
+}
+
+/// Definition of an object or interface type.
+#[derive(Debug)]
+pub(crate) struct ObjectDef<S> {
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) interfaces: Vec<String>,
+    pub(crate) fields: Vec<FieldDef<S>>,
+}
+
+impl<S> ObjectDef<S> {
+    /// Returns the definition of the `name`d field of this type.
+    fn field(&self, name: &str) -> Option<&FieldDef<S>> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+}
+
+/// Definition of a field of an object or interface type.
+#[derive(Debug)]
+pub(crate) struct FieldDef<S> {
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) arguments: Vec<ArgumentDef<S>>,
+    pub(crate) ty: TypeRef,
+    pub(crate) deprecated: Option<Option<String>>,
+}
+
+/// Definition of an argument of a field, or of a field of an input object
+/// type.
+#[derive(Debug)]
+pub(crate) struct ArgumentDef<S> {
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) ty: TypeRef,
+    pub(crate) default: Option<InputValue<S>>,
+}
+
+/// Definition of a union type.
+#[derive(Debug)]
+pub(crate) struct UnionDef {
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) members: Vec<String>,
+}
+
+/// Definition of an input object type.
+#[derive(Debug)]
+pub(crate) struct InputObjectDef<S> {
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) fields: Vec<ArgumentDef<S>>,
+}
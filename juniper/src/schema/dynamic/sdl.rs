@@ -0,0 +1,429 @@
+//! Building a [`DynamicSchema`] from a schema language document.
+
+use fnv::FnvHashMap;
+use graphql_parser::{
+    query::{Directive, Type, Value},
+    schema::{self as ext, Definition, TypeDefinition},
+};
+use indexmap::{map::Entry, IndexMap};
+
+use crate::{ast::InputValue, schema::meta::EnumValue, value::ScalarValue};
+
+use super::{
+    ArgumentDef, DynamicSchema, DynamicSchemaError, EnumDef, FieldDef, InputObjectDef, ObjectDef,
+    ScalarDef, TypeDef, TypeRef, UnionDef,
+};
+
+impl<C, S> DynamicSchema<C, S>
+where
+    S: ScalarValue,
+{
+    /// Parses a [`DynamicSchema`] from the provided schema language document.
+    ///
+    /// If the document has no `schema` definition, the `Query` and `Mutation`
+    /// types are used as the root operation types.
+    ///
+    /// # Errors
+    ///
+    /// If the document is malformed, defines a type more than once, or
+    /// contains definitions a [`DynamicSchema`] doesn't support: subscriptions,
+    /// type extensions and directive definitions.
+    pub fn parse(sdl: &str) -> Result<Self, DynamicSchemaError> {
+        let doc = graphql_parser::parse_schema::<String>(sdl).map_err(DynamicSchemaError::Parse)?;
+
+        let mut types = IndexMap::new();
+        let mut roots = None;
+        for def in doc.definitions {
+            match def {
+                Definition::SchemaDefinition(schema) => {
+                    if schema.subscription.is_some() {
+                        return Err(DynamicSchemaError::Unsupported("Subscription".into()));
+                    }
+                    roots = Some((schema.query, schema.mutation));
+                }
+                Definition::TypeDefinition(def) => {
+                    let def = type_def(def);
+                    match types.entry(def.name().to_owned()) {
+                        Entry::Occupied(e) => {
+                            return Err(DynamicSchemaError::DuplicateType(e.key().clone()))
+                        }
+                        Entry::Vacant(e) => {
+                            e.insert(def);
+                        }
+                    }
+                }
+                Definition::TypeExtension(_) => {
+                    return Err(DynamicSchemaError::Unsupported("Type extension".into()))
+                }
+                Definition::DirectiveDefinition(_) => {
+                    return Err(DynamicSchemaError::Unsupported(
+                        "Directive definition".into(),
+                    ))
+                }
+            }
+        }
+
+        let (query, mutation) = match roots {
+            Some((query, mutation)) => (query.unwrap_or_else(|| "Query".into()), mutation),
+            None => (
+                "Query".into(),
+                types.contains_key("Mutation").then(|| "Mutation".into()),
+            ),
+        };
+
+        Ok(Self {
+            types,
+            query,
+            mutation,
+            resolvers: FnvHashMap::default(),
+        })
+    }
+}
+
+/// Converts the provided type definition into a [`TypeDef`].
+fn type_def<S: ScalarValue>(def: TypeDefinition<'_, String>) -> TypeDef<S> {
+    match def {
+        TypeDefinition::Scalar(scalar) => TypeDef::Scalar(ScalarDef {
+            specified_by_url: directive_arg(&scalar.directives, "specifiedBy", "url")
+                .and_then(string_value)
+                .map(Into::into),
+            name: scalar.name,
+            description: scalar.description,
+        }),
+        TypeDefinition::Enum(enum_def) => TypeDef::Enum(EnumDef {
+            name: enum_def.name,
+            description: enum_def.description,
+            values: enum_def
+                .values
+                .into_iter()
+                .map(|v| {
+                    let mut value = EnumValue::new(&v.name);
+                    if let Some(desc) = &v.description {
+                        value = value.description(desc);
+                    }
+                    if let Some(reason) = deprecation(&v.directives) {
+                        value = value.deprecated(reason.as_deref());
+                    }
+                    value
+                })
+                .collect(),
+        }),
+        TypeDefinition::Object(obj) => TypeDef::Object(ObjectDef {
+            name: obj.name,
+            description: obj.description,
+            interfaces: obj.implements_interfaces,
+            fields: obj.fields.into_iter().map(field_def).collect(),
+        }),
+        TypeDefinition::Interface(iface) => TypeDef::Interface(ObjectDef {
+            name: iface.name,
+            description: iface.description,
+            interfaces: iface.implements_interfaces,
+            fields: iface.fields.into_iter().map(field_def).collect(),
+        }),
+        TypeDefinition::Union(union) => TypeDef::Union(UnionDef {
+            name: union.name,
+            description: union.description,
+            members: union.types,
+        }),
+        TypeDefinition::InputObject(input) => TypeDef::InputObject(InputObjectDef {
+            name: input.name,
+            description: input.description,
+            fields: input.fields.into_iter().map(argument_def).collect(),
+        }),
+    }
+}
+
+/// Converts the provided field definition into a [`FieldDef`].
+fn field_def<S: ScalarValue>(field: ext::Field<'_, String>) -> FieldDef<S> {
+    FieldDef {
+        deprecated: deprecation(&field.directives),
+        name: field.name,
+        description: field.description,
+        arguments: field.arguments.into_iter().map(argument_def).collect(),
+        ty: type_ref(field.field_type),
+    }
+}
+
+/// Converts the provided argument or input field definition into an
+/// [`ArgumentDef`].
+fn argument_def<S: ScalarValue>(arg: ext::InputValue<'_, String>) -> ArgumentDef<S> {
+    ArgumentDef {
+        name: arg.name,
+        description: arg.description,
+        ty: type_ref(arg.value_type),
+        default: arg.default_value.map(input_value),
+    }
+}
+
+/// Converts the provided type into a [`TypeRef`].
+fn type_ref(ty: Type<'_, String>) -> TypeRef {
+    match ty {
+        Type::NamedType(name) => TypeRef::Named(name),
+        Type::ListType(ty) => TypeRef::List(Box::new(type_ref(*ty))),
+        Type::NonNullType(ty) => TypeRef::NonNull(Box::new(type_ref(*ty))),
+    }
+}
+
+/// Converts the provided constant value into an [`InputValue`].
+fn input_value<S: ScalarValue>(v: Value<'_, String>) -> InputValue<S> {
+    match v {
+        Value::Variable(name) => InputValue::variable(name),
+        Value::Int(n) => {
+            let n = n.as_i64().unwrap_or_default();
+            i32::try_from(n)
+                .map(InputValue::scalar)
+                .unwrap_or_else(|_| InputValue::scalar(n as f64))
+        }
+        Value::Float(f) => InputValue::scalar(f),
+        Value::String(s) => InputValue::scalar(s),
+        Value::Boolean(b) => InputValue::scalar(b),
+        Value::Null => InputValue::null(),
+        Value::Enum(name) => InputValue::enum_value(name),
+        Value::List(items) => InputValue::list(items.into_iter().map(input_value).collect()),
+        Value::Object(fields) => InputValue::object(
+            fields
+                .into_iter()
+                .map(|(k, v)| (k, input_value(v)))
+                .collect::<IndexMap<_, _>>(),
+        ),
+    }
+}
+
+/// Returns the deprecation of an element annotated with the provided
+/// `directives`.
+fn deprecation(directives: &[Directive<'_, String>]) -> Option<Option<String>> {
+    directives
+        .iter()
+        .any(|d| d.name == "deprecated")
+        .then(|| directive_arg(directives, "deprecated", "reason").and_then(string_value))
+}
+
+/// Returns the `arg_name`d argument of the `name`d directive among the
+/// provided `directives`.
+fn directive_arg<'d, 'a>(
+    directives: &'d [Directive<'a, String>],
+    name: &str,
+    arg_name: &str,
+) -> Option<&'d Value<'a, String>> {
+    directives
+        .iter()
+        .filter(|d| d.name == name)
+        .flat_map(|d| &d.arguments)
+        .find_map(|(n, v)| (n == arg_name).then_some(v))
+}
+
+/// Returns the string of the provided string value.
+fn string_value(v: &Value<'_, String>) -> Option<String> {
+    match v {
+        Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{execute, execute_sync, graphql_value, graphql_vars, Value};
+
+    use super::{DynamicSchema, DynamicSchemaError};
+
+    const SDL: &str = r#"
+        type Query {
+            user(id: ID!): User
+            search(first: Int = 10): [Result!]!
+            nodes: [Node!]!
+        }
+
+        type Mutation {
+            rename(input: RenameInput!): Boolean
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        type User implements Node {
+            id: ID!
+            name: String!
+            role: Role!
+        }
+
+        type Post implements Node {
+            id: ID!
+            title: String @deprecated(reason: "Use `headline`")
+        }
+
+        union Result = User | Post
+
+        enum Role {
+            ADMIN
+            GUEST
+        }
+
+        input RenameInput {
+            id: ID!
+            name: String!
+        }
+    "#;
+
+    struct Context {
+        user_name: String,
+    }
+
+    fn schema() -> DynamicSchema<Context> {
+        DynamicSchema::<Context>::parse(SDL)
+            .unwrap()
+            .resolver("Query", "user", |ctx, args| {
+                let id = args.get::<String>("id")?.unwrap_or_default();
+                let name = ctx.context().user_name.clone();
+                Ok(graphql_value!({"id": id, "name": name, "role": "ADMIN"}))
+            })
+            .resolver("Query", "search", |_, args| {
+                let first = args.get::<i32>("first")?.unwrap_or_default();
+                Ok(graphql_value!([
+                    {"__typename": "User", "id": "1", "name": "John", "role": "GUEST"},
+                    {"__typename": "Post", "id": "2", "title": (first.to_string())},
+                ]))
+            })
+            .resolver("Query", "nodes", |_, _| {
+                Ok(graphql_value!([{"__typename": "Post", "id": "3"}]))
+            })
+            .resolver("Mutation", "rename", |_, args| {
+                let input = args.get::<crate::DynamicValue<Context>>("input")?;
+                Ok(Value::scalar(input.is_some()))
+            })
+    }
+
+    fn context() -> Context {
+        Context {
+            user_name: "Jane".into(),
+        }
+    }
+
+    #[test]
+    fn resolves_registered_resolvers() {
+        let schema = schema().into_root_node().unwrap();
+
+        let res = execute_sync(
+            r#"{ user(id: "7") { id name role } }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &context(),
+        );
+
+        assert_eq!(
+            res,
+            Ok((
+                graphql_value!({"user": {"id": "7", "name": "Jane", "role": "ADMIN"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_abstract_types_by_typename() {
+        let schema = schema().into_root_node().unwrap();
+
+        let res = execute_sync(
+            r#"{
+                search {
+                    __typename
+                    ... on User { name }
+                    ... on Post { title }
+                }
+                nodes { __typename id }
+            }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &context(),
+        );
+
+        assert_eq!(
+            res,
+            Ok((
+                graphql_value!({
+                    "search": [
+                        {"__typename": "User", "name": "John"},
+                        {"__typename": "Post", "title": "10"},
+                    ],
+                    "nodes": [{"__typename": "Post", "id": "3"}],
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_async() {
+        let schema = schema().into_root_node().unwrap();
+
+        let res = execute(
+            r#"mutation { rename(input: {id: "1", name: "Joe"}) }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &context(),
+        )
+        .await;
+
+        assert_eq!(res, Ok((graphql_value!({"rename": true}), vec![])));
+    }
+
+    #[test]
+    fn exposes_sdl_in_introspection() {
+        let schema = schema().into_root_node().unwrap();
+
+        let res = execute_sync(
+            r#"{
+                __type(name: "Post") {
+                    fields(includeDeprecated: true) { name deprecationReason }
+                    interfaces { name }
+                }
+            }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &context(),
+        );
+
+        assert_eq!(
+            res,
+            Ok((
+                graphql_value!({"__type": {
+                    "fields": [
+                        {"name": "id", "deprecationReason": null},
+                        {"name": "title", "deprecationReason": "Use `headline`"},
+                    ],
+                    "interfaces": [{"name": "Node"}],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_schemas() {
+        assert!(matches!(
+            DynamicSchema::<()>::parse("type Query { a: Int } type Query { b: Int }"),
+            Err(DynamicSchemaError::DuplicateType(name)) if name == "Query",
+        ));
+        assert!(matches!(
+            DynamicSchema::<()>::parse("type Query { a: Int } extend type Query { b: Int }"),
+            Err(DynamicSchemaError::Unsupported(_)),
+        ));
+        assert!(matches!(
+            DynamicSchema::<()>::parse("type Query { a: Missing }")
+                .unwrap()
+                .into_root_node(),
+            Err(DynamicSchemaError::UnknownType(name)) if name == "Missing",
+        ));
+        assert!(matches!(
+            DynamicSchema::<()>::parse("type Query { a: Int } input In { a: Int }")
+                .unwrap()
+                .resolver("In", "a", |_, _| Ok(Value::null()))
+                .into_root_node(),
+            Err(DynamicSchemaError::UnknownField { .. }),
+        ));
+    }
+}
@@ -0,0 +1,334 @@
+//! Generation of example operations out of a schema.
+
+use std::fmt::Write as _;
+
+use indexmap::IndexMap;
+
+use crate::{
+    ast::{InputValue, Type},
+    executor::Variables,
+    schema::{
+        meta::{Argument, Field, MetaType},
+        model::SchemaType,
+    },
+    value::{DefaultScalarValue, ScalarValue},
+};
+
+/// Example operation generated by [`example_operation()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExampleOperation<S = DefaultScalarValue> {
+    /// Source text of the operation.
+    pub query: String,
+
+    /// Placeholder values of all the variables declared by the operation.
+    pub variables: Variables<S>,
+}
+
+/// Generates an example operation selecting the field at the provided `path`.
+///
+/// The `path` starts with the name of a root type, followed by the names of
+/// the fields leading to the selected one, separated by dots (like
+/// `Query.hero.friends`). Every argument of these fields is provided via a
+/// variable having a placeholder value, and the selected field is queried
+/// for all its sub-fields not requiring any arguments, up to the `max_depth`
+/// nested levels.
+///
+/// Returns [`None`] if the `path` doesn't lead to a field of the schema.
+pub fn example_operation<S>(
+    schema: &SchemaType<S>,
+    path: &str,
+    max_depth: usize,
+) -> Option<ExampleOperation<S>>
+where
+    S: ScalarValue,
+{
+    let mut segments = path.split('.');
+    let root_name = segments.next()?;
+    let keyword = if root_name == schema.query_type_name {
+        "query"
+    } else if Some(root_name) == schema.mutation_type_name.as_deref() {
+        "mutation"
+    } else if Some(root_name) == schema.subscription_type_name.as_deref() {
+        "subscription"
+    } else {
+        return None;
+    };
+
+    let mut generator = Generator {
+        schema,
+        variable_definitions: vec![],
+        variables: Variables::new(),
+        max_depth,
+    };
+
+    let mut operation_name = String::new();
+    let mut body = String::new();
+    let mut parent_type = schema.concrete_type_by_name(root_name)?;
+    let mut opened = 0;
+    for segment in segments {
+        if !parent_type.is_composite() {
+            return None;
+        }
+        if opened > 0 {
+            body.push_str(" {\n");
+        }
+
+        let field = parent_type.field_by_name(segment)?;
+        let mut chars = segment.chars();
+        operation_name.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+        operation_name.extend(chars);
+
+        opened += 1;
+        let arguments = generator.arguments(field);
+        write!(body, "{}{segment}{arguments}", indent(opened)).unwrap();
+
+        parent_type = schema.concrete_type_by_name(field.field_type.innermost_name())?;
+    }
+    if operation_name.is_empty() {
+        return None;
+    }
+
+    if parent_type.is_composite() {
+        body.push_str(" {\n");
+        generator.selection_set(
+            parent_type,
+            max_depth.saturating_sub(1),
+            opened + 1,
+            &mut body,
+        );
+    } else {
+        body.push('\n');
+        opened -= 1;
+    }
+    for level in (1..=opened).rev() {
+        writeln!(body, "{}}}", indent(level)).unwrap();
+    }
+
+    let mut query = format!("{keyword} {operation_name}");
+    if !generator.variable_definitions.is_empty() {
+        write!(query, "({})", generator.variable_definitions.join(", ")).unwrap();
+    }
+    write!(query, " {{\n{body}}}\n").unwrap();
+
+    Some(ExampleOperation {
+        query,
+        variables: generator.variables,
+    })
+}
+
+fn indent(level: usize) -> String {
+    "  ".repeat(level)
+}
+
+struct Generator<'a, S> {
+    schema: &'a SchemaType<'a, S>,
+    variable_definitions: Vec<String>,
+    variables: Variables<S>,
+    max_depth: usize,
+}
+
+impl<'a, S: ScalarValue> Generator<'a, S> {
+    /// Declares a variable for every argument of the provided `field`, and
+    /// returns the arguments passing them.
+    fn arguments(&mut self, field: &Field<S>) -> String {
+        let args = field.arguments.as_deref().unwrap_or_default();
+        if args.is_empty() {
+            return String::new();
+        }
+
+        let args = args
+            .iter()
+            .map(|arg| {
+                let mut var_name = arg.name.clone();
+                let mut n = 1;
+                while self.variables.contains_key(&var_name) {
+                    n += 1;
+                    var_name = format!("{}{n}", arg.name);
+                }
+
+                let value = self.placeholder(&arg.arg_type, self.max_depth);
+                self.variable_definitions
+                    .push(format!("${var_name}: {}", arg.arg_type));
+                self.variables.insert(var_name.clone(), value);
+                format!("{}: ${var_name}", arg.name)
+            })
+            .collect::<Vec<_>>();
+        format!("({})", args.join(", "))
+    }
+
+    /// Writes the selection set of the provided composite `meta` type.
+    ///
+    /// Composite fields are selected only while there is some `depth` left,
+    /// and `__typename` is selected if nothing else could be.
+    fn selection_set(&self, meta: &MetaType<S>, depth: usize, level: usize, out: &mut String) {
+        let len = out.len();
+        match meta {
+            MetaType::Object(_) | MetaType::Interface(_) => {
+                let fields = match meta {
+                    MetaType::Object(o) => &o.fields,
+                    MetaType::Interface(i) => &i.fields,
+                    _ => unreachable!(),
+                };
+                for field in fields {
+                    if field.is_builtin()
+                        || field.deprecation_status.is_deprecated()
+                        || field.arguments.iter().flatten().any(is_required)
+                    {
+                        continue;
+                    }
+                    let field_type = match self
+                        .schema
+                        .concrete_type_by_name(field.field_type.innermost_name())
+                    {
+                        Some(t) => t,
+                        None => continue,
+                    };
+
+                    if !field_type.is_composite() {
+                        writeln!(out, "{}{}", indent(level), field.name).unwrap();
+                    } else if depth > 0 {
+                        writeln!(out, "{}{} {{", indent(level), field.name).unwrap();
+                        self.selection_set(field_type, depth - 1, level + 1, out);
+                        writeln!(out, "{}}}", indent(level)).unwrap();
+                    }
+                }
+            }
+            MetaType::Union(u) => {
+                writeln!(out, "{}__typename", indent(level)).unwrap();
+                for name in &u.of_type_names {
+                    if let Some(t) = self.schema.concrete_type_by_name(name) {
+                        writeln!(out, "{}... on {name} {{", indent(level)).unwrap();
+                        self.selection_set(t, depth, level + 1, out);
+                        writeln!(out, "{}}}", indent(level)).unwrap();
+                    }
+                }
+            }
+            _ => {}
+        }
+        if out.len() == len {
+            writeln!(out, "{}__typename", indent(level)).unwrap();
+        }
+    }
+
+    /// Returns a placeholder value of the provided input type, nesting lists
+    /// and input objects up to the `depth` levels.
+    fn placeholder(&self, ty: &Type, depth: usize) -> InputValue<S> {
+        match ty {
+            Type::List(inner, _) | Type::NonNullList(inner, _) => InputValue::list(if depth > 0 {
+                vec![self.placeholder(inner, depth - 1)]
+            } else {
+                vec![]
+            }),
+            Type::Named(name) | Type::NonNullNamed(name) => {
+                match self.schema.concrete_type_by_name(name) {
+                    Some(MetaType::Scalar(s)) => match &*s.name {
+                        "Int" => InputValue::scalar(0),
+                        "Float" => InputValue::scalar(0.0),
+                        "Boolean" => InputValue::scalar(false),
+                        _ => InputValue::scalar(String::new()),
+                    },
+                    Some(MetaType::Enum(e)) => e
+                        .values
+                        .first()
+                        .map_or_else(InputValue::null, |v| InputValue::enum_value(&v.name)),
+                    Some(MetaType::InputObject(o)) => InputValue::object(
+                        o.input_fields
+                            .iter()
+                            .filter(|f| is_required(f))
+                            .map(|f| {
+                                let value = self.placeholder(&f.arg_type, depth.saturating_sub(1));
+                                (f.name.as_str(), value)
+                            })
+                            .collect::<IndexMap<_, _>>(),
+                    ),
+                    _ => InputValue::null(),
+                }
+            }
+        }
+    }
+}
+
+/// Indicates whether the provided [`Argument`] must be always specified.
+fn is_required<S>(arg: &Argument<S>) -> bool {
+    arg.arg_type.is_non_null() && arg.default_value.is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_input_value,
+        schema::model::RootNode,
+        tests::fixtures::starwars::schema::{Database, Query},
+        EmptyMutation, EmptySubscription, Variables,
+    };
+
+    use super::ExampleOperation;
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>> {
+        RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        )
+    }
+
+    #[test]
+    fn generates_depth_limited_selection() {
+        let op = schema().example_operation("Query.human", 2).unwrap();
+
+        assert_eq!(
+            op,
+            ExampleOperation {
+                query: "query Human($id: String!) {\n  \
+                            human(id: $id) {\n    \
+                                id\n    \
+                                name\n    \
+                                friends {\n      \
+                                    id\n      \
+                                    name\n      \
+                                    appearsIn\n    \
+                                }\n    \
+                                appearsIn\n    \
+                                homePlanet\n  \
+                            }\n\
+                        }\n"
+                .into(),
+                variables: [("id".into(), graphql_input_value!(""))]
+                    .into_iter()
+                    .collect(),
+            },
+        );
+    }
+
+    #[test]
+    fn generates_nested_path() {
+        let op = schema().example_operation("Query.hero.name", 3).unwrap();
+
+        assert_eq!(
+            op.query,
+            "query HeroName($episode: Episode) {\n  \
+                 hero(episode: $episode) {\n    \
+                     name\n  \
+                 }\n\
+             }\n",
+        );
+        assert_eq!(
+            op.variables,
+            [("episode".into(), graphql_input_value!(NEW_HOPE))]
+                .into_iter()
+                .collect::<Variables>(),
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_paths() {
+        let schema = schema();
+
+        assert!(schema.example_operation("Query", 1).is_none());
+        assert!(schema.example_operation("Query.unknown", 1).is_none());
+        assert!(schema
+            .example_operation("Query.hero.name.length", 1)
+            .is_none());
+        assert!(schema.example_operation("Mutation.hero", 1).is_none());
+    }
+}
@@ -0,0 +1,1024 @@
+//! Merging of independently built schemas into a single one.
+
+use std::{error::Error, fmt, marker::PhantomData};
+
+use fnv::FnvHashMap;
+
+use crate::{
+    executor::{ExecutionResult, Executor, FieldError, Registry, ValuesStream},
+    schema::{
+        meta::{Argument, Field, MetaType, ObjectMeta},
+        model::{RootNode, SchemaType},
+    },
+    types::{
+        async_await::{GraphQLTypeAsync, GraphQLValueAsync},
+        base::{Arguments, GraphQLType, GraphQLValue},
+        subscriptions::{GraphQLSubscriptionType, GraphQLSubscriptionValue},
+    },
+    value::{DefaultScalarValue, ScalarValue, Value},
+    BoxFuture,
+};
+
+/// Name of the root query type of a merged schema.
+const QUERY: &str = "Query";
+
+/// Name of the root mutation type of a merged schema.
+const MUTATION: &str = "Mutation";
+
+/// Name of the root subscription type of a merged schema.
+const SUBSCRIPTION: &str = "Subscription";
+
+/// [`RootNode`] of a schema merged by a [`SchemaMerger`].
+pub type MergedRootNode<C, S = DefaultScalarValue> =
+    RootNode<'static, MergedObject<C, S>, MergedObject<C, S>, MergedSubscription<C, S>, S>;
+
+/// Merger of several independently built [`RootNode`]s (from different crates
+/// or teams, for example) into a single [`MergedRootNode`].
+///
+/// The `Query`, `Mutation` and `Subscription` types of the merged schema
+/// have all the root fields of the merged [`RootNode`]s, which are resolved
+/// by the [`RootNode`] defining them. All the other types are shared by the
+/// merged [`RootNode`]s, so the ones of the same name must have the same
+/// definition (like ones of the same Rust type do).
+///
+/// Root fields of a [`RootNode`] merged with
+/// [`SchemaMerger::merge_namespaced()`] are prefixed with the provided
+/// namespace, so the root fields of different [`RootNode`]s don't conflict.
+///
+/// Only the types of the merged [`RootNode`]s are merged: their limits,
+/// options and directives are not, and should be set on the
+/// [`MergedRootNode`] instead.
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_object, graphql_value, graphql_vars, EmptyMutation, EmptySubscription,
+/// #     RootNode, SchemaMerger,
+/// # };
+/// #
+/// struct UsersQuery;
+///
+/// #[graphql_object]
+/// impl UsersQuery {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// struct BillingQuery;
+///
+/// #[graphql_object]
+/// impl BillingQuery {
+///     fn invoice_count() -> i32 {
+///         3
+///     }
+/// }
+///
+/// let users = RootNode::new(UsersQuery, EmptyMutation::new(), EmptySubscription::new());
+/// let billing = RootNode::new(BillingQuery, EmptyMutation::new(), EmptySubscription::new());
+///
+/// let schema = SchemaMerger::<()>::new()
+///     .merge(users)
+///     .merge_namespaced("billing", billing)
+///     .into_root_node()
+///     .unwrap();
+///
+/// let res = juniper::execute_sync(
+///     "{ userCount billing_invoiceCount }",
+///     None,
+///     &schema,
+///     &graphql_vars! {},
+///     &(),
+/// );
+///
+/// assert_eq!(
+///     res,
+///     Ok((graphql_value!({"userCount": 2, "billing_invoiceCount": 3}), vec![])),
+/// );
+/// ```
+pub struct SchemaMerger<C, S = DefaultScalarValue> {
+    query: Merged<dyn ObjectPart<C, S>>,
+    mutation: Merged<dyn ObjectPart<C, S>>,
+    subscription: Merged<dyn SubscriptionPart<C, S>>,
+    types: Vec<FnvHashMap<String, String>>,
+}
+
+impl<C, S> fmt::Debug for SchemaMerger<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaMerger")
+            .field("query", &self.query)
+            .field("mutation", &self.mutation)
+            .field("subscription", &self.subscription)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C, S> Default for SchemaMerger<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, S> SchemaMerger<C, S> {
+    /// Creates a new [`SchemaMerger`] without any [`RootNode`]s merged.
+    pub fn new() -> Self {
+        Self {
+            query: Merged::new(QUERY),
+            mutation: Merged::new(MUTATION),
+            subscription: Merged::new(SUBSCRIPTION),
+            types: Vec::new(),
+        }
+    }
+}
+
+impl<C, S> SchemaMerger<C, S>
+where
+    C: Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    /// Merges the provided [`RootNode`] into the schema, exposing its root
+    /// fields as they are.
+    #[must_use]
+    pub fn merge<QueryT, MutationT, SubscriptionT>(
+        self,
+        root: RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    ) -> Self
+    where
+        QueryT: GraphQLTypeAsync<S, Context = C> + Send + 'static,
+        QueryT::TypeInfo: Send + Sync + 'static,
+        MutationT: GraphQLTypeAsync<S, Context = C> + Send + 'static,
+        MutationT::TypeInfo: Send + Sync + 'static,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = C> + Send + 'static,
+        SubscriptionT::TypeInfo: Send + Sync + 'static,
+    {
+        self.merge_root(None, root)
+    }
+
+    /// Merges the provided [`RootNode`] into the schema, exposing its root
+    /// fields prefixed with the provided `namespace` (so the `invoices` field
+    /// is exposed as `billing_invoices` in the `billing` namespace).
+    #[must_use]
+    pub fn merge_namespaced<QueryT, MutationT, SubscriptionT>(
+        self,
+        namespace: impl Into<String>,
+        root: RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    ) -> Self
+    where
+        QueryT: GraphQLTypeAsync<S, Context = C> + Send + 'static,
+        QueryT::TypeInfo: Send + Sync + 'static,
+        MutationT: GraphQLTypeAsync<S, Context = C> + Send + 'static,
+        MutationT::TypeInfo: Send + Sync + 'static,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = C> + Send + 'static,
+        SubscriptionT::TypeInfo: Send + Sync + 'static,
+    {
+        self.merge_root(Some(namespace.into()), root)
+    }
+
+    fn merge_root<QueryT, MutationT, SubscriptionT>(
+        mut self,
+        namespace: Option<String>,
+        root: RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    ) -> Self
+    where
+        QueryT: GraphQLTypeAsync<S, Context = C> + Send + 'static,
+        QueryT::TypeInfo: Send + Sync + 'static,
+        MutationT: GraphQLTypeAsync<S, Context = C> + Send + 'static,
+        MutationT::TypeInfo: Send + Sync + 'static,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = C> + Send + 'static,
+        SubscriptionT::TypeInfo: Send + Sync + 'static,
+    {
+        let schema = &root.schema;
+        let roots = [
+            Some(schema.query_type_name.as_str()),
+            schema.mutation_type_name.as_deref(),
+            schema.subscription_type_name.as_deref(),
+        ];
+        self.types.push(
+            schema
+                .types
+                .values()
+                .filter_map(|meta| Some((meta.name()?, meta)))
+                .filter(|(name, _)| !name.starts_with("__") && !roots.contains(&Some(*name)))
+                .map(|(name, meta)| (name.into(), type_signature(meta)))
+                .collect(),
+        );
+
+        let query_fields = root_field_names(schema, Some(&schema.query_type_name));
+        let mutation_fields = root_field_names(schema, schema.mutation_type_name.as_ref());
+        let subscription_fields = root_field_names(schema, schema.subscription_type_name.as_ref());
+
+        if let Some(fields) = query_fields {
+            let part = Part {
+                value: root.query_type,
+                info: root.query_info,
+            };
+            self.query.push(namespace.clone(), fields, Box::new(part));
+        }
+        if let Some(fields) = mutation_fields {
+            let part = Part {
+                value: root.mutation_type,
+                info: root.mutation_info,
+            };
+            self.mutation
+                .push(namespace.clone(), fields, Box::new(part));
+        }
+        if let Some(fields) = subscription_fields {
+            let part = Part {
+                value: root.subscription_type,
+                info: root.subscription_info,
+            };
+            self.subscription.push(namespace, fields, Box::new(part));
+        }
+        self
+    }
+
+    /// Checks the merged schema for conflicts, and creates a
+    /// [`MergedRootNode`] out of it.
+    ///
+    /// # Errors
+    ///
+    /// If the merged [`RootNode`]s define different types of the same name,
+    /// or the same root fields.
+    pub fn into_root_node(self) -> Result<MergedRootNode<C, S>, SchemaMergeError> {
+        let mut types = FnvHashMap::<&str, &str>::default();
+        for (name, signature) in self.types.iter().flatten() {
+            match types.insert(name, signature) {
+                Some(other) if other != signature.as_str() => {
+                    return Err(SchemaMergeError::TypeConflict {
+                        type_name: name.clone(),
+                    })
+                }
+                _ => {}
+            }
+        }
+        for root in [self.query.name, self.mutation.name, self.subscription.name] {
+            if types.contains_key(root) {
+                return Err(SchemaMergeError::TypeConflict {
+                    type_name: root.into(),
+                });
+            }
+        }
+        self.query.check()?;
+        self.mutation.check()?;
+        self.subscription.check()?;
+
+        Ok(RootNode::new_with_info(
+            MergedObject(PhantomData),
+            MergedObject(PhantomData),
+            MergedSubscription(PhantomData),
+            MergedObjectInfo(self.query),
+            MergedObjectInfo(self.mutation.or_empty("_EmptyMutation")),
+            MergedSubscriptionInfo(self.subscription.or_empty("_EmptySubscription")),
+        ))
+    }
+}
+
+/// Returns names of the fields of the provided `root` type in the `schema`,
+/// if there is such a type.
+fn root_field_names<S>(schema: &SchemaType<'_, S>, root: Option<&String>) -> Option<Vec<String>> {
+    match schema.types.get(root?.as_str())? {
+        MetaType::Object(ObjectMeta { fields, .. }) => Some(
+            fields
+                .iter()
+                .filter(|f| !f.name.starts_with("__"))
+                .map(|f| f.name.to_string())
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Returns a textual representation of the provided type definition, equal
+/// for the equally defined types only.
+fn type_signature<S: ScalarValue>(meta: &MetaType<'_, S>) -> String {
+    fn fields<S: ScalarValue>(fields: &[Field<'_, S>]) -> String {
+        fields
+            .iter()
+            .map(|f| {
+                let args = f.arguments.as_deref().unwrap_or_default();
+                format!("{}({}): {}", f.name, arguments(args), f.field_type)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn arguments<S: ScalarValue>(args: &[Argument<'_, S>]) -> String {
+        args.iter()
+            .map(|a| match &a.default_value {
+                Some(default) => format!("{}: {} = {default}", a.name, a.arg_type),
+                None => format!("{}: {}", a.name, a.arg_type),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    match meta {
+        MetaType::Scalar(m) => format!("scalar {}", m.name),
+        MetaType::Enum(m) => {
+            let values = m.values.iter().map(|v| v.name.as_str());
+            format!(
+                "enum {} {{ {} }}",
+                m.name,
+                values.collect::<Vec<_>>().join(", ")
+            )
+        }
+        MetaType::Object(m) => format!(
+            "type {} implements {} {{ {} }}",
+            m.name,
+            m.interface_names.join(" & "),
+            fields(&m.fields),
+        ),
+        MetaType::Interface(m) => format!(
+            "interface {} implements {} {{ {} }}",
+            m.name,
+            m.interface_names.join(" & "),
+            fields(&m.fields),
+        ),
+        MetaType::Union(m) => format!("union {} = {}", m.name, m.of_type_names.join(" | ")),
+        MetaType::InputObject(m) => {
+            format!("input {} {{ {} }}", m.name, arguments(&m.input_fields))
+        }
+        MetaType::List(_) | MetaType::Nullable(_) | MetaType::Placeholder(_) => String::new(),
+    }
+}
+
+/// Error of merging schemas with a [`SchemaMerger`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaMergeError {
+    /// Different types of the same name are defined by the merged schemas.
+    TypeConflict {
+        /// Name of the conflicting types.
+        type_name: String,
+    },
+
+    /// Root field of the same name is defined by several merged schemas.
+    FieldConflict {
+        /// Name of the root type of the conflicting fields.
+        type_name: String,
+        /// Name of the conflicting fields.
+        field_name: String,
+    },
+}
+
+impl fmt::Display for SchemaMergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeConflict { type_name } => {
+                write!(
+                    f,
+                    "Type `{type_name}` is defined differently by merged schemas"
+                )
+            }
+            Self::FieldConflict {
+                type_name,
+                field_name,
+            } => write!(
+                f,
+                "Field `{field_name}` of type `{type_name}` is defined by several merged schemas",
+            ),
+        }
+    }
+}
+
+impl Error for SchemaMergeError {}
+
+/// Root type of a merged schema, combining the ones of the merged
+/// [`RootNode`]s.
+struct Merged<P: ?Sized> {
+    /// Name of this root type.
+    name: &'static str,
+
+    /// Root types of the merged [`RootNode`]s, along with the namespaces of
+    /// their fields.
+    parts: Vec<(Option<String>, Box<P>)>,
+
+    /// Exposed names of the fields of this root type, along with the index of
+    /// the part resolving them, and their names in it.
+    fields: Vec<(String, usize, String)>,
+}
+
+impl<P: ?Sized> fmt::Debug for Merged<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields = self.fields.iter().map(|(name, ..)| name);
+        f.debug_struct("Merged")
+            .field("name", &self.name)
+            .field("fields", &fields.collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P: ?Sized> Merged<P> {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            parts: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds the provided `part` of this root type, having the `names`d fields.
+    fn push(&mut self, namespace: Option<String>, names: Vec<String>, part: Box<P>) {
+        let idx = self.parts.len();
+        self.fields.extend(
+            names
+                .into_iter()
+                .map(|name| (exposed_name(namespace.as_deref(), &name), idx, name)),
+        );
+        self.parts.push((namespace, part));
+    }
+
+    /// Renames this root type to the provided `empty` one, if it has no
+    /// parts, so it's omitted from the schema.
+    fn or_empty(mut self, empty: &'static str) -> Self {
+        if self.parts.is_empty() {
+            self.name = empty;
+        }
+        self
+    }
+
+    /// Checks this root type doesn't have conflicting fields.
+    fn check(&self) -> Result<(), SchemaMergeError> {
+        for (i, (name, ..)) in self.fields.iter().enumerate() {
+            if self.fields[..i].iter().any(|(other, ..)| other == name) {
+                return Err(SchemaMergeError::FieldConflict {
+                    type_name: self.name.into(),
+                    field_name: name.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the part resolving the provided `field_name`, along with the
+    /// field's name in it.
+    fn route(&self, field_name: &str) -> Option<(&P, &str)> {
+        self.fields
+            .iter()
+            .find(|(name, ..)| name == field_name)
+            .map(|(_, idx, name)| (&*self.parts[*idx].1, name.as_str()))
+    }
+
+    /// Returns an error of the provided `field_name` not existing in this root
+    /// type.
+    fn unknown_field<S>(&self, field_name: &str) -> FieldError<S> {
+        format!("Field `{field_name}` not found on type `{}`", self.name).into()
+    }
+
+    /// Builds the fields of this root type, registering their types in the
+    /// provided `registry`.
+    fn fields<'r, S>(&self, registry: &mut Registry<'r, S>) -> Vec<Field<'r, S>>
+    where
+        P: PartMeta<S>,
+        S: ScalarValue + 'r,
+    {
+        let mut fields = Vec::new();
+        for (namespace, part) in &self.parts {
+            if let MetaType::Object(ObjectMeta {
+                fields: part_fields,
+                ..
+            }) = part.meta(registry)
+            {
+                fields.extend(part_fields.into_iter().map(|mut f| {
+                    f.name = exposed_name(namespace.as_deref(), &f.name).into();
+                    f
+                }));
+            }
+        }
+        fields
+    }
+}
+
+/// Returns the name the provided root `field` is exposed with in the
+/// `namespace`.
+fn exposed_name(namespace: Option<&str>, field: &str) -> String {
+    match namespace {
+        Some(ns) => format!("{ns}_{field}"),
+        None => field.into(),
+    }
+}
+
+/// Root type of a merged [`RootNode`], along with its type info.
+struct Part<T, I> {
+    value: T,
+    info: I,
+}
+
+/// Root type of a merged [`RootNode`], building its [`MetaType`].
+trait PartMeta<S> {
+    fn meta<'r>(&self, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r;
+}
+
+impl<S, T> PartMeta<S> for Part<T, T::TypeInfo>
+where
+    S: ScalarValue,
+    T: GraphQLType<S>,
+{
+    fn meta<'r>(&self, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        // Not registered via `Registry::get_type()`, as the root types of
+        // different merged schemas may have the same name.
+        T::meta(&self.info, registry)
+    }
+}
+
+/// Root query or mutation type of a merged [`RootNode`], resolving its
+/// fields.
+trait ObjectPart<C, S>: PartMeta<S> + Send + Sync {
+    fn resolve_field(
+        &self,
+        field_name: &str,
+        args: &Arguments<S>,
+        executor: &Executor<C, S>,
+    ) -> ExecutionResult<S>;
+
+    fn resolve_field_async<'a>(
+        &'a self,
+        field_name: &'a str,
+        args: &'a Arguments<S>,
+        executor: &'a Executor<C, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>>;
+}
+
+impl<C, S, T> ObjectPart<C, S> for Part<T, T::TypeInfo>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+    T: GraphQLTypeAsync<S, Context = C> + Send,
+    T::TypeInfo: Send + Sync,
+{
+    fn resolve_field(
+        &self,
+        field_name: &str,
+        args: &Arguments<S>,
+        executor: &Executor<C, S>,
+    ) -> ExecutionResult<S> {
+        self.value
+            .resolve_field(&self.info, field_name, args, executor)
+    }
+
+    fn resolve_field_async<'a>(
+        &'a self,
+        field_name: &'a str,
+        args: &'a Arguments<S>,
+        executor: &'a Executor<C, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        self.value
+            .resolve_field_async(&self.info, field_name, args, executor)
+    }
+}
+
+/// Root subscription type of a merged [`RootNode`], resolving its fields.
+trait SubscriptionPart<C, S>: PartMeta<S> + Send + Sync {
+    fn resolve_field_into_stream<'s, 'args, 'e, 'ref_e, 'res, 'f>(
+        &'s self,
+        field_name: &'s str,
+        args: Arguments<'args, S>,
+        executor: &'ref_e Executor<'ref_e, 'e, C, S>,
+    ) -> BoxFuture<'f, Result<Value<ValuesStream<'res, S>>, FieldError<S>>>
+    where
+        's: 'res,
+        'args: 'f,
+        'ref_e: 'f,
+        'res: 'f,
+        'e: 'res;
+}
+
+impl<C, S, T> SubscriptionPart<C, S> for Part<T, T::TypeInfo>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+    T: GraphQLSubscriptionType<S, Context = C> + Send,
+    T::TypeInfo: Send + Sync,
+{
+    fn resolve_field_into_stream<'s, 'args, 'e, 'ref_e, 'res, 'f>(
+        &'s self,
+        field_name: &'s str,
+        args: Arguments<'args, S>,
+        executor: &'ref_e Executor<'ref_e, 'e, C, S>,
+    ) -> BoxFuture<'f, Result<Value<ValuesStream<'res, S>>, FieldError<S>>>
+    where
+        's: 'res,
+        'args: 'f,
+        'ref_e: 'f,
+        'res: 'f,
+        'e: 'res,
+    {
+        self.value
+            .resolve_field_into_stream(&self.info, field_name, args, executor)
+    }
+}
+
+/// Root query or mutation type of a [`MergedRootNode`].
+///
+/// Its fields are defined by its [`MergedObjectInfo`].
+pub struct MergedObject<C, S = DefaultScalarValue>(PhantomData<fn() -> (C, S)>);
+
+impl<C, S> fmt::Debug for MergedObject<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MergedObject").finish()
+    }
+}
+
+/// Type info of a [`MergedObject`], holding the root types of the merged
+/// [`RootNode`]s.
+pub struct MergedObjectInfo<C, S = DefaultScalarValue>(Merged<dyn ObjectPart<C, S>>);
+
+impl<C, S> fmt::Debug for MergedObjectInfo<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MergedObjectInfo").field(&self.0).finish()
+    }
+}
+
+impl<C, S> GraphQLType<S> for MergedObject<C, S>
+where
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(info.0.name)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let fields = info.0.fields(registry);
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<C, S> GraphQLValue<S> for MergedObject<C, S>
+where
+    S: ScalarValue,
+{
+    type Context = C;
+    type TypeInfo = MergedObjectInfo<C, S>;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        info.0.name.into()
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field_name: &str,
+        args: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match info.0.route(field_name) {
+            Some((part, name)) => part.resolve_field(name, args, executor),
+            None => Err(info.0.unknown_field(field_name)),
+        }
+    }
+}
+
+impl<C, S> GraphQLValueAsync<S> for MergedObject<C, S>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field_name: &'a str,
+        args: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        match info.0.route(field_name) {
+            Some((part, name)) => part.resolve_field_async(name, args, executor),
+            None => {
+                let err = info.0.unknown_field(field_name);
+                Box::pin(async move { Err(err) })
+            }
+        }
+    }
+}
+
+/// Root subscription type of a [`MergedRootNode`].
+///
+/// Its fields are defined by its [`MergedSubscriptionInfo`].
+pub struct MergedSubscription<C, S = DefaultScalarValue>(PhantomData<fn() -> (C, S)>);
+
+impl<C, S> fmt::Debug for MergedSubscription<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MergedSubscription").finish()
+    }
+}
+
+/// Type info of a [`MergedSubscription`], holding the root subscription types
+/// of the merged [`RootNode`]s.
+pub struct MergedSubscriptionInfo<C, S = DefaultScalarValue>(Merged<dyn SubscriptionPart<C, S>>);
+
+impl<C, S> fmt::Debug for MergedSubscriptionInfo<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MergedSubscriptionInfo")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<C, S> GraphQLType<S> for MergedSubscription<C, S>
+where
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(info.0.name)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let fields = info.0.fields(registry);
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<C, S> GraphQLValue<S> for MergedSubscription<C, S>
+where
+    S: ScalarValue,
+{
+    type Context = C;
+    type TypeInfo = MergedSubscriptionInfo<C, S>;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+}
+
+impl<C, S> GraphQLSubscriptionValue<S> for MergedSubscription<C, S>
+where
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_into_stream<'s, 'i, 'ft, 'args, 'e, 'ref_e, 'res, 'f>(
+        &'s self,
+        info: &'i Self::TypeInfo,
+        field_name: &'ft str,
+        args: Arguments<'args, S>,
+        executor: &'ref_e Executor<'ref_e, 'e, Self::Context, S>,
+    ) -> BoxFuture<'f, Result<Value<ValuesStream<'res, S>>, FieldError<S>>>
+    where
+        's: 'f,
+        'i: 'res,
+        'ft: 'f,
+        'args: 'f,
+        'ref_e: 'f,
+        'res: 'f,
+        'e: 'res,
+    {
+        match info.0.route(field_name) {
+            Some((part, name)) => part.resolve_field_into_stream(name, args, executor),
+            None => {
+                let err = info.0.unknown_field(field_name);
+                Box::pin(async move { Err(err) })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt as _;
+
+    use crate::{
+        graphql_object, graphql_subscription, graphql_value, graphql_vars, resolve_into_stream,
+        EmptyMutation, EmptySubscription, GraphQLObject, RootNode, Value,
+    };
+
+    use super::{SchemaMergeError, SchemaMerger};
+
+    #[derive(GraphQLObject)]
+    struct User {
+        name: String,
+    }
+
+    struct UsersQuery;
+
+    #[graphql_object]
+    impl UsersQuery {
+        fn users() -> Vec<User> {
+            vec![User {
+                name: "John".into(),
+            }]
+        }
+    }
+
+    struct UsersMutation;
+
+    #[graphql_object]
+    impl UsersMutation {
+        fn add_user(name: String) -> User {
+            User { name }
+        }
+    }
+
+    struct UsersSubscription;
+
+    type Stream = std::pin::Pin<Box<dyn futures::Stream<Item = User> + Send>>;
+
+    #[graphql_subscription]
+    impl UsersSubscription {
+        async fn user_added() -> Stream {
+            Box::pin(futures::stream::iter(vec![User {
+                name: "Jane".into(),
+            }]))
+        }
+    }
+
+    struct BillingQuery;
+
+    #[graphql_object]
+    impl BillingQuery {
+        fn users() -> i32 {
+            1
+        }
+
+        fn owner() -> User {
+            User {
+                name: "Alice".into(),
+            }
+        }
+    }
+
+    mod other {
+        use crate::{graphql_object, GraphQLObject};
+
+        #[derive(GraphQLObject)]
+        pub struct User {
+            id: i32,
+        }
+
+        pub struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn user() -> User {
+                User { id: 1 }
+            }
+        }
+    }
+
+    fn users() -> RootNode<'static, UsersQuery, UsersMutation, UsersSubscription> {
+        RootNode::new(UsersQuery, UsersMutation, UsersSubscription)
+    }
+
+    fn billing() -> RootNode<'static, BillingQuery, EmptyMutation, EmptySubscription> {
+        RootNode::new(BillingQuery, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[test]
+    fn merges_root_fields() {
+        let schema = SchemaMerger::<()>::new()
+            .merge(users())
+            .merge_namespaced("billing", billing())
+            .into_root_node()
+            .unwrap();
+
+        let res = crate::execute_sync(
+            "{ users { name } billing_users billing_owner { name } }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        );
+        assert_eq!(
+            res,
+            Ok((
+                graphql_value!({
+                    "users": [{"name": "John"}],
+                    "billing_users": 1,
+                    "billing_owner": {"name": "Alice"},
+                }),
+                vec![],
+            )),
+        );
+
+        let res = crate::execute_sync(
+            r#"mutation { addUser(name: "Bob") { name } }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        );
+        assert_eq!(
+            res,
+            Ok((graphql_value!({"addUser": {"name": "Bob"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn merges_root_fields_async() {
+        let schema = SchemaMerger::<()>::new()
+            .merge(users())
+            .merge_namespaced("billing", billing())
+            .into_root_node()
+            .unwrap();
+
+        let res = crate::execute(
+            "{ users { name } billing_users __typename }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        )
+        .await;
+        assert_eq!(
+            res,
+            Ok((
+                graphql_value!({
+                    "users": [{"name": "John"}],
+                    "billing_users": 1,
+                    "__typename": "Query",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn merges_subscriptions() {
+        let schema = SchemaMerger::<()>::new()
+            .merge(billing())
+            .merge_namespaced("users", users())
+            .into_root_node()
+            .unwrap();
+
+        let (res, errs) = resolve_into_stream(
+            "subscription { users_userAdded { name } }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        )
+        .await
+        .unwrap();
+        assert!(errs.is_empty());
+
+        let (name, stream) = res.into_object().unwrap().into_iter().next().unwrap();
+        assert_eq!(name, "users_userAdded");
+        let values = match stream {
+            Value::Scalar(stream) => stream.collect::<Vec<_>>().await,
+            _ => panic!("expected a stream"),
+        };
+        assert_eq!(values, vec![Ok(graphql_value!({"name": "Jane"}))]);
+    }
+
+    #[test]
+    fn omits_empty_root_types() {
+        let schema = SchemaMerger::<()>::new()
+            .merge(billing())
+            .into_root_node()
+            .unwrap();
+
+        assert_eq!(schema.schema.query_type_name, "Query");
+        assert_eq!(schema.schema.mutation_type_name, None);
+        assert_eq!(schema.schema.subscription_type_name, None);
+    }
+
+    #[test]
+    fn detects_field_conflicts() {
+        let res = SchemaMerger::<()>::new()
+            .merge(users())
+            .merge(billing())
+            .into_root_node();
+
+        assert_eq!(
+            res.unwrap_err(),
+            SchemaMergeError::FieldConflict {
+                type_name: "Query".into(),
+                field_name: "users".into(),
+            },
+        );
+    }
+
+    #[test]
+    fn detects_type_conflicts() {
+        let other = RootNode::new(
+            other::Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let res = SchemaMerger::<()>::new()
+            .merge(users())
+            .merge_namespaced("other", other)
+            .into_root_node();
+
+        assert_eq!(
+            res.unwrap_err(),
+            SchemaMergeError::TypeConflict {
+                type_name: "User".into(),
+            },
+        );
+    }
+}
@@ -2,8 +2,11 @@
 
 use juniper::IntoFieldError;
 use std::{
+    any::{Any, TypeId},
     borrow::{Cow, ToOwned},
+    collections::HashMap,
     fmt,
+    sync::Arc,
 };
 
 use crate::{
@@ -12,7 +15,7 @@ use crate::{
     schema::model::SchemaType,
     types::base::TypeKind,
     value::{DefaultScalarValue, ParseScalarValue},
-    FieldError,
+    Arguments, FieldError,
 };
 
 /// Whether an item is deprecated, with context.
@@ -42,6 +45,135 @@ impl DeprecationStatus {
     }
 }
 
+/// Typed annotations attached to a schema element (a [`MetaType`] or a
+/// [`Field`]), keyed by their types.
+///
+/// Gives extensions (authorization, caching, cost analysis and the like) a
+/// place to keep their per-type or per-field configuration right in the
+/// schema, rather than in separate maps keyed by type and field names.
+///
+/// ```rust
+/// # use juniper::meta::Metadata;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct CacheHint { max_age: u32 }
+///
+/// let mut metadata = Metadata::new();
+/// metadata.insert(CacheHint { max_age: 60 });
+///
+/// assert_eq!(metadata.get::<CacheHint>(), Some(&CacheHint { max_age: 60 }));
+/// assert_eq!(metadata.get::<String>(), None);
+/// ```
+#[derive(Clone, Default)]
+pub struct Metadata(HashMap<TypeId, Arc<dyn Any + Send + Sync>>);
+
+impl fmt::Debug for Metadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Metadata")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Metadata {
+    /// Creates a new empty [`Metadata`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the provided annotation `value`, replacing the annotation of
+    /// the same type, if any.
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) {
+        self.0.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Returns the annotation of the `T` type, if any.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|v| v.downcast_ref())
+    }
+
+    /// Indicates whether this [`Metadata`] contains an annotation of the `T`
+    /// type.
+    pub fn contains<T: Any + Send + Sync>(&self) -> bool {
+        self.0.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of annotations in this [`Metadata`].
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Indicates whether this [`Metadata`] contains no annotations.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the [`SchemaDirective`]s applied to the schema element of this
+    /// [`Metadata`].
+    pub fn directives(&self) -> &[SchemaDirective] {
+        self.get::<SchemaDirectives>()
+            .map(|d| d.0.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Applies the provided `directive` to the schema element of this
+    /// [`Metadata`], after the already applied ones.
+    pub fn push_directive(&mut self, directive: SchemaDirective) {
+        let mut directives = self.directives().to_vec();
+        directives.push(directive);
+        self.insert(SchemaDirectives(directives));
+    }
+}
+
+/// [`SchemaDirective`]s applied to a schema element, stored in its
+/// [`Metadata`].
+struct SchemaDirectives(Vec<SchemaDirective>);
+
+/// Directive applied to a schema element (a [`MetaType`] or a [`Field`]).
+///
+/// Isn't interpreted by [`juniper`](crate) in any way, but is exposed in the
+/// schema language representation of the schema, so tooling consuming it
+/// (schema composition, for example) can rely on it.
+///
+/// ```rust
+/// # use juniper::{meta::SchemaDirective, InputValue};
+/// #
+/// let key = SchemaDirective::new("key").argument("fields", InputValue::scalar("id"));
+///
+/// assert_eq!(key.name, "key");
+/// assert_eq!(key.arguments, vec![("fields".to_owned(), InputValue::scalar("id"))]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaDirective {
+    /// Name of the directive, without the leading `@`.
+    pub name: String,
+
+    /// Arguments the directive is applied with, in their order.
+    pub arguments: Vec<(String, InputValue)>,
+}
+
+impl SchemaDirective {
+    /// Creates a new [`SchemaDirective`] of the provided `name`, without any
+    /// arguments.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            arguments: Vec::new(),
+        }
+    }
+
+    /// Adds the argument of the provided `name` and `value` to this
+    /// [`SchemaDirective`].
+    #[must_use]
+    pub fn argument(mut self, name: impl Into<String>, value: InputValue) -> Self {
+        self.arguments.push((name.into(), value));
+        self
+    }
+}
+
 /// Scalar type metadata
 pub struct ScalarMeta<'a, S> {
     #[doc(hidden)]
@@ -52,6 +184,8 @@ pub struct ScalarMeta<'a, S> {
     pub specified_by_url: Option<Cow<'a, str>>,
     pub(crate) try_parse_fn: InputValueParseFn<S>,
     pub(crate) parse_fn: ScalarTokenParseFn<S>,
+    #[doc(hidden)]
+    pub metadata: Metadata,
 }
 
 /// Shortcut for an [`InputValue`] parsing function.
@@ -88,6 +222,8 @@ pub struct ObjectMeta<'a, S> {
     pub fields: Vec<Field<'a, S>>,
     #[doc(hidden)]
     pub interface_names: Vec<String>,
+    #[doc(hidden)]
+    pub metadata: Metadata,
 }
 
 /// Enum type metadata
@@ -99,6 +235,8 @@ pub struct EnumMeta<'a, S> {
     #[doc(hidden)]
     pub values: Vec<EnumValue>,
     pub(crate) try_parse_fn: InputValueParseFn<S>,
+    #[doc(hidden)]
+    pub metadata: Metadata,
 }
 
 /// Interface type metadata
@@ -112,6 +250,8 @@ pub struct InterfaceMeta<'a, S> {
     pub fields: Vec<Field<'a, S>>,
     #[doc(hidden)]
     pub interface_names: Vec<String>,
+    #[doc(hidden)]
+    pub metadata: Metadata,
 }
 
 /// Union type metadata
@@ -123,6 +263,8 @@ pub struct UnionMeta<'a> {
     pub description: Option<String>,
     #[doc(hidden)]
     pub of_type_names: Vec<String>,
+    #[doc(hidden)]
+    pub metadata: Metadata,
 }
 
 /// Input object metadata
@@ -134,6 +276,8 @@ pub struct InputObjectMeta<'a, S> {
     #[doc(hidden)]
     pub input_fields: Vec<Argument<'a, S>>,
     pub(crate) try_parse_fn: InputValueParseFn<S>,
+    #[doc(hidden)]
+    pub metadata: Metadata,
 }
 
 /// A placeholder for not-yet-registered types
@@ -169,6 +313,32 @@ pub enum MetaType<'a, S = DefaultScalarValue> {
     Placeholder(PlaceholderMeta<'a>),
 }
 
+/// Complexity of a [`Field`] in the [complexity analysis] of an operation.
+///
+/// [complexity analysis]: crate::RootNode::with_max_complexity
+#[derive(Clone)]
+pub enum Complexity<S> {
+    /// Own cost of the [`Field`], added to the complexity of its selection
+    /// set.
+    Static(usize),
+
+    /// Function calculating the whole complexity of the [`Field`] out of its
+    /// [`Arguments`] and the complexity of its selection set.
+    Fn(ComplexityFn<S>),
+}
+
+/// Shortcut for a [`Complexity`] calculating function.
+pub type ComplexityFn<S> = Arc<dyn Fn(&Arguments<'_, S>, usize) -> usize + Send + Sync>;
+
+impl<S> fmt::Debug for Complexity<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Static(cost) => f.debug_tuple("Static").field(cost).finish(),
+            Self::Fn(_) => f.debug_struct("Fn").finish_non_exhaustive(),
+        }
+    }
+}
+
 /// Metadata for a field
 #[derive(Debug, Clone)]
 pub struct Field<'a, S> {
@@ -182,6 +352,10 @@ pub struct Field<'a, S> {
     pub field_type: Type<'a>,
     #[doc(hidden)]
     pub deprecation_status: DeprecationStatus,
+    #[doc(hidden)]
+    pub complexity: Option<Complexity<S>>,
+    #[doc(hidden)]
+    pub metadata: Metadata,
 }
 
 impl<'a, S> Field<'a, S> {
@@ -190,6 +364,17 @@ impl<'a, S> Field<'a, S> {
         // "used exclusively by GraphQL’s introspection system"
         self.name.starts_with("__")
     }
+
+    /// Returns the annotation of the `T` type attached to this [`Field`], if
+    /// any.
+    pub fn annotation<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.metadata.get()
+    }
+
+    /// Returns the [`SchemaDirective`]s applied to this [`Field`].
+    pub fn directives(&self) -> &[SchemaDirective] {
+        self.metadata.directives()
+    }
 }
 
 /// Metadata for an argument to a field
@@ -203,6 +388,8 @@ pub struct Argument<'a, S> {
     pub arg_type: Type<'a>,
     #[doc(hidden)]
     pub default_value: Option<InputValue<S>>,
+    #[doc(hidden)]
+    pub deprecation_status: DeprecationStatus,
 }
 
 impl<'a, S> Argument<'a, S> {
@@ -227,6 +414,12 @@ pub struct EnumValue {
     pub description: Option<String>,
     /// Whether the field is deprecated or not, with an optional reason.
     pub deprecation_status: DeprecationStatus,
+    /// Alternative names of the enum value, accepted on input in addition to
+    /// its `name`.
+    ///
+    /// Aliases are not exposed via introspection, so they're useful for
+    /// keeping renamed enum values working for existing clients.
+    pub aliases: Vec<String>,
 }
 
 impl<'a, S> MetaType<'a, S> {
@@ -260,6 +453,47 @@ impl<'a, S> MetaType<'a, S> {
         }
     }
 
+    /// Accesses the typed [`Metadata`] annotating the type, if applicable.
+    ///
+    /// Lists, nullable wrappers, and placeholders don't have metadata.
+    pub fn metadata(&self) -> Option<&Metadata> {
+        match self {
+            MetaType::Scalar(ScalarMeta { metadata, .. })
+            | MetaType::Object(ObjectMeta { metadata, .. })
+            | MetaType::Enum(EnumMeta { metadata, .. })
+            | MetaType::Interface(InterfaceMeta { metadata, .. })
+            | MetaType::Union(UnionMeta { metadata, .. })
+            | MetaType::InputObject(InputObjectMeta { metadata, .. }) => Some(metadata),
+            _ => None,
+        }
+    }
+
+    /// Mutably accesses the typed [`Metadata`] annotating the type, if
+    /// applicable.
+    pub fn metadata_mut(&mut self) -> Option<&mut Metadata> {
+        match self {
+            MetaType::Scalar(ScalarMeta { metadata, .. })
+            | MetaType::Object(ObjectMeta { metadata, .. })
+            | MetaType::Enum(EnumMeta { metadata, .. })
+            | MetaType::Interface(InterfaceMeta { metadata, .. })
+            | MetaType::Union(UnionMeta { metadata, .. })
+            | MetaType::InputObject(InputObjectMeta { metadata, .. }) => Some(metadata),
+            _ => None,
+        }
+    }
+
+    /// Returns the annotation of the `T` type attached to the type, if any.
+    pub fn annotation<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.metadata()?.get()
+    }
+
+    /// Returns the [`SchemaDirective`]s applied to the type.
+    pub fn directives(&self) -> &[SchemaDirective] {
+        self.metadata()
+            .map(Metadata::directives)
+            .unwrap_or_default()
+    }
+
     /// Accesses the [specification URL][0], if applicable.
     ///
     /// Only custom GraphQL scalars can have a [specification URL][0].
@@ -447,6 +681,7 @@ impl<'a, S> ScalarMeta<'a, S> {
             specified_by_url: None,
             try_parse_fn: try_parse_fn::<S, T>,
             parse_fn: <T as ParseScalarValue<S>>::from_str,
+            metadata: Metadata::new(),
         }
     }
 
@@ -470,6 +705,24 @@ impl<'a, S> ScalarMeta<'a, S> {
         self
     }
 
+    /// Annotates this [`ScalarMeta`] type with the provided typed `value`,
+    /// retrievable via [`MetaType::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type.
+    #[must_use]
+    pub fn annotate<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.metadata.insert(value);
+        self
+    }
+
+    /// Applies the provided [`SchemaDirective`] to this [`ScalarMeta`] type, after
+    /// the already applied ones.
+    #[must_use]
+    pub fn directive(mut self, directive: SchemaDirective) -> Self {
+        self.metadata.push_directive(directive);
+        self
+    }
+
     /// Wraps this [`ScalarMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Scalar(self)
@@ -517,6 +770,7 @@ impl<'a, S> ObjectMeta<'a, S> {
             description: None,
             fields: fields.to_vec(),
             interface_names: vec![],
+            metadata: Metadata::new(),
         }
     }
 
@@ -541,6 +795,24 @@ impl<'a, S> ObjectMeta<'a, S> {
         self
     }
 
+    /// Annotates this [`ObjectMeta`] type with the provided typed `value`,
+    /// retrievable via [`MetaType::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type.
+    #[must_use]
+    pub fn annotate<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.metadata.insert(value);
+        self
+    }
+
+    /// Applies the provided [`SchemaDirective`] to this [`ObjectMeta`] type, after
+    /// the already applied ones.
+    #[must_use]
+    pub fn directive(mut self, directive: SchemaDirective) -> Self {
+        self.metadata.push_directive(directive);
+        self
+    }
+
     /// Wraps this [`ObjectMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Object(self)
@@ -560,6 +832,7 @@ impl<'a, S> EnumMeta<'a, S> {
             description: None,
             values: values.to_owned(),
             try_parse_fn: try_parse_fn::<S, T>,
+            metadata: Metadata::new(),
         }
     }
 
@@ -572,6 +845,24 @@ impl<'a, S> EnumMeta<'a, S> {
         self
     }
 
+    /// Annotates this [`EnumMeta`] type with the provided typed `value`,
+    /// retrievable via [`MetaType::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type.
+    #[must_use]
+    pub fn annotate<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.metadata.insert(value);
+        self
+    }
+
+    /// Applies the provided [`SchemaDirective`] to this [`EnumMeta`] type, after
+    /// the already applied ones.
+    #[must_use]
+    pub fn directive(mut self, directive: SchemaDirective) -> Self {
+        self.metadata.push_directive(directive);
+        self
+    }
+
     /// Wraps this [`EnumMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Enum(self)
@@ -590,6 +881,7 @@ impl<'a, S> InterfaceMeta<'a, S> {
             description: None,
             fields: fields.to_vec(),
             interface_names: Vec::new(),
+            metadata: Metadata::new(),
         }
     }
 
@@ -614,6 +906,24 @@ impl<'a, S> InterfaceMeta<'a, S> {
         self
     }
 
+    /// Annotates this [`InterfaceMeta`] type with the provided typed `value`,
+    /// retrievable via [`MetaType::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type.
+    #[must_use]
+    pub fn annotate<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.metadata.insert(value);
+        self
+    }
+
+    /// Applies the provided [`SchemaDirective`] to this [`InterfaceMeta`] type, after
+    /// the already applied ones.
+    #[must_use]
+    pub fn directive(mut self, directive: SchemaDirective) -> Self {
+        self.metadata.push_directive(directive);
+        self
+    }
+
     /// Wraps this [`InterfaceMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Interface(self)
@@ -628,6 +938,7 @@ impl<'a> UnionMeta<'a> {
             name,
             description: None,
             of_type_names: of_types.iter().map(|t| t.innermost_name().into()).collect(),
+            metadata: Metadata::new(),
         }
     }
 
@@ -640,6 +951,24 @@ impl<'a> UnionMeta<'a> {
         self
     }
 
+    /// Annotates this [`UnionMeta`] type with the provided typed `value`,
+    /// retrievable via [`MetaType::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type.
+    #[must_use]
+    pub fn annotate<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.metadata.insert(value);
+        self
+    }
+
+    /// Applies the provided [`SchemaDirective`] to this [`UnionMeta`] type, after
+    /// the already applied ones.
+    #[must_use]
+    pub fn directive(mut self, directive: SchemaDirective) -> Self {
+        self.metadata.push_directive(directive);
+        self
+    }
+
     /// Wraps this [`UnionMeta`] type into a generic [`MetaType`].
     pub fn into_meta<S>(self) -> MetaType<'a, S> {
         MetaType::Union(self)
@@ -660,6 +989,7 @@ impl<'a, S> InputObjectMeta<'a, S> {
             description: None,
             input_fields: input_fields.to_vec(),
             try_parse_fn: try_parse_fn::<S, T>,
+            metadata: Metadata::new(),
         }
     }
 
@@ -672,6 +1002,24 @@ impl<'a, S> InputObjectMeta<'a, S> {
         self
     }
 
+    /// Annotates this [`InputObjectMeta`] type with the provided typed `value`,
+    /// retrievable via [`MetaType::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type.
+    #[must_use]
+    pub fn annotate<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.metadata.insert(value);
+        self
+    }
+
+    /// Applies the provided [`SchemaDirective`] to this [`InputObjectMeta`] type, after
+    /// the already applied ones.
+    #[must_use]
+    pub fn directive(mut self, directive: SchemaDirective) -> Self {
+        self.metadata.push_directive(directive);
+        self
+    }
+
     /// Wraps this [`InputObjectMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::InputObject(self)
@@ -712,6 +1060,54 @@ impl<'a, S> Field<'a, S> {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
         self
     }
+
+    /// Sets the `complexity` of this [`Field`], being its own cost in the
+    /// [complexity analysis] of an operation.
+    ///
+    /// Defaults to `1`. Overwrites any previously set complexity.
+    ///
+    /// [complexity analysis]: crate::RootNode::with_max_complexity
+    #[must_use]
+    pub fn complexity(mut self, complexity: usize) -> Self {
+        self.complexity = Some(Complexity::Static(complexity));
+        self
+    }
+
+    /// Sets the function calculating the whole complexity of this [`Field`]
+    /// in the [complexity analysis] of an operation, out of its [`Arguments`]
+    /// and the complexity of its selection set.
+    ///
+    /// Allows weighting the fields returning lists or fanning out otherwise,
+    /// as the complexity of the selection set isn't multiplied automatically
+    /// then. Overwrites any previously set complexity.
+    ///
+    /// [complexity analysis]: crate::RootNode::with_max_complexity
+    #[must_use]
+    pub fn complexity_with<F>(mut self, complexity: F) -> Self
+    where
+        F: Fn(&Arguments<'_, S>, usize) -> usize + Send + Sync + 'static,
+    {
+        self.complexity = Some(Complexity::Fn(Arc::new(complexity)));
+        self
+    }
+
+    /// Annotates this [`Field`] with the provided typed `value`, retrievable
+    /// via [`Field::annotation()`].
+    ///
+    /// Overwrites any previously set annotation of the same type.
+    #[must_use]
+    pub fn annotate<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.metadata.insert(value);
+        self
+    }
+
+    /// Applies the provided [`SchemaDirective`] to this [`Field`], after
+    /// the already applied ones.
+    #[must_use]
+    pub fn directive(mut self, directive: SchemaDirective) -> Self {
+        self.metadata.push_directive(directive);
+        self
+    }
 }
 
 impl<'a, S> Argument<'a, S> {
@@ -722,6 +1118,7 @@ impl<'a, S> Argument<'a, S> {
             description: None,
             arg_type,
             default_value: None,
+            deprecation_status: DeprecationStatus::Current,
         }
     }
 
@@ -742,6 +1139,15 @@ impl<'a, S> Argument<'a, S> {
         self.default_value = Some(val);
         self
     }
+
+    /// Sets this [`Argument`] as deprecated with an optional `reason`.
+    ///
+    /// Overwrites any previously set deprecation reason.
+    #[must_use]
+    pub fn deprecated(mut self, reason: Option<&str>) -> Self {
+        self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
+        self
+    }
 }
 
 impl EnumValue {
@@ -751,6 +1157,7 @@ impl EnumValue {
             name: name.into(),
             description: None,
             deprecation_status: DeprecationStatus::Current,
+            aliases: Vec::new(),
         }
     }
 
@@ -771,6 +1178,20 @@ impl EnumValue {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
         self
     }
+
+    /// Adds the provided `alias` to this [`EnumValue`], so it's accepted on
+    /// input in addition to the [`EnumValue`]'s name.
+    #[must_use]
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Indicates whether the provided `name` is either the name or an alias of
+    /// this [`EnumValue`].
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name || self.aliases.iter().any(|a| a == name)
+    }
 }
 
 impl<'a, S: fmt::Debug> fmt::Debug for ScalarMeta<'a, S> {
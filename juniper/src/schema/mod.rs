@@ -1,6 +1,12 @@
 #![allow(clippy::module_inception)]
 
+pub mod diff;
+pub mod document_cache;
+pub mod dynamic;
+pub mod example;
+pub mod merge;
 pub mod meta;
 pub mod model;
 pub mod schema;
 pub mod translate;
+pub mod visitor;
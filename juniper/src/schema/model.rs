@@ -1,21 +1,38 @@
-use std::{borrow::Cow, fmt};
+use std::{any::Any, borrow::Cow, error::Error, fmt, mem, sync::Arc};
 
 use fnv::FnvHashMap;
+use futures::StreamExt as _;
 #[cfg(feature = "graphql-parser")]
 use graphql_parser::schema::Document;
 
 use crate::{
-    ast::Type,
-    executor::{Context, Registry},
-    schema::meta::{Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta},
+    ast::{InputValue, Type},
+    executor::{
+        Context, DirectiveHandler, DirectiveHandlers, ExecutionError, ExecutionOptions, Extension,
+        Instrumentation, Registry, ValuesStream, DEFER_DIRECTIVE, DRY_RUN_DIRECTIVE,
+    },
+    schema::{
+        diff::{diff_schemas, SchemaChange},
+        document_cache::DocumentCache,
+        example::{example_operation, ExampleOperation},
+        meta::{Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta},
+        visitor::SchemaVisitor,
+    },
     types::{base::GraphQLType, name::Name},
-    value::{DefaultScalarValue, ScalarValue},
-    GraphQLEnum,
+    validation::{IntrospectionLimits, OperationLimits, PaginationBounds},
+    value::{DefaultScalarValue, ScalarValue, Value},
+    GraphQLEnum, GraphQLError,
 };
 
+#[cfg(feature = "schema-language")]
+use crate::schema::translate::graphql_parser::sort_document;
 #[cfg(feature = "graphql-parser")]
 use crate::schema::translate::{graphql_parser::GraphQLParserTranslator, SchemaTranslator};
 
+/// Name of the directive advertising the expected size of the lists returned
+/// by a field.
+pub(crate) const LIST_SIZE_DIRECTIVE: &str = "listSize";
+
 /// Root query node of a schema
 ///
 /// This brings the mutation, subscription and query types together,
@@ -44,6 +61,53 @@ pub struct RootNode<
     pub subscription_info: SubscriptionT::TypeInfo,
     #[doc(hidden)]
     pub schema: SchemaType<'a, S>,
+    execution_options: ExecutionOptions,
+    max_complexity: Option<usize>,
+    max_depth: Option<usize>,
+    pagination_bounds: Option<PaginationBounds>,
+    introspection_limits: Option<IntrospectionLimits>,
+    operation_limits: OperationLimits,
+    introspection_disabled: bool,
+    error_mapper: Option<ErrorMapper<S>>,
+    document_cache: Option<DocumentCache<S>>,
+}
+
+/// Function transforming [`ExecutionError`]s of the operations executed
+/// against a [`RootNode`].
+///
+/// See [`RootNode::map_errors()`] for details.
+#[derive(Clone)]
+struct ErrorMapper<S>(Arc<dyn Fn(ExecutionError<S>) -> ExecutionError<S> + Send + Sync>);
+
+impl<S> fmt::Debug for ErrorMapper<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorMapper").finish_non_exhaustive()
+    }
+}
+
+/// Transforms the [`ExecutionError`]s yielded by all the provided subscription
+/// `streams` with the provided `mapper`.
+fn map_stream_errors<'s, S: Send + 's>(
+    streams: Value<ValuesStream<'s, S>>,
+    mapper: &ErrorMapper<S>,
+) -> Value<ValuesStream<'s, S>> {
+    match streams {
+        Value::Null => Value::Null,
+        Value::Scalar(stream) => {
+            let f = mapper.0.clone();
+            Value::Scalar(Box::pin(stream.map(move |res| res.map_err(&*f))))
+        }
+        Value::List(list) => Value::List(
+            list.into_iter()
+                .map(|v| map_stream_errors(v, mapper))
+                .collect(),
+        ),
+        Value::Object(obj) => Value::Object(
+            obj.into_iter()
+                .map(|(k, v)| (k, map_stream_errors(v, mapper)))
+                .collect(),
+        ),
+    }
 }
 
 /// Metadata for a schema
@@ -55,10 +119,119 @@ pub struct SchemaType<'a, S> {
     pub(crate) mutation_type_name: Option<String>,
     pub(crate) subscription_type_name: Option<String>,
     directives: FnvHashMap<String, DirectiveType<'a, S>>,
+    pub(crate) directive_handlers: DirectiveHandlers<S>,
 }
 
 impl<'a, S> Context for SchemaType<'a, S> {}
 
+/// Error of building a [`SchemaType`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaError {
+    /// Different Rust types register the same type name, while defining
+    /// different types.
+    ConflictingType {
+        /// Name of the conflicting types.
+        name: String,
+
+        /// Fields (or enum values, or union members) of the firstly
+        /// registered type, absent in the secondly registered one.
+        first: Vec<String>,
+
+        /// Fields (or enum values, or union members) of the secondly
+        /// registered type, absent in the firstly registered one.
+        second: Vec<String>,
+    },
+}
+
+impl SchemaError {
+    /// Returns a [`SchemaError::ConflictingType`] if the provided types
+    /// registered under the same name are defined differently.
+    pub(crate) fn conflicting_type<S: ScalarValue>(
+        first: &MetaType<'_, S>,
+        second: &MetaType<'_, S>,
+    ) -> Option<Self> {
+        let name = first.name()?.to_owned();
+        let (first_members, second_members) = (member_signatures(first), member_signatures(second));
+        if mem::discriminant(first) != mem::discriminant(second) {
+            return Some(Self::ConflictingType {
+                name,
+                first: first_members,
+                second: second_members,
+            });
+        }
+
+        let first = first_members
+            .iter()
+            .filter(|m| !second_members.contains(m))
+            .cloned()
+            .collect::<Vec<_>>();
+        let second = second_members
+            .iter()
+            .filter(|m| !first_members.contains(m))
+            .cloned()
+            .collect::<Vec<_>>();
+        (!first.is_empty() || !second.is_empty()).then_some(Self::ConflictingType {
+            name,
+            first,
+            second,
+        })
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictingType {
+                name,
+                first,
+                second,
+            } => write!(
+                f,
+                "Type `{name}` is defined differently by several Rust types: \
+                 [{}] conflicts with [{}]",
+                first.join(", "),
+                second.join(", "),
+            ),
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+/// Returns textual representations of the members (fields, enum values or
+/// union members) of the provided type definition.
+fn member_signatures<S: ScalarValue>(meta: &MetaType<'_, S>) -> Vec<String> {
+    fn argument<S: ScalarValue>(arg: &Argument<'_, S>) -> String {
+        match &arg.default_value {
+            Some(default) => format!("{}: {} = {default}", arg.name, arg.arg_type),
+            None => format!("{}: {}", arg.name, arg.arg_type),
+        }
+    }
+
+    match meta {
+        MetaType::Object(ObjectMeta { fields, .. })
+        | MetaType::Interface(InterfaceMeta { fields, .. }) => fields
+            .iter()
+            .map(|f| match f.arguments.as_deref() {
+                Some(args) if !args.is_empty() => {
+                    let args = args.iter().map(argument).collect::<Vec<_>>();
+                    format!("{}({}): {}", f.name, args.join(", "), f.field_type)
+                }
+                _ => format!("{}: {}", f.name, f.field_type),
+            })
+            .collect(),
+        MetaType::InputObject(m) => m.input_fields.iter().map(argument).collect(),
+        MetaType::Enum(m) => m.values.iter().map(|v| v.name.to_string()).collect(),
+        MetaType::Union(UnionMeta { of_type_names, .. }) => of_type_names.clone(),
+        MetaType::Scalar(_)
+        | MetaType::List(_)
+        | MetaType::Nullable(_)
+        | MetaType::Placeholder(_) => {
+            vec![]
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum TypeType<'a, S: 'a> {
     Concrete(&'a MetaType<'a, S>),
@@ -95,6 +268,17 @@ pub enum DirectiveLocation {
     InlineFragment,
     #[graphql(name = "ENUM_VALUE")]
     EnumValue,
+    #[graphql(name = "ARGUMENT_DEFINITION")]
+    ArgumentDefinition,
+    #[graphql(name = "INPUT_FIELD_DEFINITION")]
+    InputFieldDefinition,
+    Schema,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    #[graphql(name = "INPUT_OBJECT")]
+    InputObject,
 }
 
 impl<'a, QueryT, MutationT, SubscriptionT>
@@ -109,6 +293,20 @@ where
     pub fn new(query: QueryT, mutation: MutationT, subscription: SubscriptionT) -> Self {
         Self::new_with_info(query, mutation, subscription, (), (), ())
     }
+
+    /// Constructs a new [`RootNode`] from `query`, `mutation` and `subscription` nodes,
+    /// parametrizing it with a [`DefaultScalarValue`].
+    ///
+    /// # Errors
+    ///
+    /// See [`RootNode::try_new_with_info()`] for details.
+    pub fn try_new(
+        query: QueryT,
+        mutation: MutationT,
+        subscription: SubscriptionT,
+    ) -> Result<Self, SchemaError> {
+        Self::try_new_with_info(query, mutation, subscription, (), (), ())
+    }
 }
 
 impl<'a, QueryT, MutationT, SubscriptionT, S> RootNode<'a, QueryT, MutationT, SubscriptionT, S>
@@ -146,22 +344,456 @@ where
         query_info: QueryT::TypeInfo,
         mutation_info: MutationT::TypeInfo,
         subscription_info: SubscriptionT::TypeInfo,
+    ) -> Self {
+        let schema = SchemaType::new::<QueryT, MutationT, SubscriptionT>(
+            &query_info,
+            &mutation_info,
+            &subscription_info,
+        );
+        Self::from_schema(
+            query_obj,
+            mutation_obj,
+            subscription_obj,
+            schema,
+            query_info,
+            mutation_info,
+            subscription_info,
+        )
+    }
+
+    /// Construct a new root node from query and mutation nodes,
+    /// while also providing type info objects for the query and
+    /// mutation types.
+    ///
+    /// # Errors
+    ///
+    /// If different Rust types register the same type name, while defining
+    /// different types (which [`RootNode::new_with_info()`] silently resolves
+    /// by keeping the firstly registered one), returns a
+    /// [`SchemaError::ConflictingType`] listing their differing fields.
+    pub fn try_new_with_info(
+        query_obj: QueryT,
+        mutation_obj: MutationT,
+        subscription_obj: SubscriptionT,
+        query_info: QueryT::TypeInfo,
+        mutation_info: MutationT::TypeInfo,
+        subscription_info: SubscriptionT::TypeInfo,
+    ) -> Result<Self, SchemaError> {
+        let schema = SchemaType::try_new::<QueryT, MutationT, SubscriptionT>(
+            &query_info,
+            &mutation_info,
+            &subscription_info,
+        )?;
+        Ok(Self::from_schema(
+            query_obj,
+            mutation_obj,
+            subscription_obj,
+            schema,
+            query_info,
+            mutation_info,
+            subscription_info,
+        ))
+    }
+
+    fn from_schema(
+        query_obj: QueryT,
+        mutation_obj: MutationT,
+        subscription_obj: SubscriptionT,
+        schema: SchemaType<'a, S>,
+        query_info: QueryT::TypeInfo,
+        mutation_info: MutationT::TypeInfo,
+        subscription_info: SubscriptionT::TypeInfo,
     ) -> Self {
         RootNode {
             query_type: query_obj,
             mutation_type: mutation_obj,
             subscription_type: subscription_obj,
-            schema: SchemaType::new::<QueryT, MutationT, SubscriptionT>(
-                &query_info,
-                &mutation_info,
-                &subscription_info,
-            ),
+            schema,
             query_info,
             mutation_info,
             subscription_info,
+            execution_options: ExecutionOptions::default(),
+            max_complexity: None,
+            max_depth: None,
+            pagination_bounds: None,
+            introspection_limits: None,
+            operation_limits: OperationLimits::default(),
+            introspection_disabled: false,
+            error_mapper: None,
+            document_cache: None,
+        }
+    }
+
+    /// Registers the provided `T` type in the schema of this [`RootNode`],
+    /// even if it isn't reachable from its root types.
+    ///
+    /// See [`SchemaType::register_type()`] for details.
+    #[must_use]
+    pub fn with_type<T>(mut self, info: &T::TypeInfo) -> Self
+    where
+        T: GraphQLType<S> + ?Sized,
+    {
+        self.schema.register_type::<T>(info);
+        self
+    }
+
+    /// Sets the default [`ExecutionOptions`] used for executing operations
+    /// against this [`RootNode`].
+    ///
+    /// Overwrites any previously set [`ExecutionOptions`].
+    #[must_use]
+    pub fn with_execution_options(mut self, options: ExecutionOptions) -> Self {
+        self.execution_options = options;
+        self
+    }
+
+    /// Returns the default [`ExecutionOptions`] used for executing operations
+    /// against this [`RootNode`].
+    pub fn execution_options(&self) -> &ExecutionOptions {
+        &self.execution_options
+    }
+
+    /// Registers the provided [`Extension`] in the default
+    /// [`ExecutionOptions`] of this [`RootNode`].
+    ///
+    /// See [`ExecutionOptions::extension()`] for details.
+    #[must_use]
+    pub fn with_extension(mut self, extension: impl Extension + 'static) -> Self {
+        self.execution_options = self.execution_options.extension(extension);
+        self
+    }
+
+    /// Registers the provided [`Instrumentation`] in the default
+    /// [`ExecutionOptions`] of this [`RootNode`].
+    ///
+    /// See [`ExecutionOptions::instrumentation()`] for details.
+    #[must_use]
+    pub fn with_instrumentation(mut self, instrumentation: impl Instrumentation + 'static) -> Self {
+        self.execution_options = self.execution_options.instrumentation(instrumentation);
+        self
+    }
+
+    /// Inserts the provided `value` into the [`Data`] of the default
+    /// [`ExecutionOptions`] of this [`RootNode`], so it's available to all
+    /// its resolvers via [`Executor::data()`].
+    ///
+    /// See [`ExecutionOptions::data()`] for details.
+    ///
+    /// [`Data`]: crate::Data
+    /// [`Executor::data()`]: crate::Executor::data
+    #[must_use]
+    pub fn with_data<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.execution_options = self.execution_options.data(value);
+        self
+    }
+
+    /// Sets the function transforming every [`ExecutionError`] of the
+    /// operations executed against this [`RootNode`], before they're returned
+    /// to the caller.
+    ///
+    /// Useful for redacting internal error messages in production, without
+    /// wrapping the error type of every resolver:
+    /// ```rust
+    /// # use juniper::{EmptyMutation, EmptySubscription, FieldError, RootNode};
+    /// #
+    /// # struct Query;
+    /// #
+    /// # #[juniper::graphql_object]
+    /// # impl Query {
+    /// #     fn ping() -> bool { true }
+    /// # }
+    /// #
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new())
+    ///     .map_errors(|err| {
+    ///         // Keeps the `requestId` extension, if stamped, for correlating
+    ///         // the error with the server logs.
+    ///         err.map_error(|e| FieldError::new("Internal server error", e.extensions().clone()))
+    ///     });
+    /// ```
+    ///
+    /// Errors of `@defer`red fragments, subscription events and re-executed
+    /// live queries are transformed too, while errors preventing the execution
+    /// at all (like parsing or validation ones) are not.
+    ///
+    /// Overwrites any previously set function.
+    #[must_use]
+    pub fn map_errors(
+        mut self,
+        f: impl Fn(ExecutionError<S>) -> ExecutionError<S> + Send + Sync + 'static,
+    ) -> Self {
+        self.error_mapper = Some(ErrorMapper(Arc::new(f)));
+        self
+    }
+
+    /// Transforms the [`ExecutionError`]s of the provided execution `res`ult
+    /// with the function set via [`RootNode::map_errors()`], if any.
+    pub(crate) fn mapped_errors<T>(
+        &self,
+        res: Result<(T, Vec<ExecutionError<S>>), GraphQLError>,
+    ) -> Result<(T, Vec<ExecutionError<S>>), GraphQLError> {
+        match &self.error_mapper {
+            Some(ErrorMapper(f)) => {
+                res.map(|(value, errs)| (value, errs.into_iter().map(&**f).collect()))
+            }
+            None => res,
+        }
+    }
+
+    /// Transforms the [`ExecutionError`]s yielded by the provided subscription
+    /// `streams` with the function set via [`RootNode::map_errors()`], if any.
+    pub(crate) fn mapped_stream_errors<'s>(
+        &self,
+        streams: Value<ValuesStream<'s, S>>,
+    ) -> Value<ValuesStream<'s, S>>
+    where
+        S: Send + 's,
+    {
+        match &self.error_mapper {
+            Some(mapper) => map_stream_errors(streams, mapper),
+            None => streams,
         }
     }
 
+    /// Caches up to `capacity` documents executed against this [`RootNode`]
+    /// (see [`DocumentCache`] for details), so repeated identical operations
+    /// aren't parsed and validated against the schema again.
+    ///
+    /// Overwrites any previously set [`DocumentCache`].
+    #[must_use]
+    pub fn with_document_cache(mut self, capacity: usize) -> Self {
+        self.document_cache = Some(DocumentCache::new(capacity));
+        self
+    }
+
+    /// Returns the [`DocumentCache`] of this [`RootNode`], if it has one.
+    pub fn document_cache(&self) -> Option<&DocumentCache<S>> {
+        self.document_cache.as_ref()
+    }
+
+    /// Sets the maximum complexity of operations allowed to be executed
+    /// against this [`RootNode`].
+    ///
+    /// Complexity of every operation is estimated before its execution (see
+    /// [`operation_complexity()`] for details), and the operations exceeding
+    /// the provided `max` are rejected with a
+    /// [`GraphQLError::ComplexityLimitExceeded`].
+    ///
+    /// [`GraphQLError::ComplexityLimitExceeded`]: crate::GraphQLError::ComplexityLimitExceeded
+    /// [`operation_complexity()`]: crate::validation::operation_complexity
+    #[must_use]
+    pub fn with_max_complexity(mut self, max: usize) -> Self {
+        self.max_complexity = Some(max);
+        self
+    }
+
+    /// Returns the maximum complexity of operations allowed to be executed
+    /// against this [`RootNode`], if any.
+    pub fn max_complexity(&self) -> Option<usize> {
+        self.max_complexity
+    }
+
+    /// Sets the maximum depth of operations allowed to be executed against
+    /// this [`RootNode`].
+    ///
+    /// Operations having fields nested deeper than the provided `max` (with
+    /// the root fields being at depth `1`, and fragment spreads expanded) are
+    /// rejected with a [`GraphQLError::ValidationError`].
+    ///
+    /// [`GraphQLError::ValidationError`]: crate::GraphQLError::ValidationError
+    #[must_use]
+    pub fn with_max_depth(mut self, max: usize) -> Self {
+        self.max_depth = Some(max);
+        self
+    }
+
+    /// Returns the maximum depth of operations allowed to be executed against
+    /// this [`RootNode`], if any.
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Sets the [`PaginationBounds`] of the operations allowed to be executed
+    /// against this [`RootNode`].
+    ///
+    /// Operations having a pagination argument of any field (including the
+    /// ones provided via variables) out of the provided `bounds` are rejected
+    /// with a [`GraphQLError::ValidationError`] before any of their resolvers
+    /// run. Omitted and `null` arguments are left up to the resolvers to
+    /// default.
+    ///
+    /// [`GraphQLError::ValidationError`]: crate::GraphQLError::ValidationError
+    #[must_use]
+    pub fn with_pagination_bounds(mut self, bounds: PaginationBounds) -> Self {
+        self.pagination_bounds = Some(bounds);
+        self
+    }
+
+    /// Returns the [`PaginationBounds`] of the operations allowed to be
+    /// executed against this [`RootNode`], if any.
+    pub fn pagination_bounds(&self) -> Option<&PaginationBounds> {
+        self.pagination_bounds.as_ref()
+    }
+
+    /// Sets the [`IntrospectionLimits`] of the operations allowed to be
+    /// executed against this [`RootNode`].
+    ///
+    /// Operations exceeding the provided `limits` in their `__schema` or
+    /// `__type` selections (with fragment spreads expanded) are rejected with
+    /// a [`GraphQLError::ValidationError`] pointing to the first field
+    /// exceeding them, while the rest of their selections are left to the
+    /// [general depth limit](RootNode::with_max_depth).
+    ///
+    /// [`GraphQLError::ValidationError`]: crate::GraphQLError::ValidationError
+    #[must_use]
+    pub fn with_introspection_limits(mut self, limits: IntrospectionLimits) -> Self {
+        self.introspection_limits = Some(limits);
+        self
+    }
+
+    /// Returns the [`IntrospectionLimits`] of the operations allowed to be
+    /// executed against this [`RootNode`], if any.
+    pub fn introspection_limits(&self) -> Option<&IntrospectionLimits> {
+        self.introspection_limits.as_ref()
+    }
+
+    /// Sets the [`OperationLimits`] of the operations allowed to be executed
+    /// against this [`RootNode`].
+    ///
+    /// Operations having more aliases, root fields or directives than the
+    /// provided `limits` allow are rejected with a
+    /// [`GraphQLError::ValidationError`] before any of their resolvers run.
+    /// Fragment spreads are expanded, so a fragment spread twice is accounted
+    /// twice. Every exceeded limit is reported with a separate error, pointing
+    /// to the first alias, root field or directive exceeding it.
+    ///
+    /// [`GraphQLError::ValidationError`]: crate::GraphQLError::ValidationError
+    #[must_use]
+    pub fn with_operation_limits(mut self, limits: OperationLimits) -> Self {
+        self.operation_limits = limits;
+        self
+    }
+
+    /// Returns the [`OperationLimits`] of the operations allowed to be
+    /// executed against this [`RootNode`].
+    pub fn operation_limits(&self) -> &OperationLimits {
+        &self.operation_limits
+    }
+
+    /// Disables introspection of this [`RootNode`].
+    ///
+    /// Operations selecting the `__schema` or `__type` fields are rejected
+    /// with a [`GraphQLError::ValidationError`], while `__typename` keeps
+    /// working, as clients rely on it for resolving abstract types. May be
+    /// overridden for a single request via
+    /// [`ExecutionOptions::introspection()`].
+    ///
+    /// [`GraphQLError::ValidationError`]: crate::GraphQLError::ValidationError
+    #[must_use]
+    pub fn disable_introspection(mut self) -> Self {
+        self.introspection_disabled = true;
+        self
+    }
+
+    /// Indicates whether introspection of this [`RootNode`] is
+    /// [disabled](RootNode::disable_introspection).
+    pub fn is_introspection_disabled(&self) -> bool {
+        self.introspection_disabled
+    }
+
+    /// Generates an example operation selecting the field at the provided
+    /// `path` of this [`RootNode`], like `Query.hero.friends`.
+    ///
+    /// See [`example_operation()`] for details.
+    ///
+    /// [`example_operation()`]: crate::schema::example::example_operation
+    pub fn example_operation(&self, path: &str, max_depth: usize) -> Option<ExampleOperation<S>> {
+        example_operation(&self.schema, path, max_depth)
+    }
+
+    /// Registers the `@dryRun` directive in this [`RootNode`], allowing
+    /// clients to execute a single mutation in
+    /// [dry-run mode](ExecutionOptions::dry_run).
+    #[must_use]
+    pub fn enable_dry_run_directive(mut self) -> Self {
+        self.schema.add_directive(DirectiveType::new_dry_run());
+        self
+    }
+
+    /// Registers the `@defer` directive in this [`RootNode`], allowing
+    /// clients to mark fragments to be delivered incrementally via
+    /// [`execute_incremental()`].
+    ///
+    /// [`execute_incremental()`]: crate::execute_incremental
+    #[must_use]
+    pub fn enable_defer_directive(mut self) -> Self {
+        self.schema.add_directive(DirectiveType::new_defer());
+        self
+    }
+
+    /// Registers the provided custom executable `directive` in this
+    /// [`RootNode`], along with the `handler` applied to every field the
+    /// `directive` is specified on.
+    ///
+    /// The `directive` is exposed via introspection, and its locations and
+    /// arguments are validated as for any built-in directive. Its `handler` is
+    /// applied to [`DirectiveLocation::Field`]s only.
+    ///
+    /// See [`DirectiveHandler`] for details.
+    #[must_use]
+    pub fn with_directive(
+        mut self,
+        directive: DirectiveType<'a, S>,
+        handler: impl DirectiveHandler<S> + 'static,
+    ) -> Self {
+        self.schema
+            .directive_handlers
+            .insert(&directive.name, Arc::new(handler));
+        self.schema.add_directive(directive);
+        self
+    }
+
+    /// Registers the provided custom `directive` definition in this
+    /// [`RootNode`], exposing it via introspection and in the schema language
+    /// representation of the schema.
+    ///
+    /// Unlike [`RootNode::with_directive()`], doesn't attach any handler to the
+    /// `directive`, so it's intended for type system directives (applied to
+    /// types and fields via [`SchemaDirective`]s), interpreted by the tooling
+    /// consuming the schema rather than by [`juniper`](crate) itself.
+    ///
+    /// Overwrites any previously registered directive of the same name.
+    ///
+    /// [`SchemaDirective`]: crate::meta::SchemaDirective
+    #[must_use]
+    pub fn with_schema_directive(mut self, directive: DirectiveType<'a, S>) -> Self {
+        self.schema.add_directive(directive);
+        self
+    }
+
+    /// Walks the provided `visitor` over all the definitions of the schema of
+    /// this [`RootNode`].
+    ///
+    /// See [`SchemaVisitor`] for details.
+    pub fn visit<V: SchemaVisitor<S> + ?Sized>(&self, visitor: &mut V) {
+        self.schema.visit(visitor)
+    }
+
+    /// Computes the [`SchemaChange`]s between the schema of this [`RootNode`]
+    /// and the one of the `new` [`RootNode`].
+    ///
+    /// See [`diff_schemas()`] for details.
+    pub fn diff<NewQueryT, NewMutationT, NewSubscriptionT>(
+        &self,
+        new: &RootNode<'_, NewQueryT, NewMutationT, NewSubscriptionT, S>,
+    ) -> Vec<SchemaChange>
+    where
+        NewQueryT: GraphQLType<S>,
+        NewMutationT: GraphQLType<S>,
+        NewSubscriptionT: GraphQLType<S>,
+    {
+        diff_schemas(&self.schema, &new.schema)
+    }
+
     #[cfg(feature = "schema-language")]
     /// The schema definition as a `String` in the
     /// [GraphQL Schema Language](https://graphql.org/learn/schema/#type-language)
@@ -176,6 +808,26 @@ where
     pub fn as_parser_document(&'a self) -> Document<'a, &'a str> {
         GraphQLParserTranslator::translate_schema(&self.schema)
     }
+
+    #[cfg(feature = "schema-language")]
+    /// Deterministic hash of the [`RootNode::as_schema_language()`]
+    /// representation of this schema, normalized to not depend on the order
+    /// its types, fields, arguments and enum values are registered in.
+    ///
+    /// Remains the same across builds and processes for the same schema, so is
+    /// suitable as a cache key, an `ETag` of introspection responses, or a
+    /// fingerprint of a deployed schema. Isn't cached, so prefer computing it
+    /// once per [`RootNode`].
+    pub fn schema_hash(&self) -> u64 {
+        use std::hash::Hasher as _;
+
+        let mut doc = self.as_parser_document();
+        sort_document(&mut doc);
+
+        let mut hasher = fnv::FnvHasher::default();
+        hasher.write(doc.to_string().as_bytes());
+        hasher.finish()
+    }
 }
 
 impl<'a, S> SchemaType<'a, S> {
@@ -191,8 +843,56 @@ impl<'a, S> SchemaType<'a, S> {
         MutationT: GraphQLType<S>,
         SubscriptionT: GraphQLType<S>,
     {
-        let mut directives = FnvHashMap::default();
+        Self::build::<QueryT, MutationT, SubscriptionT>(
+            &mut Registry::new(FnvHashMap::default()),
+            query_info,
+            mutation_info,
+            subscription_info,
+        )
+    }
+
+    /// Create a new schema, failing if different Rust types register the
+    /// same type name, while defining different types.
+    ///
+    /// # Errors
+    ///
+    /// With a [`SchemaError::ConflictingType`] describing the first detected
+    /// conflict.
+    pub fn try_new<QueryT, MutationT, SubscriptionT>(
+        query_info: &QueryT::TypeInfo,
+        mutation_info: &MutationT::TypeInfo,
+        subscription_info: &SubscriptionT::TypeInfo,
+    ) -> Result<Self, SchemaError>
+    where
+        S: ScalarValue + 'a,
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+    {
         let mut registry = Registry::new(FnvHashMap::default());
+        registry.detect_conflicts();
+        let schema = Self::build::<QueryT, MutationT, SubscriptionT>(
+            &mut registry,
+            query_info,
+            mutation_info,
+            subscription_info,
+        );
+        registry.take_conflict().map_or(Ok(schema), Err)
+    }
+
+    fn build<QueryT, MutationT, SubscriptionT>(
+        registry: &mut Registry<'a, S>,
+        query_info: &QueryT::TypeInfo,
+        mutation_info: &MutationT::TypeInfo,
+        subscription_info: &SubscriptionT::TypeInfo,
+    ) -> Self
+    where
+        S: ScalarValue + 'a,
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+    {
+        let mut directives = FnvHashMap::default();
 
         let query_type_name = registry
             .get_type::<QueryT>(query_info)
@@ -208,18 +908,34 @@ impl<'a, S> SchemaType<'a, S> {
             .to_owned();
 
         registry.get_type::<SchemaType<S>>(&());
+        registry.resolve_lazy_types();
 
-        directives.insert("skip".into(), DirectiveType::new_skip(&mut registry));
-        directives.insert("include".into(), DirectiveType::new_include(&mut registry));
-        directives.insert(
-            "deprecated".into(),
-            DirectiveType::new_deprecated(&mut registry),
-        );
+        directives.insert("skip".into(), DirectiveType::new_skip(registry));
+        directives.insert("include".into(), DirectiveType::new_include(registry));
+        directives.insert("deprecated".into(), DirectiveType::new_deprecated(registry));
         directives.insert(
             "specifiedBy".into(),
-            DirectiveType::new_specified_by(&mut registry),
+            DirectiveType::new_specified_by(registry),
         );
 
+        let has_sized_lists = registry.types.values().any(|t| match t {
+            MetaType::Object(ObjectMeta { fields, .. })
+            | MetaType::Interface(InterfaceMeta { fields, .. }) => {
+                fields.iter().any(|f| f.field_type.list_size().is_some())
+            }
+            _ => false,
+        });
+        if has_sized_lists {
+            directives.insert(
+                LIST_SIZE_DIRECTIVE.into(),
+                DirectiveType::new_list_size(registry),
+            );
+        }
+
+        for (name, directive) in mem::take(&mut registry.directives) {
+            directives.entry(name).or_insert(directive);
+        }
+
         let mut meta_fields = vec![
             registry.field::<SchemaType<S>>("__schema", &()),
             registry
@@ -244,7 +960,7 @@ impl<'a, S> SchemaType<'a, S> {
         }
         SchemaType {
             description: None,
-            types: registry.types,
+            types: mem::take(&mut registry.types),
             query_type_name,
             mutation_type_name: if &mutation_type_name != "_EmptyMutation" {
                 Some(mutation_type_name)
@@ -257,9 +973,33 @@ impl<'a, S> SchemaType<'a, S> {
                 None
             },
             directives,
+            directive_handlers: DirectiveHandlers::default(),
         }
     }
 
+    /// Registers the provided `T` type (along with all the types it
+    /// references) in this schema, even if it isn't reachable from its root
+    /// types.
+    ///
+    /// Useful for implementers of a [GraphQL interface][1] resolved via
+    /// dynamic dispatch (see `#[graphql_interface(dyn = ...)]`), which may be
+    /// defined in other crates, and so aren't known to the interface itself.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    pub fn register_type<T>(&mut self, info: &T::TypeInfo)
+    where
+        S: ScalarValue + 'a,
+        T: GraphQLType<S> + ?Sized,
+    {
+        let mut registry = Registry::new(mem::take(&mut self.types));
+        registry.get_type::<T>(info);
+        registry.resolve_lazy_types();
+        for (name, directive) in mem::take(&mut registry.directives) {
+            self.directives.entry(name).or_insert(directive);
+        }
+        self.types = mem::take(&mut registry.types);
+    }
+
     /// Add a description.
     pub fn set_description(&mut self, description: impl Into<Cow<'a, str>>) {
         self.description = Some(description.into());
@@ -554,6 +1294,8 @@ where
             "deprecated",
             &[
                 DirectiveLocation::FieldDefinition,
+                DirectiveLocation::ArgumentDefinition,
+                DirectiveLocation::InputFieldDefinition,
                 DirectiveLocation::EnumValue,
             ],
             &[registry.arg::<String>("reason", &())],
@@ -573,6 +1315,49 @@ where
         )
     }
 
+    fn new_list_size(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            LIST_SIZE_DIRECTIVE,
+            &[DirectiveLocation::FieldDefinition],
+            &[registry.arg::<i32>("assumedSize", &())],
+            false,
+        )
+        .description("Expected size of the lists returned by the field.")
+    }
+
+    fn new_dry_run() -> DirectiveType<'a, S> {
+        Self::new(
+            DRY_RUN_DIRECTIVE,
+            &[DirectiveLocation::Mutation],
+            &[],
+            false,
+        )
+        .description("Validates the mutation without performing any side effects.")
+    }
+
+    fn new_defer() -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            DEFER_DIRECTIVE,
+            &[
+                DirectiveLocation::FragmentSpread,
+                DirectiveLocation::InlineFragment,
+            ],
+            &[
+                Argument::new("if", Type::NonNullNamed("Boolean".into()))
+                    .default_value(InputValue::scalar(true)),
+                Argument::new("label", Type::Named("String".into())),
+            ],
+            false,
+        )
+        .description("Delivers the fragment after the initial payload of the response.")
+    }
+
     pub fn description(mut self, description: &str) -> DirectiveType<'a, S> {
         self.description = Some(description.into());
         self
@@ -593,6 +1378,14 @@ impl fmt::Display for DirectiveLocation {
             Self::VariableDefinition => "variable definition",
             Self::Scalar => "scalar",
             Self::EnumValue => "enum value",
+            Self::ArgumentDefinition => "argument definition",
+            Self::InputFieldDefinition => "input field definition",
+            Self::Schema => "schema",
+            Self::Object => "object",
+            Self::Interface => "interface",
+            Self::Union => "union",
+            Self::Enum => "enum",
+            Self::InputObject => "input object",
         })
     }
 }
@@ -647,8 +1440,18 @@ mod test {
     #[cfg(feature = "schema-language")]
     mod schema_language {
         use crate::{
-            graphql_object, EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject,
-            GraphQLObject, GraphQLUnion, RootNode,
+            ast::Type,
+            executor::Registry,
+            graphql_object,
+            macros::reflect,
+            schema::{
+                meta::{Argument, MetaType, SchemaDirective},
+                model::{DirectiveLocation, DirectiveType},
+            },
+            types::marker,
+            DefaultScalarValue, DirectiveHandler, EmptyMutation, EmptySubscription, GraphQLEnum,
+            GraphQLInputObject, GraphQLObject, GraphQLScalar, GraphQLType, GraphQLUnion,
+            GraphQLValue, GraphQLValueAsync, InputValue, RootNode,
         };
 
         #[test]
@@ -675,6 +1478,8 @@ mod test {
             struct Coordinate {
                 latitude: f64,
                 longitude: f64,
+                #[graphql(deprecated = "Coordinates are two-dimensional.")]
+                altitude: Option<f64>,
             }
             struct Query;
             #[graphql_object]
@@ -692,8 +1497,11 @@ mod test {
                 fn fruit() -> Fruit {
                     Fruit::Apple
                 }
-                fn gluten_free(flavor: String) -> GlutenFree {
-                    if flavor == "savory" {
+                fn gluten_free(
+                    flavor: String,
+                    #[graphql(deprecated)] savory: Option<bool>,
+                ) -> GlutenFree {
+                    if flavor == "savory" || savory.unwrap_or_default() {
                         GlutenFree::Cake(Cake::default())
                     } else {
                         GlutenFree::IceCream(IceCream::default())
@@ -733,13 +1541,14 @@ mod test {
                   whatever: String!
                   arr(stuff: [Coordinate!]!): String
                   fruit: Fruit!
-                  glutenFree(flavor: String!): GlutenFree!
+                  glutenFree(flavor: String!, savory: Boolean @deprecated): GlutenFree!
                   old: Int! @deprecated
                   reallyOld: Float! @deprecated(reason: "This field is deprecated, use another.")
                 }
                 input Coordinate {
                     latitude: Float!
                     longitude: Float!
+                    altitude: Float @deprecated(reason: "Coordinates are two-dimensional.")
                 }
                 schema {
                   query: Query
@@ -749,5 +1558,344 @@ mod test {
             .unwrap();
             assert_eq!(ast.to_string(), schema.as_schema_language());
         }
+
+        #[test]
+        fn list_size() {
+            struct Query;
+            #[graphql_object]
+            impl Query {
+                fn roll() -> [i32; 3] {
+                    [1, 2, 3]
+                }
+                fn rolls() -> Vec<i32> {
+                    vec![]
+                }
+            }
+
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            let ast = graphql_parser::parse_schema::<&str>(
+                r#"
+                "Expected size of the lists returned by the field."
+                directive @listSize(assumedSize: Int!) on FIELD_DEFINITION
+                type Query {
+                  roll: [Int!]! @listSize(assumedSize: 3)
+                  rolls: [Int!]!
+                }
+                schema {
+                  query: Query
+                }
+            "#,
+            )
+            .unwrap();
+            assert_eq!(ast.to_string(), schema.as_schema_language());
+            assert!(schema.schema.directive_by_name("listSize").is_some());
+        }
+
+        #[test]
+        fn custom_directives() {
+            struct Noop;
+
+            impl DirectiveHandler for Noop {}
+
+            #[derive(GraphQLScalar)]
+            #[graphql(
+                transparent,
+                specified_by_url = "https://datatracker.ietf.org/doc/html/rfc4122"
+            )]
+            struct Uuid(String);
+
+            struct Product;
+
+            impl GraphQLType for Product {
+                fn name(_: &()) -> Option<&'static str> {
+                    Some("Product")
+                }
+
+                fn meta<'r>(_: &(), registry: &mut Registry<'r>) -> MetaType<'r>
+                where
+                    DefaultScalarValue: 'r,
+                {
+                    let fields_arg = registry.arg::<String>("fields", &());
+                    registry.register_directive(DirectiveType::new(
+                        "key",
+                        &[DirectiveLocation::Object, DirectiveLocation::Interface],
+                        &[fields_arg],
+                        true,
+                    ));
+                    let fields = [
+                        registry.field::<Uuid>("id", &()),
+                        registry.field::<i32>("price", &()).directive(
+                            SchemaDirective::new("cost").argument("weight", InputValue::scalar(2)),
+                        ),
+                    ];
+                    registry
+                        .build_object_type::<Self>(&(), &fields)
+                        .directive(
+                            SchemaDirective::new("key")
+                                .argument("fields", InputValue::scalar("id")),
+                        )
+                        .into_meta()
+                }
+            }
+
+            impl GraphQLValue for Product {
+                type Context = ();
+                type TypeInfo = ();
+
+                fn type_name<'i>(&self, info: &'i ()) -> Option<&'i str> {
+                    <Self as GraphQLType>::name(info)
+                }
+            }
+
+            impl GraphQLValueAsync for Product {}
+
+            impl marker::IsOutputType<DefaultScalarValue> for Product {}
+
+            impl reflect::BaseType<DefaultScalarValue> for Product {
+                const NAME: reflect::Type = "Product";
+            }
+
+            impl reflect::BaseSubTypes<DefaultScalarValue> for Product {
+                const NAMES: reflect::Types = &["Product"];
+            }
+
+            impl reflect::WrappedType<DefaultScalarValue> for Product {
+                const VALUE: reflect::WrappedValue = 1;
+            }
+
+            struct Query;
+
+            #[graphql_object(scalar = DefaultScalarValue)]
+            impl Query {
+                /// Products matching the `text`.
+                ///
+                /// Ordered by relevance.
+                fn search(
+                    #[graphql(default = "a \"quoted\" text")] text: String,
+                    #[graphql(default = 0.5)] threshold: f64,
+                ) -> Vec<Product> {
+                    let _ = (text, threshold);
+                    vec![]
+                }
+            }
+
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .with_directive(
+                DirectiveType::new(
+                    "cost",
+                    &[DirectiveLocation::FieldDefinition],
+                    &[Argument::new("weight", Type::NonNullNamed("Int".into()))],
+                    false,
+                )
+                .description("Relative cost of resolving the field."),
+                Noop,
+            );
+            let sdl = schema.as_schema_language();
+
+            for expected in [
+                "\"Relative cost of resolving the field.\"\n\
+                 directive @cost(weight: Int!) on FIELD_DEFINITION\n",
+                "directive @key(fields: String!) repeatable on OBJECT | INTERFACE\n",
+                "scalar Uuid @specifiedBy(url: \"https://datatracker.ietf.org/doc/html/rfc4122\")\n",
+                "type Product @key(fields: \"id\") {\n",
+                "  price: Int! @cost(weight: 2)\n",
+                "  \"\"\"\n    Products matching the `text`.\n\n    Ordered by relevance.\n  \"\"\"\n",
+                "= \"a \\\"quoted\\\" text\"",
+                "= 0.5",
+            ] {
+                assert!(sdl.contains(expected), "`{expected}` not found in:\n{sdl}");
+            }
+            assert!(!sdl.contains("directive @deprecated"), "{sdl}");
+
+            let reparsed = graphql_parser::parse_schema::<&str>(&sdl).unwrap();
+            assert_eq!(reparsed.to_string(), sdl);
+        }
+
+        #[test]
+        fn schema_hash() {
+            #[derive(GraphQLEnum)]
+            enum Episode {
+                NewHope,
+                Empire,
+            }
+
+            struct Query;
+
+            #[graphql_object]
+            impl Query {
+                fn hero(episode: Episode, limit: i32) -> String {
+                    let _ = (episode, limit);
+                    String::new()
+                }
+
+                fn version() -> i32 {
+                    1
+                }
+            }
+
+            #[derive(GraphQLEnum)]
+            #[graphql(name = "Episode")]
+            enum ReorderedEpisode {
+                Empire,
+                NewHope,
+            }
+
+            struct ReorderedQuery;
+
+            #[graphql_object(name = "Query")]
+            impl ReorderedQuery {
+                fn version() -> i32 {
+                    1
+                }
+
+                fn hero(limit: i32, episode: ReorderedEpisode) -> String {
+                    let _ = (episode, limit);
+                    String::new()
+                }
+            }
+
+            struct ChangedQuery;
+
+            #[graphql_object(name = "Query")]
+            impl ChangedQuery {
+                fn hero(episode: Episode, limit: Option<i32>) -> String {
+                    let _ = (episode, limit);
+                    String::new()
+                }
+
+                fn version() -> i32 {
+                    1
+                }
+            }
+
+            let hash = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .schema_hash();
+            let reordered = RootNode::new(
+                ReorderedQuery,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .schema_hash();
+            let changed = RootNode::new(
+                ChangedQuery,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .schema_hash();
+
+            assert_eq!(hash, reordered);
+            assert_ne!(hash, changed);
+        }
+    }
+
+    mod conflicting_types {
+        use crate::{
+            graphql_object, EmptyMutation, EmptySubscription, GraphQLObject, RootNode, SchemaError,
+        };
+
+        #[derive(GraphQLObject)]
+        #[graphql(name = "User")]
+        struct User {
+            id: i32,
+        }
+
+        #[derive(GraphQLObject)]
+        #[graphql(name = "User")]
+        struct OtherUser {
+            id: String,
+            name: String,
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn user() -> User {
+                User { id: 1 }
+            }
+
+            fn boxed_user() -> Box<User> {
+                Box::new(User { id: 2 })
+            }
+
+            fn other_user() -> OtherUser {
+                OtherUser {
+                    id: "3".into(),
+                    name: "other".into(),
+                }
+            }
+        }
+
+        struct SameQuery;
+
+        #[graphql_object]
+        impl SameQuery {
+            fn user() -> User {
+                User { id: 1 }
+            }
+
+            fn boxed_user() -> Box<User> {
+                Box::new(User { id: 2 })
+            }
+        }
+
+        #[test]
+        fn reports_differing_fields() {
+            let err = RootNode::try_new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .err()
+            .unwrap();
+
+            assert_eq!(
+                err,
+                SchemaError::ConflictingType {
+                    name: "User".into(),
+                    first: vec!["id: Int!".into()],
+                    second: vec!["id: String!".into(), "name: String!".into()],
+                },
+            );
+            assert_eq!(
+                err.to_string(),
+                "Type `User` is defined differently by several Rust types: \
+                 [id: Int!] conflicts with [id: String!, name: String!]",
+            );
+        }
+
+        #[test]
+        fn allows_same_type_via_different_rust_types() {
+            assert!(RootNode::try_new(
+                SameQuery,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .is_ok());
+        }
+
+        #[test]
+        fn new_keeps_first_type() {
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+
+            let user = schema.schema.concrete_type_by_name("User").unwrap();
+            assert!(user.field_by_name("name").is_none());
+        }
     }
 }
@@ -3,10 +3,12 @@ use std::{boxed::Box, collections::BTreeMap};
 use graphql_parser::{
     query::{Directive as ExternalDirective, Number as ExternalNumber, Type as ExternalType},
     schema::{
-        Definition, Document, EnumType as ExternalEnum, EnumValue as ExternalEnumValue,
-        Field as ExternalField, InputObjectType as ExternalInputObjectType,
-        InputValue as ExternalInputValue, InterfaceType as ExternalInterfaceType,
-        ObjectType as ExternalObjectType, ScalarType as ExternalScalarType, SchemaDefinition, Text,
+        Definition, DirectiveDefinition as ExternalDirectiveDefinition,
+        DirectiveLocation as ExternalDirectiveLocation, Document, EnumType as ExternalEnum,
+        EnumValue as ExternalEnumValue, Field as ExternalField,
+        InputObjectType as ExternalInputObjectType, InputValue as ExternalInputValue,
+        InterfaceType as ExternalInterfaceType, ObjectType as ExternalObjectType,
+        ScalarType as ExternalScalarType, SchemaDefinition, Text,
         TypeDefinition as ExternalTypeDefinition, UnionType as ExternalUnionType,
         Value as ExternalValue,
     },
@@ -16,8 +18,8 @@ use graphql_parser::{
 use crate::{
     ast::{InputValue, Type},
     schema::{
-        meta::{Argument, DeprecationStatus, EnumValue, Field, MetaType},
-        model::SchemaType,
+        meta::{Argument, DeprecationStatus, EnumValue, Field, MetaType, SchemaDirective},
+        model::{DirectiveLocation, DirectiveType, SchemaType, LIST_SIZE_DIRECTIVE},
         translate::SchemaTranslator,
     },
     value::ScalarValue,
@@ -46,6 +48,20 @@ where
     {
         let mut doc = Document::default();
 
+        // Translate custom directive defs, as the built-in ones are implied.
+        let mut directives = input
+            .directive_list()
+            .into_iter()
+            .filter(|d| !is_builtin_directive(&d.name))
+            .collect::<Vec<_>>();
+        directives.sort_by(|a, b| a.name.cmp(&b.name));
+        doc.definitions.extend(
+            directives
+                .into_iter()
+                .map(GraphQLParserTranslator::translate_directive_definition)
+                .map(Definition::DirectiveDefinition),
+        );
+
         // Translate type defs.
         let mut types = input
             .types
@@ -76,6 +92,31 @@ where
 }
 
 impl GraphQLParserTranslator {
+    fn translate_directive_definition<'a, S, T>(
+        input: &'a DirectiveType<S>,
+    ) -> ExternalDirectiveDefinition<'a, T>
+    where
+        S: ScalarValue,
+        T: Text<'a>,
+    {
+        ExternalDirectiveDefinition {
+            position: Pos::default(),
+            description: input.description.clone(),
+            name: From::from(input.name.as_str()),
+            arguments: input
+                .arguments
+                .iter()
+                .map(GraphQLParserTranslator::translate_argument)
+                .collect(),
+            repeatable: input.is_repeatable,
+            locations: input
+                .locations
+                .iter()
+                .map(translate_directive_location)
+                .collect(),
+        }
+    }
+
     fn translate_argument<'a, S, T>(input: &'a Argument<S>) -> ExternalInputValue<'a, T>
     where
         S: ScalarValue,
@@ -90,7 +131,7 @@ impl GraphQLParserTranslator {
                 .default_value
                 .as_ref()
                 .map(|x| GraphQLParserTranslator::translate_value(x)),
-            directives: vec![],
+            directives: generate_directives(&input.deprecation_status),
         }
     }
 
@@ -111,7 +152,9 @@ impl GraphQLParserTranslator {
                 } else if let Some(v) = x.as_bool() {
                     ExternalValue::Boolean(v)
                 } else {
-                    panic!("unknown argument type")
+                    // Custom scalar values are represented as strings in the
+                    // schema language.
+                    ExternalValue::String(x.to_string())
                 }
             }
             InputValue::Enum(x) => ExternalValue::Enum(From::from(x.as_str())),
@@ -162,13 +205,19 @@ impl GraphQLParserTranslator {
                 position: Pos::default(),
                 description: x.description.as_ref().map(From::from),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: x
+                    .specified_by_url
+                    .as_deref()
+                    .map(specified_by_to_directive)
+                    .into_iter()
+                    .chain(translate_directives(input.directives()))
+                    .collect(),
             }),
             MetaType::Enum(x) => ExternalTypeDefinition::Enum(ExternalEnum {
                 position: Pos::default(),
                 description: x.description.as_ref().map(|s| From::from(s.as_str())),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: translate_directives(input.directives()),
                 values: x
                     .values
                     .iter()
@@ -179,7 +228,7 @@ impl GraphQLParserTranslator {
                 position: Pos::default(),
                 description: x.description.as_ref().map(|s| From::from(s.as_str())),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: translate_directives(input.directives()),
                 types: x
                     .of_type_names
                     .iter()
@@ -195,7 +244,7 @@ impl GraphQLParserTranslator {
                     .iter()
                     .map(|s| From::from(s.as_str()))
                     .collect(),
-                directives: vec![],
+                directives: translate_directives(input.directives()),
                 fields: x
                     .fields
                     .iter()
@@ -208,7 +257,7 @@ impl GraphQLParserTranslator {
                     position: Pos::default(),
                     description: x.description.as_ref().map(|s| From::from(s.as_str())),
                     name: From::from(x.name.as_ref()),
-                    directives: vec![],
+                    directives: translate_directives(input.directives()),
                     fields: x
                         .input_fields
                         .iter()
@@ -221,7 +270,7 @@ impl GraphQLParserTranslator {
                 position: Pos::default(),
                 description: x.description.as_ref().map(|s| From::from(s.as_str())),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: translate_directives(input.directives()),
                 fields: x
                     .fields
                     .iter()
@@ -266,11 +315,15 @@ impl GraphQLParserTranslator {
             })
             .unwrap_or_else(Vec::new);
 
+        let mut directives = generate_directives(&input.deprecation_status);
+        directives.extend(list_size_to_directive(&input.field_type));
+        directives.extend(translate_directives(input.directives()));
+
         ExternalField {
             position: Pos::default(),
             name: From::from(input.name.as_str()),
             description: input.description.as_ref().map(|s| From::from(s.as_str())),
-            directives: generate_directives(&input.deprecation_status),
+            directives,
             field_type: GraphQLParserTranslator::translate_type(&input.field_type),
             arguments,
         }
@@ -294,7 +347,23 @@ where
     }
 }
 
-// Right now the only directive supported is `@deprecated`.
+fn list_size_to_directive<'a, T>(field_type: &Type<'_>) -> Option<ExternalDirective<'a, T>>
+where
+    T: Text<'a>,
+{
+    let size = i32::try_from(field_type.list_size()?).ok()?;
+    Some(ExternalDirective {
+        position: Pos::default(),
+        name: LIST_SIZE_DIRECTIVE.into(),
+        arguments: vec![(
+            From::from("assumedSize"),
+            ExternalValue::Int(ExternalNumber::from(size)),
+        )],
+    })
+}
+
+// Right now the only directive supported on arguments and enum values is
+// `@deprecated`, while fields may have `@listSize` too.
 // `@skip` and `@include` are dealt with elsewhere.
 // https://spec.graphql.org/October2021#sec-Type-System.Directives.Built-in-Directives
 fn generate_directives<'a, T>(status: &DeprecationStatus) -> Vec<ExternalDirective<'a, T>>
@@ -307,3 +376,128 @@ where
         vec![]
     }
 }
+
+/// Indicates whether the directive of the provided `name` is a built-in one,
+/// which definition is implied by the schema language.
+fn is_builtin_directive(name: &str) -> bool {
+    matches!(name, "skip" | "include" | "deprecated" | "specifiedBy")
+}
+
+fn translate_directive_location(location: &DirectiveLocation) -> ExternalDirectiveLocation {
+    match location {
+        DirectiveLocation::Query => ExternalDirectiveLocation::Query,
+        DirectiveLocation::Mutation => ExternalDirectiveLocation::Mutation,
+        DirectiveLocation::Subscription => ExternalDirectiveLocation::Subscription,
+        DirectiveLocation::Field => ExternalDirectiveLocation::Field,
+        DirectiveLocation::Scalar => ExternalDirectiveLocation::Scalar,
+        DirectiveLocation::FragmentDefinition => ExternalDirectiveLocation::FragmentDefinition,
+        DirectiveLocation::FieldDefinition => ExternalDirectiveLocation::FieldDefinition,
+        DirectiveLocation::VariableDefinition => ExternalDirectiveLocation::VariableDefinition,
+        DirectiveLocation::FragmentSpread => ExternalDirectiveLocation::FragmentSpread,
+        DirectiveLocation::InlineFragment => ExternalDirectiveLocation::InlineFragment,
+        DirectiveLocation::EnumValue => ExternalDirectiveLocation::EnumValue,
+        DirectiveLocation::ArgumentDefinition => ExternalDirectiveLocation::ArgumentDefinition,
+        DirectiveLocation::InputFieldDefinition => ExternalDirectiveLocation::InputFieldDefinition,
+        DirectiveLocation::Schema => ExternalDirectiveLocation::Schema,
+        DirectiveLocation::Object => ExternalDirectiveLocation::Object,
+        DirectiveLocation::Interface => ExternalDirectiveLocation::Interface,
+        DirectiveLocation::Union => ExternalDirectiveLocation::Union,
+        DirectiveLocation::Enum => ExternalDirectiveLocation::Enum,
+        DirectiveLocation::InputObject => ExternalDirectiveLocation::InputObject,
+    }
+}
+
+fn specified_by_to_directive<'a, T>(url: &str) -> ExternalDirective<'a, T>
+where
+    T: Text<'a>,
+{
+    ExternalDirective {
+        position: Pos::default(),
+        name: "specifiedBy".into(),
+        arguments: vec![(From::from("url"), ExternalValue::String(url.into()))],
+    }
+}
+
+fn translate_directives<'a, T>(directives: &'a [SchemaDirective]) -> Vec<ExternalDirective<'a, T>>
+where
+    T: Text<'a>,
+{
+    directives
+        .iter()
+        .map(|d| ExternalDirective {
+            position: Pos::default(),
+            name: From::from(d.name.as_str()),
+            arguments: d
+                .arguments
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        From::from(name.as_str()),
+                        GraphQLParserTranslator::translate_value(value),
+                    )
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Sorts the definitions of the provided schema `doc`ument by their kinds and
+/// names, along with the fields, arguments, enum values, union members and
+/// implemented interfaces of its types, so its representation doesn't depend on
+/// the order they were registered in.
+pub(crate) fn sort_document<'a, T>(doc: &mut Document<'a, T>)
+where
+    T: Text<'a>,
+{
+    fn sort_fields<'a, T: Text<'a>>(fields: &mut [ExternalField<'a, T>]) {
+        fields.sort_by(|a, b| a.name.cmp(&b.name));
+        for field in fields {
+            field.arguments.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+    }
+
+    fn sort_key<'d, 'a, T: Text<'a>>(def: &'d Definition<'a, T>) -> (u8, &'d str) {
+        match def {
+            Definition::DirectiveDefinition(d) => (0, d.name.as_ref()),
+            Definition::TypeDefinition(t) => (
+                1,
+                match t {
+                    ExternalTypeDefinition::Scalar(t) => t.name.as_ref(),
+                    ExternalTypeDefinition::Object(t) => t.name.as_ref(),
+                    ExternalTypeDefinition::Interface(t) => t.name.as_ref(),
+                    ExternalTypeDefinition::Union(t) => t.name.as_ref(),
+                    ExternalTypeDefinition::Enum(t) => t.name.as_ref(),
+                    ExternalTypeDefinition::InputObject(t) => t.name.as_ref(),
+                },
+            ),
+            Definition::TypeExtension(_) => (2, ""),
+            Definition::SchemaDefinition(_) => (3, ""),
+        }
+    }
+
+    for def in &mut doc.definitions {
+        match def {
+            Definition::DirectiveDefinition(d) => d.arguments.sort_by(|a, b| a.name.cmp(&b.name)),
+            Definition::TypeDefinition(ExternalTypeDefinition::Object(o)) => {
+                sort_fields(&mut o.fields);
+                o.implements_interfaces.sort();
+            }
+            Definition::TypeDefinition(ExternalTypeDefinition::Interface(i)) => {
+                sort_fields(&mut i.fields);
+                i.implements_interfaces.sort();
+            }
+            Definition::TypeDefinition(ExternalTypeDefinition::Union(u)) => u.types.sort(),
+            Definition::TypeDefinition(ExternalTypeDefinition::Enum(e)) => {
+                e.values.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+            Definition::TypeDefinition(ExternalTypeDefinition::InputObject(i)) => {
+                i.fields.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+            Definition::TypeDefinition(ExternalTypeDefinition::Scalar(_))
+            | Definition::SchemaDefinition(_)
+            | Definition::TypeExtension(_) => {}
+        }
+    }
+    doc.definitions
+        .sort_by(|a, b| sort_key(a).cmp(&sort_key(b)));
+}
@@ -0,0 +1,271 @@
+//! Walking over the definitions of a schema.
+
+use crate::{
+    schema::{
+        meta::{Argument, EnumValue, Field, MetaType},
+        model::{DirectiveType, SchemaType},
+    },
+    value::DefaultScalarValue,
+};
+
+/// Visitor of the definitions of a schema, walked by [`RootNode::visit()`].
+///
+/// Gives tools working with a schema (documentation generators, linters,
+/// permission auditors and the like) access to its definitions right away,
+/// rather than via the introspection of the schema.
+///
+/// All the methods do nothing by default, so only the relevant ones need to
+/// be implemented.
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_object, meta::{Field, MetaType}, EmptyMutation, EmptySubscription, RootNode,
+/// #     SchemaVisitor,
+/// # };
+/// #
+/// /// Collects the fields without a description.
+/// #[derive(Default)]
+/// struct Undocumented(Vec<String>);
+///
+/// impl<S> SchemaVisitor<S> for Undocumented {
+///     fn visit_field(&mut self, parent: &MetaType<S>, field: &Field<S>) {
+///         if field.description.is_none() && !field.is_builtin() && !parent.is_builtin() {
+///             self.0.push(format!("{}.{}", parent.name().unwrap(), field.name));
+///         }
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     /// Returns the API version.
+///     fn version() -> i32 { 1 }
+///
+///     fn ping() -> bool { true }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+///
+/// let mut undocumented = Undocumented::default();
+/// schema.visit(&mut undocumented);
+///
+/// assert_eq!(undocumented.0, ["Query.ping"]);
+/// ```
+///
+/// [`RootNode::visit()`]: crate::RootNode::visit
+pub trait SchemaVisitor<S = DefaultScalarValue> {
+    /// Visits a type of the schema, before visiting its fields, input fields
+    /// or enum values.
+    fn visit_type(&mut self, ty: &MetaType<S>) {
+        let _ = ty;
+    }
+
+    /// Visits a `field` of the `parent` object or interface, before visiting
+    /// its arguments.
+    fn visit_field(&mut self, parent: &MetaType<S>, field: &Field<S>) {
+        let _ = (parent, field);
+    }
+
+    /// Visits an argument of the `field` of the `parent` object or interface.
+    fn visit_argument(&mut self, parent: &MetaType<S>, field: &Field<S>, arg: &Argument<S>) {
+        let _ = (parent, field, arg);
+    }
+
+    /// Visits a `field` of the `parent` input object.
+    fn visit_input_field(&mut self, parent: &MetaType<S>, field: &Argument<S>) {
+        let _ = (parent, field);
+    }
+
+    /// Visits a `value` of the `parent` enum.
+    fn visit_enum_value(&mut self, parent: &MetaType<S>, value: &EnumValue) {
+        let _ = (parent, value);
+    }
+
+    /// Visits a directive of the schema, before visiting its arguments.
+    fn visit_directive(&mut self, directive: &DirectiveType<S>) {
+        let _ = directive;
+    }
+
+    /// Visits an argument of the `directive`.
+    fn visit_directive_argument(&mut self, directive: &DirectiveType<S>, arg: &Argument<S>) {
+        let _ = (directive, arg);
+    }
+}
+
+impl<'a, S> SchemaType<'a, S> {
+    /// Walks the provided `visitor` over all the types of this schema (the
+    /// ones exposed via introspection), and then over all its directives.
+    ///
+    /// Types and directives are walked in the order of their names, while
+    /// fields, arguments and enum values are walked in their definition
+    /// order.
+    pub fn visit<V: SchemaVisitor<S> + ?Sized>(&self, visitor: &mut V) {
+        let mut types = self
+            .concrete_type_list()
+            .into_iter()
+            .filter(|t| !matches!(t.name(), Some("_EmptyMutation" | "_EmptySubscription")))
+            .collect::<Vec<_>>();
+        types.sort_by(|a, b| a.name().cmp(&b.name()));
+        for ty in types {
+            visitor.visit_type(ty);
+            match ty {
+                MetaType::Object(obj) => visit_fields(visitor, ty, &obj.fields),
+                MetaType::Interface(iface) => visit_fields(visitor, ty, &iface.fields),
+                MetaType::InputObject(input) => {
+                    for field in &input.input_fields {
+                        visitor.visit_input_field(ty, field);
+                    }
+                }
+                MetaType::Enum(enum_meta) => {
+                    for value in &enum_meta.values {
+                        visitor.visit_enum_value(ty, value);
+                    }
+                }
+                MetaType::Scalar(_)
+                | MetaType::Union(_)
+                | MetaType::List(_)
+                | MetaType::Nullable(_)
+                | MetaType::Placeholder(_) => {}
+            }
+        }
+
+        let mut directives = self.directive_list();
+        directives.sort_by(|a, b| a.name.cmp(&b.name));
+        for directive in directives {
+            visitor.visit_directive(directive);
+            for arg in &directive.arguments {
+                visitor.visit_directive_argument(directive, arg);
+            }
+        }
+    }
+}
+
+fn visit_fields<S, V: SchemaVisitor<S> + ?Sized>(
+    visitor: &mut V,
+    parent: &MetaType<S>,
+    fields: &[Field<S>],
+) {
+    for field in fields {
+        visitor.visit_field(parent, field);
+        for arg in field.arguments.iter().flatten() {
+            visitor.visit_argument(parent, field, arg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_object,
+        schema::{
+            meta::{Argument, EnumValue, Field, MetaType},
+            model::DirectiveType,
+        },
+        EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject, RootNode,
+    };
+
+    use super::SchemaVisitor;
+
+    #[derive(GraphQLEnum)]
+    enum Episode {
+        NewHope,
+        Empire,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Filter {
+        episode: Episode,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn hero(filter: Filter, limit: Option<i32>) -> Option<String> {
+            let _ = (filter, limit);
+            None
+        }
+    }
+
+    #[derive(Default)]
+    struct Paths(Vec<String>);
+
+    impl<S> SchemaVisitor<S> for Paths {
+        fn visit_type(&mut self, ty: &MetaType<S>) {
+            self.0.push(ty.name().unwrap().into());
+        }
+
+        fn visit_field(&mut self, parent: &MetaType<S>, field: &Field<S>) {
+            self.0
+                .push(format!("{}.{}", parent.name().unwrap(), field.name));
+        }
+
+        fn visit_argument(&mut self, parent: &MetaType<S>, field: &Field<S>, arg: &Argument<S>) {
+            let parent = parent.name().unwrap();
+            self.0
+                .push(format!("{parent}.{}({})", field.name, arg.name));
+        }
+
+        fn visit_input_field(&mut self, parent: &MetaType<S>, field: &Argument<S>) {
+            self.0
+                .push(format!("{}.{}", parent.name().unwrap(), field.name));
+        }
+
+        fn visit_enum_value(&mut self, parent: &MetaType<S>, value: &EnumValue) {
+            self.0
+                .push(format!("{}.{}", parent.name().unwrap(), value.name));
+        }
+
+        fn visit_directive(&mut self, directive: &DirectiveType<S>) {
+            self.0.push(format!("@{}", directive.name));
+        }
+
+        fn visit_directive_argument(&mut self, directive: &DirectiveType<S>, arg: &Argument<S>) {
+            self.0.push(format!("@{}({})", directive.name, arg.name));
+        }
+    }
+
+    #[test]
+    fn walks_schema() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let mut paths = Paths::default();
+        schema.visit(&mut paths);
+        let paths = paths
+            .0
+            .into_iter()
+            .filter(|p| !p.contains("__"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            [
+                "Boolean",
+                "Episode",
+                "Episode.NEW_HOPE",
+                "Episode.EMPIRE",
+                "Filter",
+                "Filter.episode",
+                "Int",
+                "Query",
+                "Query.hero",
+                "Query.hero(filter)",
+                "Query.hero(limit)",
+                "String",
+                "@deprecated",
+                "@deprecated(reason)",
+                "@include",
+                "@include(if)",
+                "@skip",
+                "@skip(if)",
+                "@specifiedBy",
+                "@specifiedBy(url)",
+            ],
+        );
+    }
+}
@@ -0,0 +1,1071 @@
+//! Mock [`RootNode`] built from an introspection result of another schema.
+
+use std::{collections::HashMap, convert::Infallible, fmt, slice, sync::Arc};
+
+use futures::future::{self, BoxFuture};
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+    ast::{FromInputValue, InputValue, Selection, Type},
+    executor::{ExecutionResult, Executor, FieldResult, Registry},
+    parser::{Lexer, ScalarToken, Token},
+    schema::{
+        meta::{Argument, DeprecationStatus, EnumValue, Field, MetaType, Metadata},
+        model::RootNode,
+    },
+    types::{
+        async_await::GraphQLValueAsync,
+        base::{resolve_selection_set_into, Arguments, GraphQLType, GraphQLValue},
+        scalars::{EmptySubscription, ID},
+    },
+    value::{DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+};
+
+/// [`RootNode`] of a [`MockSchema`].
+pub type MockRootNode<S = DefaultScalarValue> =
+    RootNode<'static, MockValue<S>, MockValue<S>, EmptySubscription, S>;
+
+/// Resolver of a mocked field, returning its canned [`Value`].
+type Resolver<S> = Arc<dyn Fn(&Arguments<S>) -> FieldResult<Value<S>, S> + Send + Sync>;
+
+/// Schema of another GraphQL service, imported from its introspection result,
+/// to be served by a [`MockRootNode`] in tests.
+///
+/// It's deserialized from the JSON result of the introspection query (either
+/// the whole response, or its `data`, like the one returned by
+/// [`introspect()`]), so tests of services delegating to remote schemas may run
+/// hermetically.
+///
+/// Fields resolve either into the canned values provided via
+/// [`MockSchema::with_response()`] and [`MockSchema::with_resolver()`], or
+/// into generated ones:
+/// - `Int` fields resolve into `42`, `Float` ones into `4.2`, `Boolean` ones
+///   into `true`, `ID` ones into `"1"`, and `String` and custom scalar ones
+///   into `"Hello World"`;
+/// - enum fields resolve into their first value;
+/// - list fields resolve into a single generated item;
+/// - interface and union fields resolve into their first possible type.
+///
+/// Canned object values also provide the values of their sub-fields, the
+/// `__typename` key of them selecting the concrete type of an abstract field.
+///
+/// Subscriptions are not mocked.
+///
+/// ```rust
+/// # use juniper::{graphql_value, graphql_vars, testing::MockSchema};
+/// #
+/// # #[tokio::main]
+/// # async fn main() {
+/// let introspection = r#"{"data": {"__schema": {
+///     "queryType": {"name": "Query"},
+///     "types": [{
+///         "kind": "OBJECT",
+///         "name": "Query",
+///         "fields": [{
+///             "name": "greeting",
+///             "args": [],
+///             "type": {"kind": "SCALAR", "name": "String"}
+///         }, {
+///             "name": "count",
+///             "args": [],
+///             "type": {"kind": "SCALAR", "name": "Int"}
+///         }]
+///     }]
+/// }}}"#;
+///
+/// let schema = serde_json::from_str::<MockSchema>(introspection)
+///     .unwrap()
+///     .with_response("Query", "greeting", graphql_value!("hi"))
+///     .into_root_node();
+///
+/// let res = juniper::execute("{ greeting count }", None, &schema, &graphql_vars! {}, &())
+///     .await;
+///
+/// assert_eq!(
+///     res,
+///     Ok((graphql_value!({"greeting": "hi", "count": 42}), vec![])),
+/// );
+/// # }
+/// ```
+///
+/// [`introspect()`]: crate::introspect
+pub struct MockSchema<S = DefaultScalarValue> {
+    definitions: Definitions<S>,
+}
+
+impl<S> fmt::Debug for MockSchema<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockSchema")
+            .field("query_type", &self.definitions.query_type)
+            .field("mutation_type", &self.definitions.mutation_type)
+            .field("types", &self.definitions.types.keys())
+            .finish()
+    }
+}
+
+impl<'de, S> Deserialize<'de> for MockSchema<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let schema = match IntrospectionResponse::deserialize(deserializer)? {
+            IntrospectionResponse::Data { data } => data.schema,
+            IntrospectionResponse::Introspection(i) => i.schema,
+        };
+        Ok(Self {
+            definitions: Definitions {
+                query_type: schema.query_type.name,
+                mutation_type: schema.mutation_type.map(|t| t.name),
+                types: schema
+                    .types
+                    .into_iter()
+                    .filter(|t| !t.name.starts_with("__"))
+                    .map(|t| (t.name.clone(), t))
+                    .collect(),
+                resolvers: HashMap::new(),
+            },
+        })
+    }
+}
+
+impl<S: ScalarValue> MockSchema<S> {
+    /// Makes the field `field_name` of the `type_name` type always resolve
+    /// into the provided `value`.
+    #[must_use]
+    pub fn with_response(
+        self,
+        type_name: impl Into<String>,
+        field_name: impl Into<String>,
+        value: Value<S>,
+    ) -> Self
+    where
+        S: Send + Sync + 'static,
+    {
+        self.with_resolver(type_name, field_name, move |_| Ok(value.clone()))
+    }
+
+    /// Makes the field `field_name` of the `type_name` type resolve via the
+    /// provided `resolver` function, receiving the field [`Arguments`].
+    ///
+    /// Returned [`Value`]s are still subject to the selection set of the field.
+    #[must_use]
+    pub fn with_resolver(
+        mut self,
+        type_name: impl Into<String>,
+        field_name: impl Into<String>,
+        resolver: impl Fn(&Arguments<S>) -> FieldResult<Value<S>, S> + Send + Sync + 'static,
+    ) -> Self {
+        self.definitions
+            .resolvers
+            .insert((type_name.into(), field_name.into()), Arc::new(resolver));
+        self
+    }
+
+    /// Creates a new [`MockRootNode`] serving this [`MockSchema`].
+    pub fn into_root_node(self) -> MockRootNode<S>
+    where
+        S: 'static,
+    {
+        let query_type = self.definitions.query_type.clone();
+        let mutation_type = self
+            .definitions
+            .mutation_type
+            .clone()
+            .unwrap_or_else(|| EMPTY_MUTATION.into());
+        let definitions = Arc::new(self.definitions);
+
+        RootNode::new_with_info(
+            MockValue::generated(),
+            MockValue::generated(),
+            EmptySubscription::new(),
+            MockTypeInfo::new(definitions.clone(), TypeRef::Named(query_type)),
+            MockTypeInfo::new(definitions, TypeRef::Named(mutation_type)),
+            (),
+        )
+    }
+}
+
+/// Name of the root mutation type, making a [`RootNode`] omit it.
+const EMPTY_MUTATION: &str = "_EmptyMutation";
+
+/// Value of any type of a [`MockSchema`].
+#[derive(Clone, Debug)]
+pub struct MockValue<S = DefaultScalarValue> {
+    /// Canned [`Value`], if any.
+    data: Option<Value<S>>,
+}
+
+impl<S> MockValue<S> {
+    /// Creates a new [`MockValue`] being fully generated.
+    fn generated() -> Self {
+        Self { data: None }
+    }
+}
+
+/// [`GraphQLValue::TypeInfo`] of a [`MockValue`].
+pub struct MockTypeInfo<S = DefaultScalarValue> {
+    definitions: Arc<Definitions<S>>,
+    ty: TypeRef,
+}
+
+impl<S> fmt::Debug for MockTypeInfo<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockTypeInfo")
+            .field("ty", &self.ty)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> MockTypeInfo<S> {
+    fn new(definitions: Arc<Definitions<S>>, ty: TypeRef) -> Self {
+        Self { definitions, ty }
+    }
+
+    /// Returns the [`MockTypeInfo`] of the provided type reference in the same
+    /// schema.
+    fn of(&self, ty: TypeRef) -> Self {
+        Self::new(self.definitions.clone(), ty)
+    }
+
+    /// Returns the [`MockTypeInfo`] of the named type in the same schema.
+    fn named(&self, name: &str) -> Self {
+        self.of(TypeRef::Named(name.into()))
+    }
+
+    /// Returns the name of this type, if it's a named one.
+    fn name(&self) -> Option<&str> {
+        self.ty.name()
+    }
+
+    /// Returns the definition of this type, if it's a named and known one.
+    fn definition(&self) -> Option<&TypeDef> {
+        self.name().and_then(|n| self.definitions.types.get(n))
+    }
+}
+
+impl<S: ScalarValue> GraphQLType<S> for MockValue<S> {
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        info.name()
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let def = match info.definition() {
+            Some(def) => def,
+            // Unknown types (like the absent mutation one) have no fields.
+            None => return registry.build_object_type::<Self>(info, &[]).into_meta(),
+        };
+        let types = |registry: &mut Registry<'r, S>, refs: &Option<Vec<TypeRef>>| {
+            refs.iter()
+                .flatten()
+                .filter_map(TypeRef::name)
+                .map(|name| registry.get_type::<Self>(&info.named(name)))
+                .collect::<Vec<_>>()
+        };
+
+        match def.kind {
+            Kind::Scalar => {
+                let meta = registry.build_scalar_type::<Self>(info);
+                match &def.description {
+                    Some(d) => meta.description(d).into_meta(),
+                    None => meta.into_meta(),
+                }
+            }
+            Kind::Enum => {
+                let values = def
+                    .enum_values
+                    .iter()
+                    .flatten()
+                    .map(|v| {
+                        let mut value = EnumValue::new(&v.name);
+                        if let Some(d) = &v.description {
+                            value = value.description(d);
+                        }
+                        if v.is_deprecated {
+                            value = value.deprecated(v.deprecation_reason.as_deref());
+                        }
+                        value
+                    })
+                    .collect::<Vec<_>>();
+                let meta = registry.build_enum_type::<Self>(info, &values);
+                match &def.description {
+                    Some(d) => meta.description(d).into_meta(),
+                    None => meta.into_meta(),
+                }
+            }
+            Kind::InputObject => {
+                let fields = def
+                    .input_fields
+                    .iter()
+                    .flatten()
+                    .map(|f| input_value_meta(registry, info, f))
+                    .collect::<Vec<_>>();
+                let meta = registry.build_input_object_type::<Self>(info, &fields);
+                match &def.description {
+                    Some(d) => meta.description(d).into_meta(),
+                    None => meta.into_meta(),
+                }
+            }
+            Kind::Object => {
+                let fields = fields_meta(registry, info, def);
+                let interfaces = types(registry, &def.interfaces);
+                let meta = registry
+                    .build_object_type::<Self>(info, &fields)
+                    .interfaces(&interfaces);
+                match &def.description {
+                    Some(d) => meta.description(d).into_meta(),
+                    None => meta.into_meta(),
+                }
+            }
+            Kind::Interface => {
+                let fields = fields_meta(registry, info, def);
+                let interfaces = types(registry, &def.interfaces);
+                // Register the implementers, even if they aren't referenced
+                // by any field.
+                types(registry, &def.possible_types);
+                let meta = registry
+                    .build_interface_type::<Self>(info, &fields)
+                    .interfaces(&interfaces);
+                match &def.description {
+                    Some(d) => meta.description(d).into_meta(),
+                    None => meta.into_meta(),
+                }
+            }
+            Kind::Union => {
+                let members = types(registry, &def.possible_types);
+                let meta = registry.build_union_type::<Self>(info, &members);
+                match &def.description {
+                    Some(d) => meta.description(d).into_meta(),
+                    None => meta.into_meta(),
+                }
+            }
+            Kind::List | Kind::NonNull => {
+                panic!("`{}` is not a named type", def.name)
+            }
+        }
+    }
+}
+
+impl<S: ScalarValue> GraphQLValue<S> for MockValue<S> {
+    type Context = ();
+    type TypeInfo = MockTypeInfo<S>;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field_name: &str,
+        arguments: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        let type_name = info.name().unwrap_or_default();
+        let field = info
+            .definition()
+            .and_then(|def| def.fields.iter().flatten().find(|f| f.name == field_name))
+            .ok_or_else(|| format!("Unknown field `{field_name}` on mocked type `{type_name}`"))?;
+
+        let canned = match &self.data {
+            Some(Value::Object(obj)) => obj.get_field_value(field_name).cloned(),
+            _ => None,
+        };
+        let data = match canned {
+            Some(value) => Some(value),
+            None => info
+                .definitions
+                .resolvers
+                .get(&(type_name.to_owned(), field_name.to_owned()))
+                .map(|resolve| resolve(arguments))
+                .transpose()?,
+        };
+
+        executor.resolve(&info.of(field.ty.clone()), &Self { data })
+    }
+
+    fn resolve_into_type(
+        &self,
+        info: &Self::TypeInfo,
+        type_name: &str,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        self.resolve(&info.named(type_name), selection_set, executor)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        match info.definition() {
+            Some(def) if matches!(def.kind, Kind::Interface | Kind::Union) => {
+                self.concrete_type(def).unwrap_or_default().into()
+            }
+            _ => info.name().unwrap_or_default().into(),
+        }
+    }
+
+    fn resolve(
+        &self,
+        info: &Self::TypeInfo,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        if matches!(self.data, Some(Value::Null)) {
+            return match &info.ty {
+                TypeRef::NonNull(_) => Err("Mocked value of non-null type is null".into()),
+                _ => Ok(Value::null()),
+            };
+        }
+
+        match &info.ty {
+            TypeRef::NonNull(of) => {
+                let value = self.resolve(&info.of((**of).clone()), selection_set, executor)?;
+                if value.is_null() {
+                    Err("Mocked value of non-null type is null".into())
+                } else {
+                    Ok(value)
+                }
+            }
+            TypeRef::List(of) => {
+                let item_info = info.of((**of).clone());
+                let items = match &self.data {
+                    Some(Value::List(items)) => items.iter().cloned().map(Some).collect(),
+                    Some(value) => vec![Some(value.clone())],
+                    None => vec![None],
+                };
+                items
+                    .into_iter()
+                    .map(|data| executor.resolve(&item_info, &Self { data }))
+                    .collect::<Result<_, _>>()
+                    .map(Value::list)
+            }
+            TypeRef::Named(name) => {
+                let def = info.definition();
+                match (def.map(|d| d.kind), selection_set) {
+                    (Some(Kind::Interface | Kind::Union), _) => {
+                        match def.and_then(|d| self.concrete_type(d)) {
+                            Some(concrete) => {
+                                self.resolve_into_type(info, concrete, selection_set, executor)
+                            }
+                            None => Ok(Value::null()),
+                        }
+                    }
+                    (_, Some(selection_set)) => {
+                        let mut res = Object::with_capacity(selection_set.len());
+                        Ok(
+                            if resolve_selection_set_into(
+                                self,
+                                info,
+                                selection_set,
+                                executor,
+                                &mut res,
+                            ) {
+                                Value::Object(res)
+                            } else {
+                                Value::null()
+                            },
+                        )
+                    }
+                    (kind, None) => Ok(match &self.data {
+                        Some(value) => value.clone(),
+                        None => generate_leaf(name, kind, def),
+                    }),
+                }
+            }
+        }
+    }
+}
+
+impl<S> GraphQLValueAsync<S> for MockValue<S>
+where
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_into_type_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        type_name: &str,
+        selection_set: Option<&'a [Selection<'a, S>]>,
+        executor: &'a Executor<'a, 'a, Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(future::ready(self.resolve_into_type(
+            info,
+            type_name,
+            selection_set,
+            executor,
+        )))
+    }
+
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field_name: &'a str,
+        arguments: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(future::ready(
+            self.resolve_field(info, field_name, arguments, executor),
+        ))
+    }
+
+    fn resolve_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        selection_set: Option<&'a [Selection<S>]>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        // Mocked values are always ready, so there is nothing to await.
+        Box::pin(future::ready(self.resolve(info, selection_set, executor)))
+    }
+}
+
+impl<S> FromInputValue<S> for MockValue<S> {
+    type Error = Infallible;
+
+    fn from_input_value(_: &InputValue<S>) -> Result<Self, Self::Error> {
+        Ok(Self::generated())
+    }
+}
+
+impl<S: ScalarValue> ParseScalarValue<S> for MockValue<S> {
+    fn from_str(token: ScalarToken<'_>) -> ParseScalarResult<S> {
+        match token {
+            ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(token),
+            ScalarToken::Int(_) => <i32 as ParseScalarValue<S>>::from_str(token)
+                .or_else(|_| <f64 as ParseScalarValue<S>>::from_str(token)),
+            ScalarToken::Float(_) => <f64 as ParseScalarValue<S>>::from_str(token),
+        }
+    }
+}
+
+impl<S: ScalarValue> MockValue<S> {
+    /// Returns the name of the concrete type to resolve the provided abstract
+    /// type into.
+    fn concrete_type<'d>(&'d self, def: &'d TypeDef) -> Option<&'d str> {
+        let possible = def
+            .possible_types
+            .iter()
+            .flatten()
+            .filter_map(TypeRef::name);
+        let typename = match &self.data {
+            Some(Value::Object(obj)) => obj
+                .get_field_value("__typename")
+                .and_then(Value::as_scalar)
+                .and_then(ScalarValue::as_str),
+            _ => None,
+        };
+        match typename {
+            Some(name) => possible.clone().find(|t| *t == name),
+            None => possible.clone().next(),
+        }
+    }
+}
+
+/// Generates a value of the named leaf type.
+fn generate_leaf<S: ScalarValue>(
+    name: &str,
+    kind: Option<Kind>,
+    def: Option<&TypeDef>,
+) -> Value<S> {
+    match (name, kind) {
+        ("Int", _) => Value::scalar(42),
+        ("Float", _) => Value::scalar(4.2),
+        ("Boolean", _) => Value::scalar(true),
+        ("ID", _) => Value::scalar("1".to_owned()),
+        (_, Some(Kind::Enum)) => def
+            .and_then(|d| d.enum_values.iter().flatten().next())
+            .map(|v| Value::scalar(v.name.clone()))
+            .unwrap_or_else(Value::null),
+        _ => Value::scalar("Hello World".to_owned()),
+    }
+}
+
+/// Builds the metadata of the fields of the provided type definition.
+fn fields_meta<'r, S: ScalarValue + 'r>(
+    registry: &mut Registry<'r, S>,
+    info: &MockTypeInfo<S>,
+    def: &TypeDef,
+) -> Vec<Field<'r, S>> {
+    def.fields
+        .iter()
+        .flatten()
+        .map(|f| {
+            let mut field = Field {
+                name: f.name.as_str().into(),
+                description: None,
+                arguments: None,
+                field_type: type_meta(registry, info, &f.ty),
+                deprecation_status: DeprecationStatus::Current,
+                complexity: None,
+                metadata: Metadata::new(),
+            };
+            for arg in &f.args {
+                field = field.argument(input_value_meta(registry, info, arg));
+            }
+            if let Some(d) = &f.description {
+                field = field.description(d);
+            }
+            if f.is_deprecated {
+                field = field.deprecated(f.deprecation_reason.as_deref());
+            }
+            field
+        })
+        .collect()
+}
+
+/// Builds the metadata of the provided argument or input field definition.
+fn input_value_meta<'r, S: ScalarValue + 'r>(
+    registry: &mut Registry<'r, S>,
+    info: &MockTypeInfo<S>,
+    def: &InputValueDef,
+) -> Argument<'r, S> {
+    let mut arg = Argument::new(&def.name, type_meta(registry, info, &def.ty));
+    if let Some(d) = &def.description {
+        arg = arg.description(d);
+    }
+    if let Some(value) = def.default_value.as_deref().and_then(parse_literal) {
+        arg = arg.default_value(value);
+    }
+    arg
+}
+
+/// Converts the provided type reference into a [`Type`], registering the named
+/// type it refers to.
+fn type_meta<'r, S: ScalarValue + 'r>(
+    registry: &mut Registry<'r, S>,
+    info: &MockTypeInfo<S>,
+    ty: &TypeRef,
+) -> Type<'r> {
+    match ty {
+        TypeRef::NonNull(of) => match type_meta(registry, info, of) {
+            Type::Named(name) => Type::NonNullNamed(name),
+            Type::List(of, size) => Type::NonNullList(of, size),
+            ty => ty,
+        },
+        TypeRef::List(of) => Type::List(Box::new(type_meta(registry, info, of)), None),
+        TypeRef::Named(name) => {
+            let ty = match name.as_str() {
+                "Int" => registry.get_type::<i32>(&()),
+                "Float" => registry.get_type::<f64>(&()),
+                "String" => registry.get_type::<String>(&()),
+                "Boolean" => registry.get_type::<bool>(&()),
+                "ID" => registry.get_type::<ID>(&()),
+                _ => registry.get_type::<MockValue<S>>(&info.named(name)),
+            };
+            match ty {
+                Type::NonNullNamed(name) => Type::Named(name),
+                ty => ty,
+            }
+        }
+    }
+}
+
+/// Parses the provided GraphQL literal of a default value.
+///
+/// Returns [`None`] if the literal cannot be parsed.
+fn parse_literal<S: ScalarValue>(literal: &str) -> Option<InputValue<S>> {
+    let tokens = Lexer::new(literal)
+        .map(|t| t.map(|t| t.item))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    let mut tokens = tokens.iter();
+    let value = parse_value(&mut tokens)?;
+    matches!(tokens.next(), Some(Token::EndOfFile)).then_some(value)
+}
+
+fn parse_value<S: ScalarValue>(tokens: &mut slice::Iter<'_, Token<'_>>) -> Option<InputValue<S>> {
+    Some(match *tokens.next()? {
+        Token::Scalar(token) => {
+            InputValue::Scalar(<MockValue<S> as ParseScalarValue<S>>::from_str(token).ok()?)
+        }
+        Token::Name("true") => InputValue::scalar(true),
+        Token::Name("false") => InputValue::scalar(false),
+        Token::Name("null") => InputValue::null(),
+        Token::Name(name) => InputValue::enum_value(name),
+        Token::BracketOpen => {
+            let mut items = vec![];
+            while tokens.as_slice().first()? != &Token::BracketClose {
+                items.push(parse_value(tokens)?);
+            }
+            tokens.next();
+            InputValue::list(items)
+        }
+        Token::CurlyOpen => {
+            let mut fields = IndexMap::new();
+            loop {
+                match *tokens.next()? {
+                    Token::CurlyClose => break,
+                    Token::Name(name) if tokens.next()? == &Token::Colon => {
+                        fields.insert(name, parse_value(tokens)?);
+                    }
+                    _ => return None,
+                }
+            }
+            InputValue::object(fields)
+        }
+        _ => return None,
+    })
+}
+
+/// Types of a [`MockSchema`] along with the resolvers of its fields.
+struct Definitions<S> {
+    query_type: String,
+    mutation_type: Option<String>,
+    types: HashMap<String, TypeDef>,
+    resolvers: HashMap<(String, String), Resolver<S>>,
+}
+
+/// Result of the introspection query, either the whole response or its `data`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IntrospectionResponse {
+    Data { data: Introspection },
+    Introspection(Introspection),
+}
+
+#[derive(Deserialize)]
+struct Introspection {
+    #[serde(rename = "__schema")]
+    schema: SchemaDef,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SchemaDef {
+    query_type: NamedRef,
+    mutation_type: Option<NamedRef>,
+    types: Vec<TypeDef>,
+}
+
+#[derive(Deserialize)]
+struct NamedRef {
+    name: String,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Kind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TypeDef {
+    kind: Kind,
+    name: String,
+    description: Option<String>,
+    fields: Option<Vec<FieldDef>>,
+    input_fields: Option<Vec<InputValueDef>>,
+    interfaces: Option<Vec<TypeRef>>,
+    enum_values: Option<Vec<EnumValueDef>>,
+    possible_types: Option<Vec<TypeRef>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldDef {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    args: Vec<InputValueDef>,
+    #[serde(rename = "type")]
+    ty: TypeRef,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InputValueDef {
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    ty: TypeRef,
+    default_value: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnumValueDef {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+/// Reference to a (possibly wrapped) type of a [`MockSchema`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(try_from = "RawTypeRef")]
+enum TypeRef {
+    Named(String),
+    List(Box<TypeRef>),
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Named(name) => Some(name),
+            Self::List(_) | Self::NonNull(_) => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTypeRef {
+    kind: Kind,
+    name: Option<String>,
+    of_type: Option<Box<RawTypeRef>>,
+}
+
+impl TryFrom<RawTypeRef> for TypeRef {
+    type Error = String;
+
+    fn try_from(raw: RawTypeRef) -> Result<Self, Self::Error> {
+        let of_type = |raw: RawTypeRef| {
+            raw.of_type
+                .ok_or_else(|| format!("`{:?}` type reference without `ofType`", raw.kind))
+                .and_then(|of| Self::try_from(*of))
+                .map(Box::new)
+        };
+        match raw.kind {
+            Kind::List => of_type(raw).map(Self::List),
+            Kind::NonNull => of_type(raw).map(Self::NonNull),
+            kind => raw
+                .name
+                .map(Self::Named)
+                .ok_or_else(|| format!("`{kind:?}` type reference without `name`")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graphql_value, graphql_vars, introspect, IntrospectionFormat};
+
+    use super::*;
+
+    const INTROSPECTION: &str = r#"{"__schema": {
+        "queryType": {"name": "Query"},
+        "mutationType": {"name": "Mutation"},
+        "subscriptionType": null,
+        "types": [
+            {"kind": "OBJECT", "name": "Query", "fields": [
+                {"name": "user", "args": [
+                    {"name": "id", "type": {"kind": "NON_NULL", "name": null, "ofType":
+                        {"kind": "SCALAR", "name": "ID", "ofType": null}},
+                     "defaultValue": null}
+                 ], "type": {"kind": "OBJECT", "name": "User", "ofType": null}},
+                {"name": "search", "args": [
+                    {"name": "first", "type": {"kind": "SCALAR", "name": "Int"},
+                     "defaultValue": "10"}
+                 ], "type": {"kind": "NON_NULL", "ofType": {"kind": "LIST", "ofType":
+                    {"kind": "NON_NULL", "ofType": {"kind": "UNION", "name": "Result"}}}}}
+            ], "interfaces": []},
+            {"kind": "OBJECT", "name": "Mutation", "fields": [
+                {"name": "rename", "args": [
+                    {"name": "input", "type": {"kind": "INPUT_OBJECT", "name": "RenameInput"}}
+                 ], "type": {"kind": "SCALAR", "name": "Boolean"}}
+            ]},
+            {"kind": "INTERFACE", "name": "Node", "fields": [
+                {"name": "id", "args": [], "type": {"kind": "NON_NULL", "ofType":
+                    {"kind": "SCALAR", "name": "ID"}}}
+            ], "possibleTypes": [{"kind": "OBJECT", "name": "User"}]},
+            {"kind": "OBJECT", "name": "User", "description": "A user.", "fields": [
+                {"name": "id", "args": [], "type": {"kind": "NON_NULL", "ofType":
+                    {"kind": "SCALAR", "name": "ID"}}},
+                {"name": "name", "args": [], "type": {"kind": "SCALAR", "name": "String"}},
+                {"name": "role", "args": [], "type": {"kind": "ENUM", "name": "Role"}},
+                {"name": "joined", "args": [], "type": {"kind": "SCALAR", "name": "Date"}}
+            ], "interfaces": [{"kind": "INTERFACE", "name": "Node"}]},
+            {"kind": "OBJECT", "name": "Post", "fields": [
+                {"name": "title", "args": [], "type": {"kind": "SCALAR", "name": "String"}}
+            ], "interfaces": []},
+            {"kind": "UNION", "name": "Result", "possibleTypes": [
+                {"kind": "OBJECT", "name": "User"},
+                {"kind": "OBJECT", "name": "Post"}
+            ]},
+            {"kind": "ENUM", "name": "Role", "enumValues": [
+                {"name": "ADMIN", "isDeprecated": false},
+                {"name": "GUEST", "isDeprecated": false}
+            ]},
+            {"kind": "SCALAR", "name": "Date"},
+            {"kind": "INPUT_OBJECT", "name": "RenameInput", "inputFields": [
+                {"name": "name", "type": {"kind": "SCALAR", "name": "String"},
+                 "defaultValue": "\"anonymous\""}
+            ]},
+            {"kind": "SCALAR", "name": "String"},
+            {"kind": "OBJECT", "name": "__Schema", "fields": []}
+        ]
+    }}"#;
+
+    fn schema() -> MockSchema {
+        serde_json::from_str(INTROSPECTION).unwrap()
+    }
+
+    #[tokio::test]
+    async fn generates_values() {
+        let schema = schema().into_root_node();
+
+        let res = crate::execute(
+            r#"{
+                user(id: "7") { __typename id name role joined }
+                search { ... on Post { title } ... on Node { id } }
+            }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        )
+        .await;
+
+        assert_eq!(
+            res,
+            Ok((
+                graphql_value!({
+                    "user": {
+                        "__typename": "User",
+                        "id": "1",
+                        "name": "Hello World",
+                        "role": "ADMIN",
+                        "joined": "Hello World",
+                    },
+                    "search": [{"id": "1"}],
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_canned_values() {
+        let schema = schema()
+            .with_response(
+                "Query",
+                "search",
+                graphql_value!([
+                    {"__typename": "Post", "title": "Hello"},
+                    {"__typename": "User", "id": "2"},
+                ]),
+            )
+            .with_resolver("Query", "user", |args| {
+                let id = args.get::<String>("id")?.unwrap_or_default();
+                Ok(graphql_value!({"id": id, "name": null}))
+            })
+            .into_root_node();
+
+        let res = crate::execute_sync(
+            r#"{
+                user(id: "7") { id name }
+                search { __typename ... on Post { title } ... on User { id } }
+            }"#,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        );
+
+        assert_eq!(
+            res,
+            Ok((
+                graphql_value!({
+                    "user": {"id": "7", "name": null},
+                    "search": [
+                        {"__typename": "Post", "title": "Hello"},
+                        {"__typename": "User", "id": "2"},
+                    ],
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_mutations() {
+        let schema = schema()
+            .with_response("Mutation", "rename", graphql_value!(false))
+            .into_root_node();
+
+        let res = crate::execute_sync(
+            "mutation { rename(input: {}) }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        );
+
+        assert_eq!(res, Ok((graphql_value!({"rename": false}), vec![])));
+    }
+
+    #[test]
+    fn validates_against_imported_schema() {
+        let schema = schema().into_root_node();
+
+        assert!(
+            crate::execute_sync("{ user { id } }", None, &schema, &graphql_vars! {}, &()).is_err(),
+            "missing required argument must be rejected",
+        );
+        assert!(
+            crate::execute_sync("{ unknown }", None, &schema, &graphql_vars! {}, &()).is_err(),
+            "unknown field must be rejected",
+        );
+    }
+
+    #[test]
+    fn reexposes_introspection() {
+        let schema = schema().into_root_node();
+
+        let (res, errors) = introspect(&schema, &(), IntrospectionFormat::default()).unwrap();
+        assert!(errors.is_empty());
+
+        let types = res
+            .as_object_value()
+            .and_then(|o| o.get_field_value("__schema"))
+            .and_then(|s| s.as_object_value())
+            .and_then(|s| s.get_field_value("types"))
+            .and_then(Value::as_list_value)
+            .unwrap();
+        let user = types
+            .iter()
+            .filter_map(Value::as_object_value)
+            .find(|t| t.get_field_value("name") == Some(&graphql_value!("User")))
+            .unwrap();
+        assert_eq!(
+            user.get_field_value("description"),
+            Some(&graphql_value!("A user.")),
+        );
+    }
+
+    #[test]
+    fn parses_default_values() {
+        assert_eq!(
+            parse_literal::<DefaultScalarValue>(r#"{a: [1, 2.5, "s"], b: RED, c: null}"#),
+            Some(InputValue::object(
+                [
+                    (
+                        "a",
+                        InputValue::list(vec![
+                            InputValue::scalar(1),
+                            InputValue::scalar(2.5),
+                            InputValue::scalar("s"),
+                        ]),
+                    ),
+                    ("b", InputValue::enum_value("RED")),
+                    ("c", InputValue::null()),
+                ]
+                .into_iter()
+                .collect::<IndexMap<_, _>>(),
+            )),
+        );
+        assert_eq!(parse_literal::<DefaultScalarValue>("[1"), None);
+        assert_eq!(parse_literal::<DefaultScalarValue>("1 2"), None);
+    }
+}
@@ -0,0 +1,353 @@
+//! Utilities for testing custom GraphQL types and schemas.
+
+mod mock;
+
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    ast::{FromInputValue, InputValue, ToInputValue},
+    executor::{resolve_standalone_value, IntoFieldError, Rng, SplitMixRng},
+    parser::{Lexer, Token},
+    schema::model::SchemaType,
+    types::{
+        base::GraphQLValue,
+        scalars::{EmptyMutation, EmptySubscription},
+    },
+    value::{DefaultScalarValue, ParseScalarValue, ScalarValue},
+};
+
+pub use self::mock::{MockRootNode, MockSchema, MockTypeInfo, MockValue};
+
+/// Generator of random values checked by a [`ScalarRoundtripSuite`].
+type Generator<T> = Box<dyn FnMut(&dyn Rng) -> T>;
+
+/// Default number of values generated by a [`ScalarRoundtripSuite`].
+const DEFAULT_CASES: usize = 256;
+
+/// Creates a new [`ScalarRoundtripSuite`] checking the custom scalar `T`
+/// represented with a [`DefaultScalarValue`].
+///
+/// Use [`ScalarRoundtripSuite::new()`] to check the scalar against a custom
+/// [`ScalarValue`].
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{testing::scalar_roundtrip_suite, GraphQLScalar};
+/// #
+/// #[derive(Debug, GraphQLScalar, PartialEq)]
+/// #[graphql(transparent)]
+/// struct UserId(i32);
+///
+/// // Usually, it's placed into a `#[test]` function.
+/// scalar_roundtrip_suite::<UserId>()
+///     .value(UserId(i32::MIN))
+///     .generator(|rng| UserId(rng.next_u64() as i32))
+///     .run();
+/// ```
+#[must_use]
+pub fn scalar_roundtrip_suite<T>() -> ScalarRoundtripSuite<T> {
+    ScalarRoundtripSuite::new()
+}
+
+/// Property-based test suite checking the consistency of the input, output
+/// and token parsing functions of a custom GraphQL scalar `T`.
+///
+/// For every explicitly provided and every generated value `x` it asserts
+/// that:
+/// - `x` is resolved into a scalar [`Value`], which is converted back into the
+///   same `x` via [`FromInputValue`] (`from_input(to_output(x)) == x`);
+/// - `x` is converted into an [`InputValue`] via [`ToInputValue`], which is
+///   converted back into the same `x` (`from_input(to_input(x)) == x`);
+/// - the GraphQL literal representing the resolved [`Value`] is parsed via
+///   [`ParseScalarValue`] into a value, which is converted back into the same
+///   `x` via [`FromInputValue`].
+///
+/// Violations are reported by panicking, so the suite is intended to be
+/// [`run()`] in tests.
+///
+/// Generated values are deterministic for the same [`seed()`], which is
+/// reported on failures to reproduce them.
+///
+/// [`run()`]: ScalarRoundtripSuite::run
+/// [`seed()`]: ScalarRoundtripSuite::seed
+/// [`Value`]: crate::Value
+pub struct ScalarRoundtripSuite<T, S = DefaultScalarValue> {
+    values: Vec<T>,
+    generator: Option<Generator<T>>,
+    cases: usize,
+    seed: u64,
+    _scalar: PhantomData<S>,
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for ScalarRoundtripSuite<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScalarRoundtripSuite")
+            .field("values", &self.values)
+            .field("generator", &self.generator.as_ref().map(|_| ".."))
+            .field("cases", &self.cases)
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
+impl<T, S> Default for ScalarRoundtripSuite<T, S> {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            generator: None,
+            cases: DEFAULT_CASES,
+            seed: 0,
+            _scalar: PhantomData,
+        }
+    }
+}
+
+impl<T, S> ScalarRoundtripSuite<T, S> {
+    /// Creates a new empty [`ScalarRoundtripSuite`] checking the custom scalar
+    /// `T` represented with the `S` [`ScalarValue`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the provided `value` to be checked explicitly.
+    ///
+    /// Useful for covering edge cases, which are unlikely to be generated.
+    #[must_use]
+    pub fn value(mut self, value: T) -> Self {
+        self.values.push(value);
+        self
+    }
+
+    /// Adds the provided `values` to be checked explicitly.
+    #[must_use]
+    pub fn values(mut self, values: impl IntoIterator<Item = T>) -> Self {
+        self.values.extend(values);
+        self
+    }
+
+    /// Sets the `generator` of random values to be checked.
+    ///
+    /// Overwrites any previously set generator.
+    #[must_use]
+    pub fn generator(mut self, generator: impl FnMut(&dyn Rng) -> T + 'static) -> Self {
+        self.generator = Some(Box::new(generator));
+        self
+    }
+
+    /// Sets the number of values to be generated by the [`generator()`].
+    ///
+    /// Defaults to `256`.
+    ///
+    /// [`generator()`]: ScalarRoundtripSuite::generator
+    #[must_use]
+    pub fn cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Sets the `seed` of the [`Rng`] provided to the [`generator()`].
+    ///
+    /// Defaults to `0`.
+    ///
+    /// [`generator()`]: ScalarRoundtripSuite::generator
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+impl<T, S> ScalarRoundtripSuite<T, S>
+where
+    T: GraphQLValue<S, Context = (), TypeInfo = ()>
+        + FromInputValue<S>
+        + ToInputValue<S>
+        + ParseScalarValue<S>
+        + PartialEq
+        + fmt::Debug,
+    <T as FromInputValue<S>>::Error: IntoFieldError<S>,
+    S: ScalarValue,
+{
+    /// Runs this [`ScalarRoundtripSuite`] over all the explicitly provided and
+    /// generated values.
+    ///
+    /// # Panics
+    ///
+    /// If any of the checked values violates the round-trip laws.
+    pub fn run(self) {
+        let schema =
+            SchemaType::new::<EmptyMutation, EmptyMutation, EmptySubscription>(&(), &(), &());
+
+        for (i, x) in self.values.iter().enumerate() {
+            if let Err(e) = check(x, &schema) {
+                panic!("explicit value #{i} `{x:?}` {e}");
+            }
+        }
+
+        if let Some(mut generate) = self.generator {
+            let rng = SplitMixRng::with_seed(self.seed);
+            for i in 0..self.cases {
+                let x = generate(&rng);
+                if let Err(e) = check(&x, &schema) {
+                    panic!("generated value #{i} `{x:?}` (seed {}) {e}", self.seed,);
+                }
+            }
+        }
+    }
+}
+
+/// Checks the round-trip laws described in [`ScalarRoundtripSuite`] docs for
+/// the provided value `x`, returning a description of the first violation, if
+/// any.
+fn check<T, S>(x: &T, schema: &SchemaType<S>) -> Result<(), String>
+where
+    T: GraphQLValue<S, Context = (), TypeInfo = ()>
+        + FromInputValue<S>
+        + ToInputValue<S>
+        + ParseScalarValue<S>
+        + PartialEq
+        + fmt::Debug,
+    <T as FromInputValue<S>>::Error: IntoFieldError<S>,
+    S: ScalarValue,
+{
+    // Converts the provided `input` back, ensuring it results in `x`.
+    let roundtrip = |input: &InputValue<S>, what: &str| match T::from_input_value(input) {
+        Ok(y) if &y == x => Ok(()),
+        Ok(y) => Err(format!("{what} `{input}`, which is parsed back as `{y:?}`")),
+        Err(e) => Err(format!(
+            "{what} `{input}`, which fails to be parsed back: {}",
+            e.into_field_error().message(),
+        )),
+    };
+
+    let output = resolve_standalone_value(x, &(), schema)
+        .map_err(|e| format!("fails to be resolved: {}", e.message()))?;
+    let output = output
+        .as_scalar()
+        .cloned()
+        .map(InputValue::Scalar)
+        .ok_or_else(|| format!("is resolved into a non-scalar value `{output}`"))?;
+    roundtrip(&output, "is resolved into")?;
+
+    roundtrip(&x.to_input_value(), "is converted into input value")?;
+
+    let literal = match output.as_string_value() {
+        Some(s) => string_literal(s),
+        None => output.to_string(),
+    };
+    if let Some(Ok(token)) = Lexer::new(&literal).next() {
+        if let Token::Scalar(token) = token.item {
+            let parsed = T::from_str(token).map_err(|e| {
+                format!("is resolved into `{literal}`, whose token fails to be parsed: {e}")
+            })?;
+            roundtrip(
+                &InputValue::Scalar(parsed),
+                &format!("is resolved into `{literal}`, whose token is parsed into"),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Represents the provided string `s` as an escaped GraphQL string literal.
+fn string_literal(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GraphQLScalar, InputValue, ScalarValue, Value, ID};
+
+    use super::scalar_roundtrip_suite;
+
+    #[test]
+    fn passes_for_builtin_scalars() {
+        scalar_roundtrip_suite::<i32>()
+            .values([i32::MIN, -1, 0, 1, i32::MAX])
+            .generator(|rng| rng.next_u64() as i32)
+            .run();
+        scalar_roundtrip_suite::<f64>()
+            .values([-0.5, 0.0, 1.0, 1e-10])
+            .generator(|rng| rng.next_f64() * 1e6 - 5e5)
+            .run();
+        scalar_roundtrip_suite::<String>()
+            .values(["".into(), "\"quoted\"\n\t\\".into(), "юникод".into()])
+            .generator(|rng| format!("str-{}", rng.next_u64()))
+            .run();
+        scalar_roundtrip_suite::<bool>().values([true, false]).run();
+        scalar_roundtrip_suite::<ID>()
+            .generator(|rng| ID::new(rng.next_u64().to_string()))
+            .run();
+    }
+
+    /// Scalar, whose output representation mismatches the input one.
+    #[derive(Debug, GraphQLScalar, PartialEq)]
+    #[graphql(with = mismatched, parse_token(i32))]
+    struct Mismatched(i32);
+
+    mod mismatched {
+        use super::*;
+
+        pub(super) fn to_output<S: ScalarValue>(v: &Mismatched) -> Value<S> {
+            Value::scalar(v.0.to_string())
+        }
+
+        pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Mismatched, String> {
+            v.as_int_value()
+                .map(Mismatched)
+                .ok_or_else(|| format!("Expected `Int`, found: {v}"))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "fails to be parsed back")]
+    fn detects_output_mismatch() {
+        scalar_roundtrip_suite::<Mismatched>()
+            .generator(|rng| Mismatched(rng.next_u64() as i32))
+            .run();
+    }
+
+    /// Scalar, whose token parsing mismatches its input parsing.
+    #[derive(Debug, GraphQLScalar, PartialEq)]
+    #[graphql(with = bad_token, parse_token(String))]
+    struct BadToken(i32);
+
+    mod bad_token {
+        use super::*;
+
+        pub(super) fn to_output<S: ScalarValue>(v: &BadToken) -> Value<S> {
+            Value::scalar(v.0)
+        }
+
+        pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<BadToken, String> {
+            v.as_int_value()
+                .map(BadToken)
+                .ok_or_else(|| format!("Expected `Int`, found: {v}"))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "whose token fails to be parsed")]
+    fn detects_token_mismatch() {
+        scalar_roundtrip_suite::<BadToken>()
+            .value(BadToken(42))
+            .run();
+    }
+}
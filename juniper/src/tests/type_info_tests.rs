@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use indexmap::IndexMap;
 
 use crate::{
@@ -105,3 +107,169 @@ fn test_node() {
         )),
     );
 }
+
+/// Catalog of dynamic object types: type name to its fields with their type
+/// names.
+type Catalog = IndexMap<String, Vec<(String, String)>>;
+
+#[derive(Clone)]
+pub struct DynTypeInfo {
+    name: String,
+    catalog: Arc<Catalog>,
+}
+
+impl DynTypeInfo {
+    fn field_type(&self, field_name: &str) -> Self {
+        let (_, ty) = self.catalog[&self.name]
+            .iter()
+            .find(|(name, _)| name == field_name)
+            .unwrap();
+        Self {
+            name: ty.clone(),
+            catalog: self.catalog.clone(),
+        }
+    }
+}
+
+pub enum DynField {
+    Scalar(String),
+    Object(DynObject),
+}
+
+pub struct DynObject {
+    fields: IndexMap<String, DynField>,
+}
+
+impl<S> GraphQLType<S> for DynObject
+where
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(&info.name)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let fields = info.catalog[&info.name]
+            .iter()
+            .map(|(name, ty)| match ty.as_str() {
+                "String" => registry.field::<String>(name, &()),
+                _ => registry.field_lazy::<DynObject>(name, &info.field_type(name)),
+            })
+            .collect::<Vec<_>>();
+
+        registry
+            .build_object_type::<DynObject>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<S> GraphQLValue<S> for DynObject
+where
+    S: ScalarValue,
+{
+    type Context = ();
+    type TypeInfo = DynTypeInfo;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field_name: &str,
+        _: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match &self.fields[field_name] {
+            DynField::Scalar(s) => executor.resolve(&(), s),
+            DynField::Object(o) => executor.resolve(&info.field_type(field_name), o),
+        }
+    }
+}
+
+#[test]
+fn test_lazily_registered_cyclic_types() {
+    let catalog = Arc::new(
+        [
+            (
+                "Author".into(),
+                vec![
+                    ("name".into(), "String".into()),
+                    ("book".into(), "Book".into()),
+                ],
+            ),
+            (
+                "Book".into(),
+                vec![
+                    ("title".into(), "String".into()),
+                    ("author".into(), "Author".into()),
+                ],
+            ),
+        ]
+        .into_iter()
+        .collect::<Catalog>(),
+    );
+    let root = DynObject {
+        fields: [
+            ("name".into(), DynField::Scalar("Tolkien".into())),
+            (
+                "book".into(),
+                DynField::Object(DynObject {
+                    fields: [
+                        ("title".into(), DynField::Scalar("The Hobbit".into())),
+                        (
+                            "author".into(),
+                            DynField::Object(DynObject {
+                                fields: [("name".into(), DynField::Scalar("Tolkien".into()))]
+                                    .into_iter()
+                                    .collect(),
+                            }),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    };
+    let schema: RootNode<_, _, _> = RootNode::new_with_info(
+        root,
+        EmptyMutation::new(),
+        EmptySubscription::new(),
+        DynTypeInfo {
+            name: "Author".into(),
+            catalog,
+        },
+        (),
+        (),
+    );
+
+    assert!(schema.schema.concrete_type_by_name("Book").is_some());
+
+    let doc = r#"{
+        name
+        book {
+            title
+            author { name }
+        }
+    }"#;
+    assert_eq!(
+        crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &()),
+        Ok((
+            graphql_value!({
+                "name": "Tolkien",
+                "book": {
+                    "title": "The Hobbit",
+                    "author": {"name": "Tolkien"},
+                },
+            }),
+            vec![],
+        )),
+    );
+}
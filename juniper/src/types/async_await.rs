@@ -1,7 +1,7 @@
 use crate::{
     ast::Selection,
     executor::{ExecutionResult, Executor},
-    parser::Spanning,
+    parser::{SourcePosition, Spanning},
     value::{DefaultScalarValue, Object, ScalarValue, Value},
 };
 
@@ -134,7 +134,7 @@ where
 
 // Wrapper function around resolve_selection_set_into_async_recursive.
 // This wrapper is necessary because async fns can not be recursive.
-fn resolve_selection_set_into_async<'a, 'e, T, S>(
+pub(crate) fn resolve_selection_set_into_async<'a, 'e, T, S>(
     instance: &'a T,
     info: &'a T::TypeInfo,
     selection_set: &'e [Selection<'e, S>],
@@ -180,16 +180,21 @@ where
     use futures::stream::{FuturesOrdered, StreamExt as _};
 
     #[derive(futures_enum::Future)]
-    enum AsyncValueFuture<A, B, C, D> {
+    enum AsyncValueFuture<A, B, C, D, E, F> {
         Field(A),
-        FragmentSpread(B),
-        InlineFragment1(C),
-        InlineFragment2(D),
+        FragmentSpread1(B),
+        FragmentSpread2(C),
+        InlineFragment1(D),
+        InlineFragment2(E),
+        InlineFragment3(F),
     }
 
     let mut object = Object::with_capacity(selection_set.len());
 
-    let mut async_values = FuturesOrdered::<AsyncValueFuture<_, _, _, _>>::new();
+    let mut async_values = FuturesOrdered::<AsyncValueFuture<_, _, _, _, _, _>>::new();
+
+    let concurrent = executor.options().resolves_fields_concurrently();
+    let serial = executor.resolves_serially();
 
     let meta_type = executor
         .schema()
@@ -201,14 +206,27 @@ where
         )
         .expect("Type not found in schema");
 
+    let mut spread_fragments = executor.spread_fragments().cloned();
+
     for selection in selection_set {
+        if serial {
+            // Every sibling should be resolved only once the previous one is.
+            while let Some(item) = async_values.next().await {
+                if !merge_async_value(&mut object, item) {
+                    return Value::null();
+                }
+            }
+        }
+
         match *selection {
             Selection::Field(Spanning {
                 item: ref f,
                 start: ref start_pos,
                 ..
             }) => {
-                if is_excluded(&f.directives, executor.variables()) {
+                if is_excluded(&f.directives, executor.variables())
+                    || executor.is_deferred(&f.directives, *start_pos)
+                {
                     continue;
                 }
 
@@ -252,17 +270,45 @@ where
 
                 let pos = *start_pos;
                 let is_non_null = meta_field.field_type.is_non_null();
+                let parent_type = meta_type.name().unwrap_or_default();
 
                 let response_name = response_name.to_string();
                 async_values.push(AsyncValueFuture::Field(async move {
                     // TODO: implement custom future type instead of
                     //       two-level boxing.
-                    let res = instance
-                        .resolve_field_async(info, f.name.item, &args, &sub_exec)
-                        .await;
+                    let res = match sub_exec.check_recursion_depth() {
+                        // The result is discarded anyway, so there is no need
+                        // to resolve the field at all.
+                        Ok(()) if sub_exec.has_failed_strictly() => Ok(Value::null()),
+                        Ok(()) => {
+                            sub_exec
+                                .hooked_async(
+                                    parent_type,
+                                    f.name.item,
+                                    sub_exec.directed_async(
+                                        &f.directives,
+                                        parent_type,
+                                        f.name.item,
+                                        || {
+                                            sub_exec.interruptible(instance.resolve_field_async(
+                                                info,
+                                                f.name.item,
+                                                &args,
+                                                &sub_exec,
+                                            ))
+                                        },
+                                    ),
+                                )
+                                .await
+                        }
+                        Err(e) => Err(e),
+                    };
 
                     let value = match res {
-                        Ok(Value::Null) if is_non_null => None,
+                        Ok(Value::Null) if is_non_null => {
+                            sub_exec.push_nulled_error_at(parent_type, f.name.item, pos);
+                            None
+                        }
                         Ok(v) => Some(v),
                         Err(e) => {
                             sub_exec.push_error_at(e, pos);
@@ -286,18 +332,33 @@ where
                 start: ref start_pos,
                 ..
             }) => {
-                if is_excluded(&spread.directives, executor.variables()) {
+                if is_excluded(&spread.directives, executor.variables())
+                    || executor.is_deferred(&spread.directives, *start_pos)
+                {
                     continue;
                 }
 
-                let fragment = &executor
+                // The same fragment spread again into the same value resolves
+                // to the same fields, so is resolved only once.
+                let spread_fragments = spread_fragments.get_or_insert_with(Default::default);
+                if !spread_fragments
+                    .lock()
+                    .unwrap()
+                    .insert(spread.name.item.into())
+                {
+                    continue;
+                }
+
+                let fragment = executor
                     .fragment_by_name(spread.name.item)
                     .expect("Fragment could not be found");
 
-                let sub_exec = executor.type_sub_executor(
-                    Some(fragment.type_condition.item),
-                    Some(&fragment.selection_set[..]),
-                );
+                let sub_exec = executor
+                    .type_sub_executor(
+                        Some(fragment.type_condition.item),
+                        Some(&fragment.selection_set[..]),
+                    )
+                    .with_spread_fragments(spread_fragments.clone());
 
                 let concrete_type_name = instance.concrete_type_name(sub_exec.context(), info);
                 let type_name = instance.type_name(info);
@@ -306,6 +367,22 @@ where
                     .is_named_subtype(&concrete_type_name, fragment.type_condition.item)
                     || Some(fragment.type_condition.item) == type_name
                 {
+                    if concurrent {
+                        let pos = *start_pos;
+                        async_values.push(AsyncValueFuture::FragmentSpread2(async move {
+                            let sub_result = instance
+                                .resolve_into_type_async(
+                                    info,
+                                    &concrete_type_name,
+                                    Some(&fragment.selection_set[..]),
+                                    &sub_exec,
+                                )
+                                .await;
+                            AsyncValue::Nested(fragment_value(sub_result, &sub_exec, pos))
+                        }));
+                        continue;
+                    }
+
                     let sub_result = instance
                         .resolve_into_type_async(
                             info,
@@ -317,7 +394,7 @@ where
 
                     if let Ok(Value::Object(obj)) = sub_result {
                         for (k, v) in obj {
-                            async_values.push(AsyncValueFuture::FragmentSpread(async move {
+                            async_values.push(AsyncValueFuture::FragmentSpread1(async move {
                                 AsyncValue::Field(AsyncField {
                                     name: k,
                                     value: Some(v),
@@ -335,14 +412,22 @@ where
                 start: ref start_pos,
                 ..
             }) => {
-                if is_excluded(&fragment.directives, executor.variables()) {
+                if is_excluded(&fragment.directives, executor.variables())
+                    || executor.is_deferred(&fragment.directives, *start_pos)
+                {
                     continue;
                 }
 
-                let sub_exec = executor.type_sub_executor(
-                    fragment.type_condition.as_ref().map(|c| c.item),
-                    Some(&fragment.selection_set[..]),
-                );
+                let sub_exec = executor
+                    .type_sub_executor(
+                        fragment.type_condition.as_ref().map(|c| c.item),
+                        Some(&fragment.selection_set[..]),
+                    )
+                    .with_spread_fragments(
+                        spread_fragments
+                            .get_or_insert_with(Default::default)
+                            .clone(),
+                    );
 
                 if let Some(ref type_condition) = fragment.type_condition {
                     // Check whether the type matches the type condition.
@@ -351,6 +436,22 @@ where
                         .schema()
                         .is_named_subtype(&concrete_type_name, type_condition.item)
                     {
+                        if concurrent {
+                            let pos = *start_pos;
+                            async_values.push(AsyncValueFuture::InlineFragment3(async move {
+                                let sub_result = instance
+                                    .resolve_into_type_async(
+                                        info,
+                                        &concrete_type_name,
+                                        Some(&fragment.selection_set[..]),
+                                        &sub_exec,
+                                    )
+                                    .await;
+                                AsyncValue::Nested(fragment_value(sub_result, &sub_exec, pos))
+                            }));
+                            continue;
+                        }
+
                         let sub_result = instance
                             .resolve_into_type_async(
                                 info,
@@ -390,27 +491,60 @@ where
     }
 
     while let Some(item) = async_values.next().await {
-        match item {
-            AsyncValue::Field(AsyncField { name, value }) => {
-                if let Some(value) = value {
-                    merge_key_into(&mut object, &name, value);
-                } else {
-                    return Value::null();
-                }
-            }
-            AsyncValue::Nested(obj) => match obj {
-                v @ Value::Null => {
-                    return v;
-                }
-                Value::Object(obj) => {
-                    for (k, v) in obj {
-                        merge_key_into(&mut object, &k, v);
-                    }
-                }
-                _ => unreachable!(),
-            },
+        if !merge_async_value(&mut object, item) {
+            return Value::null();
         }
     }
 
     Value::Object(object)
 }
+
+/// Merges the provided resolved [`AsyncValue`] into the `object`.
+///
+/// Returns `false` if the whole `object` should be resolved as `null` instead.
+fn merge_async_value<S>(object: &mut Object<S>, value: AsyncValue<S>) -> bool {
+    match value {
+        AsyncValue::Field(AsyncField { name, value }) => {
+            if let Some(value) = value {
+                merge_key_into(object, &name, value);
+            } else {
+                return false;
+            }
+        }
+        AsyncValue::Nested(obj) => match obj {
+            Value::Null => {
+                return false;
+            }
+            Value::Object(obj) => {
+                for (k, v) in obj {
+                    merge_key_into(object, &k, v);
+                }
+            }
+            _ => unreachable!(),
+        },
+    }
+    true
+}
+
+/// Converts the result of resolving a fragment with a type condition into an
+/// object to be merged into the parent one.
+///
+/// Errors are reported to the provided `executor` rather than nullifying the
+/// parent object, the same way the fragments resolved inline do.
+fn fragment_value<CtxT, S>(
+    result: ExecutionResult<S>,
+    executor: &Executor<CtxT, S>,
+    pos: SourcePosition,
+) -> Value<S>
+where
+    S: ScalarValue,
+{
+    match result {
+        Ok(v @ Value::Object(_)) => v,
+        Ok(_) => Value::Object(Object::with_capacity(0)),
+        Err(e) => {
+            executor.push_error_at(e, pos);
+            Value::Object(Object::with_capacity(0))
+        }
+    }
+}
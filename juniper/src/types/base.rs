@@ -98,6 +98,23 @@ impl<'a, S> Arguments<'a, S> {
         Self { args }
     }
 
+    /// Creates new [`Arguments`] out of the provided `args`, filling in the
+    /// default values of the absent `meta_args`.
+    pub(crate) fn with_defaults(
+        mut args: IndexMap<&'a str, InputValue<S>>,
+        meta_args: &'a [Argument<S>],
+    ) -> Self
+    where
+        S: Clone,
+    {
+        for arg in meta_args {
+            if let Some(val) = &arg.default_value {
+                args.entry(arg.name.as_str()).or_insert_with(|| val.clone());
+            }
+        }
+        Self { args: Some(args) }
+    }
+
     /// Gets an argument by the given `name` and converts it into the desired
     /// type.
     ///
@@ -121,6 +138,27 @@ impl<'a, S> Arguments<'a, S> {
             .transpose()
             .map_err(IntoFieldError::into_field_error)
     }
+    /// Gets a string argument by the given `name`, borrowing it rather than
+    /// converting into an owned [`String`] (as [`Arguments::get()`] does).
+    ///
+    /// Returns [`None`] if an argument with such `name` is not present, or is
+    /// `null`.
+    ///
+    /// # Errors
+    ///
+    /// If the argument is not a string.
+    pub fn get_str(&self, name: &str) -> FieldResult<Option<&str>, S>
+    where
+        S: ScalarValue,
+    {
+        match self.args.as_ref().and_then(|args| args.get(name)) {
+            None | Some(InputValue::Null) => Ok(None),
+            Some(v) => v
+                .as_string_value()
+                .map(Some)
+                .ok_or_else(|| format!("Expected `String`, found: {v}").into()),
+        }
+    }
 }
 
 /// Primary trait used to resolve GraphQL values.
@@ -398,6 +436,35 @@ where
     fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
     where
         S: 'r;
+
+    /// Returns name of this [`GraphQLType`] to expose, when it's used as an
+    /// input type (of an argument or an input object field).
+    ///
+    /// Differs from the [`GraphQLType::name()`] only for the types
+    /// representing both a [GraphQL object][1] and a
+    /// [GraphQL input object][2].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+    fn input_name(info: &Self::TypeInfo) -> Option<&str> {
+        Self::name(info)
+    }
+
+    /// Returns [`MetaType`] representing this [`GraphQLType`], when it's used
+    /// as an input type (of an argument or an input object field).
+    ///
+    /// Differs from the [`GraphQLType::meta()`] only for the types
+    /// representing both a [GraphQL object][1] and a
+    /// [GraphQL input object][2].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+    fn input_meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        Self::meta(info, registry)
+    }
 }
 
 /// Resolver logic for queries'/mutations' selection set.
@@ -427,6 +494,8 @@ where
         )
         .expect("Type not found in schema");
 
+    let mut spread_fragments = executor.spread_fragments().cloned();
+
     for selection in selection_set {
         match *selection {
             Selection::Field(Spanning {
@@ -434,7 +503,9 @@ where
                 start: ref start_pos,
                 ..
             }) => {
-                if is_excluded(&f.directives, executor.variables()) {
+                if is_excluded(&f.directives, executor.variables())
+                    || executor.is_deferred(&f.directives, *start_pos)
+                {
                     continue;
                 }
 
@@ -465,25 +536,38 @@ where
                     f.selection_set.as_ref().map(|v| &v[..]),
                 );
 
-                let field_result = instance.resolve_field(
-                    info,
-                    f.name.item,
-                    &Arguments::new(
-                        f.arguments.as_ref().map(|m| {
-                            m.item
-                                .iter()
-                                .filter_map(|&(ref k, ref v)| {
-                                    v.item.clone().into_const(exec_vars).map(|v| (k.item, v))
-                                })
-                                .collect()
-                        }),
-                        &meta_field.arguments,
-                    ),
-                    &sub_exec,
+                let args = Arguments::new(
+                    f.arguments.as_ref().map(|m| {
+                        m.item
+                            .iter()
+                            .filter_map(|&(ref k, ref v)| {
+                                v.item.clone().into_const(exec_vars).map(|v| (k.item, v))
+                            })
+                            .collect()
+                    }),
+                    &meta_field.arguments,
                 );
+                if executor.has_failed_strictly() {
+                    return false;
+                }
+                let field_result = sub_exec.check_recursion_depth().and_then(|()| {
+                    let parent_type = meta_type.name().unwrap_or_default();
+                    sub_exec.hooked(parent_type, f.name.item, || {
+                        sub_exec.directed(&f.directives, parent_type, f.name.item, || {
+                            instance.resolve_field(info, f.name.item, &args, &sub_exec)
+                        })
+                    })
+                });
 
                 match field_result {
-                    Ok(Value::Null) if meta_field.field_type.is_non_null() => return false,
+                    Ok(Value::Null) if meta_field.field_type.is_non_null() => {
+                        sub_exec.push_nulled_error_at(
+                            meta_type.name().unwrap_or_default(),
+                            f.name.item,
+                            *start_pos,
+                        );
+                        return false;
+                    }
                     Ok(v) => merge_key_into(result, response_name, v),
                     Err(e) => {
                         sub_exec.push_error_at(e, *start_pos);
@@ -501,7 +585,20 @@ where
                 start: ref start_pos,
                 ..
             }) => {
-                if is_excluded(&spread.directives, executor.variables()) {
+                if is_excluded(&spread.directives, executor.variables())
+                    || executor.is_deferred(&spread.directives, *start_pos)
+                {
+                    continue;
+                }
+
+                // The same fragment spread again into the same value resolves
+                // to the same fields, so is resolved only once.
+                let spread_fragments = spread_fragments.get_or_insert_with(Default::default);
+                if !spread_fragments
+                    .lock()
+                    .unwrap()
+                    .insert(spread.name.item.into())
+                {
                     continue;
                 }
 
@@ -509,10 +606,12 @@ where
                     .fragment_by_name(spread.name.item)
                     .expect("Fragment could not be found");
 
-                let sub_exec = executor.type_sub_executor(
-                    Some(fragment.type_condition.item),
-                    Some(&fragment.selection_set[..]),
-                );
+                let sub_exec = executor
+                    .type_sub_executor(
+                        Some(fragment.type_condition.item),
+                        Some(&fragment.selection_set[..]),
+                    )
+                    .with_spread_fragments(spread_fragments.clone());
 
                 let concrete_type_name = instance.concrete_type_name(sub_exec.context(), info);
                 let type_name = instance.type_name(info);
@@ -542,14 +641,22 @@ where
                 start: ref start_pos,
                 ..
             }) => {
-                if is_excluded(&fragment.directives, executor.variables()) {
+                if is_excluded(&fragment.directives, executor.variables())
+                    || executor.is_deferred(&fragment.directives, *start_pos)
+                {
                     continue;
                 }
 
-                let sub_exec = executor.type_sub_executor(
-                    fragment.type_condition.as_ref().map(|c| c.item),
-                    Some(&fragment.selection_set[..]),
-                );
+                let sub_exec = executor
+                    .type_sub_executor(
+                        fragment.type_condition.as_ref().map(|c| c.item),
+                        Some(&fragment.selection_set[..]),
+                    )
+                    .with_spread_fragments(
+                        spread_fragments
+                            .get_or_insert_with(Default::default)
+                            .clone(),
+                    );
 
                 if let Some(ref type_condition) = fragment.type_condition {
                     // Check whether the type matches the type condition.
@@ -602,6 +709,10 @@ where
             ..
         } in directives
         {
+            if directive.name.item != "skip" && directive.name.item != "include" {
+                continue;
+            }
+
             let condition: bool = directive
                 .arguments
                 .iter()
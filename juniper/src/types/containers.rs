@@ -164,7 +164,12 @@ impl<S: ScalarValue, T: FromInputValue<S>> FromInputValue<S> for Vec<T> {
         match v {
             InputValue::List(l) => l
                 .iter()
-                .map(|i| i.item.convert().map_err(FromInputValueVecError::Item))
+                .enumerate()
+                .map(|(index, i)| {
+                    i.item
+                        .convert()
+                        .map_err(|error| FromInputValueVecError::Item { index, error })
+                })
                 .collect(),
             // See "Input Coercion" on List types:
             // https://spec.graphql.org/October2021#sec-Combining-List-and-Non-Null
@@ -172,7 +177,7 @@ impl<S: ScalarValue, T: FromInputValue<S>> FromInputValue<S> for Vec<T> {
             other => other
                 .convert()
                 .map(|e| vec![e])
-                .map_err(FromInputValueVecError::Item),
+                .map_err(|error| FromInputValueVecError::Item { index: 0, error }),
         }
     }
 }
@@ -203,7 +208,14 @@ where
     Null,
 
     /// Error of converting [`InputValue::List`]'s item.
-    Item(T::Error),
+    Item {
+        /// Index of the failed item (`0` for a single value coerced into a
+        /// list).
+        index: usize,
+
+        /// Error of converting the item.
+        error: T::Error,
+    },
 }
 
 impl<T, S> IntoFieldError<S> for FromInputValueVecError<T, S>
@@ -214,8 +226,12 @@ where
 {
     fn into_field_error(self) -> FieldError<S> {
         match self {
-            Self::Null => "Failed to convert into `Vec`: Value cannot be `null`".into(),
-            Self::Item(s) => s.into_field_error(),
+            Self::Null => FieldError::from("Failed to convert into `Vec`: Value cannot be `null`")
+                .at_input_value(),
+            Self::Item { index, error } => error
+                .into_field_error()
+                .at_input_value()
+                .prepend_input_index(index),
         }
     }
 }
@@ -413,11 +429,11 @@ where
                 };
 
                 let mut items = ls.iter().map(|i| i.item.convert());
-                for elem in &mut out.arr[..] {
+                for (index, elem) in out.arr.iter_mut().enumerate() {
                     if let Some(i) = items
                         .next()
                         .transpose()
-                        .map_err(FromInputValueArrayError::Item)?
+                        .map_err(|error| FromInputValueArrayError::Item { index, error })?
                     {
                         *elem = MaybeUninit::new(i);
                         out.init_len += 1;
@@ -445,7 +461,7 @@ where
             ref other => {
                 other
                     .convert()
-                    .map_err(FromInputValueArrayError::Item)
+                    .map_err(|error| FromInputValueArrayError::Item { index: 0, error })
                     .and_then(|e: T| {
                         // TODO: Use `mem::transmute` instead of
                         //       `mem::transmute_copy` below, once it's allowed
@@ -510,7 +526,14 @@ where
     },
 
     /// Error of converting [`InputValue::List`]'s item.
-    Item(T::Error),
+    Item {
+        /// Index of the failed item (`0` for a single value coerced into an
+        /// array).
+        index: usize,
+
+        /// Error of converting the item.
+        error: T::Error,
+    },
 }
 
 impl<T, S> IntoFieldError<S> for FromInputValueArrayError<T, S>
@@ -522,12 +545,17 @@ where
     fn into_field_error(self) -> FieldError<S> {
         const ERROR_PREFIX: &str = "Failed to convert into exact-size array";
         match self {
-            Self::Null => format!("{ERROR_PREFIX}: Value cannot be `null`").into(),
-            Self::WrongCount { actual, expected } => {
-                format!("{ERROR_PREFIX}: wrong elements count: {actual} instead of {expected}",)
-                    .into()
+            Self::Null => {
+                FieldError::from(format!("{ERROR_PREFIX}: Value cannot be `null`")).at_input_value()
             }
-            Self::Item(s) => s.into_field_error(),
+            Self::WrongCount { actual, expected } => FieldError::from(format!(
+                "{ERROR_PREFIX}: wrong elements count: {actual} instead of {expected}",
+            ))
+            .at_input_value(),
+            Self::Item { index, error } => error
+                .into_field_error()
+                .at_input_value()
+                .prepend_input_index(index),
         }
     }
 }
@@ -581,6 +609,18 @@ where
         .expect("Current type is not a list type")
         .is_non_null();
 
+    if executor.resolves_serially() {
+        let mut values = Vec::with_capacity(items.len());
+        for it in items {
+            let value = executor.resolve_into_value_async(info, it).await;
+            if stop_on_null && value.is_null() {
+                return Ok(value);
+            }
+            values.push(value);
+        }
+        return Ok(Value::list(values));
+    }
+
     let mut futures = items
         .map(|it| async move { executor.resolve_into_value_async(info, it).await })
         .collect::<FuturesOrdered<_>>();
@@ -598,7 +638,9 @@ where
 
 #[cfg(test)]
 mod coercion {
-    use crate::{graphql_input_value, FromInputValue as _, InputValue, IntoFieldError as _};
+    use crate::{
+        graphql_input_value, graphql_value, FromInputValue as _, InputValue, IntoFieldError as _,
+    };
 
     use super::{FromInputValueArrayError, FromInputValueVecError};
 
@@ -683,15 +725,17 @@ mod coercion {
         let v: V = graphql_input_value!([1, 2, null]);
         assert_eq!(
             <Vec<i32>>::from_input_value(&v),
-            Err(FromInputValueVecError::Item(
-                "Expected `Int`, found: null".into_field_error(),
-            )),
+            Err(FromInputValueVecError::Item {
+                index: 2,
+                error: "Expected `Int`, found: null".into_field_error(),
+            }),
         );
         assert_eq!(
             <Option<Vec<i32>>>::from_input_value(&v),
-            Err(FromInputValueVecError::Item(
-                "Expected `Int`, found: null".into_field_error(),
-            )),
+            Err(FromInputValueVecError::Item {
+                index: 2,
+                error: "Expected `Int`, found: null".into_field_error(),
+            }),
         );
         assert_eq!(
             <Vec<Option<i32>>>::from_input_value(&v),
@@ -703,7 +747,10 @@ mod coercion {
         );
         assert_eq!(
             <Vec<Vec<i32>>>::from_input_value(&v),
-            Err(FromInputValueVecError::Item(FromInputValueVecError::Null)),
+            Err(FromInputValueVecError::Item {
+                index: 2,
+                error: FromInputValueVecError::Null,
+            }),
         );
         // Looks like the spec ambiguity.
         // See: https://github.com/graphql/graphql-spec/pull/515
@@ -713,6 +760,21 @@ mod coercion {
         );
     }
 
+    #[test]
+    fn vec_item_error_path() {
+        let v: V = graphql_input_value!([[1], [2, null]]);
+
+        let err = <Vec<Vec<i32>>>::from_input_value(&v)
+            .unwrap_err()
+            .into_field_error();
+
+        assert_eq!(err.message(), "Expected `Int`, found: null");
+        assert_eq!(
+            err.input_path(),
+            Some(&[graphql_value!(1), graphql_value!(1)][..])
+        );
+    }
+
     // See "Input Coercion" examples on List types:
     // https://spec.graphql.org/October2021#sec-List.Input-Coercion
     #[test]
@@ -793,15 +855,17 @@ mod coercion {
         let v: V = graphql_input_value!([1, 2, null]);
         assert_eq!(
             <[i32; 3]>::from_input_value(&v),
-            Err(FromInputValueArrayError::Item(
-                "Expected `Int`, found: null".into_field_error(),
-            )),
+            Err(FromInputValueArrayError::Item {
+                index: 2,
+                error: "Expected `Int`, found: null".into_field_error(),
+            }),
         );
         assert_eq!(
             <Option<[i32; 3]>>::from_input_value(&v),
-            Err(FromInputValueArrayError::Item(
-                "Expected `Int`, found: null".into_field_error(),
-            )),
+            Err(FromInputValueArrayError::Item {
+                index: 2,
+                error: "Expected `Int`, found: null".into_field_error(),
+            }),
         );
         assert_eq!(
             <[Option<i32>; 3]>::from_input_value(&v),
@@ -813,9 +877,10 @@ mod coercion {
         );
         assert_eq!(
             <[[i32; 1]; 3]>::from_input_value(&v),
-            Err(FromInputValueArrayError::Item(
-                FromInputValueArrayError::Null
-            )),
+            Err(FromInputValueArrayError::Item {
+                index: 2,
+                error: FromInputValueArrayError::Null,
+            }),
         );
         // Looks like the spec ambiguity.
         // See: https://github.com/graphql/graphql-spec/pull/515
@@ -824,4 +889,19 @@ mod coercion {
             Ok(Some([Some([Some(1)]), Some([Some(2)]), None])),
         );
     }
+
+    #[test]
+    fn array_item_error_path() {
+        let v: V = graphql_input_value!([[1, 2], [3]]);
+
+        let err = <[[i32; 2]; 2]>::from_input_value(&v)
+            .unwrap_err()
+            .into_field_error();
+
+        assert_eq!(
+            err.message(),
+            "Failed to convert into exact-size array: wrong elements count: 1 instead of 2",
+        );
+        assert_eq!(err.input_path(), Some(&[graphql_value!(1)][..]));
+    }
 }
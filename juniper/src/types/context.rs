@@ -0,0 +1,322 @@
+//! GraphQL support for values carrying their own context.
+
+use crate::{
+    ast::Selection,
+    executor::{ExecutionResult, Executor, Registry},
+    macros::reflect,
+    schema::meta::MetaType,
+    types::{
+        async_await::GraphQLValueAsync,
+        base::{Arguments, GraphQLType, GraphQLValue},
+        marker::IsOutputType,
+    },
+    value::ScalarValue,
+    BoxFuture,
+};
+
+/// Value resolved with its own owned context, replacing the context of the
+/// parent value for the whole subtree of the value.
+///
+/// Unlike returning a `(&Context, Value)` tuple from a resolver, which
+/// requires the new context to outlive the whole execution, [`WithContext`]
+/// owns the new context, so it may be created right in the resolver, even
+/// asynchronously (e.g. a tenant-scoped database handle fetched with
+/// `.await`).
+///
+/// Is represented in a GraphQL schema exactly as the wrapped value, and may be
+/// returned from a field resolver regardless of the parent context type.
+///
+/// ```rust
+/// # use juniper::{graphql_object, Context, WithContext};
+/// #
+/// struct Database;
+///
+/// impl Context for Database {}
+///
+/// impl Database {
+///     async fn tenant(&self, id: &str) -> Tenant {
+///         Tenant { id: id.into() }
+///     }
+/// }
+///
+/// struct Tenant {
+///     id: String,
+/// }
+///
+/// impl Context for Tenant {}
+///
+/// struct TenantView;
+///
+/// #[graphql_object(context = Tenant)]
+/// impl TenantView {
+///     fn id(context: &Tenant) -> &str {
+///         &context.id
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Database)]
+/// impl Query {
+///     async fn tenant(id: String, context: &Database) -> WithContext<Tenant, TenantView> {
+///         WithContext::new(context.tenant(&id).await, TenantView)
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct WithContext<C, T> {
+    context: C,
+    value: T,
+}
+
+impl<C, T> WithContext<C, T> {
+    /// Wraps the provided `value` to be resolved with the provided owned
+    /// `context`.
+    pub fn new(context: C, value: T) -> Self {
+        Self { context, value }
+    }
+
+    /// Returns the context the wrapped value is resolved with.
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Returns the wrapped value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Unwraps this [`WithContext`] into its context and value.
+    pub fn into_inner(self) -> (C, T) {
+        (self.context, self.value)
+    }
+}
+
+impl<S, C, T> GraphQLType<S> for WithContext<C, T>
+where
+    T: GraphQLType<S, Context = C>,
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        T::name(info)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        T::meta(info, registry)
+    }
+}
+
+impl<S, C, T> GraphQLValue<S> for WithContext<C, T>
+where
+    T: GraphQLValue<S, Context = C>,
+    S: ScalarValue,
+{
+    // Requires nothing from the parent context, as it's replaced anyway.
+    type Context = ();
+    type TypeInfo = T::TypeInfo;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        self.value.type_name(info)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        self.value.concrete_type_name(&self.context, info)
+    }
+
+    fn resolve_into_type(
+        &self,
+        info: &Self::TypeInfo,
+        name: &str,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        let executor = executor.replaced_context(&self.context);
+        self.value
+            .resolve_into_type(info, name, selection_set, &executor)
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        args: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        let executor = executor.replaced_context(&self.context);
+        self.value.resolve_field(info, field, args, &executor)
+    }
+
+    fn resolve(
+        &self,
+        info: &Self::TypeInfo,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        let executor = executor.replaced_context(&self.context);
+        self.value.resolve(info, selection_set, &executor)
+    }
+}
+
+impl<S, C, T> GraphQLValueAsync<S> for WithContext<C, T>
+where
+    T: GraphQLValueAsync<S, Context = C>,
+    T::TypeInfo: Sync,
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field_name: &'a str,
+        arguments: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(async move {
+            let executor = executor.replaced_context(&self.context);
+            self.value
+                .resolve_field_async(info, field_name, arguments, &executor)
+                .await
+        })
+    }
+
+    fn resolve_into_type_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        type_name: &str,
+        selection_set: Option<&'a [Selection<'a, S>]>,
+        executor: &'a Executor<'a, 'a, Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        let type_name = type_name.to_owned();
+        Box::pin(async move {
+            let executor = executor.replaced_context(&self.context);
+            self.value
+                .resolve_into_type_async(info, &type_name, selection_set, &executor)
+                .await
+        })
+    }
+
+    fn resolve_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        selection_set: Option<&'a [Selection<S>]>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(async move {
+            let executor = executor.replaced_context(&self.context);
+            self.value
+                .resolve_async(info, selection_set, &executor)
+                .await
+        })
+    }
+}
+
+impl<S, C, T> IsOutputType<S> for WithContext<C, T>
+where
+    T: IsOutputType<S> + GraphQLType<S, Context = C>,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
+impl<S, C, T: reflect::BaseType<S>> reflect::BaseType<S> for WithContext<C, T> {
+    const NAME: reflect::Type = T::NAME;
+}
+
+impl<S, C, T: reflect::BaseSubTypes<S>> reflect::BaseSubTypes<S> for WithContext<C, T> {
+    const NAMES: reflect::Types = T::NAMES;
+}
+
+impl<S, C, T: reflect::WrappedType<S>> reflect::WrappedType<S> for WithContext<C, T> {
+    const VALUE: reflect::WrappedValue = T::VALUE;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        executor::Context,
+        graphql_object, graphql_value, graphql_vars,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::WithContext;
+
+    struct Database {
+        tenants: Vec<&'static str>,
+    }
+
+    impl Context for Database {}
+
+    struct Tenant {
+        name: &'static str,
+    }
+
+    impl Context for Tenant {}
+
+    struct TenantView;
+
+    #[graphql_object(context = Tenant)]
+    impl TenantView {
+        fn name(context: &Tenant) -> &str {
+            context.name
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object(context = Database)]
+    impl Query {
+        fn tenant(index: i32, context: &Database) -> WithContext<Tenant, TenantView> {
+            let name = context.tenants[index as usize];
+            WithContext::new(Tenant { name }, TenantView)
+        }
+
+        async fn tenant_async(
+            index: i32,
+            context: &Database,
+        ) -> Option<WithContext<Tenant, TenantView>> {
+            let name = *context.tenants.get(index as usize)?;
+            let tenant = async { Tenant { name } }.await;
+            Some(WithContext::new(tenant, TenantView))
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    fn database() -> Database {
+        Database {
+            tenants: vec!["acme", "globex"],
+        }
+    }
+
+    #[test]
+    fn resolves_with_owned_context() {
+        let doc = r#"{ tenant(index: 1) { name } }"#;
+
+        assert_eq!(
+            crate::execute_sync(doc, None, &schema(), &graphql_vars! {}, &database()),
+            Ok((graphql_value!({"tenant": {"name": "globex"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_with_asynchronously_derived_context() {
+        let doc = r#"{
+            a: tenantAsync(index: 0) { name }
+            b: tenantAsync(index: 2) { name }
+        }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema(), &graphql_vars! {}, &database()).await,
+            Ok((graphql_value!({"a": {"name": "acme"}, "b": null}), vec![])),
+        );
+    }
+}
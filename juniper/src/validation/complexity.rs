@@ -0,0 +1,416 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use crate::{
+    ast::{
+        Definition, Document, Field, Fragment, InputValue, Operation, OperationType, Selection,
+        Type,
+    },
+    executor::Variables,
+    parser::Spanning,
+    schema::{
+        meta::{Complexity, MetaType},
+        model::SchemaType,
+    },
+    types::base::Arguments,
+    value::ScalarValue,
+};
+
+/// Names of the field arguments multiplying the complexity of the field's
+/// selection set, in order of precedence.
+const MULTIPLIER_ARGUMENTS: [&str; 2] = ["first", "last"];
+
+/// Estimates the complexity of executing the provided validated `operation`.
+///
+/// Every field costs `1`, unless overridden via [`Field::complexity()`], plus
+/// the complexity of its selection set. A field may calculate its whole
+/// complexity on its own instead, via [`Field::complexity_with()`], out of its
+/// arguments and the complexity of its selection set. Otherwise, the latter is
+/// multiplied by the value
+/// of the `first` (or `last`) argument, if the field has one, as that's how
+/// many objects are expected to be resolved for a paginated list. Otherwise,
+/// it's multiplied by the expected size of the returned lists, if it's known
+/// (like for the `[T; N]` arrays).
+///
+/// All the fragments are accounted, regardless their type conditions or
+/// `@skip`/`@include` directives, so the estimation is an upper bound.
+///
+/// [`Field::complexity()`]: crate::meta::Field::complexity
+/// [`Field::complexity_with()`]: crate::meta::Field::complexity_with
+pub fn operation_complexity<S>(
+    document: &Document<S>,
+    operation: &Spanning<Operation<S>>,
+    variables: &Variables<S>,
+    schema: &SchemaType<S>,
+) -> usize
+where
+    S: ScalarValue,
+{
+    let root_type_name = match operation.item.operation_type {
+        OperationType::Query => Some(schema.query_type_name.as_str()),
+        OperationType::Mutation => schema.mutation_type_name.as_deref(),
+        OperationType::Subscription => schema.subscription_type_name.as_deref(),
+    };
+
+    let estimator = Estimator {
+        schema,
+        fragments: document
+            .iter()
+            .filter_map(|d| match d {
+                Definition::Fragment(f) => Some((f.item.name.item, &f.item)),
+                Definition::Operation(_) => None,
+            })
+            .collect(),
+        variables,
+        operation: &operation.item,
+    };
+    estimator.selection_set(
+        &operation.item.selection_set,
+        root_type_name.and_then(|n| schema.concrete_type_by_name(n)),
+    )
+}
+
+struct Estimator<'a, S> {
+    schema: &'a SchemaType<'a, S>,
+    fragments: HashMap<&'a str, &'a Fragment<'a, S>>,
+    variables: &'a Variables<S>,
+    operation: &'a Operation<'a, S>,
+}
+
+impl<'a, S: ScalarValue> Estimator<'a, S> {
+    fn selection_set(
+        &self,
+        selection_set: &[Selection<S>],
+        parent_type: Option<&MetaType<S>>,
+    ) -> usize {
+        selection_set
+            .iter()
+            .map(|selection| match selection {
+                Selection::Field(f) => self.field(&f.item, parent_type),
+                Selection::FragmentSpread(spread) => {
+                    self.fragments.get(spread.item.name.item).map_or(0, |f| {
+                        self.selection_set(
+                            &f.selection_set,
+                            self.schema
+                                .concrete_type_by_name(f.type_condition.item)
+                                .or(parent_type),
+                        )
+                    })
+                }
+                Selection::InlineFragment(f) => self.selection_set(
+                    &f.item.selection_set,
+                    f.item
+                        .type_condition
+                        .as_ref()
+                        .and_then(|c| self.schema.concrete_type_by_name(c.item))
+                        .or(parent_type),
+                ),
+            })
+            .fold(0, usize::saturating_add)
+    }
+
+    fn field(&self, field: &Field<S>, parent_type: Option<&MetaType<S>>) -> usize {
+        let meta_field = parent_type.and_then(|t| t.field_by_name(field.name.item));
+
+        let children = field.selection_set.as_ref().map_or(0, |selection_set| {
+            let field_type = meta_field.and_then(|f| {
+                self.schema
+                    .concrete_type_by_name(f.field_type.innermost_name())
+            });
+            self.selection_set(selection_set, field_type)
+        });
+
+        let cost = match meta_field.and_then(|f| f.complexity.as_ref()) {
+            Some(Complexity::Static(cost)) => *cost,
+            Some(Complexity::Fn(complexity)) => {
+                let args = field
+                    .arguments
+                    .iter()
+                    .flat_map(|args| args.item.iter())
+                    .filter_map(|(name, value)| {
+                        let value = self.const_value(&value.item)?;
+                        Some((name.item, value))
+                    })
+                    .collect::<IndexMap<_, _>>();
+                let args = Arguments::with_defaults(
+                    args,
+                    meta_field
+                        .and_then(|f| f.arguments.as_deref())
+                        .unwrap_or_default(),
+                );
+                return complexity(&args, children);
+            }
+            None => 1,
+        };
+
+        let multiplier = MULTIPLIER_ARGUMENTS
+            .iter()
+            .find_map(|&name| {
+                field
+                    .arguments
+                    .as_ref()
+                    .and_then(|args| args.item.get(name))
+                    .and_then(|v| self.int_value(&v.item))
+                    .or_else(|| {
+                        meta_field
+                            .and_then(|f| f.arguments.as_ref())
+                            .and_then(|args| args.iter().find(|a| a.name == name))
+                            .and_then(|a| a.default_value.as_ref())
+                            .and_then(|v| self.int_value(v))
+                    })
+            })
+            .map(|n| n.max(0) as usize)
+            .or_else(|| meta_field.and_then(|f| list_size(&f.field_type)))
+            .unwrap_or(1);
+
+        cost.saturating_add(multiplier.saturating_mul(children))
+    }
+
+    /// Resolves the provided `value` as an integer, looking up the variables
+    /// (and their default values) if needed.
+    fn int_value(&self, value: &InputValue<S>) -> Option<i32> {
+        match value {
+            InputValue::Variable(name) => {
+                self.variable_value(name).and_then(InputValue::as_int_value)
+            }
+            v => v.as_int_value(),
+        }
+    }
+
+    /// Resolves the provided `value` as a constant one, substituting the
+    /// variables (and their default values) in it.
+    ///
+    /// Returns [`None`] if the `value` is a variable not provided at all.
+    fn const_value(&self, value: &InputValue<S>) -> Option<InputValue<S>> {
+        match value {
+            InputValue::Variable(name) => self.variable_value(name).cloned(),
+            InputValue::List(l) => Some(InputValue::list(
+                l.iter()
+                    .map(|v| self.const_value(&v.item).unwrap_or(InputValue::Null))
+                    .collect(),
+            )),
+            InputValue::Object(o) => Some(InputValue::object(
+                o.iter()
+                    .filter_map(|(k, v)| Some((k.item.clone(), self.const_value(&v.item)?)))
+                    .collect::<IndexMap<_, _>>(),
+            )),
+            v => Some(v.clone()),
+        }
+    }
+
+    /// Looks up the value of the `name`d variable, falling back to its
+    /// default value, if any.
+    fn variable_value(&self, name: &str) -> Option<&InputValue<S>> {
+        self.variables.get(name).or_else(|| {
+            self.operation
+                .variable_definitions
+                .as_ref()?
+                .item
+                .items
+                .iter()
+                .find(|(n, _)| n.item == name)?
+                .1
+                .default_value
+                .as_ref()
+                .map(|v| &v.item)
+        })
+    }
+}
+
+/// Returns the expected number of the elements of the provided (possibly
+/// nested) list type, if the sizes of its lists are known.
+fn list_size(ty: &Type) -> Option<usize> {
+    let size = ty.list_size()?;
+    let inner = match ty {
+        Type::List(inner, _) | Type::NonNullList(inner, _) => inner,
+        Type::Named(_) | Type::NonNullNamed(_) => return None,
+    };
+    Some(list_size(inner).map_or(size, |n| n.saturating_mul(size)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        executor::{get_operation, Variables},
+        graphql_input_value, graphql_object,
+        parser::parse_document_source,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::complexity::operation_complexity,
+        DefaultScalarValue, GraphQLError,
+    };
+
+    struct Item;
+
+    #[graphql_object]
+    impl Item {
+        fn id() -> i32 {
+            1
+        }
+
+        fn name() -> &'static str {
+            "item"
+        }
+
+        fn children(first: Option<i32>) -> Vec<Item> {
+            (0..first.unwrap_or(1)).map(|_| Item).collect()
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn item() -> Item {
+            Item
+        }
+
+        fn items(#[graphql(default = 10)] first: i32) -> Vec<Item> {
+            (0..first).map(|_| Item).collect()
+        }
+
+        fn pair() -> [[Item; 2]; 3] {
+            [[Item, Item], [Item, Item], [Item, Item]]
+        }
+
+        #[graphql(complexity = 10)]
+        fn expensive() -> i32 {
+            0
+        }
+
+        #[graphql(complexity = |args, children| {
+            let count = args.get::<i32>("count").ok().flatten().unwrap_or(0);
+            1 + count.max(0) as usize * children
+        })]
+        fn weighted(count: Option<i32>) -> Vec<Item> {
+            (0..count.unwrap_or(0)).map(|_| Item).collect()
+        }
+    }
+
+    fn complexity(query: &str, variables: Variables<DefaultScalarValue>) -> usize {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let document = parse_document_source(query, &schema.schema).unwrap();
+        let operation = get_operation(&document, None).unwrap();
+
+        operation_complexity(&document, operation, &variables, &schema.schema)
+    }
+
+    #[test]
+    fn counts_every_field() {
+        assert_eq!(complexity("{ item { id name } }", Variables::new()), 3);
+    }
+
+    #[test]
+    fn counts_fragments() {
+        assert_eq!(
+            complexity(
+                "{ item { ...F ... on Item { id } } } fragment F on Item { name }",
+                Variables::new(),
+            ),
+            3,
+        );
+    }
+
+    #[test]
+    fn multiplies_by_first_argument() {
+        assert_eq!(
+            complexity(
+                "{ item { children(first: 5) { id name } } }",
+                Variables::new()
+            ),
+            12,
+        );
+    }
+
+    #[test]
+    fn multiplies_by_argument_default() {
+        assert_eq!(complexity("{ items { id } }", Variables::new()), 11);
+    }
+
+    #[test]
+    fn multiplies_by_variable() {
+        let query = "query Q($n: Int = 3) { item { children(first: $n) { id } } }";
+
+        assert_eq!(complexity(query, Variables::new()), 5);
+        assert_eq!(
+            complexity(
+                query,
+                [("n".into(), graphql_input_value!(100))]
+                    .into_iter()
+                    .collect(),
+            ),
+            102,
+        );
+    }
+
+    #[test]
+    fn multiplies_by_list_size() {
+        assert_eq!(complexity("{ pair { id name } }", Variables::new()), 13);
+    }
+
+    #[test]
+    fn uses_static_field_complexity() {
+        assert_eq!(
+            complexity("{ expensive item { id } }", Variables::new()),
+            12
+        );
+    }
+
+    #[test]
+    fn uses_field_complexity_function() {
+        assert_eq!(
+            complexity("{ weighted(count: 3) { id name } }", Variables::new()),
+            7,
+        );
+
+        let query = "query Q($c: Int = 4) { weighted(count: $c) { id } }";
+
+        assert_eq!(complexity(query, Variables::new()), 5);
+        assert_eq!(
+            complexity(
+                query,
+                [("c".into(), graphql_input_value!(2))]
+                    .into_iter()
+                    .collect(),
+            ),
+            3,
+        );
+    }
+
+    #[test]
+    fn rejects_too_complex_operations() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        )
+        .with_max_complexity(3);
+
+        assert!(crate::execute_sync(
+            "{ item { id name } }",
+            None,
+            &schema,
+            &Variables::new(),
+            &()
+        )
+        .is_ok());
+        assert_eq!(
+            crate::execute_sync(
+                "{ item { id name children { id } } }",
+                None,
+                &schema,
+                &Variables::new(),
+                &()
+            ),
+            Err(GraphQLError::ComplexityLimitExceeded {
+                complexity: 5,
+                max: 3,
+            }),
+        );
+    }
+}
@@ -247,7 +247,7 @@ where
         // TODO: avoid this bad duplicate as_str() call. (value system refactor)
         InputValue::Scalar(scalar) if scalar.as_str().is_some() => {
             if let Some(name) = scalar.as_str() {
-                if !meta.values.iter().any(|ev| ev.name == *name) {
+                if !meta.values.iter().any(|ev| ev.is_named(name)) {
                     errors.push(unification_error(
                         var_name,
                         var_pos,
@@ -258,7 +258,7 @@ where
             }
         }
         InputValue::Enum(name) => {
-            if !meta.values.iter().any(|ev| &ev.name == name) {
+            if !meta.values.iter().any(|ev| ev.is_named(name)) {
                 errors.push(unification_error(
                     var_name,
                     var_pos,
@@ -0,0 +1,75 @@
+/// Limits of the introspection part of operations (the fields selected under
+/// `__schema` and `__type`), guarding against amplification via deeply nested
+/// `ofType` chains or huge introspection selections.
+///
+/// Applied independently of the general depth and complexity limits, so
+/// these may be kept tight, while the legitimate tooling (like [GraphiQL])
+/// introspecting a schema still works with the defaults.
+///
+/// ```rust
+/// # use juniper::validation::IntrospectionLimits;
+/// #
+/// let limits = IntrospectionLimits::new().max_of_type_depth(8);
+///
+/// assert_eq!(limits.get_max_of_type_depth(), 8);
+/// assert_eq!(limits.get_max_fields(), IntrospectionLimits::DEFAULT_MAX_FIELDS);
+/// ```
+///
+/// [GraphiQL]: https://github.com/graphql/graphiql
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IntrospectionLimits {
+    max_of_type_depth: usize,
+    max_fields: usize,
+}
+
+impl Default for IntrospectionLimits {
+    fn default() -> Self {
+        Self {
+            max_of_type_depth: Self::DEFAULT_MAX_OF_TYPE_DEPTH,
+            max_fields: Self::DEFAULT_MAX_FIELDS,
+        }
+    }
+}
+
+impl IntrospectionLimits {
+    /// Default maximum number of `ofType` fields nested into each other.
+    pub const DEFAULT_MAX_OF_TYPE_DEPTH: usize = 16;
+
+    /// Default maximum number of introspection fields selected by an
+    /// operation.
+    pub const DEFAULT_MAX_FIELDS: usize = 1_000;
+
+    /// Creates new [`IntrospectionLimits`] with all the limits set to their
+    /// defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of `ofType` fields nested into each other.
+    #[must_use]
+    pub fn max_of_type_depth(mut self, max: usize) -> Self {
+        self.max_of_type_depth = max;
+        self
+    }
+
+    /// Returns the maximum number of `ofType` fields nested into each other.
+    pub fn get_max_of_type_depth(&self) -> usize {
+        self.max_of_type_depth
+    }
+
+    /// Sets the maximum number of introspection fields selected by an
+    /// operation, with fragment spreads being expanded (so a fragment spread
+    /// twice is accounted twice).
+    #[must_use]
+    pub fn max_fields(mut self, max: usize) -> Self {
+        self.max_fields = max;
+        self
+    }
+
+    /// Returns the maximum number of introspection fields selected by an
+    /// operation.
+    pub fn get_max_fields(&self) -> usize {
+        self.max_fields
+    }
+}
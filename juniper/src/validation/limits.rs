@@ -0,0 +1,75 @@
+/// Limits on the number of aliases, root fields and directives of operations,
+/// guarding against amplification attacks: a cheap to send operation aliasing
+/// the same expensive field thousands of times, or burying it under a pile of
+/// directives, passes the depth limit while still multiplying the work done.
+///
+/// None of the limits is set by default.
+///
+/// ```rust
+/// # use juniper::validation::OperationLimits;
+/// #
+/// let limits = OperationLimits::new().max_aliases(30).max_root_fields(10);
+///
+/// assert_eq!(limits.get_max_aliases(), Some(30));
+/// assert_eq!(limits.get_max_directives(), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct OperationLimits {
+    max_aliases: Option<usize>,
+    max_root_fields: Option<usize>,
+    max_directives: Option<usize>,
+}
+
+impl OperationLimits {
+    /// Creates new [`OperationLimits`] with none of the limits set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of aliased fields selected by an operation.
+    #[must_use]
+    pub fn max_aliases(mut self, max: usize) -> Self {
+        self.max_aliases = Some(max);
+        self
+    }
+
+    /// Returns the maximum number of aliased fields selected by an operation,
+    /// if any.
+    pub fn get_max_aliases(&self) -> Option<usize> {
+        self.max_aliases
+    }
+
+    /// Sets the maximum number of fields an operation selects on its root
+    /// type.
+    #[must_use]
+    pub fn max_root_fields(mut self, max: usize) -> Self {
+        self.max_root_fields = Some(max);
+        self
+    }
+
+    /// Returns the maximum number of fields an operation selects on its root
+    /// type, if any.
+    pub fn get_max_root_fields(&self) -> Option<usize> {
+        self.max_root_fields
+    }
+
+    /// Sets the maximum number of directives used by an operation.
+    #[must_use]
+    pub fn max_directives(mut self, max: usize) -> Self {
+        self.max_directives = Some(max);
+        self
+    }
+
+    /// Returns the maximum number of directives used by an operation, if any.
+    pub fn get_max_directives(&self) -> Option<usize> {
+        self.max_directives
+    }
+
+    /// Indicates whether none of these [`OperationLimits`] is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_aliases.is_none()
+            && self.max_root_fields.is_none()
+            && self.max_directives.is_none()
+    }
+}
@@ -1,8 +1,12 @@
 //! Query validation related methods and data structures
 
+mod complexity;
 mod context;
 mod input_value;
+mod introspection;
+mod limits;
 mod multi_visitor;
+mod pagination;
 mod rules;
 mod traits;
 mod visitor;
@@ -10,18 +14,116 @@ mod visitor;
 #[cfg(test)]
 pub(crate) mod test_harness;
 
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    ast::{Fragment, Operation},
+    parser::Spanning,
+};
+
 pub use self::{
+    complexity::operation_complexity,
     context::{RuleError, ValidatorContext},
     input_value::validate_input_values,
+    introspection::IntrospectionLimits,
+    limits::OperationLimits,
     multi_visitor::MultiVisitorNil,
+    pagination::PaginationBounds,
     rules::visit_all_rules,
     traits::Visitor,
     visitor::visit,
 };
 
+pub(crate) use self::rules::{visit_operation_rules, OperationRules};
+
 #[cfg(test)]
 pub use self::test_harness::{
     expect_fails_fn, expect_fails_fn_with_schema, expect_fails_rule, expect_fails_rule_with_schema,
     expect_passes_fn, expect_passes_fn_with_schema, expect_passes_rule,
     expect_passes_rule_with_schema,
 };
+
+/// Scope of the items recorded in [`OperationScopes`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Scope<'a> {
+    Operation,
+    Fragment(&'a str),
+}
+
+/// Items recorded by a rule while visiting the operation to be executed and
+/// the fragments of a [`Document`], so the fragments are accounted only where
+/// the operation spreads them.
+///
+/// [`Document`]: crate::ast::Document
+pub(crate) struct OperationScopes<'a, T> {
+    operation: Option<&'a str>,
+    items: HashMap<Scope<'a>, Vec<T>>,
+    current: Option<Scope<'a>>,
+}
+
+impl<'a, T> OperationScopes<'a, T> {
+    /// Creates new empty [`OperationScopes`] of the operation to be executed,
+    /// having the provided `name`.
+    pub(crate) fn new(operation: Option<&'a str>) -> Self {
+        Self {
+            operation,
+            items: HashMap::new(),
+            current: None,
+        }
+    }
+
+    /// Starts recording the items of the provided `operation`, unless it's not
+    /// the one to be executed.
+    pub(crate) fn enter_operation<S>(&mut self, operation: &'a Spanning<Operation<S>>) {
+        let name = operation.item.name.as_ref().map(|n| n.item);
+        self.current = (name == self.operation).then_some(Scope::Operation);
+    }
+
+    /// Starts recording the items of the provided `fragment`.
+    pub(crate) fn enter_fragment<S>(&mut self, fragment: &'a Spanning<Fragment<S>>) {
+        self.current = Some(Scope::Fragment(fragment.item.name.item));
+    }
+
+    /// Records the provided `item` in the operation or fragment being visited.
+    pub(crate) fn record(&mut self, item: T) {
+        if let Some(scope) = self.current {
+            self.items.entry(scope).or_default().push(item);
+        }
+    }
+
+    /// Returns the items recorded in the operation to be executed.
+    pub(crate) fn operation(&self) -> &[T] {
+        self.get(Scope::Operation)
+    }
+
+    /// Returns the items recorded in the fragment of the provided `name`.
+    pub(crate) fn fragment(&self, name: &'a str) -> &[T] {
+        self.get(Scope::Fragment(name))
+    }
+
+    /// Returns the items recorded in the operation to be executed and all the
+    /// fragments reachable from it via the spreads returned by `spread`, with
+    /// every fragment accounted once.
+    pub(crate) fn reachable(&self, spread: impl Fn(&T) -> Option<&'a str>) -> Vec<&T> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![self.operation()];
+        let mut reachable = vec![];
+        while let Some(items) = stack.pop() {
+            for item in items {
+                match spread(item) {
+                    Some(name) => {
+                        if visited.insert(name) {
+                            stack.push(self.fragment(name));
+                        }
+                    }
+                    None => reachable.push(item),
+                }
+            }
+        }
+        reachable
+    }
+
+    fn get(&self, scope: Scope<'a>) -> &[T] {
+        self.items.get(&scope).map_or(&[], Vec::as_slice)
+    }
+}
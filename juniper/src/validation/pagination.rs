@@ -0,0 +1,55 @@
+/// Bounds of the pagination arguments (like `first`, `last` or `limit`) of
+/// all the fields in a schema, limiting the size of the requested result
+/// windows.
+///
+/// ```rust
+/// # use juniper::validation::PaginationBounds;
+/// #
+/// let bounds = PaginationBounds::new(100).argument_names(["first", "last", "take"]);
+///
+/// assert_eq!(bounds.max(), 100);
+/// assert!(bounds.applies_to("take"));
+/// assert!(!bounds.applies_to("limit"));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaginationBounds {
+    argument_names: Vec<String>,
+    max: i32,
+}
+
+impl PaginationBounds {
+    /// Names of the pagination arguments bounded by default.
+    pub const DEFAULT_ARGUMENT_NAMES: &'static [&'static str] = &["first", "last", "limit"];
+
+    /// Creates new [`PaginationBounds`] allowing the
+    /// [default pagination arguments](Self::DEFAULT_ARGUMENT_NAMES) to be in
+    /// `0..=max` range.
+    #[must_use]
+    pub fn new(max: i32) -> Self {
+        Self {
+            argument_names: Self::DEFAULT_ARGUMENT_NAMES
+                .iter()
+                .map(|&n| n.into())
+                .collect(),
+            max,
+        }
+    }
+
+    /// Sets the names of the bounded pagination arguments, replacing the
+    /// [default ones](Self::DEFAULT_ARGUMENT_NAMES).
+    #[must_use]
+    pub fn argument_names<N: Into<String>>(mut self, names: impl IntoIterator<Item = N>) -> Self {
+        self.argument_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the maximum value of the bounded pagination arguments.
+    pub fn max(&self) -> i32 {
+        self.max
+    }
+
+    /// Indicates whether the argument of the provided `name` is bounded.
+    pub fn applies_to(&self, name: &str) -> bool {
+        self.argument_names.iter().any(|n| n == name)
+    }
+}
@@ -34,7 +34,12 @@ where
                     &non_null_error_message(var_name.item, &var_def.var_type.item),
                     &[*start],
                 )
-            } else {
+            } else if ctx
+                .schema
+                .concrete_type_by_name(var_def.var_type.item.innermost_name())
+                .is_some()
+            {
+                // Unknown types are reported by the `KnownTypeNames` rule.
                 let meta_type = ctx.schema.make_type(&var_def.var_type.item);
 
                 if !is_valid_literal_value(ctx.schema, &meta_type, var_value) {
@@ -93,6 +98,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn variables_of_unknown_types_with_default_values() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          query UnknownTypes($a: Unknown = 1, $b: [Unknown] = [1]) {
+            dog { name }
+          }
+        "#,
+        );
+    }
+
     #[test]
     fn variables_with_valid_default_values() {
         expect_passes_rule::<_, _, DefaultScalarValue>(
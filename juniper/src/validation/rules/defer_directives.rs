@@ -0,0 +1,212 @@
+use std::collections::hash_map::{Entry, HashMap};
+
+use crate::{
+    ast::{Directive, Fragment, InputValue, Operation, OperationType},
+    executor::DEFER_DIRECTIVE,
+    parser::{SourcePosition, Spanning},
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+pub struct DeferDirectives<'a> {
+    labels: HashMap<&'a str, SourcePosition>,
+    in_subscription: bool,
+}
+
+pub fn factory<'a>() -> DeferDirectives<'a> {
+    DeferDirectives {
+        labels: HashMap::new(),
+        in_subscription: false,
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for DeferDirectives<'a>
+where
+    S: ScalarValue,
+{
+    fn enter_operation_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        self.in_subscription = op.item.operation_type == OperationType::Subscription;
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        _: &'a Spanning<Fragment<S>>,
+    ) {
+        self.in_subscription = false;
+    }
+
+    fn enter_directive(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        directive: &'a Spanning<Directive<S>>,
+    ) {
+        if directive.item.name.item != DEFER_DIRECTIVE {
+            return;
+        }
+
+        if self.in_subscription {
+            ctx.report_error(SUBSCRIPTION_ERROR, &[directive.start]);
+        }
+
+        let label = directive
+            .item
+            .arguments
+            .as_ref()
+            .and_then(|args| args.item.get("label"));
+        if let Some(label) = label {
+            match &label.item {
+                InputValue::Variable(_) => {
+                    ctx.report_error(VARIABLE_LABEL_ERROR, &[label.start]);
+                }
+                v => {
+                    if let Some(name) = v.as_string_value() {
+                        match self.labels.entry(name) {
+                            Entry::Occupied(e) => {
+                                ctx.report_error(
+                                    &duplicate_label_message(name),
+                                    &[*e.get(), label.start],
+                                );
+                            }
+                            Entry::Vacant(e) => {
+                                e.insert(label.start);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const SUBSCRIPTION_ERROR: &str = "@defer is not supported in subscriptions";
+
+const VARIABLE_LABEL_ERROR: &str = "@defer label must be a static string, not a variable";
+
+fn duplicate_label_message(label: &str) -> String {
+    format!("There can only be one @defer directive labeled \"{label}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{duplicate_label_message, factory, SUBSCRIPTION_ERROR, VARIABLE_LABEL_ERROR};
+
+    use crate::{
+        parser::SourcePosition,
+        validation::{expect_fails_rule, expect_passes_rule, RuleError},
+        value::DefaultScalarValue,
+    };
+
+    #[test]
+    fn unlabeled_defers() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog {
+              ... @defer {
+                name
+              }
+              ...DogFields @defer
+            }
+          }
+
+          fragment DogFields on Dog {
+            barks
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn unique_labels() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog {
+              ... @defer(label: "name") {
+                name
+              }
+              ... @defer(label: "barks") {
+                barks
+              }
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn duplicate_labels() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog {
+              ... @defer(label: "dog") {
+                name
+              }
+            }
+          }
+
+          fragment DogFields on Dog {
+            ... @defer(label: "dog") {
+              barks
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &duplicate_label_message("dog"),
+                &[
+                    SourcePosition::new(63, 3, 32),
+                    SourcePosition::new(204, 10, 30),
+                ],
+            )],
+        );
+    }
+
+    #[test]
+    fn variable_label() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          query Q($label: String) {
+            dog {
+              ... @defer(label: $label) {
+                name
+              }
+            }
+          }
+        "#,
+            &[RuleError::new(
+                VARIABLE_LABEL_ERROR,
+                &[SourcePosition::new(87, 3, 32)],
+            )],
+        );
+    }
+
+    #[test]
+    fn defer_in_subscription() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          subscription {
+            dog {
+              ... @defer {
+                name
+              }
+            }
+          }
+        "#,
+            &[RuleError::new(
+                SUBSCRIPTION_ERROR,
+                &[SourcePosition::new(62, 3, 18)],
+            )],
+        );
+    }
+}
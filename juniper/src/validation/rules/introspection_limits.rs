@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{Document, Field, Fragment, FragmentSpread, Operation},
+    parser::{SourcePosition, Spanning},
+    validation::{IntrospectionLimits, OperationScopes, RuleError, ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+enum Item<'a> {
+    /// Start of the named field.
+    Field(&'a str, SourcePosition),
+
+    /// End of the last started field.
+    FieldEnd,
+
+    /// Spread of the named fragment.
+    Spread(&'a str),
+}
+
+pub struct IntrospectionLimitsRule<'a> {
+    limits: Option<&'a IntrospectionLimits>,
+    scopes: OperationScopes<'a, Item<'a>>,
+}
+
+pub fn factory<'a>(
+    operation: Option<&'a str>,
+    limits: Option<&'a IntrospectionLimits>,
+) -> IntrospectionLimitsRule<'a> {
+    IntrospectionLimitsRule {
+        limits,
+        scopes: OperationScopes::new(operation),
+    }
+}
+
+/// Introspection fields counted so far, with fragment spreads expanded.
+struct Counter<'a> {
+    limits: &'a IntrospectionLimits,
+    fields: usize,
+
+    /// Indicators whether the fragments select `__schema` or `__type` fields.
+    introspecting: HashMap<&'a str, bool>,
+
+    /// Fragments being expanded, guarding against cyclic spreads, rejected by
+    /// validation beforehand.
+    expanding: Vec<&'a str>,
+}
+
+impl<'a> IntrospectionLimitsRule<'a> {
+    /// Counts the introspection fields of the provided `items`, being a part
+    /// of introspection if `introspected`, and nested into `of_type_depth`
+    /// `ofType` fields.
+    ///
+    /// Returns the error about the first field exceeding the limits, stopping
+    /// the counting there, so even a pathologically large operation is cheap
+    /// to check.
+    fn count(
+        &self,
+        items: &[Item<'a>],
+        introspected: bool,
+        of_type_depth: usize,
+        counter: &mut Counter<'a>,
+    ) -> Option<RuleError> {
+        let mut stack = vec![(introspected, of_type_depth)];
+        for item in items {
+            let (introspected, of_type_depth) = *stack.last()?;
+            match *item {
+                Item::Field(name, pos) => {
+                    let introspected = introspected || name == "__schema" || name == "__type";
+                    let mut of_type_depth = of_type_depth;
+                    if introspected {
+                        counter.fields += 1;
+                        if counter.fields > counter.limits.get_max_fields() {
+                            let message = format!(
+                                "Introspection selects more than the maximum of {} fields",
+                                counter.limits.get_max_fields(),
+                            );
+                            return Some(RuleError::new(&message, &[pos]));
+                        }
+                        if name == "ofType" {
+                            of_type_depth += 1;
+                            if of_type_depth > counter.limits.get_max_of_type_depth() {
+                                let message = format!(
+                                    "Introspection nests more than the maximum of {} `ofType` \
+                                     fields",
+                                    counter.limits.get_max_of_type_depth(),
+                                );
+                                return Some(RuleError::new(&message, &[pos]));
+                            }
+                        }
+                    }
+                    stack.push((introspected, of_type_depth));
+                }
+                Item::FieldEnd => {
+                    stack.pop();
+                }
+                // Fragments spread outside of introspection count only if they
+                // select introspection fields themselves.
+                Item::Spread(name)
+                    if !counter.expanding.contains(&name)
+                        && (introspected || self.introspects(name, counter)) =>
+                {
+                    counter.expanding.push(name);
+                    let fragment = self.scopes.fragment(name);
+                    let res = self.count(fragment, introspected, of_type_depth, counter);
+                    counter.expanding.pop();
+                    if res.is_some() {
+                        return res;
+                    }
+                }
+                Item::Spread(_) => {}
+            }
+        }
+        None
+    }
+
+    /// Indicates whether the fragment of the provided `name` selects any
+    /// `__schema` or `__type` fields, with fragment spreads expanded.
+    fn introspects(&self, name: &'a str, counter: &mut Counter<'a>) -> bool {
+        if let Some(introspects) = counter.introspecting.get(name) {
+            return *introspects;
+        }
+        // Guards against cyclic spreads, rejected by validation beforehand.
+        counter.introspecting.insert(name, false);
+        let introspects = self.scopes.fragment(name).iter().any(|item| match *item {
+            Item::Field(name, _) => name == "__schema" || name == "__type",
+            Item::FieldEnd => false,
+            Item::Spread(name) => self.introspects(name, counter),
+        });
+        counter.introspecting.insert(name, introspects);
+        introspects
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for IntrospectionLimitsRule<'a>
+where
+    S: ScalarValue,
+{
+    fn exit_document(&mut self, ctx: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {
+        let limits = match self.limits {
+            Some(limits) => limits,
+            None => return,
+        };
+
+        let mut counter = Counter {
+            limits,
+            fields: 0,
+            introspecting: HashMap::new(),
+            expanding: vec![],
+        };
+        if let Some(e) = self.count(self.scopes.operation(), false, 0, &mut counter) {
+            ctx.append_errors(vec![e]);
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        if self.limits.is_some() {
+            self.scopes.enter_operation(op);
+        }
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        f: &'a Spanning<Fragment<S>>,
+    ) {
+        if self.limits.is_some() {
+            self.scopes.enter_fragment(f);
+        }
+    }
+
+    fn enter_field(&mut self, _: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        self.scopes
+            .record(Item::Field(field.item.name.item, field.start));
+    }
+
+    fn exit_field(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Field<S>>) {
+        self.scopes.record(Item::FieldEnd);
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        spread: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        self.scopes.record(Item::Spread(spread.item.name.item));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_object, graphql_vars,
+        introspection::INTROSPECTION_QUERY,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::{IntrospectionLimits, RuleError},
+        GraphQLError,
+    };
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn ids() -> Vec<i32> {
+            vec![1, 2, 3]
+        }
+    }
+
+    fn schema(
+        limits: IntrospectionLimits,
+    ) -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .with_introspection_limits(limits)
+    }
+
+    #[test]
+    fn allows_standard_introspection_query_by_default() {
+        let schema = schema(IntrospectionLimits::new());
+
+        let res = crate::execute_sync(INTROSPECTION_QUERY, None, &schema, &graphql_vars! {}, &());
+
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    fn rejects_deep_of_type_chains() {
+        let schema = schema(IntrospectionLimits::new().max_of_type_depth(1));
+        let doc = r#"{ __type(name: "Query") { fields { type { ofType { ofType { name } } } } } }"#;
+
+        let res = crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &());
+
+        assert_eq!(
+            res,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Introspection nests more than the maximum of 1 `ofType` fields",
+                &[SourcePosition::new(51, 0, 51)],
+            )])),
+        );
+    }
+
+    #[test]
+    fn rejects_large_introspection_selections() {
+        let schema = schema(IntrospectionLimits::new().max_fields(3));
+        let doc = "{ ids a: __schema { ...Types } b: __schema { ...Types } } \
+                   fragment Types on __Schema { types { name } }";
+
+        let res = crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &());
+
+        assert_eq!(
+            res,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Introspection selects more than the maximum of 3 fields",
+                &[SourcePosition::new(31, 0, 31)],
+            )])),
+        );
+    }
+
+    #[test]
+    fn counts_fragments_selecting_introspection() {
+        let schema = schema(IntrospectionLimits::new().max_fields(2));
+        let doc = "{ ids ...Schema ...Schema } \
+                   fragment Schema on Query { __schema { queryType { name } } }";
+
+        let res = crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &());
+
+        assert_eq!(
+            res,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Introspection selects more than the maximum of 2 fields",
+                &[SourcePosition::new(78, 0, 78)],
+            )])),
+        );
+    }
+
+    #[test]
+    fn ignores_regular_fields() {
+        let schema = schema(IntrospectionLimits::new().max_fields(0));
+
+        let res = crate::execute_sync(
+            "{ a: ids b: ids c: ids }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        );
+
+        assert!(res.is_ok(), "{res:?}");
+    }
+}
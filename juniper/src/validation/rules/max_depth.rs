@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{Document, Field, Fragment, FragmentSpread, Operation},
+    parser::{SourcePosition, Spanning},
+    validation::{OperationScopes, ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+enum Item<'a> {
+    /// Field nested into the provided number of fields (itself included) of
+    /// its operation or fragment.
+    Field(usize, SourcePosition),
+
+    /// Spread of the named fragment, nested into the provided number of fields
+    /// of its operation or fragment.
+    Spread(usize, &'a str),
+}
+
+pub struct MaxDepth<'a> {
+    max: Option<usize>,
+    scopes: OperationScopes<'a, Item<'a>>,
+    depth: usize,
+}
+
+pub fn factory(operation: Option<&str>, max: Option<usize>) -> MaxDepth<'_> {
+    MaxDepth {
+        max,
+        scopes: OperationScopes::new(operation),
+        depth: 0,
+    }
+}
+
+impl<'a> MaxDepth<'a> {
+    /// Returns the depth of the provided `items`: the maximum number of fields
+    /// nested into each other, with fragment spreads expanded.
+    fn depth(&self, items: &[Item<'a>], depths: &mut HashMap<&'a str, usize>) -> usize {
+        items
+            .iter()
+            .map(|item| match *item {
+                Item::Field(depth, _) => depth,
+                Item::Spread(depth, name) => depth + self.fragment_depth(name, depths),
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn fragment_depth(&self, name: &'a str, depths: &mut HashMap<&'a str, usize>) -> usize {
+        if let Some(depth) = depths.get(name) {
+            return *depth;
+        }
+        // Guards against cyclic spreads, rejected by validation beforehand.
+        depths.insert(name, 0);
+        let depth = self.depth(self.scopes.fragment(name), depths);
+        depths.insert(name, depth);
+        depth
+    }
+
+    /// Returns the position of the first field of the provided `items` nested
+    /// deeper than `max`, once they're nested into `offset` fields.
+    fn first_too_deep(
+        &self,
+        items: &[Item<'a>],
+        offset: usize,
+        max: usize,
+        depths: &mut HashMap<&'a str, usize>,
+    ) -> Option<SourcePosition> {
+        for item in items {
+            match *item {
+                Item::Field(depth, pos) if offset + depth > max => return Some(pos),
+                Item::Spread(depth, name)
+                    if offset + depth + self.fragment_depth(name, depths) > max =>
+                {
+                    let fragment = self.scopes.fragment(name);
+                    if let Some(pos) = self.first_too_deep(fragment, offset + depth, max, depths) {
+                        return Some(pos);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for MaxDepth<'a>
+where
+    S: ScalarValue,
+{
+    fn exit_document(&mut self, ctx: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {
+        let max = match self.max {
+            Some(max) => max,
+            None => return,
+        };
+
+        let mut depths = HashMap::new();
+        let depth = self.depth(self.scopes.operation(), &mut depths);
+        if depth > max {
+            if let Some(pos) = self.first_too_deep(self.scopes.operation(), 0, max, &mut depths) {
+                ctx.report_error(&error_message(depth, max), &[pos]);
+            }
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        if self.max.is_some() {
+            self.scopes.enter_operation(op);
+        }
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        f: &'a Spanning<Fragment<S>>,
+    ) {
+        if self.max.is_some() {
+            self.scopes.enter_fragment(f);
+        }
+    }
+
+    fn enter_field(&mut self, _: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        self.depth += 1;
+        self.scopes.record(Item::Field(self.depth, field.start));
+    }
+
+    fn exit_field(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Field<S>>) {
+        self.depth -= 1;
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        spread: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        self.scopes
+            .record(Item::Spread(self.depth, spread.item.name.item));
+    }
+}
+
+fn error_message(depth: usize, max: usize) -> String {
+    format!("Operation depth of {depth} exceeds the maximum of {max}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_object, graphql_vars,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::RuleError,
+        GraphQLError,
+    };
+
+    struct Person;
+
+    #[graphql_object]
+    impl Person {
+        fn name() -> &'static str {
+            "person"
+        }
+
+        fn friends() -> Vec<Person> {
+            vec![Person]
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn me() -> Person {
+            Person
+        }
+    }
+
+    fn schema(max: usize) -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new()).with_max_depth(max)
+    }
+
+    fn execute(doc: &str, max: usize) -> Result<(), GraphQLError> {
+        crate::execute_sync(doc, None, &schema(max), &graphql_vars! {}, &()).map(drop)
+    }
+
+    fn error(depth: usize, max: usize, pos: SourcePosition) -> GraphQLError {
+        GraphQLError::ValidationError(vec![RuleError::new(
+            &super::error_message(depth, max),
+            &[pos],
+        )])
+    }
+
+    #[test]
+    fn counts_nested_fields() {
+        assert_eq!(
+            execute("{ __typename }", 0),
+            Err(error(1, 0, SourcePosition::new(2, 0, 2))),
+        );
+        assert_eq!(execute("{ me { name friends { name } } }", 3), Ok(()));
+        assert_eq!(
+            execute("{ me { name friends { name } } }", 2),
+            Err(error(3, 2, SourcePosition::new(22, 0, 22))),
+        );
+    }
+
+    #[test]
+    fn expands_fragments() {
+        let doc = "{ me { ...F ... on Person { friends { name } } } } \
+                   fragment F on Person { friends { friends { name } } }";
+
+        assert_eq!(execute(doc, 4), Ok(()));
+        assert_eq!(
+            execute(doc, 3),
+            Err(error(4, 3, SourcePosition::new(94, 0, 94))),
+        );
+    }
+
+    #[test]
+    fn ignores_other_operations() {
+        let doc = "query A { me { name } } query B { me { friends { name } } }";
+        let schema = schema(2);
+
+        let res = crate::execute_sync(doc, Some("A"), &schema, &graphql_vars! {}, &());
+        assert!(res.is_ok(), "{res:?}");
+        let res = crate::execute_sync(doc, Some("B"), &schema, &graphql_vars! {}, &());
+        assert!(
+            matches!(res, Err(GraphQLError::ValidationError(ref errs)) if errs.len() == 1),
+            "{res:?}",
+        );
+    }
+
+    #[test]
+    fn points_to_first_too_deep_field() {
+        assert_eq!(execute("{ me { friends { name } } }", 3), Ok(()));
+        assert_eq!(
+            execute("{ me { friends { name } } }", 2),
+            Err(error(3, 2, SourcePosition::new(17, 0, 17))),
+        );
+    }
+}
@@ -1,21 +1,28 @@
 mod arguments_of_correct_type;
 mod default_values_of_correct_type;
+mod defer_directives;
 mod fields_on_correct_type;
 mod fragments_on_composite_types;
+mod introspection_limits;
 mod known_argument_names;
 mod known_directives;
 mod known_fragment_names;
 mod known_type_names;
 mod lone_anonymous_operation;
+mod max_depth;
 mod no_fragment_cycles;
+mod no_introspection;
 mod no_undefined_variables;
 mod no_unused_fragments;
 mod no_unused_variables;
+mod operation_limits;
 mod overlapping_fields_can_be_merged;
+mod pagination_bounds;
 mod possible_fragment_spreads;
 mod provided_non_null_arguments;
 mod scalar_leafs;
 mod unique_argument_names;
+mod unique_directives_per_location;
 mod unique_fragment_names;
 mod unique_input_field_names;
 mod unique_operation_names;
@@ -24,8 +31,13 @@ mod variables_are_input_types;
 mod variables_in_allowed_position;
 
 use crate::{
-    ast::Document,
-    validation::{visit, MultiVisitorNil, ValidatorContext},
+    ast::{Document, Operation},
+    executor::Variables,
+    parser::Spanning,
+    validation::{
+        visit, IntrospectionLimits, MultiVisitorNil, OperationLimits, PaginationBounds,
+        ValidatorContext,
+    },
     value::ScalarValue,
 };
 use std::fmt::Debug;
@@ -45,6 +57,7 @@ where
     let mut stage1 = MultiVisitorNil
         .with(self::arguments_of_correct_type::factory())
         .with(self::default_values_of_correct_type::factory())
+        .with(self::defer_directives::factory())
         .with(self::fields_on_correct_type::factory())
         .with(self::fragments_on_composite_types::factory())
         .with(self::known_argument_names::factory())
@@ -60,6 +73,7 @@ where
         .with(self::provided_non_null_arguments::factory())
         .with(self::scalar_leafs::factory())
         .with(self::unique_argument_names::factory())
+        .with(self::unique_directives_per_location::factory())
         .with(self::unique_fragment_names::factory())
         .with(self::unique_input_field_names::factory())
         .with(self::unique_operation_names::factory())
@@ -75,6 +89,78 @@ where
     visit(&mut stage2, ctx, doc);
 }
 
+/// Limits of the operations executed against a schema, validated by
+/// [`visit_operation_rules()`].
+#[derive(Clone, Copy)]
+pub(crate) struct OperationRules<'a> {
+    /// Maximum number of fields nested into each other, if any.
+    pub(crate) max_depth: Option<usize>,
+
+    /// Bounds of the pagination arguments of all the fields, if any.
+    pub(crate) pagination_bounds: Option<&'a PaginationBounds>,
+
+    /// Indicator whether the `__schema` and `__type` fields may be selected.
+    pub(crate) introspection: bool,
+
+    /// Limits of the `__schema` and `__type` selections, if any.
+    pub(crate) introspection_limits: Option<&'a IntrospectionLimits>,
+
+    /// Limits on the number of aliases, root fields and directives.
+    pub(crate) operation_limits: &'a OperationLimits,
+}
+
+impl<'a> OperationRules<'a> {
+    /// Indicates whether none of these [`OperationRules`] limits operations.
+    fn is_unlimited(&self) -> bool {
+        self.max_depth.is_none()
+            && self.pagination_bounds.is_none()
+            && self.introspection
+            && self.introspection_limits.is_none()
+            && self.operation_limits.is_unlimited()
+    }
+}
+
+/// Validates the provided `operation` of the `doc`, already validated via
+/// [`visit_all_rules()`], to be executed with the provided `variables` within
+/// the provided `rules`.
+///
+/// Unlike [`visit_all_rules()`], the outcome depends on the executed
+/// operation and its variables, so can't be cached along with the `doc`.
+/// Fragment spreads are expanded, so the fragments are accounted only where
+/// the `operation` spreads them.
+pub(crate) fn visit_operation_rules<'a, S>(
+    ctx: &mut ValidatorContext<'a, S>,
+    doc: &'a Document<S>,
+    operation: &'a Spanning<Operation<S>>,
+    variables: &'a Variables<S>,
+    rules: OperationRules<'a>,
+) where
+    S: ScalarValue,
+{
+    if rules.is_unlimited() {
+        return;
+    }
+
+    let name = operation.item.name.as_ref().map(|n| n.item);
+    let mut visitor = MultiVisitorNil
+        .with(self::introspection_limits::factory(
+            name,
+            rules.introspection_limits,
+        ))
+        .with(self::max_depth::factory(name, rules.max_depth))
+        .with(self::no_introspection::factory(name, !rules.introspection))
+        .with(self::operation_limits::factory(
+            name,
+            rules.operation_limits,
+        ))
+        .with(self::pagination_bounds::factory(
+            operation,
+            variables,
+            rules.pagination_bounds,
+        ));
+    visit(&mut visitor, ctx, doc);
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{parser::SourcePosition, DefaultScalarValue};
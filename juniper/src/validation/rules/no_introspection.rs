@@ -0,0 +1,170 @@
+use crate::{
+    ast::{Document, Field, Fragment, FragmentSpread, Operation},
+    parser::{SourcePosition, Spanning},
+    validation::{OperationScopes, ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+enum Item<'a> {
+    Introspection(&'a str, SourcePosition),
+    Spread(&'a str),
+}
+
+pub struct NoIntrospection<'a> {
+    disabled: bool,
+    scopes: OperationScopes<'a, Item<'a>>,
+}
+
+pub fn factory(operation: Option<&str>, disabled: bool) -> NoIntrospection<'_> {
+    NoIntrospection {
+        disabled,
+        scopes: OperationScopes::new(operation),
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for NoIntrospection<'a>
+where
+    S: ScalarValue,
+{
+    fn exit_document(&mut self, ctx: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {
+        let fields = self.scopes.reachable(|item| match *item {
+            Item::Spread(name) => Some(name),
+            Item::Introspection(..) => None,
+        });
+        for item in fields {
+            if let Item::Introspection(name, pos) = *item {
+                ctx.report_error(&error_message(name), &[pos]);
+            }
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        if self.disabled {
+            self.scopes.enter_operation(op);
+        }
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        f: &'a Spanning<Fragment<S>>,
+    ) {
+        if self.disabled {
+            self.scopes.enter_fragment(f);
+        }
+    }
+
+    fn enter_field(&mut self, _: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        // `__typename` is allowed, as clients rely on it for resolving abstract
+        // types.
+        if let name @ ("__schema" | "__type") = field.item.name.item {
+            self.scopes.record(Item::Introspection(name, field.start));
+        }
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        spread: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        self.scopes.record(Item::Spread(spread.item.name.item));
+    }
+}
+
+fn error_message(field_name: &str) -> String {
+    format!("GraphQL introspection is not allowed, but the operation selects `{field_name}`")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_object, graphql_vars,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::RuleError,
+        ExecutionOptions, GraphQLError, IntrospectionFormat,
+    };
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn ids() -> Vec<i32> {
+            vec![1, 2, 3]
+        }
+    }
+
+    #[test]
+    fn rejects_introspection_when_disabled() {
+        let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .disable_introspection();
+        let doc = r#"{ __typename ...F __type(name: "Query") { name } }
+                     fragment F on Query { ids __schema { types { name } } }"#;
+
+        let res = crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &());
+
+        assert_eq!(
+            res,
+            Err(GraphQLError::ValidationError(vec![
+                RuleError::new(
+                    "GraphQL introspection is not allowed, but the operation selects `__type`",
+                    &[SourcePosition::new(18, 0, 18)],
+                ),
+                RuleError::new(
+                    "GraphQL introspection is not allowed, but the operation selects `__schema`",
+                    &[SourcePosition::new(98, 1, 47)],
+                ),
+            ])),
+        );
+        assert!(
+            crate::execute_sync("{ __typename ids }", None, &schema, &graphql_vars! {}, &())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn overrides_introspection_per_request() {
+        let enabled = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+        let disabled = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .disable_introspection();
+        let doc = "{ __schema { queryType { name } } }";
+
+        let res = crate::execute_sync_with_options(
+            doc,
+            None,
+            &disabled,
+            &graphql_vars! {},
+            &(),
+            &ExecutionOptions::new().introspection(true),
+        );
+        assert!(res.is_ok(), "{res:?}");
+
+        let res = crate::execute_sync_with_options(
+            doc,
+            None,
+            &enabled,
+            &graphql_vars! {},
+            &(),
+            &ExecutionOptions::new().introspection(false),
+        );
+        assert!(
+            matches!(res, Err(GraphQLError::ValidationError(_))),
+            "{res:?}",
+        );
+    }
+
+    #[test]
+    fn allows_server_side_introspection_when_disabled() {
+        let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .disable_introspection();
+
+        let res = crate::introspect(&schema, &(), IntrospectionFormat::All);
+
+        assert!(res.is_ok(), "{res:?}");
+    }
+}
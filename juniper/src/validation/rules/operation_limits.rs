@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{Directive, Document, Field, Fragment, FragmentSpread, Operation, VariableDefinition},
+    parser::{SourcePosition, Spanning},
+    validation::{OperationLimits, OperationScopes, RuleError, ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+enum Item<'a> {
+    /// Field, selected on the type of its operation or fragment if `true`.
+    Field(bool, SourcePosition),
+
+    Alias(SourcePosition),
+
+    Directive(SourcePosition),
+
+    /// Spread of the named fragment, spread on the type of its operation or
+    /// fragment if `true`.
+    Spread(bool, &'a str),
+}
+
+pub struct OperationLimitsRule<'a> {
+    limits: &'a OperationLimits,
+    scopes: OperationScopes<'a, Item<'a>>,
+    depth: usize,
+    in_variable_definition: bool,
+}
+
+pub fn factory<'a>(
+    operation: Option<&'a str>,
+    limits: &'a OperationLimits,
+) -> OperationLimitsRule<'a> {
+    OperationLimitsRule {
+        limits,
+        scopes: OperationScopes::new(operation),
+        depth: 0,
+        in_variable_definition: false,
+    }
+}
+
+#[derive(Default)]
+struct Count {
+    value: usize,
+    max: Option<usize>,
+    exceeded_at: Option<SourcePosition>,
+}
+
+impl Count {
+    fn increment(&mut self, max: Option<usize>, position: SourcePosition) {
+        self.value += 1;
+        if let Some(max) = max {
+            if self.value > max && self.exceeded_at.is_none() {
+                self.max = Some(max);
+                self.exceeded_at = Some(position);
+            }
+        }
+    }
+
+    /// Indicates whether adding the provided `n` to this [`Count`] exceeds the
+    /// provided `max` for the first time.
+    fn exceeds(&self, n: usize, max: Option<usize>) -> bool {
+        self.exceeded_at.is_none() && max.map_or(false, |max| self.value + n > max)
+    }
+
+    fn error(&self, what: &str) -> Option<RuleError> {
+        let (max, pos) = (self.max?, self.exceeded_at?);
+        Some(RuleError::new(
+            &format!("Operation has more than the maximum of {max} {what}"),
+            &[pos],
+        ))
+    }
+}
+
+/// Aliases, root fields and directives of a fragment, with fragment spreads
+/// expanded.
+#[derive(Clone, Copy, Default)]
+struct Totals {
+    aliases: usize,
+
+    /// Fields selected on the root type, once the fragment is spread on it.
+    root_fields: usize,
+
+    directives: usize,
+}
+
+/// Aliases, root fields and directives counted so far, with fragment spreads
+/// expanded.
+#[derive(Default)]
+struct Counter<'a> {
+    aliases: Count,
+    root_fields: Count,
+    directives: Count,
+    totals: HashMap<&'a str, Totals>,
+}
+
+impl<'a> OperationLimitsRule<'a> {
+    /// Counts the provided `items`, being selected on the operation root type
+    /// if `root`.
+    ///
+    /// Fragments are expanded only if some limit is exceeded within them, so
+    /// their items are counted once otherwise, however many times they're
+    /// spread.
+    fn count(&self, items: &[Item<'a>], root: bool, counter: &mut Counter<'a>) {
+        let limits = self.limits;
+        for item in items {
+            match *item {
+                Item::Field(on_root, pos) => {
+                    if root && on_root {
+                        counter
+                            .root_fields
+                            .increment(limits.get_max_root_fields(), pos);
+                    }
+                }
+                Item::Alias(pos) => counter.aliases.increment(limits.get_max_aliases(), pos),
+                Item::Directive(pos) => counter
+                    .directives
+                    .increment(limits.get_max_directives(), pos),
+                Item::Spread(on_root, name) => {
+                    let root = root && on_root;
+                    let totals = self.totals(name, &mut counter.totals);
+                    let root_fields = if root { totals.root_fields } else { 0 };
+                    if counter
+                        .aliases
+                        .exceeds(totals.aliases, limits.get_max_aliases())
+                        || counter
+                            .root_fields
+                            .exceeds(root_fields, limits.get_max_root_fields())
+                        || counter
+                            .directives
+                            .exceeds(totals.directives, limits.get_max_directives())
+                    {
+                        self.count(self.scopes.fragment(name), root, counter);
+                    } else {
+                        counter.aliases.value += totals.aliases;
+                        counter.root_fields.value += root_fields;
+                        counter.directives.value += totals.directives;
+                    }
+                }
+            }
+        }
+    }
+
+    fn totals(&self, name: &'a str, totals: &mut HashMap<&'a str, Totals>) -> Totals {
+        if let Some(t) = totals.get(name) {
+            return *t;
+        }
+        // Guards against cyclic spreads, rejected by validation beforehand.
+        totals.insert(name, Totals::default());
+        let mut t = Totals::default();
+        for item in self.scopes.fragment(name) {
+            match *item {
+                Item::Field(on_root, _) => t.root_fields += usize::from(on_root),
+                Item::Alias(_) => t.aliases += 1,
+                Item::Directive(_) => t.directives += 1,
+                Item::Spread(on_root, name) => {
+                    let spread = self.totals(name, totals);
+                    t.aliases += spread.aliases;
+                    t.directives += spread.directives;
+                    if on_root {
+                        t.root_fields += spread.root_fields;
+                    }
+                }
+            }
+        }
+        totals.insert(name, t);
+        t
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for OperationLimitsRule<'a>
+where
+    S: ScalarValue,
+{
+    fn exit_document(&mut self, ctx: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {
+        if self.limits.is_unlimited() {
+            return;
+        }
+
+        let mut counter = Counter::default();
+        self.count(self.scopes.operation(), true, &mut counter);
+
+        ctx.append_errors(
+            [
+                counter.aliases.error("aliases"),
+                counter.root_fields.error("root fields"),
+                counter.directives.error("directives"),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        );
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        if !self.limits.is_unlimited() {
+            self.scopes.enter_operation(op);
+        }
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        f: &'a Spanning<Fragment<S>>,
+    ) {
+        if !self.limits.is_unlimited() {
+            self.scopes.enter_fragment(f);
+        }
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        _: &'a (Spanning<&'a str>, VariableDefinition<S>),
+    ) {
+        self.in_variable_definition = true;
+    }
+
+    fn exit_variable_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        _: &'a (Spanning<&'a str>, VariableDefinition<S>),
+    ) {
+        self.in_variable_definition = false;
+    }
+
+    fn enter_directive(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        directive: &'a Spanning<Directive<S>>,
+    ) {
+        // Directives of variables don't affect the execution.
+        if !self.in_variable_definition {
+            self.scopes.record(Item::Directive(directive.start));
+        }
+    }
+
+    fn enter_field(&mut self, _: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        self.scopes
+            .record(Item::Field(self.depth == 0, field.start));
+        if let Some(alias) = &field.item.alias {
+            self.scopes.record(Item::Alias(alias.start));
+        }
+        self.depth += 1;
+    }
+
+    fn exit_field(&mut self, _: &mut ValidatorContext<'a, S>, _: &'a Spanning<Field<S>>) {
+        self.depth -= 1;
+    }
+
+    fn exit_fragment_spread(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        spread: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        // Recorded after the directives of the spread, as these are visited
+        // before the fragment is expanded.
+        self.scopes
+            .record(Item::Spread(self.depth == 0, spread.item.name.item));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_object, graphql_vars,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::{OperationLimits, RuleError},
+        GraphQLError,
+    };
+
+    struct Person;
+
+    #[graphql_object]
+    impl Person {
+        fn name() -> &'static str {
+            "person"
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn me() -> Person {
+            Person
+        }
+
+        fn answer() -> i32 {
+            42
+        }
+    }
+
+    fn schema(
+        limits: OperationLimits,
+    ) -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .with_operation_limits(limits)
+    }
+
+    fn execute(doc: &str, limits: OperationLimits) -> Result<(), GraphQLError> {
+        crate::execute_sync(doc, None, &schema(limits), &graphql_vars! {}, &()).map(drop)
+    }
+
+    #[test]
+    fn rejects_too_many_aliases() {
+        let limits = OperationLimits::new().max_aliases(2);
+
+        assert_eq!(execute("{ a: answer b: answer answer }", limits), Ok(()));
+        assert_eq!(
+            execute(
+                "{ a: answer ...F } fragment F on Query { b: answer c: answer }",
+                limits,
+            ),
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Operation has more than the maximum of 2 aliases",
+                &[SourcePosition::new(51, 0, 51)],
+            )])),
+        );
+    }
+
+    #[test]
+    fn rejects_too_many_root_fields() {
+        let limits = OperationLimits::new().max_root_fields(2);
+
+        assert_eq!(execute("{ me { name name: name } answer }", limits), Ok(()));
+        assert_eq!(
+            execute("{ answer ... on Query { me { name } a: answer } }", limits),
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Operation has more than the maximum of 2 root fields",
+                &[SourcePosition::new(36, 0, 36)],
+            )])),
+        );
+    }
+
+    #[test]
+    fn rejects_too_many_directives() {
+        let limits = OperationLimits::new().max_directives(2);
+
+        assert_eq!(execute("{ answer @include(if: true) }", limits), Ok(()));
+        assert_eq!(
+            execute(
+                "{ answer @include(if: true) me @skip(if: false) { name @skip(if: false) } }",
+                limits,
+            ),
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                "Operation has more than the maximum of 2 directives",
+                &[SourcePosition::new(55, 0, 55)],
+            )])),
+        );
+    }
+
+    #[test]
+    fn counts_fragments_every_time_spread() {
+        let limits = OperationLimits::new().max_aliases(3).max_root_fields(3);
+        let doc = "{ ...F ...F } fragment F on Query { a: answer me { n: name } }";
+
+        assert_eq!(
+            execute(doc, limits),
+            Err(GraphQLError::ValidationError(vec![
+                RuleError::new(
+                    "Operation has more than the maximum of 3 root fields",
+                    &[SourcePosition::new(46, 0, 46)],
+                ),
+                RuleError::new(
+                    "Operation has more than the maximum of 3 aliases",
+                    &[SourcePosition::new(51, 0, 51)],
+                ),
+            ])),
+        );
+    }
+
+    #[test]
+    fn reports_every_exceeded_limit() {
+        let limits = OperationLimits::new()
+            .max_aliases(0)
+            .max_root_fields(1)
+            .max_directives(0);
+
+        assert_eq!(
+            execute("{ a: answer b: answer @skip(if: true) }", limits),
+            Err(GraphQLError::ValidationError(vec![
+                RuleError::new(
+                    "Operation has more than the maximum of 0 aliases",
+                    &[SourcePosition::new(2, 0, 2)],
+                ),
+                RuleError::new(
+                    "Operation has more than the maximum of 1 root fields",
+                    &[SourcePosition::new(12, 0, 12)],
+                ),
+                RuleError::new(
+                    "Operation has more than the maximum of 0 directives",
+                    &[SourcePosition::new(22, 0, 22)],
+                ),
+            ])),
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{Document, Field, Fragment, FragmentSpread, InputValue, Operation},
+    executor::Variables,
+    parser::{SourcePosition, Spanning},
+    validation::{OperationScopes, PaginationBounds, ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+enum Item<'a> {
+    Error(String, SourcePosition),
+    Spread(&'a str),
+}
+
+pub struct PaginationArguments<'a, S> {
+    bounds: Option<&'a PaginationBounds>,
+    variables: &'a Variables<S>,
+    defaults: HashMap<&'a str, &'a InputValue<S>>,
+    scopes: OperationScopes<'a, Item<'a>>,
+}
+
+pub fn factory<'a, S>(
+    operation: &'a Spanning<Operation<S>>,
+    variables: &'a Variables<S>,
+    bounds: Option<&'a PaginationBounds>,
+) -> PaginationArguments<'a, S> {
+    PaginationArguments {
+        bounds,
+        variables,
+        defaults: operation
+            .item
+            .variable_definitions
+            .iter()
+            .flat_map(|defs| &defs.item.items)
+            .filter_map(|(name, def)| Some((name.item, &def.default_value.as_ref()?.item)))
+            .collect(),
+        scopes: OperationScopes::new(operation.item.name.as_ref().map(|n| n.item)),
+    }
+}
+
+impl<'a, S: ScalarValue> PaginationArguments<'a, S> {
+    /// Checks the provided `value` of the pagination argument, resolving the
+    /// variables of the operation to be executed, and returns the error
+    /// message, if it's out of the `bounds`.
+    ///
+    /// Omitted and `null` arguments are not checked, leaving them up to the
+    /// resolvers to default.
+    fn check(
+        &self,
+        bounds: &PaginationBounds,
+        field_name: &str,
+        arg_name: &str,
+        value: &Spanning<InputValue<S>>,
+    ) -> Option<String> {
+        let resolved = match &value.item {
+            InputValue::Variable(var) => self
+                .variables
+                .get(var)
+                .or_else(|| self.defaults.get(var.as_str()).copied()),
+            v => Some(v),
+        };
+        let n = resolved.and_then(InputValue::as_int_value)?;
+        if n < 0 {
+            Some(format!(
+                r#"Argument "{arg_name}" of field "{field_name}" must not be negative"#
+            ))
+        } else if n > bounds.max() {
+            Some(format!(
+                r#"Argument "{arg_name}" of field "{field_name}" exceeds the maximum of {}"#,
+                bounds.max(),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for PaginationArguments<'a, S>
+where
+    S: ScalarValue,
+{
+    fn exit_document(&mut self, ctx: &mut ValidatorContext<'a, S>, _: &'a Document<S>) {
+        let errors = self.scopes.reachable(|item| match *item {
+            Item::Spread(name) => Some(name),
+            Item::Error(..) => None,
+        });
+        for item in errors {
+            if let Item::Error(message, pos) = item {
+                ctx.report_error(message, &[*pos]);
+            }
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        if self.bounds.is_some() {
+            self.scopes.enter_operation(op);
+        }
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        f: &'a Spanning<Fragment<S>>,
+    ) {
+        if self.bounds.is_some() {
+            self.scopes.enter_fragment(f);
+        }
+    }
+
+    fn enter_field(&mut self, _: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        let args = field.item.arguments.iter().flat_map(|a| &a.item.items);
+        for (name, value) in args {
+            if bounds.applies_to(name.item) {
+                if let Some(message) = self.check(bounds, field.item.name.item, name.item, value) {
+                    self.scopes.record(Item::Error(message, value.start));
+                }
+            }
+        }
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        _: &mut ValidatorContext<'a, S>,
+        spread: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        self.scopes.record(Item::Spread(spread.item.name.item));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphql_object, graphql_vars,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::{PaginationBounds, RuleError},
+        GraphQLError,
+    };
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn items(first: Option<i32>, offset: Option<i32>) -> Vec<i32> {
+            (offset.unwrap_or(0)..)
+                .take(first.unwrap_or(10) as usize)
+                .collect()
+        }
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation, EmptySubscription> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .with_pagination_bounds(PaginationBounds::new(5))
+    }
+
+    #[test]
+    fn allows_arguments_within_bounds() {
+        let schema = schema();
+
+        for doc in [
+            "{ items(first: 5) }",
+            "{ items(first: 0) }",
+            "{ items }",
+            "{ items(first: null) }",
+            "{ items(offset: 1000) }",
+        ] {
+            let res = crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &());
+            assert!(res.is_ok(), "{doc}: {res:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_literal_arguments_out_of_bounds() {
+        let schema = schema();
+
+        let res = crate::execute_sync(
+            "{ items(first: 6) ... on Query { other: items(first: -1) } }",
+            None,
+            &schema,
+            &graphql_vars! {},
+            &(),
+        );
+
+        assert_eq!(
+            res,
+            Err(GraphQLError::ValidationError(vec![
+                RuleError::new(
+                    r#"Argument "first" of field "items" exceeds the maximum of 5"#,
+                    &[SourcePosition::new(15, 0, 15)],
+                ),
+                RuleError::new(
+                    r#"Argument "first" of field "items" must not be negative"#,
+                    &[SourcePosition::new(53, 0, 53)],
+                ),
+            ])),
+        );
+    }
+
+    #[test]
+    fn rejects_variables_out_of_bounds() {
+        let schema = schema();
+        let doc = "query($n: Int = 100) { items(first: $n) }";
+
+        let res = crate::execute_sync(doc, None, &schema, &graphql_vars! {"n": 5}, &());
+        assert!(res.is_ok(), "{res:?}");
+
+        let res = crate::execute_sync(doc, None, &schema, &graphql_vars! {"n": 6}, &());
+        assert!(
+            matches!(res, Err(GraphQLError::ValidationError(ref errs)) if errs.len() == 1),
+            "{res:?}",
+        );
+
+        let res = crate::execute_sync(doc, None, &schema, &graphql_vars! {}, &());
+        assert!(
+            matches!(res, Err(GraphQLError::ValidationError(ref errs)) if errs.len() == 1),
+            "{res:?}",
+        );
+    }
+
+    #[test]
+    fn checks_spread_fragments_once() {
+        let schema = schema();
+        let doc = "query A { ...F ...F } query B { items } \
+                   fragment F on Query { items(first: 6) }";
+
+        let res = crate::execute_sync(doc, Some("B"), &schema, &graphql_vars! {}, &());
+        assert!(res.is_ok(), "{res:?}");
+
+        let res = crate::execute_sync(doc, Some("A"), &schema, &graphql_vars! {}, &());
+        assert_eq!(
+            res,
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                r#"Argument "first" of field "items" exceeds the maximum of 5"#,
+                &[SourcePosition::new(75, 0, 75)],
+            )])),
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::hash_map::{Entry, HashMap};
+
+use crate::{
+    ast::{
+        Directive, Field, Fragment, FragmentSpread, InlineFragment, Operation, VariableDefinition,
+    },
+    parser::Spanning,
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+pub struct UniqueDirectivesPerLocation;
+
+pub fn factory() -> UniqueDirectivesPerLocation {
+    UniqueDirectivesPerLocation
+}
+
+impl<'a, S> Visitor<'a, S> for UniqueDirectivesPerLocation
+where
+    S: ScalarValue,
+{
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        check_directives(ctx, &op.item.directives);
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        f: &'a Spanning<Fragment<S>>,
+    ) {
+        check_directives(ctx, &f.item.directives);
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        (_, var_def): &'a (Spanning<&'a str>, VariableDefinition<S>),
+    ) {
+        check_directives(ctx, &var_def.directives);
+    }
+
+    fn enter_field(&mut self, ctx: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        check_directives(ctx, &field.item.directives);
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        spread: &'a Spanning<FragmentSpread<S>>,
+    ) {
+        check_directives(ctx, &spread.item.directives);
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        fragment: &'a Spanning<InlineFragment<S>>,
+    ) {
+        check_directives(ctx, &fragment.item.directives);
+    }
+}
+
+/// Reports every non-repeatable directive specified more than once in the
+/// provided `directives` of a single location.
+///
+/// Unknown directives are ignored, as they're reported by the
+/// `known_directives` rule already.
+fn check_directives<'a, S>(
+    ctx: &mut ValidatorContext<'a, S>,
+    directives: &'a Option<Vec<Spanning<Directive<S>>>>,
+) where
+    S: ScalarValue,
+{
+    let mut known_names = HashMap::new();
+    for directive in directives.iter().flatten() {
+        let name = directive.item.name.item;
+        match ctx.schema.directive_by_name(name) {
+            Some(d) if !d.is_repeatable => {}
+            _ => continue,
+        }
+        match known_names.entry(name) {
+            Entry::Occupied(e) => {
+                ctx.report_error(&error_message(name), &[*e.get(), directive.start]);
+            }
+            Entry::Vacant(e) => {
+                e.insert(directive.start);
+            }
+        }
+    }
+}
+
+fn error_message(directive_name: &str) -> String {
+    format!(r#"The directive "{directive_name}" can only be used once at this location"#)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_message, factory};
+
+    use crate::{
+        parser::SourcePosition,
+        validation::{expect_fails_rule, expect_passes_rule, RuleError},
+        value::DefaultScalarValue,
+    };
+
+    #[test]
+    fn no_directives() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          fragment Test on Dog {
+            name
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn unique_directives_in_different_locations() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          fragment Test on Dog @onFragmentDefinition {
+            name @onField
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn same_directives_in_different_locations() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog @onField {
+              name @onField
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn repeatable_directives_in_same_location() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog @onFieldRepeatable @onFieldRepeatable {
+              name
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn duplicate_directives_in_one_location() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog {
+              name @onField @onField
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message("onField"),
+                &[
+                    SourcePosition::new(50, 3, 19),
+                    SourcePosition::new(59, 3, 28),
+                ],
+            )],
+        );
+    }
+
+    #[test]
+    fn many_duplicate_directives_in_one_location() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog @skip(if: true) @skip(if: false) @skip(if: true) {
+              name
+            }
+          }
+        "#,
+            &[
+                RuleError::new(
+                    &error_message("skip"),
+                    &[
+                        SourcePosition::new(29, 2, 16),
+                        SourcePosition::new(45, 2, 32),
+                    ],
+                ),
+                RuleError::new(
+                    &error_message("skip"),
+                    &[
+                        SourcePosition::new(29, 2, 16),
+                        SourcePosition::new(62, 2, 49),
+                    ],
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn different_duplicate_directives_in_one_location() {
+        expect_fails_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          fragment Test on Dog @onFragmentDefinition @onFragmentDefinition {
+            name
+          }
+        "#,
+            &[RuleError::new(
+                &error_message("onFragmentDefinition"),
+                &[
+                    SourcePosition::new(32, 1, 31),
+                    SourcePosition::new(54, 1, 53),
+                ],
+            )],
+        );
+    }
+}
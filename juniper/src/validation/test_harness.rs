@@ -900,6 +900,12 @@ where
         &[],
         false,
     ));
+    root.schema.add_directive(DirectiveType::new(
+        "onFieldRepeatable",
+        &[DirectiveLocation::Field],
+        &[],
+        true,
+    ));
     root.schema.add_directive(DirectiveType::new(
         "onFragmentDefinition",
         &[DirectiveLocation::FragmentDefinition],
@@ -0,0 +1,380 @@
+//! Path-based diffing and patching of [`Value`]s.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use super::{DefaultScalarValue, Value};
+
+/// Segment of a path in a response.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PathSegment {
+    /// Response name of a field.
+    Field(String),
+
+    /// Index of a list element.
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(name) => f.write_str(name),
+            Self::Index(i) => write!(f, "{i}"),
+        }
+    }
+}
+
+/// Single change of a [`Value`], modelled after a [JSON Patch] operation.
+///
+/// Paths are relative to the patched [`Value`], with an empty path denoting
+/// the whole [`Value`].
+///
+/// [JSON Patch]: https://datatracker.ietf.org/doc/html/rfc6902
+#[derive(Clone, Debug, PartialEq)]
+pub enum Patch<S = DefaultScalarValue> {
+    /// Adds the `value` as a new field of an object, or inserts it into a list
+    /// at the index, shifting the following elements.
+    Add {
+        /// Path the `value` is added at.
+        path: Vec<PathSegment>,
+
+        /// Added value.
+        value: Value<S>,
+    },
+
+    /// Removes a field of an object, or an element of a list, shifting the
+    /// following elements.
+    Remove {
+        /// Path of the removed value.
+        path: Vec<PathSegment>,
+    },
+
+    /// Replaces the existing value with the `value`.
+    Replace {
+        /// Path of the replaced value.
+        path: Vec<PathSegment>,
+
+        /// New value.
+        value: Value<S>,
+    },
+}
+
+impl<S> Patch<S> {
+    /// Returns the path this [`Patch`] applies at.
+    pub fn path(&self) -> &[PathSegment] {
+        match self {
+            Self::Add { path, .. } | Self::Remove { path } | Self::Replace { path, .. } => path,
+        }
+    }
+}
+
+/// Error of [applying](apply) a [`Patch`] at a path not existing in the
+/// patched [`Value`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatchError {
+    path: Vec<PathSegment>,
+}
+
+impl PatchError {
+    /// Returns the path the failed [`Patch`] has been applied at.
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Cannot apply patch at path `")?;
+        for (i, segment) in self.path.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            write!(f, "{segment}")?;
+        }
+        f.write_str("`")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatchError {}
+
+/// Computes the [`Patch`]es turning the `old` [`Value`] into the `new` one.
+///
+/// Objects are diffed field by field, and lists element by element (with the
+/// surplus elements being added or removed at the end), so the [`Patch`]es
+/// stay small for the usual changes of a response, like a changed scalar or
+/// an appended list element. Values of different kinds are replaced as a
+/// whole.
+///
+/// The returned [`Patch`]es are meant to be [applied](apply) in their order.
+///
+/// ```rust
+/// # use juniper::{graphql_value, value::{apply, diff}, Value};
+/// #
+/// let old: Value = graphql_value!({"hero": {"name": "R2-D2", "friends": ["Luke"]}});
+/// let new: Value = graphql_value!({"hero": {"name": "Artoo", "friends": ["Luke", "Leia"]}});
+///
+/// let patches = diff(&old, &new);
+/// assert_eq!(patches.len(), 2);
+///
+/// let mut patched = old.clone();
+/// apply(&mut patched, patches).unwrap();
+/// assert_eq!(patched, new);
+/// ```
+pub fn diff<S>(old: &Value<S>, new: &Value<S>) -> Vec<Patch<S>>
+where
+    S: Clone + PartialEq,
+{
+    let mut patches = Vec::new();
+    diff_into(old, new, &mut Vec::new(), &mut patches);
+    patches
+}
+
+fn diff_into<S>(
+    old: &Value<S>,
+    new: &Value<S>,
+    path: &mut Vec<PathSegment>,
+    patches: &mut Vec<Patch<S>>,
+) where
+    S: Clone + PartialEq,
+{
+    match (old, new) {
+        _ if old == new => {}
+        (Value::Object(old), Value::Object(new)) => {
+            for (name, _) in old.iter().filter(|(n, _)| !new.contains_field(n)) {
+                patches.push(Patch::Remove {
+                    path: with_segment(path, PathSegment::Field(name.clone())),
+                });
+            }
+            for (name, value) in new.iter() {
+                match old.get_field_value(name) {
+                    Some(old_value) => {
+                        path.push(PathSegment::Field(name.clone()));
+                        diff_into(old_value, value, path, patches);
+                        path.pop();
+                    }
+                    None => patches.push(Patch::Add {
+                        path: with_segment(path, PathSegment::Field(name.clone())),
+                        value: value.clone(),
+                    }),
+                }
+            }
+        }
+        (Value::List(old), Value::List(new)) => {
+            for (i, (old_item, item)) in old.iter().zip(new).enumerate() {
+                path.push(PathSegment::Index(i));
+                diff_into(old_item, item, path, patches);
+                path.pop();
+            }
+            // Removing from the end keeps the indices of the preceding
+            // elements intact.
+            for i in (new.len()..old.len()).rev() {
+                patches.push(Patch::Remove {
+                    path: with_segment(path, PathSegment::Index(i)),
+                });
+            }
+            for (i, item) in new.iter().enumerate().skip(old.len()) {
+                patches.push(Patch::Add {
+                    path: with_segment(path, PathSegment::Index(i)),
+                    value: item.clone(),
+                });
+            }
+        }
+        _ => patches.push(Patch::Replace {
+            path: path.clone(),
+            value: new.clone(),
+        }),
+    }
+}
+
+fn with_segment(path: &[PathSegment], segment: PathSegment) -> Vec<PathSegment> {
+    let mut path = path.to_vec();
+    path.push(segment);
+    path
+}
+
+/// Applies the provided [`Patch`]es to the `value` in their order, as
+/// computed by [`diff()`].
+///
+/// # Errors
+///
+/// If a [`Patch`] refers to a path not existing in the `value`. The `value`
+/// keeps the [`Patch`]es preceding the failed one applied.
+pub fn apply<S>(
+    value: &mut Value<S>,
+    patches: impl IntoIterator<Item = Patch<S>>,
+) -> Result<(), PatchError> {
+    for patch in patches {
+        apply_one(value, patch)?;
+    }
+    Ok(())
+}
+
+fn apply_one<S>(value: &mut Value<S>, patch: Patch<S>) -> Result<(), PatchError> {
+    let path = patch.path().to_vec();
+    let err = || PatchError { path: path.clone() };
+
+    let (last, parent_path) = match path.split_last() {
+        Some(split) => split,
+        None => {
+            return match patch {
+                Patch::Add { value: new, .. } | Patch::Replace { value: new, .. } => {
+                    *value = new;
+                    Ok(())
+                }
+                Patch::Remove { .. } => Err(err()),
+            };
+        }
+    };
+    let parent = parent_path
+        .iter()
+        .try_fold(value, |v, segment| child(v, segment))
+        .ok_or_else(err)?;
+
+    match (parent, last, patch) {
+        (Value::Object(o), PathSegment::Field(name), Patch::Add { value: new, .. }) => {
+            o.add_field(name.as_str(), new);
+        }
+        (Value::Object(o), PathSegment::Field(name), Patch::Remove { .. }) => {
+            o.remove_field(name).ok_or_else(err)?;
+        }
+        (Value::List(l), PathSegment::Index(i), Patch::Add { value: new, .. }) if *i <= l.len() => {
+            l.insert(*i, new);
+        }
+        (Value::List(l), PathSegment::Index(i), Patch::Remove { .. }) if *i < l.len() => {
+            l.remove(*i);
+        }
+        (parent, segment, Patch::Replace { value: new, .. }) => {
+            *child(parent, segment).ok_or_else(err)? = new;
+        }
+        _ => return Err(err()),
+    }
+    Ok(())
+}
+
+fn child<'v, S>(value: &'v mut Value<S>, segment: &PathSegment) -> Option<&'v mut Value<S>> {
+    match (value, segment) {
+        (Value::Object(o), PathSegment::Field(name)) => o.get_mut_field_value(name),
+        (Value::List(l), PathSegment::Index(i)) => l.get_mut(*i),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graphql_value, Value};
+
+    use super::{apply, diff, Patch, PatchError, PathSegment};
+
+    fn field(name: &str) -> PathSegment {
+        PathSegment::Field(name.into())
+    }
+
+    #[test]
+    fn diffs_nothing_for_equal_values() {
+        let value: Value = graphql_value!({"a": [1, 2, {"b": null}]});
+
+        assert!(diff(&value, &value.clone()).is_empty());
+    }
+
+    #[test]
+    fn diffs_objects_by_fields() {
+        let old: Value = graphql_value!({"a": 1, "b": 2, "c": {"d": 3}});
+        let new: Value = graphql_value!({"b": 2, "c": {"d": 4}, "e": 5});
+
+        assert_eq!(
+            diff(&old, &new),
+            [
+                Patch::Remove {
+                    path: vec![field("a")],
+                },
+                Patch::Replace {
+                    path: vec![field("c"), field("d")],
+                    value: graphql_value!(4),
+                },
+                Patch::Add {
+                    path: vec![field("e")],
+                    value: graphql_value!(5),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn diffs_lists_by_elements() {
+        let old: Value = graphql_value!([1, 2, 3, 4]);
+        let new: Value = graphql_value!([1, 5]);
+
+        let patches = diff(&old, &new);
+        assert_eq!(
+            patches,
+            [
+                Patch::Replace {
+                    path: vec![PathSegment::Index(1)],
+                    value: graphql_value!(5),
+                },
+                Patch::Remove {
+                    path: vec![PathSegment::Index(3)],
+                },
+                Patch::Remove {
+                    path: vec![PathSegment::Index(2)],
+                },
+            ],
+        );
+
+        let mut patched = old.clone();
+        apply(&mut patched, patches).unwrap();
+        assert_eq!(patched, new);
+
+        let patches = diff(&new, &old);
+        let mut patched = new;
+        apply(&mut patched, patches).unwrap();
+        assert_eq!(patched, old);
+    }
+
+    #[test]
+    fn replaces_values_of_different_kinds() {
+        let old: Value = graphql_value!({"a": [1]});
+        let new: Value = graphql_value!({"a": {"b": 1}});
+
+        let patches = diff(&old, &new);
+        assert_eq!(patches.len(), 1);
+
+        let mut patched = old;
+        apply(&mut patched, patches).unwrap();
+        assert_eq!(patched, new);
+
+        let patches = diff(&patched, &graphql_value!(null));
+        assert_eq!(
+            patches,
+            [Patch::Replace {
+                path: vec![],
+                value: graphql_value!(null),
+            }],
+        );
+    }
+
+    #[test]
+    fn fails_on_missing_paths() {
+        let mut value: Value = graphql_value!({"a": [1]});
+
+        let res = apply(
+            &mut value,
+            [Patch::Remove {
+                path: vec![field("a"), PathSegment::Index(1)],
+            }],
+        );
+
+        assert_eq!(
+            res,
+            Err(PatchError {
+                path: vec![field("a"), PathSegment::Index(1)],
+            }),
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Cannot apply patch at path `a.1`",
+        );
+        assert_eq!(value, graphql_value!({"a": [1]}));
+    }
+}
@@ -1,7 +1,15 @@
+//! Values returned by an execution, and utilities for working with them.
+
+mod diff;
 mod object;
 mod scalar;
 
-use std::{any::TypeId, borrow::Cow, fmt, mem};
+use alloc::{
+    borrow::{Cow, ToOwned as _},
+    string::String,
+    vec::Vec,
+};
+use core::{any::TypeId, fmt, mem};
 
 use crate::{
     ast::{InputValue, ToInputValue},
@@ -9,6 +17,7 @@ use crate::{
 };
 
 pub use self::{
+    diff::{apply, diff, Patch, PatchError, PathSegment},
     object::Object,
     scalar::{DefaultScalarValue, ParseScalarResult, ParseScalarValue, ScalarValue},
 };
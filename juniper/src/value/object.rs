@@ -1,12 +1,15 @@
-use std::mem;
+use alloc::string::String;
+use core::mem;
 
-use super::Value;
+use fnv::FnvBuildHasher;
 use indexmap::map::{IndexMap, IntoIter};
 
+use super::Value;
+
 /// An Object value
 #[derive(Debug, Clone, PartialEq)]
 pub struct Object<S> {
-    key_value_list: IndexMap<String, Value<S>>,
+    key_value_list: IndexMap<String, Value<S>, FnvBuildHasher>,
 }
 
 impl<S> Object<S> {
@@ -14,7 +17,7 @@ impl<S> Object<S> {
     /// preallocated slots for field-value pairs
     pub fn with_capacity(size: usize) -> Self {
         Object {
-            key_value_list: IndexMap::with_capacity(size),
+            key_value_list: IndexMap::with_capacity_and_hasher(size, FnvBuildHasher::default()),
         }
     }
 
@@ -65,6 +68,12 @@ impl<S> Object<S> {
     pub fn get_mut_field_value<K: AsRef<str>>(&mut self, key: K) -> Option<&mut Value<S>> {
         self.key_value_list.get_mut(key.as_ref())
     }
+
+    /// Remove a field, preserving the order of the remaining ones, and return
+    /// its value, if any
+    pub fn remove_field<K: AsRef<str>>(&mut self, key: K) -> Option<Value<S>> {
+        self.key_value_list.shift_remove(key.as_ref())
+    }
 }
 
 impl<S> IntoIterator for Object<S> {
@@ -92,7 +101,10 @@ where
     {
         let iter = iter.into_iter();
         let mut ret = Self {
-            key_value_list: IndexMap::with_capacity(iter.size_hint().0),
+            key_value_list: IndexMap::with_capacity_and_hasher(
+                iter.size_hint().0,
+                FnvBuildHasher::default(),
+            ),
         };
         for (k, v) in iter {
             ret.add_field(k, v);
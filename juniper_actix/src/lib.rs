@@ -4,48 +4,42 @@
 #![deny(warnings)]
 
 use actix_web::{
-    error::JsonPayloadError, http::Method, web, Error, FromRequest, HttpMessage, HttpRequest,
-    HttpResponse,
+    http::{header, Method, StatusCode},
+    web, Error, FromRequest, HttpRequest, HttpResponse,
 };
-use juniper::{
-    http::{
-        graphiql::graphiql_source, playground::playground_source, GraphQLBatchRequest,
-        GraphQLRequest,
-    },
-    ScalarValue,
-};
-use serde::Deserialize;
-
-#[derive(Deserialize, Clone, PartialEq, Debug)]
-#[serde(deny_unknown_fields)]
-struct GetGraphQLRequest {
-    query: String,
-    #[serde(rename = "operationName")]
-    operation_name: Option<String>,
-    variables: Option<String>,
-}
+use juniper::{http::GraphQLBatchRequest, CancellationToken, Correlation, ScalarValue};
+use juniper_http::{graphiql_source, playground_source, RequestError, JSON_CONTENT_TYPE};
 
-impl<S> From<GetGraphQLRequest> for GraphQLRequest<S>
+pub use juniper_http::BatchConfig;
+
+/// Actix Web GraphQL Handler for GET and POST requests
+pub async fn graphql_handler<Query, Mutation, Subscription, CtxT, S>(
+    schema: &juniper::RootNode<'static, Query, Mutation, Subscription, S>,
+    context: &CtxT,
+    req: HttpRequest,
+    payload: actix_web::web::Payload,
+) -> Result<HttpResponse, Error>
 where
-    S: ScalarValue,
+    Query: juniper::GraphQLTypeAsync<S, Context = CtxT>,
+    Query::TypeInfo: Sync,
+    Mutation: juniper::GraphQLTypeAsync<S, Context = CtxT>,
+    Mutation::TypeInfo: Sync,
+    Subscription: juniper::GraphQLSubscriptionType<S, Context = CtxT>,
+    Subscription::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
 {
-    fn from(get_req: GetGraphQLRequest) -> Self {
-        let GetGraphQLRequest {
-            query,
-            operation_name,
-            variables,
-        } = get_req;
-        let variables = variables.map(|s| serde_json::from_str(&s).unwrap());
-        Self::new(query, operation_name, variables)
-    }
+    graphql_handler_batched(schema, context, req, payload, &BatchConfig::default()).await
 }
 
-/// Actix Web GraphQL Handler for GET and POST requests
-pub async fn graphql_handler<Query, Mutation, Subscription, CtxT, S>(
+/// Same as [`graphql_handler()`], but handles batched requests according to the
+/// provided [`BatchConfig`].
+pub async fn graphql_handler_batched<Query, Mutation, Subscription, CtxT, S>(
     schema: &juniper::RootNode<'static, Query, Mutation, Subscription, S>,
     context: &CtxT,
     req: HttpRequest,
     payload: actix_web::web::Payload,
+    config: &BatchConfig,
 ) -> Result<HttpResponse, Error>
 where
     Query: juniper::GraphQLTypeAsync<S, Context = CtxT>,
@@ -58,11 +52,12 @@ where
     S: ScalarValue + Send + Sync,
 {
     match *req.method() {
-        Method::POST => post_graphql_handler(schema, context, req, payload).await,
+        Method::POST => post_graphql_handler_batched(schema, context, req, payload, config).await,
         Method::GET => get_graphql_handler(schema, context, req).await,
-        _ => Err(actix_web::error::UrlGenerationError::ResourceNotFound.into()),
+        _ => Ok(error_response(&RequestError::MethodNotAllowed)),
     }
 }
+
 /// Actix GraphQL Handler for GET requests
 pub async fn get_graphql_handler<Query, Mutation, Subscription, CtxT, S>(
     schema: &juniper::RootNode<'static, Query, Mutation, Subscription, S>,
@@ -79,17 +74,18 @@ where
     CtxT: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let get_req = web::Query::<GetGraphQLRequest>::from_query(req.query_string())?;
-    let req = GraphQLRequest::from(get_req.into_inner());
-    let gql_response = req.execute(schema, context).await;
-    let body_response = serde_json::to_string(&gql_response)?;
-    let mut response = match gql_response.is_ok() {
-        true => HttpResponse::Ok(),
-        false => HttpResponse::BadRequest(),
-    };
-    Ok(response
-        .content_type("application/json")
-        .body(body_response))
+    match juniper_http::parse_get(req.query_string()) {
+        Ok(req) => {
+            execute(
+                schema,
+                context,
+                GraphQLBatchRequest::Single(req),
+                &BatchConfig::default(),
+            )
+            .await
+        }
+        Err(e) => Ok(error_response(&e)),
+    }
 }
 
 /// Actix GraphQL Handler for POST requests
@@ -109,27 +105,84 @@ where
     CtxT: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let req = match req.content_type() {
-        "application/json" => {
-            let body = String::from_request(&req, &mut payload.into_inner()).await?;
-            serde_json::from_str::<GraphQLBatchRequest<S>>(&body)
-                .map_err(JsonPayloadError::Deserialize)
-        }
-        "application/graphql" => {
-            let body = String::from_request(&req, &mut payload.into_inner()).await?;
-            Ok(GraphQLBatchRequest::Single(GraphQLRequest::new(
-                body, None, None,
-            )))
-        }
-        _ => Err(JsonPayloadError::ContentType),
-    }?;
-    let gql_batch_response = req.execute(schema, context).await;
+    post_graphql_handler_batched(schema, context, req, payload, &BatchConfig::default()).await
+}
+
+/// Same as [`post_graphql_handler()`], but handles batched requests according
+/// to the provided [`BatchConfig`].
+pub async fn post_graphql_handler_batched<Query, Mutation, Subscription, CtxT, S>(
+    schema: &juniper::RootNode<'static, Query, Mutation, Subscription, S>,
+    context: &CtxT,
+    req: HttpRequest,
+    payload: actix_web::web::Payload,
+    config: &BatchConfig,
+) -> Result<HttpResponse, Error>
+where
+    Query: juniper::GraphQLTypeAsync<S, Context = CtxT>,
+    Query::TypeInfo: Sync,
+    Mutation: juniper::GraphQLTypeAsync<S, Context = CtxT>,
+    Mutation::TypeInfo: Sync,
+    Subscription: juniper::GraphQLSubscriptionType<S, Context = CtxT>,
+    Subscription::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let body = web::Bytes::from_request(&req, &mut payload.into_inner()).await?;
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok());
+    match juniper_http::parse_post(content_type, &body)
+        .and_then(|gql_req| config.check(&gql_req).map(|()| gql_req))
+    {
+        Ok(gql_req) => execute(schema, context, gql_req, config).await,
+        Err(e) => Ok(error_response(&e)),
+    }
+}
+
+/// Executes the provided [`GraphQLBatchRequest`] according to the provided
+/// [`BatchConfig`], and responds with its result.
+async fn execute<Query, Mutation, Subscription, CtxT, S>(
+    schema: &juniper::RootNode<'static, Query, Mutation, Subscription, S>,
+    context: &CtxT,
+    req: GraphQLBatchRequest<S>,
+    config: &BatchConfig,
+) -> Result<HttpResponse, Error>
+where
+    Query: juniper::GraphQLTypeAsync<S, Context = CtxT>,
+    Query::TypeInfo: Sync,
+    Mutation: juniper::GraphQLTypeAsync<S, Context = CtxT>,
+    Mutation::TypeInfo: Sync,
+    Subscription: juniper::GraphQLSubscriptionType<S, Context = CtxT>,
+    Subscription::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let cancellation = CancellationToken::new().cancel_on_drop();
+    let gql_batch_response = config
+        .execute(
+            &req,
+            schema,
+            context,
+            &Correlation::default(),
+            cancellation.token(),
+        )
+        .await;
+    cancellation.disarm();
     let gql_response = serde_json::to_string(&gql_batch_response)?;
-    let mut response = match gql_batch_response.is_ok() {
-        true => HttpResponse::Ok(),
-        false => HttpResponse::BadRequest(),
-    };
-    Ok(response.content_type("application/json").body(gql_response))
+    let status = StatusCode::from_u16(juniper_http::response_status(&gql_batch_response))
+        .unwrap_or(StatusCode::BAD_REQUEST);
+    Ok(HttpResponse::build(status)
+        .content_type(JSON_CONTENT_TYPE)
+        .body(gql_response))
+}
+
+/// Responds with the provided [`RequestError`] of parsing a GraphQL request.
+fn error_response(err: &RequestError) -> HttpResponse {
+    let status = StatusCode::from_u16(err.status_code()).unwrap_or(StatusCode::BAD_REQUEST);
+    HttpResponse::build(status)
+        .content_type("text/plain; charset=utf-8")
+        .body(err.to_string())
 }
 
 /// Create a handler that replies with an HTML page containing GraphiQL. This does not handle routing, so you can mount it on any endpoint
@@ -671,6 +724,75 @@ mod tests {
         );
     }
 
+    #[actix_web::rt::test]
+    async fn batch_request_size_is_limited() {
+        async fn limited_index(
+            req: HttpRequest,
+            payload: actix_web::web::Payload,
+            schema: web::Data<Schema>,
+        ) -> Result<HttpResponse, Error> {
+            let context = Database::new();
+            let config = BatchConfig::new().max_size(1);
+            post_graphql_handler_batched(&schema, &context, req, payload, &config).await
+        }
+
+        let schema: Schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+
+        let req = TestRequest::post()
+            .append_header(("content-type", "application/json"))
+            .set_payload(
+                r##"[
+                     { "query": "{ hero(episode: NEW_HOPE) { name } }" },
+                     { "query": "{ hero(episode: EMPIRE) { id name } }" }
+                 ]"##,
+            )
+            .uri("/")
+            .to_request();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(schema))
+                .route("/", web::post().to(limited_index)),
+        )
+        .await;
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            take_response_body_string(resp).await,
+            "batch of 2 operations exceeds the maximum of 1",
+        );
+    }
+
+    #[actix_web::rt::test]
+    async fn graphql_get_rejects_invalid_variables() {
+        let schema: Schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+
+        let req = TestRequest::get()
+            .uri("/?query=%7B%20hero%20%7B%20name%20%7D%20%7D&variables=NOT_JSON")
+            .to_request();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(schema))
+                .route("/", web::get().to(index)),
+        )
+        .await;
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
     #[test]
     fn batch_request_deserialization_can_fail() {
         let json = r#"blah"#;
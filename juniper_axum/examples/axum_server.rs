@@ -0,0 +1,96 @@
+//! This example demonstrates simple default integration with [`axum`].
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::{
+    response::Html,
+    routing::{get, on, MethodFilter},
+    Extension, Router,
+};
+use futures::stream::{BoxStream, StreamExt as _};
+use juniper::{
+    graphql_object, graphql_subscription, DefaultScalarValue, EmptyMutation, FieldError, RootNode,
+};
+use juniper_axum::{graphiql, graphql, playground, subscriptions::graphql_ws};
+use tokio::time::interval;
+use tokio_stream::wrappers::IntervalStream;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Context;
+
+impl juniper::Context for Context {}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    /// Adds two `a` and `b` numbers.
+    fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Subscription;
+
+type NumberStream = BoxStream<'static, Result<i32, FieldError>>;
+
+#[graphql_subscription(context = Context)]
+impl Subscription {
+    /// Counts seconds.
+    async fn count() -> NumberStream {
+        let mut value = 0;
+        let stream = IntervalStream::new(interval(Duration::from_secs(1))).map(move |_| {
+            value += 1;
+            Ok(value)
+        });
+        Box::pin(stream)
+    }
+}
+
+type Schema = RootNode<'static, Query, EmptyMutation<Context>, Subscription>;
+
+async fn homepage() -> Html<&'static str> {
+    "<html><h1>juniper_axum/simple example</h1>\
+           <div>visit <a href=\"/graphiql\">GraphiQL</a></div>\
+           <div>visit <a href=\"/playground\">GraphQL Playground</a></div>\
+    </html>"
+        .into()
+}
+
+#[tokio::main]
+async fn main() {
+    let schema = Schema::new(Query, EmptyMutation::new(), Subscription);
+
+    let app = Router::new()
+        .route(
+            "/graphql",
+            on(
+                MethodFilter::GET | MethodFilter::POST,
+                graphql::<Query, EmptyMutation<Context>, Subscription, Context, DefaultScalarValue>,
+            ),
+        )
+        .route(
+            "/subscriptions",
+            get(graphql_ws::<
+                Query,
+                EmptyMutation<Context>,
+                Subscription,
+                Context,
+                DefaultScalarValue,
+            >),
+        )
+        .route("/graphiql", get(graphiql("/graphql", "/subscriptions")))
+        .route("/playground", get(playground("/graphql", "/subscriptions")))
+        .route("/", get(homepage))
+        .layer(Extension(Arc::new(schema)))
+        .layer(Extension(Context));
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], 8080));
+    println!("Listening on http://{addr}");
+    axum::Server::bind(&addr)
+        .serve(app.into_make_service())
+        .await
+        .unwrap_or_else(|e| panic!("failed to run `axum::Server`: {e}"));
+}
@@ -0,0 +1,209 @@
+//! Types and traits for extracting data from [`Request`]s.
+
+use std::mem;
+
+use axum::{
+    async_trait,
+    body::{Bytes, HttpBody},
+    extract::FromRequest,
+    http::{Method, Request, StatusCode},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use juniper::{http::GraphQLBatchRequest, DefaultScalarValue, ScalarValue};
+
+/// Extractor for [`axum`] to extract a [`JuniperRequest`].
+///
+/// Handles the requests described in [`juniper_http::parse_request()`]. Any
+/// other request is rejected with an appropriate HTTP status code.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use axum::{routing::post, Extension, Router};
+/// use juniper::{
+///     graphql_object, EmptyMutation, EmptySubscription, RootNode,
+/// };
+/// use juniper_axum::{extract::JuniperRequest, response::JuniperResponse};
+///
+/// #[derive(Clone, Copy, Debug)]
+/// pub struct Context;
+///
+/// impl juniper::Context for Context {}
+///
+/// #[derive(Clone, Copy, Debug)]
+/// pub struct Query;
+///
+/// #[graphql_object(context = Context)]
+/// impl Query {
+///     fn add(a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+/// }
+///
+/// type Schema = RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+///
+/// let schema = Schema::new(
+///    Query,
+///    EmptyMutation::<Context>::new(),
+///    EmptySubscription::<Context>::new()
+/// );
+///
+/// let app: Router = Router::new()
+///     .route("/graphql", post(graphql))
+///     .layer(Extension(Arc::new(schema)))
+///     .layer(Extension(Context));
+///
+/// async fn graphql(
+///     Extension(schema): Extension<Arc<Schema>>,
+///     Extension(context): Extension<Context>,
+///     JuniperRequest(req): JuniperRequest, // should be the last argument as consumes `Request`
+/// ) -> JuniperResponse {
+///     JuniperResponse(req.execute(&*schema, &context).await)
+/// }
+/// ```
+///
+/// [GraphQL]: https://graphql.org
+#[derive(Debug, PartialEq)]
+pub struct JuniperRequest<S = DefaultScalarValue>(pub GraphQLBatchRequest<S>)
+where
+    S: ScalarValue;
+
+#[async_trait]
+impl<S, State, Body> FromRequest<State, Body> for JuniperRequest<S>
+where
+    S: ScalarValue,
+    State: Send + Sync,
+    Body: HttpBody + Send + 'static,
+    Body::Data: Send,
+    Body::Error: Into<BoxError>,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request<Body>, state: &State) -> Result<Self, Self::Rejection> {
+        let (mut parts, body) = req.into_parts();
+
+        // `GET` requests are described by their query string only, so there is
+        // no need to read their body.
+        let body = if parts.method == Method::GET {
+            Bytes::new()
+        } else {
+            // Extensions are kept, as they may configure the body limit.
+            let mut body_req = Request::new(body);
+            *body_req.extensions_mut() = mem::take(&mut parts.extensions);
+            Bytes::from_request(body_req, state)
+                .await
+                .map_err(IntoResponse::into_response)?
+        };
+
+        juniper_http::parse_request(&parts, &body)
+            .map(Self)
+            .map_err(|e| {
+                let status =
+                    StatusCode::from_u16(e.status_code()).unwrap_or(StatusCode::BAD_REQUEST);
+                (status, e.to_string()).into_response()
+            })
+    }
+}
+
+#[cfg(test)]
+mod juniper_request_tests {
+    use axum::{body::Body, extract::FromRequest as _, http::Request};
+    use juniper::{
+        graphql_input_value,
+        http::{GraphQLBatchRequest, GraphQLRequest},
+    };
+
+    use super::JuniperRequest;
+
+    #[tokio::test]
+    async fn from_get_request() {
+        let req = Request::get("/?query=%7B%20add%28a%3A%202%2C%20b%3A%203%29%20%7D")
+            .body(Body::empty())
+            .unwrap_or_else(|e| panic!("cannot build `Request`: {e}"));
+
+        let expected = JuniperRequest(GraphQLBatchRequest::Single(GraphQLRequest::new(
+            "{ add(a: 2, b: 3) }".into(),
+            None,
+            None,
+        )));
+
+        assert_eq!(do_from_request(req).await, expected);
+    }
+
+    #[tokio::test]
+    async fn from_get_request_with_variables() {
+        let req = Request::get(
+            "/?query=query%28%24id%3A%20String%21%29%20%7B%20human%28id%3A%20%24id%29%20%7B%20id\
+             %20%7D%20%7D&variables=%7B%22id%22%3A%20%221000%22%7D",
+        )
+        .body(Body::empty())
+        .unwrap_or_else(|e| panic!("cannot build `Request`: {e}"));
+
+        let expected = JuniperRequest(GraphQLBatchRequest::Single(GraphQLRequest::new(
+            "query($id: String!) { human(id: $id) { id } }".into(),
+            None,
+            Some(graphql_input_value!({"id": "1000"})),
+        )));
+
+        assert_eq!(do_from_request(req).await, expected);
+    }
+
+    #[tokio::test]
+    async fn from_json_post_request() {
+        let req = Request::post("/")
+            .header("content-type", "application/json; charset=utf-8")
+            .body(Body::from(r#"{"query": "{ add(a: 2, b: 3) }"}"#))
+            .unwrap_or_else(|e| panic!("cannot build `Request`: {e}"));
+
+        let expected = JuniperRequest(GraphQLBatchRequest::Single(GraphQLRequest::new(
+            "{ add(a: 2, b: 3) }".into(),
+            None,
+            None,
+        )));
+
+        assert_eq!(do_from_request(req).await, expected);
+    }
+
+    #[tokio::test]
+    async fn from_graphql_post_request() {
+        let req = Request::post("/")
+            .header("content-type", "application/graphql")
+            .body(Body::from(r#"{ add(a: 2, b: 3) }"#))
+            .unwrap_or_else(|e| panic!("cannot build `Request`: {e}"));
+
+        let expected = JuniperRequest(GraphQLBatchRequest::Single(GraphQLRequest::new(
+            "{ add(a: 2, b: 3) }".into(),
+            None,
+            None,
+        )));
+
+        assert_eq!(do_from_request(req).await, expected);
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_content_type() {
+        let req = Request::post("/")
+            .header("content-type", "text/plain")
+            .body(Body::from(r#"{ add(a: 2, b: 3) }"#))
+            .unwrap_or_else(|e| panic!("cannot build `Request`: {e}"));
+
+        let rejection = JuniperRequest::<juniper::DefaultScalarValue>::from_request(req, &())
+            .await
+            .expect_err("`JuniperRequest` should be rejected");
+
+        assert_eq!(rejection.status(), 415);
+    }
+
+    async fn do_from_request(req: Request<Body>) -> JuniperRequest {
+        match JuniperRequest::from_request(req, &()).await {
+            Ok(resp) => resp,
+            Err(resp) => panic!(
+                "`JuniperRequest::from_request()` failed with `{}` status code",
+                resp.status(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,336 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+pub mod extract;
+pub mod response;
+#[cfg(feature = "subscriptions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subscriptions")))]
+pub mod subscriptions;
+
+use std::{future, sync::Arc};
+
+use axum::{
+    http::StatusCode,
+    response::{Html, IntoResponse as _, Response},
+    Extension,
+};
+use juniper::{
+    CancellationToken, Correlation, GraphQLSubscriptionType, GraphQLTypeAsync, RootNode,
+    ScalarValue,
+};
+use juniper_http::{graphiql_source, playground_source};
+
+pub use juniper_http::BatchConfig;
+
+use self::{extract::JuniperRequest, response::JuniperResponse};
+
+/// Handles a [`JuniperRequest`] (either a single or a batched one), executing
+/// it against the schema and with the context, provided via [`Extension`]s.
+///
+/// Batched requests are handled according to the [`BatchConfig`] provided via
+/// an [`Extension`], if any.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use axum::{routing::on, routing::MethodFilter, Extension, Router};
+/// use juniper::{
+///     graphql_object, DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode,
+/// };
+/// use juniper_axum::graphql;
+///
+/// #[derive(Clone, Copy, Debug)]
+/// pub struct Context;
+///
+/// impl juniper::Context for Context {}
+///
+/// #[derive(Clone, Copy, Debug)]
+/// pub struct Query;
+///
+/// #[graphql_object(context = Context)]
+/// impl Query {
+///     fn add(a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+/// }
+///
+/// type Schema = RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+///
+/// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+///
+/// let app: Router = Router::new()
+///     .route(
+///         "/graphql",
+///         on(
+///             MethodFilter::GET | MethodFilter::POST,
+///             graphql::<
+///                 Query,
+///                 EmptyMutation<Context>,
+///                 EmptySubscription<Context>,
+///                 Context,
+///                 DefaultScalarValue,
+///             >,
+///         ),
+///     )
+///     .layer(Extension(Arc::new(schema)))
+///     .layer(Extension(Context));
+/// ```
+pub async fn graphql<Query, Mutation, Subscription, CtxT, S>(
+    Extension(root_node): Extension<Arc<RootNode<'static, Query, Mutation, Subscription, S>>>,
+    Extension(context): Extension<CtxT>,
+    batch_config: Option<Extension<BatchConfig>>,
+    JuniperRequest(req): JuniperRequest<S>,
+) -> Result<JuniperResponse<S>, Response>
+where
+    Query: GraphQLTypeAsync<S, Context = CtxT>,
+    Query::TypeInfo: Sync,
+    Mutation: GraphQLTypeAsync<S, Context = CtxT>,
+    Mutation::TypeInfo: Sync,
+    Subscription: GraphQLSubscriptionType<S, Context = CtxT>,
+    Subscription::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let config = batch_config.map(|Extension(c)| c).unwrap_or_default();
+    config
+        .check(&req)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()).into_response())?;
+
+    let cancellation = CancellationToken::new().cancel_on_drop();
+    let resp = config
+        .execute(
+            &req,
+            &root_node,
+            &context,
+            &Correlation::default(),
+            cancellation.token(),
+        )
+        .await;
+    cancellation.disarm();
+
+    Ok(JuniperResponse(resp))
+}
+
+/// Creates a handler that replies with an HTML page containing [GraphiQL].
+///
+/// This does not handle routing, so you can mount it on any endpoint.
+///
+/// # Example
+///
+/// ```rust
+/// use axum::{routing::get, Router};
+/// use juniper_axum::graphiql;
+///
+/// let app: Router = Router::new()
+///     .route("/", get(graphiql("/graphql", "/subscriptions")));
+/// ```
+///
+/// [GraphiQL]: https://github.com/graphql/graphiql
+pub fn graphiql<'a>(
+    graphql_endpoint_path: &str,
+    subscriptions_endpoint_path: impl Into<Option<&'a str>>,
+) -> impl FnOnce() -> future::Ready<Html<String>> + Clone + Send {
+    let html = Html(graphiql_source(
+        graphql_endpoint_path,
+        subscriptions_endpoint_path.into(),
+    ));
+
+    || future::ready(html)
+}
+
+/// Creates a handler that replies with an HTML page containing
+/// [GraphQL Playground].
+///
+/// This does not handle routing, so you can mount it on any endpoint.
+///
+/// # Example
+///
+/// ```rust
+/// use axum::{routing::get, Router};
+/// use juniper_axum::playground;
+///
+/// let app: Router = Router::new()
+///     .route("/", get(playground("/graphql", "/subscriptions")));
+/// ```
+///
+/// [GraphQL Playground]: https://github.com/prisma/graphql-playground
+pub fn playground<'a>(
+    graphql_endpoint_path: &str,
+    subscriptions_endpoint_path: impl Into<Option<&'a str>>,
+) -> impl FnOnce() -> future::Ready<Html<String>> + Clone + Send {
+    let html = Html(playground_source(
+        graphql_endpoint_path,
+        subscriptions_endpoint_path.into(),
+    ));
+
+    || future::ready(html)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+        routing::{get, on, MethodFilter},
+        Extension, Router,
+    };
+    use juniper::{
+        http::tests::{run_http_test_suite, HttpIntegration, TestResponse},
+        tests::fixtures::starwars::schema::{Database, Query},
+        DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode,
+    };
+    use tower::ServiceExt as _;
+
+    use super::{graphiql, graphql, playground, BatchConfig};
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>>;
+
+    fn app() -> Router {
+        app_with(BatchConfig::default())
+    }
+
+    fn app_with(batch_config: BatchConfig) -> Router {
+        let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+
+        Router::new()
+            .route(
+                "/",
+                on(
+                    MethodFilter::GET | MethodFilter::POST,
+                    graphql::<
+                        Query,
+                        EmptyMutation<Database>,
+                        EmptySubscription<Database>,
+                        Database,
+                        DefaultScalarValue,
+                    >,
+                ),
+            )
+            .route("/graphiql", get(graphiql("/dogs-api/graphql", None)))
+            .route(
+                "/playground",
+                get(playground("/dogs-api/graphql", "/dogs-api/subscriptions")),
+            )
+            .layer(Extension(Arc::new(schema)))
+            .layer(Extension(Database::new()))
+            .layer(Extension(batch_config))
+    }
+
+    async fn request(req: Request<Body>) -> TestResponse {
+        request_to(app(), req).await
+    }
+
+    async fn request_to(app: Router, req: Request<Body>) -> TestResponse {
+        let resp = app
+            .oneshot(req)
+            .await
+            .unwrap_or_else(|e| panic!("`Router` failed: {e}"));
+
+        let status_code = resp.status().as_u16().into();
+        let content_type = resp
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .map(|v| v.to_str().unwrap().to_owned())
+            .unwrap_or_default();
+        let body = hyper::body::to_bytes(resp.into_body())
+            .await
+            .unwrap_or_else(|e| panic!("cannot read response body: {e}"));
+
+        TestResponse {
+            status_code,
+            body: Some(String::from_utf8(body.to_vec()).unwrap()),
+            content_type,
+        }
+    }
+
+    struct TestAxumIntegration;
+
+    impl TestAxumIntegration {
+        fn make_request(&self, req: Request<Body>) -> TestResponse {
+            tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(request(req))
+        }
+    }
+
+    impl HttpIntegration for TestAxumIntegration {
+        fn get(&self, url: &str) -> TestResponse {
+            self.make_request(Request::get(url).body(Body::empty()).unwrap())
+        }
+
+        fn post_json(&self, url: &str, body: &str) -> TestResponse {
+            self.make_request(
+                Request::post(url)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.to_owned()))
+                    .unwrap(),
+            )
+        }
+
+        fn post_graphql(&self, url: &str, body: &str) -> TestResponse {
+            self.make_request(
+                Request::post(url)
+                    .header(header::CONTENT_TYPE, "application/graphql")
+                    .body(Body::from(body.to_owned()))
+                    .unwrap(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_axum_integration() {
+        run_http_test_suite(&TestAxumIntegration);
+    }
+
+    #[tokio::test]
+    async fn rejects_too_large_batches() {
+        let req = || {
+            Request::post("/")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(
+                    r#"[{"query": "{ hero { name } }"}, {"query": "{ hero { id } }"}]"#,
+                ))
+                .unwrap()
+        };
+
+        let resp = request_to(app_with(BatchConfig::new().max_size(2)), req()).await;
+        assert_eq!(resp.status_code, 200);
+
+        let resp = request_to(app_with(BatchConfig::new().max_size(1)), req()).await;
+        assert_eq!(resp.status_code, 400);
+        assert_eq!(
+            resp.body.as_deref(),
+            Some("batch of 2 operations exceeds the maximum of 1"),
+        );
+    }
+
+    #[tokio::test]
+    async fn graphiql_endpoint_returns_graphiql_source() {
+        let resp = request(Request::get("/graphiql").body(Body::empty()).unwrap()).await;
+
+        assert_eq!(resp.status_code, i32::from(StatusCode::OK.as_u16()));
+        assert_eq!(resp.content_type, "text/html; charset=utf-8");
+        assert!(resp
+            .body
+            .unwrap()
+            .contains("<script>var GRAPHQL_URL = '/dogs-api/graphql';</script>"));
+    }
+
+    #[tokio::test]
+    async fn playground_endpoint_returns_playground_source() {
+        let resp = request(Request::get("/playground").body(Body::empty()).unwrap()).await;
+
+        assert_eq!(resp.status_code, i32::from(StatusCode::OK.as_u16()));
+        assert_eq!(resp.content_type, "text/html; charset=utf-8");
+        assert!(resp.body.unwrap().contains(
+            "GraphQLPlayground.init(root, { endpoint: '/dogs-api/graphql', \
+             subscriptionEndpoint: '/dogs-api/subscriptions' })",
+        ));
+    }
+}
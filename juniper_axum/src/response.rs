@@ -0,0 +1,25 @@
+//! [`JuniperResponse`] definition.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use juniper::{http::GraphQLBatchResponse, DefaultScalarValue, ScalarValue};
+
+/// Wrapper around a [`GraphQLBatchResponse`], implementing [`IntoResponse`], so
+/// it can be returned from [`axum`] handlers.
+///
+/// Responds with a JSON body and `200 OK` status code, if all the executed
+/// operations succeeded, or `400 Bad Request` status code otherwise.
+pub struct JuniperResponse<S = DefaultScalarValue>(pub GraphQLBatchResponse<S>)
+where
+    S: ScalarValue;
+
+impl<S: ScalarValue> IntoResponse for JuniperResponse<S> {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(juniper_http::response_status(&self.0))
+            .unwrap_or(StatusCode::BAD_REQUEST);
+        (status, Json(self.0)).into_response()
+    }
+}
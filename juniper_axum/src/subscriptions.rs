@@ -0,0 +1,206 @@
+//! Definitions for handling GraphQL subscriptions over WebSocket connections.
+
+use std::{convert::Infallible, fmt, sync::Arc};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Extension,
+    },
+    response::Response,
+};
+use futures::{
+    future::{self, Either},
+    SinkExt as _, StreamExt as _, TryStreamExt as _,
+};
+use juniper::{GraphQLSubscriptionType, GraphQLTypeAsync, RootNode, ScalarValue};
+use juniper_graphql_ws::{ArcSchema, ClientMessage, Connection, ConnectionConfig, Init};
+
+/// Name of the [`graphql-ws` protocol][1], used to negotiate it as a WebSocket
+/// subprotocol.
+///
+/// [1]: https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md
+const GRAPHQL_WS_PROTOCOL: &str = "graphql-ws";
+
+/// Handles a WebSocket upgrade request, serving the [`graphql-ws` protocol][1]
+/// over the upgraded connection.
+///
+/// The schema and the context to resolve subscriptions with are provided via
+/// [`Extension`]s. Use [`serve_ws()`] directly for more control (like
+/// authenticating the connection with the parameters provided by the client).
+///
+/// # Example
+///
+/// ```rust
+/// use std::{pin::Pin, sync::Arc};
+///
+/// use axum::{routing::get, Extension, Router};
+/// use futures::{stream, Stream};
+/// use juniper::{
+///     graphql_object, graphql_subscription, DefaultScalarValue, EmptyMutation, FieldError,
+///     RootNode,
+/// };
+/// use juniper_axum::{playground, subscriptions::graphql_ws};
+///
+/// #[derive(Clone)]
+/// pub struct Context;
+///
+/// impl juniper::Context for Context {}
+///
+/// #[derive(Clone, Copy, Debug)]
+/// pub struct Query;
+///
+/// #[graphql_object(context = Context)]
+/// impl Query {
+///     fn add(a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+/// }
+///
+/// #[derive(Clone, Copy, Debug)]
+/// pub struct Subscription;
+///
+/// type NumberStream = Pin<Box<dyn Stream<Item = Result<i32, FieldError>> + Send>>;
+///
+/// #[graphql_subscription(context = Context)]
+/// impl Subscription {
+///     /// Counts to three.
+///     async fn count() -> NumberStream {
+///         Box::pin(stream::iter(vec![Ok(1), Ok(2), Ok(3)]))
+///     }
+/// }
+///
+/// type Schema = RootNode<'static, Query, EmptyMutation<Context>, Subscription>;
+///
+/// let schema = Schema::new(Query, EmptyMutation::new(), Subscription);
+///
+/// let app: Router = Router::new()
+///     .route(
+///         "/subscriptions",
+///         get(graphql_ws::<Query, EmptyMutation<Context>, Subscription, Context, DefaultScalarValue>),
+///     )
+///     .route("/playground", get(playground("/graphql", "/subscriptions")))
+///     .layer(Extension(Arc::new(schema)))
+///     .layer(Extension(Context));
+/// ```
+///
+/// [1]: https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md
+pub async fn graphql_ws<Query, Mutation, Subscription, CtxT, S>(
+    ws: WebSocketUpgrade,
+    Extension(root_node): Extension<Arc<RootNode<'static, Query, Mutation, Subscription, S>>>,
+    Extension(context): Extension<CtxT>,
+) -> Response
+where
+    Query: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Query::TypeInfo: Send + Sync,
+    Mutation: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Mutation::TypeInfo: Send + Sync,
+    Subscription: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    Subscription::TypeInfo: Send + Sync,
+    CtxT: Clone + Unpin + Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    ws.protocols([GRAPHQL_WS_PROTOCOL])
+        .on_upgrade(move |socket| async move {
+            // Errors are not recoverable at this point, as the connection is
+            // already gone, so there is nothing left to do with them.
+            let _ = serve_ws(socket, root_node, ConnectionConfig::new(context)).await;
+        })
+}
+
+/// Serves the [`graphql-ws` protocol][1] over the provided [`WebSocket`]
+/// connection.
+///
+/// The `init` argument is used to provide the context and additional
+/// configuration for connections. This can be a [`ConnectionConfig`] if the
+/// context and configuration are already known, or it can be a closure that
+/// gets executed asynchronously when the client sends the `ConnectionInit`
+/// message. Using a closure allows to perform an authentication based on the
+/// parameters provided by the client.
+///
+/// [1]: https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md
+pub async fn serve_ws<Query, Mutation, Subscription, CtxT, S, I>(
+    socket: WebSocket,
+    root_node: Arc<RootNode<'static, Query, Mutation, Subscription, S>>,
+    init: I,
+) -> Result<(), Error>
+where
+    Query: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Query::TypeInfo: Send + Sync,
+    Mutation: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Mutation::TypeInfo: Send + Sync,
+    Subscription: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    Subscription::TypeInfo: Send + Sync,
+    CtxT: Unpin + Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+    I: Init<S, CtxT> + Send,
+{
+    let (ws_tx, ws_rx) = socket.split();
+    let (s_tx, s_rx) = Connection::new(ArcSchema(root_node), init).split();
+
+    let ws_rx = ws_rx
+        .map_err(Error::Axum)
+        // Pings and pongs are handled by `axum` automatically.
+        .try_filter(|msg| future::ready(!matches!(msg, Message::Ping(_) | Message::Pong(_))))
+        .map_ok(IncomingMessage);
+    let s_rx = s_rx.map(|msg| {
+        serde_json::to_string(&msg)
+            .map(Message::Text)
+            .map_err(Error::Serde)
+    });
+
+    match future::select(
+        ws_rx.forward(s_tx.sink_err_into()),
+        s_rx.forward(ws_tx.sink_map_err(Error::Axum)),
+    )
+    .await
+    {
+        Either::Left((r, _)) | Either::Right((r, _)) => r,
+    }
+}
+
+/// Incoming [`WebSocket`] [`Message`] to be converted into a [`ClientMessage`].
+struct IncomingMessage(Message);
+
+impl<S: ScalarValue> TryFrom<IncomingMessage> for ClientMessage<S> {
+    type Error = serde_json::Error;
+
+    fn try_from(msg: IncomingMessage) -> serde_json::Result<Self> {
+        match msg.0 {
+            Message::Text(text) => serde_json::from_str(&text),
+            Message::Binary(bytes) => serde_json::from_slice(&bytes),
+            Message::Close(_) => Ok(Self::ConnectionTerminate),
+            // Filtered out in the `serve_ws()` already.
+            Message::Ping(_) | Message::Pong(_) => unreachable!(),
+        }
+    }
+}
+
+/// Errors that can happen while serving a connection.
+#[derive(Debug)]
+pub enum Error {
+    /// Errors that can happen in [`axum`] while serving a connection.
+    Axum(axum::Error),
+
+    /// Errors that can happen while serializing outgoing messages. Note that
+    /// errors that occur while deserializing incoming messages are handled
+    /// internally by the protocol.
+    Serde(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Axum(e) => write!(f, "`axum` error: {e}"),
+            Self::Serde(e) => write!(f, "`serde` error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Infallible> for Error {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
@@ -0,0 +1,5 @@
+fn main() {
+    // The crate-wide default renaming policy is read via `option_env!` in
+    // `common::rename`, so rebuild whenever it changes.
+    println!("cargo:rerun-if-env-changed=JUNIPER_RENAME_ALL");
+}
@@ -0,0 +1,131 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of [GraphQL directives][0] applied to schema elements.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+
+use proc_macro2::TokenStream;
+use quote::{quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::common::parse::ParseBufferExt as _;
+
+/// [GraphQL directives][0] applied to a schema element, defined via
+/// `#[graphql(directives(name(arg = value, ...), ...))]` attribute.
+///
+/// Generates a [`directive()`][1] call for each directive, so it's exposed in
+/// the schema language representation of the schema.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+/// [1]: juniper::meta::Field::directive
+#[derive(Debug, Default)]
+pub(crate) struct Directives(Vec<Directive>);
+
+/// Single [GraphQL directive][0] applied to a schema element.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+#[derive(Debug)]
+struct Directive {
+    /// Name of this [`Directive`], without the leading `@`.
+    name: syn::Ident,
+
+    /// Arguments this [`Directive`] is applied with, in their order.
+    arguments: Vec<DirectiveArgument>,
+}
+
+/// Single argument of a [`Directive`].
+#[derive(Debug)]
+struct DirectiveArgument {
+    /// Name of this [`DirectiveArgument`].
+    name: syn::Ident,
+
+    /// Expression of the value of this [`DirectiveArgument`], converted into
+    /// an [`InputValue`] via [`ToInputValue`].
+    ///
+    /// [`InputValue`]: juniper::InputValue
+    /// [`ToInputValue`]: juniper::ToInputValue
+    value: syn::Expr,
+}
+
+impl Parse for DirectiveArgument {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name = input.parse_any_ident()?;
+        input.parse::<token::Eq>()?;
+        let value = input.parse::<syn::Expr>()?;
+        Ok(Self { name, value })
+    }
+}
+
+impl Parse for Directive {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name = input.parse_any_ident()?;
+        let mut arguments = Vec::<DirectiveArgument>::new();
+        if input.is_next::<token::Paren>() {
+            let inner;
+            let _ = syn::parenthesized!(inner in input);
+            for arg in Punctuated::<DirectiveArgument, token::Comma>::parse_terminated(&inner)? {
+                if arguments.iter().any(|a| a.name == arg.name) {
+                    return Err(syn::Error::new(
+                        arg.name.span(),
+                        "duplicated directive argument",
+                    ));
+                }
+                arguments.push(arg);
+            }
+        }
+        Ok(Self { name, arguments })
+    }
+}
+
+impl Parse for Directives {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        Ok(Self(
+            Punctuated::<Directive, token::Comma>::parse_terminated(&inner)?
+                .into_iter()
+                .collect(),
+        ))
+    }
+}
+
+impl Directives {
+    /// Returns the [`Span`] of these [`Directives`].
+    ///
+    /// [`Span`]: proc_macro2::Span
+    pub(crate) fn span(&self) -> proc_macro2::Span {
+        self.0
+            .first()
+            .map_or_else(proc_macro2::Span::call_site, |d| d.name.span())
+    }
+}
+
+impl ToTokens for Directives {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        for Directive { name, arguments } in &self.0 {
+            let name_str = name.to_string();
+            let arguments = arguments.iter().map(|DirectiveArgument { name, value }| {
+                let name = name.to_string();
+                quote_spanned! { value.span() =>
+                    .argument(
+                        #name,
+                        ::juniper::ToInputValue::<::juniper::DefaultScalarValue>::to_input_value(
+                            &(#value),
+                        ),
+                    )
+                }
+            });
+            quote_spanned! { name.span() =>
+                .directive(
+                    ::juniper::meta::SchemaDirective::new(#name_str)
+                        #( #arguments )*
+                )
+            }
+            .to_tokens(into);
+        }
+    }
+}
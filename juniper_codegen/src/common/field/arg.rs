@@ -5,17 +5,18 @@
 
 use std::mem;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
 use syn::{
     ext::IdentExt as _,
     parse::{Parse, ParseStream},
+    parse_quote,
     spanned::Spanned,
     token,
 };
 
 use crate::common::{
-    default, diagnostic, filter_attrs,
+    default, deprecation, diagnostic, filter_attrs,
     parse::{
         attr::{err, OptionExt as _},
         ParseBufferExt as _, TypeExt as _,
@@ -37,6 +38,14 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
     pub(crate) name: Option<SpanContainer<syn::LitStr>>,
 
+    /// Explicitly specified [`rename::Policy`] for the name of a
+    /// [GraphQL argument][1] represented by this method argument.
+    ///
+    /// If [`None`], then the [`rename::Policy`] of the whole field is used.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) rename: Option<SpanContainer<rename::Policy>>,
+
     /// Explicitly specified [description][2] of this [GraphQL argument][1].
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
@@ -52,6 +61,12 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Required-Arguments
     pub(crate) default: Option<SpanContainer<default::Value>>,
 
+    /// Explicitly specified [deprecation][2] of this [GraphQL argument][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
+    pub(crate) deprecated: Option<SpanContainer<deprecation::Directive>>,
+
     /// Explicitly specified marker indicating that this method argument doesn't
     /// represent a [GraphQL argument][1], but is a [`Context`] being injected
     /// into a [GraphQL field][2] resolving function.
@@ -90,6 +105,17 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(name.span()), name))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "rename" => {
+                    input.parse::<token::Eq>()?;
+                    let val = input.parse::<syn::LitStr>()?;
+                    out.rename
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(val.span()),
+                            val.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "desc" | "description" => {
                     input.parse::<token::Eq>()?;
                     let desc = input.parse::<Description>()?;
@@ -103,6 +129,16 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(val.span()), val))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "deprecated" => {
+                    let directive = input.parse::<deprecation::Directive>()?;
+                    out.deprecated
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            directive.reason.as_ref().map(|r| r.span()),
+                            directive,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     let span = ident.span();
                     out.context
@@ -131,8 +167,10 @@ impl Attr {
     fn try_merge(self, mut another: Self) -> syn::Result<Self> {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
+            rename: try_merge_opt!(rename: self, another),
             description: try_merge_opt!(description: self, another),
             default: try_merge_opt!(default: self, another),
+            deprecated: try_merge_opt!(deprecated: self, another),
             context: try_merge_opt!(context: self, another),
             executor: try_merge_opt!(executor: self, another),
         })
@@ -147,8 +185,10 @@ impl Attr {
 
         if let Some(context) = &attr.context {
             if attr.name.is_some()
+                || attr.rename.is_some()
                 || attr.description.is_some()
                 || attr.default.is_some()
+                || attr.deprecated.is_some()
                 || attr.executor.is_some()
             {
                 return Err(syn::Error::new(
@@ -160,8 +200,10 @@ impl Attr {
 
         if let Some(executor) = &attr.executor {
             if attr.name.is_some()
+                || attr.rename.is_some()
                 || attr.description.is_some()
                 || attr.default.is_some()
+                || attr.deprecated.is_some()
                 || attr.context.is_some()
             {
                 return Err(syn::Error::new(
@@ -171,6 +213,15 @@ impl Attr {
             }
         }
 
+        if let Some(rename) = &attr.rename {
+            if attr.name.is_some() {
+                return Err(syn::Error::new(
+                    rename.span_ident(),
+                    "`rename` attribute argument is not composable with `name` argument",
+                ));
+            }
+        }
+
         Ok(attr)
     }
 
@@ -178,13 +229,19 @@ impl Attr {
     /// [`OnField`] argument.
     fn ensure_no_regular_arguments(&self) -> syn::Result<()> {
         if let Some(span) = &self.name {
-            return Err(Self::err_disallowed(&span, "name"));
+            return Err(Self::err_disallowed(span.span(), "name"));
+        }
+        if let Some(span) = &self.rename {
+            return Err(Self::err_disallowed(span.span_ident(), "rename"));
         }
         if let Some(span) = &self.description {
-            return Err(Self::err_disallowed(&span, "description"));
+            return Err(Self::err_disallowed(span.span(), "description"));
         }
         if let Some(span) = &self.default {
-            return Err(Self::err_disallowed(&span, "default"));
+            return Err(Self::err_disallowed(span.span(), "default"));
+        }
+        if let Some(span) = &self.deprecated {
+            return Err(Self::err_disallowed(span.span(), "deprecated"));
         }
         Ok(())
     }
@@ -192,9 +249,9 @@ impl Attr {
     /// Emits "argument is not allowed" [`syn::Error`] for the given `arg`
     /// pointing to the given `span`.
     #[must_use]
-    fn err_disallowed<S: Spanned>(span: &S, arg: &str) -> syn::Error {
+    fn err_disallowed(span: Span, arg: &str) -> syn::Error {
         syn::Error::new(
-            span.span(),
+            span,
             format!("attribute argument `#[graphql({arg} = ...)]` is not allowed here",),
         )
     }
@@ -229,6 +286,82 @@ pub(crate) struct OnField {
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/October2021#sec-Required-Arguments
     pub(crate) default: Option<default::Value>,
+
+    /// [Deprecation][2] of this [GraphQL field argument][1] to put into
+    /// GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
+    pub(crate) deprecated: Option<deprecation::Directive>,
+
+    /// Kind of the string borrowed from the [`juniper::Arguments`], if this
+    /// [GraphQL field argument][1] is represented by a `&str` (or an
+    /// `Option<&str>`), rather than by an owned value.
+    ///
+    /// The [`OnField::ty`] is the owned counterpart in such case.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) borrowed: Option<BorrowedStr>,
+
+    /// `#[cfg]` attributes of the Rust method argument representing this
+    /// [GraphQL field argument][1], conditionally compiling all the code
+    /// generated for it.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) cfg_attrs: Vec<syn::Attribute>,
+}
+
+/// Kind of a string borrowed by a [GraphQL field argument][1] from the
+/// [`juniper::Arguments`].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum BorrowedStr {
+    /// `&str` representing a required `String!` argument.
+    Required,
+
+    /// `Option<&str>` representing a nullable `String` argument.
+    Nullable,
+}
+
+impl BorrowedStr {
+    /// Detects the [`BorrowedStr`] represented by the given Rust type, along
+    /// with the owned Rust type to represent it in GraphQL schema.
+    fn detect(ty: &syn::Type) -> Option<(Self, syn::Type)> {
+        if is_str_ref(ty) {
+            return Some((Self::Required, parse_quote! { ::std::string::String }));
+        }
+        let p = match ty.unparenthesized() {
+            syn::Type::Path(p) if p.qself.is_none() => p,
+            _ => return None,
+        };
+        let seg = p.path.segments.last()?;
+        let is_option_str = seg.ident == "Option"
+            && matches!(
+                &seg.arguments,
+                syn::PathArguments::AngleBracketed(a) if a.args.len() == 1 && matches!(
+                    a.args.first(),
+                    Some(syn::GenericArgument::Type(inner)) if is_str_ref(inner),
+                ),
+            );
+        is_option_str.then(|| {
+            (
+                Self::Nullable,
+                parse_quote! { ::std::option::Option<::std::string::String> },
+            )
+        })
+    }
+}
+
+/// Checks whether the given Rust type is a shared `&str` reference.
+fn is_str_ref(ty: &syn::Type) -> bool {
+    matches!(
+        ty.unparenthesized(),
+        syn::Type::Reference(r) if r.mutability.is_none() && matches!(
+            r.elem.unparenthesized(),
+            syn::Type::Path(p) if p.qself.is_none() && p.path.is_ident("str"),
+        ),
+    )
 }
 
 /// Possible kinds of Rust method arguments for code generation.
@@ -263,6 +396,13 @@ impl OnMethod {
         }
     }
 
+    /// Returns `#[cfg]` attributes of this argument, if it represents an
+    /// [`OnField`] one.
+    #[must_use]
+    pub(crate) fn cfg_attrs(&self) -> &[syn::Attribute] {
+        self.as_regular().map_or(&[], |arg| &arg.cfg_attrs)
+    }
+
     /// Returns [`syn::Type`] of this [`OnMethod::Context`], if it represents
     /// the one.
     #[must_use]
@@ -281,8 +421,10 @@ impl OnMethod {
     /// [`marker::IsOutputType::mark`]: juniper::marker::IsOutputType::mark
     #[must_use]
     pub(crate) fn method_mark_tokens(&self, scalar: &scalar::Type) -> Option<TokenStream> {
-        let ty = &self.as_regular()?.ty;
+        let arg = self.as_regular()?;
+        let (ty, cfg) = (&arg.ty, &arg.cfg_attrs);
         Some(quote_spanned! { ty.span() =>
+            #( #cfg )*
             <#ty as ::juniper::marker::IsInputType<#scalar>>::mark();
         })
     }
@@ -300,6 +442,7 @@ impl OnMethod {
         let (name, ty) = (&arg.name, &arg.ty);
 
         let description = &arg.description;
+        let deprecated = &arg.deprecated;
 
         let method = if let Some(val) = &arg.default {
             quote_spanned! { val.span() =>
@@ -309,7 +452,7 @@ impl OnMethod {
             quote! { .arg::<#ty>(#name, info) }
         };
 
-        Some(quote! { .argument(registry #method #description) })
+        Some(quote! { .argument(registry #method #description #deprecated) })
     }
 
     /// Returns generated code for the [`GraphQLValue::resolve_field`] method,
@@ -325,27 +468,51 @@ impl OnMethod {
     ) -> TokenStream {
         match self {
             Self::Regular(arg) => {
-                let (name, ty) = (&arg.name, &arg.ty);
+                let (name, ty, cfg) = (&arg.name, &arg.ty, &arg.cfg_attrs);
                 let err_text = format!("Missing argument `{name}`: {{}}");
 
-                let arg = quote! {
-                    args.get::<#ty>(#name).and_then(|opt| opt.map_or_else(|| {
-                        <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
-                            .map_err(|e| {
-                                ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
-                                    .map_message(|m| format!(#err_text, m))
-                            })
-                    }, Ok))
+                let arg = match arg.borrowed {
+                    Some(BorrowedStr::Required) => quote! {
+                        args.get_str(#name).and_then(|opt| opt.ok_or_else(|| {
+                            ::juniper::FieldError::<#scalar>::from(
+                                format!(#err_text, "Expected `String`, found: null"),
+                            )
+                        }))
+                    },
+                    Some(BorrowedStr::Nullable) => quote! { args.get_str(#name) },
+                    // Arguments may be absent even if having a default value,
+                    // when the field is resolved via an interface not
+                    // declaring them, so fall back to the default value here.
+                    None if arg.default.is_some() => {
+                        let val = &arg.default;
+                        quote! {
+                            args.get::<#ty>(#name)
+                                .map_err(|e| e.prepend_input_path(#name))
+                                .map(|opt| opt.unwrap_or_else(|| #val))
+                        }
+                    }
+                    None => quote! {
+                        args.get::<#ty>(#name)
+                            .map_err(|e| e.prepend_input_path(#name))
+                            .and_then(|opt| opt.map_or_else(|| {
+                            <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
+                                .map_err(|e| {
+                                    ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
+                                        .map_message(|m| format!(#err_text, m))
+                                })
+                        }, Ok))
+                    },
                 };
                 if for_async {
                     quote! {
+                        #( #cfg )*
                         match #arg {
                             Ok(v) => v,
                             Err(e) => return Box::pin(async { Err(e) }),
                         }
                     }
                 } else {
-                    quote! { #arg? }
+                    quote! { #( #cfg )* #arg? }
                 }
             }
 
@@ -370,15 +537,49 @@ impl OnMethod {
         let orig_attrs = argument.attrs.clone();
 
         // Remove repeated attributes from the method, to omit incorrect expansion.
+        // Doc comments are removed as well, because Rust doesn't allow them on
+        // function arguments, while we use them as descriptions.
         argument.attrs = mem::take(&mut argument.attrs)
             .into_iter()
-            .filter(|attr| !path_eq_single(&attr.path, "graphql"))
+            .filter(|attr| {
+                !path_eq_single(&attr.path, "graphql") && !path_eq_single(&attr.path, "doc")
+            })
             .collect();
 
         let attr = Attr::from_attrs("graphql", &orig_attrs)
             .map_err(|e| proc_macro_error::emit_error!(e))
             .ok()?;
 
+        let cfg_attrs = filter_attrs("cfg", &orig_attrs)
+            .cloned()
+            .collect::<Vec<_>>();
+        let arg = Self::parse_without_cfg(argument, renaming, scope, attr, &orig_attrs);
+        if let (Some(cfg), Some(Self::Context(_) | Self::Executor)) = (cfg_attrs.first(), &arg) {
+            scope.emit_custom(
+                cfg.span(),
+                "`#[cfg]` attribute is supported only on regular arguments",
+            );
+            return None;
+        }
+        arg.map(|arg| match arg {
+            Self::Regular(mut arg) => {
+                arg.cfg_attrs = cfg_attrs;
+                Self::Regular(arg)
+            }
+            arg => arg,
+        })
+    }
+
+    /// Parses an [`OnMethod`] argument from the given Rust method argument
+    /// definition, with its already parsed [`Attr`] and original
+    /// [`syn::Attribute`]s, without considering its `#[cfg]` attributes.
+    fn parse_without_cfg(
+        argument: &syn::PatType,
+        renaming: &rename::Policy,
+        scope: &diagnostic::Scope,
+        attr: Attr,
+        orig_attrs: &[syn::Attribute],
+    ) -> Option<Self> {
         if attr.context.is_some() {
             return Some(Self::Context(Box::new(argument.ty.unreferenced().clone())));
         }
@@ -404,7 +605,10 @@ impl OnMethod {
         let name = if let Some(name) = attr.name.as_ref() {
             name.as_ref().value()
         } else if let syn::Pat::Ident(name) = &*argument.pat {
-            renaming.apply(&name.ident.unraw().to_string())
+            attr.rename
+                .as_deref()
+                .unwrap_or(renaming)
+                .apply(&name.ident.unraw().to_string())
         } else {
             scope
                 .custom(
@@ -428,11 +632,37 @@ impl OnMethod {
             return None;
         }
 
+        if let Some(deprecated) = &attr.deprecated {
+            if attr.default.is_none() && !argument.ty.is_option() {
+                scope.emit_custom(
+                    deprecated.span_ident(),
+                    "required argument cannot be deprecated, \
+                     make it `Option` or specify its `default` value",
+                );
+                return None;
+            }
+        }
+
+        let (borrowed, ty) = match BorrowedStr::detect(&argument.ty) {
+            Some((borrowed, ty)) => (Some(borrowed), ty),
+            None => (None, argument.ty.as_ref().clone()),
+        };
+
+        let description = match attr.description {
+            Some(desc) => Some(desc),
+            None => Description::parse_from_doc_attrs(orig_attrs)
+                .map_err(|e| proc_macro_error::emit_error!(e))
+                .ok()?,
+        };
+
         Some(Self::Regular(Box::new(OnField {
             name,
-            ty: argument.ty.as_ref().clone(),
-            description: attr.description.map(SpanContainer::into_inner),
+            ty,
+            description: description.map(SpanContainer::into_inner),
             default: attr.default.map(SpanContainer::into_inner),
+            deprecated: attr.deprecated.map(SpanContainer::into_inner),
+            borrowed,
+            cfg_attrs: vec![],
         })))
     }
 }
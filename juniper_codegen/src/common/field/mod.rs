@@ -15,12 +15,12 @@ use syn::{
 };
 
 use crate::common::{
-    deprecation, filter_attrs,
+    default, deprecation, directive, filter_attrs, metadata,
     parse::{
         attr::{err, OptionExt as _},
         ParseBufferExt as _,
     },
-    scalar, Description, SpanContainer,
+    rename, scalar, Description, SpanContainer,
 };
 
 pub(crate) use self::arg::OnMethod as MethodArgument;
@@ -62,6 +62,107 @@ pub(crate) struct Attr {
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) ignore: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified path of the function to resolve this
+    /// [GraphQL field][1] with when an operation is executed in dry-run mode.
+    ///
+    /// The function must have the same signature as the method representing
+    /// this [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) validate_only: Option<SpanContainer<syn::ExprPath>>,
+
+    /// Explicitly specified expression of the [`Guard`] to check before
+    /// resolving this [GraphQL field][1].
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) guard: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified predicate over the context, hiding this
+    /// [GraphQL field][1] at execution time whenever it returns `true`.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) skip_if: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified expression of the error to resolve this
+    /// [GraphQL field][1] with, rather than `null`, whenever it's hidden by
+    /// its [`Attr::skip_if`] predicate.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) skip_error: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified complexity of this [GraphQL field][1] in the
+    /// complexity analysis of an operation.
+    ///
+    /// Either a static cost expression, or a closure calculating the whole
+    /// complexity out of the field's arguments and the complexity of its
+    /// selection set.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) complexity: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified function converting the error of the [`Result`]
+    /// returned by the method representing this [GraphQL field][1], before it's
+    /// converted into a [`FieldError`] via [`IntoFieldError`].
+    ///
+    /// [`FieldError`]: juniper::FieldError
+    /// [`IntoFieldError`]: juniper::IntoFieldError
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) map_err: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified marker indicating that [GraphQL fields][1] of this
+    /// struct field's type should be flattened into the parent
+    /// [GraphQL object][2], rather than representing a single
+    /// [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) flatten: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified marker indicating that all the [GraphQL fields][1]
+    /// not defined by the parent [GraphQL object][2] should be resolved by
+    /// this struct field's type, which is a [GraphQL object][2] itself.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) delegate: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified [`rename::Policy`] for all [GraphQL arguments][2]
+    /// of this [GraphQL field][1].
+    ///
+    /// If [`None`], then the [`rename::Policy`] of the whole type is used.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) rename_args: Option<SpanContainer<rename::Policy>>,
+
+    /// Explicitly specified typed [`Metadata`] annotations of this
+    /// [GraphQL field][1].
+    ///
+    /// [`Metadata`]: juniper::meta::Metadata
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) meta: Option<SpanContainer<metadata::Annotations>>,
+
+    /// Explicitly specified [GraphQL directives][2] applied to this
+    /// [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Option<SpanContainer<directive::Directives>>,
+
+    /// Explicitly specified Rust types to substitute the generic type
+    /// parameters of the method representing this [GraphQL field][1] with.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) generics: Option<SpanContainer<Vec<(syn::Ident, syn::Type)>>>,
+
+    /// Explicitly specified argument of `#[derive(GraphQLInputObject)]`
+    /// derived on the same struct (like `default`), which is irrelevant for
+    /// this [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) input_arg: Option<SpanContainer<syn::Ident>>,
 }
 
 impl Parse for Attr {
@@ -98,6 +199,123 @@ impl Parse for Attr {
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
                     .none_or_else(|_| err::dup_arg(&ident))?,
+                "validate_only" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::ExprPath>()?;
+                    out.validate_only
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let expr = input.parse::<syn::Expr>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(expr.span()), expr))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "skip_if" => {
+                    input.parse::<token::Eq>()?;
+                    let expr = input.parse::<syn::Expr>()?;
+                    out.skip_if
+                        .replace(SpanContainer::new(ident.span(), Some(expr.span()), expr))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "skip_error" => {
+                    input.parse::<token::Eq>()?;
+                    let expr = input.parse::<syn::Expr>()?;
+                    out.skip_error
+                        .replace(SpanContainer::new(ident.span(), Some(expr.span()), expr))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "complexity" => {
+                    input.parse::<token::Eq>()?;
+                    let expr = input.parse::<syn::Expr>()?;
+                    out.complexity
+                        .replace(SpanContainer::new(ident.span(), Some(expr.span()), expr))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "map_err" => {
+                    input.parse::<token::Eq>()?;
+                    let expr = input.parse::<syn::Expr>()?;
+                    out.map_err
+                        .replace(SpanContainer::new(ident.span(), Some(expr.span()), expr))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "flatten" => out
+                    .flatten
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "delegate" => out
+                    .delegate
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "rename_args" => {
+                    input.parse::<token::Eq>()?;
+                    let val = input.parse::<syn::LitStr>()?;
+                    out.rename_args
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(val.span()),
+                            val.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "meta" => {
+                    let meta = input.parse::<metadata::Annotations>()?;
+                    out.meta
+                        .replace(SpanContainer::new(ident.span(), Some(meta.span()), meta))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "directives" => {
+                    let directives = input.parse::<directive::Directives>()?;
+                    out.directives
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(directives.span()),
+                            directives,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "generics" => {
+                    let inner;
+                    let _ = syn::parenthesized!(inner in input);
+                    let parsed = inner.parse_terminated::<_, token::Comma>(|input| {
+                        let param = input.parse::<syn::Ident>()?;
+                        input.parse::<token::Eq>()?;
+                        let ty = input.parse::<syn::Type>()?;
+                        Ok((param, ty))
+                    })?;
+                    let mut substitutions = Vec::<(syn::Ident, syn::Type)>::new();
+                    for (param, ty) in parsed {
+                        if substitutions.iter().any(|(p, _)| *p == param) {
+                            return Err(syn::Error::new(
+                                param.span(),
+                                "duplicated generic parameter",
+                            ));
+                        }
+                        substitutions.push((param, ty));
+                    }
+                    out.generics
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            substitutions.first().map(|(p, _)| p.span()),
+                            substitutions,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "default" => {
+                    let _ = input.parse::<default::Value>()?;
+                    out.input_arg.get_or_insert_with(|| {
+                        SpanContainer::new(ident.span(), None, ident.clone())
+                    });
+                }
+                "validate" => {
+                    input.parse::<token::Eq>()?;
+                    let _ = input.parse::<syn::ExprPath>()?;
+                    out.input_arg.get_or_insert_with(|| {
+                        SpanContainer::new(ident.span(), None, ident.clone())
+                    });
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -117,6 +335,19 @@ impl Attr {
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
             ignore: try_merge_opt!(ignore: self, another),
+            validate_only: try_merge_opt!(validate_only: self, another),
+            guard: try_merge_opt!(guard: self, another),
+            skip_if: try_merge_opt!(skip_if: self, another),
+            skip_error: try_merge_opt!(skip_error: self, another),
+            complexity: try_merge_opt!(complexity: self, another),
+            map_err: try_merge_opt!(map_err: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
+            delegate: try_merge_opt!(delegate: self, another),
+            rename_args: try_merge_opt!(rename_args: self, another),
+            meta: try_merge_opt!(meta: self, another),
+            directives: try_merge_opt!(directives: self, another),
+            generics: try_merge_opt!(generics: self, another),
+            input_arg: self.input_arg.or(another.input_arg),
         })
     }
 
@@ -130,7 +361,22 @@ impl Attr {
             .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
 
         if let Some(ignore) = &attr.ignore {
-            if attr.name.is_some() || attr.description.is_some() || attr.deprecated.is_some() {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || attr.validate_only.is_some()
+                || attr.guard.is_some()
+                || attr.skip_if.is_some()
+                || attr.skip_error.is_some()
+                || attr.complexity.is_some()
+                || attr.map_err.is_some()
+                || attr.flatten.is_some()
+                || attr.delegate.is_some()
+                || attr.rename_args.is_some()
+                || attr.meta.is_some()
+                || attr.directives.is_some()
+                || attr.generics.is_some()
+            {
                 return Err(syn::Error::new(
                     ignore.span(),
                     "`ignore` attribute argument is not composable with any other arguments",
@@ -138,6 +384,60 @@ impl Attr {
             }
         }
 
+        if let Some(flatten) = &attr.flatten {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || attr.validate_only.is_some()
+                || attr.guard.is_some()
+                || attr.skip_if.is_some()
+                || attr.skip_error.is_some()
+                || attr.complexity.is_some()
+                || attr.map_err.is_some()
+                || attr.delegate.is_some()
+                || attr.rename_args.is_some()
+                || attr.meta.is_some()
+                || attr.directives.is_some()
+                || attr.generics.is_some()
+            {
+                return Err(syn::Error::new(
+                    flatten.span(),
+                    "`flatten` attribute argument is not composable with any other arguments",
+                ));
+            }
+        }
+
+        if let Some(delegate) = &attr.delegate {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || attr.validate_only.is_some()
+                || attr.guard.is_some()
+                || attr.skip_if.is_some()
+                || attr.skip_error.is_some()
+                || attr.complexity.is_some()
+                || attr.map_err.is_some()
+                || attr.rename_args.is_some()
+                || attr.meta.is_some()
+                || attr.directives.is_some()
+                || attr.generics.is_some()
+            {
+                return Err(syn::Error::new(
+                    delegate.span(),
+                    "`delegate` attribute argument is not composable with any other arguments",
+                ));
+            }
+        }
+
+        if let Some(skip_error) = &attr.skip_error {
+            if attr.skip_if.is_none() {
+                return Err(syn::Error::new(
+                    skip_error.span(),
+                    "`skip_error` attribute argument requires `skip_if` to be specified",
+                ));
+            }
+        }
+
         if attr.description.is_none() {
             attr.description = Description::parse_from_doc_attrs(attrs)?;
         }
@@ -204,6 +504,67 @@ pub(crate) struct Definition {
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) is_async: bool,
+
+    /// Path of the function to resolve this [GraphQL field][1] with when an
+    /// operation is executed in dry-run mode, instead of the method
+    /// representing this [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) validate_only: Option<syn::ExprPath>,
+
+    /// Expression of the [`Guard`] to check before resolving this
+    /// [GraphQL field][1].
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) guard: Option<syn::Expr>,
+
+    /// Predicate over the context hiding this [GraphQL field][1] at execution
+    /// time, along with the optional expression of the error to resolve it
+    /// with, rather than `null`.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) skip_if: Option<(syn::Expr, Option<syn::Expr>)>,
+
+    /// Complexity of this [GraphQL field][1] in the complexity analysis of an
+    /// operation: either a static cost expression, or a closure calculating
+    /// the whole complexity.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) complexity: Option<syn::Expr>,
+
+    /// Function converting the error of the [`Result`] returned by the method
+    /// representing this [GraphQL field][1], before converting it into a
+    /// [`FieldError`].
+    ///
+    /// [`FieldError`]: juniper::FieldError
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) map_err: Option<syn::Expr>,
+
+    /// Typed [`Metadata`] annotations of this [GraphQL field][1].
+    ///
+    /// [`Metadata`]: juniper::meta::Metadata
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) meta: Option<metadata::Annotations>,
+
+    /// [GraphQL directives][2] applied to this [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Option<directive::Directives>,
+
+    /// Generic arguments to call the Rust method representing this
+    /// [GraphQL field][1] with, monomorphizing it for the schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) turbofish: Option<syn::AngleBracketedGenericArguments>,
+
+    /// `#[cfg]` attributes of the Rust method (or struct field) representing
+    /// this [GraphQL field][1], conditionally compiling all the code generated
+    /// for it.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) cfg_attrs: Vec<syn::Attribute>,
 }
 
 impl Definition {
@@ -265,9 +626,14 @@ impl Definition {
             >>::Type
         };
 
+        let cfg = &self.cfg_attrs;
+
         quote_spanned! { self.ty.span() =>
-            #( #args_marks )*
-            <#resolved_ty as ::juniper::marker::IsOutputType<#scalar>>::mark();
+            #( #cfg )*
+            {
+                #( #args_marks )*
+                <#resolved_ty as ::juniper::marker::IsOutputType<#scalar>>::mark();
+            }
         }
     }
 
@@ -292,20 +658,88 @@ impl Definition {
 
         let description = &self.description;
         let deprecated = &self.deprecated;
-
-        let args = self
-            .arguments
-            .iter()
-            .flat_map(|args| args.iter().filter_map(MethodArgument::method_meta_tokens));
+        let meta = &self.meta;
+        let directives = &self.directives;
+        let complexity = self.complexity.as_ref().map(|c| {
+            if matches!(c, syn::Expr::Closure(_)) {
+                quote! { .complexity_with(#c) }
+            } else {
+                quote! { .complexity(#c) }
+            }
+        });
+
+        let args = self.arguments.iter().flat_map(|args| {
+            args.iter().filter_map(|arg| {
+                let meta = arg.method_meta_tokens()?;
+                let cfg = arg.cfg_attrs();
+                Some(quote! {
+                    #( #cfg )*
+                    let field = field #meta;
+                })
+            })
+        });
+        let cfg = &self.cfg_attrs;
 
         quote! {
-            registry.field_convert::<#ty, _, Self::Context>(#name, info)
+            #( #cfg )*
+            {
+                let field = registry.field_convert::<#ty, _, Self::Context>(#name, info);
                 #( #args )*
-                #description
-                #deprecated
+                field
+                    #description
+                    #deprecated
+                    #complexity
+                    #meta
+                    #directives
+            }
         }
     }
 
+    /// Returns generated code applying the [`Definition::map_err`] function of
+    /// this [GraphQL field][1] to the provided `res`ult of its resolver (or to
+    /// the output of its resolving `Future`, if `is_future` is `true`), if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    #[must_use]
+    pub(crate) fn map_err_tokens(&self, res: TokenStream, is_future: bool) -> TokenStream {
+        match &self.map_err {
+            Some(map_err) if is_future => quote! {
+                ::juniper::futures::FutureExt::map(#res, |res| {
+                    ::juniper::macros::helper::map_err(res, #map_err)
+                })
+            },
+            Some(map_err) => quote! { ::juniper::macros::helper::map_err(#res, #map_err) },
+            None => res,
+        }
+    }
+
+    /// Returns generated code checking the [`Definition::skip_if`] predicate of
+    /// this [GraphQL field][1], and returning early with the result of hiding
+    /// it, wrapped via the provided `ret` function.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    #[must_use]
+    pub(crate) fn skip_if_tokens(
+        &self,
+        scalar: &scalar::Type,
+        ret: impl FnOnce(TokenStream) -> TokenStream,
+    ) -> Option<TokenStream> {
+        self.skip_if.as_ref().map(|(predicate, error)| {
+            let res = if let Some(error) = error {
+                quote! { Err(::juniper::IntoFieldError::<#scalar>::into_field_error(#error)) }
+            } else {
+                quote! { Ok(::juniper::Value::null()) }
+            };
+            let ret = ret(res);
+
+            quote! {
+                if ::juniper::macros::helper::is_skipped(#predicate, executor.context()) {
+                    #ret
+                }
+            }
+        })
+    }
+
     /// Returns generated code for the
     /// [`GraphQLSubscriptionValue::resolve_field_into_stream`][0] method, which
     /// resolves this [GraphQL field][1] as [subscription][2].
@@ -332,7 +766,9 @@ impl Definition {
                 quote! { self, }
             });
 
-            quote! { Self::#ident(#rcv #( #args ),*) }
+            let turbofish = &self.turbofish;
+
+            quote! { Self::#ident #turbofish(#rcv #( #args ),*) }
         } else {
             ty = parse_quote! { _ };
             quote! { &self.#ident }
@@ -340,10 +776,25 @@ impl Definition {
         if !self.is_async {
             fut = quote! { ::juniper::futures::future::ready(#fut) };
         }
+        let fut = self.map_err_tokens(fut, true);
+
+        let guard = self.guard.as_ref().map(|guard| {
+            quote! {
+                ::juniper::macros::helper::check_guard(
+                    #guard, executor.context(), &args, #name,
+                ).await?;
+            }
+        });
+
+        let skip_if = self.skip_if_tokens(scalar, |res| quote! { return #res; });
+        let cfg = &self.cfg_attrs;
 
         quote! {
+            #( #cfg )*
             #name => {
                 ::juniper::futures::FutureExt::boxed(async move {
+                    #skip_if
+                    #guard
                     let res: #ty = #fut.await;
                     let res = ::juniper::IntoFieldResult::<_, #scalar>::into_result(res)?;
                     let executor = executor.as_owned_executor();
@@ -0,0 +1,84 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of typed [`Metadata`] annotations of schema elements.
+//!
+//! [`Metadata`]: juniper::meta::Metadata
+
+use proc_macro2::TokenStream;
+use quote::{quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token,
+};
+
+/// Typed annotations of a schema element, defined via
+/// `#[graphql(meta(Type = value, ...))]` attribute.
+///
+/// Generates an [`annotate()`][0] call for each annotation, so its `value` is
+/// retrievable as its `Type` from the [`Metadata`] at runtime.
+///
+/// [`Metadata`]: juniper::meta::Metadata
+/// [0]: juniper::meta::Field::annotate
+#[derive(Debug, Default)]
+pub(crate) struct Annotations(Vec<Annotation>);
+
+/// Single typed annotation of a schema element.
+#[derive(Debug)]
+struct Annotation {
+    /// Rust type to annotate a schema element as.
+    ty: syn::Type,
+
+    /// Expression of the value to annotate a schema element with.
+    value: syn::Expr,
+}
+
+impl Parse for Annotation {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let ty = input.parse::<syn::Type>()?;
+        input.parse::<token::Eq>()?;
+        let value = input.parse::<syn::Expr>()?;
+        Ok(Self { ty, value })
+    }
+}
+
+impl Parse for Annotations {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let mut out = Vec::<Annotation>::new();
+        for annotation in Punctuated::<Annotation, token::Comma>::parse_terminated(&inner)? {
+            let ty = annotation.ty.to_token_stream().to_string();
+            if out.iter().any(|a| a.ty.to_token_stream().to_string() == ty) {
+                return Err(syn::Error::new(
+                    annotation.ty.span(),
+                    "duplicated annotation of the same type",
+                ));
+            }
+            out.push(annotation);
+        }
+        Ok(Self(out))
+    }
+}
+
+impl Annotations {
+    /// Returns the [`Span`] of these [`Annotations`].
+    ///
+    /// [`Span`]: proc_macro2::Span
+    pub(crate) fn span(&self) -> proc_macro2::Span {
+        self.0
+            .first()
+            .map_or_else(proc_macro2::Span::call_site, |a| a.ty.span())
+    }
+}
+
+impl ToTokens for Annotations {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        for Annotation { ty, value } in &self.0 {
+            quote_spanned! { ty.span() =>
+                .annotate::<#ty>(#value)
+            }
+            .to_tokens(into);
+        }
+    }
+}
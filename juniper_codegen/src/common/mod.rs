@@ -4,9 +4,12 @@ pub(crate) mod default;
 pub(crate) mod deprecation;
 mod description;
 pub(crate) mod diagnostic;
+pub(crate) mod directive;
 pub(crate) mod field;
 pub(crate) mod gen;
+pub(crate) mod metadata;
 pub(crate) mod parse;
+pub(crate) mod remote;
 pub(crate) mod rename;
 pub(crate) mod scalar;
 mod span_container;
@@ -114,9 +114,18 @@ pub(crate) trait TypeExt {
     /// inferring.
     fn lifetimes_anonymized(&mut self);
 
+    /// Substitutes all the occurrences of the given generic type parameters in
+    /// this [`syn::Type`] with the provided [`syn::Type`]s.
+    fn type_params_substituted(&mut self, substitutions: &[(syn::Ident, syn::Type)]);
+
     /// Returns the topmost [`syn::Ident`] of this [`syn::TypePath`], if any.
     #[must_use]
     fn topmost_ident(&self) -> Option<&syn::Ident>;
+
+    /// Checks whether this [`syn::Type`] is an [`Option`] (so represents a
+    /// `null`able GraphQL type).
+    #[must_use]
+    fn is_option(&self) -> bool;
 }
 
 impl TypeExt for syn::Type {
@@ -220,6 +229,12 @@ impl TypeExt for syn::Type {
         });
     }
 
+    fn type_params_substituted(&mut self, substitutions: &[(syn::Ident, syn::Type)]) {
+        if !substitutions.is_empty() {
+            SubstituteTypeParams(substitutions).visit_type_mut(self)
+        }
+    }
+
     fn topmost_ident(&self) -> Option<&syn::Ident> {
         match self.unparenthesized() {
             syn::Type::Path(p) => Some(&p.path),
@@ -237,6 +252,36 @@ impl TypeExt for syn::Type {
         .last()
         .map(|s| &s.ident)
     }
+
+    fn is_option(&self) -> bool {
+        match self.unparenthesized() {
+            syn::Type::Path(p) if p.qself.is_none() => p
+                .path
+                .segments
+                .last()
+                .map_or(false, |s| s.ident == "Option"),
+            _ => false,
+        }
+    }
+}
+
+/// Substitutes generic type parameters with the provided [`syn::Type`]s.
+struct SubstituteTypeParams<'a>(&'a [(syn::Ident, syn::Type)]);
+
+impl<'a> VisitMut for SubstituteTypeParams<'a> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(p) = ty {
+            if p.qself.is_none() {
+                if let Some(param) = p.path.get_ident() {
+                    if let Some((_, subst)) = self.0.iter().find(|(par, _)| par == param) {
+                        *ty = subst.clone();
+                        return;
+                    }
+                }
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty)
+    }
 }
 
 /// Extension of [`syn::Generics`] providing common function widely used by this crate for parsing.
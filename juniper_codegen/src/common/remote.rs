@@ -0,0 +1,126 @@
+//! Common functions, definitions and extensions for generating conversions
+//! between a Rust type, a GraphQL type is derived on, and the remote type
+//! (defined in another crate) it mirrors.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned as _;
+
+/// Generates code implementing [`From`] conversion of the `remote` type into
+/// the given local Rust type, which mirrors its definition.
+///
+/// Each field is converted with an [`Into`] conversion, so the local type may
+/// use other remote-mirroring types for its fields.
+#[must_use]
+pub(crate) fn from_remote_tokens(ast: &syn::DeriveInput, remote: &syn::Path) -> TokenStream {
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let body = match &ast.data {
+        syn::Data::Struct(data) => {
+            let fields = data.fields.iter().enumerate().map(|(n, f)| {
+                let member = member(n, f);
+                quote! { #member: ::std::convert::Into::into(remote.#member) }
+            });
+            quote! { Self { #( #fields ),* } }
+        }
+        syn::Data::Enum(data) => {
+            // Generic arguments are not allowed in a pattern path, so are left
+            // to be inferred.
+            let mut remote_enum = remote.clone();
+            if let Some(last) = remote_enum.segments.last_mut() {
+                last.arguments = syn::PathArguments::None;
+            }
+            let arms = data.variants.iter().map(|v| {
+                let var = &v.ident;
+                let (pat, val) = variant_fields(&v.fields);
+                quote! { #remote_enum::#var { #( #pat ),* } => Self::#var { #( #val ),* }, }
+            });
+            quote! { match remote { #( #arms )* } }
+        }
+        syn::Data::Union(_) => return quote! {},
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::From<#remote> for #ident #ty_generics
+            #where_clause
+        {
+            fn from(remote: #remote) -> Self {
+                #body
+            }
+        }
+    }
+}
+
+/// Generates code implementing [`From`] conversion of the given local Rust
+/// type into the `remote` type, whose definition it mirrors.
+///
+/// Each field is converted with an [`Into`] conversion, so the local type may
+/// use other remote-mirroring types for its fields.
+#[must_use]
+pub(crate) fn into_remote_tokens(ast: &syn::DeriveInput, remote: &syn::Path) -> TokenStream {
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let body = match &ast.data {
+        syn::Data::Struct(data) => {
+            let fields = data.fields.iter().enumerate().map(|(n, f)| {
+                let member = member(n, f);
+                quote! { #member: ::std::convert::Into::into(local.#member) }
+            });
+            quote! { Self { #( #fields ),* } }
+        }
+        syn::Data::Enum(data) => {
+            let arms = data.variants.iter().map(|v| {
+                let var = &v.ident;
+                let (pat, val) = variant_fields(&v.fields);
+                quote! { #ident::#var { #( #pat ),* } => Self::#var { #( #val ),* }, }
+            });
+            quote! { match local { #( #arms )* } }
+        }
+        syn::Data::Union(_) => return quote! {},
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::From<#ident #ty_generics> for #remote
+            #where_clause
+        {
+            fn from(local: #ident #ty_generics) -> Self {
+                #body
+            }
+        }
+    }
+}
+
+/// Returns the [`syn::Member`] to access the given `n`th [`syn::Field`] with.
+fn member(n: usize, field: &syn::Field) -> syn::Member {
+    field.ident.clone().map_or_else(
+        || {
+            syn::Member::Unnamed(syn::Index {
+                index: n as u32,
+                span: field.span(),
+            })
+        },
+        syn::Member::Named,
+    )
+}
+
+/// Returns the code destructuring the given enum variant `fields` in a match
+/// arm pattern, along with the code constructing them back via an [`Into`]
+/// conversion.
+fn variant_fields(fields: &syn::Fields) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(n, f)| {
+            let member = member(n, f);
+            let binding = format_ident!("__field{}", n);
+            (
+                quote! { #member: #binding },
+                quote! { #member: ::std::convert::Into::into(#binding) },
+            )
+        })
+        .unzip()
+}
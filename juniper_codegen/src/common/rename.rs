@@ -22,6 +22,46 @@ pub(crate) enum Policy {
 }
 
 impl Policy {
+    /// Returns the default [`Policy`] for [GraphQL fields][1] and
+    /// [arguments][2], when no `rename_all` attribute's argument is specified.
+    ///
+    /// This is [`Policy::CamelCase`], unless overridden for the whole build via
+    /// the `JUNIPER_RENAME_ALL` environment variable (usually set in the `[env]`
+    /// section of a `.cargo/config.toml` file). The variable is read when this
+    /// crate is compiled, so [Cargo] tracks its changes, and is ignored for
+    /// `internal` types, as their names are mandated by the GraphQL spec.
+    ///
+    /// # Panics
+    ///
+    /// Aborts the macro expansion if the `JUNIPER_RENAME_ALL` environment
+    /// variable contains an unknown [`Policy`].
+    ///
+    /// [Cargo]: https://doc.rust-lang.org/cargo
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) fn default_for_fields(is_internal: bool) -> Self {
+        if is_internal {
+            return Self::CamelCase;
+        }
+        Self::from_env_var(option_env!("JUNIPER_RENAME_ALL")).unwrap_or_else(|policy| {
+            proc_macro_error::abort_call_site!(
+                "unknown renaming policy `{}` in `JUNIPER_RENAME_ALL` environment variable",
+                policy,
+            )
+        })
+    }
+
+    /// Parses the [`Policy`] from the given value of the `JUNIPER_RENAME_ALL`
+    /// environment variable, falling back to [`Policy::CamelCase`] if it's
+    /// not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns the given `value` back if it's not a known [`Policy`].
+    fn from_env_var(value: Option<&str>) -> Result<Self, &str> {
+        value.map_or(Ok(Self::CamelCase), |v| Self::from_str(v).map_err(|_| v))
+    }
+
     /// Applies this [`Policy`] to the given `name`.
     pub(crate) fn apply(&self, name: &str) -> String {
         match self {
@@ -162,3 +202,36 @@ mod to_upper_snake_case_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod default_for_fields_tests {
+    use super::Policy;
+
+    #[test]
+    fn defaults_to_camel_case() {
+        assert_eq!(Policy::from_env_var(None), Ok(Policy::CamelCase));
+    }
+
+    #[test]
+    fn parses_env_var() {
+        for (input, expected) in [
+            ("none", Policy::None),
+            ("camelCase", Policy::CamelCase),
+            ("SCREAMING_SNAKE_CASE", Policy::ScreamingSnakeCase),
+        ] {
+            assert_eq!(Policy::from_env_var(Some(input)), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_env_var() {
+        for input in ["", "snake_case", "CamelCase"] {
+            assert_eq!(Policy::from_env_var(Some(input)), Err(input));
+        }
+    }
+
+    #[test]
+    fn ignores_env_var_for_internal_types() {
+        assert_eq!(Policy::default_for_fields(true), Policy::CamelCase);
+    }
+}
@@ -3,12 +3,12 @@
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
-use quote::ToTokens as _;
+use quote::quote;
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned};
 
-use crate::common::{diagnostic, rename, scalar, SpanContainer};
+use crate::common::{diagnostic, remote, scalar, SpanContainer};
 
-use super::{ContainerAttr, Definition, ValueDefinition, VariantAttr};
+use super::{rename_source::Renaming, ContainerAttr, Definition, ValueDefinition, VariantAttr};
 
 /// [`diagnostic::Scope`] of errors for `#[derive(GraphQLEnum)]` macro.
 const ERR: diagnostic::Scope = diagnostic::Scope::EnumDerive;
@@ -25,15 +25,16 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
     };
 
     let mut has_ignored_variants = false;
-    let renaming = attr
-        .rename_values
-        .map(SpanContainer::into_inner)
-        .unwrap_or(rename::Policy::ScreamingSnakeCase);
+    let renaming = Renaming::new(
+        attr.rename_values.map(SpanContainer::into_inner),
+        attr.rename_source.as_deref().copied(),
+        &ast.attrs,
+    )?;
     let values = data
         .variants
         .iter()
         .filter_map(|v| {
-            parse_value(v, renaming).or_else(|| {
+            parse_value(v, &renaming).or_else(|| {
                 has_ignored_variants = true;
                 None
             })
@@ -57,6 +58,18 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         ));
     }
 
+    let aliases = values.iter().flat_map(|v| &v.aliases).collect::<Vec<_>>();
+    let unique_aliases = aliases.iter().copied().collect::<HashSet<_>>();
+    if unique_aliases.len() != aliases.len()
+        || unique_aliases.iter().any(|a| unique_values.contains(a))
+    {
+        return Err(ERR.custom_error(
+            data.variants.span(),
+            "expected all GraphQL enum value aliases to be unique and not to \
+             clash with GraphQL enum value names",
+        ));
+    }
+
     let name = attr
         .name
         .clone()
@@ -72,6 +85,16 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         );
     }
 
+    if let Some(str_conversions) = &attr.str_conversions {
+        if has_ignored_variants {
+            ERR.emit_custom(
+                str_conversions.span_ident(),
+                "`str_conversions` attribute argument is not supported on enums \
+                 with ignored variants",
+            );
+        }
+    }
+
     let context = attr
         .context
         .map_or_else(|| parse_quote! { () }, SpanContainer::into_inner);
@@ -80,6 +103,15 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 
     proc_macro_error::abort_if_dirty();
 
+    let remote_conversions = attr.remote.as_deref().map(|remote| {
+        let from_remote = remote::from_remote_tokens(&ast, remote);
+        let into_remote = remote::into_remote_tokens(&ast, remote);
+        quote! {
+            #from_remote
+            #into_remote
+        }
+    });
+
     let definition = Definition {
         ident: ast.ident,
         generics: ast.generics,
@@ -89,15 +121,19 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         scalar,
         values,
         has_ignored_variants,
+        str_conversions: attr.str_conversions.is_some(),
     };
 
-    Ok(definition.into_token_stream())
+    Ok(quote! {
+        #definition
+        #remote_conversions
+    })
 }
 
 /// Parses a [`ValueDefinition`] from the given Rust enum variant definition.
 ///
 /// Returns [`None`] if the parsing fails, or the enum variant is ignored.
-fn parse_value(v: &syn::Variant, renaming: rename::Policy) -> Option<ValueDefinition> {
+fn parse_value(v: &syn::Variant, renaming: &Renaming) -> Option<ValueDefinition> {
     let attr = VariantAttr::from_attrs("graphql", &v.attrs)
         .map_err(|e| proc_macro_error::emit_error!(e))
         .ok()?;
@@ -110,19 +146,31 @@ fn parse_value(v: &syn::Variant, renaming: rename::Policy) -> Option<ValueDefini
         err_variant_with_fields(&v.fields)?;
     }
 
+    let (source_name, source_aliases) = renaming.parse_variant(&v.attrs);
+
     let name = attr
         .name
-        .map_or_else(
-            || renaming.apply(&v.ident.unraw().to_string()),
-            SpanContainer::into_inner,
-        )
+        .map(SpanContainer::into_inner)
+        .or(source_name)
+        .unwrap_or_else(|| renaming.apply(&v.ident.unraw().to_string()))
         .into_boxed_str();
 
+    let mut aliases = attr
+        .aliases
+        .into_iter()
+        .map(SpanContainer::into_inner)
+        .chain(source_aliases.into_iter().filter(|a| **a != *name))
+        .map(String::into_boxed_str)
+        .collect::<Vec<_>>();
+    aliases.sort();
+    aliases.dedup();
+
     Some(ValueDefinition {
         ident: v.ident.clone(),
         name,
         description: attr.description.map(SpanContainer::into_inner),
         deprecated: attr.deprecated.map(SpanContainer::into_inner),
+        aliases,
     })
 }
 
@@ -3,6 +3,9 @@
 //! [0]: https://spec.graphql.org/October2021#sec-Enums
 
 pub(crate) mod derive;
+mod rename_source;
+
+use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
@@ -75,6 +78,35 @@ struct ContainerAttr {
     /// [1]: https://spec.graphql.org/October2021#EnumValuesDefinition
     rename_values: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified [`rename_source::Source`] to reuse the naming of
+    /// [values][1] of this [GraphQL enum][0] from.
+    ///
+    /// If [`None`], then only `#[graphql]` attributes are considered for
+    /// naming.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    /// [1]: https://spec.graphql.org/October2021#EnumValuesDefinition
+    rename_source: Option<SpanContainer<rename_source::Source>>,
+
+    /// Explicitly specified marker for generating [`FromStr`], [`Display`] and
+    /// `TryFrom<&str>` implementations consistent with the names (and aliases)
+    /// of the [values][1] of this [GraphQL enum][0].
+    ///
+    /// [`Display`]: std::fmt::Display
+    /// [`FromStr`]: std::str::FromStr
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    /// [1]: https://spec.graphql.org/October2021#EnumValuesDefinition
+    str_conversions: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified path of the remote enum (defined in another crate)
+    /// whose definition this [GraphQL enum][0] mirrors.
+    ///
+    /// If [`Some`], then [`From`] conversions between this enum and the remote
+    /// one are generated in both directions.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    remote: Option<SpanContainer<syn::Path>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     is_internal: bool,
@@ -129,6 +161,28 @@ impl Parse for ContainerAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "rename_source" => {
+                    input.parse::<token::Eq>()?;
+                    let val = input.parse::<syn::LitStr>()?;
+                    out.rename_source
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(val.span()),
+                            val.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?;
+                }
+                "str_conversions" => out
+                    .str_conversions
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "remote" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::Path>()?;
+                    out.remote
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "internal" => {
                     out.is_internal = true;
                 }
@@ -152,6 +206,9 @@ impl ContainerAttr {
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             rename_values: try_merge_opt!(rename_values: self, another),
+            rename_source: try_merge_opt!(rename_source: self, another),
+            str_conversions: try_merge_opt!(str_conversions: self, another),
+            remote: try_merge_opt!(remote: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -204,6 +261,14 @@ struct VariantAttr {
     /// [3]: https://spec.graphql.org/October2021#sel-GAHnBZDACEDDGAA_6L
     deprecated: Option<SpanContainer<deprecation::Directive>>,
 
+    /// Explicitly specified aliases of this [GraphQL enum value][1], being
+    /// accepted on input in addition to its name.
+    ///
+    /// Aliases are not exposed via introspection.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+    aliases: HashSet<SpanContainer<String>>,
+
     /// Explicitly specified marker for the Rust enum variant to be ignored and
     /// not included into the code generated for a [GraphQL enum][0]
     /// implementation.
@@ -246,6 +311,20 @@ impl Parse for VariantAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "alias" | "aliases" => {
+                    input.parse::<token::Eq>()?;
+                    for alias in input.parse_maybe_wrapped_and_punctuated::<
+                        syn::LitStr, token::Bracket, token::Comma,
+                    >()? {
+                        out.aliases
+                            .replace(SpanContainer::new(
+                                ident.span(),
+                                Some(alias.span()),
+                                alias.value(),
+                            ))
+                            .none_or_else(|_| err::dup_arg(alias.span()))?;
+                    }
+                }
                 "ignore" | "skip" => out
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
@@ -268,6 +347,7 @@ impl VariantAttr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
+            aliases: try_merge_hashset!(aliases: self, another => span_joined),
             ignore: try_merge_opt!(ignore: self, another),
         })
     }
@@ -320,6 +400,13 @@ struct ValueDefinition {
     /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
     /// [2]: https://spec.graphql.org/October2021#sec--deprecated
     deprecated: Option<deprecation::Directive>,
+
+    /// Aliases of this [GraphQL enum value][1], being accepted on input in
+    /// addition to its [`ValueDefinition::name`], but not exposed in GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+    aliases: Vec<Box<str>>,
 }
 
 /// Representation of a [GraphQL enum][0] for code generation.
@@ -375,6 +462,14 @@ struct Definition {
     ///
     /// [0]: https://spec.graphql.org/October2021#sec-Enums
     has_ignored_variants: bool,
+
+    /// Indicates whether [`FromStr`], [`Display`] and `TryFrom<&str>`
+    /// implementations should be generated for this [GraphQL enum][0].
+    ///
+    /// [`Display`]: std::fmt::Display
+    /// [`FromStr`]: std::str::FromStr
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    str_conversions: bool,
 }
 
 impl ToTokens for Definition {
@@ -386,6 +481,9 @@ impl ToTokens for Definition {
         self.impl_from_input_value_tokens().to_tokens(into);
         self.impl_to_input_value_tokens().to_tokens(into);
         self.impl_reflection_traits_tokens().to_tokens(into);
+        if self.str_conversions {
+            self.impl_str_conversions_tokens().to_tokens(into);
+        }
     }
 }
 
@@ -436,11 +534,13 @@ impl Definition {
             let v_name = &v.name;
             let v_description = &v.description;
             let v_deprecation = &v.deprecated;
+            let v_aliases = &v.aliases;
 
             quote! {
                 ::juniper::meta::EnumValue::new(#v_name)
                     #v_description
                     #v_deprecation
+                    #( .alias(#v_aliases) )*
             }
         });
 
@@ -575,9 +675,10 @@ impl Definition {
         let variants = self.values.iter().map(|v| {
             let ident = &v.ident;
             let name = &v.name;
+            let aliases = &v.aliases;
 
             quote! {
-                Some(#name) => Ok(Self::#ident),
+                Some(#name #( | #aliases )*) => Ok(Self::#ident),
             }
         });
 
@@ -684,6 +785,82 @@ impl Definition {
         }
     }
 
+    /// Returns generated code implementing [`FromStr`], [`Display`] and
+    /// `TryFrom<&str>` traits for this [GraphQL enum][0], mapping its Rust
+    /// enum variants to the names of its [values][1] (accepting aliases on
+    /// parsing as well).
+    ///
+    /// [`Display`]: std::fmt::Display
+    /// [`FromStr`]: std::str::FromStr
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    /// [1]: https://spec.graphql.org/October2021#EnumValuesDefinition
+    fn impl_str_conversions_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let from_str_variants = self.values.iter().map(|v| {
+            let var_ident = &v.ident;
+            let name = &v.name;
+            let aliases = &v.aliases;
+
+            quote! {
+                #name #( | #aliases )* => Ok(Self::#var_ident),
+            }
+        });
+        let display_variants = self.values.iter().map(|v| {
+            let var_ident = &v.ident;
+            let name = &v.name;
+
+            quote! {
+                Self::#var_ident => #name,
+            }
+        });
+
+        let mut try_from_generics = self.generics.clone();
+        try_from_generics.params.push(parse_quote! { '__s });
+        let (try_from_impl_generics, _, _) = try_from_generics.split_for_impl();
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::std::str::FromStr for #ident #ty_generics
+                #where_clause
+            {
+                type Err = ::std::string::String;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        #( #from_str_variants )*
+                        _ => Err(::std::format!("Unknown enum value: {}", s)),
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl #try_from_impl_generics ::std::convert::TryFrom<&'__s str>
+                for #ident #ty_generics
+                #where_clause
+            {
+                type Error = ::std::string::String;
+
+                fn try_from(s: &'__s str) -> Result<Self, Self::Error> {
+                    <Self as ::std::str::FromStr>::from_str(s)
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::std::fmt::Display for #ident #ty_generics
+                #where_clause
+            {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(match self {
+                        #( #display_variants )*
+                    })
+                }
+            }
+        }
+    }
+
     /// Returns prepared [`syn::Generics`] for [`GraphQLType`] trait (and
     /// similar) implementation of this enum.
     ///
@@ -0,0 +1,442 @@
+//! Reusing of the [GraphQL enum values][1] naming from the attributes of other
+//! derive macros, specified via `#[graphql(rename_source = ...)]` attribute.
+//!
+//! [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+
+use std::str::FromStr;
+
+use syn::parse::{Parse, ParseStream};
+
+use crate::common::{filter_attrs, rename};
+
+/// Source of the naming to reuse for [GraphQL enum values][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Source {
+    /// [`serde`] attributes: `#[serde(rename_all = ...)]` on the enum, and
+    /// `#[serde(rename = ...)]` and `#[serde(alias = ...)]` on its variants.
+    ///
+    /// [`serde`]: https://docs.rs/serde
+    Serde,
+
+    /// [`strum`] attributes: `#[strum(serialize_all = ...)]` on the enum, and
+    /// `#[strum(to_string = ...)]` and `#[strum(serialize = ...)]` on its
+    /// variants.
+    ///
+    /// [`strum`]: https://docs.rs/strum
+    Strum,
+}
+
+impl Source {
+    /// Returns the name of the attributes of this [`Source`].
+    fn attr_name(self) -> &'static str {
+        match self {
+            Self::Serde => "serde",
+            Self::Strum => "strum",
+        }
+    }
+
+    /// Returns the name of the argument specifying the [`Casing`] of all the
+    /// enum variants in the attributes of this [`Source`].
+    fn casing_arg(self) -> &'static str {
+        match self {
+            Self::Serde => "rename_all",
+            Self::Strum => "serialize_all",
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = ();
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source {
+            "serde" => Ok(Self::Serde),
+            "strum" => Ok(Self::Strum),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<syn::LitStr> for Source {
+    type Error = syn::Error;
+
+    fn try_from(lit: syn::LitStr) -> syn::Result<Self> {
+        Self::from_str(&lit.value()).map_err(|_| {
+            syn::Error::new(
+                lit.span(),
+                "unknown renaming source, expected one of: \"serde\", \"strum\"",
+            )
+        })
+    }
+}
+
+impl Parse for Source {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        Self::try_from(input.parse::<syn::LitStr>()?)
+    }
+}
+
+/// Casing of the enum variants names, specified in the attributes of a
+/// [`Source`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Casing {
+    /// `lowercase`.
+    Lower,
+
+    /// `UPPERCASE`.
+    Upper,
+
+    /// `PascalCase`.
+    Pascal,
+
+    /// `camelCase`.
+    Camel,
+
+    /// `snake_case`.
+    Snake,
+
+    /// `SCREAMING_SNAKE_CASE`.
+    ScreamingSnake,
+}
+
+impl Casing {
+    /// Parses a [`Casing`] from the provided [`syn::LitStr`] in terms of the
+    /// given [`Source`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`Casing`] is unknown, or produces invalid [GraphQL names][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#Name
+    fn parse(source: Source, lit: &syn::LitStr) -> syn::Result<Self> {
+        let casing = match (source, lit.value().as_str()) {
+            (_, "lowercase") => Self::Lower,
+            (_, "UPPERCASE") => Self::Upper,
+            (_, "PascalCase") | (Source::Strum, "UpperCamelCase") => Self::Pascal,
+            (_, "camelCase") | (Source::Strum, "lowerCamelCase" | "mixed_case") => Self::Camel,
+            (_, "snake_case") => Self::Snake,
+            (_, "SCREAMING_SNAKE_CASE") | (Source::Strum, "shouty_snake_case") => {
+                Self::ScreamingSnake
+            }
+            (_, casing) => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "`{}({} = \"{casing}\")` cannot be reused for GraphQL enum \
+                         values, as it's either unknown or produces invalid \
+                         GraphQL names",
+                        source.attr_name(),
+                        source.casing_arg(),
+                    ),
+                ))
+            }
+        };
+        Ok(casing)
+    }
+
+    /// Applies this [`Casing`] to the given enum variant `name` in the same way
+    /// the provided [`Source`] does.
+    fn apply(self, source: Source, name: &str) -> String {
+        match source {
+            Source::Serde => self.apply_serde(name),
+            Source::Strum => self.apply_strum(name),
+        }
+    }
+
+    /// Applies this [`Casing`] to the given enum variant `name` in the same way
+    /// as [`serde`] does.
+    ///
+    /// [`serde`]: https://docs.rs/serde
+    fn apply_serde(self, name: &str) -> String {
+        match self {
+            Self::Lower => name.to_ascii_lowercase(),
+            Self::Upper => name.to_ascii_uppercase(),
+            Self::Pascal => name.into(),
+            Self::Camel => {
+                let mut chars = name.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_ascii_lowercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            }
+            Self::Snake | Self::ScreamingSnake => {
+                let mut snake = String::with_capacity(name.len());
+                for (i, c) in name.char_indices() {
+                    if i > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(c.to_ascii_lowercase());
+                }
+                if self == Self::ScreamingSnake {
+                    snake.make_ascii_uppercase();
+                }
+                snake
+            }
+        }
+    }
+
+    /// Applies this [`Casing`] to the given enum variant `name` in the same way
+    /// as [`strum`] does (via [`heck`]).
+    ///
+    /// [`heck`]: https://docs.rs/heck
+    /// [`strum`]: https://docs.rs/strum
+    fn apply_strum(self, name: &str) -> String {
+        let capitalize = |w: &str| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars.flat_map(char::to_lowercase)))
+                .into_iter()
+                .flatten()
+                .collect::<String>()
+        };
+
+        match self {
+            Self::Lower => name.to_lowercase(),
+            Self::Upper => name.to_uppercase(),
+            Self::Pascal => split_words(name).map(capitalize).collect(),
+            Self::Camel => split_words(name)
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            Self::Snake => split_words(name)
+                .map(str::to_lowercase)
+                .collect::<Vec<_>>()
+                .join("_"),
+            Self::ScreamingSnake => split_words(name)
+                .map(str::to_uppercase)
+                .collect::<Vec<_>>()
+                .join("_"),
+        }
+    }
+}
+
+/// Splits the provided `name` into words in the same way as [`heck`] does.
+///
+/// [`heck`]: https://docs.rs/heck
+fn split_words(name: &str) -> impl Iterator<Item = &str> {
+    name.split(|c: char| !c.is_alphanumeric())
+        .flat_map(|part| {
+            // `Some(true)` for uppercase mode, `Some(false)` for lowercase one,
+            // and `None` for a word boundary.
+            let mut mode = None;
+            let (mut words, mut init) = (vec![], 0);
+            let mut chars = part.char_indices().peekable();
+            while let Some((i, c)) = chars.next() {
+                if let Some(&(next_i, next)) = chars.peek() {
+                    let next_mode = if c.is_lowercase() {
+                        Some(false)
+                    } else if c.is_uppercase() {
+                        Some(true)
+                    } else {
+                        mode
+                    };
+                    if next_mode == Some(false) && next.is_uppercase() {
+                        words.push(&part[init..next_i]);
+                        init = next_i;
+                        mode = None;
+                    } else if mode == Some(true) && c.is_uppercase() && next.is_lowercase() {
+                        words.push(&part[init..i]);
+                        init = i;
+                        mode = None;
+                    } else {
+                        mode = next_mode;
+                    }
+                }
+            }
+            words.push(&part[init..]);
+            words
+        })
+        .filter(|w| !w.is_empty())
+}
+
+/// Naming of the [GraphQL enum values][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+#[derive(Debug)]
+pub(super) struct Renaming {
+    /// Explicitly specified [`rename::Policy`] via
+    /// `#[graphql(rename_all = ...)]` attribute.
+    policy: Option<rename::Policy>,
+
+    /// [`Source`] to reuse the naming from, along with its [`Casing`] of all
+    /// the enum variants, if any.
+    source: Option<(Source, Option<Casing>)>,
+}
+
+impl Renaming {
+    /// Creates a new [`Renaming`] out of the provided explicit
+    /// [`rename::Policy`] and [`Source`], parsing the [`Casing`] of the latter
+    /// from the given enum [`syn::Attribute`]s.
+    ///
+    /// # Errors
+    ///
+    /// If the [`Casing`] specified in the [`Source`] attributes cannot be
+    /// reused.
+    pub(super) fn new(
+        policy: Option<rename::Policy>,
+        source: Option<Source>,
+        attrs: &[syn::Attribute],
+    ) -> syn::Result<Self> {
+        let source = source
+            .map(|source| {
+                let mut casing = None;
+                for (arg, lit) in source_args(source, attrs) {
+                    if arg == source.casing_arg() {
+                        casing = Some(Casing::parse(source, &lit)?);
+                    }
+                }
+                Ok::<_, syn::Error>((source, casing))
+            })
+            .transpose()?;
+
+        Ok(Self { policy, source })
+    }
+
+    /// Returns the default name of a [GraphQL enum value][1] for the Rust enum
+    /// variant with the provided `ident`.
+    ///
+    /// Explicit `#[graphql(rename_all = ...)]` attribute takes precedence over
+    /// the [`Casing`] of the [`Source`], while
+    /// [`rename::Policy::ScreamingSnakeCase`] is used if none is specified.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+    pub(super) fn apply(&self, ident: &str) -> String {
+        match (self.policy, self.source) {
+            (Some(policy), _) => policy.apply(ident),
+            (None, Some((source, Some(casing)))) => casing.apply(source, ident),
+            (None, _) => rename::Policy::ScreamingSnakeCase.apply(ident),
+        }
+    }
+
+    /// Parses the name and aliases of a [GraphQL enum value][1] from the
+    /// [`Source`] attributes placed on a Rust enum variant, if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+    pub(super) fn parse_variant(&self, attrs: &[syn::Attribute]) -> (Option<String>, Vec<String>) {
+        let source = if let Some((source, _)) = self.source {
+            source
+        } else {
+            return (None, vec![]);
+        };
+
+        let (mut name, mut aliases) = (None, vec![]);
+        match source {
+            Source::Serde => {
+                for (arg, lit) in source_args(source, attrs) {
+                    match arg.as_str() {
+                        "rename" | "rename.serialize" => name = Some(lit.value()),
+                        "alias" | "rename.deserialize" => aliases.push(lit.value()),
+                        _ => {}
+                    }
+                }
+            }
+            Source::Strum => {
+                let mut serialize = vec![];
+                for (arg, lit) in source_args(source, attrs) {
+                    match arg.as_str() {
+                        "to_string" => name = Some(lit.value()),
+                        "serialize" => serialize.push(lit.value()),
+                        _ => {}
+                    }
+                }
+                if name.is_none() {
+                    // `strum` prefers the longest `serialize` value for output.
+                    name = serialize.iter().max_by_key(|s| s.len()).cloned();
+                }
+                aliases = serialize;
+            }
+        }
+        aliases.retain(|a| Some(a) != name.as_ref());
+        aliases.sort();
+        aliases.dedup();
+
+        (name, aliases)
+    }
+}
+
+/// Collects all the string arguments of the [`Source`] attributes among the
+/// provided [`syn::Attribute`]s.
+///
+/// Nested arguments (like `#[serde(rename(serialize = "..."))]`) are named via
+/// dot (`rename.serialize`). Any attributes not parseable as [`syn::Meta`] are
+/// ignored, as the [`Source`] derive macro reports about them on its own.
+fn source_args(source: Source, attrs: &[syn::Attribute]) -> Vec<(String, syn::LitStr)> {
+    let mut args = vec![];
+    for meta in filter_attrs(source.attr_name(), attrs).filter_map(|a| a.parse_meta().ok()) {
+        let list = if let syn::Meta::List(list) = meta {
+            list
+        } else {
+            continue;
+        };
+        for nested in list.nested {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => {
+                    if let (Some(ident), syn::Lit::Str(lit)) = (nv.path.get_ident(), nv.lit) {
+                        args.push((ident.to_string(), lit));
+                    }
+                }
+                syn::NestedMeta::Meta(syn::Meta::List(inner)) => {
+                    let outer = if let Some(ident) = inner.path.get_ident() {
+                        ident.to_string()
+                    } else {
+                        continue;
+                    };
+                    for nested in inner.nested {
+                        if let syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) = nested {
+                            if let (Some(ident), syn::Lit::Str(lit)) = (nv.path.get_ident(), nv.lit)
+                            {
+                                args.push((format!("{outer}.{ident}"), lit));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    args
+}
+
+#[cfg(test)]
+mod casing_tests {
+    use super::{Casing, Source};
+
+    #[test]
+    fn applies_like_serde() {
+        for (casing, input, expected) in [
+            (Casing::Lower, "HttpServer", "httpserver"),
+            (Casing::Upper, "HttpServer", "HTTPSERVER"),
+            (Casing::Pascal, "HttpServer", "HttpServer"),
+            (Casing::Camel, "HttpServer", "httpServer"),
+            (Casing::Snake, "HttpServer", "http_server"),
+            (Casing::Snake, "HTTPServer", "h_t_t_p_server"),
+            (Casing::ScreamingSnake, "HttpServer", "HTTP_SERVER"),
+        ] {
+            assert_eq!(casing.apply(Source::Serde, input), expected);
+        }
+    }
+
+    #[test]
+    fn applies_like_strum() {
+        for (casing, input, expected) in [
+            (Casing::Lower, "HttpServer", "httpserver"),
+            (Casing::Upper, "HttpServer", "HTTPSERVER"),
+            (Casing::Pascal, "HTTPServer", "HttpServer"),
+            (Casing::Camel, "HTTPServer", "httpServer"),
+            (Casing::Snake, "HTTPServer", "http_server"),
+            (Casing::Snake, "Version2Beta", "version2_beta"),
+            (Casing::ScreamingSnake, "HttpServer", "HTTP_SERVER"),
+        ] {
+            assert_eq!(casing.apply(Source::Strum, input), expected);
+        }
+    }
+}
@@ -3,10 +3,10 @@
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
-use quote::ToTokens as _;
+use quote::quote;
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned};
 
-use crate::common::{diagnostic, rename, scalar, SpanContainer};
+use crate::common::{diagnostic, parse::TypeExt as _, remote, rename, scalar, SpanContainer};
 
 use super::{ContainerAttr, Definition, FieldAttr, FieldDefinition};
 
@@ -27,7 +27,7 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
     let renaming = attr
         .rename_fields
         .map(SpanContainer::into_inner)
-        .unwrap_or(rename::Policy::CamelCase);
+        .unwrap_or_else(|| rename::Policy::default_for_fields(attr.is_internal));
 
     let is_internal = attr.is_internal;
     let fields = data
@@ -42,24 +42,26 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         return Err(ERR.custom_error(data.fields.span(), "expected at least 1 non-ignored field"));
     }
 
-    let unique_fields = fields.iter().map(|v| &v.name).collect::<HashSet<_>>();
-    if unique_fields.len() != fields.len() {
+    let named_fields = fields.iter().filter(|f| !f.flattened);
+    let unique_fields = named_fields
+        .clone()
+        .map(|f| &f.name)
+        .collect::<HashSet<_>>();
+    if unique_fields.len() != named_fields.count() {
         return Err(ERR.custom_error(
             data.fields.span(),
             "expected all fields to have unique names",
         ));
     }
 
-    let name = attr
-        .name
-        .clone()
-        .map(SpanContainer::into_inner)
+    let explicit_name = attr.input_name.as_ref().or(attr.name.as_ref());
+    let name = explicit_name
+        .map(|n| n.as_ref().clone())
         .unwrap_or_else(|| ast.ident.unraw().to_string())
         .into_boxed_str();
     if !attr.is_internal && name.starts_with("__") {
         ERR.no_double_underscore(
-            attr.name
-                .as_ref()
+            explicit_name
                 .map(SpanContainer::span_ident)
                 .unwrap_or_else(|| ast.ident.span()),
         );
@@ -73,6 +75,11 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 
     proc_macro_error::abort_if_dirty();
 
+    let into_remote = attr
+        .remote
+        .as_deref()
+        .map(|remote| remote::into_remote_tokens(&ast, remote));
+
     let definition = Definition {
         ident: ast.ident,
         generics: ast.generics,
@@ -81,9 +88,14 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         context,
         scalar,
         fields,
+        validate: attr.validate.map(SpanContainer::into_inner),
+        with_object: attr.input_name.is_some(),
     };
 
-    Ok(definition.into_token_stream())
+    Ok(quote! {
+        #definition
+        #into_remote
+    })
 }
 
 /// Parses a [`FieldDefinition`] from the given struct field definition.
@@ -98,6 +110,31 @@ fn parse_field(
         .map_err(|e| proc_macro_error::emit_error!(e))
         .ok()?;
 
+    if let Some(flatten) = &field_attr.flatten {
+        if field_attr.name.is_some()
+            || field_attr.default.is_some()
+            || field_attr.deprecated.is_some()
+            || field_attr.ignore.is_some()
+            || field_attr.validate.is_some()
+        {
+            ERR.emit_custom(
+                flatten.span_ident(),
+                "`flatten` attribute argument is not composable with any other arguments",
+            );
+        }
+    }
+
+    if let Some(deprecated) = &field_attr.deprecated {
+        let is_required = field_attr.default.is_none() && !f.ty.is_option();
+        if is_required && field_attr.ignore.is_none() && field_attr.flatten.is_none() {
+            ERR.emit_custom(
+                deprecated.span_ident(),
+                "required field cannot be deprecated, \
+                 make it `Option` or specify its `default` value",
+            );
+        }
+    }
+
     let ident = f.ident.as_ref().or_else(|| err_unnamed_field(f))?;
 
     let name = field_attr
@@ -117,7 +154,10 @@ fn parse_field(
         default: field_attr.default.map(SpanContainer::into_inner),
         name,
         description: field_attr.description.map(SpanContainer::into_inner),
+        deprecated: field_attr.deprecated.map(SpanContainer::into_inner),
         ignored: field_attr.ignore.is_some(),
+        validate: field_attr.validate.map(SpanContainer::into_inner),
+        flattened: field_attr.flatten.is_some(),
     })
 }
 
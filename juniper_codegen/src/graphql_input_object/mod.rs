@@ -15,7 +15,7 @@ use syn::{
 };
 
 use crate::common::{
-    default, filter_attrs,
+    default, deprecation, directive, filter_attrs, metadata,
     parse::{
         attr::{err, OptionExt as _},
         ParseBufferExt as _,
@@ -36,6 +36,16 @@ struct ContainerAttr {
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     name: Option<SpanContainer<String>>,
 
+    /// Explicitly specified name of this [GraphQL input object][0], taking
+    /// precedence over the [`ContainerAttr::name`].
+    ///
+    /// Allows to derive a [GraphQL object][1] and a [GraphQL input object][0]
+    /// on the same Rust struct, as their names should differ.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    input_name: Option<SpanContainer<String>>,
+
     /// Explicitly specified [description][2] of this [GraphQL input object][0].
     ///
     /// If [`None`], then Rust doc comment will be used as the [description][2],
@@ -69,12 +79,29 @@ struct ContainerAttr {
     /// Explicitly specified [`rename::Policy`] for all fields of this
     /// [GraphQL input object][0].
     ///
-    /// If [`None`], then the [`rename::Policy::CamelCase`] will be applied by
-    /// default.
+    /// If [`None`], then the [`rename::Policy::default_for_fields()`] will be
+    /// applied.
     ///
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     rename_fields: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified path of the function validating this
+    /// [GraphQL input object][0] once it's fully constructed.
+    ///
+    /// If [`None`], then no validation is performed.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    validate: Option<SpanContainer<syn::ExprPath>>,
+
+    /// Explicitly specified path of the remote type (defined in another crate)
+    /// whose definition this [GraphQL input object][0] mirrors.
+    ///
+    /// If [`Some`], then a [`From`] conversion of this type into the remote
+    /// one is generated.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    remote: Option<SpanContainer<syn::Path>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     is_internal: bool,
@@ -97,6 +124,17 @@ impl Parse for ContainerAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "input_name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.input_name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "desc" | "description" => {
                     input.parse::<token::Eq>()?;
                     let desc = input.parse::<Description>()?;
@@ -129,9 +167,37 @@ impl Parse for ContainerAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "validate" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::ExprPath>()?;
+                    out.validate
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "remote" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::Path>()?;
+                    out.remote
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "internal" => {
                     out.is_internal = true;
                 }
+                // Arguments of `#[derive(GraphQLObject)]` derived on the same
+                // struct, which are not relevant for input objects.
+                "impl" | "implements" | "interfaces" => {
+                    input.parse::<token::Eq>()?;
+                    let _ = input.parse_maybe_wrapped_and_punctuated::<
+                        syn::Type, token::Bracket, token::Comma,
+                    >()?;
+                }
+                "meta" => {
+                    let _ = input.parse::<metadata::Annotations>()?;
+                }
+                "directives" => {
+                    let _ = input.parse::<directive::Directives>()?;
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -148,10 +214,13 @@ impl ContainerAttr {
     fn try_merge(self, mut another: Self) -> syn::Result<Self> {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
+            input_name: try_merge_opt!(input_name: self, another),
             description: try_merge_opt!(description: self, another),
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             rename_fields: try_merge_opt!(rename_fields: self, another),
+            validate: try_merge_opt!(validate: self, another),
+            remote: try_merge_opt!(remote: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -205,6 +274,16 @@ struct FieldAttr {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<SpanContainer<Description>>,
 
+    /// Explicitly specified [deprecation][2] of this
+    /// [GraphQL input object field][1].
+    ///
+    /// If [`None`], then Rust `#[deprecated]` attribute will be used as the
+    /// [deprecation][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
+    deprecated: Option<SpanContainer<deprecation::Directive>>,
+
     /// Explicitly specified marker for the Rust struct field to be ignored and
     /// not included into the code generated for a [GraphQL input object][0]
     /// implementation.
@@ -215,6 +294,22 @@ struct FieldAttr {
     /// [`default`]: Self::default
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     ignore: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified path of the function validating the value of this
+    /// [GraphQL input object field][1] once it's converted.
+    ///
+    /// If [`None`], then no validation is performed.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    validate: Option<SpanContainer<syn::ExprPath>>,
+
+    /// Explicitly specified marker for the fields of the Rust struct field's
+    /// type to be flattened into the parent [GraphQL input object][0], rather
+    /// than representing a single [GraphQL input object field][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    flatten: Option<SpanContainer<syn::Ident>>,
 }
 
 impl Parse for FieldAttr {
@@ -247,10 +342,44 @@ impl Parse for FieldAttr {
                         .replace(SpanContainer::new(ident.span(), Some(desc.span()), desc))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "deprecated" => {
+                    let directive = input.parse::<deprecation::Directive>()?;
+                    out.deprecated
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            directive.reason.as_ref().map(|r| r.span()),
+                            directive,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ignore" | "skip" => out
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
                     .none_or_else(|_| err::dup_arg(&ident))?,
+                "validate" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::ExprPath>()?;
+                    out.validate
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "flatten" => out
+                    .flatten
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                // Arguments of `#[derive(GraphQLObject)]` derived on the same
+                // struct, which are not relevant for input object fields.
+                "guard" | "skip_if" | "skip_error" | "complexity" => {
+                    input.parse::<token::Eq>()?;
+                    let _ = input.parse::<syn::Expr>()?;
+                }
+                "delegate" => {}
+                "meta" => {
+                    let _ = input.parse::<metadata::Annotations>()?;
+                }
+                "directives" => {
+                    let _ = input.parse::<directive::Directives>()?;
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -269,7 +398,10 @@ impl FieldAttr {
             name: try_merge_opt!(name: self, another),
             default: try_merge_opt!(default: self, another),
             description: try_merge_opt!(description: self, another),
+            deprecated: try_merge_opt!(deprecated: self, another),
             ignore: try_merge_opt!(ignore: self, another),
+            validate: try_merge_opt!(validate: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
         })
     }
 
@@ -284,6 +416,10 @@ impl FieldAttr {
             attr.description = Description::parse_from_doc_attrs(attrs)?;
         }
 
+        if attr.deprecated.is_none() {
+            attr.deprecated = deprecation::Directive::parse_from_deprecated_attr(attrs)?;
+        }
+
         Ok(attr)
     }
 }
@@ -326,6 +462,13 @@ struct FieldDefinition {
     /// [2]: https://spec.graphql.org/October2021#sec-Descriptions
     description: Option<Description>,
 
+    /// [Deprecation][2] of this [GraphQL input object field][1] to put into
+    /// GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
+    deprecated: Option<deprecation::Directive>,
+
     /// Indicator whether the Rust struct field behinds this
     /// [GraphQL input object field][1] is being ignored and should not be
     /// included into the generated code.
@@ -336,6 +479,20 @@ struct FieldDefinition {
     /// [`default`]: Self::default
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     ignored: bool,
+
+    /// Path of the function validating the value of this
+    /// [GraphQL input object field][1] once it's converted.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    validate: Option<syn::ExprPath>,
+
+    /// Indicator whether the fields of the Rust struct field's type behind
+    /// this [GraphQL input object field][1] are flattened into the parent
+    /// [GraphQL input object][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    flattened: bool,
 }
 
 /// Representation of [GraphQL input object][0] for code generation.
@@ -388,17 +545,37 @@ struct Definition {
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     /// [1]: https://spec.graphql.org/October2021#InputFieldsDefinition
     fields: Vec<FieldDefinition>,
+    /// Path of the function validating this [GraphQL input object][0] once
+    /// it's fully constructed.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    validate: Option<syn::ExprPath>,
+
+    /// Indicator whether this [GraphQL input object][0] is represented by the
+    /// same Rust type as a [GraphQL object][1], generating the [`GraphQLType`]
+    /// implementation for both of them.
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    with_object: bool,
 }
 
 impl ToTokens for Definition {
     fn to_tokens(&self, into: &mut TokenStream) {
         self.impl_input_type_tokens().to_tokens(into);
-        self.impl_graphql_type_tokens().to_tokens(into);
-        self.impl_graphql_value_tokens().to_tokens(into);
-        self.impl_graphql_value_async_tokens().to_tokens(into);
+        if self.with_object {
+            self.impl_shared_input_object_tokens().to_tokens(into);
+        } else {
+            self.impl_graphql_type_tokens().to_tokens(into);
+            self.impl_graphql_value_tokens().to_tokens(into);
+            self.impl_graphql_value_async_tokens().to_tokens(into);
+        }
         self.impl_from_input_value_tokens().to_tokens(into);
         self.impl_to_input_value_tokens().to_tokens(into);
-        self.impl_reflection_traits_tokens().to_tokens(into);
+        if !self.with_object {
+            self.impl_reflection_traits_tokens().to_tokens(into);
+        }
     }
 }
 
@@ -455,23 +632,7 @@ impl Definition {
         let (impl_generics, _, where_clause) = generics.split_for_impl();
         let (_, ty_generics, _) = self.generics.split_for_impl();
 
-        let description = &self.description;
-
-        let fields = self.fields.iter().filter_map(|f| {
-            let ty = &f.ty;
-            let name = &f.name;
-
-            (!f.ignored).then(|| {
-                let arg = if let Some(default) = &f.default {
-                    quote! { .arg_with_default::<#ty>(#name, &#default, info) }
-                } else {
-                    quote! { .arg::<#ty>(#name, info) }
-                };
-                let description = &f.description;
-
-                quote! { registry #arg #description }
-            })
-        });
+        let meta = self.meta_tokens();
 
         quote! {
             #[automatically_derived]
@@ -490,16 +651,109 @@ impl Definition {
                 where
                     #scalar: 'r,
                 {
-                    let fields = [#( #fields ),*];
-                    registry
-                        .build_input_object_type::<#ident #ty_generics>(info, &fields)
-                        #description
-                        .into_meta()
+                    #meta
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`SharedInputObject`] trait for
+    /// this [GraphQL input object][0], represented by the same Rust type as a
+    /// [GraphQL object][1].
+    ///
+    /// [`SharedInputObject`]: juniper::macros::helper::SharedInputObject
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn impl_shared_input_object_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let scalar = &self.scalar;
+        let name = &self.name;
+
+        let generics = self.impl_generics(false);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let meta = self.meta_tokens();
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::helper::SharedInputObject<#scalar>
+                for #ident #ty_generics
+                #where_clause
+            {
+                const NAME: &'static str = #name;
+
+                fn meta<'r>(
+                    info: &(),
+                    registry: &mut ::juniper::Registry<'r, #scalar>,
+                ) -> ::juniper::meta::MetaType<'r, #scalar>
+                where
+                    #scalar: 'r,
+                {
+                    #meta
                 }
             }
         }
     }
 
+    /// Returns generated code of the [`GraphQLType::meta`] method body for
+    /// this [GraphQL input object][0], using `info` and `registry` variables.
+    ///
+    /// [`GraphQLType::meta`]: juniper::GraphQLType::meta
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    #[must_use]
+    fn meta_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let scalar = &self.scalar;
+
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let description = &self.description;
+
+        let fields = self.fields.iter().filter_map(|f| {
+            let ty = &f.ty;
+            let name = &f.name;
+
+            (!f.ignored && !f.flattened).then(|| {
+                let arg = if let Some(default) = &f.default {
+                    quote! { .arg_with_default::<#ty>(#name, &#default, info) }
+                } else {
+                    quote! { .arg::<#ty>(#name, info) }
+                };
+                let description = &f.description;
+                let deprecated = &f.deprecated;
+
+                quote! { registry #arg #description #deprecated }
+            })
+        });
+        let flattened_tys = self
+            .fields
+            .iter()
+            .filter_map(|f| (!f.ignored && f.flattened).then_some(&f.ty))
+            .collect::<Vec<_>>();
+        let fields = if flattened_tys.is_empty() {
+            quote! { let fields = [#( #fields ),*]; }
+        } else {
+            quote! {
+                let mut fields = ::std::vec![#( #fields ),*];
+                #( fields.extend(
+                    ::juniper::macros::helper::flattened_input_fields::<#flattened_tys, #scalar>(
+                        info, registry,
+                    ),
+                ); )*
+            }
+        };
+
+        quote! {
+            #fields
+            registry
+                .build_input_object_type::<#ident #ty_generics>(info, &fields)
+                #description
+                .into_meta()
+        }
+    }
+
     /// Returns generated code implementing [`GraphQLValue`] trait for this
     /// [GraphQL input object][0].
     ///
@@ -579,6 +833,11 @@ impl Definition {
                     },
                     |expr| quote! { #expr },
                 )
+            } else if f.flattened {
+                quote! {
+                    ::juniper::FromInputValue::<#scalar>::from_input_value(value)
+                        .map_err(::juniper::IntoFieldError::<#scalar>::into_field_error)?
+                }
             } else {
                 let name = &f.name;
 
@@ -586,26 +845,54 @@ impl Definition {
                     || {
                         quote! {
                             ::juniper::FromInputValue::<#scalar>::from_implicit_null()
-                                .map_err(::juniper::IntoFieldError::into_field_error)?
+                                .map_err(|e| {
+                                    ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
+                                        .at_input_value()
+                                        .prepend_input_path(#name)
+                                })?
                         }
                     },
                     |expr| quote! { #expr },
                 );
 
+                let validate = f.validate.as_ref().map(|validate| {
+                    quote! {
+                        #validate(&v).map_err(|e| {
+                            ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
+                                .at_input_value()
+                                .prepend_input_path(#name)
+                        })?;
+                    }
+                });
+
                 quote! {
-                    match obj.get(#name) {
+                    let v = match obj.get(#name) {
                         Some(v) => {
                             ::juniper::FromInputValue::<#scalar>::from_input_value(v)
-                                .map_err(::juniper::IntoFieldError::into_field_error)?
+                                .map_err(|e| {
+                                    ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
+                                        .at_input_value()
+                                        .prepend_input_path(#name)
+                                })?
                         }
                         None => { #fallback }
-                    }
+                    };
+                    #validate
+                    v
                 }
             };
 
             quote! { #ident: { #construct }, }
         });
 
+        let validate = self.validate.as_ref().map(|validate| {
+            quote! {
+                #validate(&value).map_err(|e| {
+                    ::juniper::IntoFieldError::<#scalar>::into_field_error(e).at_input_value()
+                })?;
+            }
+        });
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::FromInputValue<#scalar>
@@ -623,9 +910,12 @@ impl Definition {
                             ::std::format!("Expected input object, found: {}", value))
                         )?;
 
-                    Ok(#ident {
+                    #[allow(deprecated)]
+                    let value = #ident {
                         #( #fields )*
-                    })
+                    };
+                    #validate
+                    Ok(value)
                 }
             }
         }
@@ -649,12 +939,24 @@ impl Definition {
             let ident = &f.ident;
             let name = &f.name;
 
-            (!f.ignored).then(|| {
+            (!f.ignored && !f.flattened).then(|| {
                 quote! {
                     (#name, ::juniper::ToInputValue::to_input_value(&self.#ident))
                 }
             })
         });
+        let flattened = self.fields.iter().filter_map(|f| {
+            let ident = &f.ident;
+
+            (!f.ignored && f.flattened).then(|| {
+                quote! {
+                    ::juniper::macros::helper::extend_input_object(
+                        &mut value,
+                        ::juniper::ToInputValue::to_input_value(&self.#ident),
+                    );
+                }
+            })
+        });
 
         quote! {
             #[automatically_derived]
@@ -663,33 +965,73 @@ impl Definition {
                 #where_clause
             {
                 fn to_input_value(&self) -> ::juniper::InputValue<#scalar> {
-                    ::juniper::InputValue::object(
+                    #[allow(unused_mut)]
+                    let mut value = ::juniper::InputValue::object(
                         #[allow(deprecated)]
                         ::std::array::IntoIter::new([#( #fields ),*])
                             .collect()
-                    )
+                    );
+                    #( #flattened )*
+                    value
                 }
             }
         }
     }
 
-    /// Returns generated code implementing [`BaseType`], [`BaseSubTypes`] and
-    /// [`WrappedType`] traits for this [GraphQL input object][0].
+    /// Returns generated code implementing [`BaseType`], [`BaseSubTypes`],
+    /// [`WrappedType`] and [`Fields`] traits for this
+    /// [GraphQL input object][0].
+    ///
+    /// Also asserts the uniqueness of its [fields][1] names, if any of them are
+    /// flattened from other [GraphQL input objects][0].
     ///
     /// [`BaseSubTypes`]: juniper::macros::reflect::BaseSubTypes
     /// [`BaseType`]: juniper::macros::reflect::BaseType
+    /// [`Fields`]: juniper::macros::reflect::Fields
     /// [`WrappedType`]: juniper::macros::reflect::WrappedType
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#InputFieldsDefinition
     #[must_use]
     fn impl_reflection_traits_tokens(&self) -> TokenStream {
         let ident = &self.ident;
         let name = &self.name;
         let scalar = &self.scalar;
+        let const_scalar = scalar.default_ty();
 
         let generics = self.impl_generics(false);
         let (impl_generics, _, where_clause) = generics.split_for_impl();
         let (_, ty_generics, _) = self.generics.split_for_impl();
 
+        let field_names = self
+            .fields
+            .iter()
+            .filter_map(|f| (!f.ignored && !f.flattened).then_some(&f.name));
+        let flattened_tys = self
+            .fields
+            .iter()
+            .filter_map(|f| (!f.ignored && f.flattened).then_some(&f.ty))
+            .collect::<Vec<_>>();
+        // `const` items cannot refer to generic parameters, so names of the
+        // flattened fields are known for non-generic types only.
+        let (fields_names, assert_unique_fields) =
+            if flattened_tys.is_empty() || !self.generics.params.is_empty() {
+                (quote! { &[#( #field_names ),*] }, None)
+            } else {
+                (
+                    quote! {
+                        ::juniper::const_concat_names!(
+                            &[#( #field_names ),*],
+                            #( <#flattened_tys as ::juniper::macros::reflect::Fields<
+                                #const_scalar,
+                            >>::NAMES, )*
+                        )
+                    },
+                    Some(quote! {
+                        ::juniper::assert_unique_fields!(#ident, #const_scalar);
+                    }),
+                )
+            };
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::macros::reflect::BaseType<#scalar>
@@ -713,6 +1055,15 @@ impl Definition {
             {
                 const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
             }
+
+            impl #impl_generics ::juniper::macros::reflect::Fields<#scalar>
+                for #ident #ty_generics
+                #where_clause
+            {
+                const NAMES: ::juniper::macros::reflect::Names = #fields_names;
+            }
+
+            #assert_unique_fields
         }
     }
 
@@ -70,14 +70,20 @@ fn expand_on_trait(
         .rename_fields
         .as_deref()
         .copied()
-        .unwrap_or(rename::Policy::CamelCase);
+        .unwrap_or_else(|| rename::Policy::default_for_fields(attr.is_internal));
 
+    let mut default_fields = Vec::new();
     let fields = ast
         .items
         .iter_mut()
         .filter_map(|item| {
             if let syn::TraitItem::Method(m) = item {
-                return parse_trait_method(m, &renaming);
+                let has_default = m.default.is_some();
+                let field = parse_trait_method(m, &renaming)?;
+                if has_default {
+                    default_fields.push(field.name.clone());
+                }
+                return Some(field);
             }
             None
         })
@@ -111,6 +117,23 @@ fn expand_on_trait(
 
     let (enum_ident, enum_alias_ident) = enum_idents(trait_ident, attr.r#enum.as_deref());
 
+    if let Some(r#dyn) = &attr.r#dyn {
+        if !ast.generics.params.is_empty() {
+            ERR.emit_custom(
+                r#dyn.span_ident(),
+                "`dyn` attribute argument is not supported on generic traits",
+            );
+        }
+        for f in fields.iter().filter(|f| !f.has_receiver) {
+            ERR.emit_custom(
+                f.ident.span(),
+                "must have a `&self` receiver to be resolved via dynamic dispatch",
+            );
+        }
+    }
+
+    proc_macro_error::abort_if_dirty();
+
     let generated_code = Definition {
         generics: ast.generics.clone(),
         vis: ast.vis.clone(),
@@ -131,10 +154,39 @@ fn expand_on_trait(
             .into_iter()
             .map(SpanContainer::into_inner)
             .collect(),
+        inherited_fields: (!default_fields.is_empty())
+            .then(|| (trait_ident.clone(), default_fields)),
+        r#dyn: attr
+            .r#dyn
+            .map(|alias| (alias.into_inner(), trait_ident.clone())),
         suppress_dead_code: None,
         src_intra_doc_link: format!("trait@{trait_ident}").into_boxed_str(),
     };
 
+    if generated_code.r#dyn.is_some() {
+        // Trait objects are resolved by upcasting them into the implementer
+        // type, so the trait methods are transformed into boxed futures to
+        // keep the trait object safe.
+        let scalar = generated_code.scalar.default_ty();
+        let context = &generated_code.context;
+        ast.supertraits.push(parse_quote! {
+            ::juniper::macros::helper::AsDynGraphQLValue<
+                #scalar,
+                Context = #context,
+                TypeInfo = (),
+            >
+        });
+        let is_async_trait = ast.attrs.iter().any(|attr| {
+            attr.path
+                .segments
+                .last()
+                .map_or(false, |seg| seg.ident == "async_trait")
+        });
+        if !is_async_trait {
+            ast.attrs.push(parse_quote! { #[::juniper::async_trait] });
+        }
+    }
+
     Ok(quote! {
         #ast
         #generated_code
@@ -166,8 +218,83 @@ fn parse_trait_method(
         return None;
     }
 
-    if method.default.is_some() {
-        return err_default_impl_block(&method.default);
+    if let Some(validate_only) = &attr.validate_only {
+        ERR.emit_custom(
+            validate_only.span_ident(),
+            "`validate_only` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(map_err) = &attr.map_err {
+        ERR.emit_custom(
+            map_err.span_ident(),
+            "`map_err` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(generics) = &attr.generics {
+        ERR.emit_custom(
+            generics.span_ident(),
+            "`generics` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(input_arg) = &attr.input_arg {
+        ERR.emit_custom(
+            input_arg.span_ident(),
+            format!(
+                "`{}` attribute argument is supported only on \
+                 `#[derive(GraphQLInputObject)]` fields",
+                input_arg.as_ref(),
+            ),
+        );
+        return None;
+    }
+
+    if let Some(guard) = &attr.guard {
+        ERR.emit_custom(
+            guard.span_ident(),
+            "`guard` attribute argument is supported only on \
+             `#[graphql_object]` and `#[derive(GraphQLObject)]` fields",
+        );
+        return None;
+    }
+
+    if let Some(skip_if) = &attr.skip_if {
+        ERR.emit_custom(
+            skip_if.span_ident(),
+            "`skip_if` attribute argument is supported only on \
+             `#[graphql_object]` and `#[derive(GraphQLObject)]` fields",
+        );
+        return None;
+    }
+
+    if let Some(flatten) = &attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` struct fields",
+        );
+        return None;
+    }
+
+    if let Some(delegate) = &attr.delegate {
+        ERR.emit_custom(
+            delegate.span_ident(),
+            "`delegate` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` struct fields",
+        );
+        return None;
+    }
+
+    if method.default.is_some() && method.sig.asyncness.is_some() {
+        return err_async_default_impl_block(&method.default);
     }
 
     let name = attr
@@ -191,7 +318,10 @@ fn parse_trait_method(
         .iter_mut()
         .filter_map(|arg| match arg {
             syn::FnArg::Receiver(_) => None,
-            syn::FnArg::Typed(arg) => field::MethodArgument::parse(arg, renaming, &ERR),
+            syn::FnArg::Typed(arg) => {
+                let renaming = attr.rename_args.as_deref().unwrap_or(renaming);
+                field::MethodArgument::parse(arg, renaming, &ERR)
+            }
         })
         .collect();
 
@@ -210,6 +340,15 @@ fn parse_trait_method(
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
         is_async: method.sig.asyncness.is_some(),
+        validate_only: None,
+        guard: None,
+        skip_if: None,
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        map_err: None,
+        meta: attr.meta.map(SpanContainer::into_inner),
+        directives: attr.directives.map(SpanContainer::into_inner),
+        turbofish: None,
+        cfg_attrs: vec![],
     })
 }
 
@@ -249,6 +388,13 @@ fn expand_on_derive_input(
         );
     }
 
+    if let Some(r#dyn) = &attr.r#dyn {
+        ERR.emit_custom(
+            r#dyn.span_ident(),
+            "`dyn` attribute argument is supported only on traits",
+        );
+    }
+
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
     proc_macro_error::abort_if_dirty();
@@ -257,7 +403,7 @@ fn expand_on_derive_input(
         .rename_fields
         .as_deref()
         .copied()
-        .unwrap_or(rename::Policy::CamelCase);
+        .unwrap_or_else(|| rename::Policy::default_for_fields(attr.is_internal));
 
     let fields = data
         .fields
@@ -312,6 +458,8 @@ fn expand_on_derive_input(
             .into_iter()
             .map(SpanContainer::into_inner)
             .collect(),
+        inherited_fields: None,
+        r#dyn: None,
         suppress_dead_code: None,
         src_intra_doc_link: format!("struct@{struct_ident}").into_boxed_str(),
     };
@@ -348,6 +496,89 @@ fn parse_struct_field(
         return None;
     }
 
+    if let Some(validate_only) = &attr.validate_only {
+        ERR.emit_custom(
+            validate_only.span_ident(),
+            "`validate_only` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(map_err) = &attr.map_err {
+        ERR.emit_custom(
+            map_err.span_ident(),
+            "`map_err` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(generics) = &attr.generics {
+        ERR.emit_custom(
+            generics.span_ident(),
+            "`generics` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(input_arg) = &attr.input_arg {
+        ERR.emit_custom(
+            input_arg.span_ident(),
+            format!(
+                "`{}` attribute argument is supported only on \
+                 `#[derive(GraphQLInputObject)]` fields",
+                input_arg.as_ref(),
+            ),
+        );
+        return None;
+    }
+
+    if let Some(guard) = &attr.guard {
+        ERR.emit_custom(
+            guard.span_ident(),
+            "`guard` attribute argument is supported only on \
+             `#[graphql_object]` and `#[derive(GraphQLObject)]` fields",
+        );
+        return None;
+    }
+
+    if let Some(skip_if) = &attr.skip_if {
+        ERR.emit_custom(
+            skip_if.span_ident(),
+            "`skip_if` attribute argument is supported only on \
+             `#[graphql_object]` and `#[derive(GraphQLObject)]` fields",
+        );
+        return None;
+    }
+
+    if let Some(flatten) = &attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` struct fields",
+        );
+        return None;
+    }
+
+    if let Some(delegate) = &attr.delegate {
+        ERR.emit_custom(
+            delegate.span_ident(),
+            "`delegate` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` struct fields",
+        );
+        return None;
+    }
+
+    if let Some(rename_args) = &attr.rename_args {
+        ERR.emit_custom(
+            rename_args.span_ident(),
+            "`rename_args` attribute argument is supported only on methods",
+        );
+        return None;
+    }
+
     let name = attr
         .name
         .as_ref()
@@ -375,15 +606,24 @@ fn parse_struct_field(
         arguments: None,
         has_receiver: false,
         is_async: false,
+        validate_only: None,
+        guard: None,
+        skip_if: None,
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        map_err: None,
+        meta: attr.meta.map(SpanContainer::into_inner),
+        directives: attr.directives.map(SpanContainer::into_inner),
+        turbofish: None,
+        cfg_attrs: vec![],
     })
 }
 
-/// Emits "trait method can't have default implementation" [`syn::Error`]
+/// Emits "async trait method can't have default implementation" [`syn::Error`]
 /// pointing to the given `span`.
-fn err_default_impl_block<T, S: Spanned>(span: &S) -> Option<T> {
+fn err_async_default_impl_block<T, S: Spanned>(span: &S) -> Option<T> {
     ERR.emit_custom(
         span.span(),
-        "trait method can't have default implementation",
+        "async trait method can't have default implementation",
     );
     None
 }
@@ -40,6 +40,13 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         );
     }
 
+    if let Some(r#dyn) = &attr.r#dyn {
+        ERR.emit_custom(
+            r#dyn.span_ident(),
+            "`dyn` attribute argument is supported only on traits",
+        );
+    }
+
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
     proc_macro_error::abort_if_dirty();
@@ -48,7 +55,7 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         .rename_fields
         .as_deref()
         .copied()
-        .unwrap_or(rename::Policy::CamelCase);
+        .unwrap_or_else(|| rename::Policy::default_for_fields(attr.is_internal));
 
     let fields = data
         .fields
@@ -104,6 +111,8 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
             .into_iter()
             .map(SpanContainer::into_inner)
             .collect(),
+        inherited_fields: None,
+        r#dyn: None,
         suppress_dead_code: Some((ast.ident.clone(), data.fields.clone())),
         src_intra_doc_link: format!("struct@{struct_ident}").into_boxed_str(),
     }
@@ -125,6 +134,89 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         return None;
     }
 
+    if let Some(validate_only) = &attr.validate_only {
+        ERR.emit_custom(
+            validate_only.span_ident(),
+            "`validate_only` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(map_err) = &attr.map_err {
+        ERR.emit_custom(
+            map_err.span_ident(),
+            "`map_err` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(generics) = &attr.generics {
+        ERR.emit_custom(
+            generics.span_ident(),
+            "`generics` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(input_arg) = &attr.input_arg {
+        ERR.emit_custom(
+            input_arg.span_ident(),
+            format!(
+                "`{}` attribute argument is supported only on \
+                 `#[derive(GraphQLInputObject)]` fields",
+                input_arg.as_ref(),
+            ),
+        );
+        return None;
+    }
+
+    if let Some(guard) = &attr.guard {
+        ERR.emit_custom(
+            guard.span_ident(),
+            "`guard` attribute argument is supported only on \
+             `#[graphql_object]` and `#[derive(GraphQLObject)]` fields",
+        );
+        return None;
+    }
+
+    if let Some(skip_if) = &attr.skip_if {
+        ERR.emit_custom(
+            skip_if.span_ident(),
+            "`skip_if` attribute argument is supported only on \
+             `#[graphql_object]` and `#[derive(GraphQLObject)]` fields",
+        );
+        return None;
+    }
+
+    if let Some(flatten) = &attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` struct fields",
+        );
+        return None;
+    }
+
+    if let Some(delegate) = &attr.delegate {
+        ERR.emit_custom(
+            delegate.span_ident(),
+            "`delegate` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` struct fields",
+        );
+        return None;
+    }
+
+    if let Some(rename_args) = &attr.rename_args {
+        ERR.emit_custom(
+            rename_args.span_ident(),
+            "`rename_args` attribute argument is supported only on methods",
+        );
+        return None;
+    }
+
     let name = attr
         .name
         .as_ref()
@@ -152,5 +244,14 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         arguments: None,
         has_receiver: false,
         is_async: false,
+        validate_only: None,
+        guard: None,
+        skip_if: None,
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        map_err: None,
+        meta: attr.meta.map(SpanContainer::into_inner),
+        directives: attr.directives.map(SpanContainer::into_inner),
+        turbofish: None,
+        cfg_attrs: vec![],
     })
 }
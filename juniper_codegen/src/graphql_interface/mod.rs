@@ -79,6 +79,15 @@ struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
     r#enum: Option<SpanContainer<syn::Ident>>,
 
+    /// Explicitly specified identifier of the type alias of Rust trait object
+    /// behind the trait, allowing to resolve a [GraphQL interface][1] type via
+    /// dynamic dispatch.
+    ///
+    /// If [`None`], then no trait object is generated to be resolved.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    r#dyn: Option<SpanContainer<syn::Ident>>,
+
     /// Explicitly specified Rust types of [GraphQL objects][2] or
     /// [interfaces][1] implementing this [GraphQL interface][1] type.
     ///
@@ -126,8 +135,8 @@ struct Attr {
     /// Explicitly specified [`rename::Policy`] for all fields of this
     /// [GraphQL interface][1] type.
     ///
-    /// If [`None`], then the [`rename::Policy::CamelCase`] will be applied by
-    /// default.
+    /// If [`None`], then the [`rename::Policy::default_for_fields()`] will be
+    /// applied.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
     rename_fields: Option<SpanContainer<rename::Policy>>,
@@ -206,6 +215,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(alias.span()), alias))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "dyn" => {
+                    input.parse::<token::Eq>()?;
+                    let alias = input.parse::<syn::Ident>()?;
+                    out.r#dyn
+                        .replace(SpanContainer::new(ident.span(), Some(alias.span()), alias))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "async" => {
                     let span = ident.span();
                     out.asyncness
@@ -248,6 +264,7 @@ impl Attr {
             implemented_for: try_merge_hashset!(implemented_for: self, another => span_joined),
             implements: try_merge_hashset!(implements: self, another => span_joined),
             r#enum: try_merge_opt!(r#enum: self, another),
+            r#dyn: try_merge_opt!(r#dyn: self, another),
             asyncness: try_merge_opt!(asyncness: self, another),
             rename_fields: try_merge_opt!(rename_fields: self, another),
             is_internal: self.is_internal || another.is_internal,
@@ -341,6 +358,26 @@ struct Definition {
     /// [1]: https://spec.graphql.org/October2021#sel-GAHbhBDABAB_E-0b
     implements: Vec<syn::TypePath>,
 
+    /// Rust trait defining this [GraphQL interface][1], along with the names
+    /// of its [GraphQL fields][2] having a default implementation in it, and
+    /// so being inherited by the [`Implementer`]s not defining them on their
+    /// own.
+    ///
+    /// [`None`] if there are no such [GraphQL fields][2].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
+    inherited_fields: Option<(syn::Ident, Vec<String>)>,
+
+    /// Name of the type alias for the trait object of the Rust trait defining
+    /// this [GraphQL interface][1], along with the trait itself.
+    ///
+    /// [`None`] if this [GraphQL interface][1] isn't resolvable via dynamic
+    /// dispatch.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    r#dyn: Option<(syn::Ident, syn::Ident)>,
+
     /// Unlike `#[graphql_interface]` maro, `#[derive(GraphQLInterface)]` can't
     /// append `#[allow(dead_code)]` to the unused struct, representing
     /// [GraphQL interface][1]. We generate hacky `const` which doesn't actually
@@ -368,6 +405,9 @@ impl ToTokens for Definition {
         self.impl_field_meta_tokens().to_tokens(into);
         self.impl_field_tokens().to_tokens(into);
         self.impl_async_field_tokens().to_tokens(into);
+        self.impl_inherited_fields_tokens().to_tokens(into);
+        self.impl_async_inherited_fields_tokens().to_tokens(into);
+        self.impl_dyn_tokens().to_tokens(into);
     }
 }
 
@@ -983,6 +1023,16 @@ impl Definition {
                         _ => None,
                     })
                     .unzip();
+                let defaulted_args_names = field
+                    .arguments
+                    .iter()
+                    .flat_map(|vec| vec.iter())
+                    .filter_map(|arg| match arg {
+                        field::MethodArgument::Regular(arg) if arg.default.is_some() => {
+                            Some(&arg.name)
+                        }
+                        _ => None,
+                    });
 
                 quote! {
                     #[allow(non_snake_case)]
@@ -1008,6 +1058,8 @@ impl Definition {
                             <#args_tys as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
                             <#args_tys as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE,
                         ) ),*];
+                        const DEFAULTED_ARGUMENTS: ::juniper::macros::reflect::Names =
+                            &[#( #defaulted_args_names ),*];
                     }
                 }
             })
@@ -1058,6 +1110,33 @@ impl Definition {
                     quote! { _ => unreachable!() }
                 });
 
+                let resolvers = const_implemented_for.iter().map(|impl_for| {
+                    if self.is_inherited(field) {
+                        // Implementers may either define the inherited field
+                        // on their own, or inherit it, so it's resolved by
+                        // name.
+                        quote! {{
+                            ::juniper::GraphQLValue::<#scalar>::resolve_field(
+                                v, info, #field_name, args, executor,
+                            )
+                        }}
+                    } else {
+                        quote! {{
+                            ::juniper::assert_field!(
+                                #ty #const_ty_generics,
+                                #impl_for,
+                                #const_scalar,
+                                #field_name,
+                            );
+
+                            <_ as ::juniper::macros::reflect::Field::<
+                                #scalar,
+                                { ::juniper::macros::reflect::fnv1a128(#field_name) },
+                            >>::call(v, info, args, executor)
+                        }}
+                    }
+                });
+
                 quote_spanned! { field.ident.span() =>
                     #[allow(non_snake_case)]
                     #[automatically_derived]
@@ -1072,19 +1151,7 @@ impl Definition {
                             executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
                         ) -> ::juniper::ExecutionResult<#scalar> {
                             match self {
-                                #( #ty::#implemented_for_idents(v) => {
-                                    ::juniper::assert_field!(
-                                        #ty #const_ty_generics,
-                                        #const_implemented_for,
-                                        #const_scalar,
-                                        #field_name,
-                                    );
-
-                                    <_ as ::juniper::macros::reflect::Field::<
-                                        #scalar,
-                                        { ::juniper::macros::reflect::fnv1a128(#field_name) },
-                                    >>::call(v, info, args, executor)
-                                } )*
+                                #( #ty::#implemented_for_idents(v) => #resolvers )*
                                 #unreachable_arm
                             }
                         }
@@ -1138,6 +1205,33 @@ impl Definition {
                     quote! { _ => unreachable!() }
                 });
 
+                let resolvers = const_implemented_for.iter().map(|impl_for| {
+                    if self.is_inherited(field) {
+                        // Implementers may either define the inherited field
+                        // on their own, or inherit it, so it's resolved by
+                        // name.
+                        quote! {{
+                            ::juniper::GraphQLValueAsync::<#scalar>::resolve_field_async(
+                                v, info, #field_name, args, executor,
+                            )
+                        }}
+                    } else {
+                        quote! {{
+                            ::juniper::assert_field!(
+                                #ty #const_ty_generics,
+                                #impl_for,
+                                #const_scalar,
+                                #field_name,
+                            );
+
+                            <_ as ::juniper::macros::reflect::AsyncField<
+                                #scalar,
+                                { ::juniper::macros::reflect::fnv1a128(#field_name) },
+                            >>::call(v, info, args, executor)
+                        }}
+                    }
+                });
+
                 quote_spanned! { field.ident.span() =>
                     #[allow(non_snake_case)]
                     #[automatically_derived]
@@ -1152,19 +1246,7 @@ impl Definition {
                             executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
                         ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
                             match self {
-                                #( #ty::#implemented_for_idents(v) => {
-                                    ::juniper::assert_field!(
-                                        #ty #const_ty_generics,
-                                        #const_implemented_for,
-                                        #const_scalar,
-                                        #field_name,
-                                    );
-
-                                    <_ as ::juniper::macros::reflect::AsyncField<
-                                        #scalar,
-                                        { ::juniper::macros::reflect::fnv1a128(#field_name) },
-                                    >>::call(v, info, args, executor)
-                                } )*
+                                #( #ty::#implemented_for_idents(v) => #resolvers )*
                                 #unreachable_arm
                             }
                         }
@@ -1174,6 +1256,440 @@ impl Definition {
             .collect()
     }
 
+    /// Returns generated code implementing [`InheritedFields`] trait for this
+    /// [GraphQL interface][1] and any of its [`Implementer`]s.
+    ///
+    /// [`InheritedFields`]: juniper::macros::reflect::InheritedFields
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    #[must_use]
+    fn impl_inherited_fields_tokens(&self) -> TokenStream {
+        let ty = &self.enum_alias_ident;
+        let scalar = &self.scalar;
+        let name = &self.name;
+
+        let generics = self.impl_inherited_fields_generics(false);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let names = self.inherited_fields().map(|f| &f.name);
+        let resolvers = self.inherited_fields().map(|field| {
+            let (field_name, ty) = (&field.name, &field.ty);
+            let call = self.method_inherited_field_call_tokens(field, false);
+            let resolving_code = gen::sync_resolving_code();
+            quote! {
+                #field_name => {
+                    let res: #ty = #call;
+                    #resolving_code
+                }
+            }
+        });
+
+        quote! {
+            #[allow(deprecated, non_snake_case)]
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::reflect::InheritedFields<#scalar, __Impl>
+                for #ty #ty_generics
+                #where_clause
+            {
+                const NAMES: ::juniper::macros::reflect::Names = &[#( #names ),*];
+
+                fn resolve_field(
+                    obj: &__Impl,
+                    info: &<__Impl as ::juniper::GraphQLValue<#scalar>>::TypeInfo,
+                    field: &str,
+                    args: &::juniper::Arguments<'_, #scalar>,
+                    executor: &::juniper::Executor<
+                        '_,
+                        '_,
+                        <__Impl as ::juniper::GraphQLValue<#scalar>>::Context,
+                        #scalar,
+                    >,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    let _ = (obj, info, args, executor);
+                    match field {
+                        #( #resolvers )*
+                        _ => Err(::juniper::FieldError::from(::std::format!(
+                            "Field `{}` is not inherited from interface `{}`",
+                            field,
+                            #name,
+                        ))),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`AsyncInheritedFields`] trait for
+    /// this [GraphQL interface][1] and any of its [`Implementer`]s.
+    ///
+    /// [`AsyncInheritedFields`]: juniper::macros::reflect::AsyncInheritedFields
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    #[must_use]
+    fn impl_async_inherited_fields_tokens(&self) -> TokenStream {
+        let ty = &self.enum_alias_ident;
+        let scalar = &self.scalar;
+        let name = &self.name;
+
+        let generics = self.impl_inherited_fields_generics(true);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let resolvers = self.inherited_fields().map(|field| {
+            let (field_name, ty) = (&field.name, &field.ty);
+            let call = self.method_inherited_field_call_tokens(field, true);
+            let resolving_code = gen::async_resolving_code(Some(ty));
+            quote! {
+                #field_name => {
+                    let fut = ::juniper::futures::future::ready(#call);
+                    #resolving_code
+                }
+            }
+        });
+
+        quote! {
+            #[allow(deprecated, non_snake_case)]
+            #[automatically_derived]
+            impl #impl_generics ::juniper::macros::reflect::AsyncInheritedFields<#scalar, __Impl>
+                for #ty #ty_generics
+                #where_clause
+            {
+                fn resolve_field_async<'b>(
+                    obj: &'b __Impl,
+                    info: &'b <__Impl as ::juniper::GraphQLValue<#scalar>>::TypeInfo,
+                    field: &'b str,
+                    args: &'b ::juniper::Arguments<'_, #scalar>,
+                    executor: &'b ::juniper::Executor<
+                        '_,
+                        '_,
+                        <__Impl as ::juniper::GraphQLValue<#scalar>>::Context,
+                        #scalar,
+                    >,
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    let _ = (obj, info, args, executor);
+                    match field {
+                        #( #resolvers )*
+                        _ => Box::pin(async move {
+                            Err(::juniper::FieldError::from(::std::format!(
+                                "Field `{}` is not inherited from interface `{}`",
+                                field,
+                                #name,
+                            )))
+                        }),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns generated code of the type alias for the trait object of this
+    /// [GraphQL interface][1], along with all the trait implementations
+    /// required to resolve it via dynamic dispatch.
+    ///
+    /// Returns [`None`] if this [GraphQL interface][1] isn't resolvable via
+    /// dynamic dispatch.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    #[must_use]
+    fn impl_dyn_tokens(&self) -> Option<TokenStream> {
+        let (alias_ident, trait_ident) = self.r#dyn.as_ref()?;
+        let vis = &self.vis;
+        let enum_ty = &self.enum_alias_ident;
+        let scalar = self.scalar.default_ty();
+        let context = &self.context;
+        let name = &self.name;
+
+        let ty = quote! { dyn #trait_ident + '__obj + Send + Sync };
+
+        let alias_doc = format!(
+            "Trait object of [`{}`]({}) [GraphQL interface][0], resolved via \
+             dynamic dispatch.\
+             \n\n\
+             [0]: https://spec.graphql.org/October2021#sec-Interfaces",
+            self.name, self.src_intra_doc_link,
+        );
+
+        Some(quote! {
+            #[automatically_derived]
+            #[doc = #alias_doc]
+            #vis type #alias_ident = dyn #trait_ident + Send + Sync;
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::marker::GraphQLInterface<#scalar> for #ty {
+                fn mark() {
+                    <#enum_ty as ::juniper::marker::GraphQLInterface<#scalar>>::mark()
+                }
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::marker::IsOutputType<#scalar> for #ty {
+                fn mark() {
+                    <#enum_ty as ::juniper::marker::IsOutputType<#scalar>>::mark()
+                }
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::GraphQLType<#scalar> for #ty {
+                fn name(_ : &Self::TypeInfo) -> Option<&'static str> {
+                    Some(#name)
+                }
+
+                fn meta<'r>(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<'r, #scalar>
+                ) -> ::juniper::meta::MetaType<'r, #scalar>
+                where #scalar: 'r,
+                {
+                    <#enum_ty as ::juniper::GraphQLType<#scalar>>::meta(info, registry)
+                }
+            }
+
+            #[allow(deprecated)]
+            #[automatically_derived]
+            impl<'__obj> ::juniper::GraphQLValue<#scalar> for #ty {
+                type Context = #context;
+                type TypeInfo = ();
+
+                fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                    <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+                }
+
+                fn resolve_field(
+                    &self,
+                    info: &Self::TypeInfo,
+                    field: &str,
+                    args: &::juniper::Arguments<'_, #scalar>,
+                    executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    ::juniper::GraphQLValue::<#scalar>::resolve_field(
+                        ::juniper::macros::helper::AsDynGraphQLValue::<#scalar>
+                            ::as_dyn_graphql_value(self),
+                        info,
+                        field,
+                        args,
+                        executor,
+                    )
+                }
+
+                fn concrete_type_name(
+                    &self,
+                    context: &Self::Context,
+                    info: &Self::TypeInfo,
+                ) -> String {
+                    ::juniper::GraphQLValue::<#scalar>::concrete_type_name(
+                        ::juniper::macros::helper::AsDynGraphQLValue::<#scalar>
+                            ::as_dyn_graphql_value(self),
+                        context,
+                        info,
+                    )
+                }
+
+                fn resolve_into_type(
+                    &self,
+                    info: &Self::TypeInfo,
+                    _: &str,
+                    _: Option<&[::juniper::Selection<'_, #scalar>]>,
+                    executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    executor.resolve(
+                        info,
+                        ::juniper::macros::helper::AsDynGraphQLValue::<#scalar>
+                            ::as_dyn_graphql_value(self),
+                    )
+                }
+            }
+
+            #[allow(deprecated)]
+            #[automatically_derived]
+            impl<'__obj> ::juniper::GraphQLValueAsync<#scalar> for #ty {
+                fn resolve_field_async<'b>(
+                    &'b self,
+                    info: &'b Self::TypeInfo,
+                    field: &'b str,
+                    args: &'b ::juniper::Arguments<'_, #scalar>,
+                    executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    ::juniper::GraphQLValueAsync::<#scalar>::resolve_field_async(
+                        ::juniper::macros::helper::AsDynGraphQLValue::<#scalar>
+                            ::as_dyn_graphql_value_async(self),
+                        info,
+                        field,
+                        args,
+                        executor,
+                    )
+                }
+
+                fn resolve_into_type_async<'b>(
+                    &'b self,
+                    info: &'b Self::TypeInfo,
+                    _: &str,
+                    _: Option<&'b [::juniper::Selection<'b, #scalar>]>,
+                    executor: &'b ::juniper::Executor<'b, 'b, Self::Context, #scalar>
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    Box::pin(executor.resolve_async(
+                        info,
+                        ::juniper::macros::helper::AsDynGraphQLValue::<#scalar>
+                            ::as_dyn_graphql_value_async(self),
+                    ))
+                }
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::macros::reflect::BaseType<#scalar> for #ty {
+                const NAME: ::juniper::macros::reflect::Type = #name;
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::macros::reflect::BaseSubTypes<#scalar> for #ty {
+                const NAMES: ::juniper::macros::reflect::Types =
+                    <#enum_ty as ::juniper::macros::reflect::BaseSubTypes<#scalar>>::NAMES;
+                const OPEN: bool = true;
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::macros::reflect::Implements<#scalar> for #ty {
+                const NAMES: ::juniper::macros::reflect::Types =
+                    <#enum_ty as ::juniper::macros::reflect::Implements<#scalar>>::NAMES;
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::macros::reflect::WrappedType<#scalar> for #ty {
+                const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+            }
+
+            #[automatically_derived]
+            impl<'__obj> ::juniper::macros::reflect::Fields<#scalar> for #ty {
+                const NAMES: ::juniper::macros::reflect::Names =
+                    <#enum_ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES;
+            }
+
+            #[automatically_derived]
+            impl<'__obj, __Impl> ::juniper::macros::reflect::InheritedFields<#scalar, __Impl>
+                for #ty
+            where
+                __Impl: ::juniper::GraphQLValue<#scalar>,
+                #enum_ty: ::juniper::macros::reflect::InheritedFields<#scalar, __Impl>,
+            {
+                const NAMES: ::juniper::macros::reflect::Names = <
+                    #enum_ty as ::juniper::macros::reflect::InheritedFields<#scalar, __Impl>
+                >::NAMES;
+
+                fn resolve_field(
+                    obj: &__Impl,
+                    info: &<__Impl as ::juniper::GraphQLValue<#scalar>>::TypeInfo,
+                    field: &str,
+                    args: &::juniper::Arguments<'_, #scalar>,
+                    executor: &::juniper::Executor<
+                        '_,
+                        '_,
+                        <__Impl as ::juniper::GraphQLValue<#scalar>>::Context,
+                        #scalar,
+                    >,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    <#enum_ty as ::juniper::macros::reflect::InheritedFields<#scalar, __Impl>>
+                        ::resolve_field(obj, info, field, args, executor)
+                }
+            }
+
+            #[automatically_derived]
+            impl<'__obj, __Impl> ::juniper::macros::reflect::AsyncInheritedFields<#scalar, __Impl>
+                for #ty
+            where
+                __Impl: ::juniper::GraphQLValue<#scalar>,
+                #enum_ty: ::juniper::macros::reflect::AsyncInheritedFields<#scalar, __Impl>,
+            {
+                fn resolve_field_async<'b>(
+                    obj: &'b __Impl,
+                    info: &'b <__Impl as ::juniper::GraphQLValue<#scalar>>::TypeInfo,
+                    field: &'b str,
+                    args: &'b ::juniper::Arguments<'_, #scalar>,
+                    executor: &'b ::juniper::Executor<
+                        '_,
+                        '_,
+                        <__Impl as ::juniper::GraphQLValue<#scalar>>::Context,
+                        #scalar,
+                    >,
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    <#enum_ty as ::juniper::macros::reflect::AsyncInheritedFields<#scalar, __Impl>>
+                        ::resolve_field_async(obj, info, field, args, executor)
+                }
+            }
+        })
+    }
+
+    /// Returns generated code calling the default implementation of the
+    /// provided inherited [GraphQL field][1] on an `obj` [`Implementer`].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    #[must_use]
+    fn method_inherited_field_call_tokens(
+        &self,
+        field: &field::Definition,
+        for_async: bool,
+    ) -> TokenStream {
+        let scalar = &self.scalar;
+        let ident = &field.ident;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let trait_ident = self.inherited_fields.as_ref().map(|(ident, _)| ident);
+
+        let args = field
+            .arguments
+            .iter()
+            .flatten()
+            .map(|arg| arg.method_resolve_field_tokens(scalar, for_async));
+        let rcv = field.has_receiver.then(|| {
+            quote! { obj, }
+        });
+
+        quote! { <__Impl as #trait_ident #ty_generics>::#ident(#rcv #( #args ),*) }
+    }
+
+    /// Returns prepared [`syn::Generics`] for [`InheritedFields`] trait (and
+    /// similar) implementation of this enum, parametrized by the `__Impl`
+    /// [`Implementer`] type.
+    ///
+    /// [`InheritedFields`]: juniper::macros::reflect::InheritedFields
+    #[must_use]
+    fn impl_inherited_fields_generics(&self, for_async: bool) -> syn::Generics {
+        let scalar = &self.scalar;
+        let context = &self.context;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let mut generics = self.impl_generics(for_async);
+        generics.params.push(parse_quote! { __Impl });
+
+        let async_bound = for_async.then(|| {
+            quote! { ::juniper::GraphQLValueAsync<#scalar> + }
+        });
+        let trait_bound = self
+            .inherited_fields
+            .as_ref()
+            .map(|(trait_ident, _)| quote! { + #trait_ident #ty_generics });
+        generics.make_where_clause().predicates.push(parse_quote! {
+            __Impl: #async_bound
+                ::juniper::GraphQLValue<#scalar, Context = #context, TypeInfo = ()>
+                #trait_bound
+        });
+
+        generics
+    }
+
+    /// Returns [`field::Definition`]s of this [GraphQL interface][1] having a
+    /// default implementation.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+    fn inherited_fields(&self) -> impl Iterator<Item = &field::Definition> {
+        self.fields.iter().filter(|f| self.is_inherited(f))
+    }
+
+    /// Indicates whether the provided [`field::Definition`] has a default
+    /// implementation, being inherited by the [`Implementer`]s.
+    #[must_use]
+    fn is_inherited(&self, field: &field::Definition) -> bool {
+        self.inherited_fields
+            .as_ref()
+            .map_or(false, |(_, names)| names.contains(&field.name))
+    }
+
     /// Returns generated code for the [`GraphQLValue::concrete_type_name`][0]
     /// method, which returns name of the underlying [`implementers`][1] GraphQL
     /// type contained in this enum.
@@ -7,7 +7,7 @@ use quote::{quote, ToTokens};
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned};
 
 use crate::common::{
-    diagnostic, field,
+    diagnostic, field, filter_attrs,
     parse::{self, TypeExt as _},
     path_eq_single, rename, scalar, SpanContainer,
 };
@@ -61,6 +61,25 @@ where
         );
     }
 
+    if let Some(input_arg) = &attr.input_arg {
+        ERR.emit_custom(
+            input_arg.span_ident(),
+            format!(
+                "`{}` attribute argument is supported only on structs deriving \
+                 `GraphQLInputObject`",
+                input_arg.as_ref(),
+            ),
+        );
+    }
+
+    if let Some(remote) = &attr.remote {
+        ERR.emit_custom(
+            remote.span_ident(),
+            "`remote` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` structs",
+        );
+    }
+
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
     proc_macro_error::abort_if_dirty();
@@ -69,7 +88,7 @@ where
         .rename_fields
         .as_deref()
         .copied()
-        .unwrap_or(rename::Policy::CamelCase);
+        .unwrap_or_else(|| rename::Policy::default_for_fields(attr.is_internal));
 
     let async_only = TypeId::of::<Operation>() != TypeId::of::<Query>();
     let fields: Vec<_> = ast
@@ -77,7 +96,7 @@ where
         .iter_mut()
         .filter_map(|item| {
             if let syn::ImplItem::Method(m) = item {
-                parse_field(m, async_only, &renaming)
+                parse_field(m, async_only, &renaming, &scalar)
             } else {
                 None
             }
@@ -118,11 +137,15 @@ where
         context,
         scalar,
         fields,
+        flattened: vec![],
         interfaces: attr
             .interfaces
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        meta: attr.meta.map(SpanContainer::into_inner),
+        directives: attr.directives.map(SpanContainer::into_inner),
+        with_input_object: false,
         _operation: PhantomData,
     };
 
@@ -140,6 +163,7 @@ fn parse_field(
     method: &mut syn::ImplItemMethod,
     async_only: bool,
     renaming: &rename::Policy,
+    scalar: &scalar::Type,
 ) -> Option<field::Definition> {
     let method_attrs = method.attrs.clone();
 
@@ -157,6 +181,36 @@ fn parse_field(
         return None;
     }
 
+    if let Some(flatten) = &attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` struct fields",
+        );
+        return None;
+    }
+
+    if let Some(delegate) = &attr.delegate {
+        ERR.emit_custom(
+            delegate.span_ident(),
+            "`delegate` attribute argument is supported only on \
+             `#[derive(GraphQLObject)]` struct fields",
+        );
+        return None;
+    }
+
+    if let Some(input_arg) = &attr.input_arg {
+        ERR.emit_custom(
+            input_arg.span_ident(),
+            format!(
+                "`{}` attribute argument is supported only on \
+                 `#[derive(GraphQLInputObject)]` fields",
+                input_arg.as_ref(),
+            ),
+        );
+        return None;
+    }
+
     if async_only && method.sig.asyncness.is_none() {
         return err_no_sync_resolvers(&method.sig);
     }
@@ -178,7 +232,9 @@ fn parse_field(
         return None;
     }
 
-    let arguments = {
+    let generics = method_generics(&method.sig.generics, attr.generics.as_ref(), scalar)?;
+
+    let mut arguments: Vec<_> = {
         if let Some(arg) = method.sig.inputs.first() {
             match arg {
                 syn::FnArg::Receiver(rcv) => {
@@ -201,7 +257,10 @@ fn parse_field(
             .iter_mut()
             .filter_map(|arg| match arg {
                 syn::FnArg::Receiver(_) => None,
-                syn::FnArg::Typed(arg) => field::MethodArgument::parse(arg, renaming, &ERR),
+                syn::FnArg::Typed(arg) => {
+                    let renaming = attr.rename_args.as_deref().unwrap_or(renaming);
+                    field::MethodArgument::parse(arg, renaming, &ERR)
+                }
             })
             .collect()
     };
@@ -212,6 +271,30 @@ fn parse_field(
     };
     ty.lifetimes_anonymized();
 
+    let substitutions = generics
+        .iter()
+        .filter_map(|(param, ty)| Some((param.clone(), ty.clone()?)))
+        .collect::<Vec<_>>();
+    ty.type_params_substituted(&substitutions);
+    if attr.map_err.is_some() {
+        ty = parse_quote! { ::juniper::macros::helper::MapErr<#ty, #scalar> };
+    }
+    for arg in &mut arguments {
+        match arg {
+            field::MethodArgument::Regular(arg) => arg.ty.type_params_substituted(&substitutions),
+            field::MethodArgument::Context(ctx) => ctx.type_params_substituted(&substitutions),
+            field::MethodArgument::Executor => {}
+        }
+    }
+    // Type parameters left unresolved are inferred from the method's usage.
+    let turbofish = (!substitutions.is_empty()).then(|| {
+        let tys = generics.iter().map(|(_, ty)| match ty {
+            Some(ty) => quote! { #ty },
+            None => quote! { _ },
+        });
+        parse_quote! { ::<#( #tys ),*> }
+    });
+
     Some(field::Definition {
         name,
         ty,
@@ -221,9 +304,103 @@ fn parse_field(
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
         is_async: method.sig.asyncness.is_some(),
+        validate_only: attr.validate_only.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
+        skip_if: attr.skip_if.map(|pred| {
+            (
+                pred.into_inner(),
+                attr.skip_error.map(SpanContainer::into_inner),
+            )
+        }),
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        map_err: attr.map_err.map(SpanContainer::into_inner),
+        meta: attr.meta.map(SpanContainer::into_inner),
+        directives: attr.directives.map(SpanContainer::into_inner),
+        turbofish,
+        cfg_attrs: filter_attrs("cfg", &method_attrs).cloned().collect(),
     })
 }
 
+/// Resolves the Rust types to monomorphize the generic type parameters of a
+/// method representing a [GraphQL field][1] with, in their declaration order.
+///
+/// Type parameters specified via `#[graphql(generics(T = Type))]` attribute
+/// argument are substituted with the specified types, and the ones bounded by
+/// [`ScalarValue`] are substituted with the `scalar` of the
+/// [GraphQL object][2]. All the other ones are left to be inferred.
+///
+/// Returns [`None`] if the method has unsupported or unknown generic
+/// parameters.
+///
+/// [`ScalarValue`]: juniper::ScalarValue
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
+#[must_use]
+fn method_generics(
+    generics: &syn::Generics,
+    explicit: Option<&SpanContainer<Vec<(syn::Ident, syn::Type)>>>,
+    scalar: &scalar::Type,
+) -> Option<Vec<(syn::Ident, Option<syn::Type>)>> {
+    let explicit = explicit.map(|e| e.as_slice()).unwrap_or_default();
+    if let Some((param, _)) = explicit
+        .iter()
+        .find(|(param, _)| !generics.type_params().any(|tp| tp.ident == *param))
+    {
+        ERR.emit_custom(param.span(), "unknown generic type parameter of the method");
+        return None;
+    }
+
+    let mut out = Vec::new();
+    for param in &generics.params {
+        match param {
+            syn::GenericParam::Lifetime(_) => {}
+            syn::GenericParam::Const(c) => {
+                ERR.emit_custom(
+                    c.ident.span(),
+                    "const generic parameters are not supported on methods",
+                );
+                return None;
+            }
+            syn::GenericParam::Type(tp) => {
+                let ty = explicit
+                    .iter()
+                    .find_map(|(p, ty)| (*p == tp.ident).then(|| ty.clone()))
+                    .or_else(|| {
+                        is_scalar_bounded(tp, generics.where_clause.as_ref()).then(|| scalar.ty())
+                    });
+                out.push((tp.ident.clone(), ty));
+            }
+        }
+    }
+    Some(out)
+}
+
+/// Checks whether the given generic type parameter is bounded by the
+/// [`ScalarValue`] trait, either inline or in the `where` clause.
+///
+/// [`ScalarValue`]: juniper::ScalarValue
+#[must_use]
+fn is_scalar_bounded(param: &syn::TypeParam, where_clause: Option<&syn::WhereClause>) -> bool {
+    let is_scalar_bound = |bound: &syn::TypeParamBound| {
+        matches!(
+            bound,
+            syn::TypeParamBound::Trait(b)
+                if b.path.segments.last().map_or(false, |s| s.ident == "ScalarValue"),
+        )
+    };
+
+    param.bounds.iter().any(is_scalar_bound)
+        || where_clause.map_or(false, |w| {
+            w.predicates.iter().any(|pred| match pred {
+                syn::WherePredicate::Type(pred) => {
+                    matches!(&pred.bounded_ty, syn::Type::Path(p) if p.path.is_ident(&param.ident))
+                        && pred.bounds.iter().any(is_scalar_bound)
+                }
+                _ => false,
+            })
+        })
+}
+
 /// Emits "invalid method receiver" [`syn::Error`] pointing to the given `span`.
 #[must_use]
 fn err_invalid_method_receiver<T, S: Spanned>(span: &S) -> Option<T> {
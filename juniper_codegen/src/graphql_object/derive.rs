@@ -4,12 +4,14 @@ use std::marker::PhantomData;
 
 use proc_macro2::TokenStream;
 use proc_macro_error::ResultExt as _;
-use quote::ToTokens;
+use quote::quote;
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
 
-use crate::common::{diagnostic, field, parse::TypeExt as _, rename, scalar, SpanContainer};
+use crate::common::{
+    diagnostic, field, parse::TypeExt as _, remote, rename, scalar, SpanContainer,
+};
 
-use super::{Attr, Definition, Query};
+use super::{Attr, Definition, Flattened, Query};
 
 /// [`diagnostic::Scope`] of errors for `#[derive(GraphQLObject)]` macro.
 const ERR: diagnostic::Scope = diagnostic::Scope::ObjectDerive;
@@ -19,17 +21,25 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
     let ast = syn::parse2::<syn::DeriveInput>(input).unwrap_or_abort();
 
     match &ast.data {
-        syn::Data::Struct(_) => expand_struct(ast),
+        syn::Data::Struct(_) => {
+            let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+            let from_remote = attr
+                .remote
+                .as_deref()
+                .map(|remote| remote::from_remote_tokens(&ast, remote));
+            let definition = expand_struct(attr, ast)?;
+            Ok(quote! {
+                #definition
+                #from_remote
+            })
+        }
         _ => Err(ERR.custom_error(ast.span(), "can only be derived for structs")),
     }
-    .map(ToTokens::into_token_stream)
 }
 
 /// Expands into generated code a `#[derive(GraphQLObject)]` macro placed on a
 /// Rust struct.
-fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
-    let attr = Attr::from_attrs("graphql", &ast.attrs)?;
-
+fn expand_struct(attr: Attr, ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
     let struct_span = ast.span();
     let struct_ident = ast.ident;
 
@@ -58,26 +68,38 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
         .rename_fields
         .as_deref()
         .copied()
-        .unwrap_or(rename::Policy::CamelCase);
+        .unwrap_or_else(|| rename::Policy::default_for_fields(attr.is_internal));
 
     let mut fields = vec![];
+    let mut flattened = vec![];
     if let syn::Data::Struct(data) = &ast.data {
-        if let syn::Fields::Named(fs) = &data.fields {
-            fields = fs
-                .named
-                .iter()
-                .filter_map(|f| parse_field(f, &renaming))
-                .collect();
-        } else {
-            ERR.emit_custom(struct_span, "only named fields are allowed");
+        match &data.fields {
+            syn::Fields::Named(fs) => {
+                fields = fs
+                    .named
+                    .iter()
+                    .filter_map(|f| parse_field(f, &renaming, &mut flattened))
+                    .collect();
+            }
+            syn::Fields::Unnamed(fs) => {
+                for (index, f) in fs.unnamed.iter().enumerate() {
+                    parse_unnamed_field(f, index, &mut flattened);
+                }
+            }
+            syn::Fields::Unit => {
+                ERR.emit_custom(struct_span, "only named fields are allowed");
+            }
         }
     }
 
     proc_macro_error::abort_if_dirty();
 
-    if fields.is_empty() {
+    if fields.is_empty() && flattened.is_empty() {
         ERR.emit_custom(struct_span, "must have at least one field");
     }
+    if flattened.iter().filter(|f| f.delegated).count() > 1 {
+        ERR.emit_custom(struct_span, "must have at most one delegated field");
+    }
     if !field::all_different(&fields) {
         ERR.emit_custom(struct_span, "must have a different name for each field");
     }
@@ -95,20 +117,29 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
             .unwrap_or_else(|| parse_quote! { () }),
         scalar,
         fields,
+        flattened,
         interfaces: attr
             .interfaces
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        meta: attr.meta.map(SpanContainer::into_inner),
+        directives: attr.directives.map(SpanContainer::into_inner),
+        with_input_object: attr.with_input_object,
         _operation: PhantomData,
     })
 }
 
 /// Parses a [`field::Definition`] from the given Rust struct [`syn::Field`].
 ///
-/// Returns [`None`] if parsing fails, or the struct field is ignored.
+/// Returns [`None`] if parsing fails, or the struct field is ignored or
+/// pushed into the `flattened` ones.
 #[must_use]
-fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::Definition> {
+fn parse_field(
+    field: &syn::Field,
+    renaming: &rename::Policy,
+    flattened: &mut Vec<Flattened>,
+) -> Option<field::Definition> {
     let attr = field::Attr::from_attrs("graphql", &field.attrs)
         .map_err(|e| proc_macro_error::emit_error!(e))
         .ok()?;
@@ -119,6 +150,50 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
 
     let field_ident = field.ident.as_ref().unwrap();
 
+    if attr.flatten.is_some() || attr.delegate.is_some() {
+        flattened.push(Flattened {
+            member: syn::Member::Named(field_ident.clone()),
+            ty: field.ty.unparenthesized().clone(),
+            delegated: attr.delegate.is_some(),
+        });
+        return None;
+    }
+
+    if let Some(rename_args) = &attr.rename_args {
+        ERR.emit_custom(
+            rename_args.span_ident(),
+            "`rename_args` attribute argument is supported only on methods",
+        );
+        return None;
+    }
+
+    if let Some(validate_only) = &attr.validate_only {
+        ERR.emit_custom(
+            validate_only.span_ident(),
+            "`validate_only` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(map_err) = &attr.map_err {
+        ERR.emit_custom(
+            map_err.span_ident(),
+            "`map_err` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
+    if let Some(generics) = &attr.generics {
+        ERR.emit_custom(
+            generics.span_ident(),
+            "`generics` attribute argument is supported only on \
+             `#[graphql_object]` methods",
+        );
+        return None;
+    }
+
     let name = attr
         .name
         .as_ref()
@@ -146,5 +221,46 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         arguments: None,
         has_receiver: false,
         is_async: false,
+        validate_only: None,
+        guard: attr.guard.map(SpanContainer::into_inner),
+        skip_if: attr.skip_if.map(|pred| {
+            (
+                pred.into_inner(),
+                attr.skip_error.map(SpanContainer::into_inner),
+            )
+        }),
+        complexity: attr.complexity.map(SpanContainer::into_inner),
+        map_err: None,
+        meta: attr.meta.map(SpanContainer::into_inner),
+        directives: attr.directives.map(SpanContainer::into_inner),
+        turbofish: None,
+        cfg_attrs: vec![],
     })
 }
+
+/// Parses the given unnamed Rust struct [`syn::Field`], which may be only
+/// ignored, flattened or delegated, into the `flattened` ones.
+fn parse_unnamed_field(field: &syn::Field, index: usize, flattened: &mut Vec<Flattened>) {
+    let attr = match field::Attr::from_attrs("graphql", &field.attrs) {
+        Ok(attr) => attr,
+        Err(e) => return proc_macro_error::emit_error!(e),
+    };
+
+    if attr.ignore.is_some() {
+        return;
+    }
+
+    if attr.flatten.is_none() && attr.delegate.is_none() {
+        return ERR.emit_custom(
+            field.span(),
+            "only named fields are allowed, unless marked with \
+             `#[graphql(flatten)]`, `#[graphql(delegate)]` or `#[graphql(ignore)]`",
+        );
+    }
+
+    flattened.push(Flattened {
+        member: syn::Member::Unnamed(index.into()),
+        ty: field.ty.unparenthesized().clone(),
+        delegated: attr.delegate.is_some(),
+    });
+}
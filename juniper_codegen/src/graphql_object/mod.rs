@@ -18,7 +18,7 @@ use syn::{
 };
 
 use crate::common::{
-    field, filter_attrs, gen,
+    directive, field, filter_attrs, gen, metadata,
     parse::{
         attr::{err, OptionExt as _},
         GenericsExt as _, ParseBufferExt as _, TypeExt,
@@ -81,12 +81,48 @@ pub(crate) struct Attr {
     /// Explicitly specified [`rename::Policy`] for all fields of this
     /// [GraphQL object][1] type.
     ///
-    /// If [`None`], then the [`rename::Policy::CamelCase`] will be applied by
-    /// default.
+    /// If [`None`], then the [`rename::Policy::default_for_fields()`] will be
+    /// applied.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     pub(crate) rename_fields: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified typed [`Metadata`] annotations of this
+    /// [GraphQL object][1] type.
+    ///
+    /// [`Metadata`]: juniper::meta::Metadata
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) meta: Option<SpanContainer<metadata::Annotations>>,
+
+    /// Explicitly specified [GraphQL directives][2] applied to this
+    /// [GraphQL object][1] type.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Option<SpanContainer<directive::Directives>>,
+
+    /// Explicitly specified argument of `#[derive(GraphQLInputObject)]`
+    /// derived on the same struct (like `input_name`), which is irrelevant for
+    /// this [GraphQL object][1] type.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) input_arg: Option<SpanContainer<syn::Ident>>,
+
+    /// Indicator whether a [GraphQL input object][2] is derived on the same
+    /// struct via `#[derive(GraphQLInputObject)]` (with its `input_name`).
+    ///
+    /// [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+    pub(crate) with_input_object: bool,
+
+    /// Explicitly specified path of the remote type (defined in another crate)
+    /// whose definition this [GraphQL object][1] type mirrors.
+    ///
+    /// If [`Some`], then a [`From`] conversion of the remote type into this
+    /// one is generated.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) remote: Option<SpanContainer<syn::Path>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     pub(crate) is_internal: bool,
@@ -153,9 +189,47 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "meta" => {
+                    let meta = input.parse::<metadata::Annotations>()?;
+                    out.meta
+                        .replace(SpanContainer::new(ident.span(), Some(meta.span()), meta))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "directives" => {
+                    let directives = input.parse::<directive::Directives>()?;
+                    out.directives
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(directives.span()),
+                            directives,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "remote" => {
+                    input.parse::<token::Eq>()?;
+                    let path = input.parse::<syn::Path>()?;
+                    out.remote
+                        .replace(SpanContainer::new(ident.span(), Some(path.span()), path))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "internal" => {
                     out.is_internal = true;
                 }
+                "input_name" => {
+                    input.parse::<token::Eq>()?;
+                    let _ = input.parse::<syn::LitStr>()?;
+                    out.input_arg.get_or_insert_with(|| {
+                        SpanContainer::new(ident.span(), None, ident.clone())
+                    });
+                    out.with_input_object = true;
+                }
+                "validate" => {
+                    input.parse::<token::Eq>()?;
+                    let _ = input.parse::<syn::ExprPath>()?;
+                    out.input_arg.get_or_insert_with(|| {
+                        SpanContainer::new(ident.span(), None, ident.clone())
+                    });
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -177,6 +251,11 @@ impl Attr {
             scalar: try_merge_opt!(scalar: self, another),
             interfaces: try_merge_hashset!(interfaces: self, another => span_joined),
             rename_fields: try_merge_opt!(rename_fields: self, another),
+            meta: try_merge_opt!(meta: self, another),
+            directives: try_merge_opt!(directives: self, another),
+            input_arg: self.input_arg.or(another.input_arg),
+            with_input_object: self.with_input_object || another.with_input_object,
+            remote: try_merge_opt!(remote: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -246,12 +325,41 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) fields: Vec<field::Definition>,
 
+    /// Rust struct fields whose [GraphQL fields][2] are flattened into this
+    /// [GraphQL object][1].
+    ///
+    /// At most one of them may be [delegated](Flattened::delegated).
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) flattened: Vec<Flattened>,
+
     /// [GraphQL interfaces][2] implemented by this [GraphQL object][1].
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
     pub(crate) interfaces: HashSet<syn::Type>,
 
+    /// Typed [`Metadata`] annotations of this [GraphQL object][1].
+    ///
+    /// [`Metadata`]: juniper::meta::Metadata
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) meta: Option<metadata::Annotations>,
+
+    /// [GraphQL directives][2] applied to this [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Option<directive::Directives>,
+
+    /// Indicator whether the Rust type of this [GraphQL object][1] represents
+    /// a [GraphQL input object][2] too, so should register it when used as an
+    /// input type.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+    pub(crate) with_input_object: bool,
+
     /// [GraphQL operation][1] this [`Definition`] should generate code for.
     ///
     /// Either [GraphQL query][2] or [GraphQL subscription][3].
@@ -262,6 +370,32 @@ pub(crate) struct Definition<Operation: ?Sized> {
     pub(crate) _operation: PhantomData<Box<Operation>>,
 }
 
+/// Representation of a Rust struct field, whose [GraphQL fields][2] are
+/// flattened into the parent [GraphQL object][1], for code generation.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
+#[derive(Debug)]
+pub(crate) struct Flattened {
+    /// Name (or index) of the flattened Rust struct field.
+    pub(crate) member: syn::Member,
+
+    /// Rust type of the flattened Rust struct field.
+    ///
+    /// Should be a [GraphQL object][1] itself.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) ty: syn::Type,
+
+    /// Indicator whether all the [GraphQL fields][1] not defined by the parent
+    /// [GraphQL object][2] are resolved by this Rust struct field, rather than
+    /// only the ones known at compile time.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) delegated: bool,
+}
+
 impl<Operation: ?Sized + 'static> Definition<Operation> {
     /// Returns prepared [`syn::Generics::split_for_impl`] for [`GraphQLType`]
     /// trait (and similar) implementation of this [GraphQL object][1].
@@ -324,6 +458,19 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         (quote! { #impl_generics }, where_clause.cloned())
     }
 
+    /// Returns the Rust struct field resolving all the [GraphQL fields][1] not
+    /// defined by this [GraphQL object][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn delegated(&self) -> Option<&syn::Member> {
+        self.flattened
+            .iter()
+            .find(|f| f.delegated)
+            .map(|f| &f.member)
+    }
+
     /// Returns generated code implementing [`marker::IsOutputType`] trait for
     /// this [GraphQL object][1].
     ///
@@ -342,6 +489,8 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             .iter()
             .map(|f| f.method_mark_tokens(coerce_result, scalar));
 
+        let flattened_tys = self.flattened.iter().map(|f| &f.ty);
+
         let interface_tys = self.interfaces.iter();
 
         quote! {
@@ -350,6 +499,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             {
                 fn mark() {
                     #( #fields_marks )*
+                    #( <#flattened_tys as ::juniper::marker::GraphQLObject<#scalar>>::mark(); )*
                     #( <#interface_tys as ::juniper::marker::IsOutputType<#scalar>>::mark(); )*
                 }
             }
@@ -370,7 +520,10 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         let name = &self.name;
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
-        let fields = self.fields.iter().map(|f| &f.name);
+        let fields = self.fields.iter().map(|f| {
+            let (cfg, name) = (&f.cfg_attrs, &f.name);
+            quote! { #( #cfg )* #name }
+        });
         let interfaces = self.interfaces.iter();
 
         quote! {
@@ -432,19 +585,61 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
 
         let name = &self.name;
         let description = &self.description;
+        let meta = &self.meta;
+        let directives = &self.directives;
 
         let extract_stream_type = TypeId::of::<Operation>() != TypeId::of::<Query>();
-        let fields_meta = self
-            .fields
-            .iter()
-            .map(|f| f.method_meta_tokens(extract_stream_type.then_some(scalar)));
-
         // Sorting is required to preserve/guarantee the order of interfaces registered in schema.
         let mut interface_tys: Vec<_> = self.interfaces.iter().collect();
         interface_tys.sort_unstable_by(|a, b| {
             let (a, b) = (quote!(#a).to_string(), quote!(#b).to_string());
             a.cmp(&b)
         });
+
+        let fields_meta = self
+            .fields
+            .iter()
+            .map(|f| f.method_meta_tokens(extract_stream_type.then_some(scalar)));
+        let fields = if self.flattened.is_empty() && interface_tys.is_empty() {
+            quote! {
+                let fields = [
+                    #( #fields_meta, )*
+                ];
+            }
+        } else {
+            let flattened_tys = self.flattened.iter().map(|f| &f.ty);
+            quote! {
+                let mut fields = ::std::vec::Vec::from([
+                    #( #fields_meta, )*
+                ]);
+                #( fields.extend(
+                    ::juniper::macros::helper::flattened_fields::<#flattened_tys, #scalar>(
+                        info, registry,
+                    ),
+                ); )*
+                #( ::juniper::macros::helper::inherit_fields::<#interface_tys, #ty, #scalar>(
+                    &mut fields, info, registry,
+                ); )*
+            }
+        };
+        let input_object = self.with_input_object.then(|| {
+            quote! {
+                fn input_name(_: &Self::TypeInfo) -> Option<&'static str> {
+                    Some(<Self as ::juniper::macros::helper::SharedInputObject<#scalar>>::NAME)
+                }
+
+                fn input_meta<'r>(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<'r, #scalar>
+                ) -> ::juniper::meta::MetaType<'r, #scalar>
+                where #scalar: 'r,
+                {
+                    <Self as ::juniper::macros::helper::SharedInputObject<#scalar>>::meta(
+                        info, registry,
+                    )
+                }
+            }
+        });
         let interfaces = (!interface_tys.is_empty()).then(|| {
             quote! {
                 .interfaces(&[
@@ -467,14 +662,16 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                 ) -> ::juniper::meta::MetaType<'r, #scalar>
                 where #scalar: 'r,
                 {
-                    let fields = [
-                        #( #fields_meta, )*
-                    ];
+                    #fields
                     registry.build_object_type::<#ty>(info, &fields)
                         #description
                         #interfaces
+                        #meta
+                        #directives
                         .into_meta()
                 }
+
+                #input_object
             }
         }
     }
@@ -572,8 +769,8 @@ impl Definition<Query> {
                     .flat_map(|vec| vec.iter())
                     .filter_map(|arg| match arg {
                         field::MethodArgument::Regular(arg) => {
-                            let (name, ty) = (&arg.name, &arg.ty);
-                            Some(quote! {(
+                            let (name, ty, cfg) = (&arg.name, &arg.ty, &arg.cfg_attrs);
+                            Some(quote! { #( #cfg )* (
                                 #name,
                                 <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
                                 <#ty as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE,
@@ -582,8 +779,22 @@ impl Definition<Query> {
                         field::MethodArgument::Executor | field::MethodArgument::Context(_) => None,
                     })
                     .collect::<Vec<_>>();
+                let defaulted_arguments = field
+                    .arguments
+                    .iter()
+                    .flat_map(|vec| vec.iter())
+                    .filter_map(|arg| match arg {
+                        field::MethodArgument::Regular(arg) if arg.default.is_some() => {
+                            let (name, cfg) = (&arg.name, &arg.cfg_attrs);
+                            Some(quote! { #( #cfg )* #name })
+                        }
+                        _ => None,
+                    });
+
+                let cfg = &field.cfg_attrs;
 
                 quote! {
+                    #( #cfg )*
                     #[allow(deprecated, non_snake_case)]
                     #[automatically_derived]
                     impl #impl_generics ::juniper::macros::reflect::FieldMeta<
@@ -603,6 +814,8 @@ impl Definition<Query> {
                             ::juniper::macros::reflect::Type,
                             ::juniper::macros::reflect::WrappedValue,
                         )] = &[#(#arguments,)*];
+                        const DEFAULTED_ARGUMENTS: ::juniper::macros::reflect::Names =
+                            &[#(#defaulted_arguments,)*];
                     }
                 }
             })
@@ -639,27 +852,54 @@ impl Definition<Query> {
                             .as_ref()
                             .unwrap()
                             .iter()
-                            .map(|arg| arg.method_resolve_field_tokens(scalar, false));
+                            .map(|arg| arg.method_resolve_field_tokens(scalar, false))
+                            .collect::<Vec<_>>();
 
                         let rcv = field.has_receiver.then(|| {
                             quote! { self, }
                         });
-
-                        quote! { Self::#ident(#rcv #( #args ),*) }
+                        let turbofish = &field.turbofish;
+
+                        if let Some(validate) = &field.validate_only {
+                            quote! {
+                                if executor.is_dry_run() {
+                                    #validate(#rcv #( #args ),*)
+                                } else {
+                                    Self::#ident #turbofish(#rcv #( #args ),*)
+                                }
+                            }
+                        } else {
+                            quote! { Self::#ident #turbofish(#rcv #( #args ),*) }
+                        }
                     } else {
                         res_ty = parse_quote! { _ };
                         quote! { &self.#ident }
                     };
+                    let res = field.map_err_tokens(res, false);
 
                     let resolving_code = gen::sync_resolving_code();
+                    let guard = field.guard.as_ref().map(|guard| {
+                        quote! {
+                            ::juniper::macros::helper::check_guard_sync(
+                                &(#guard), executor.context(), args, #name,
+                            )?;
+                        }
+                    });
+
+                    let skip_if = field.skip_if_tokens(scalar, |res| quote! { return #res; });
 
                     quote! {
+                        #skip_if
+                        #guard
                         let res: #res_ty = #res;
                         #resolving_code
                     }
                 };
 
+                let cfg = &field.cfg_attrs;
+
                 quote! {
+                    #( #cfg )*
                     #[allow(deprecated, non_snake_case)]
                     #[automatically_derived]
                     impl #impl_generics ::juniper::macros::reflect::Field<
@@ -703,13 +943,35 @@ impl Definition<Query> {
                         .as_ref()
                         .unwrap()
                         .iter()
-                        .map(|arg| arg.method_resolve_field_tokens(scalar, true));
+                        .map(|arg| arg.method_resolve_field_tokens(scalar, true))
+                        .collect::<Vec<_>>();
 
                     let rcv = field.has_receiver.then(|| {
                         quote! { self, }
                     });
-
-                    quote! { Self::#ident(#rcv #( #args ),*) }
+                    let turbofish = &field.turbofish;
+
+                    match &field.validate_only {
+                        Some(validate) if field.is_async => quote! {
+                            if executor.is_dry_run() {
+                                ::juniper::futures::future::Either::Left(
+                                    #validate(#rcv #( #args ),*),
+                                )
+                            } else {
+                                ::juniper::futures::future::Either::Right(
+                                    Self::#ident #turbofish(#rcv #( #args ),*),
+                                )
+                            }
+                        },
+                        Some(validate) => quote! {
+                            if executor.is_dry_run() {
+                                #validate(#rcv #( #args ),*)
+                            } else {
+                                Self::#ident #turbofish(#rcv #( #args ),*)
+                            }
+                        },
+                        None => quote! { Self::#ident #turbofish(#rcv #( #args ),*) },
+                    }
                 } else {
                     res_ty = parse_quote! { _ };
                     quote! { &self.#ident }
@@ -717,10 +979,42 @@ impl Definition<Query> {
                 if !field.is_async {
                     res = quote! { ::juniper::futures::future::ready(#res) };
                 }
+                let res = field.map_err_tokens(res, true);
 
                 let resolving_code = gen::async_resolving_code(Some(&res_ty));
+                let skip_if = field.skip_if_tokens(scalar, |res| {
+                    quote! { return Box::pin(::juniper::futures::future::ready(#res)); }
+                });
+                let resolve = if let Some(guard) = &field.guard {
+                    // Guard is checked before even creating the resolving
+                    // future, as for sync fields it's created eagerly. The
+                    // future is created in a closure, so the early returns on
+                    // invalid arguments don't escape from the `async` block.
+                    quote! {
+                        Box::pin(async move {
+                            ::juniper::macros::helper::check_guard(
+                                #guard, executor.context(), args, #name,
+                            ).await?;
+                            let resolve = move || -> ::juniper::BoxFuture<
+                                'b, ::juniper::ExecutionResult<#scalar>,
+                            > {
+                                let fut = #res;
+                                #resolving_code
+                            };
+                            resolve().await
+                        })
+                    }
+                } else {
+                    quote! {
+                        let fut = #res;
+                        #resolving_code
+                    }
+                };
+
+                let cfg = &field.cfg_attrs;
 
                 quote! {
+                    #( #cfg )*
                     #[allow(deprecated, non_snake_case)]
                     #[automatically_derived]
                     impl #impl_generics ::juniper::macros::reflect::AsyncField<
@@ -735,8 +1029,8 @@ impl Definition<Query> {
                             args: &'b ::juniper::Arguments<'_, #scalar>,
                             executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
                         ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
-                            let fut = #res;
-                            #resolving_code
+                            #skip_if
+                            #resolve
                         }
                     }
                 }
@@ -761,8 +1055,9 @@ impl Definition<Query> {
         let name = &self.name;
 
         let fields_resolvers = self.fields.iter().map(|f| {
-            let name = &f.name;
+            let (name, cfg) = (&f.name, &f.cfg_attrs);
             quote! {
+                #( #cfg )*
                 #name => {
                     ::juniper::macros::reflect::Field::<
                         #scalar,
@@ -772,8 +1067,38 @@ impl Definition<Query> {
             }
         });
 
-        let no_field_err =
-            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+        let flattened_resolvers = self.flattened.iter().filter(|f| !f.delegated).map(|f| {
+            let (member, ty) = (&f.member, &f.ty);
+            quote! {
+                _ if <#ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES
+                    .contains(&field) => {
+                    ::juniper::GraphQLValue::<#scalar>::resolve_field(
+                        &self.#member, info, field, args, executor,
+                    )
+                }
+            }
+        });
+
+        let inherited_resolvers = self.interfaces.iter().map(|iface| {
+            quote! {
+                _ if <#iface as ::juniper::macros::reflect::InheritedFields<#scalar, Self>>::NAMES
+                    .contains(&field) => {
+                    <#iface as ::juniper::macros::reflect::InheritedFields<#scalar, Self>>
+                        ::resolve_field(self, info, field, args, executor)
+                }
+            }
+        });
+
+        let no_field_err = self.delegated().map_or_else(
+            || field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name),
+            |member| {
+                quote! {
+                    ::juniper::GraphQLValue::<#scalar>::resolve_field(
+                        &self.#member, info, field, args, executor,
+                    )
+                }
+            },
+        );
 
         quote! {
             #[allow(deprecated)]
@@ -796,6 +1121,8 @@ impl Definition<Query> {
                 ) -> ::juniper::ExecutionResult<#scalar> {
                     match field {
                         #( #fields_resolvers )*
+                        #( #flattened_resolvers )*
+                        #( #inherited_resolvers )*
                         _ => #no_field_err,
                     }
                 }
@@ -825,8 +1152,9 @@ impl Definition<Query> {
         let ty_name = ty.to_token_stream().to_string();
 
         let fields_resolvers = self.fields.iter().map(|f| {
-            let name = &f.name;
+            let (name, cfg) = (&f.name, &f.cfg_attrs);
             quote! {
+                #( #cfg )*
                 #name => {
                     ::juniper::macros::reflect::AsyncField::<
                         #scalar,
@@ -836,8 +1164,42 @@ impl Definition<Query> {
             }
         });
 
-        let no_field_err =
-            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+        let flattened_resolvers = self.flattened.iter().filter(|f| !f.delegated).map(|f| {
+            let (member, ty) = (&f.member, &f.ty);
+            quote! {
+                _ if <#ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES
+                    .contains(&field) => {
+                    ::juniper::GraphQLValueAsync::<#scalar>::resolve_field_async(
+                        &self.#member, info, field, args, executor,
+                    )
+                }
+            }
+        });
+
+        let inherited_resolvers = self.interfaces.iter().map(|iface| {
+            quote! {
+                _ if <#iface as ::juniper::macros::reflect::InheritedFields<#scalar, Self>>::NAMES
+                    .contains(&field) => {
+                    <#iface as ::juniper::macros::reflect::AsyncInheritedFields<#scalar, Self>>
+                        ::resolve_field_async(self, info, field, args, executor)
+                }
+            }
+        });
+
+        let no_field_resolver = self.delegated().map_or_else(
+            || {
+                let no_field_err =
+                    field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+                quote! { Box::pin(async move { #no_field_err }) }
+            },
+            |member| {
+                quote! {
+                    ::juniper::GraphQLValueAsync::<#scalar>::resolve_field_async(
+                        &self.#member, info, field, args, executor,
+                    )
+                }
+            },
+        );
 
         quote! {
             #[allow(deprecated, non_snake_case)]
@@ -853,7 +1215,9 @@ impl Definition<Query> {
                 ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
                     match field {
                         #( #fields_resolvers )*
-                        _ => Box::pin(async move { #no_field_err }),
+                        #( #flattened_resolvers )*
+                        #( #inherited_resolvers )*
+                        _ => #no_field_resolver,
                     }
                 }
             }
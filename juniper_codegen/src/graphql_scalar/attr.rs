@@ -42,6 +42,12 @@ fn expand_on_type_alias(
             "`transparent` attribute argument isn't applicable to type aliases",
         ));
     }
+    if let Some(validate) = &attr.validate {
+        return Err(ERR.custom_error(
+            validate.span_ident(),
+            "`validate` attribute argument isn't applicable to type aliases",
+        ));
+    }
 
     let methods = parse_type_alias_methods(&ast, &attr)?;
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
@@ -39,6 +39,22 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 /// Parses [`Methods`] from the provided [`Attr`] for the specified
 /// [`syn::DeriveInput`].
 pub(super) fn parse_derived_methods(ast: &syn::DeriveInput, attr: &Attr) -> syn::Result<Methods> {
+    if let Some(validate) = &attr.validate {
+        if !attr.transparent {
+            return Err(ERR.custom_error(
+                validate.span_ident(),
+                "`validate` attribute argument requires `transparent`",
+            ));
+        }
+        if attr.from_input.is_some() {
+            return Err(ERR.custom_error(
+                validate.span_ident(),
+                "`validate` attribute argument cannot be combined with \
+                 `from_input_with`, as it validates the delegated parsing only",
+            ));
+        }
+    }
+
     match (
         attr.to_output.as_deref().cloned(),
         attr.from_input.as_deref().cloned(),
@@ -107,6 +123,7 @@ pub(super) fn parse_derived_methods(ast: &syn::DeriveInput, attr: &Attr) -> syn:
                 to_output,
                 from_input,
                 parse_token,
+                validate: attr.validate.as_deref().cloned(),
                 field: Box::new(field),
             })
         }
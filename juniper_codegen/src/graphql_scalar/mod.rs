@@ -73,6 +73,12 @@ struct Attr {
     /// [`FromInputValue::from_input_value`]: juniper::FromInputValue::from_input_value
     from_input: Option<SpanContainer<syn::ExprPath>>,
 
+    /// Explicitly specified function validating the value of the
+    /// [`Self::transparent`] field, parsed from an [`InputValue`].
+    ///
+    /// [`InputValue`]: juniper::InputValue
+    validate: Option<SpanContainer<syn::ExprPath>>,
+
     /// Explicitly specified resolver to be used as
     /// [`ParseScalarValue::from_str`] implementation.
     ///
@@ -81,6 +87,12 @@ struct Attr {
 
     /// Explicitly specified module with all custom resolvers for
     /// [`Self::to_output`], [`Self::from_input`] and [`Self::parse_token`].
+    ///
+    /// `with = serde` is resolved to the built-in module implementing them via
+    /// [`serde::Serialize`] and [`serde::Deserialize`] of the type.
+    ///
+    /// [`serde::Deserialize`]: https://docs.rs/serde/1/serde/trait.Deserialize.html
+    /// [`serde::Serialize`]: https://docs.rs/serde/1/serde/trait.Serialize.html
     with: Option<SpanContainer<syn::ExprPath>>,
 
     /// Explicit where clause added to [`syn::WhereClause`].
@@ -146,6 +158,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "validate" => {
+                    input.parse::<token::Eq>()?;
+                    let scl = input.parse::<syn::ExprPath>()?;
+                    out.validate
+                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "parse_token_with" => {
                     input.parse::<token::Eq>()?;
                     let scl = input.parse::<syn::ExprPath>()?;
@@ -177,9 +196,13 @@ impl Parse for Attr {
                 }
                 "with" => {
                     input.parse::<token::Eq>()?;
-                    let scl = input.parse::<syn::ExprPath>()?;
+                    let mut scl = input.parse::<syn::ExprPath>()?;
+                    let span = scl.span();
+                    if scl.path.is_ident("serde") {
+                        scl = parse_quote! { ::juniper::macros::helper::serde_scalar };
+                    }
                     out.with
-                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .replace(SpanContainer::new(ident.span(), Some(span), scl))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
                 "where" => {
@@ -234,6 +257,7 @@ impl Attr {
             scalar: try_merge_opt!(scalar: self, another),
             to_output: try_merge_opt!(to_output: self, another),
             from_input: try_merge_opt!(from_input: self, another),
+            validate: try_merge_opt!(validate: self, another),
             parse_token: try_merge_opt!(parse_token: self, another),
             with: try_merge_opt!(with: self, another),
             where_clause: try_merge_opt!(where_clause: self, another),
@@ -704,6 +728,9 @@ enum Methods {
         /// or `#[graphql(parse_token(...))]`.
         parse_token: Option<ParseToken>,
 
+        /// Function provided with `#[graphql(validate = ...)]`.
+        validate: Option<syn::ExprPath>,
+
         /// [`Field`] to resolve not provided methods.
         field: Box<Field>,
     },
@@ -770,11 +797,27 @@ impl Methods {
             } => {
                 quote! { #from_input(input) }
             }
-            Self::Delegated { field, .. } => {
+            Self::Delegated {
+                field, validate, ..
+            } => {
                 let field_ty = field.ty();
                 let self_constructor = field.closure_constructor();
+                let validation = validate.as_ref().map(|validate| {
+                    quote! {
+                        .map_err(::juniper::executor::IntoFieldError::<#scalar>::into_field_error)
+                        .and_then(|v| {
+                            #validate(&v)
+                                .map(|()| v)
+                                .map_err(
+                                    ::juniper::executor::IntoFieldError::<#scalar>
+                                        ::into_field_error,
+                                )
+                        })
+                    }
+                });
                 quote! {
                     <#field_ty as ::juniper::FromInputValue<#scalar>>::from_input_value(input)
+                        #validation
                         .map(#self_constructor)
                 }
             }
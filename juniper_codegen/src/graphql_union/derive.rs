@@ -2,10 +2,10 @@
 
 use proc_macro2::TokenStream;
 use proc_macro_error::ResultExt as _;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _, Data, Fields};
 
-use crate::common::{diagnostic, parse::TypeExt as _, scalar, SpanContainer};
+use crate::common::{diagnostic, parse::TypeExt as _, path_eq_single, scalar, SpanContainer};
 
 use super::{
     all_variants_different, emerge_union_variants_from_attr, Attr, Definition, VariantAttr,
@@ -21,15 +21,19 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 
     match &ast.data {
         Data::Enum(_) => expand_enum(ast),
-        Data::Struct(_) => expand_struct(ast),
+        Data::Struct(_) => expand_struct(ast).map(ToTokens::into_token_stream),
         _ => Err(ERR.custom_error(ast.span(), "can only be derived for enums and structs")),
     }
-    .map(ToTokens::into_token_stream)
 }
 
 /// Expands into generated code a `#[derive(GraphQLUnion)]` macro placed on a
 /// Rust enum.
-fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
+///
+/// Along with the [`Definition`], generates an implicit [GraphQL object][1]
+/// for each enum variant with named fields.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+fn expand_enum(ast: syn::DeriveInput) -> syn::Result<TokenStream> {
     let attr = Attr::from_attrs("graphql", &ast.attrs)?;
 
     let enum_span = ast.span();
@@ -49,12 +53,21 @@ fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
         );
     }
 
+    let mut implicit_objects = vec![];
     let mut variants: Vec<_> = match ast.data {
         Data::Enum(data) => data.variants,
         _ => unreachable!(),
     }
     .into_iter()
-    .filter_map(|var| parse_variant_from_enum_variant(var, &enum_ident, &attr))
+    .filter_map(|var| {
+        parse_variant_from_enum_variant(
+            var,
+            &enum_ident,
+            &ast.generics,
+            &attr,
+            &mut implicit_objects,
+        )
+    })
     .collect();
 
     proc_macro_error::abort_if_dirty();
@@ -74,7 +87,7 @@ fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
 
     proc_macro_error::abort_if_dirty();
 
-    Ok(Definition {
+    let definition = Definition {
         name,
         ty: parse_quote! { #enum_ident },
         is_trait_object: false,
@@ -86,19 +99,30 @@ fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
         scalar: scalar::Type::parse(attr.scalar.as_deref(), &ast.generics),
         generics: ast.generics,
         variants,
+    };
+
+    Ok(quote! {
+        #definition
+        #( #implicit_objects )*
     })
 }
 
 /// Parses given Rust enum `var`iant as [GraphQL union][1] variant.
 ///
+/// Generated code of the implicit [GraphQL object][2] is pushed into the
+/// provided `implicit_objects`, if the variant has named fields.
+///
 /// On failure returns [`None`] and internally fills up [`proc_macro_error`]
 /// with the corresponding errors.
 ///
 /// [1]: https://spec.graphql.org/October2021#sec-Unions
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
 fn parse_variant_from_enum_variant(
     var: syn::Variant,
     enum_ident: &syn::Ident,
+    enum_generics: &syn::Generics,
     enum_attr: &Attr,
+    implicit_objects: &mut Vec<TokenStream>,
 ) -> Option<VariantDefinition> {
     let attr = VariantAttr::from_attrs("graphql", &var.attrs)
         .map_err(|e| proc_macro_error::emit_error!(e))
@@ -108,6 +132,36 @@ fn parse_variant_from_enum_variant(
     }
 
     let var_ident = var.ident;
+    let enum_path = quote! { #enum_ident::#var_ident };
+
+    if let Fields::Named(fields) = var.fields {
+        if let Some(rslvr) = &attr.external_resolver {
+            ERR.emit_custom(
+                rslvr.span_ident(),
+                "external resolver function is not supported on variants with named fields",
+            );
+            return None;
+        }
+
+        let (ty, resolver_code, object) = implicit_object_from_enum_variant(
+            &var_ident,
+            &var.attrs,
+            &fields,
+            enum_ident,
+            enum_generics,
+            enum_attr,
+        )?;
+        implicit_objects.push(object);
+
+        return Some(VariantDefinition {
+            ty,
+            resolver_code,
+            resolver_check: parse_quote! {
+                matches!(self, #enum_path { .. })
+            },
+            context: None,
+        });
+    }
 
     let ty = match var.fields {
         Fields::Unnamed(fields) => {
@@ -124,13 +178,12 @@ fn parse_variant_from_enum_variant(
     .map_err(|span| {
         ERR.emit_custom(
             span,
-            "enum allows only unnamed variants with a single field, e.g. `Some(T)`",
+            "enum allows only unnamed variants with a single field, e.g. `Some(T)`, \
+             or variants with named fields, e.g. `Human { id: String }`",
         )
     })
     .ok()?;
 
-    let enum_path = quote! { #enum_ident::#var_ident };
-
     let resolver_code = if let Some(rslvr) = attr.external_resolver {
         if let Some(other) = enum_attr.external_resolvers.get(&ty) {
             ERR.emit_custom(
@@ -167,6 +220,84 @@ fn parse_variant_from_enum_variant(
     })
 }
 
+/// Generates an implicit [GraphQL object][2] representing the given Rust enum
+/// variant with named `fields` as a [GraphQL union][1] member.
+///
+/// The generated [GraphQL object][2] is named after the variant and borrows its
+/// fields, along with their `#[graphql]` attributes and doc comments.
+///
+/// Returns the Rust type of the generated [GraphQL object][2], the Rust code
+/// resolving the variant into it, and the generated code itself.
+///
+/// On failure returns [`None`] and internally fills up [`proc_macro_error`]
+/// with the corresponding errors.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Unions
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
+fn implicit_object_from_enum_variant(
+    var_ident: &syn::Ident,
+    var_attrs: &[syn::Attribute],
+    fields: &syn::FieldsNamed,
+    enum_ident: &syn::Ident,
+    enum_generics: &syn::Generics,
+    enum_attr: &Attr,
+) -> Option<(syn::Type, syn::Expr, TokenStream)> {
+    if !enum_generics.params.is_empty() {
+        ERR.emit_custom(
+            var_ident.span(),
+            "variants with named fields are supported only on non-generic enums",
+        );
+        return None;
+    }
+
+    let object_ident = format_ident!("__{}{}", enum_ident.unraw(), var_ident.unraw());
+    let name = var_ident.unraw().to_string();
+
+    let docs = var_attrs
+        .iter()
+        .filter(|attr| path_eq_single(&attr.path, "doc"));
+    let context = enum_attr
+        .context
+        .as_deref()
+        .map(|ctx| quote! { context = #ctx, });
+    let scalar = enum_attr.scalar.as_deref().map(|scl| match scl {
+        scalar::AttrValue::Concrete(ty) => quote! { scalar = #ty, },
+        scalar::AttrValue::Generic(pred) => quote! { scalar = #pred, },
+    });
+
+    let field_idents: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+    let object_fields = fields.named.iter().map(|f| {
+        let (attrs, ident, ty) = (&f.attrs, &f.ident, &f.ty);
+        quote! {
+            #( #attrs )*
+            #ident: &'__r #ty
+        }
+    });
+
+    let object = quote! {
+        #( #docs )*
+        #[derive(::juniper::GraphQLObject)]
+        #[graphql(name = #name, #context #scalar)]
+        struct #object_ident<'__r> {
+            #( #object_fields, )*
+        }
+    };
+    let resolver_code = parse_quote! {
+        match self {
+            #enum_ident::#var_ident { #( #field_idents ),* } => {
+                Some(#object_ident { #( #field_idents ),* })
+            }
+            _ => None,
+        }
+    };
+
+    Some((
+        parse_quote! { #object_ident<'static> },
+        resolver_code,
+        object,
+    ))
+}
+
 /// Expands into generated code a `#[derive(GraphQLUnion)]` macro placed on a
 /// Rust struct.
 fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition> {
@@ -129,7 +129,7 @@ use proc_macro_error::{proc_macro_error, ResultExt as _};
 /// }
 /// ```
 ///
-/// # Custom name and description
+/// # Custom name, description and deprecation
 ///
 /// The name of a [GraphQL input object][0] or its [fields][1] may be overridden
 /// with the `name` attribute's argument. By default, a type name or a struct
@@ -139,6 +139,13 @@ use proc_macro_error::{proc_macro_error, ResultExt as _};
 /// specified either with the `description`/`desc` attribute's argument, or with
 /// a regular Rust doc comment.
 ///
+/// A [field][1] of a [GraphQL input object][0] may be deprecated by specifying
+/// the `deprecated` attribute's argument, or with a regular Rust `#[deprecated]`
+/// attribute. Deprecated [fields][1] are omitted from introspection, unless
+/// requested with `inputFields(includeDeprecated: true)`. Per the
+/// specification, a required [field][1] (non-`null`able and without a default
+/// value) must not be deprecated.
+///
 /// ```rust
 /// # use juniper::GraphQLInputObject;
 /// #
@@ -156,6 +163,9 @@ use proc_macro_error::{proc_macro_error, ResultExt as _};
 ///
 ///     #[graphql(name = "y", desc = "Ordinate value")]
 ///     y_coord: f64,
+///
+///     #[graphql(deprecated = "Points are two-dimensional")]
+///     z: Option<f64>,
 /// }
 /// ```
 ///
@@ -182,6 +192,10 @@ use proc_macro_error::{proc_macro_error, ResultExt as _};
 /// }
 /// ```
 ///
+/// The default `camelCase` policy may be changed for the whole build by setting
+/// the `JUNIPER_RENAME_ALL` environment variable at compile time (in the
+/// `[env]` section of `.cargo/config.toml`, for example).
+///
 /// # Ignoring fields
 ///
 /// To omit exposing a Rust field in a GraphQL schema, use the `ignore`
@@ -209,10 +223,125 @@ use proc_macro_error::{proc_macro_error, ResultExt as _};
 /// }
 /// ```
 ///
+/// # Validation
+///
+/// To validate a Rust field once it's converted from GraphQL input, or a whole
+/// Rust struct once it's constructed, use the `validate = <path>` attribute's
+/// argument on that field or struct respectively. The specified function
+/// accepts a reference to the validated value and returns a [`Result`], which
+/// error should implement [`IntoFieldError`]. Returned errors have the path to
+/// the invalid input value (starting with an argument name) in their
+/// `"inputPath"` extension.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// fn non_negative(v: &f64) -> Result<(), &'static str> {
+///     (*v >= 0.0).then_some(()).ok_or("must not be negative")
+/// }
+///
+/// fn ordered(r: &Range) -> Result<(), &'static str> {
+///     (r.from <= r.to).then_some(()).ok_or("`from` is greater than `to`")
+/// }
+///
+/// #[derive(GraphQLInputObject)]
+/// #[graphql(validate = ordered)]
+/// struct Range {
+///     #[graphql(validate = non_negative)]
+///     from: f64,
+///     to: f64,
+/// }
+/// ```
+///
+/// # Flattening
+///
+/// To inline the fields of another [GraphQL input object][0] into this one
+/// (similarly to `#[serde(flatten)]`), use the `flatten` attribute's argument
+/// on the Rust field holding it. The flattened Rust type is constructed from
+/// the whole input of this [GraphQL input object][0], and is not required to
+/// be registered in the schema by itself.
+///
+/// Names of the flattened [fields][1] should not clash with the other
+/// [fields][1] of this [GraphQL input object][0], which is checked at compile
+/// time for non-generic Rust types.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct Address {
+///     city: String,
+///     street: String,
+/// }
+///
+/// #[derive(GraphQLInputObject)]
+/// struct NewUser {
+///     name: String,
+///     #[graphql(flatten)]
+///     address: Address, // `NewUser` has `name`, `city` and `street` fields.
+/// }
+/// ```
+///
+/// # Deriving along with `GraphQLObject`
+///
+/// The same Rust struct may represent both a [GraphQL object][3] and a
+/// [GraphQL input object][0], by deriving both `GraphQLObject` and
+/// `GraphQLInputObject` on it. As the names of these GraphQL types should
+/// differ, use an `input_name` attribute's argument to name the
+/// [GraphQL input object][0]. All the other attribute's arguments (like
+/// `description` or `rename_all`) and doc comments are shared by both GraphQL
+/// types, while the ones specific to only one of them are ignored by the other.
+///
+/// ```rust
+/// # use juniper::{GraphQLInputObject, GraphQLObject};
+/// #
+/// /// A point on a map.
+/// #[derive(GraphQLInputObject, GraphQLObject)]
+/// #[graphql(input_name = "PointInput", rename_all = "none")]
+/// struct Point {
+///     x_coord: f64,
+///     #[graphql(default = 0.0)] // used by the `PointInput` only
+///     y_coord: f64,
+/// }
+/// ```
+///
+/// # Remote types
+///
+/// Similarly to [`serde`'s remote derive][4], a [GraphQL input object][0] may
+/// be defined for a struct from another crate (which cannot be annotated with
+/// `#[derive(GraphQLInputObject)]`), by mirroring its definition in a local
+/// struct and specifying the path to the original struct with a `remote`
+/// attribute's argument. This generates a [`From`] conversion of the local
+/// struct into the remote one, converting each field via [`Into`]. The fields
+/// of the remote struct should be accessible from the local crate.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// # mod geo {
+/// #     pub struct Point {
+/// #         pub x: f64,
+/// #         pub y: f64,
+/// #     }
+/// # }
+/// #
+/// #[derive(GraphQLInputObject)]
+/// #[graphql(name = "PointInput", remote = geo::Point)]
+/// struct PointInputDef {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let point: geo::Point = PointInputDef { x: 1.0, y: 2.0 }.into();
+/// ```
+///
+/// [`IntoFieldError`]: juniper::IntoFieldError
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
 /// [1]: https://spec.graphql.org/October2021#InputFieldsDefinition
 /// [2]: https://spec.graphql.org/October2021#sec-Scalars
+/// [3]: https://spec.graphql.org/October2021#sec-Objects
+/// [4]: https://serde.rs/remote-derive.html
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLInputObject, attributes(graphql))]
 pub fn derive_input_object(input: TokenStream) -> TokenStream {
@@ -302,6 +431,81 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Aliases
+///
+/// To keep accepting the old name of a renamed [GraphQL enum value][1] on
+/// input (during a migration period, for example), use the `alias` attribute's
+/// argument. Aliases are accepted on input only, while introspection and
+/// output expose the canonical name only.
+///
+/// ```rust
+/// # use juniper::GraphQLEnum;
+/// #
+/// #[derive(GraphQLEnum)]
+/// enum Episode {
+///     #[graphql(alias = "A_NEW_HOPE")]
+///     NewHope,
+///     #[graphql(alias = ["EMPIRE_STRIKES_BACK", "ESB"])]
+///     Empire,
+///     Jedi,
+/// }
+/// ```
+///
+/// # Reusing `serde` or `strum` naming
+///
+/// To keep [GraphQL enum values][1] in sync with an existing serialization of
+/// the Rust enum, use the `rename_source` attribute's argument, specifying
+/// either `"serde"` or `"strum"`. Then the `#[serde(rename_all = ...)]`
+/// (`#[strum(serialize_all = ...)]`) attribute is reused as the renaming policy,
+/// and the `#[serde(rename = ...)]` (`#[strum(to_string = ...)]` or the longest
+/// `#[strum(serialize = ...)]`) attribute as the name of a
+/// [GraphQL enum value][1], while the other accepted names on input
+/// (`#[serde(alias = ...)]` or `#[strum(serialize = ...)]`) become its aliases.
+/// Any `#[graphql]` attribute still takes precedence over them.
+///
+/// ```rust
+/// # use juniper::GraphQLEnum;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Deserialize, GraphQLEnum, Serialize)]
+/// #[graphql(rename_source = "serde")]
+/// #[serde(rename_all = "snake_case")]
+/// enum Episode {
+///     NewHope, // `new_hope`
+///     #[serde(rename = "empire_strikes_back", alias = "esb")]
+///     Empire,
+///     #[graphql(name = "RETURN_OF_THE_JEDI")]
+///     Jedi,
+/// }
+/// ```
+///
+/// # String conversions
+///
+/// To reuse the same mapping between Rust enum variants and
+/// [GraphQL enum values][1] outside GraphQL (for parsing CLI arguments or
+/// configs, for example), use the `str_conversions` attribute's argument. It
+/// additionally generates [`FromStr`] and `TryFrom<&str>` implementations
+/// accepting [GraphQL enum value][1] names (and their aliases), along with a
+/// [`Display`] implementation writing them. It cannot be used along with
+/// ignored Rust enum variants.
+///
+/// ```rust
+/// # use juniper::GraphQLEnum;
+/// #
+/// #[derive(Debug, GraphQLEnum, PartialEq)]
+/// #[graphql(str_conversions)]
+/// enum Episode {
+///     #[graphql(alias = "A_NEW_HOPE")]
+///     NewHope,
+///     Empire,
+///     Jedi,
+/// }
+///
+/// assert_eq!("A_NEW_HOPE".parse(), Ok(Episode::NewHope));
+/// assert_eq!(Episode::try_from("JEDI"), Ok(Episode::Jedi));
+/// assert_eq!(Episode::Empire.to_string(), "EMPIRE");
+/// ```
+///
 /// # Ignoring enum variants
 ///
 /// To omit exposing a Rust enum variant in a GraphQL schema, use the `ignore`
@@ -321,6 +525,36 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Remote types
+///
+/// Similarly to [`serde`'s remote derive][2], a [GraphQL enum][0] may be
+/// defined for an enum from another crate (which cannot be annotated with
+/// `#[derive(GraphQLEnum)]`), by mirroring its definition in a local enum and
+/// specifying the path to the original enum with a `remote` attribute's
+/// argument. This generates [`From`] conversions between the local enum and
+/// the remote one in both directions.
+///
+/// ```rust
+/// # use juniper::GraphQLEnum;
+/// #
+/// # mod compass {
+/// #     pub enum Direction {
+/// #         North,
+/// #         South,
+/// #     }
+/// # }
+/// #
+/// #[derive(GraphQLEnum)]
+/// #[graphql(name = "Direction", remote = compass::Direction)]
+/// enum DirectionDef {
+///     North,
+///     South,
+/// }
+///
+/// let dir: compass::Direction = DirectionDef::North.into();
+/// assert!(matches!(DirectionDef::from(dir), DirectionDef::North));
+/// ```
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, `#[derive(GraphQLEnum)]` macro generates code, which is generic
@@ -342,6 +576,7 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021#sec-Enums
 /// [1]: https://spec.graphql.org/October2021#sec-Enum-Value
+/// [2]: https://serde.rs/remote-derive.html
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLEnum, attributes(graphql))]
 pub fn derive_enum(input: TokenStream) -> TokenStream {
@@ -403,6 +638,29 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// All of the methods inherited from `Newtype`'s field may also be overridden
 /// with the attributes described below.
 ///
+/// # Validation
+///
+/// Values of a `transparent` [GraphQL scalar][0] may be validated, once parsed
+/// by the wrapped type, via `#[graphql(validate = <fn path>)]` attribute. The
+/// validation function receives a reference to the parsed value of the wrapped
+/// type, and its error (which must implement [`IntoFieldError`]) is reported
+/// as the input coercion error:
+/// ```rust
+/// # use juniper::GraphQLScalar;
+/// #
+/// #[derive(GraphQLScalar)]
+/// #[graphql(transparent, validate = validate_email)]
+/// struct EmailAddress(String);
+///
+/// fn validate_email(email: &str) -> Result<(), String> {
+///     //                                       ^^^^^^ must implement `IntoFieldError`
+///     match email.split_once('@') {
+///         Some((user, domain)) if !user.is_empty() && domain.contains('.') => Ok(()),
+///         _ => Err(format!("Malformed email address: {email}")),
+///     }
+/// }
+/// ```
+///
 /// # Custom resolving
 ///
 /// Customization of a [GraphQL scalar][0] type resolving is possible via
@@ -636,6 +894,29 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 /// # fn main() {}
 /// ```
 ///
+/// # Serde-powered resolving
+///
+/// Types already implementing [`Serialize`] and [`Deserialize`] may reuse them
+/// for resolving by specifying `#[graphql(with = serde)]` attribute. Their
+/// values are round-tripped through [`Value`] and [`InputValue`], while any
+/// `String`, `Int` or `Float` token is accepted for parsing.
+/// ```rust
+/// # use juniper::GraphQLScalar;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Deserialize, GraphQLScalar, Serialize)]
+/// #[graphql(with = serde)]
+/// #[serde(untagged)]
+/// enum StringOrInt {
+///     String(String),
+///     Int(i32),
+/// }
+/// ```
+///
+/// > __NOTE:__ `with = serde` always refers to the built-in resolvers, so any
+/// >           custom module named `serde` should be referred by a longer path
+/// >           (like `self::serde`).
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, this macro generates code, which is generic over a
@@ -653,7 +934,12 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
 ///
 /// [0]: https://spec.graphql.org/October2021#sec-Scalars
 /// [1]: https://rust-unofficial.github.io/patterns/patterns/behavioural/newtype.html
+/// [`Deserialize`]: https://docs.rs/serde/1/serde/trait.Deserialize.html
+/// [`InputValue`]: juniper::InputValue
+/// [`IntoFieldError`]: juniper::IntoFieldError
 /// [`ScalarValue`]: juniper::ScalarValue
+/// [`Serialize`]: https://docs.rs/serde/1/serde/trait.Serialize.html
+/// [`Value`]: juniper::Value
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLScalar, attributes(graphql))]
 pub fn derive_scalar(input: TokenStream) -> TokenStream {
@@ -965,7 +1251,12 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// trait CharacterWithDocs {
 ///     /// ID of the character.
 ///     #[deprecated]
-///     fn id(&self, #[graphql(default)] num: i32) -> &str;
+///     fn id(
+///         &self,
+///         /// Arbitrary number.
+///         #[graphql(default)]
+///         num: i32,
+///     ) -> &str;
 /// }
 /// ```
 ///
@@ -1151,6 +1442,152 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Default field implementations
+///
+/// Trait methods may have a default implementation, making the field being
+/// inherited by the implementers of a [GraphQL interface][1], unless they
+/// define it on their own. Such implementers should implement the trait, so
+/// the default implementation (or its override in the trait implementation)
+/// is used to resolve the field.
+///
+/// ```rust
+/// # use juniper::{graphql_interface, graphql_object, GraphQLObject};
+/// #
+/// #[graphql_interface(for = [Human, Droid])]
+/// trait Character {
+///     fn name(&self) -> &str;
+///
+///     // NOTICE: Inherited by the implementers not defining it on their own.
+///     fn display_name(&self) -> String {
+///         format!("Character {}", self.name())
+///     }
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(impl = CharacterValue)]
+/// struct Human {
+///     name: String,
+/// }
+///
+/// impl Character for Human {
+///     fn name(&self) -> &str {
+///         &self.name
+///     }
+/// }
+///
+/// struct Droid {
+///     name: String,
+/// }
+///
+/// #[graphql_object(impl = CharacterValue)]
+/// impl Droid {
+///     fn name(&self) -> &str {
+///         &self.name
+///     }
+///
+///     // NOTICE: Overrides the default implementation.
+///     fn display_name(&self) -> String {
+///         format!("Droid {}", self.name)
+///     }
+/// }
+///
+/// impl Character for Droid {
+///     fn name(&self) -> &str {
+///         &self.name
+///     }
+/// }
+/// ```
+///
+/// # Dynamic dispatch
+///
+/// By default, a [GraphQL interface][1] is resolved via the generated enum,
+/// so all its implementers should be known upfront and listed in the `for`
+/// attribute's argument. To resolve it via [trait objects][3] as well (so its
+/// implementers may be defined in downstream crates), specify the name of
+/// the trait object type alias with a `dyn` attribute's argument.
+///
+/// This transforms the trait into an [`async_trait`] (so its `async` methods
+/// are [object safe][2]), and requires all the trait methods to have a
+/// `&self` receiver. The implementers not listed in the `for` attribute's
+/// argument should reference the trait object type alias in their `impl`
+/// attribute's argument instead, and be registered in the schema with the
+/// [`RootNode::with_type()`] method, unless they're reachable from its root
+/// types.
+///
+/// ```rust
+/// # use juniper::{
+/// #     async_trait, graphql_interface, graphql_object, DefaultScalarValue, EmptyMutation,
+/// #     EmptySubscription, GraphQLObject, RootNode,
+/// # };
+/// #
+/// // NOTICE: Generates `type DynCharacter = dyn Character + Send + Sync`.
+/// #[graphql_interface(for = Human, dyn = DynCharacter)]
+/// trait Character {
+///     async fn id(&self) -> String;
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(impl = CharacterValue)]
+/// struct Human {
+///     id: String,
+///     home_planet: String,
+/// }
+///
+/// #[async_trait]
+/// impl Character for Human {
+///     async fn id(&self) -> String {
+///         self.id.clone()
+///     }
+/// }
+///
+/// // May be defined in a downstream crate.
+/// struct Droid {
+///     id: String,
+///     primary_function: String,
+/// }
+///
+/// // NOTICE: Trait objects are resolved with the `DefaultScalarValue` only.
+/// #[graphql_object(impl = DynCharacter, scalar = DefaultScalarValue)]
+/// impl Droid {
+///     fn id(&self) -> &str {
+///         &self.id
+///     }
+///
+///     fn primary_function(&self) -> &str {
+///         &self.primary_function
+///     }
+/// }
+///
+/// #[async_trait]
+/// impl Character for Droid {
+///     async fn id(&self) -> String {
+///         self.id.clone()
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(scalar = DefaultScalarValue)]
+/// impl Query {
+///     fn characters() -> Vec<Box<DynCharacter>> {
+///         vec![
+///             Box::new(Human {
+///                 id: "human-32".into(),
+///                 home_planet: "Tatooine".into(),
+///             }),
+///             Box::new(Droid {
+///                 id: "droid-99".into(),
+///                 primary_function: "Run".into(),
+///             }),
+///         ]
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new())
+///     .with_type::<Droid>(&());
+/// # assert!(schema.schema.concrete_type_by_name("Droid").is_some());
+/// ```
+///
 /// # Custom context
 ///
 /// By default, the generated implementation tries to infer [`Context`] type from signatures of
@@ -1281,8 +1718,10 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// [`async_trait`]: https://docs.rs/async-trait
 /// [`Context`]: juniper::Context
 /// [`Executor`]: juniper::Executor
+/// [`RootNode::with_type()`]: juniper::RootNode::with_type
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021
 /// [1]: https://spec.graphql.org/October2021#sec-Interfaces
@@ -1419,6 +1858,10 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// Like for [`#[graphql_object]` macro](macro@graphql_object), the default
+/// policy may be changed build-wide via the `JUNIPER_RENAME_ALL` environment
+/// variable.
+///
 /// # Ignoring struct fields
 ///
 /// To omit exposing a struct field in the GraphQL schema, use an `ignore`
@@ -1435,6 +1878,143 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Flattening
+///
+/// To inline the fields of another [GraphQL object][1] into this one
+/// (similarly to `#[serde(flatten)]`), use the `flatten` attribute's argument
+/// on the Rust field holding it. The flattened Rust type should have the same
+/// [`Context`] and implement [`GraphQLObject`] (either derived or via
+/// `#[graphql_object]`), but is not required to be registered in the schema by
+/// itself.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// #
+/// #[derive(GraphQLObject)]
+/// struct Audit {
+///     created_at: String,
+///     updated_at: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Article {
+///     title: String,
+///     #[graphql(flatten)]
+///     audit: Audit, // `Article` has `title`, `createdAt` and `updatedAt` fields.
+/// }
+/// ```
+///
+/// Tuple struct fields may be flattened too, which allows to split the fields
+/// of a single [GraphQL object][1] (like a large `Query`) across several
+/// `#[graphql_object]` blocks living in different modules or crates (see
+/// also the [`merge_objects!`] macro).
+///
+/// ```
+/// # use juniper::{graphql_object, GraphQLObject};
+/// #
+/// # mod users {
+/// #     use juniper::graphql_object;
+/// #
+/// #     pub struct UsersQuery;
+/// #
+/// #     #[graphql_object]
+/// #     impl UsersQuery {
+/// #         fn users_count() -> i32 { 2 }
+/// #     }
+/// # }
+/// #
+/// # mod posts {
+/// #     use juniper::graphql_object;
+/// #
+/// #     pub struct PostsQuery;
+/// #
+/// #     #[graphql_object]
+/// #     impl PostsQuery {
+/// #         fn posts_count() -> i32 { 3 }
+/// #     }
+/// # }
+/// #
+/// #[derive(GraphQLObject)]
+/// struct Query(
+///     #[graphql(flatten)] users::UsersQuery,
+///     #[graphql(flatten)] posts::PostsQuery,
+/// ); // `Query` has `usersCount` and `postsCount` fields.
+/// ```
+///
+/// Note, that fields are flattened only one level deep (fields flattened into
+/// the flattened type are not resolved), and the flattened fields are not
+/// considered when checking the [GraphQL interfaces][2] implemented by this
+/// [GraphQL object][1] at compile time.
+///
+/// # Delegating
+///
+/// To expose the fields of a [GraphQL object][1] defined in another crate via
+/// a newtype wrapping it, use the `delegate` attribute's argument on the
+/// wrapped Rust field. It works like the `flatten` one, except that any field
+/// unknown to this [GraphQL object][1] is resolved by the delegated one, and
+/// it may be placed on tuple struct fields. At most one field may be
+/// delegated.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// #
+/// # mod remote {
+/// #     #[derive(juniper::GraphQLObject)]
+/// #     pub struct User {
+/// #         pub name: String,
+/// #     }
+/// # }
+/// #
+/// #[derive(GraphQLObject)]
+/// #[graphql(name = "User")]
+/// struct User(#[graphql(delegate)] remote::User); // `User` has `name` field.
+///
+/// #[derive(GraphQLObject)]
+/// struct Admin {
+///     level: i32,
+///     #[graphql(delegate)]
+///     user: remote::User, // `Admin` has `level` and `name` fields.
+/// }
+/// ```
+///
+/// # Remote types
+///
+/// Similarly to [`serde`'s remote derive][3], a [GraphQL object][1] may be
+/// defined for a struct from another crate (which cannot be annotated with
+/// `#[derive(GraphQLObject)]`), by mirroring its definition in a local struct
+/// and specifying the path to the original struct with a `remote` attribute's
+/// argument. This generates a [`From`] conversion of the remote struct into the
+/// local one, converting each field via [`Into`], so the local struct may be
+/// returned from resolvers in place of the remote one. The fields of the
+/// remote struct should be accessible from the local crate.
+///
+/// ```
+/// # use juniper::{graphql_object, GraphQLObject};
+/// #
+/// # mod geo {
+/// #     pub struct Point {
+/// #         pub x: f64,
+/// #         pub y: f64,
+/// #     }
+/// # }
+/// #
+/// #[derive(GraphQLObject)]
+/// #[graphql(name = "Point", remote = geo::Point)]
+/// struct PointDef {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn origin() -> PointDef {
+///         geo::Point { x: 0.0, y: 0.0 }.into()
+///     }
+/// }
+/// ```
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, `#[derive(GraphQLObject)]` macro generates code, which is
@@ -1461,8 +2041,13 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// [`Context`]: juniper::Context
+/// [`GraphQLObject`]: juniper::marker::GraphQLObject
+/// [`merge_objects!`]: juniper::merge_objects
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Interfaces
+/// [3]: https://serde.rs/remote-derive.html
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLObject, attributes(graphql))]
 pub fn derive_object(body: TokenStream) -> TokenStream {
@@ -1515,6 +2100,12 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///     async fn add(a: f64, b: f64, c: Option<f64>) -> f64 {
 ///         a + b + c.unwrap_or(0.0)
 ///     }
+///
+///     // `String` arguments may be borrowed as `&str` (or `Option<&str>`)
+///     // instead, avoiding copying them.
+///     fn word_count(text: &str) -> i32 {
+///         text.split_whitespace().count() as i32
+///     }
 /// }
 /// ```
 ///
@@ -1553,6 +2144,62 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Static fields
+///
+/// Associated functions without a `self` receiver are exposed as fields too,
+/// which is handy for constants and metadata fields on root types. They still
+/// may accept arguments, the context or the [`Executor`].
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn api_version() -> &'static str {
+///         "1.0"
+///     }
+///
+///     fn max_page_size() -> i32 {
+///         100
+///     }
+/// }
+/// ```
+///
+/// # Conditional compilation
+///
+/// Methods and their arguments may be annotated with regular Rust `#[cfg]`
+/// attributes, so the [GraphQL object][1] exposes such fields and arguments
+/// only when the specified conditions (like enabled Cargo features) hold.
+/// `#[cfg]` attributes are not supported on the context and [`Executor`]
+/// arguments.
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn version() -> &'static str {
+///         "1.0"
+///     }
+///
+///     #[cfg(feature = "unstable")]
+///     fn experimental() -> bool {
+///         true
+///     }
+///
+///     fn search(
+///         query: String,
+///         #[cfg(feature = "unstable")] fuzzy: Option<bool>,
+///     ) -> Vec<String> {
+///         vec![query]
+///     }
+/// }
+/// ```
+///
 /// # Custom name, description, deprecation and argument defaults
 ///
 /// The name of [GraphQL object][1], its field, or a field argument may be
@@ -1565,7 +2212,9 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///
 /// A field of [GraphQL object][1] may be deprecated by specifying a
 /// `deprecated` attribute's argument, or with regular Rust `#[deprecated]`
-/// attribute.
+/// attribute. A field argument may be deprecated with a `deprecated`
+/// attribute's argument only, and, per the specification, should be either
+/// `null`able or have a default value.
 ///
 /// The default value of a field argument may be specified with a `default`
 /// attribute argument (if no exact value is specified then [`Default::default`]
@@ -1593,6 +2242,8 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///         // A default can be any valid expression that yields the right type.
 ///         #[graphql(default = 5)]
 ///         num: i32,
+///         #[graphql(deprecated = "Use `number` instead")]
+///         num_opt: Option<i32>,
 ///     ) -> &str {
 ///         "Don't use me!"
 ///     }
@@ -1609,8 +2260,11 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///     #[deprecated]
 ///     fn id(
 ///         &self,
+///         // Doc comments also work on field arguments.
+///         /// Arbitrary number.
 ///         // If expression is not specified then `Default::default()` is used.
-///         #[graphql(default)] num: i32,
+///         #[graphql(default)]
+///         num: i32,
 ///     ) -> &str {
 ///         "Deprecated"
 ///     }
@@ -1650,6 +2304,46 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// The policy for arguments may be overridden for a single field with a
+/// `rename_args` attribute's argument, or for a single argument with a
+/// `rename` attribute's argument.
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     // NOTICE: In the generated GraphQL schema this field will be available
+///     //         as `convert` with `FROM_UNIT` and `TO_UNIT` arguments.
+///     #[graphql(rename_args = "SCREAMING_SNAKE_CASE")]
+///     fn convert(from_unit: String, to_unit: String) -> String {
+///         format!("{from_unit} -> {to_unit}")
+///     }
+///
+///     // NOTICE: In the generated GraphQL schema this field will be available
+///     //         as `sortBy` with `ORDER` and `limitTo` arguments.
+///     fn sort_by(#[graphql(rename = "SCREAMING_SNAKE_CASE")] order: i32, limit_to: i32) -> i32 {
+///         order + limit_to
+///     }
+/// }
+/// ```
+///
+/// If a convention other than `camelCase` should be used by default for all
+/// the fields and arguments across the build, it can be specified via the
+/// `JUNIPER_RENAME_ALL` environment variable at compile time, usually in a
+/// `.cargo/config.toml` file:
+/// ```toml
+/// [env]
+/// JUNIPER_RENAME_ALL = "none"
+/// ```
+/// This affects all the [GraphQL objects][1], interfaces and input objects
+/// without an explicit `rename_all` attribute's argument, but not enums, which
+/// are renamed in `SCREAMING_SNAKE_CASE` by default anyway, nor the
+/// introspection types of `juniper` itself. Changing the variable rebuilds the
+/// affected crates.
+///
 /// # Ignoring methods
 ///
 /// To omit some method to be assumed as a [GraphQL object][1] field and ignore
@@ -1671,6 +2365,248 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Dry-run resolvers
+///
+/// To resolve a field with another function when an operation is executed in
+/// dry-run mode (see [`ExecutionOptions::dry_run`]), specify the path to that
+/// function with a `validate_only` attribute's argument. The function must
+/// have the same signature as the method it replaces, and is expected to only
+/// validate its input without causing any side effects.
+///
+/// ```
+/// # use juniper::{graphql_object, FieldResult};
+/// #
+/// struct Mutation;
+///
+/// #[graphql_object]
+/// impl Mutation {
+///     #[graphql(validate_only = Self::validate_create_user)]
+///     fn create_user(name: String) -> FieldResult<String> {
+///         // Store the user somewhere...
+///         Ok(name)
+///     }
+///
+///     #[graphql(ignore)]
+///     fn validate_create_user(name: String) -> FieldResult<String> {
+///         if name.is_empty() {
+///             return Err("`name` must not be empty".into());
+///         }
+///         Ok(name)
+///     }
+/// }
+/// ```
+///
+/// # Error conversion
+///
+/// To return a domain error type, which cannot implement [`IntoFieldError`]
+/// (due to orphan rules, for example), directly from a method, specify a
+/// function converting it into any [`IntoFieldError`] type with a `map_err`
+/// attribute's argument. The method has to return a [`Result`] then.
+///
+/// ```
+/// # use juniper::{graphql_object, FieldError};
+/// #
+/// # mod domain {
+/// #     pub enum Error {
+/// #         NotFound,
+/// #         Forbidden,
+/// #     }
+/// # }
+/// #
+/// fn to_field_error(e: domain::Error) -> FieldError {
+///     match e {
+///         domain::Error::NotFound => "Not found".into(),
+///         domain::Error::Forbidden => "Forbidden".into(),
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     #[graphql(map_err = to_field_error)]
+///     fn user(id: i32) -> Result<String, domain::Error> {
+///         if id == 1 {
+///             Ok("John".into())
+///         } else {
+///             Err(domain::Error::NotFound)
+///         }
+///     }
+///
+///     #[graphql(map_err = |_| "Oops")]
+///     fn admin() -> Result<String, domain::Error> {
+///         Err(domain::Error::Forbidden)
+///     }
+/// }
+/// ```
+///
+/// # Guards
+///
+/// To deny access to a field unless some check passes, specify a [`Guard`]
+/// with a `guard` attribute's argument. Any `Fn(&Context) -> bool` function
+/// is a [`Guard`]. The check runs before the method is called, and a denied
+/// field resolves to a [`FieldError`] with the `FORBIDDEN` code instead.
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct Session {
+///     is_admin: bool,
+/// }
+///
+/// impl juniper::Context for Session {}
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Session)]
+/// impl Query {
+///     #[graphql(guard = |s: &Session| s.is_admin)]
+///     fn secret() -> &'static str {
+///         "42"
+///     }
+/// }
+/// ```
+///
+/// # Conditional fields
+///
+/// To hide a field at execution time (behind a feature flag, for example),
+/// specify an `Fn(&Context) -> bool` predicate with a `skip_if` attribute's
+/// argument. The field is still registered in the schema, but whenever the
+/// predicate returns `true`, the method isn't called and the field resolves
+/// to `null`. To resolve it to an error instead, specify any
+/// [`IntoFieldError`] expression with a `skip_error` attribute's argument.
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct Flags {
+///     beta: bool,
+/// }
+///
+/// impl juniper::Context for Flags {}
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Flags)]
+/// impl Query {
+///     #[graphql(skip_if = |f: &Flags| !f.beta)]
+///     fn preview() -> Option<i32> {
+///         Some(1)
+///     }
+///
+///     #[graphql(skip_if = |f: &Flags| !f.beta, skip_error = "Beta is disabled")]
+///     fn experiment() -> i32 {
+///         2
+///     }
+/// }
+/// ```
+///
+/// # Complexity
+///
+/// To weight a field in the [complexity analysis] of operations, specify
+/// its own cost with a `complexity` attribute's argument. For the fields
+/// returning lists or fanning out otherwise, a closure of
+/// `(&Arguments, usize) -> usize` may be specified instead, calculating the
+/// whole complexity of the field out of its arguments and the complexity of
+/// its selection set. The same argument is supported on
+/// `#[graphql_interface]` fields too.
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct User;
+///
+/// #[graphql_object]
+/// impl User {
+///     fn id() -> i32 {
+///         1
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     #[graphql(complexity = 5)]
+///     fn search() -> Vec<i32> {
+///         vec![]
+///     }
+///
+///     #[graphql(complexity = |args, children| {
+///         let limit = args.get::<i32>("limit").ok().flatten().unwrap_or(10);
+///         1 + limit.max(0) as usize * children
+///     })]
+///     fn users(limit: Option<i32>) -> Vec<User> {
+///         (0..limit.unwrap_or(10)).map(|_| User).collect()
+///     }
+/// }
+/// ```
+///
+/// [complexity analysis]: juniper::RootNode::with_max_complexity
+///
+/// # Metadata
+///
+/// To attach typed annotations to a [GraphQL object][1] or its fields (for
+/// extensions like caching or cost analysis to pick up at runtime), list them
+/// with a `meta` attribute's argument as `Type = value` pairs. They're
+/// retrievable by their types via [`MetaType::annotation`] and
+/// [`Field::annotation`].
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct CacheHint {
+///     max_age: u32,
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(meta(CacheHint = CacheHint { max_age: 60 }))]
+/// impl Query {
+///     #[graphql(meta(CacheHint = CacheHint { max_age: 0 }))]
+///     fn now() -> i32 {
+///         0
+///     }
+/// }
+/// ```
+///
+/// [`Field::annotation`]: juniper::meta::Field::annotation
+/// [`MetaType::annotation`]: juniper::meta::MetaType::annotation
+///
+/// # Directives
+///
+/// To apply [type system directives][5] to a [GraphQL object][1] or its
+/// fields (for schema composition tooling to pick up, for example), list them
+/// with a `directives` attribute's argument, along with their arguments, if
+/// any. Argument values may be any Rust expressions implementing
+/// [`ToInputValue`]. Applied directives are exposed in the schema language
+/// representation of the schema, while their definitions are to be
+/// registered via [`RootNode::with_schema_directive`] or
+/// [`Registry::register_directive`].
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct Product;
+///
+/// #[graphql_object(directives(key(fields = "id"), shareable))]
+/// impl Product {
+///     fn id() -> &'static str {
+///         "product-1"
+///     }
+///
+///     #[graphql(directives(cost(weight = 3)))]
+///     fn price() -> i32 {
+///         42
+///     }
+/// }
+/// ```
+///
+/// [`Registry::register_directive`]: juniper::Registry::register_directive
+/// [`RootNode::with_schema_directive`]: juniper::RootNode::with_schema_directive
+/// [`ToInputValue`]: juniper::ToInputValue
+/// [5]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+///
 /// # Custom context
 ///
 /// By default, the generated implementation tries to infer [`Context`] type
@@ -1747,6 +2683,41 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Generic methods
+///
+/// Methods may be generic over type parameters, being monomorphized for the
+/// schema:
+/// - type parameters bounded by [`ScalarValue`] are substituted with the
+///   [`ScalarValue`] type the [GraphQL object][1] is resolved with;
+/// - other type parameters (like the ones convertible into GraphQL types)
+///   may be specified explicitly via `#[graphql(generics(T = Type, ...))]`
+///   attribute's argument, otherwise they're inferred by the compiler.
+///
+/// ```
+/// # use juniper::{graphql_object, Executor, FieldResult, ScalarValue};
+/// #
+/// struct Human {
+///     name: String,
+/// }
+///
+/// #[graphql_object]
+/// impl Human {
+///     fn name<S: ScalarValue>(
+///         &self,
+///         executor: &Executor<'_, '_, (), S>,
+///     ) -> FieldResult<&str, S> {
+///         let _ = executor;
+///         Ok(&self.name)
+///     }
+///
+///     // NOTICE: Will be exposed as `greeting(to: String!): String!` field.
+///     #[graphql(generics(T = String, R = String))]
+///     fn greeting<T: Into<String>, R: From<String>>(&self, to: T) -> R {
+///         format!("Hello, {}! I'm {}.", to.into(), self.name).into()
+///     }
+/// }
+/// ```
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, `#[graphql_object]` macro generates code, which is generic over
@@ -1782,8 +2753,12 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// ```
 ///
 /// [`Context`]: juniper::Context
+/// [`ExecutionOptions::dry_run`]: juniper::ExecutionOptions::dry_run
 /// [`Executor`]: juniper::Executor
+/// [`FieldError`]: juniper::FieldError
 /// [`GraphQLType`]: juniper::GraphQLType
+/// [`Guard`]: juniper::Guard
+/// [`IntoFieldError`]: juniper::IntoFieldError
 /// [`GraphQLValue`]: juniper::GraphQLValue
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021
@@ -1994,6 +2969,43 @@ pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream
 /// }
 /// ```
 ///
+/// # Enum variants with named fields
+///
+/// For simple unions, an enum variant may have named fields instead of
+/// wrapping a separately defined [GraphQL object][2]. In such case, an implicit
+/// [GraphQL object][2] is generated for the variant, named after the variant
+/// and described with its doc comment. The variant fields become the
+/// [GraphQL object][2] fields, and may be configured with the same `#[graphql]`
+/// attributes as the ones of `#[derive(GraphQLObject)]` struct fields.
+///
+/// Such variants are supported only on non-generic enums, and cannot use an
+/// external resolver function.
+///
+/// ```
+/// # use juniper::{GraphQLObject, GraphQLUnion};
+/// #
+/// #[derive(GraphQLObject)]
+/// struct Ewok {
+///     id: String,
+///     funny: bool,
+/// }
+///
+/// #[derive(GraphQLUnion)]
+/// enum Character {
+///     /// Human being.
+///     Human {
+///         id: String,
+///         #[graphql(name = "planet")]
+///         home_planet: String,
+///     },
+///     Droid {
+///         id: String,
+///         primary_function: String,
+///     },
+///     Ewok(Ewok),
+/// }
+/// ```
+///
 /// # Ignoring enum variants
 ///
 /// To omit exposing an enum variant in the GraphQL schema, use an `ignore`
@@ -2148,6 +3160,7 @@ pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream
 /// [`Context`]: juniper::Context
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [1]: https://spec.graphql.org/October2021#sec-Unions
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
 /// [4]: https://doc.rust-lang.org/stable/std/primitive.unit.html
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLUnion, attributes(graphql))]
@@ -261,17 +261,17 @@ impl Definition {
             (
                 Method::AsStr,
                 quote! { fn as_str(&self) -> Option<&str> },
-                quote! { ::std::convert::AsRef::as_ref(v) },
+                quote! { ::core::convert::AsRef::as_ref(v) },
             ),
             (
                 Method::AsString,
-                quote! { fn as_string(&self) -> Option<String> },
-                quote! { ::std::string::ToString::to_string(v) },
+                quote! { fn as_string(&self) -> Option<::juniper::alloc::string::String> },
+                quote! { ::juniper::alloc::string::ToString::to_string(v) },
             ),
             (
                 Method::IntoString,
-                quote! { fn into_string(self) -> Option<String> },
-                quote! { ::std::string::String::from(v) },
+                quote! { fn into_string(self) -> Option<::juniper::alloc::string::String> },
+                quote! { ::juniper::alloc::string::String::from(v) },
             ),
             (
                 Method::AsBool,
@@ -332,7 +332,7 @@ impl Definition {
 
                 quote! {
                     #[automatically_derived]
-                    impl #impl_gen ::std::convert::From<#var_ty> for #ty_ident #ty_gen
+                    impl #impl_gen ::core::convert::From<#var_ty> for #ty_ident #ty_gen
                         #where_clause
                     {
                         fn from(v: #var_ty) -> Self {
@@ -341,7 +341,7 @@ impl Definition {
                     }
 
                     #[automatically_derived]
-                    impl #impl_gen ::std::convert::From<#ty_ident #ty_gen> for Option<#var_ty>
+                    impl #impl_gen ::core::convert::From<#ty_ident #ty_gen> for Option<#var_ty>
                         #where_clause
                     {
                         fn from(ty: #ty_ident #ty_gen) -> Self {
@@ -354,7 +354,7 @@ impl Definition {
                     }
 
                     #[automatically_derived]
-                    impl #lf_impl_gen ::std::convert::From<&'___a #ty_ident #ty_gen> for
+                    impl #lf_impl_gen ::core::convert::From<&'___a #ty_ident #ty_gen> for
                         Option<&'___a #var_ty>
                         #where_clause
                     {
@@ -390,7 +390,7 @@ impl Definition {
                     .as_mut()
                     .unwrap()
                     .predicates
-                    .push(parse_quote! { #var_ty: ::std::fmt::Display });
+                    .push(parse_quote! { #var_ty: ::core::fmt::Display });
             }
         }
         let (impl_gen, ty_gen, where_clause) = generics.split_for_impl();
@@ -403,15 +403,15 @@ impl Definition {
                 .as_ref()
                 .map_or_else(|| quote! { (v) }, |i| quote! { { #i: v } });
 
-            quote! { Self::#var_ident #var_field => ::std::fmt::Display::fmt(v, f), }
+            quote! { Self::#var_ident #var_field => ::core::fmt::Display::fmt(v, f), }
         });
 
         quote! {
             #[automatically_derived]
-            impl #impl_gen ::std::fmt::Display for #ident #ty_gen
+            impl #impl_gen ::core::fmt::Display for #ident #ty_gen
                 #where_clause
             {
-                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     match self {
                         #( #arms )*
                     }
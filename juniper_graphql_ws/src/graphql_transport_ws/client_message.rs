@@ -0,0 +1,101 @@
+use juniper::Variables;
+use serde::Deserialize;
+
+use crate::{utils::default_for_null, StartPayload};
+
+/// ClientMessage defines the message types that clients can send.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(bound(deserialize = "S: Deserialize<'de>"))]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+pub enum ClientMessage<S> {
+    /// ConnectionInit is sent by the client upon connecting.
+    ConnectionInit {
+        /// Optional parameters of any type sent from the client. These are often used for
+        /// authentication.
+        #[serde(default, deserialize_with = "default_for_null")]
+        payload: Variables<S>,
+    },
+    /// Ping is used for detecting failed connections, displaying latency metrics or other types of
+    /// network probing. The server responds with a Pong. Any payload is ignored.
+    Ping,
+    /// Pong is the response to a Ping, or a unidirectional keep-alive. Any payload is ignored.
+    Pong,
+    /// Subscribe messages are used to execute a GraphQL operation.
+    Subscribe {
+        /// The id of the operation. This can be anything, but must be unique. If there is another
+        /// in-flight operation with the same id, the connection is closed.
+        id: String,
+
+        /// The query, variables, and operation name.
+        payload: StartPayload<S>,
+    },
+    /// Complete messages are used to stop listening for the results of an operation.
+    Complete {
+        /// The id of the operation to stop.
+        id: String,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use juniper::{graphql_vars, DefaultScalarValue};
+
+    use super::*;
+
+    #[test]
+    fn test_deserialization() {
+        type ClientMessage = super::ClientMessage<DefaultScalarValue>;
+
+        assert_eq!(
+            ClientMessage::ConnectionInit {
+                payload: graphql_vars! {"foo": "bar"},
+            },
+            serde_json::from_str(r##"{"type": "connection_init", "payload": {"foo": "bar"}}"##)
+                .unwrap(),
+        );
+
+        assert_eq!(
+            ClientMessage::ConnectionInit {
+                payload: graphql_vars! {},
+            },
+            serde_json::from_str(r##"{"type": "connection_init"}"##).unwrap(),
+        );
+
+        assert_eq!(
+            ClientMessage::Ping,
+            serde_json::from_str(r##"{"type": "ping", "payload": {"foo": "bar"}}"##).unwrap(),
+        );
+
+        assert_eq!(
+            ClientMessage::Pong,
+            serde_json::from_str(r##"{"type": "pong"}"##).unwrap(),
+        );
+
+        assert_eq!(
+            ClientMessage::Subscribe {
+                id: "foo".into(),
+                payload: StartPayload {
+                    query: "query MyQuery { __typename }".into(),
+                    variables: graphql_vars! {"foo": "bar"},
+                    operation_name: Some("MyQuery".into()),
+                },
+            },
+            serde_json::from_str(
+                r##"{"type": "subscribe", "id": "foo", "payload": {
+                "query": "query MyQuery { __typename }",
+                "variables": {
+                    "foo": "bar"
+                },
+                "operationName": "MyQuery"
+            }}"##
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            ClientMessage::Complete { id: "foo".into() },
+            serde_json::from_str(r##"{"type": "complete", "id": "foo"}"##).unwrap(),
+        );
+    }
+}
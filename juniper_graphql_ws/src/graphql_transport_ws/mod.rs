@@ -0,0 +1,785 @@
+//! Implementation of the [`graphql-transport-ws` protocol][1], used by the current versions of
+//! [Apollo Client] and the [`graphql-ws`] JS library.
+//!
+//! It shares the [`Schema`], [`Init`] and [`ConnectionConfig`] definitions with the legacy
+//! `graphql-ws` protocol implementation in the crate root.
+//!
+//! [Apollo Client]: https://www.apollographql.com/docs/react
+//! [`graphql-ws`]: https://github.com/enisdenjo/graphql-ws
+//! [1]: https://github.com/enisdenjo/graphql-ws/blob/v5.11.2/PROTOCOL.md
+
+mod client_message;
+pub use client_message::*;
+
+mod server_message;
+pub use server_message::*;
+
+use std::{collections::HashMap, convert::Infallible, error::Error, pin::Pin, sync::Arc};
+
+use juniper::{
+    futures::{
+        channel::oneshot,
+        future::{self, BoxFuture, Either, Future, FutureExt},
+        stream::{self, BoxStream, StreamExt},
+        task::{Context, Poll, Waker},
+        Sink, Stream,
+    },
+    GraphQLError, RuleError, ScalarValue,
+};
+
+use crate::{
+    scheduler::{FairScheduler, WeightedStream},
+    ConnectionConfig, ExecutionParams, Init, Schema,
+};
+
+enum Reaction<S: Schema> {
+    ServerMessage(ServerMessage<S::ScalarValue>),
+    Close { code: u16, message: String },
+}
+
+impl<S: Schema> Reaction<S> {
+    /// Converts the reaction into a one-item stream.
+    fn into_stream(self) -> BoxStream<'static, Self> {
+        stream::once(future::ready(self)).boxed()
+    }
+
+    /// Creates a one-item stream closing the connection with the given code and reason.
+    fn close(code: u16, message: impl Into<String>) -> WeightedStream<Self> {
+        Self::Close {
+            code,
+            message: message.into(),
+        }
+        .into_stream()
+        .into()
+    }
+}
+
+/// Converts a message emitted by an operation into its `graphql-transport-ws` counterpart.
+fn operation_message<S: Schema>(reaction: crate::Reaction<S>) -> ServerMessage<S::ScalarValue> {
+    match reaction {
+        crate::Reaction::ServerMessage(crate::ServerMessage::Data { id, payload }) => {
+            ServerMessage::Next { id, payload }
+        }
+        crate::Reaction::ServerMessage(crate::ServerMessage::Error { id, payload }) => {
+            ServerMessage::Error { id, payload }
+        }
+        _ => unreachable!("operations only emit `Data` and `Error` messages"),
+    }
+}
+
+enum ConnectionState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
+    /// PreInit is the state before a ConnectionInit message has been accepted.
+    PreInit { init: I, schema: S },
+    /// Active is the state after a ConnectionInit message has been accepted.
+    Active {
+        config: Arc<ConnectionConfig<S::Context>>,
+        stoppers: HashMap<String, oneshot::Sender<()>>,
+        schema: S,
+    },
+    /// Terminated is the state after the connection has been requested to be closed.
+    Terminated,
+}
+
+impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
+    // Each message we receive results in a stream of zero or more reactions. For example, a Ping
+    // message results in a one-item stream with the Pong reaction.
+    async fn handle_message(
+        self,
+        msg: ClientMessage<S::ScalarValue>,
+    ) -> (Self, WeightedStream<Reaction<S>>) {
+        match self {
+            Self::PreInit { init, schema } => match msg {
+                ClientMessage::ConnectionInit { payload } => match init.init(payload).await {
+                    Ok(config) => {
+                        let keep_alive_interval = config.keep_alive_interval;
+                        let keep_alive_timer = config.keep_alive_timer();
+
+                        let mut s =
+                            Reaction::ServerMessage(ServerMessage::ConnectionAck).into_stream();
+
+                        if let Some(timer) = keep_alive_timer {
+                            // The protocol has no dedicated keep-alive message, but allows Pong
+                            // to be sent as a unidirectional heartbeat.
+                            s = s
+                                .chain(stream::unfold(timer, move |timer| async move {
+                                    timer.sleep(keep_alive_interval).await;
+                                    Some((Reaction::ServerMessage(ServerMessage::Pong), timer))
+                                }))
+                                .boxed();
+                        }
+
+                        (
+                            Self::Active {
+                                config: Arc::new(config),
+                                stoppers: HashMap::new(),
+                                schema,
+                            },
+                            s.into(),
+                        )
+                    }
+                    Err(e) => (Self::Terminated, Reaction::close(4403, e.to_string())),
+                },
+                ClientMessage::Ping => (
+                    Self::PreInit { init, schema },
+                    Reaction::ServerMessage(ServerMessage::Pong)
+                        .into_stream()
+                        .into(),
+                ),
+                ClientMessage::Subscribe { .. } => {
+                    (Self::Terminated, Reaction::close(4401, "Unauthorized"))
+                }
+                ClientMessage::Pong | ClientMessage::Complete { .. } => (
+                    Self::PreInit { init, schema },
+                    stream::empty().boxed().into(),
+                ),
+            },
+            Self::Active {
+                config,
+                mut stoppers,
+                schema,
+            } => {
+                let reactions = match msg {
+                    ClientMessage::ConnectionInit { .. } => {
+                        return (
+                            Self::Terminated,
+                            Reaction::close(4429, "Too many initialisation requests"),
+                        );
+                    }
+                    ClientMessage::Ping => Reaction::ServerMessage(ServerMessage::Pong)
+                        .into_stream()
+                        .into(),
+                    ClientMessage::Pong => stream::empty().boxed().into(),
+                    ClientMessage::Subscribe { id, payload } => {
+                        // Prune finished operations first, so their ids can be reused.
+                        stoppers.retain(|_, tx| !tx.is_canceled());
+
+                        if stoppers.contains_key(&id) {
+                            return (
+                                Self::Terminated,
+                                Reaction::close(
+                                    4409,
+                                    format!("Subscriber for {id} already exists"),
+                                ),
+                            );
+                        }
+
+                        if config.max_in_flight_operations > 0
+                            && stoppers.len() >= config.max_in_flight_operations
+                        {
+                            // Too many in-flight operations. Just send back a validation error.
+                            Reaction::ServerMessage(ServerMessage::Error {
+                                id,
+                                payload: GraphQLError::ValidationError(vec![RuleError::new(
+                                    "Too many in-flight operations.",
+                                    &[],
+                                )])
+                                .into(),
+                            })
+                            .into_stream()
+                            .into()
+                        } else {
+                            // Create a channel that we can use to cancel the operation.
+                            let (tx, rx) = oneshot::channel::<()>();
+                            stoppers.insert(id.clone(), tx);
+
+                            let weight = config.operation_weight(payload.operation_name.as_deref());
+
+                            // Create the operation stream. This stream will emit Next and Error
+                            // messages, but will not emit Complete – that part is up to us.
+                            let s = crate::start(
+                                id.clone(),
+                                ExecutionParams {
+                                    start_payload: payload,
+                                    config: config.clone(),
+                                    schema: schema.clone(),
+                                },
+                            )
+                            .into_stream()
+                            .flatten()
+                            .map(operation_message)
+                            .boxed();
+
+                            // Combine this with our oneshot channel so that the stream ends if the
+                            // oneshot is ever fired, and send the Complete message once the stream
+                            // ends by itself. Neither an operation completed by the client nor a
+                            // failed one gets a Complete message.
+                            let s = stream::unfold(Some((rx, s)), move |state| {
+                                let id = id.clone();
+                                async move {
+                                    let (rx, mut s) = match state {
+                                        Some(state) => state,
+                                        None => return None,
+                                    };
+                                    let (msg, rx) = match future::select(rx, s.next()).await {
+                                        Either::Left(_) => return None,
+                                        Either::Right(next) => next,
+                                    };
+                                    match msg {
+                                        Some(msg @ ServerMessage::Error { .. }) => {
+                                            Some((msg, None))
+                                        }
+                                        Some(msg) => Some((msg, Some((rx, s)))),
+                                        None => Some((ServerMessage::Complete { id }, None)),
+                                    }
+                                }
+                            })
+                            .map(Reaction::ServerMessage);
+
+                            WeightedStream::new(s.boxed(), weight)
+                        }
+                    }
+                    ClientMessage::Complete { id } => {
+                        stoppers.remove(&id);
+                        stream::empty().boxed().into()
+                    }
+                };
+                (
+                    Self::Active {
+                        config,
+                        stoppers,
+                        schema,
+                    },
+                    reactions,
+                )
+            }
+            Self::Terminated => (self, stream::empty().boxed().into()),
+        }
+    }
+}
+
+enum ConnectionSinkState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
+    Ready {
+        state: ConnectionState<S, I>,
+    },
+    HandlingMessage {
+        #[allow(clippy::type_complexity)]
+        result: BoxFuture<'static, (ConnectionState<S, I>, WeightedStream<Reaction<S>>)>,
+    },
+    Closed,
+}
+
+/// Output of a [`Connection`] to be sent to the client.
+#[derive(Debug, PartialEq)]
+pub enum Output<S: ScalarValue> {
+    /// A message to send to the client.
+    Message(ServerMessage<S>),
+
+    /// A request to close the WebSocket connection with the given code and reason. The
+    /// [`Connection`] won't output anything after it.
+    Close {
+        /// The close code, as specified by the protocol.
+        code: u16,
+
+        /// The close reason.
+        message: String,
+    },
+}
+
+/// Implements the graphql-transport-ws protocol. This is a sink for `TryInto<ClientMessage>` and a
+/// stream of `Output`.
+pub struct Connection<S: Schema, I: Init<S::ScalarValue, S::Context>> {
+    reactions: FairScheduler<Reaction<S>>,
+    stream_waker: Option<Waker>,
+    sink_state: ConnectionSinkState<S, I>,
+    closed: bool,
+}
+
+impl<S, I> Connection<S, I>
+where
+    S: Schema,
+    I: Init<S::ScalarValue, S::Context>,
+{
+    /// Creates a new connection, which is a sink for `TryInto<ClientMessage>` and a stream of
+    /// `Output`.
+    ///
+    /// The `schema` argument should typically be an `Arc<RootNode<...>>`.
+    ///
+    /// The `init` argument is used to provide the context and additional configuration for
+    /// connections. This can be a `ConnectionConfig` if the context and configuration are already
+    /// known, or it can be a closure that gets executed asynchronously when the client sends the
+    /// ConnectionInit message. Using a closure allows you to perform authentication based on the
+    /// parameters provided by the client.
+    pub fn new(schema: S, init: I) -> Self {
+        Self {
+            reactions: FairScheduler::new(),
+            stream_waker: None,
+            sink_state: ConnectionSinkState::Ready {
+                state: ConnectionState::PreInit { init, schema },
+            },
+            closed: false,
+        }
+    }
+}
+
+impl<S, I, T> Sink<T> for Connection<S, I>
+where
+    T: TryInto<ClientMessage<S::ScalarValue>>,
+    T::Error: Error,
+    S: Schema,
+    I: Init<S::ScalarValue, S::Context> + Send,
+{
+    type Error = Infallible;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        match &mut self.sink_state {
+            ConnectionSinkState::Ready { .. } => Poll::Ready(Ok(())),
+            ConnectionSinkState::HandlingMessage { ref mut result } => {
+                match Pin::new(result).poll(cx) {
+                    Poll::Ready((state, reactions)) => {
+                        self.reactions.push(reactions);
+                        self.sink_state = ConnectionSinkState::Ready { state };
+                        if let Some(waker) = self.stream_waker.take() {
+                            // Wake up the stream so it can poll the new reactions.
+                            waker.wake();
+                        }
+                        Poll::Ready(Ok(()))
+                    }
+                    Poll::Pending => Poll::Pending,
+                }
+            }
+            ConnectionSinkState::Closed => panic!("poll_ready called after close"),
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let s = self.get_mut();
+        let state = &mut s.sink_state;
+        *state = match std::mem::replace(state, ConnectionSinkState::Closed) {
+            ConnectionSinkState::Ready { state } => {
+                match item.try_into() {
+                    Ok(msg) => ConnectionSinkState::HandlingMessage {
+                        result: state.handle_message(msg).boxed(),
+                    },
+                    Err(e) => {
+                        // If we weren't able to parse the message, close the connection.
+                        s.reactions.push(Reaction::close(4400, e.to_string()));
+                        if let Some(waker) = s.stream_waker.take() {
+                            waker.wake();
+                        }
+                        ConnectionSinkState::Ready {
+                            state: ConnectionState::Terminated,
+                        }
+                    }
+                }
+            }
+            _ => panic!("start_send called when not ready"),
+        };
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        <Self as Sink<T>>::poll_ready(self, cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        self.sink_state = ConnectionSinkState::Closed;
+        if let Some(waker) = self.stream_waker.take() {
+            // Wake up the stream so it can close too.
+            waker.wake();
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S, I> Stream for Connection<S, I>
+where
+    S: Schema,
+    I: Init<S::ScalarValue, S::Context>,
+{
+    type Item = Output<S::ScalarValue>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.stream_waker = Some(cx.waker().clone());
+
+        if self.closed {
+            return Poll::Ready(None);
+        }
+        if let ConnectionSinkState::Closed = self.sink_state {
+            return Poll::Ready(None);
+        }
+
+        // Poll the reactions for new outgoing messages.
+        if !self.reactions.is_empty() {
+            match Pin::new(&mut self.reactions).poll_next(cx) {
+                Poll::Ready(Some(reaction)) => match reaction {
+                    Reaction::ServerMessage(msg) => return Poll::Ready(Some(Output::Message(msg))),
+                    Reaction::Close { code, message } => {
+                        // Nothing else is sent after the connection is requested to be closed.
+                        self.closed = true;
+                        self.reactions = FairScheduler::new();
+                        return Poll::Ready(Some(Output::Close { code, message }));
+                    }
+                },
+                Poll::Ready(None) => {
+                    // In rare cases, the reaction stream may terminate. For example, this will
+                    // happen if the first message we receive does not require any reaction. Just
+                    // recreate it in that case.
+                    self.reactions = FairScheduler::new();
+                }
+                _ => (),
+            }
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{convert::Infallible, io, time::Duration};
+
+    use juniper::{
+        futures::sink::SinkExt, graphql_input_value, graphql_object, graphql_subscription,
+        graphql_value, graphql_vars, DefaultScalarValue, EmptyMutation, FieldResult, RootNode,
+        Variables,
+    };
+
+    use crate::{DataPayload, StartPayload};
+
+    use super::*;
+
+    struct Context(i32);
+
+    impl juniper::Context for Context {}
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        /// context just resolves to the current context.
+        async fn context(context: &Context) -> i32 {
+            context.0
+        }
+    }
+
+    struct Subscription;
+
+    #[graphql_subscription(context = Context)]
+    impl Subscription {
+        /// never never emits anything.
+        async fn never(_context: &Context) -> BoxStream<'static, FieldResult<i32>> {
+            tokio::time::sleep(Duration::from_secs(10000))
+                .map(|_| unreachable!())
+                .into_stream()
+                .boxed()
+        }
+
+        /// context emits the current context once, then never emits anything else.
+        async fn context(context: &Context) -> BoxStream<'static, FieldResult<i32>> {
+            stream::once(future::ready(Ok(context.0)))
+                .chain(
+                    tokio::time::sleep(Duration::from_secs(10000))
+                        .map(|_| unreachable!())
+                        .into_stream(),
+                )
+                .boxed()
+        }
+    }
+
+    type ClientMessage = super::ClientMessage<DefaultScalarValue>;
+    type ServerMessage = super::ServerMessage<DefaultScalarValue>;
+    type Output = super::Output<DefaultScalarValue>;
+
+    fn new_test_schema() -> Arc<RootNode<'static, Query, EmptyMutation<Context>, Subscription>> {
+        Arc::new(RootNode::new(Query, EmptyMutation::new(), Subscription))
+    }
+
+    fn subscribe(id: &str, query: &str) -> ClientMessage {
+        ClientMessage::Subscribe {
+            id: id.into(),
+            payload: StartPayload {
+                query: query.into(),
+                variables: graphql_vars! {},
+                operation_name: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_secs(0)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(subscribe("foo", "{context}")).await.unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::Next {
+                id: "foo".into(),
+                payload: DataPayload {
+                    data: graphql_value!({"context": 1}),
+                    errors: vec![],
+                },
+            }),
+            conn.next().await.unwrap()
+        );
+
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_secs(0)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(subscribe("foo", "subscription Foo {context}"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::Next {
+                id: "foo".into(),
+                payload: DataPayload {
+                    data: graphql_value!({"context": 1}),
+                    errors: vec![],
+                },
+            }),
+            conn.next().await.unwrap()
+        );
+
+        // Completing the operation by the client doesn't send a Complete message back.
+        conn.send(ClientMessage::Complete { id: "foo".into() })
+            .await
+            .unwrap();
+        conn.send(ClientMessage::Ping).await.unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::Pong),
+            conn.next().await.unwrap()
+        );
+
+        // The id of the completed operation can be reused.
+        conn.send(subscribe("foo", "subscription Foo {context}"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::Next {
+                id: "foo".into(),
+                payload: DataPayload {
+                    data: graphql_value!({"context": 1}),
+                    errors: vec![],
+                },
+            }),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ping_before_init() {
+        let mut conn = Connection::new(new_test_schema(), ConnectionConfig::new(Context(1)));
+
+        conn.send(ClientMessage::Ping).await.unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::Pong),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_before_init() {
+        let mut conn = Connection::new(new_test_schema(), ConnectionConfig::new(Context(1)));
+
+        conn.send(subscribe("foo", "{context}")).await.unwrap();
+
+        assert_eq!(
+            Output::Close {
+                code: 4401,
+                message: "Unauthorized".into(),
+            },
+            conn.next().await.unwrap()
+        );
+        assert_eq!(None, conn.next().await);
+    }
+
+    #[tokio::test]
+    async fn test_init_params_ok() {
+        let mut conn = Connection::new(new_test_schema(), |params: Variables| async move {
+            assert_eq!(params.get("foo"), Some(&graphql_input_value!("bar")));
+            Ok(ConnectionConfig::new(Context(1))) as Result<_, Infallible>
+        });
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {"foo": "bar"},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_init_params_error() {
+        let mut conn = Connection::new(new_test_schema(), |params: Variables| async move {
+            assert_eq!(params.get("foo"), Some(&graphql_input_value!("bar")));
+            Err(io::Error::new(io::ErrorKind::Other, "init error"))
+        });
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {"foo": "bar"},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Close {
+                code: 4403,
+                message: "init error".into(),
+            },
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_init() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_secs(0)),
+        );
+
+        for _ in 0..2 {
+            conn.send(ClientMessage::ConnectionInit {
+                payload: graphql_vars! {},
+            })
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+        assert_eq!(
+            Output::Close {
+                code: 4429,
+                message: "Too many initialisation requests".into(),
+            },
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_operation_id() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_secs(0)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(subscribe("foo", "subscription Foo {never}"))
+            .await
+            .unwrap();
+        conn.send(subscribe("foo", "subscription Foo {never}"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Output::Close {
+                code: 4409,
+                message: "Subscriber for foo already exists".into(),
+            },
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parse_error() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_secs(0)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(subscribe("foo", "asd")).await.unwrap();
+
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::Error { id, .. }) => assert_eq!(id, "foo"),
+            out => panic!("expected error, got: {out:?}"),
+        }
+
+        // No Complete message follows an Error one.
+        conn.send(ClientMessage::Ping).await.unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::Pong),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keep_alives() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_millis(20)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        for _ in 0..10 {
+            assert_eq!(
+                Output::Message(ServerMessage::Pong),
+                conn.next().await.unwrap()
+            );
+        }
+    }
+}
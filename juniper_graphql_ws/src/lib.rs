@@ -11,6 +11,9 @@ pub use server_message::*;
 mod schema;
 pub use schema::*;
 
+pub mod graphql_transport_ws;
+
+mod scheduler;
 mod utils;
 
 use std::{
@@ -22,13 +25,15 @@ use juniper::{
     futures::{
         channel::oneshot,
         future::{self, BoxFuture, Either, Future, FutureExt, TryFutureExt},
-        stream::{self, BoxStream, SelectAll, StreamExt},
+        stream::{self, BoxStream, StreamExt},
         task::{Context, Poll, Waker},
         Sink, Stream,
     },
-    GraphQLError, RuleError, ScalarValue, Variables,
+    runtime_timer, GraphQLError, RuleError, ScalarValue, Timer, Variables,
 };
 
+use self::scheduler::{FairScheduler, WeightedStream};
+
 struct ExecutionParams<S: Schema> {
     start_payload: StartPayload<S::ScalarValue>,
     config: Arc<ConnectionConfig<S::Context>>,
@@ -41,6 +46,9 @@ pub struct ConnectionConfig<CtxT> {
     context: CtxT,
     max_in_flight_operations: usize,
     keep_alive_interval: Duration,
+    timer: Option<Arc<dyn Timer>>,
+    default_operation_weight: usize,
+    operation_weights: HashMap<String, usize>,
 }
 
 impl<CtxT> ConnectionConfig<CtxT> {
@@ -50,6 +58,9 @@ impl<CtxT> ConnectionConfig<CtxT> {
             context,
             max_in_flight_operations: 0,
             keep_alive_interval: Duration::from_secs(15),
+            timer: runtime_timer(),
+            default_operation_weight: 1,
+            operation_weights: HashMap::new(),
         }
     }
 
@@ -62,13 +73,72 @@ impl<CtxT> ConnectionConfig<CtxT> {
         self
     }
 
-    /// Specifies the interval at which to send keep-alives. Specifying a zero duration will
-    /// disable keep-alives. By default, keep-alives are sent every 15 seconds.
+    /// Specifies the interval at which to send keep-alives (`ka` messages for the `graphql-ws`
+    /// protocol and `pong` messages for the `graphql-transport-ws` one). Specifying a zero duration
+    /// will disable keep-alives. By default, keep-alives are sent every 15 seconds.
+    ///
+    /// Keep-alives are scheduled by a [`Timer`], so are disabled (with a warning logged) if there
+    /// is none. See [`ConnectionConfig::with_timer()`] for details.
     #[must_use]
     pub fn with_keep_alive_interval(mut self, interval: Duration) -> Self {
         self.keep_alive_interval = interval;
         self
     }
+
+    /// Specifies the [`Timer`] used to schedule keep-alives. By default, the timer of the async
+    /// runtime enabled via the `tokio` (default) or `async-std` Cargo feature is used. If there is
+    /// no timer (e.g. with `default-features = false`), keep-alives are disabled and a warning is
+    /// logged on every accepted connection, so a timer should be specified explicitly in that case.
+    #[must_use]
+    pub fn with_timer(mut self, timer: impl Timer + 'static) -> Self {
+        self.timer = Some(Arc::new(timer));
+        self
+    }
+
+    /// Returns the [`Timer`] to schedule keep-alives with, if they're enabled.
+    fn keep_alive_timer(&self) -> Option<Arc<dyn Timer>> {
+        if self.keep_alive_interval == Duration::from_secs(0) {
+            return None;
+        }
+        if self.timer.is_none() {
+            log::warn!(
+                "Keep-alives are disabled, as no `Timer` is specified: enable the `tokio` or \
+                 `async-std` Cargo feature of `juniper_graphql_ws`, or use \
+                 `ConnectionConfig::with_timer()`",
+            );
+        }
+        self.timer.clone()
+    }
+
+    /// Specifies the weight of operations without an explicitly configured one. Messages of
+    /// concurrent operations are interleaved in a weighted round-robin fashion, so an operation may
+    /// send up to its weight messages in a row before the other ready operations get their turn.
+    /// This prevents a single busy subscription from starving the others sharing the connection.
+    /// A zero weight is treated as 1. By default, all operations have a weight of 1.
+    #[must_use]
+    pub fn with_default_operation_weight(mut self, weight: usize) -> Self {
+        self.default_operation_weight = weight;
+        self
+    }
+
+    /// Specifies the weight of operations with the given operation name, overriding the default
+    /// one. See [`ConnectionConfig::with_default_operation_weight()`] for details.
+    #[must_use]
+    pub fn with_operation_weight(
+        mut self,
+        operation_name: impl Into<String>,
+        weight: usize,
+    ) -> Self {
+        self.operation_weights.insert(operation_name.into(), weight);
+        self
+    }
+
+    fn operation_weight(&self, operation_name: Option<&str>) -> usize {
+        operation_name
+            .and_then(|name| self.operation_weights.get(name))
+            .copied()
+            .unwrap_or(self.default_operation_weight)
+    }
 }
 
 impl<S: ScalarValue, CtxT: Unpin + Send + 'static> Init<S, CtxT> for ConnectionConfig<CtxT> {
@@ -141,9 +211,9 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
     async fn handle_message(
         self,
         msg: ClientMessage<S::ScalarValue>,
-    ) -> (Self, BoxStream<'static, Reaction<S>>) {
+    ) -> (Self, WeightedStream<Reaction<S>>) {
         if let ClientMessage::ConnectionTerminate = msg {
-            return (self, Reaction::EndStream.into_stream());
+            return (self, Reaction::EndStream.into_stream().into());
         }
 
         match self {
@@ -151,13 +221,14 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                 ClientMessage::ConnectionInit { payload } => match init.init(payload).await {
                     Ok(config) => {
                         let keep_alive_interval = config.keep_alive_interval;
+                        let keep_alive_timer = config.keep_alive_timer();
 
                         let mut s = stream::iter(vec![Reaction::ServerMessage(
                             ServerMessage::ConnectionAck,
                         )])
                         .boxed();
 
-                        if keep_alive_interval > Duration::from_secs(0) {
+                        if let Some(timer) = keep_alive_timer {
                             s = s
                                 .chain(
                                     Reaction::ServerMessage(ServerMessage::ConnectionKeepAlive)
@@ -165,11 +236,11 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                                 )
                                 .boxed();
                             s = s
-                                .chain(stream::unfold((), move |_| async move {
-                                    tokio::time::sleep(keep_alive_interval).await;
+                                .chain(stream::unfold(timer, move |timer| async move {
+                                    timer.sleep(keep_alive_interval).await;
                                     Some((
                                         Reaction::ServerMessage(ServerMessage::ConnectionKeepAlive),
-                                        (),
+                                        timer,
                                     ))
                                 }))
                                 .boxed();
@@ -181,7 +252,7 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                                 stoppers: HashMap::new(),
                                 schema,
                             },
-                            s,
+                            s.into(),
                         )
                     }
                     Err(e) => (
@@ -194,10 +265,14 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                             }),
                             Reaction::EndStream,
                         ])
-                        .boxed(),
+                        .boxed()
+                        .into(),
                     ),
                 },
-                _ => (Self::PreInit { init, schema }, stream::empty().boxed()),
+                _ => (
+                    Self::PreInit { init, schema },
+                    stream::empty().boxed().into(),
+                ),
             },
             Self::Active {
                 config,
@@ -209,7 +284,7 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                         if stoppers.contains_key(&id) {
                             // We already have an operation with this id, so we can't start a new
                             // one.
-                            stream::empty().boxed()
+                            stream::empty().boxed().into()
                         } else {
                             // Go ahead and prune canceled stoppers before adding a new one.
                             stoppers.retain(|_, tx| !tx.is_canceled());
@@ -229,14 +304,18 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                                     Reaction::ServerMessage(ServerMessage::Complete { id }),
                                 ])
                                 .boxed()
+                                .into()
                             } else {
                                 // Create a channel that we can use to cancel the operation.
                                 let (tx, rx) = oneshot::channel::<()>();
                                 stoppers.insert(id.clone(), tx);
 
+                                let weight =
+                                    config.operation_weight(payload.operation_name.as_deref());
+
                                 // Create the operation stream. This stream will emit Data and Error
                                 // messages, but will not emit Complete – that part is up to us.
-                                let s = Self::start(
+                                let s = start(
                                     id.clone(),
                                     ExecutionParams {
                                         start_payload: payload,
@@ -263,15 +342,15 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                                         .into_stream(),
                                 );
 
-                                s.boxed()
+                                WeightedStream::new(s.boxed(), weight)
                             }
                         }
                     }
                     ClientMessage::Stop { id } => {
                         stoppers.remove(&id);
-                        stream::empty().boxed()
+                        stream::empty().boxed().into()
                     }
-                    _ => stream::empty().boxed(),
+                    _ => stream::empty().boxed().into(),
                 };
                 (
                     Self::Active {
@@ -282,49 +361,54 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                     reactions,
                 )
             }
-            Self::Terminated => (self, stream::empty().boxed()),
+            Self::Terminated => (self, stream::empty().boxed().into()),
         }
     }
+}
 
-    async fn start(id: String, params: ExecutionParams<S>) -> BoxStream<'static, Reaction<S>> {
-        // TODO: This could be made more efficient if `juniper` exposed
-        //       functionality to allow us to parse and validate the query,
-        //       determine whether it's a subscription, and then execute it.
-        //       For now, the query gets parsed and validated twice.
-
-        let params = Arc::new(params);
-
-        // Try to execute this as a query or mutation.
-        match juniper::execute(
-            &params.start_payload.query,
-            params.start_payload.operation_name.as_deref(),
-            params.schema.root_node(),
-            &params.start_payload.variables,
-            &params.config.context,
-        )
-        .await
-        {
-            Ok((data, errors)) => {
-                return Reaction::ServerMessage(ServerMessage::Data {
-                    id: id.clone(),
-                    payload: DataPayload { data, errors },
-                })
-                .into_stream();
-            }
-            Err(GraphQLError::IsSubscription) => {}
-            Err(e) => {
-                return Reaction::ServerMessage(ServerMessage::Error {
-                    id: id.clone(),
-                    // e only references data owned by params. The new ErrorPayload will continue to keep that data alive.
-                    payload: unsafe { ErrorPayload::new_unchecked(Box::new(params.clone()), e) },
-                })
-                .into_stream();
-            }
+/// Starts executing the operation described by the `params`, returning a stream of `Data` or
+/// `Error` reactions for it. The stream doesn't emit `Complete`, as that's up to the protocol.
+async fn start<S: Schema>(
+    id: String,
+    params: ExecutionParams<S>,
+) -> BoxStream<'static, Reaction<S>> {
+    // TODO: This could be made more efficient if `juniper` exposed
+    //       functionality to allow us to parse and validate the query,
+    //       determine whether it's a subscription, and then execute it.
+    //       For now, the query gets parsed and validated twice.
+
+    let params = Arc::new(params);
+
+    // Try to execute this as a query or mutation.
+    match juniper::execute(
+        &params.start_payload.query,
+        params.start_payload.operation_name.as_deref(),
+        params.schema.root_node(),
+        &params.start_payload.variables,
+        &params.config.context,
+    )
+    .await
+    {
+        Ok((data, errors)) => {
+            return Reaction::ServerMessage(ServerMessage::Data {
+                id: id.clone(),
+                payload: DataPayload { data, errors },
+            })
+            .into_stream();
+        }
+        Err(GraphQLError::IsSubscription) => {}
+        Err(e) => {
+            return Reaction::ServerMessage(ServerMessage::Error {
+                id: id.clone(),
+                // e only references data owned by params. The new ErrorPayload will continue to keep that data alive.
+                payload: unsafe { ErrorPayload::new_unchecked(Box::new(params.clone()), e) },
+            })
+            .into_stream();
         }
-
-        // Try to execute as a subscription.
-        SubscriptionStart::new(id, params.clone()).boxed()
     }
+
+    // Try to execute as a subscription.
+    SubscriptionStart::new(id, params.clone()).boxed()
 }
 
 struct InterruptableStream<S> {
@@ -475,7 +559,7 @@ enum ConnectionSinkState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
     },
     HandlingMessage {
         #[allow(clippy::type_complexity)]
-        result: BoxFuture<'static, (ConnectionState<S, I>, BoxStream<'static, Reaction<S>>)>,
+        result: BoxFuture<'static, (ConnectionState<S, I>, WeightedStream<Reaction<S>>)>,
     },
     Closed,
 }
@@ -483,7 +567,7 @@ enum ConnectionSinkState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
 /// Implements the graphql-ws protocol. This is a sink for `TryInto<ClientMessage>` and a stream of
 /// `ServerMessage`.
 pub struct Connection<S: Schema, I: Init<S::ScalarValue, S::Context>> {
-    reactions: SelectAll<BoxStream<'static, Reaction<S>>>,
+    reactions: FairScheduler<Reaction<S>>,
     stream_waker: Option<Waker>,
     sink_state: ConnectionSinkState<S, I>,
 }
@@ -504,7 +588,7 @@ where
     /// parameters provided by the client.
     pub fn new(schema: S, init: I) -> Self {
         Self {
-            reactions: SelectAll::new(),
+            reactions: FairScheduler::new(),
             stream_waker: None,
             sink_state: ConnectionSinkState::Ready {
                 state: ConnectionState::PreInit { init, schema },
@@ -606,7 +690,7 @@ where
                     // In rare cases, the reaction stream may terminate. For example, this will
                     // happen if the first message we receive does not require any reaction. Just
                     // recreate it in that case.
-                    self.reactions = SelectAll::new();
+                    self.reactions = FairScheduler::new();
                 }
                 _ => (),
             }
@@ -665,6 +749,11 @@ mod test {
                 .boxed()
         }
 
+        /// repeat emits the current context over and over again.
+        async fn repeat(context: &Context) -> BoxStream<'static, FieldResult<i32>> {
+            stream::repeat(Ok(context.0)).boxed()
+        }
+
         /// error emits an error once, then never emits anything else.
         async fn error(_context: &Context) -> BoxStream<'static, FieldResult<i32>> {
             stream::once(future::ready(Err(FieldError::new(
@@ -799,6 +888,47 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn test_operation_weights() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_operation_weight("Heavy", 2),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(ServerMessage::ConnectionAck, conn.next().await.unwrap());
+
+        for (id, name) in [("foo", "Light"), ("bar", "Heavy")] {
+            conn.send(ClientMessage::Start {
+                id: id.into(),
+                payload: StartPayload {
+                    query: format!("subscription {name} {{repeat}}"),
+                    variables: graphql_vars! {},
+                    operation_name: Some(name.into()),
+                },
+            })
+            .await
+            .unwrap();
+        }
+
+        let mut ids = vec![];
+        for _ in 0..6 {
+            match conn.next().await.unwrap() {
+                ServerMessage::Data { id, .. } => ids.push(id),
+                msg => panic!("unexpected message: {msg:?}"),
+            }
+        }
+
+        assert_eq!(ids, ["foo", "bar", "bar", "foo", "bar", "bar"]);
+    }
+
     #[tokio::test]
     async fn test_init_params_ok() {
         let mut conn = Connection::new(new_test_schema(), |params: Variables| async move {
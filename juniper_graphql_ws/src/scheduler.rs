@@ -0,0 +1,123 @@
+use std::{collections::VecDeque, pin::Pin};
+
+use juniper::futures::{
+    stream::BoxStream,
+    task::{Context, Poll},
+    Stream,
+};
+
+/// A stream together with its share of a [`FairScheduler`]'s output.
+pub(crate) struct WeightedStream<T> {
+    stream: BoxStream<'static, T>,
+    weight: usize,
+    credit: usize,
+}
+
+impl<T> WeightedStream<T> {
+    /// Wraps the given stream, letting it yield up to `weight` items in a row whenever it's its
+    /// turn. A zero weight is treated as 1.
+    pub(crate) fn new(stream: BoxStream<'static, T>, weight: usize) -> Self {
+        Self {
+            stream,
+            weight: weight.max(1),
+            credit: 0,
+        }
+    }
+}
+
+impl<T> From<BoxStream<'static, T>> for WeightedStream<T> {
+    fn from(stream: BoxStream<'static, T>) -> Self {
+        Self::new(stream, 1)
+    }
+}
+
+/// Interleaves the items of multiple streams in a weighted round-robin fashion, so a stream that
+/// is always ready (like a busy subscription) can't starve the others.
+///
+/// On its turn, a ready stream may yield up to its weight items before the next stream is polled.
+/// Streams that aren't ready forfeit the rest of their turn.
+pub(crate) struct FairScheduler<T> {
+    queue: VecDeque<WeightedStream<T>>,
+}
+
+impl<T> FairScheduler<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            queue: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, stream: impl Into<WeightedStream<T>>) {
+        self.queue.push_back(stream.into());
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl<T> Stream for FairScheduler<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        // Every stream is polled at most once, so each of them registers the waker.
+        for _ in 0..self.queue.len() {
+            let mut entry = self.queue.pop_front().unwrap();
+            if entry.credit == 0 {
+                entry.credit = entry.weight;
+            }
+            match entry.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    entry.credit -= 1;
+                    if entry.credit > 0 {
+                        self.queue.push_front(entry);
+                    } else {
+                        self.queue.push_back(entry);
+                    }
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(None) => {}
+                Poll::Pending => {
+                    entry.credit = 0;
+                    self.queue.push_back(entry);
+                }
+            }
+        }
+
+        if self.queue.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use juniper::futures::stream::{self, StreamExt as _};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn interleaves_streams_by_weight() {
+        let mut scheduler = FairScheduler::new();
+        scheduler.push(WeightedStream::new(stream::repeat('a').take(6).boxed(), 2));
+        scheduler.push(stream::repeat('b').take(3).boxed());
+        scheduler.push(WeightedStream::new(stream::iter(['c']).boxed(), 0));
+
+        let items: String = scheduler.collect().await;
+
+        assert_eq!(items, "aabcaabaab");
+    }
+
+    #[tokio::test]
+    async fn skips_pending_streams() {
+        let mut scheduler = FairScheduler::new();
+        scheduler.push(stream::pending::<char>().boxed());
+        scheduler.push(stream::iter(['a', 'b']).boxed());
+
+        assert_eq!(scheduler.next().await, Some('a'));
+        assert_eq!(scheduler.next().await, Some('b'));
+        assert!(!scheduler.is_empty());
+    }
+}
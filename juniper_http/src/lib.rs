@@ -0,0 +1,555 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+#[cfg(feature = "tower")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tower")))]
+pub mod service;
+
+use std::{error::Error, fmt, str};
+
+use futures::{stream, StreamExt as _};
+use juniper::{
+    executor::{REQUEST_ID_HEADER, TRACEPARENT_HEADER},
+    http::{problem::RequestErrorKind, GraphQLBatchRequest, GraphQLBatchResponse, GraphQLRequest},
+    CancellationToken, Correlation, GraphQLSubscriptionType, GraphQLTypeAsync, InputValue,
+    RootNode, ScalarValue,
+};
+use url::form_urlencoded;
+
+pub use juniper::http::{
+    apollo_sandbox::{apollo_sandbox_source, ApolloSandboxOptions},
+    graphiql::{graphiql_source, graphiql_source_with_options, GraphiQLOptions},
+    playground::playground_source,
+};
+
+/// `Content-Type` of a JSON-encoded [GraphQL] request or response.
+///
+/// [GraphQL]: https://graphql.org
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// `Content-Type` of a request containing a raw [GraphQL] query.
+///
+/// [GraphQL]: https://graphql.org
+pub const GRAPHQL_CONTENT_TYPE: &str = "application/graphql";
+
+/// Incoming HTTP request, as seen by this crate.
+///
+/// Implement this trait for the request type of a web framework (or a wrapper
+/// around it) to [parse](parse_request()) its [GraphQL] requests. It's
+/// implemented for the [`http`] crate types already.
+///
+/// [GraphQL]: https://graphql.org
+pub trait HttpRequest {
+    /// Returns the HTTP method of this request (like `GET` or `POST`).
+    fn method(&self) -> &str;
+
+    /// Returns the value of the header with the provided `name`, if it's
+    /// present and is a valid string.
+    fn header(&self, name: &str) -> Option<&str>;
+
+    /// Returns the query string of this request (without the leading `?`).
+    fn query_string(&self) -> Option<&str>;
+
+    /// Returns the value of the `Content-Type` header of this request.
+    fn content_type(&self) -> Option<&str> {
+        self.header("content-type")
+    }
+}
+
+impl<B> HttpRequest for http::Request<B> {
+    fn method(&self) -> &str {
+        self.method().as_str()
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers().get(name).and_then(|v| v.to_str().ok())
+    }
+
+    fn query_string(&self) -> Option<&str> {
+        self.uri().query()
+    }
+}
+
+impl HttpRequest for http::request::Parts {
+    fn method(&self) -> &str {
+        self.method.as_str()
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    fn query_string(&self) -> Option<&str> {
+        self.uri.query()
+    }
+}
+
+/// Parses the [GraphQL] request of the provided HTTP request with the `body`.
+///
+/// Handles:
+/// - `GET` requests, with the [GraphQL] request described by `query`,
+///   `operationName` and `variables` (JSON-encoded) query string parameters
+///   (the `body` is ignored);
+/// - `POST` requests with `application/json` body, containing either a single
+///   or a batched [GraphQL] request;
+/// - `POST` requests with `application/graphql` body, containing a raw
+///   [GraphQL] query.
+///
+/// # Errors
+///
+/// If the request is not one of the above, or is malformed.
+///
+/// [GraphQL]: https://graphql.org
+pub fn parse_request<S: ScalarValue>(
+    req: &impl HttpRequest,
+    body: &[u8],
+) -> Result<GraphQLBatchRequest<S>, RequestError> {
+    let method = req.method();
+    if method.eq_ignore_ascii_case("GET") {
+        parse_get(req.query_string().unwrap_or_default()).map(GraphQLBatchRequest::Single)
+    } else if method.eq_ignore_ascii_case("POST") {
+        parse_post(req.content_type(), body)
+    } else {
+        Err(RequestError::MethodNotAllowed)
+    }
+}
+
+/// Parses the [GraphQL] request of a `GET` HTTP request from its query string
+/// (without the leading `?`).
+///
+/// # Errors
+///
+/// If the `query` parameter is missing, any parameter is specified multiple
+/// times, or the `variables` parameter is not a valid JSON.
+///
+/// [GraphQL]: https://graphql.org
+pub fn parse_get<S: ScalarValue>(query_string: &str) -> Result<GraphQLRequest<S>, RequestError> {
+    let (mut query, mut operation_name, mut variables) = (None, None, None);
+    for (key, value) in form_urlencoded::parse(query_string.as_bytes()) {
+        match key.as_ref() {
+            "query" => {
+                if query.is_some() {
+                    return Err(RequestError::DuplicateParameter("query"));
+                }
+                query = Some(value.into_owned());
+            }
+            "operationName" => {
+                if operation_name.is_some() {
+                    return Err(RequestError::DuplicateParameter("operationName"));
+                }
+                operation_name = Some(value.into_owned());
+            }
+            "variables" => {
+                let parsed = serde_json::from_str::<InputValue<S>>(&value)
+                    .map_err(RequestError::Variables)?;
+                if variables.replace(parsed).is_some() {
+                    return Err(RequestError::DuplicateParameter("variables"));
+                }
+            }
+            _ => {}
+        }
+    }
+    let query = query.ok_or(RequestError::MissingQuery)?;
+    Ok(GraphQLRequest::new(query, operation_name, variables))
+}
+
+/// Parses the [GraphQL] request of a `POST` HTTP request from its
+/// `Content-Type` and `body`.
+///
+/// # Errors
+///
+/// If the `content_type` is neither `application/json` nor
+/// `application/graphql`, or the `body` is malformed.
+///
+/// [GraphQL]: https://graphql.org
+pub fn parse_post<S: ScalarValue>(
+    content_type: Option<&str>,
+    body: &[u8],
+) -> Result<GraphQLBatchRequest<S>, RequestError> {
+    // Parameters (like `charset`) are irrelevant for determining the request
+    // format.
+    let mime = content_type
+        .and_then(|ct| ct.split(';').next())
+        .map(str::trim)
+        .unwrap_or_default();
+    if mime.eq_ignore_ascii_case(JSON_CONTENT_TYPE) {
+        serde_json::from_slice(body).map_err(RequestError::Json)
+    } else if mime.eq_ignore_ascii_case(GRAPHQL_CONTENT_TYPE) {
+        let query = str::from_utf8(body).map_err(RequestError::Utf8)?;
+        Ok(GraphQLBatchRequest::Single(GraphQLRequest::new(
+            query.into(),
+            None,
+            None,
+        )))
+    } else {
+        Err(RequestError::UnsupportedContentType)
+    }
+}
+
+/// Returns the [`Correlation`] of the provided HTTP request, described by its
+/// `traceparent` and `x-request-id` headers.
+///
+/// The request ID (if any) is expected to be echoed in the `x-request-id`
+/// header of the response.
+pub fn correlation(req: &impl HttpRequest) -> Correlation {
+    Correlation::from_headers(
+        req.header(TRACEPARENT_HEADER),
+        req.header(REQUEST_ID_HEADER),
+    )
+}
+
+/// Returns the HTTP status code to respond with the provided
+/// [`GraphQLBatchResponse`].
+///
+/// That's `200 OK` if all the executed operations succeeded, or
+/// `400 Bad Request` otherwise.
+pub fn response_status<S: ScalarValue>(resp: &GraphQLBatchResponse<S>) -> u16 {
+    if resp.is_ok() {
+        200
+    } else {
+        400
+    }
+}
+
+/// Options of handling batched requests, containing a JSON array of operations
+/// in a single body.
+///
+/// By default, neither the number of operations in a batch nor the number of
+/// them executed concurrently is limited.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BatchConfig {
+    max_size: Option<usize>,
+    max_concurrency: Option<usize>,
+}
+
+impl BatchConfig {
+    /// Creates a new [`BatchConfig`] with no limits set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of operations in a single batch.
+    ///
+    /// Larger batches are rejected by [`BatchConfig::check()`].
+    #[must_use]
+    pub fn max_size(mut self, max: usize) -> Self {
+        self.max_size = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of operations of a batch executed concurrently.
+    ///
+    /// Use `1` to execute the operations one by one, in order.
+    #[must_use]
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        self.max_concurrency = Some(max.max(1));
+        self
+    }
+
+    /// Checks whether the provided [`GraphQLBatchRequest`] complies with this
+    /// [`BatchConfig`].
+    ///
+    /// # Errors
+    ///
+    /// If the request is a batch larger than the
+    /// [maximum size](BatchConfig::max_size).
+    pub fn check<S: ScalarValue>(&self, req: &GraphQLBatchRequest<S>) -> Result<(), RequestError> {
+        match (req, self.max_size) {
+            (GraphQLBatchRequest::Batch(reqs), Some(max)) if reqs.len() > max => {
+                Err(RequestError::BatchTooLarge {
+                    size: reqs.len(),
+                    max,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Executes the provided [`GraphQLBatchRequest`], limiting the number of
+    /// its operations executed concurrently.
+    ///
+    /// Responses of a batch are in the order of its operations.
+    pub async fn execute<'a, QueryT, MutationT, SubscriptionT, S>(
+        &self,
+        req: &'a GraphQLBatchRequest<S>,
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+        correlation: &Correlation,
+        cancellation: &CancellationToken,
+    ) -> GraphQLBatchResponse<S>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = QueryT::Context>,
+        SubscriptionT::TypeInfo: Sync,
+        S: ScalarValue + Send + Sync,
+    {
+        match (req, self.max_concurrency) {
+            (GraphQLBatchRequest::Batch(reqs), Some(max)) => {
+                // Futures are created upfront, as a closure being held across
+                // the `.await` prevents the returned future from being `Send`
+                // for any lifetime, as required by spawning it.
+                let futs = reqs
+                    .iter()
+                    .map(|req| {
+                        req.execute_cancellable(root_node, context, correlation, cancellation)
+                    })
+                    .collect::<Vec<_>>();
+                GraphQLBatchResponse::Batch(stream::iter(futs).buffered(max).collect().await)
+            }
+            _ => {
+                req.execute_cancellable(root_node, context, correlation, cancellation)
+                    .await
+            }
+        }
+    }
+}
+
+/// Error of parsing an HTTP request into a [GraphQL] request.
+///
+/// [GraphQL]: https://graphql.org
+#[derive(Debug)]
+pub enum RequestError {
+    /// HTTP method of the request is neither `GET` nor `POST`.
+    MethodNotAllowed,
+
+    /// `Content-Type` of a `POST` request is neither `application/json` nor
+    /// `application/graphql`.
+    UnsupportedContentType,
+
+    /// `query` parameter of a `GET` request is missing.
+    MissingQuery,
+
+    /// The named parameter of a `GET` request is specified multiple times.
+    DuplicateParameter(&'static str),
+
+    /// `variables` parameter of a `GET` request is not a valid JSON.
+    Variables(serde_json::Error),
+
+    /// `application/graphql` body is not a valid UTF-8.
+    Utf8(str::Utf8Error),
+
+    /// `application/json` body is not a valid [GraphQL] request.
+    ///
+    /// [GraphQL]: https://graphql.org
+    Json(serde_json::Error),
+
+    /// Batched request contains more operations than
+    /// [allowed](BatchConfig::max_size).
+    BatchTooLarge {
+        /// Number of operations in the batch.
+        size: usize,
+
+        /// Maximum allowed number of operations in a batch.
+        max: usize,
+    },
+}
+
+impl RequestError {
+    /// Returns the [`RequestErrorKind`] of this [`RequestError`].
+    pub fn kind(&self) -> RequestErrorKind {
+        match self {
+            Self::MethodNotAllowed => RequestErrorKind::MethodNotAllowed,
+            Self::UnsupportedContentType => RequestErrorKind::UnsupportedContentType,
+            Self::MissingQuery
+            | Self::DuplicateParameter(_)
+            | Self::Variables(_)
+            | Self::Utf8(_)
+            | Self::Json(_)
+            | Self::BatchTooLarge { .. } => RequestErrorKind::MalformedRequest,
+        }
+    }
+
+    /// Returns the HTTP status code to respond with this [`RequestError`].
+    pub fn status_code(&self) -> u16 {
+        self.kind().status()
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MethodNotAllowed => write!(f, "expected GET or POST request"),
+            Self::UnsupportedContentType => write!(
+                f,
+                "expected `{JSON_CONTENT_TYPE}` or `{GRAPHQL_CONTENT_TYPE}` content type",
+            ),
+            Self::MissingQuery => write!(f, "`query` parameter is missing"),
+            Self::DuplicateParameter(name) => {
+                write!(f, "`{name}` parameter is specified multiple times")
+            }
+            Self::Variables(e) => write!(f, "invalid `variables` parameter: {e}"),
+            Self::Utf8(e) => write!(f, "invalid UTF-8 body: {e}"),
+            Self::Json(e) => write!(f, "invalid JSON body: {e}"),
+            Self::BatchTooLarge { size, max } => {
+                write!(f, "batch of {size} operations exceeds the maximum of {max}")
+            }
+        }
+    }
+}
+
+impl Error for RequestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Variables(e) | Self::Json(e) => Some(e),
+            Self::Utf8(e) => Some(e),
+            Self::MethodNotAllowed
+            | Self::UnsupportedContentType
+            | Self::MissingQuery
+            | Self::DuplicateParameter(_)
+            | Self::BatchTooLarge { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use juniper::{
+        graphql_input_value,
+        http::{GraphQLBatchRequest, GraphQLRequest},
+        tests::fixtures::starwars::schema::{Database, Query},
+        CancellationToken, Correlation, EmptyMutation, EmptySubscription, RootNode,
+    };
+
+    use super::{parse_request, response_status, BatchConfig, RequestError};
+
+    fn request(
+        method: &str,
+        uri: &str,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> Result<GraphQLBatchRequest, RequestError> {
+        let mut req = http::Request::builder().method(method).uri(uri);
+        if let Some(ct) = content_type {
+            req = req.header("content-type", ct);
+        }
+        parse_request(&req.body(()).unwrap(), body.as_bytes())
+    }
+
+    #[test]
+    fn parses_get_request() {
+        let req = request(
+            "GET",
+            "/?query=query%20Q%28%24id%3A%20String%21%29%20%7B%20human%28id%3A%20%24id%29%20%7B%20id%20%7D%20%7D\
+             &operationName=Q&variables=%7B%22id%22%3A%20%221000%22%7D",
+            None,
+            "",
+        )
+        .unwrap();
+
+        assert_eq!(
+            req,
+            GraphQLBatchRequest::Single(GraphQLRequest::new(
+                "query Q($id: String!) { human(id: $id) { id } }".into(),
+                Some("Q".into()),
+                Some(graphql_input_value!({"id": "1000"})),
+            )),
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_get_requests() {
+        assert!(matches!(
+            request("GET", "/", None, ""),
+            Err(RequestError::MissingQuery),
+        ));
+        assert!(matches!(
+            request("GET", "/?query=a&query=b", None, ""),
+            Err(RequestError::DuplicateParameter("query")),
+        ));
+        assert!(matches!(
+            request("GET", "/?query=a&variables=%7B", None, ""),
+            Err(RequestError::Variables(_)),
+        ));
+    }
+
+    #[test]
+    fn parses_post_requests() {
+        let req = request(
+            "POST",
+            "/",
+            Some("application/json; charset=utf-8"),
+            r#"[{"query": "{ a }"}, {"query": "{ b }"}]"#,
+        )
+        .unwrap();
+        assert!(matches!(req, GraphQLBatchRequest::Batch(reqs) if reqs.len() == 2));
+
+        let req = request("POST", "/", Some("application/graphql"), "{ a }").unwrap();
+        assert_eq!(
+            req,
+            GraphQLBatchRequest::Single(GraphQLRequest::new("{ a }".into(), None, None)),
+        );
+    }
+
+    #[test]
+    fn maps_errors_to_status_codes() {
+        let err = request("PUT", "/", None, "").unwrap_err();
+        assert_eq!(err.status_code(), 405);
+
+        let err = request("POST", "/", Some("text/plain"), "{ a }").unwrap_err();
+        assert_eq!(err.status_code(), 415);
+
+        let err = request("POST", "/", Some("application/json"), "[]").unwrap_err();
+        assert_eq!(err.status_code(), 400);
+    }
+
+    #[test]
+    fn checks_batch_size() {
+        let req = request(
+            "POST",
+            "/",
+            Some("application/json"),
+            r#"[{"query": "{ a }"}, {"query": "{ b }"}]"#,
+        )
+        .unwrap();
+
+        assert!(BatchConfig::new().check(&req).is_ok());
+        assert!(BatchConfig::new().max_size(2).check(&req).is_ok());
+        assert!(matches!(
+            BatchConfig::new().max_size(1).check(&req),
+            Err(RequestError::BatchTooLarge { size: 2, max: 1 }),
+        ));
+    }
+
+    #[tokio::test]
+    async fn executes_batch_in_order() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+        let req = request(
+            "POST",
+            "/",
+            Some("application/json"),
+            r#"[{"query": "{ hero { name } }"}, {"query": "{ human(id: \"1000\") { id } }"}]"#,
+        )
+        .unwrap();
+
+        let resp = BatchConfig::new()
+            .max_concurrency(1)
+            .execute(
+                &req,
+                &schema,
+                &Database::new(),
+                &Correlation::default(),
+                &CancellationToken::new(),
+            )
+            .await;
+
+        assert_eq!(response_status(&resp), 200);
+        assert_eq!(
+            serde_json::to_value(&resp).unwrap(),
+            serde_json::json!([
+                {"data": {"hero": {"name": "R2-D2"}}},
+                {"data": {"human": {"id": "1000"}}},
+            ]),
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! [`Service`] serving [GraphQL] over HTTP.
+//!
+//! [GraphQL]: https://graphql.org
+
+use std::{
+    convert::Infallible,
+    error::Error as StdError,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Buf as _;
+use http::{header, Method, Request, Response, StatusCode};
+use http_body::Body;
+use juniper::{
+    executor::REQUEST_ID_HEADER, CancellationToken, GraphQLSubscriptionType, GraphQLTypeAsync,
+    RootNode, ScalarValue,
+};
+use tower_service::Service;
+
+use crate::{BatchConfig, JSON_CONTENT_TYPE};
+
+/// [`Service`] executing [GraphQL] requests against a schema, so it can be
+/// composed with [`tower`] middleware (like timeouts, rate limits or tracing)
+/// and mounted into any [`tower`]-based web server.
+///
+/// Performs the whole pipeline of [parsing](crate::parse_request()),
+/// validating, executing and serializing, responding with a JSON body. Its
+/// [`Service::Error`] is [`Infallible`], as any error is rendered into a
+/// response.
+///
+/// Requests are executed with the context provided on creation, unless a
+/// middleware has put an `Arc<CtxT>` into the [`Request::extensions()`] (to
+/// authenticate a user, for example).
+///
+/// # Example
+///
+/// ```rust
+/// use std::{sync::Arc, time::Duration};
+///
+/// use juniper::{
+///     tests::fixtures::starwars::schema::{Database, Query},
+///     EmptyMutation, EmptySubscription, RootNode,
+/// };
+/// use juniper_http::service::GraphQLService;
+/// use tower::ServiceBuilder;
+///
+/// type Schema = RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>>;
+///
+/// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+///
+/// let service = ServiceBuilder::new()
+///     .timeout(Duration::from_secs(30))
+///     .service(GraphQLService::new(Arc::new(schema), Arc::new(Database::new())));
+/// # let _ = service;
+/// ```
+///
+/// [GraphQL]: https://graphql.org
+/// [`tower`]: https://docs.rs/tower
+pub struct GraphQLService<QueryT, MutationT, SubscriptionT, CtxT, S>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    S: ScalarValue,
+{
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: Arc<CtxT>,
+    batch: BatchConfig,
+}
+
+impl<QueryT, MutationT, SubscriptionT, CtxT, S>
+    GraphQLService<QueryT, MutationT, SubscriptionT, CtxT, S>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    S: ScalarValue,
+{
+    /// Creates a new [`GraphQLService`] executing requests against the
+    /// `root_node` with the `context`.
+    pub fn new(
+        root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+        context: Arc<CtxT>,
+    ) -> Self {
+        Self {
+            root_node,
+            context,
+            batch: BatchConfig::default(),
+        }
+    }
+
+    /// Sets the [`BatchConfig`] to handle batched requests with.
+    #[must_use]
+    pub fn batch_config(mut self, config: BatchConfig) -> Self {
+        self.batch = config;
+        self
+    }
+}
+
+impl<QueryT, MutationT, SubscriptionT, CtxT, S> Clone
+    for GraphQLService<QueryT, MutationT, SubscriptionT, CtxT, S>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    S: ScalarValue,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root_node: Arc::clone(&self.root_node),
+            context: Arc::clone(&self.context),
+            batch: self.batch,
+        }
+    }
+}
+
+impl<QueryT, MutationT, SubscriptionT, CtxT, S> fmt::Debug
+    for GraphQLService<QueryT, MutationT, SubscriptionT, CtxT, S>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    S: ScalarValue,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GraphQLService")
+            .field("batch", &self.batch)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B, QueryT, MutationT, SubscriptionT, CtxT, S> Service<Request<B>>
+    for GraphQLService<QueryT, MutationT, SubscriptionT, CtxT, S>
+where
+    B: Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn StdError + Send + Sync>>,
+    QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    QueryT::TypeInfo: Send + Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    MutationT::TypeInfo: Send + Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    SubscriptionT::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    type Response = Response<String>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let this = self.clone();
+        Box::pin(async move { Ok(this.serve(req).await) })
+    }
+}
+
+impl<QueryT, MutationT, SubscriptionT, CtxT, S>
+    GraphQLService<QueryT, MutationT, SubscriptionT, CtxT, S>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    QueryT::TypeInfo: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    SubscriptionT::TypeInfo: Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync,
+{
+    async fn serve<B>(self, req: Request<B>) -> Response<String>
+    where
+        B: Body,
+        B::Error: Into<Box<dyn StdError + Send + Sync>>,
+    {
+        let (parts, body) = req.into_parts();
+        let correlation = crate::correlation(&parts);
+        let context = parts
+            .extensions
+            .get::<Arc<CtxT>>()
+            .cloned()
+            .unwrap_or(self.context);
+
+        // Only a POST request carries a body to parse.
+        let body = if parts.method == Method::POST {
+            match read_body(body).await {
+                Ok(body) => body,
+                Err(e) => {
+                    let e: Box<dyn StdError + Send + Sync> = e.into();
+                    return response(
+                        StatusCode::BAD_REQUEST,
+                        "text/plain; charset=utf-8",
+                        format!("cannot read body: {e}"),
+                        correlation.request_id(),
+                    );
+                }
+            }
+        } else {
+            vec![]
+        };
+        let parsed = crate::parse_request::<S>(&parts, &body)
+            .and_then(|gql_req| self.batch.check(&gql_req).map(|()| gql_req));
+        let gql_req = match parsed {
+            Ok(gql_req) => gql_req,
+            Err(e) => {
+                return response(
+                    StatusCode::from_u16(e.status_code()).unwrap_or(StatusCode::BAD_REQUEST),
+                    "text/plain; charset=utf-8",
+                    e.to_string(),
+                    correlation.request_id(),
+                )
+            }
+        };
+
+        // The future of a request is dropped once the client is gone (or a
+        // middleware times it out), so its operations are cancelled.
+        let cancellation = CancellationToken::new().cancel_on_drop();
+        let res = self
+            .batch
+            .execute(
+                &gql_req,
+                &*self.root_node,
+                &*context,
+                &correlation,
+                cancellation.token(),
+            )
+            .await;
+        cancellation.disarm();
+
+        response(
+            StatusCode::from_u16(crate::response_status(&res)).unwrap_or(StatusCode::BAD_REQUEST),
+            JSON_CONTENT_TYPE,
+            serde_json::to_string(&res).unwrap(),
+            correlation.request_id(),
+        )
+    }
+}
+
+/// Reads the whole provided [`Body`].
+async fn read_body<B: Body>(body: B) -> Result<Vec<u8>, B::Error> {
+    let mut body = Box::pin(body);
+    let mut bytes = vec![];
+    while let Some(chunk) = body.data().await {
+        let mut chunk = chunk?;
+        while chunk.has_remaining() {
+            let part = chunk.chunk();
+            let len = part.len();
+            bytes.extend_from_slice(part);
+            chunk.advance(len);
+        }
+    }
+    Ok(bytes)
+}
+
+fn response(
+    status: StatusCode,
+    content_type: &'static str,
+    body: String,
+    request_id: Option<&str>,
+) -> Response<String> {
+    let mut resp = Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, content_type);
+    if let Some(id) = request_id {
+        resp = resp.header(REQUEST_ID_HEADER, id);
+    }
+    resp.body(body)
+        .unwrap_or_else(|e| panic!("cannot build `Response`: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use http::{header, Request, StatusCode};
+    use juniper::{
+        tests::fixtures::starwars::schema::{Database, Query},
+        DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode,
+    };
+    use tower::ServiceExt as _;
+
+    use crate::BatchConfig;
+
+    use super::GraphQLService;
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>>;
+
+    type Service = GraphQLService<
+        Query,
+        EmptyMutation<Database>,
+        EmptySubscription<Database>,
+        Database,
+        DefaultScalarValue,
+    >;
+
+    fn service() -> Service {
+        GraphQLService::new(
+            Arc::new(Schema::new(
+                Query,
+                EmptyMutation::new(),
+                EmptySubscription::new(),
+            )),
+            Arc::new(Database::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn serves_post_request() {
+        let req = Request::post("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header("x-request-id", "abc")
+            .body(r#"{"query": "{ hero { name } }"}"#.to_owned())
+            .unwrap();
+
+        let resp = service().oneshot(req).await.unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(resp.headers()["x-request-id"], "abc");
+        assert_eq!(resp.body(), r#"{"data":{"hero":{"name":"R2-D2"}}}"#);
+    }
+
+    #[tokio::test]
+    async fn serves_get_request() {
+        let req = Request::get("/?query=%7B%20hero%20%7B%20id%20%7D%20%7D")
+            .body(String::new())
+            .unwrap();
+
+        let resp = service().oneshot(req).await.unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.body(), r#"{"data":{"hero":{"id":"2001"}}}"#);
+    }
+
+    #[tokio::test]
+    async fn renders_request_errors() {
+        let req = Request::put("/").body(String::new()).unwrap();
+        let resp = service().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        let req = Request::post("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(r#"[{"query": "{ hero { id } }"}, {"query": "{ hero { id } }"}]"#.to_owned())
+            .unwrap();
+        let resp = service()
+            .batch_config(BatchConfig::new().max_size(1))
+            .oneshot(req)
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
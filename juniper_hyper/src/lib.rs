@@ -1,17 +1,25 @@
 #![doc = include_str!("../README.md")]
 
-use std::{error::Error, fmt, string::FromUtf8Error, sync::Arc};
+use std::{error::Error, fmt, sync::Arc};
 
 use hyper::{
+    body::HttpBody as _,
     header::{self, HeaderValue},
     Body, Method, Request, Response, StatusCode,
 };
 use juniper::{
-    http::{GraphQLBatchRequest, GraphQLRequest as JuniperGraphQLRequest, GraphQLRequest},
-    GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync, InputValue, RootNode, ScalarValue,
+    executor::{CancellationToken, Correlation, REQUEST_ID_HEADER, TRACEPARENT_HEADER},
+    http::{
+        etag,
+        multipart::{self, MultipartError, MultipartParser, Uploads},
+        problem::{ProblemJson, RequestErrorKind, PROBLEM_JSON_CONTENT_TYPE},
+        GraphQLBatchRequest,
+    },
+    GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync, RootNode, ScalarValue,
 };
-use serde_json::error::Error as SerdeError;
-use url::form_urlencoded;
+use juniper_http::RequestError;
+
+pub use juniper_http::BatchConfig;
 
 pub async fn graphql_sync<CtxT, QueryT, MutationT, SubscriptionT, S>(
     root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
@@ -28,8 +36,37 @@ where
     CtxT: Sync,
     S: ScalarValue + Send + Sync,
 {
-    match parse_req(req).await {
-        Ok(req) => execute_request_sync(root_node, context, req).await,
+    let validator = cache_validator(&req);
+    let correlation = correlation(&req);
+    match parse_req(req, None).await {
+        Ok(req) => execute_request_sync(root_node, context, req, validator, correlation).await,
+        Err(resp) => resp,
+    }
+}
+
+/// Same as [`graphql_sync()`], but renders request-level errors (like an
+/// unsupported content type or a malformed body) as `application/problem+json`
+/// according to the provided [`ProblemJson`] options.
+pub async fn graphql_sync_with_problem_json<CtxT, QueryT, MutationT, SubscriptionT, S>(
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: Arc<CtxT>,
+    req: Request<Body>,
+    problem: &ProblemJson,
+) -> Response<Body>
+where
+    QueryT: GraphQLType<S, Context = CtxT>,
+    QueryT::TypeInfo: Sync,
+    MutationT: GraphQLType<S, Context = CtxT>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = CtxT>,
+    SubscriptionT::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let validator = cache_validator(&req);
+    let correlation = correlation(&req);
+    match parse_req(req, Some(problem)).await {
+        Ok(req) => execute_request_sync(root_node, context, req, validator, correlation).await,
         Err(resp) => resp,
     }
 }
@@ -49,73 +86,199 @@ where
     CtxT: Sync,
     S: ScalarValue + Send + Sync,
 {
-    match parse_req(req).await {
-        Ok(req) => execute_request(root_node, context, req).await,
+    let validator = cache_validator(&req);
+    let correlation = correlation(&req);
+    match parse_req(req, None).await {
+        Ok(req) => {
+            execute_request(
+                root_node,
+                context,
+                req,
+                validator,
+                correlation,
+                &BatchConfig::default(),
+            )
+            .await
+        }
         Err(resp) => resp,
     }
 }
 
-async fn parse_req<S: ScalarValue>(
+/// Same as [`graphql()`], but renders request-level errors (like an unsupported
+/// content type or a malformed body) as `application/problem+json` according to
+/// the provided [`ProblemJson`] options.
+pub async fn graphql_with_problem_json<CtxT, QueryT, MutationT, SubscriptionT, S>(
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: Arc<CtxT>,
     req: Request<Body>,
-) -> Result<GraphQLBatchRequest<S>, Response<Body>> {
-    match *req.method() {
-        Method::GET => parse_get_req(req),
-        Method::POST => {
-            let content_type = req
-                .headers()
-                .get(header::CONTENT_TYPE)
-                .map(HeaderValue::to_str);
-            match content_type {
-                Some(Ok("application/json")) => parse_post_json_req(req.into_body()).await,
-                Some(Ok("application/graphql")) => parse_post_graphql_req(req.into_body()).await,
-                _ => return Err(new_response(StatusCode::BAD_REQUEST)),
-            }
+    problem: &ProblemJson,
+) -> Response<Body>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    QueryT::TypeInfo: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    SubscriptionT::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let validator = cache_validator(&req);
+    let correlation = correlation(&req);
+    match parse_req(req, Some(problem)).await {
+        Ok(req) => {
+            execute_request(
+                root_node,
+                context,
+                req,
+                validator,
+                correlation,
+                &BatchConfig::default(),
+            )
+            .await
         }
-        _ => return Err(new_response(StatusCode::METHOD_NOT_ALLOWED)),
+        Err(resp) => resp,
     }
-    .map_err(render_error)
 }
 
-fn parse_get_req<S: ScalarValue>(
+/// Same as [`graphql()`], but handles batched requests according to the
+/// provided [`BatchConfig`].
+///
+/// The response of a batched request is a JSON array of the operations
+/// responses, in the order of the operations in the request.
+pub async fn graphql_batched<CtxT, QueryT, MutationT, SubscriptionT, S>(
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: Arc<CtxT>,
     req: Request<Body>,
-) -> Result<GraphQLBatchRequest<S>, GraphQLRequestError> {
-    req.uri()
-        .query()
-        .map(|q| gql_request_from_get(q).map(GraphQLBatchRequest::Single))
-        .unwrap_or_else(|| {
-            Err(GraphQLRequestError::Invalid(
-                "'query' parameter is missing".into(),
-            ))
-        })
+    config: &BatchConfig,
+) -> Response<Body>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    QueryT::TypeInfo: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    SubscriptionT::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let validator = cache_validator(&req);
+    let correlation = correlation(&req);
+    match parse_req(req, None).await {
+        Ok(req) => match config.check(&req) {
+            Ok(()) => {
+                execute_request(root_node, context, req, validator, correlation, config).await
+            }
+            Err(e) => render_error(e.into()),
+        },
+        Err(resp) => resp,
+    }
 }
 
-async fn parse_post_json_req<S: ScalarValue>(
-    body: Body,
-) -> Result<GraphQLBatchRequest<S>, GraphQLRequestError> {
-    let chunk = hyper::body::to_bytes(body)
-        .await
-        .map_err(GraphQLRequestError::BodyHyper)?;
-
-    let input = String::from_utf8(chunk.iter().cloned().collect())
-        .map_err(GraphQLRequestError::BodyUtf8)?;
-
-    serde_json::from_str::<GraphQLBatchRequest<S>>(&input)
-        .map_err(GraphQLRequestError::BodyJSONError)
+/// Same as [`graphql()`], but additionally accepts `multipart/form-data`
+/// requests uploading files, as specified by the
+/// [GraphQL multipart request specification][0].
+///
+/// The uploaded files are written to disk while the body is received, and the
+/// context is created from their [`Uploads`] (which are empty for requests of
+/// other content types), so resolvers are able to read them.
+///
+/// [0]: https://github.com/jaydenseric/graphql-multipart-request-spec
+pub async fn graphql_with_uploads<CtxT, QueryT, MutationT, SubscriptionT, S>(
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: impl FnOnce(Uploads) -> CtxT,
+    req: Request<Body>,
+    parser: &MultipartParser,
+) -> Response<Body>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    QueryT::TypeInfo: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    SubscriptionT::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let validator = cache_validator(&req);
+    let correlation = correlation(&req);
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .filter(|ct| multipart::is_multipart(ct))
+        .map(str::to_owned);
+    let parsed = match (req.method(), content_type) {
+        (&Method::POST, Some(content_type)) => {
+            parse_multipart_req(req.into_body(), &content_type, parser)
+                .await
+                .map_err(render_error)
+        }
+        _ => parse_req(req, None).await.map(|req| (req, Uploads::new())),
+    };
+    match parsed {
+        Ok((req, uploads)) => {
+            let context = Arc::new(context(uploads));
+            execute_request(
+                root_node,
+                context,
+                req,
+                validator,
+                correlation,
+                &BatchConfig::default(),
+            )
+            .await
+        }
+        Err(resp) => resp,
+    }
 }
 
-async fn parse_post_graphql_req<S: ScalarValue>(
-    body: Body,
-) -> Result<GraphQLBatchRequest<S>, GraphQLRequestError> {
-    let chunk = hyper::body::to_bytes(body)
-        .await
-        .map_err(GraphQLRequestError::BodyHyper)?;
-
-    let query = String::from_utf8(chunk.iter().cloned().collect())
-        .map_err(GraphQLRequestError::BodyUtf8)?;
+async fn parse_req<S: ScalarValue>(
+    req: Request<Body>,
+    problem: Option<&ProblemJson>,
+) -> Result<GraphQLBatchRequest<S>, Response<Body>> {
+    let problem = problem.filter(|p| {
+        p.is_accepted(
+            req.headers()
+                .get(header::ACCEPT)
+                .and_then(|v| v.to_str().ok()),
+        )
+    });
+    let (parts, body) = req.into_parts();
+    // Only a POST request carries a body to parse.
+    let body = if parts.method == Method::POST {
+        hyper::body::to_bytes(body)
+            .await
+            .map_err(|e| match problem {
+                Some(p) => {
+                    new_problem_response(p, RequestErrorKind::MalformedRequest, e.to_string())
+                }
+                None => render_error(GraphQLRequestError::BodyHyper(e)),
+            })?
+    } else {
+        Default::default()
+    };
+    juniper_http::parse_request(&parts, &body).map_err(|e| match problem {
+        Some(p) => new_problem_response(p, e.kind(), e.to_string()),
+        None => render_error(e.into()),
+    })
+}
 
-    Ok(GraphQLBatchRequest::Single(GraphQLRequest::new(
-        query, None, None,
-    )))
+async fn parse_multipart_req<S: ScalarValue>(
+    mut body: Body,
+    content_type: &str,
+    parser: &MultipartParser,
+) -> Result<(GraphQLBatchRequest<S>, Uploads), GraphQLRequestError> {
+    let mut multipart = parser
+        .begin(content_type)
+        .map_err(GraphQLRequestError::Multipart)?;
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(GraphQLRequestError::BodyHyper)?;
+        multipart
+            .feed(&chunk)
+            .map_err(GraphQLRequestError::Multipart)?;
+    }
+    multipart.finish().map_err(GraphQLRequestError::Multipart)
 }
 
 pub async fn graphiql(
@@ -124,7 +287,7 @@ pub async fn graphiql(
 ) -> Response<Body> {
     let mut resp = new_html_response(StatusCode::OK);
     // XXX: is the call to graphiql_source blocking?
-    *resp.body_mut() = Body::from(juniper::http::graphiql::graphiql_source(
+    *resp.body_mut() = Body::from(juniper_http::graphiql_source(
         graphql_endpoint,
         subscriptions_endpoint,
     ));
@@ -136,7 +299,7 @@ pub async fn playground(
     subscriptions_endpoint: Option<&str>,
 ) -> Response<Body> {
     let mut resp = new_html_response(StatusCode::OK);
-    *resp.body_mut() = Body::from(juniper::http::playground::playground_source(
+    *resp.body_mut() = Body::from(juniper_http::playground_source(
         graphql_endpoint,
         subscriptions_endpoint,
     ));
@@ -144,6 +307,15 @@ pub async fn playground(
 }
 
 fn render_error(err: GraphQLRequestError) -> Response<Body> {
+    match err {
+        GraphQLRequestError::Request(RequestError::MethodNotAllowed) => {
+            return new_response(StatusCode::METHOD_NOT_ALLOWED)
+        }
+        GraphQLRequestError::Request(RequestError::UnsupportedContentType) => {
+            return new_response(StatusCode::BAD_REQUEST)
+        }
+        _ => {}
+    }
     let message = err.to_string();
     let mut resp = new_response(StatusCode::BAD_REQUEST);
     *resp.body_mut() = Body::from(message);
@@ -154,6 +326,8 @@ async fn execute_request_sync<CtxT, QueryT, MutationT, SubscriptionT, S>(
     root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
     context: Arc<CtxT>,
     request: GraphQLBatchRequest<S>,
+    validator: Option<CacheValidator>,
+    correlation: Correlation,
 ) -> Response<Body>
 where
     QueryT: GraphQLType<S, Context = CtxT>,
@@ -165,26 +339,20 @@ where
     CtxT: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let res = request.execute_sync(&*root_node, &context);
-    let body = Body::from(serde_json::to_string_pretty(&res).unwrap());
-    let code = if res.is_ok() {
-        StatusCode::OK
-    } else {
-        StatusCode::BAD_REQUEST
-    };
-    let mut resp = new_response(code);
-    resp.headers_mut().insert(
-        header::CONTENT_TYPE,
-        HeaderValue::from_static("application/json"),
-    );
-    *resp.body_mut() = body;
-    resp
+    let res = request.execute_sync_correlated(&*root_node, &context, &correlation);
+    let body = serde_json::to_string_pretty(&res).unwrap();
+    let code = StatusCode::from_u16(juniper_http::response_status(&res))
+        .unwrap_or(StatusCode::BAD_REQUEST);
+    with_request_id(new_json_response(code, body, validator), &correlation)
 }
 
 async fn execute_request<CtxT, QueryT, MutationT, SubscriptionT, S>(
     root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
     context: Arc<CtxT>,
     request: GraphQLBatchRequest<S>,
+    validator: Option<CacheValidator>,
+    correlation: Correlation,
+    batch: &BatchConfig,
 ) -> Response<Body>
 where
     QueryT: GraphQLTypeAsync<S, Context = CtxT>,
@@ -196,68 +364,94 @@ where
     CtxT: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let res = request.execute(&*root_node, &context).await;
-    let body = Body::from(serde_json::to_string_pretty(&res).unwrap());
-    let code = if res.is_ok() {
-        StatusCode::OK
-    } else {
-        StatusCode::BAD_REQUEST
-    };
-    let mut resp = new_response(code);
-    resp.headers_mut().insert(
-        header::CONTENT_TYPE,
-        HeaderValue::from_static("application/json"),
-    );
-    *resp.body_mut() = body;
-    resp
+    // Hyper drops the future of a request, once its connection is closed.
+    let cancellation = CancellationToken::new().cancel_on_drop();
+    let res = batch
+        .execute(
+            &request,
+            &*root_node,
+            &context,
+            &correlation,
+            cancellation.token(),
+        )
+        .await;
+    cancellation.disarm();
+    let body = serde_json::to_string_pretty(&res).unwrap();
+    let code = StatusCode::from_u16(juniper_http::response_status(&res))
+        .unwrap_or(StatusCode::BAD_REQUEST);
+    with_request_id(new_json_response(code, body, validator), &correlation)
 }
 
-fn gql_request_from_get<S>(input: &str) -> Result<JuniperGraphQLRequest<S>, GraphQLRequestError>
-where
-    S: ScalarValue,
-{
-    let mut query = None;
-    let operation_name = None;
-    let mut variables = None;
-    for (key, value) in form_urlencoded::parse(input.as_bytes()).into_owned() {
-        match key.as_ref() {
-            "query" => {
-                if query.is_some() {
-                    return Err(invalid_err("query"));
-                }
-                query = Some(value)
-            }
-            "operationName" => {
-                if operation_name.is_some() {
-                    return Err(invalid_err("operationName"));
-                }
-            }
-            "variables" => {
-                if variables.is_some() {
-                    return Err(invalid_err("variables"));
-                }
-                match serde_json::from_str::<InputValue<S>>(&value)
-                    .map_err(GraphQLRequestError::Variables)
-                {
-                    Ok(parsed_variables) => variables = Some(parsed_variables),
-                    Err(e) => return Err(e),
-                }
-            }
-            _ => continue,
-        }
-    }
-    match query {
-        Some(query) => Ok(JuniperGraphQLRequest::new(query, operation_name, variables)),
-        None => Err(GraphQLRequestError::Invalid(
-            "'query' parameter is missing".into(),
-        )),
+/// `If-None-Match` header of a GET request, validating whether its cached
+/// response is still fresh.
+struct CacheValidator {
+    if_none_match: Option<String>,
+}
+
+/// Returns the [`CacheValidator`] of the provided `req`, if its response is
+/// cacheable (it's a GET request).
+fn cache_validator(req: &Request<Body>) -> Option<CacheValidator> {
+    (req.method() == Method::GET).then(|| CacheValidator {
+        if_none_match: req
+            .headers()
+            .get(header::IF_NONE_MATCH)
+            .and_then(|v| v.to_str().ok())
+            .map(Into::into),
+    })
+}
+
+/// Extracts the [`Correlation`] of the provided `req` from its `traceparent`
+/// and `x-request-id` headers.
+fn correlation(req: &Request<Body>) -> Correlation {
+    let header = |name| req.headers().get(name).and_then(|v| v.to_str().ok());
+    Correlation::from_headers(header(TRACEPARENT_HEADER), header(REQUEST_ID_HEADER))
+}
+
+/// Echoes the request ID of the provided `correlation` in the `x-request-id`
+/// header of the provided `resp`, so clients are able to report it.
+fn with_request_id(mut resp: Response<Body>, correlation: &Correlation) -> Response<Body> {
+    if let Some(id) = correlation
+        .request_id()
+        .and_then(|id| HeaderValue::from_str(id).ok())
+    {
+        resp.headers_mut().insert(REQUEST_ID_HEADER, id);
     }
+    resp
 }
 
-fn invalid_err(parameter_name: &str) -> GraphQLRequestError {
-    GraphQLRequestError::Invalid(format!(
-        "`{parameter_name}` parameter is specified multiple times",
-    ))
+/// Creates a new JSON response with the provided `code` and `body`.
+///
+/// Successful responses to GET requests are given an `ETag`, and become
+/// `304 Not Modified` ones if the provided `validator` matches it.
+fn new_json_response(
+    code: StatusCode,
+    body: String,
+    validator: Option<CacheValidator>,
+) -> Response<Body> {
+    let validator = validator.filter(|_| code == StatusCode::OK);
+    let etag = validator.as_ref().map(|_| etag::etag(body.as_bytes()));
+    let not_modified = validator
+        .and_then(|v| v.if_none_match)
+        .zip(etag.as_deref())
+        .map_or(false, |(header, etag)| etag::if_none_match(&header, etag));
+
+    let mut resp = new_response(if not_modified {
+        StatusCode::NOT_MODIFIED
+    } else {
+        code
+    });
+    if let Some(etag) = etag {
+        resp.headers_mut()
+            .insert(header::ETAG, HeaderValue::from_str(&etag).unwrap());
+    }
+    if !not_modified {
+        resp.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        *resp.body_mut() = Body::from(body);
+    }
+    resp
 }
 
 fn new_response(code: StatusCode) -> Response<Body> {
@@ -266,6 +460,22 @@ fn new_response(code: StatusCode) -> Response<Body> {
     r
 }
 
+fn new_problem_response(
+    problem: &ProblemJson,
+    kind: RequestErrorKind,
+    detail: impl Into<String>,
+) -> Response<Body> {
+    let details = problem.problem(kind, detail);
+    let mut resp =
+        new_response(StatusCode::from_u16(details.status).unwrap_or(StatusCode::BAD_REQUEST));
+    resp.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+    );
+    *resp.body_mut() = Body::from(serde_json::to_string_pretty(&details).unwrap());
+    resp
+}
+
 fn new_html_response(code: StatusCode) -> Response<Body> {
     let mut resp = new_response(code);
     resp.headers_mut().insert(
@@ -278,20 +488,22 @@ fn new_html_response(code: StatusCode) -> Response<Body> {
 #[derive(Debug)]
 enum GraphQLRequestError {
     BodyHyper(hyper::Error),
-    BodyUtf8(FromUtf8Error),
-    BodyJSONError(SerdeError),
-    Variables(SerdeError),
-    Invalid(String),
+    Request(RequestError),
+    Multipart(MultipartError),
+}
+
+impl From<RequestError> for GraphQLRequestError {
+    fn from(e: RequestError) -> Self {
+        Self::Request(e)
+    }
 }
 
 impl fmt::Display for GraphQLRequestError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             GraphQLRequestError::BodyHyper(err) => fmt::Display::fmt(err, f),
-            GraphQLRequestError::BodyUtf8(err) => fmt::Display::fmt(err, f),
-            GraphQLRequestError::BodyJSONError(err) => fmt::Display::fmt(err, f),
-            GraphQLRequestError::Variables(err) => fmt::Display::fmt(err, f),
-            GraphQLRequestError::Invalid(err) => fmt::Display::fmt(err, f),
+            GraphQLRequestError::Request(err) => fmt::Display::fmt(err, f),
+            GraphQLRequestError::Multipart(err) => fmt::Display::fmt(err, f),
         }
     }
 }
@@ -300,10 +512,8 @@ impl Error for GraphQLRequestError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             GraphQLRequestError::BodyHyper(err) => Some(err),
-            GraphQLRequestError::BodyUtf8(err) => Some(err),
-            GraphQLRequestError::BodyJSONError(err) => Some(err),
-            GraphQLRequestError::Variables(err) => Some(err),
-            GraphQLRequestError::Invalid(_) => None,
+            GraphQLRequestError::Request(err) => Some(err),
+            GraphQLRequestError::Multipart(err) => Some(err),
         }
     }
 }
@@ -311,18 +521,26 @@ impl Error for GraphQLRequestError {
 #[cfg(test)]
 mod tests {
     use hyper::{
+        header,
         server::Server,
         service::{make_service_fn, service_fn},
-        Body, Method, Response, StatusCode,
+        Body, Method, Request, Response, StatusCode,
     };
     use juniper::{
-        http::tests as http_tests,
+        http::{
+            multipart::{MultipartParser, Upload},
+            problem::ProblemJson,
+            tests as http_tests, GraphQLBatchRequest,
+        },
         tests::fixtures::starwars::schema::{Database, Query},
-        EmptyMutation, EmptySubscription, RootNode,
+        Correlation, DefaultScalarValue, EmptyMutation, EmptySubscription, FromInputValue as _,
+        RootNode,
     };
     use reqwest::{self, blocking::Response as ReqwestResponse};
     use std::{convert::Infallible, net::SocketAddr, sync::Arc, thread, time::Duration};
 
+    use super::BatchConfig;
+
     struct TestHyperIntegration {
         port: u16,
     }
@@ -449,4 +667,174 @@ mod tests {
     async fn test_sync_hyper_integration() {
         run_hyper_integration(true).await
     }
+
+    #[tokio::test]
+    async fn test_problem_json_for_unsupported_content_type() {
+        let req = Request::post("/graphql")
+            .header(header::CONTENT_TYPE, "text/plain")
+            .header(header::ACCEPT, "application/problem+json")
+            .body(Body::from("{}"))
+            .unwrap();
+
+        let resp = super::parse_req::<DefaultScalarValue>(req, Some(&ProblemJson::new()))
+            .await
+            .unwrap_err();
+
+        assert_eq!(resp.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(
+            resp.headers()[header::CONTENT_TYPE],
+            "application/problem+json",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_problem_json_if_not_accepted() {
+        let req = Request::post("/graphql")
+            .header(header::CONTENT_TYPE, "text/plain")
+            .body(Body::from("{}"))
+            .unwrap();
+
+        let resp = super::parse_req::<DefaultScalarValue>(req, Some(&ProblemJson::new()))
+            .await
+            .unwrap_err();
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(resp.headers().get(header::CONTENT_TYPE).is_none());
+    }
+
+    #[test]
+    fn test_etag_not_modified() {
+        let body = r#"{"data":{"hero":null}}"#.to_owned();
+        let validator = || {
+            Some(super::CacheValidator {
+                if_none_match: None,
+            })
+        };
+
+        let resp = super::new_json_response(StatusCode::OK, body.clone(), validator());
+        assert_eq!(resp.status(), StatusCode::OK);
+        let etag = resp.headers()[header::ETAG].to_str().unwrap().to_owned();
+
+        let resp = super::new_json_response(
+            StatusCode::OK,
+            body.clone(),
+            Some(super::CacheValidator {
+                if_none_match: Some(etag.clone()),
+            }),
+        );
+        assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(resp.headers()[header::ETAG], etag.as_str());
+
+        let resp = super::new_json_response(StatusCode::BAD_REQUEST, body, validator());
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(resp.headers().get(header::ETAG).is_none());
+    }
+
+    #[test]
+    fn test_request_id_echoed() {
+        let req = Request::builder()
+            .header(
+                "traceparent",
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            )
+            .body(Body::empty())
+            .unwrap();
+        let correlation = super::correlation(&req);
+        assert_eq!(
+            correlation.request_id(),
+            Some("4bf92f3577b34da6a3ce929d0e0e4736"),
+        );
+
+        let resp = super::with_request_id(super::new_response(StatusCode::OK), &correlation);
+        assert_eq!(
+            resp.headers()["x-request-id"],
+            "4bf92f3577b34da6a3ce929d0e0e4736",
+        );
+
+        let resp =
+            super::with_request_id(super::new_response(StatusCode::OK), &Correlation::default());
+        assert!(resp.headers().get("x-request-id").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_multipart_req_chunked() {
+        let body = "--xyz\r\n\
+                    Content-Disposition: form-data; name=\"operations\"\r\n\r\n\
+                    {\"query\": \"{ hero { name } }\", \"variables\": {\"file\": null}}\r\n\
+                    --xyz\r\n\
+                    Content-Disposition: form-data; name=\"map\"\r\n\r\n\
+                    {\"0\": [\"variables.file\"]}\r\n\
+                    --xyz\r\n\
+                    Content-Disposition: form-data; name=\"0\"; filename=\"a.txt\"\r\n\r\n\
+                    content\r\n\
+                    --xyz--\r\n";
+        let (mut tx, req_body) = Body::channel();
+        tokio::spawn(async move {
+            for chunk in body.as_bytes().chunks(5) {
+                tx.send_data(chunk.to_vec().into()).await.unwrap();
+            }
+        });
+
+        let (req, uploads) = super::parse_multipart_req::<DefaultScalarValue>(
+            req_body,
+            "multipart/form-data; boundary=xyz",
+            &MultipartParser::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(uploads.len(), 1);
+        match req {
+            GraphQLBatchRequest::Single(req) => {
+                let upload = Upload::from_input_value(&req.variables()["file"]).unwrap();
+                let file = uploads.open(&upload).unwrap().unwrap();
+                assert_eq!(file.size(), 7);
+            }
+            GraphQLBatchRequest::Batch(_) => panic!("expected single request"),
+        }
+    }
+
+    async fn batched_request(body: &'static str, config: &BatchConfig) -> Response<Body> {
+        let schema = Arc::new(RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        ));
+        let req = Request::post("/graphql")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap();
+
+        super::graphql_batched(schema, Arc::new(Database::new()), req, config).await
+    }
+
+    #[tokio::test]
+    async fn test_batched_request_limited_concurrency() {
+        let resp = batched_request(
+            r#"[{"query": "{ hero { name } }"}, {"query": "{ human(id: \"1000\") { id } }"}]"#,
+            &BatchConfig::new().max_concurrency(1),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            serde_json::json!([
+                {"data": {"hero": {"name": "R2-D2"}}},
+                {"data": {"human": {"id": "1000"}}},
+            ]),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batched_request_exceeding_max_size() {
+        let resp = batched_request(
+            r#"[{"query": "{ hero { name } }"}, {"query": "{ hero { id } }"}]"#,
+            &BatchConfig::new().max_size(1),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
 }
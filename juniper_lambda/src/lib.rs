@@ -0,0 +1,225 @@
+#![doc = include_str!("../README.md")]
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+use juniper::{
+    executor::REQUEST_ID_HEADER, CancellationToken, GraphQLSubscriptionType, GraphQLTypeAsync,
+    RootNode, ScalarValue,
+};
+use juniper_http::JSON_CONTENT_TYPE;
+use lambda_http::{http::header, Body, Request, Response};
+
+pub use juniper_http::BatchConfig;
+
+/// Handles an AWS Lambda HTTP [`Request`] (coming from API Gateway, an
+/// Application Load Balancer or a function URL), executing the [GraphQL]
+/// request it contains against the `root_node` with the `context`.
+///
+/// Accepts both `GET` and `POST` requests, with either a single or a batched
+/// [GraphQL] request, as described in [`juniper_http::parse_request()`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+///
+/// use juniper::{
+///     tests::fixtures::starwars::schema::{Database, Query},
+///     EmptyMutation, EmptySubscription, RootNode,
+/// };
+/// use lambda_http::{run, service_fn, Error, Request};
+///
+/// type Schema = RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>>;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Error> {
+///     let schema = Arc::new(Schema::new(
+///         Query,
+///         EmptyMutation::new(),
+///         EmptySubscription::new(),
+///     ));
+///     let database = Arc::new(Database::new());
+///
+///     run(service_fn(move |req: Request| {
+///         let (schema, database) = (schema.clone(), database.clone());
+///         async move { Ok::<_, Error>(juniper_lambda::graphql(&*schema, &*database, req).await) }
+///     }))
+///     .await
+/// }
+/// ```
+///
+/// [GraphQL]: https://graphql.org
+pub async fn graphql<QueryT, MutationT, SubscriptionT, CtxT, S>(
+    root_node: &RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    context: &CtxT,
+    req: Request,
+) -> Response<Body>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    QueryT::TypeInfo: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    SubscriptionT::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    graphql_batched(root_node, context, req, &BatchConfig::default()).await
+}
+
+/// Same as [`graphql()`], but handles batched requests according to the
+/// provided [`BatchConfig`].
+pub async fn graphql_batched<QueryT, MutationT, SubscriptionT, CtxT, S>(
+    root_node: &RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+    context: &CtxT,
+    req: Request,
+    config: &BatchConfig,
+) -> Response<Body>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+    QueryT::TypeInfo: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+    SubscriptionT::TypeInfo: Sync,
+    CtxT: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let correlation = juniper_http::correlation(&req);
+    let parsed = juniper_http::parse_request::<S>(&req, req.body().as_ref())
+        .and_then(|gql_req| config.check(&gql_req).map(|()| gql_req));
+
+    // The Lambda runtime has no way to signal a client disconnecting, so
+    // operations are never cancelled.
+    let (status, content_type, body) = match parsed {
+        Ok(gql_req) => {
+            let resp = config
+                .execute(
+                    &gql_req,
+                    root_node,
+                    context,
+                    &correlation,
+                    &CancellationToken::new(),
+                )
+                .await;
+            (
+                juniper_http::response_status(&resp),
+                JSON_CONTENT_TYPE,
+                serde_json::to_string(&resp).unwrap(),
+            )
+        }
+        Err(e) => (e.status_code(), "text/plain; charset=utf-8", e.to_string()),
+    };
+
+    let mut resp = Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, content_type);
+    if let Some(id) = correlation.request_id() {
+        resp = resp.header(REQUEST_ID_HEADER, id);
+    }
+    resp.body(Body::Text(body))
+        .unwrap_or_else(|e| panic!("cannot build `Response`: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use juniper::{
+        tests::fixtures::starwars::schema::{Database, Query},
+        EmptyMutation, EmptySubscription, RootNode,
+    };
+    use lambda_http::{
+        http::{header, Request},
+        Body, Response,
+    };
+
+    use super::{graphql, graphql_batched, BatchConfig};
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>>;
+
+    fn schema() -> Schema {
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    fn body(resp: &Response<Body>) -> serde_json::Value {
+        match resp.body() {
+            Body::Text(text) => serde_json::from_str(text).unwrap(),
+            body => panic!("unexpected body: {body:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn handles_get_request() {
+        let req = Request::get("/graphql?query=%7B%20hero%20%7B%20name%20%7D%20%7D")
+            .header("x-request-id", "abc")
+            .body(Body::Empty)
+            .unwrap();
+
+        let resp = graphql(&schema(), &Database::new(), req).await;
+
+        assert_eq!(resp.status(), 200);
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(resp.headers()["x-request-id"], "abc");
+        assert_eq!(
+            body(&resp),
+            serde_json::json!({"data": {"hero": {"name": "R2-D2"}}}),
+        );
+    }
+
+    #[tokio::test]
+    async fn handles_batched_post_request() {
+        let req = Request::post("/graphql")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                r#"[{"query": "{ hero { name } }"}, {"query": "{ human(id: \"1000\") { id } }"}]"#,
+            ))
+            .unwrap();
+
+        let resp = graphql(&schema(), &Database::new(), req).await;
+
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            body(&resp),
+            serde_json::json!([
+                {"data": {"hero": {"name": "R2-D2"}}},
+                {"data": {"human": {"id": "1000"}}},
+            ]),
+        );
+    }
+
+    #[tokio::test]
+    async fn handles_binary_body() {
+        let req = Request::post("/graphql")
+            .header(header::CONTENT_TYPE, "application/graphql")
+            .body(Body::Binary(b"{ hero { name } }".to_vec()))
+            .unwrap();
+
+        let resp = graphql(&schema(), &Database::new(), req).await;
+
+        assert_eq!(resp.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() {
+        let req = Request::post("/graphql")
+            .header(header::CONTENT_TYPE, "text/plain")
+            .body(Body::from("{ hero { name } }"))
+            .unwrap();
+        let resp = graphql(&schema(), &Database::new(), req).await;
+        assert_eq!(resp.status(), 415);
+
+        let req = Request::post("/graphql")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                r#"[{"query": "{ hero { name } }"}, {"query": "{ hero { id } }"}]"#,
+            ))
+            .unwrap();
+        let resp = graphql_batched(
+            &schema(),
+            &Database::new(),
+            req,
+            &BatchConfig::new().max_size(1),
+        )
+        .await;
+        assert_eq!(resp.status(), 400);
+    }
+}
@@ -1,4 +1,9 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+#[cfg(feature = "subscriptions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subscriptions")))]
+pub mod subscriptions;
 
 use std::{borrow::Cow, io::Cursor};
 
@@ -6,17 +11,19 @@ use rocket::{
     data::{self, FromData, ToByteUnit},
     form::{error::ErrorKind, DataField, Error, Errors, FromForm, Options, ValueField},
     http::{ContentType, Status},
-    outcome::Outcome::{Failure, Forward, Success},
+    outcome::Outcome::{self, Success},
     response::{self, content, Responder, Response},
     Data, Request,
 };
 
 use juniper::{
     http::{self, GraphQLBatchRequest},
-    DefaultScalarValue, FieldError, GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync,
-    InputValue, RootNode, ScalarValue,
+    CancellationToken, Correlation, DefaultScalarValue, FieldError, GraphQLSubscriptionType,
+    GraphQLType, GraphQLTypeAsync, InputValue, RootNode, ScalarValue,
 };
 
+pub use juniper_http::BatchConfig;
+
 /// Simple wrapper around an incoming GraphQL request
 ///
 /// See the `http` module for more information. This type can be constructed
@@ -47,7 +54,7 @@ pub fn graphiql_source(
     graphql_endpoint_url: &str,
     subscriptions_endpoint_url: Option<&str>,
 ) -> content::RawHtml<String> {
-    content::RawHtml(juniper::http::graphiql::graphiql_source(
+    content::RawHtml(juniper_http::graphiql_source(
         graphql_endpoint_url,
         subscriptions_endpoint_url,
     ))
@@ -58,7 +65,7 @@ pub fn playground_source(
     graphql_endpoint_url: &str,
     subscriptions_endpoint_url: Option<&str>,
 ) -> content::RawHtml<String> {
-    content::RawHtml(juniper::http::playground::playground_source(
+    content::RawHtml(juniper_http::playground_source(
         graphql_endpoint_url,
         subscriptions_endpoint_url,
     ))
@@ -80,14 +87,9 @@ where
         SubscriptionT: GraphQLType<S, Context = CtxT>,
     {
         let response = self.0.execute_sync(root_node, context);
-        let status = if response.is_ok() {
-            Status::Ok
-        } else {
-            Status::BadRequest
-        };
         let json = serde_json::to_string(&response).unwrap();
 
-        GraphQLResponse(status, json)
+        GraphQLResponse(response_status(&response), json)
     }
 
     /// Asynchronously execute an incoming GraphQL query.
@@ -106,15 +108,44 @@ where
         CtxT: Sync,
         S: Send + Sync,
     {
-        let response = self.0.execute(root_node, context).await;
-        let status = if response.is_ok() {
-            Status::Ok
-        } else {
-            Status::BadRequest
-        };
+        self.execute_batched(root_node, context, &BatchConfig::default())
+            .await
+    }
+
+    /// Same as [`GraphQLRequest::execute()`], but handles batched requests
+    /// according to the provided [`BatchConfig`].
+    pub async fn execute_batched<CtxT, QueryT, MutationT, SubscriptionT>(
+        &self,
+        root_node: &RootNode<'_, QueryT, MutationT, SubscriptionT, S>,
+        context: &CtxT,
+        config: &BatchConfig,
+    ) -> GraphQLResponse
+    where
+        QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+        QueryT::TypeInfo: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+        SubscriptionT::TypeInfo: Sync,
+        CtxT: Sync,
+        S: Send + Sync,
+    {
+        if let Err(e) = config.check(&self.0) {
+            return GraphQLResponse::error(FieldError::new(e, juniper::Value::null()));
+        }
+
+        let response = config
+            .execute(
+                &self.0,
+                root_node,
+                context,
+                &Correlation::default(),
+                &CancellationToken::new(),
+            )
+            .await;
         let json = serde_json::to_string(&response).unwrap();
 
-        GraphQLResponse(status, json)
+        GraphQLResponse(response_status(&response), json)
     }
 
     /// Returns the operation names associated with this request.
@@ -243,7 +274,7 @@ where
     fn push_value(ctx: &mut Self::Context, field: ValueField<'f>) {
         match field.name.key().map(|key| key.as_str()) {
             Some("query") => ctx.query(field.value.into()),
-            Some("operation_name") => ctx.operation_name(field.value.into()),
+            Some("operationName" | "operation_name") => ctx.operation_name(field.value.into()),
             Some("variables") => ctx.variables(field.value.into()),
             Some(key) => {
                 if ctx.opts.strict {
@@ -301,39 +332,35 @@ where
     ) -> data::Outcome<'r, Self, Self::Error> {
         use rocket::tokio::io::AsyncReadExt as _;
 
-        let content_type = req
-            .content_type()
-            .map(|ct| (ct.top().as_str(), ct.sub().as_str()));
-        let is_json = match content_type {
-            Some(("application", "json")) => true,
-            Some(("application", "graphql")) => false,
-            _ => return Box::pin(async move { Forward(data) }).await,
-        };
-
         Box::pin(async move {
             let limit = req
                 .limits()
                 .get("graphql")
                 .unwrap_or_else(|| BODY_LIMIT.bytes());
             let mut reader = data.open(limit);
-            let mut body = String::new();
-            if let Err(e) = reader.read_to_string(&mut body).await {
-                return Failure((Status::InternalServerError, format!("{e:?}")));
+            let mut body = Vec::new();
+            if let Err(e) = reader.read_to_end(&mut body).await {
+                return Outcome::Error((Status::InternalServerError, format!("{e:?}")));
             }
 
-            Success(GraphQLRequest(if is_json {
-                match serde_json::from_str(&body) {
-                    Ok(req) => req,
-                    Err(e) => return Failure((Status::BadRequest, e.to_string())),
-                }
-            } else {
-                GraphQLBatchRequest::Single(http::GraphQLRequest::new(body, None, None))
-            }))
+            match juniper_http::parse_post(req.headers().get_one("Content-Type"), &body) {
+                Ok(req) => Success(GraphQLRequest(req)),
+                Err(e) => Outcome::Error((
+                    Status::from_code(e.status_code()).unwrap_or(Status::BadRequest),
+                    e.to_string(),
+                )),
+            }
         })
         .await
     }
 }
 
+/// Returns the [`Status`] to respond with the provided
+/// [`http::GraphQLBatchResponse`].
+fn response_status<S: ScalarValue>(resp: &http::GraphQLBatchResponse<S>) -> Status {
+    Status::from_code(juniper_http::response_status(resp)).unwrap_or(Status::BadRequest)
+}
+
 impl<'r, 'o: 'r> Responder<'r, 'o> for GraphQLResponse {
     fn respond_to(self, _req: &'r Request<'_>) -> response::Result<'o> {
         let GraphQLResponse(status, body) = self;
@@ -472,6 +499,18 @@ mod fromform_tests {
         assert_eq!(result.unwrap(), expected);
     }
 
+    #[test]
+    fn test_operation_name_spec_param() {
+        let result: Result<GraphQLRequest, Errors> =
+            Form::parse_encoded(RawStr::new("query=test&operationName=op"));
+        let expected = GraphQLRequest(GraphQLBatchRequest::Single(http::GraphQLRequest::new(
+            "test".into(),
+            Some("op".into()),
+            None,
+        )));
+        assert_eq!(result.unwrap(), expected);
+    }
+
     #[test]
     fn test_url_decode() {
         let result: Result<GraphQLRequest, Errors> = Form::parse_encoded(RawStr::new(
@@ -588,6 +627,37 @@ mod tests {
         assert_eq!(resp.await.status_code, 200);
     }
 
+    #[rocket::async_test]
+    async fn test_batch_size_limit() {
+        #[post("/", data = "<request>")]
+        async fn post_graphql_batched_handler(
+            context: &State<Database>,
+            request: super::GraphQLRequest,
+            schema: &State<Schema>,
+        ) -> super::GraphQLResponse {
+            request
+                .execute_batched(&*schema, &*context, &super::BatchConfig::new().max_size(1))
+                .await
+        }
+
+        let rocket = make_rocket_without_routes().mount("/", routes![post_graphql_batched_handler]);
+        let client = Client::untracked(rocket).await.expect("valid rocket");
+
+        let resp = client
+            .post("/")
+            .header(ContentType::JSON)
+            .body(r#"[{"query": "{hero{name}}"}, {"query": "{hero{id}}"}]"#)
+            .dispatch()
+            .await;
+        let resp = make_test_response(resp).await;
+
+        assert_eq!(resp.status_code, 400);
+        assert!(resp
+            .body
+            .unwrap()
+            .contains("batch of 2 operations exceeds the maximum of 1"));
+    }
+
     fn make_rocket() -> Rocket<Build> {
         make_rocket_without_routes().mount("/", routes![post_graphql_handler, get_graphql_handler])
     }
@@ -0,0 +1,317 @@
+//! Definitions for handling GraphQL subscriptions.
+//!
+//! Subscriptions are served over a WebSocket connection, upgraded from a
+//! regular HTTP request, with either the legacy [`graphql-ws` protocol][1] or
+//! the newer [`graphql-transport-ws` protocol][2], as implemented by the
+//! [`juniper_graphql_ws`] crate. The protocol is negotiated via the
+//! `Sec-WebSocket-Protocol` header, falling back to the legacy one if the
+//! client doesn't specify any.
+//!
+//! [1]: https://github.com/apollographql/subscriptions-transport-ws/blob/0ce7a1e1eb687fe51214483e4735f50a2f2d5c79/PROTOCOL.md
+//! [2]: https://github.com/enisdenjo/graphql-ws/blob/v5.11.2/PROTOCOL.md
+
+use std::{io, pin::Pin, sync::Arc};
+
+use futures::{
+    future::{self, BoxFuture, Either},
+    FutureExt as _, SinkExt as _, StreamExt as _,
+};
+use juniper::{GraphQLSubscriptionType, GraphQLTypeAsync, RootNode, ScalarValue};
+use juniper_graphql_ws::{graphql_transport_ws, ArcSchema, ClientMessage, Connection, Init};
+use rocket::{
+    data::{IoHandler, IoStream},
+    http::Status,
+    outcome::Outcome,
+    request::{self, FromRequest},
+    response::{self, Responder},
+    Request, Response,
+};
+use tokio_tungstenite::{
+    tungstenite::{
+        handshake::derive_accept_key,
+        protocol::{frame::coding::CloseCode, CloseFrame, Role},
+        Message,
+    },
+    WebSocketStream,
+};
+
+/// WebSocket subprotocol a GraphQL subscriptions connection is served with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Protocol {
+    /// Legacy [`graphql-ws` protocol][1] of the `subscriptions-transport-ws`
+    /// library.
+    ///
+    /// [1]: https://github.com/apollographql/subscriptions-transport-ws/blob/0ce7a1e1eb687fe51214483e4735f50a2f2d5c79/PROTOCOL.md
+    GraphQLWs,
+
+    /// [`graphql-transport-ws` protocol][1] of the `graphql-ws` library.
+    ///
+    /// [1]: https://github.com/enisdenjo/graphql-ws/blob/v5.11.2/PROTOCOL.md
+    GraphQLTransportWs,
+}
+
+impl Protocol {
+    /// Returns the name of this [`Protocol`], as specified in the
+    /// `Sec-WebSocket-Protocol` header.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::GraphQLWs => "graphql-ws",
+            Self::GraphQLTransportWs => "graphql-transport-ws",
+        }
+    }
+
+    /// Parses the provided `name` of a [`Protocol`], if it's supported.
+    fn from_name(name: &str) -> Option<Self> {
+        [Self::GraphQLWs, Self::GraphQLTransportWs]
+            .into_iter()
+            .find(|p| p.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Request guard of a WebSocket upgrade request to serve GraphQL subscriptions
+/// over.
+///
+/// Forwards requests not asking for a WebSocket upgrade, and fails with
+/// `400 Bad Request` if the client doesn't support any of the [`Protocol`]s.
+#[derive(Debug)]
+pub struct WebSocket {
+    key: String,
+    protocol: Protocol,
+    protocol_requested: bool,
+}
+
+impl WebSocket {
+    /// Returns the [`Protocol`] negotiated with the client.
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    /// Serves GraphQL subscriptions over the WebSocket connection, once it's
+    /// upgraded.
+    ///
+    /// The `init` argument is used to provide the context and additional
+    /// configuration for connections. This can be a
+    /// [`juniper_graphql_ws::ConnectionConfig`] if the context and
+    /// configuration are already known, or it can be a closure that gets
+    /// executed asynchronously when the client sends the `ConnectionInit`
+    /// message. Using a closure allows you to perform authentication based on
+    /// the parameters provided by the client.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    ///
+    /// use juniper::{
+    ///     tests::fixtures::starwars::schema::{Database, Query},
+    ///     EmptyMutation, EmptySubscription, RootNode,
+    /// };
+    /// use juniper_graphql_ws::ConnectionConfig;
+    /// use juniper_rocket::subscriptions::{WebSocket, WebSocketResponse};
+    /// use rocket::State;
+    ///
+    /// type Schema = RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>>;
+    ///
+    /// #[rocket::get("/subscriptions")]
+    /// fn subscriptions(ws: WebSocket, schema: &State<Arc<Schema>>) -> WebSocketResponse {
+    ///     ws.serve(schema.inner().clone(), ConnectionConfig::new(Database::new()))
+    /// }
+    /// ```
+    pub fn serve<QueryT, MutationT, SubscriptionT, CtxT, S, I>(
+        self,
+        root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+        init: I,
+    ) -> WebSocketResponse
+    where
+        QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+        QueryT::TypeInfo: Send + Sync,
+        MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+        MutationT::TypeInfo: Send + Sync,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+        SubscriptionT::TypeInfo: Send + Sync,
+        CtxT: Unpin + Send + Sync + 'static,
+        S: ScalarValue + Send + Sync + 'static,
+        I: Init<S, CtxT> + Send,
+    {
+        let protocol = self.protocol;
+        WebSocketResponse {
+            accept_key: derive_accept_key(self.key.as_bytes()),
+            protocol: self.protocol_requested.then_some(protocol),
+            serve: Box::new(move |io| {
+                serve_connection(io, protocol, ArcSchema(root_node), init).boxed()
+            }),
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for WebSocket {
+    type Error = &'static str;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let headers = req.headers();
+
+        let is_websocket = headers
+            .get("Upgrade")
+            .flat_map(|v| v.split(','))
+            .any(|p| p.trim().eq_ignore_ascii_case("websocket"));
+        let key = match headers.get_one("Sec-WebSocket-Key") {
+            Some(key) if is_websocket => key.to_owned(),
+            _ => return Outcome::Forward(Status::UpgradeRequired),
+        };
+
+        let mut requested = headers
+            .get("Sec-WebSocket-Protocol")
+            .flat_map(|v| v.split(','))
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .peekable();
+        let protocol_requested = requested.peek().is_some();
+        let protocol = if protocol_requested {
+            match requested.find_map(Protocol::from_name) {
+                Some(p) => p,
+                None => {
+                    return Outcome::Error((
+                        Status::BadRequest,
+                        "none of the requested WebSocket subprotocols is supported",
+                    ))
+                }
+            }
+        } else {
+            Protocol::GraphQLWs
+        };
+
+        Outcome::Success(Self {
+            key,
+            protocol,
+            protocol_requested,
+        })
+    }
+}
+
+/// [`Responder`] upgrading a [`WebSocket`] connection to serve GraphQL
+/// subscriptions over it.
+///
+/// Responds with `426 Upgrade Required` if the connection can't be upgraded.
+pub struct WebSocketResponse {
+    accept_key: String,
+    protocol: Option<Protocol>,
+    serve: Box<dyn FnOnce(IoStream) -> BoxFuture<'static, io::Result<()>> + Send>,
+}
+
+impl<'r> Responder<'r, 'static> for WebSocketResponse {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        let mut resp = Response::build();
+        resp.status(Status::UpgradeRequired)
+            .raw_header("Sec-WebSocket-Accept", self.accept_key);
+        if let Some(protocol) = self.protocol {
+            resp.raw_header("Sec-WebSocket-Protocol", protocol.name());
+        }
+        resp.upgrade("websocket", Upgrade(self.serve)).ok()
+    }
+}
+
+/// [`IoHandler`] of an upgraded [`WebSocket`] connection.
+struct Upgrade(Box<dyn FnOnce(IoStream) -> BoxFuture<'static, io::Result<()>> + Send>);
+
+#[rocket::async_trait]
+impl IoHandler for Upgrade {
+    async fn io(self: Pin<Box<Self>>, io: IoStream) -> io::Result<()> {
+        (Pin::into_inner(self).0)(io).await
+    }
+}
+
+/// Raw payload of a WebSocket message received from a client.
+struct Frame(Vec<u8>);
+
+impl<S: ScalarValue> TryFrom<Frame> for ClientMessage<S> {
+    type Error = serde_json::Error;
+
+    fn try_from(msg: Frame) -> serde_json::Result<Self> {
+        serde_json::from_slice(&msg.0)
+    }
+}
+
+impl<S: ScalarValue> TryFrom<Frame> for graphql_transport_ws::ClientMessage<S> {
+    type Error = serde_json::Error;
+
+    fn try_from(msg: Frame) -> serde_json::Result<Self> {
+        serde_json::from_slice(&msg.0)
+    }
+}
+
+/// Serves GraphQL subscriptions with the provided [`Protocol`] over the
+/// upgraded `io` stream.
+async fn serve_connection<QueryT, MutationT, SubscriptionT, CtxT, S, I>(
+    io: IoStream,
+    protocol: Protocol,
+    schema: ArcSchema<QueryT, MutationT, SubscriptionT, CtxT, S>,
+    init: I,
+) -> io::Result<()>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    QueryT::TypeInfo: Send + Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    MutationT::TypeInfo: Send + Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    SubscriptionT::TypeInfo: Send + Sync,
+    CtxT: Unpin + Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+    I: Init<S, CtxT> + Send,
+{
+    let (ws_tx, ws_rx) = WebSocketStream::from_raw_socket(io, Role::Server, None)
+        .await
+        .split();
+
+    // Pings are answered by the WebSocket implementation itself, so only data
+    // messages are passed to the protocol, until the client closes the
+    // connection.
+    let ws_rx = ws_rx
+        .take_while(|msg| future::ready(!matches!(msg, Ok(Message::Close(_)))))
+        .filter_map(|msg| {
+            future::ready(match msg {
+                Ok(Message::Text(text)) => Some(Ok(Frame(text.into_bytes()))),
+                Ok(Message::Binary(bytes)) => Some(Ok(Frame(bytes))),
+                Ok(_) => None,
+                Err(e) => Some(Err(io::Error::new(io::ErrorKind::Other, e))),
+            })
+        });
+    let ws_tx = ws_tx.sink_map_err(|e| io::Error::new(io::ErrorKind::Other, e));
+
+    let (incoming, outgoing) = match protocol {
+        Protocol::GraphQLWs => {
+            let (s_tx, s_rx) = Connection::new(schema, init).split();
+            let s_rx = s_rx.map(|msg| {
+                serde_json::to_string(&msg)
+                    .map(Message::Text)
+                    .map_err(io::Error::from)
+            });
+            (
+                ws_rx.forward(s_tx.sink_map_err(|e| match e {})).boxed(),
+                s_rx.forward(ws_tx).boxed(),
+            )
+        }
+        Protocol::GraphQLTransportWs => {
+            let (s_tx, s_rx) = graphql_transport_ws::Connection::new(schema, init).split();
+            let s_rx = s_rx.map(|output| match output {
+                graphql_transport_ws::Output::Message(msg) => serde_json::to_string(&msg)
+                    .map(Message::Text)
+                    .map_err(io::Error::from),
+                graphql_transport_ws::Output::Close { code, message } => {
+                    Ok(Message::Close(Some(CloseFrame {
+                        code: CloseCode::from(code),
+                        reason: message.into(),
+                    })))
+                }
+            });
+            (
+                ws_rx.forward(s_tx.sink_map_err(|e| match e {})).boxed(),
+                s_rx.forward(ws_tx).boxed(),
+            )
+        }
+    };
+
+    match future::select(incoming, outgoing).await {
+        Either::Left((r, _)) | Either::Right((r, _)) => r,
+    }
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "subscriptions")]
+
+use std::{
+    net::{Ipv4Addr, TcpListener},
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+
+use futures::{stream, SinkExt as _, Stream, StreamExt as _};
+use juniper::{graphql_object, graphql_subscription, EmptyMutation, RootNode};
+use juniper_graphql_ws::ConnectionConfig;
+use juniper_rocket::subscriptions::{WebSocket, WebSocketResponse};
+use rocket::{config::LogLevel, State};
+use serde_json::json;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{client::IntoClientRequest as _, http::HeaderValue, Message},
+};
+
+struct Context;
+
+impl juniper::Context for Context {}
+
+struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    fn hello() -> &'static str {
+        "world"
+    }
+}
+
+struct Subscription;
+
+#[graphql_subscription(context = Context)]
+impl Subscription {
+    async fn count() -> Pin<Box<dyn Stream<Item = i32> + Send>> {
+        Box::pin(stream::iter(1..=2))
+    }
+}
+
+type Schema = RootNode<'static, Query, EmptyMutation<Context>, Subscription>;
+
+#[rocket::get("/subscriptions")]
+fn subscriptions(ws: WebSocket, schema: &State<Arc<Schema>>) -> WebSocketResponse {
+    ws.serve(
+        schema.inner().clone(),
+        ConnectionConfig::new(Context).with_keep_alive_interval(Duration::from_secs(0)),
+    )
+}
+
+/// Launches a server serving the [`subscriptions`] route, returning its URL.
+async fn launch() -> String {
+    let port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let config = rocket::Config {
+        address: Ipv4Addr::LOCALHOST.into(),
+        port,
+        log_level: LogLevel::Off,
+        ..rocket::Config::debug_default()
+    };
+    let schema = Schema::new(Query, EmptyMutation::new(), Subscription);
+    let rocket = rocket::custom(config)
+        .manage(Arc::new(schema))
+        .mount("/", rocket::routes![subscriptions]);
+    rocket::tokio::spawn(rocket.launch());
+    format!("ws://127.0.0.1:{port}/subscriptions")
+}
+
+/// Connects to the provided `url` with the provided WebSocket subprotocol,
+/// retrying until the server is up.
+async fn connect(
+    url: &str,
+    protocol: &'static str,
+) -> tokio_tungstenite::WebSocketStream<
+    tokio_tungstenite::MaybeTlsStream<rocket::tokio::net::TcpStream>,
+> {
+    for _ in 0..50 {
+        let mut req = url.into_client_request().unwrap();
+        req.headers_mut()
+            .insert("Sec-WebSocket-Protocol", HeaderValue::from_static(protocol));
+        if let Ok((ws, resp)) = connect_async(req).await {
+            assert_eq!(resp.headers()["Sec-WebSocket-Protocol"], protocol);
+            return ws;
+        }
+        rocket::tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("failed to connect to {url}");
+}
+
+async fn send<S>(ws: &mut S, msg: serde_json::Value)
+where
+    S: futures::Sink<Message> + Unpin,
+    S::Error: std::fmt::Debug,
+{
+    ws.send(Message::Text(msg.to_string())).await.unwrap();
+}
+
+async fn recv<S, E>(ws: &mut S) -> serde_json::Value
+where
+    S: Stream<Item = Result<Message, E>> + Unpin,
+    E: std::fmt::Debug,
+{
+    match ws.next().await.unwrap().unwrap() {
+        Message::Text(text) => serde_json::from_str(&text).unwrap(),
+        msg => panic!("unexpected message: {msg:?}"),
+    }
+}
+
+#[rocket::async_test]
+async fn serves_graphql_transport_ws() {
+    let url = launch().await;
+    let mut ws = connect(&url, "graphql-transport-ws").await;
+
+    send(&mut ws, json!({"type": "connection_init"})).await;
+    assert_eq!(recv(&mut ws).await, json!({"type": "connection_ack"}));
+
+    send(
+        &mut ws,
+        json!({
+            "id": "1",
+            "type": "subscribe",
+            "payload": {"query": "subscription { count }"},
+        }),
+    )
+    .await;
+    assert_eq!(
+        recv(&mut ws).await,
+        json!({"id": "1", "type": "next", "payload": {"data": {"count": 1}}}),
+    );
+    assert_eq!(
+        recv(&mut ws).await,
+        json!({"id": "1", "type": "next", "payload": {"data": {"count": 2}}}),
+    );
+    assert_eq!(recv(&mut ws).await, json!({"id": "1", "type": "complete"}));
+}
+
+#[rocket::async_test]
+async fn serves_graphql_ws() {
+    let url = launch().await;
+    let mut ws = connect(&url, "graphql-ws").await;
+
+    send(&mut ws, json!({"type": "connection_init", "payload": {}})).await;
+    assert_eq!(recv(&mut ws).await, json!({"type": "connection_ack"}));
+
+    send(
+        &mut ws,
+        json!({
+            "id": "1",
+            "type": "start",
+            "payload": {"query": "subscription { count }"},
+        }),
+    )
+    .await;
+    assert_eq!(
+        recv(&mut ws).await,
+        json!({"id": "1", "type": "data", "payload": {"data": {"count": 1}}}),
+    );
+    assert_eq!(
+        recv(&mut ws).await,
+        json!({"id": "1", "type": "data", "payload": {"data": {"count": 2}}}),
+    );
+    assert_eq!(recv(&mut ws).await, json!({"id": "1", "type": "complete"}));
+}
@@ -2,6 +2,8 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+pub mod sse;
+
 use std::{
     pin::Pin,
     task::{self, Poll},
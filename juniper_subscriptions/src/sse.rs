@@ -0,0 +1,257 @@
+//! Implementation of the [GraphQL over Server-Sent Events Protocol][1], allowing to serve
+//! subscriptions over plain HTTP, where WebSockets are not available (blocked by proxies or CDNs,
+//! for example).
+//!
+//! Only the "distinct connections mode" is supported: every operation is requested via its own
+//! HTTP request (usually a `POST` one, having the `Accept: text/event-stream` header), and the body
+//! of the response to it is a stream of [`Event`]s. HTTP integration crates should:
+//! 1. Check whether the client [accepts an event stream](accepts_event_stream()).
+//! 2. Parse a [`GraphQLRequest`] from the request as usual.
+//! 3. Respond with the [`CONTENT_TYPE`] header and the stream returned by [`serve()`], writing
+//!    every [`Event`] into the response body (its [`Display`](fmt::Display) implementation
+//!    produces the wire format).
+//!
+//! Queries and mutations are served too, emitting a single `next` event.
+//!
+//! [1]: https://github.com/enisdenjo/graphql-sse/blob/v2.0.0/PROTOCOL.md
+
+use std::{fmt, sync::Arc};
+
+use futures::{
+    channel::mpsc, future, stream, FutureExt as _, SinkExt as _, Stream, StreamExt as _,
+};
+use juniper::{
+    http::{self, GraphQLRequest, GraphQLResponse},
+    DefaultScalarValue, GraphQLError, GraphQLSubscriptionType, GraphQLTypeAsync, RootNode,
+    ScalarValue,
+};
+
+use crate::Connection;
+
+/// `Content-Type` header value of responses streaming [`Event`]s.
+pub const CONTENT_TYPE: &str = "text/event-stream";
+
+/// Checks whether the provided `Accept` header value allows to respond with a stream of
+/// [`Event`]s, so the request should be served via [`serve()`].
+#[must_use]
+pub fn accepts_event_stream(accept: &str) -> bool {
+    accept.split(',').any(|media_type| {
+        media_type
+            .split(';')
+            .next()
+            .map_or(false, |t| t.trim().eq_ignore_ascii_case(CONTENT_TYPE))
+    })
+}
+
+/// Event sent to the client in the "distinct connections mode".
+#[derive(Debug)]
+pub enum Event<S = DefaultScalarValue> {
+    /// Result of an operation execution. For queries and mutations, it's sent once. For
+    /// subscriptions, it's sent for every event in the event stream.
+    Next(GraphQLResponse<S>),
+
+    /// Indicates that no more events will be sent for the operation.
+    Complete,
+}
+
+impl<S: ScalarValue> Event<S> {
+    /// Returns the name of this [`Event`], as specified by the protocol.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Next(_) => "next",
+            Self::Complete => "complete",
+        }
+    }
+
+    /// Returns the data of this [`Event`] serialized into a JSON string (empty for the
+    /// [`Event::Complete`]).
+    ///
+    /// # Errors
+    ///
+    /// If the contained [`GraphQLResponse`] fails to serialize.
+    pub fn data(&self) -> serde_json::Result<String> {
+        match self {
+            Self::Next(resp) => serde_json::to_string(resp),
+            Self::Complete => Ok(String::new()),
+        }
+    }
+}
+
+impl<S: ScalarValue> fmt::Display for Event<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Serialized JSON contains no newlines, so fits into a single `data` field.
+        let data = self.data().map_err(|_| fmt::Error)?;
+        write!(f, "event: {}\ndata: {data}\n\n", self.name())
+    }
+}
+
+/// Executes the provided [`GraphQLRequest`] against the `root_node` with the `context`, returning
+/// a stream of [`Event`]s to be sent to the client in the "distinct connections mode".
+///
+/// The returned stream always ends with the [`Event::Complete`], unless it's dropped before (when
+/// the client disconnects, for example), which stops the operation.
+pub fn serve<QueryT, MutationT, SubscriptionT, CtxT, S>(
+    req: GraphQLRequest<S>,
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: Arc<CtxT>,
+) -> impl Stream<Item = Event<S>> + Send + 'static
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    QueryT::TypeInfo: Send + Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    MutationT::TypeInfo: Send + Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    SubscriptionT::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    // The operation borrows the request, the schema and the context, so it's driven by a future
+    // owning them, which passes the events through a channel. The channel has no buffer, so the
+    // operation doesn't outpace the client.
+    let (mut tx, rx) = mpsc::channel(0);
+
+    let operation = async move {
+        // Try to execute this as a query or mutation.
+        match juniper::execute(
+            &req.query,
+            req.operation_name.as_deref(),
+            &root_node,
+            &req.variables(),
+            &context,
+        )
+        .await
+        {
+            Err(GraphQLError::IsSubscription) => {}
+            res => {
+                if tx
+                    .send(Event::Next(GraphQLResponse::from_result(res)))
+                    .await
+                    .is_ok()
+                {
+                    let _ = tx.send(Event::Complete).await;
+                }
+                return;
+            }
+        }
+
+        // Try to execute as a subscription.
+        match http::resolve_into_stream(&req, &root_node, &context).await {
+            Ok((stream, errors)) => {
+                let mut outputs = Connection::from_stream(stream, errors);
+                while let Some(output) = outputs.next().await {
+                    let resp = GraphQLResponse::from_result(Ok((output.data, output.errors)));
+                    if tx.send(Event::Next(resp)).await.is_err() {
+                        // The client has gone, so there is no one to send events to.
+                        return;
+                    }
+                }
+            }
+            Err(e) => {
+                if tx
+                    .send(Event::Next(GraphQLResponse::from_result(Err(e))))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        }
+        let _ = tx.send(Event::Complete).await;
+    };
+
+    stream::select(
+        rx,
+        operation.into_stream().filter_map(|()| future::ready(None)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::{pin::Pin, sync::Arc};
+
+    use futures::{stream, Stream, StreamExt as _};
+    use juniper::{
+        graphql_object, graphql_subscription, http::GraphQLRequest, DefaultScalarValue,
+        EmptyMutation, FieldResult, RootNode,
+    };
+
+    use super::{accepts_event_stream, serve};
+
+    struct Context(i32);
+
+    impl juniper::Context for Context {}
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn context(context: &Context) -> i32 {
+            context.0
+        }
+    }
+
+    struct Subscription;
+
+    type NumberStream = Pin<Box<dyn Stream<Item = FieldResult<i32>> + Send>>;
+
+    #[graphql_subscription(context = Context)]
+    impl Subscription {
+        async fn count(context: &Context) -> NumberStream {
+            Box::pin(stream::iter((1..=context.0).map(Ok)))
+        }
+    }
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Context>, Subscription>;
+
+    async fn events(query: &str) -> Vec<String> {
+        let schema = Arc::new(Schema::new(Query, EmptyMutation::new(), Subscription));
+        let req = GraphQLRequest::<DefaultScalarValue>::new(query.into(), None, None);
+
+        serve(req, schema, Arc::new(Context(2)))
+            .map(|ev| ev.to_string())
+            .collect()
+            .await
+    }
+
+    #[test]
+    fn detects_event_stream_accept() {
+        assert!(accepts_event_stream("text/event-stream"));
+        assert!(accepts_event_stream(
+            "application/json, text/event-stream; charset=utf-8"
+        ));
+        assert!(!accepts_event_stream("application/json"));
+    }
+
+    #[tokio::test]
+    async fn serves_query() {
+        assert_eq!(
+            events("{ context }").await,
+            [
+                "event: next\ndata: {\"data\":{\"context\":2}}\n\n",
+                "event: complete\ndata: \n\n",
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_subscription() {
+        assert_eq!(
+            events("subscription { count }").await,
+            [
+                "event: next\ndata: {\"data\":{\"count\":1}}\n\n",
+                "event: next\ndata: {\"data\":{\"count\":2}}\n\n",
+                "event: complete\ndata: \n\n",
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_errors() {
+        let events = events("subscription { unknown }").await;
+
+        assert_eq!(events.len(), 2);
+        assert!(events[0].starts_with("event: next\ndata: {\"errors\":["));
+        assert_eq!(events[1], "event: complete\ndata: \n\n");
+    }
+}
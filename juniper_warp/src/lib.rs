@@ -3,16 +3,15 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
-use std::{collections::HashMap, str, sync::Arc};
-
-use anyhow::anyhow;
-use futures::{FutureExt as _, TryFutureExt};
-use juniper::{
-    http::{GraphQLBatchRequest, GraphQLRequest},
-    ScalarValue,
-};
+use std::sync::Arc;
+
+use futures::TryFutureExt as _;
+use juniper::{http::GraphQLBatchRequest, CancellationToken, Correlation, ScalarValue};
+use juniper_http::{RequestError, JSON_CONTENT_TYPE};
 use tokio::task;
-use warp::{body, filters::BoxedFilter, http, hyper::body::Bytes, query, Filter};
+use warp::{body, filters::BoxedFilter, header, http, hyper::body::Bytes, query, Filter};
+
+pub use juniper_http::BatchConfig;
 
 /// Make a filter for graphql queries/mutations.
 ///
@@ -73,6 +72,26 @@ pub fn make_graphql_filter<Query, Mutation, Subscription, CtxT, S>(
     schema: juniper::RootNode<'static, Query, Mutation, Subscription, S>,
     context_extractor: BoxedFilter<(CtxT,)>,
 ) -> BoxedFilter<(http::Response<Vec<u8>>,)>
+where
+    Query: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Query::TypeInfo: Send + Sync,
+    Mutation: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Mutation::TypeInfo: Send + Sync,
+    Subscription: juniper::GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    Subscription::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    make_graphql_filter_batched(schema, context_extractor, BatchConfig::default())
+}
+
+/// Same as [`make_graphql_filter()`], but handles batched requests according to
+/// the provided [`BatchConfig`].
+pub fn make_graphql_filter_batched<Query, Mutation, Subscription, CtxT, S>(
+    schema: juniper::RootNode<'static, Query, Mutation, Subscription, S>,
+    context_extractor: BoxedFilter<(CtxT,)>,
+    config: BatchConfig,
+) -> BoxedFilter<(http::Response<Vec<u8>>,)>
 where
     Query: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
     Query::TypeInfo: Send + Sync,
@@ -84,72 +103,36 @@ where
     S: ScalarValue + Send + Sync + 'static,
 {
     let schema = Arc::new(schema);
-    let post_json_schema = schema.clone();
-    let post_graphql_schema = schema.clone();
 
-    let handle_post_json_request = move |context: CtxT, req: GraphQLBatchRequest<S>| {
-        let schema = post_json_schema.clone();
+    let handle_request = move |context: CtxT, req: Result<GraphQLBatchRequest<S>, RequestError>| {
+        let schema = schema.clone();
         async move {
-            let resp = req.execute(&schema, &context).await;
+            let req = match req.and_then(|req| config.check(&req).map(|()| req)) {
+                Ok(req) => req,
+                Err(e) => return Ok(error_response(&e)),
+            };
+
+            let cancellation = CancellationToken::new().cancel_on_drop();
+            let resp = config
+                .execute(
+                    &req,
+                    &schema,
+                    &context,
+                    &Correlation::default(),
+                    cancellation.token(),
+                )
+                .await;
+            cancellation.disarm();
 
             Ok::<_, warp::Rejection>(build_response(
-                serde_json::to_vec(&resp)
-                    .map(|json| (json, resp.is_ok()))
-                    .map_err(Into::into),
+                serde_json::to_vec(&resp).map(|json| (json, juniper_http::response_status(&resp))),
             ))
         }
     };
-    let post_json_filter = warp::post()
-        .and(context_extractor.clone())
-        .and(body::json())
-        .and_then(handle_post_json_request);
-
-    let handle_post_graphql_request = move |context: CtxT, body: Bytes| {
-        let schema = post_graphql_schema.clone();
-        async move {
-            let query = str::from_utf8(body.as_ref())
-                .map_err(|e| anyhow!("Request body query is not a valid UTF-8 string: {e}"))?;
-            let req = GraphQLRequest::new(query.into(), None, None);
-
-            let resp = req.execute(&schema, &context).await;
-
-            Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
-        }
-        .then(|res| async { Ok::<_, warp::Rejection>(build_response(res)) })
-    };
-    let post_graphql_filter = warp::post()
-        .and(context_extractor.clone())
-        .and(body::bytes())
-        .and_then(handle_post_graphql_request);
 
-    let handle_get_request = move |context: CtxT, mut qry: HashMap<String, String>| {
-        let schema = schema.clone();
-        async move {
-            let req = GraphQLRequest::new(
-                qry.remove("query")
-                    .ok_or_else(|| anyhow!("Missing GraphQL query string in query parameters"))?,
-                qry.remove("operation_name"),
-                qry.remove("variables")
-                    .map(|vs| serde_json::from_str(&vs))
-                    .transpose()?,
-            );
-
-            let resp = req.execute(&schema, &context).await;
-
-            Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
-        }
-        .then(|res| async move { Ok::<_, warp::Rejection>(build_response(res)) })
-    };
-    let get_filter = warp::get()
-        .and(context_extractor)
-        .and(query::query())
-        .and_then(handle_get_request);
-
-    get_filter
-        .or(post_json_filter)
-        .unify()
-        .or(post_graphql_filter)
-        .unify()
+    context_extractor
+        .and(graphql_request())
+        .and_then(handle_request)
         .boxed()
 }
 
@@ -166,37 +149,18 @@ where
     S: ScalarValue + Send + Sync + 'static,
 {
     let schema = Arc::new(schema);
-    let post_json_schema = schema.clone();
-    let post_graphql_schema = schema.clone();
 
-    let handle_post_json_request = move |context: CtxT, req: GraphQLBatchRequest<S>| {
-        let schema = post_json_schema.clone();
+    let handle_request = move |context: CtxT, req: Result<GraphQLBatchRequest<S>, RequestError>| {
+        let schema = schema.clone();
         async move {
-            let res = task::spawn_blocking(move || {
-                let resp = req.execute_sync(&schema, &context);
-                Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
-            })
-            .await?;
-
-            Ok(build_response(res))
-        }
-        .map_err(|e: task::JoinError| warp::reject::custom(JoinError(e)))
-    };
-    let post_json_filter = warp::post()
-        .and(context_extractor.clone())
-        .and(body::json())
-        .and_then(handle_post_json_request);
+            let req = match req {
+                Ok(req) => req,
+                Err(e) => return Ok(error_response(&e)),
+            };
 
-    let handle_post_graphql_request = move |context: CtxT, body: Bytes| {
-        let schema = post_graphql_schema.clone();
-        async move {
             let res = task::spawn_blocking(move || {
-                let query = str::from_utf8(body.as_ref())
-                    .map_err(|e| anyhow!("Request body is not a valid UTF-8 string: {e}"))?;
-                let req = GraphQLRequest::new(query.into(), None, None);
-
                 let resp = req.execute_sync(&schema, &context);
-                Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
+                serde_json::to_vec(&resp).map(|json| (json, juniper_http::response_status(&resp)))
             })
             .await?;
 
@@ -204,45 +168,33 @@ where
         }
         .map_err(|e: task::JoinError| warp::reject::custom(JoinError(e)))
     };
-    let post_graphql_filter = warp::post()
-        .and(context_extractor.clone())
-        .and(body::bytes())
-        .and_then(handle_post_graphql_request);
-
-    let handle_get_request = move |context: CtxT, mut qry: HashMap<String, String>| {
-        let schema = schema.clone();
-        async move {
-            let res = task::spawn_blocking(move || {
-                let req = GraphQLRequest::new(
-                    qry.remove("query").ok_or_else(|| {
-                        anyhow!("Missing GraphQL query string in query parameters")
-                    })?,
-                    qry.remove("operation_name"),
-                    qry.remove("variables")
-                        .map(|vs| serde_json::from_str(&vs))
-                        .transpose()?,
-                );
 
-                let resp = req.execute_sync(&schema, &context);
-                Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
-            })
-            .await?;
+    context_extractor
+        .and(graphql_request())
+        .and_then(handle_request)
+        .boxed()
+}
 
-            Ok(build_response(res))
-        }
-        .map_err(|e: task::JoinError| warp::reject::custom(JoinError(e)))
-    };
+/// Makes a filter extracting a [`GraphQLBatchRequest`] out of a `GET` or `POST`
+/// HTTP request, as described in [`juniper_http::parse_request()`].
+///
+/// Requests of other HTTP methods are rejected, while malformed ones are
+/// extracted as a [`RequestError`] to respond with.
+fn graphql_request<S>() -> BoxedFilter<(Result<GraphQLBatchRequest<S>, RequestError>,)>
+where
+    S: ScalarValue + Send + 'static,
+{
     let get_filter = warp::get()
-        .and(context_extractor)
-        .and(query::query())
-        .and_then(handle_get_request);
-
-    get_filter
-        .or(post_json_filter)
-        .unify()
-        .or(post_graphql_filter)
-        .unify()
-        .boxed()
+        .and(query::raw().or(warp::any().map(String::new)).unify())
+        .map(|qs: String| juniper_http::parse_get(&qs).map(GraphQLBatchRequest::Single));
+    let post_filter = warp::post()
+        .and(header::optional::<String>("content-type"))
+        .and(body::bytes())
+        .map(|content_type: Option<String>, body: Bytes| {
+            juniper_http::parse_post(content_type.as_deref(), &body)
+        });
+
+    get_filter.or(post_filter).unify().boxed()
 }
 
 /// Error raised by `tokio_threadpool` if the thread pool has been shutdown.
@@ -253,11 +205,11 @@ pub struct JoinError(task::JoinError);
 
 impl warp::reject::Reject for JoinError {}
 
-fn build_response(response: Result<(Vec<u8>, bool), anyhow::Error>) -> http::Response<Vec<u8>> {
+fn build_response(response: Result<(Vec<u8>, u16), serde_json::Error>) -> http::Response<Vec<u8>> {
     match response {
-        Ok((body, is_ok)) => http::Response::builder()
-            .status(if is_ok { 200 } else { 400 })
-            .header("content-type", "application/json")
+        Ok((body, status)) => http::Response::builder()
+            .status(status)
+            .header("content-type", JSON_CONTENT_TYPE)
             .body(body)
             .expect("response is valid"),
         Err(_) => http::Response::builder()
@@ -267,6 +219,14 @@ fn build_response(response: Result<(Vec<u8>, bool), anyhow::Error>) -> http::Res
     }
 }
 
+fn error_response(err: &RequestError) -> http::Response<Vec<u8>> {
+    http::Response::builder()
+        .status(err.status_code())
+        .header("content-type", "text/plain; charset=utf-8")
+        .body(err.to_string().into_bytes())
+        .expect("response is valid")
+}
+
 /// Create a filter that replies with an HTML page containing GraphiQL. This does not handle routing, so you can mount it on any endpoint.
 ///
 /// For example:
@@ -304,7 +264,7 @@ fn graphiql_response(
     http::Response::builder()
         .header("content-type", "text/html;charset=utf-8")
         .body(
-            juniper::http::graphiql::graphiql_source(graphql_endpoint_url, subscriptions_endpoint)
+            juniper_http::graphiql_source(graphql_endpoint_url, subscriptions_endpoint)
                 .into_bytes(),
         )
         .expect("response is valid")
@@ -327,11 +287,8 @@ fn playground_response(
     http::Response::builder()
         .header("content-type", "text/html;charset=utf-8")
         .body(
-            juniper::http::playground::playground_source(
-                graphql_endpoint_url,
-                subscriptions_endpoint_url,
-            )
-            .into_bytes(),
+            juniper_http::playground_source(graphql_endpoint_url, subscriptions_endpoint_url)
+                .into_bytes(),
         )
         .expect("response is valid")
 }
@@ -635,6 +592,78 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn rejects_too_large_batches() {
+        use juniper::{
+            tests::fixtures::starwars::schema::{Database, Query},
+            EmptyMutation, EmptySubscription, RootNode,
+        };
+
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+
+        let state = warp::any().map(Database::new);
+        let filter = warp::path("graphql2").and(make_graphql_filter_batched(
+            schema,
+            state.boxed(),
+            BatchConfig::new().max_size(1),
+        ));
+
+        let response = request()
+            .method("POST")
+            .path("/graphql2")
+            .header("content-type", "application/json")
+            .body(
+                r##"[
+                     { "query": "{ hero(episode: NEW_HOPE) { name } }" },
+                     { "query": "{ hero(episode: EMPIRE) { id name } }" }
+                 ]"##,
+            )
+            .reply(&filter)
+            .await;
+
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            String::from_utf8(response.body().to_vec()).unwrap(),
+            "batch of 2 operations exceeds the maximum of 1",
+        );
+    }
+
+    #[tokio::test]
+    async fn get_request_takes_operation_name() {
+        use juniper::{
+            tests::fixtures::starwars::schema::{Database, Query},
+            EmptyMutation, EmptySubscription, RootNode,
+        };
+
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+
+        let state = warp::any().map(Database::new);
+        let filter = warp::path("graphql2").and(make_graphql_filter(schema, state.boxed()));
+
+        let response = request()
+            .method("GET")
+            .path(
+                "/graphql2?query=query%20A%20%7B%20hero%20%7B%20id%20%7D%20%7D%20\
+                 query%20B%20%7B%20hero%20%7B%20name%20%7D%20%7D&operationName=B",
+            )
+            .reply(&filter)
+            .await;
+
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert_eq!(
+            String::from_utf8(response.body().to_vec()).unwrap(),
+            r#"{"data":{"hero":{"name":"R2-D2"}}}"#,
+        );
+    }
+
     #[test]
     fn batch_request_deserialization_can_fail() {
         let json = r#"blah"#;
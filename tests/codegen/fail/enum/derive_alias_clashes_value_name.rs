@@ -0,0 +1,10 @@
+use juniper::GraphQLEnum;
+
+#[derive(GraphQLEnum)]
+enum Test {
+    Test,
+    #[graphql(alias = "TEST")]
+    Test1,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use juniper::GraphQLEnum;
+
+#[derive(GraphQLEnum)]
+#[graphql(str_conversions)]
+enum Test {
+    Variant,
+    #[graphql(ignore)]
+    Ignored,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    #[graphql(deprecated = "Not used")]
+    test: String,
+}
+
+fn main() {}
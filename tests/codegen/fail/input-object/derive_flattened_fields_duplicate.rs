@@ -0,0 +1,22 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct City {
+    city: String,
+}
+
+#[derive(GraphQLInputObject)]
+struct Address {
+    #[graphql(flatten)]
+    city: City,
+    building: i32,
+}
+
+#[derive(GraphQLInputObject)]
+struct NewUser {
+    city: String,
+    #[graphql(flatten)]
+    address: Address,
+}
+
+fn main() {}
@@ -2,7 +2,7 @@ use juniper::graphql_interface;
 
 #[graphql_interface]
 trait Character {
-    fn id(&self) -> &str {
+    async fn id(&self) -> &str {
         "default"
     }
 }
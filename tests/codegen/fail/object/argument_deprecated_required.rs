@@ -0,0 +1,12 @@
+use juniper::graphql_object;
+
+struct Obj;
+
+#[graphql_object]
+impl Obj {
+    fn id(&self, #[graphql(deprecated = "Not used")] num: i32) -> &str {
+        "funA"
+    }
+}
+
+fn main() {}
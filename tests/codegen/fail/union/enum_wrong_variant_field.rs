@@ -2,7 +2,7 @@ use juniper::{GraphQLObject, GraphQLUnion};
 
 #[derive(GraphQLUnion)]
 enum Character1 {
-    A { human: Human },
+    A,
 }
 
 #[derive(GraphQLUnion)]
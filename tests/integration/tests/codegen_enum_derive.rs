@@ -752,6 +752,303 @@ mod renamed_all_fields {
     }
 }
 
+mod aliased_values {
+    use super::*;
+
+    #[derive(GraphQLEnum)]
+    enum Character {
+        #[graphql(alias = "PERSON")]
+        Human,
+        #[graphql(alias = ["ROBOT", "ANDROID"])]
+        Droid,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn pass_as_is(character: Character) -> Character {
+            character
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human: passAsIs(character: HUMAN)
+            person: passAsIs(character: PERSON)
+            robot: passAsIs(character: ROBOT)
+            android: passAsIs(character: ANDROID)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "human": "HUMAN",
+                    "person": "HUMAN",
+                    "robot": "DROID",
+                    "android": "DROID",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_from_variable() {
+        const DOC: &str = r#"query($character: Character!) {
+            passAsIs(character: $character)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {"character": "ANDROID"},
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"passAsIs": "DROID"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_no_aliases_in_enum_values() {
+        const DOC: &str = r#"{
+            __type(name: "Character") {
+                enumValues {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"enumValues": [
+                    {"name": "HUMAN"},
+                    {"name": "DROID"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod serde_rename_source {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, GraphQLEnum, PartialEq, Serialize)]
+    #[graphql(rename_source = "serde")]
+    #[serde(rename_all = "snake_case")]
+    enum Character {
+        MainHuman,
+        #[serde(rename = "robot", alias = "android")]
+        Droid,
+        #[graphql(name = "ALIEN")]
+        Wookiee,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn pass_as_is(character: Character) -> Character {
+            character
+        }
+    }
+
+    #[test]
+    fn is_consistent_with_serde() {
+        assert_eq!(
+            serde_json::to_value([Character::MainHuman, Character::Droid]).unwrap(),
+            serde_json::json!(["main_human", "robot"]),
+        );
+        assert_eq!(
+            serde_json::from_str::<Character>(r#""android""#).unwrap(),
+            Character::Droid,
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human: passAsIs(character: main_human)
+            robot: passAsIs(character: robot)
+            android: passAsIs(character: android)
+            alien: passAsIs(character: ALIEN)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "human": "main_human",
+                    "robot": "robot",
+                    "android": "robot",
+                    "alien": "ALIEN",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_source_names_in_enum_values() {
+        const DOC: &str = r#"{
+            __type(name: "Character") {
+                enumValues {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"enumValues": [
+                    {"name": "main_human"},
+                    {"name": "robot"},
+                    {"name": "ALIEN"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod str_conversions {
+    use std::str::FromStr as _;
+
+    use super::*;
+
+    #[derive(Debug, GraphQLEnum, PartialEq)]
+    #[graphql(rename_all = "camelCase", str_conversions)]
+    enum Character {
+        #[graphql(alias = "PERSON")]
+        Human,
+        #[graphql(name = "robot")]
+        Droid,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn pass_as_is(character: Character) -> Character {
+            character
+        }
+    }
+
+    #[test]
+    fn parses_names_and_aliases() {
+        assert_eq!(Character::from_str("Human"), Ok(Character::Human));
+        assert_eq!(Character::from_str("PERSON"), Ok(Character::Human));
+        assert_eq!(Character::try_from("robot"), Ok(Character::Droid));
+        assert_eq!(
+            Character::from_str("droid"),
+            Err("Unknown enum value: droid".to_owned()),
+        );
+    }
+
+    #[test]
+    fn displays_names() {
+        assert_eq!(Character::Human.to_string(), "Human");
+        assert_eq!(Character::Droid.to_string(), "robot");
+    }
+
+    #[tokio::test]
+    async fn is_consistent_with_graphql() {
+        const DOC: &str = r#"{
+            human: passAsIs(character: Human)
+            robot: passAsIs(character: robot)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"human": "Human", "robot": "robot"}), vec![])),
+        );
+    }
+}
+
+mod remote_enum {
+    use super::*;
+
+    mod compass {
+        #[derive(Debug, PartialEq)]
+        pub enum Direction<T> {
+            North,
+            South,
+            Custom(T),
+        }
+    }
+
+    #[derive(GraphQLEnum)]
+    #[graphql(name = "Direction", remote = compass::Direction<T>)]
+    enum DirectionDef<T> {
+        North,
+        South,
+        #[graphql(ignore)]
+        Custom(T),
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn opposite(direction: DirectionDef<()>) -> DirectionDef<()> {
+            match compass::Direction::from(direction) {
+                compass::Direction::North => compass::Direction::South,
+                compass::Direction::South => compass::Direction::North,
+                compass::Direction::Custom(t) => compass::Direction::Custom(t),
+            }
+            .into()
+        }
+    }
+
+    #[test]
+    fn converts_both_ways() {
+        assert_eq!(
+            compass::Direction::from(DirectionDef::Custom(1)),
+            compass::Direction::Custom(1),
+        );
+        assert!(matches!(
+            DirectionDef::from(compass::Direction::<()>::North),
+            DirectionDef::North,
+        ));
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            opposite(direction: NORTH)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"opposite": "SOUTH"}), vec![])),
+        );
+    }
+}
+
 mod explicit_scalar {
     use super::*;
 
@@ -531,6 +531,226 @@ mod ignored_field {
     }
 }
 
+mod flattened_field {
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    struct Address {
+        city: String,
+        #[graphql(default = 1)]
+        building: i32,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct NewUser {
+        name: String,
+        #[graphql(flatten)]
+        address: Address,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn describe(user: NewUser) -> String {
+            format!(
+                "{} from {}, {}",
+                user.name, user.address.city, user.address.building,
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            describe(user: { name: "Alice", city: "Kyiv" })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"describe": "Alice from Kyiv, 1"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_input_fields() {
+        const DOC: &str = r#"{
+            __type(name: "NewUser") {
+                inputFields {
+                    name
+                    defaultValue
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "name", "defaultValue": null},
+                    {"name": "city", "defaultValue": null},
+                    {"name": "building", "defaultValue": "1"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn converts_to_input_value() {
+        use juniper::{DefaultScalarValue, ToInputValue};
+
+        let user = NewUser {
+            name: "Alice".into(),
+            address: Address {
+                city: "Kyiv".into(),
+                building: 3,
+            },
+        };
+
+        assert_eq!(
+            ToInputValue::<DefaultScalarValue>::to_input_value(&user).to_string(),
+            r#"{name: "Alice", city: "Kyiv", building: 3}"#,
+        );
+    }
+}
+
+mod validation {
+    use juniper::{ExecutionError, FieldError};
+
+    use super::*;
+
+    fn non_negative(v: &f64) -> Result<(), &'static str> {
+        if *v < 0.0 {
+            return Err("Must not be negative");
+        }
+        Ok(())
+    }
+
+    fn ordered(range: &Range) -> Result<(), String> {
+        if range.from > range.to {
+            return Err(format!("`{}` is greater than `{}`", range.from, range.to));
+        }
+        Ok(())
+    }
+
+    #[derive(GraphQLInputObject)]
+    #[graphql(validate = ordered)]
+    struct Range {
+        #[graphql(validate = non_negative)]
+        from: f64,
+        to: f64,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Filter {
+        range: Range,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn length(filter: Filter) -> f64 {
+            filter.range.to - filter.range.from
+        }
+
+        fn total(ranges: Vec<Range>) -> f64 {
+            ranges.iter().map(|r| r.to - r.from).sum()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            length(filter: { range: { from: 1, to: 3 } })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"length": 2.0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_invalid_field_with_path() {
+        const DOC: &str = r#"{
+            length(filter: { range: { from: -1, to: 3 } })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    SourcePosition::new(14, 1, 12),
+                    &["length"],
+                    FieldError::new(
+                        "Must not be negative",
+                        graphql_value!({"inputPath": ["filter", "range", "from"]}),
+                    ),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_invalid_object_with_path() {
+        const DOC: &str = r#"{
+            length(filter: { range: { from: 3, to: 1 } })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    SourcePosition::new(14, 1, 12),
+                    &["length"],
+                    FieldError::new(
+                        "`3` is greater than `1`",
+                        graphql_value!({"inputPath": ["filter", "range"]}),
+                    ),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_invalid_list_item_with_path() {
+        const DOC: &str = r#"{
+            total(ranges: [{ from: 1, to: 3 }, { from: -1, to: 3 }])
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    SourcePosition::new(14, 1, 12),
+                    &["total"],
+                    FieldError::new(
+                        "Must not be negative",
+                        graphql_value!({"inputPath": ["ranges", 1, "from"]}),
+                    ),
+                )],
+            )),
+        );
+    }
+}
+
 mod description_from_doc_comment {
     use super::*;
 
@@ -795,6 +1015,93 @@ mod description_from_graphql_attr {
     }
 }
 
+mod deprecated_field {
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    struct Point2D {
+        x: f64,
+        y: f64,
+        #[graphql(deprecated)]
+        z: Option<f64>,
+        #[deprecated(note = "Use `x`.")]
+        w: Option<f64>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        #[allow(deprecated)]
+        fn sum(point: Point2D) -> f64 {
+            point.x + point.y + point.z.unwrap_or_default() + point.w.unwrap_or_default()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            sum(point: { x: 1, y: 2, z: 3, w: 4 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"sum": 10.0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn omits_deprecated_input_fields_by_default() {
+        const DOC: &str = r#"{
+            __type(name: "Point2D") {
+                inputFields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [{"name": "x"}, {"name": "y"}]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn provides_deprecation_reason() {
+        const DOC: &str = r#"{
+            __type(name: "Point2D") {
+                inputFields(includeDeprecated: true) {
+                    name
+                    isDeprecated
+                    deprecationReason
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "x", "isDeprecated": false, "deprecationReason": null},
+                    {"name": "y", "isDeprecated": false, "deprecationReason": null},
+                    {"name": "z", "isDeprecated": true, "deprecationReason": null},
+                    {"name": "w", "isDeprecated": true, "deprecationReason": "Use `x`."},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod renamed_all_fields {
     use super::*;
 
@@ -885,3 +1192,148 @@ mod renamed_all_fields {
         );
     }
 }
+
+mod derived_with_object {
+    use juniper::GraphQLObject;
+
+    use super::*;
+
+    /// A point on a map.
+    #[derive(GraphQLInputObject, GraphQLObject)]
+    #[graphql(input_name = "PointInput", rename_all = "none")]
+    struct Point {
+        x_coord: f64,
+        /// Vertical coordinate.
+        #[graphql(default = 10.0)]
+        y_coord: f64,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn mirror(point: Point) -> Point {
+            Point {
+                x_coord: point.y_coord,
+                y_coord: point.x_coord,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            mirror(point: {x_coord: 1.0}) {
+                x_coord
+                y_coord
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"mirror": {"x_coord": 10.0, "y_coord": 1.0}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn shares_descriptions() {
+        const DOC: &str = r#"{
+            object: __type(name: "Point") {
+                kind
+                description
+                fields {
+                    name
+                    description
+                }
+            }
+            input: __type(name: "PointInput") {
+                kind
+                description
+                inputFields {
+                    name
+                    description
+                    defaultValue
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "object": {
+                        "kind": "OBJECT",
+                        "description": "A point on a map.",
+                        "fields": [
+                            {"name": "x_coord", "description": null},
+                            {"name": "y_coord", "description": "Vertical coordinate."},
+                        ],
+                    },
+                    "input": {
+                        "kind": "INPUT_OBJECT",
+                        "description": "A point on a map.",
+                        "inputFields": [
+                            {"name": "x_coord", "description": null, "defaultValue": null},
+                            {
+                                "name": "y_coord",
+                                "description": "Vertical coordinate.",
+                                "defaultValue": "10",
+                            },
+                        ],
+                    },
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod remote_struct {
+    use super::*;
+
+    mod geo {
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+    }
+
+    #[derive(GraphQLInputObject)]
+    #[graphql(name = "PointInput", remote = geo::Point)]
+    struct PointInputDef {
+        x: i32,
+        #[graphql(default = 10)]
+        y: i32,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn sum(point: PointInputDef) -> i32 {
+            let point = geo::Point::from(point);
+            point.x + point.y
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            sum(point: {x: 1})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"sum": 11}), vec![])),
+        );
+    }
+}
@@ -3353,6 +3353,60 @@ mod nullable_argument_subtyping {
     }
 }
 
+mod defaulted_argument_subtyping {
+    use super::*;
+
+    #[graphql_interface(for = Droid)]
+    trait Character {
+        fn id(&self) -> String;
+    }
+
+    struct Droid {
+        id: String,
+    }
+
+    #[graphql_object(impl = CharacterValue)]
+    impl Droid {
+        fn id(&self, #[graphql(default = true)] is_present: bool) -> &str {
+            is_present.then_some(&*self.id).unwrap_or("missing")
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn character(&self) -> CharacterValue {
+            Droid {
+                id: "droid-99".into(),
+            }
+            .into()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_id_field() {
+        const DOC: &str = r#"{
+            character {
+                id
+                ... on Droid {
+                    absentId: id(isPresent: false)
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"character": {"id": "droid-99", "absentId": "missing"}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod simple_subtyping {
     use super::*;
 
@@ -3880,6 +3934,290 @@ mod branching_subtyping {
     }
 }
 
+mod default_fields {
+    use super::*;
+
+    #[graphql_interface(for = [Human, Droid])]
+    trait Character {
+        fn id(&self) -> &str;
+
+        fn display_name(&self, prefix: Option<String>) -> String {
+            format!("{}{}", prefix.unwrap_or_default(), self.id())
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue)]
+    struct Human {
+        id: String,
+        home_planet: String,
+    }
+
+    impl Character for Human {
+        fn id(&self) -> &str {
+            &self.id
+        }
+    }
+
+    struct Droid {
+        id: String,
+        primary_function: String,
+    }
+
+    #[graphql_object(impl = CharacterValue)]
+    impl Droid {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn primary_function(&self) -> &str {
+            &self.primary_function
+        }
+
+        fn display_name(&self, prefix: Option<String>) -> String {
+            format!("{}droid {}", prefix.unwrap_or_default(), self.id)
+        }
+    }
+
+    impl Character for Droid {
+        fn id(&self) -> &str {
+            &self.id
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    enum QueryRoot {
+        Human,
+        Droid,
+    }
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn character(&self) -> CharacterValue {
+            match self {
+                Self::Human => Human {
+                    id: "human-32".into(),
+                    home_planet: "earth".into(),
+                }
+                .into(),
+                Self::Droid => Droid {
+                    id: "droid-99".into(),
+                    primary_function: "run".into(),
+                }
+                .into(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_inherited_field() {
+        const DOC: &str = r#"{
+            character {
+                displayName(prefix: "-")
+                ... on Human {
+                    own: displayName
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"character": {
+                    "displayName": "-human-32",
+                    "own": "human-32",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_overridden_field() {
+        const DOC: &str = r#"{
+            character {
+                displayName(prefix: "-")
+                ... on Droid {
+                    own: displayName
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Droid);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"character": {
+                    "displayName": "-droid droid-99",
+                    "own": "droid droid-99",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn exposes_inherited_field_on_implementers() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id"},
+                    {"name": "homePlanet"},
+                    {"name": "displayName"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod dyn_dispatch {
+    use juniper::{async_trait, EmptyMutation, EmptySubscription, RootNode};
+
+    use super::*;
+
+    #[graphql_interface(for = Human, dyn = DynCharacter)]
+    trait Character {
+        async fn id(&self) -> String;
+
+        fn info(&self) -> String {
+            "character".into()
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue)]
+    struct Human {
+        id: String,
+        home_planet: String,
+    }
+
+    #[async_trait]
+    impl Character for Human {
+        async fn id(&self) -> String {
+            self.id.clone()
+        }
+    }
+
+    // Not known to the `Character` interface, as if defined in another crate.
+    struct Droid {
+        id: String,
+        primary_function: String,
+    }
+
+    #[graphql_object(impl = DynCharacter, scalar = DefaultScalarValue)]
+    impl Droid {
+        async fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn primary_function(&self) -> &str {
+            &self.primary_function
+        }
+    }
+
+    #[async_trait]
+    impl Character for Droid {
+        async fn id(&self) -> String {
+            self.id.clone()
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl QueryRoot {
+        fn characters(&self) -> Vec<Box<DynCharacter>> {
+            vec![
+                Box::new(Human {
+                    id: "human-32".into(),
+                    home_planet: "earth".into(),
+                }),
+                Box::new(Droid {
+                    id: "droid-99".into(),
+                    primary_function: "run".into(),
+                }),
+            ]
+        }
+    }
+
+    fn schema() -> RootNode<'static, QueryRoot, EmptyMutation, EmptySubscription> {
+        RootNode::new(QueryRoot, EmptyMutation::new(), EmptySubscription::new())
+            .with_type::<Droid>(&())
+    }
+
+    #[tokio::test]
+    async fn resolves_implementers() {
+        const DOC: &str = r#"{
+            characters {
+                __typename
+                id
+                info
+                ... on Human {
+                    homePlanet
+                }
+                ... on Droid {
+                    primaryFunction
+                }
+            }
+        }"#;
+
+        assert_eq!(
+            execute(DOC, None, &schema(), &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"characters": [{
+                    "__typename": "Human",
+                    "id": "human-32",
+                    "info": "character",
+                    "homePlanet": "earth",
+                }, {
+                    "__typename": "Droid",
+                    "id": "droid-99",
+                    "info": "character",
+                    "primaryFunction": "run",
+                }]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn registers_runtime_implementers() {
+        const DOC: &str = r#"{
+            __type(name: "Character") {
+                possibleTypes {
+                    name
+                }
+            }
+        }"#;
+
+        assert_eq!(
+            execute(DOC, None, &schema(), &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"possibleTypes": [
+                    {"name": "Human"},
+                    {"name": "Droid"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod preserves_visibility {
     use super::*;
 
@@ -256,20 +256,483 @@ mod raw_method {
     }
 }
 
+mod static_method {
+    use super::*;
+
+    struct Context {
+        region: &'static str,
+    }
+
+    impl juniper::Context for Context {}
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Context)]
+    impl QueryRoot {
+        fn api_version() -> &'static str {
+            "1.0"
+        }
+
+        async fn max_page_size() -> i32 {
+            100
+        }
+
+        fn region(ctx: &Context) -> &'static str {
+            ctx.region
+        }
+
+        fn limited(limit: i32) -> i32 {
+            limit.min(Self::PAGE_SIZE)
+        }
+    }
+
+    impl QueryRoot {
+        const PAGE_SIZE: i32 = 100;
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            apiVersion
+            maxPageSize
+            region
+            limited(limit: 500)
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Context { region: "eu" };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({
+                    "apiVersion": "1.0",
+                    "maxPageSize": 100,
+                    "region": "eu",
+                    "limited": 100,
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod ignored_method {
     use super::*;
 
     struct Human;
 
-    #[graphql_object]
+    #[graphql_object]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        #[allow(dead_code)]
+        #[graphql(ignore)]
+        fn planet() -> &'static str {
+            "earth"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human {
+                id
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"human": {"id": "human-32"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn is_not_field() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{"name": "id"}]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod cfg_method {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        #[cfg(not(test))]
+        fn planet() -> &'static str {
+            "earth"
+        }
+
+        #[cfg(test)]
+        async fn home_planet() -> &'static str {
+            "earth"
+        }
+
+        fn greet(
+            #[cfg(not(test))] name: String,
+            #[cfg(test)]
+            #[graphql(default = "human")]
+            title: String,
+        ) -> String {
+            format!("Hello, {title}!")
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human {
+                id
+                homePlanet
+                greet
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "homePlanet": "earth",
+                    "greet": "Hello, human!",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_only_enabled_fields_and_arguments() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                    args {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "args": []},
+                    {"name": "homePlanet", "args": []},
+                    {"name": "greet", "args": [{"name": "title"}]},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod validate_only_method {
+    use juniper::{
+        execute_with_options, EmptySubscription, ExecutionError, ExecutionOptions, GraphQLError,
+        RootNode,
+    };
+
+    use super::*;
+
+    fn validate_counter(value: i32) -> FieldResult<i32> {
+        if value < 0 {
+            return Err("Negative counter".into());
+        }
+        Ok(0)
+    }
+
+    struct MutationRoot;
+
+    #[graphql_object]
+    impl MutationRoot {
+        #[graphql(validate_only = validate_counter)]
+        fn counter(value: i32) -> FieldResult<i32> {
+            Ok(value + 1)
+        }
+
+        #[graphql(validate_only = Self::validate_async_counter)]
+        async fn async_counter(value: i32) -> FieldResult<i32> {
+            Ok(value + 1)
+        }
+
+        #[graphql(ignore)]
+        async fn validate_async_counter(value: i32) -> FieldResult<i32> {
+            validate_counter(value)
+        }
+
+        fn plain(value: i32) -> i32 {
+            value + 1
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn id() -> &'static str {
+            "query-root"
+        }
+
+        #[graphql(validate_only = validate_counter)]
+        fn counter(value: i32) -> FieldResult<i32> {
+            Ok(value + 1)
+        }
+    }
+
+    fn schema() -> RootNode<'static, QueryRoot, MutationRoot, EmptySubscription> {
+        RootNode::new(QueryRoot, MutationRoot, EmptySubscription::new())
+    }
+
+    #[tokio::test]
+    async fn resolves_normally() {
+        const DOC: &str = r#"mutation {
+            counter(value: 1)
+            asyncCounter(value: 1)
+            plain(value: 1)
+        }"#;
+
+        let schema = schema();
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"counter": 2, "asyncCounter": 2, "plain": 2}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_with_validate_only_in_dry_run() {
+        const DOC: &str = r#"mutation {
+            counter(value: 1)
+            asyncCounter(value: 1)
+            plain(value: 1)
+        }"#;
+
+        let schema = schema();
+        let options = ExecutionOptions::new().dry_run(true);
+
+        assert_eq!(
+            execute_with_options(DOC, None, &schema, &graphql_vars! {}, &(), &options).await,
+            Ok((
+                graphql_value!({"counter": 0, "asyncCounter": 0, "plain": 2}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_validation_errors_in_dry_run() {
+        const DOC: &str = r#"mutation {
+            counter(value: -1)
+        }"#;
+
+        let schema = schema().with_execution_options(ExecutionOptions::new().dry_run(true));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::new(
+                    juniper::parser::SourcePosition::new(23, 1, 12),
+                    &["counter"],
+                    FieldError::from("Negative counter"),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_with_validate_only_via_directive() {
+        const DOC: &str = r#"mutation @dryRun {
+            counter(value: 1)
+            asyncCounter(value: 1)
+        }"#;
+
+        let schema = schema().enable_dry_run_directive();
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"counter": 0, "asyncCounter": 0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_queries_normally_in_dry_run() {
+        const DOC: &str = r#"{
+            counter(value: 1)
+        }"#;
+
+        let schema = schema();
+        let options = ExecutionOptions::new().dry_run(true);
+
+        assert_eq!(
+            execute_with_options(DOC, None, &schema, &graphql_vars! {}, &(), &options).await,
+            Ok((graphql_value!({"counter": 2}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_directive_on_query() {
+        const DOC: &str = r#"query @dryRun {
+            counter(value: 1)
+        }"#;
+
+        let schema = schema().enable_dry_run_directive();
+
+        assert!(matches!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Err(GraphQLError::ValidationError(_)),
+        ));
+    }
+}
+
+mod annotated {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct CacheHint {
+        max_age: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Cost(usize);
+
+    struct Human;
+
+    #[graphql_object(meta(CacheHint = CacheHint { max_age: 60 }))]
+    impl Human {
+        #[graphql(meta(CacheHint = CacheHint { max_age: 0 }, Cost = Cost(3)))]
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        fn home_planet() -> &'static str {
+            "earth"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[test]
+    fn exposes_metadata() {
+        let schema = schema(QueryRoot);
+        let human = schema.schema.concrete_type_by_name("Human").unwrap();
+
+        assert_eq!(
+            human.annotation::<CacheHint>(),
+            Some(&CacheHint { max_age: 60 }),
+        );
+        assert_eq!(human.annotation::<Cost>(), None);
+
+        let id = human.field_by_name("id").unwrap();
+        assert_eq!(
+            id.annotation::<CacheHint>(),
+            Some(&CacheHint { max_age: 0 })
+        );
+        assert_eq!(id.annotation::<Cost>(), Some(&Cost(3)));
+
+        let home_planet = human.field_by_name("homePlanet").unwrap();
+        assert!(home_planet.metadata.is_empty());
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human {
+                id
+                homePlanet
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "homePlanet": "earth"}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod applied_directives {
+    use juniper::{
+        meta::{Argument, SchemaDirective},
+        DirectiveLocation, DirectiveType, InputValue, Type,
+    };
+
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object(directives(key(fields = "id"), shareable))]
     impl Human {
+        #[graphql(directives(cost(weight = 3)))]
         fn id() -> &'static str {
             "human-32"
         }
 
-        #[allow(dead_code)]
-        #[graphql(ignore)]
-        fn planet() -> &'static str {
+        fn home_planet() -> &'static str {
             "earth"
         }
     }
@@ -283,40 +746,77 @@ mod ignored_method {
         }
     }
 
-    #[tokio::test]
-    async fn resolves() {
-        const DOC: &str = r#"{
-            human {
-                id
-            }
-        }"#;
-
+    #[test]
+    fn exposes_directives() {
         let schema = schema(QueryRoot);
+        let human = schema.schema.concrete_type_by_name("Human").unwrap();
 
         assert_eq!(
-            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
-            Ok((graphql_value!({"human": {"id": "human-32"}}), vec![])),
+            human.directives(),
+            [
+                SchemaDirective::new("key").argument("fields", InputValue::scalar("id")),
+                SchemaDirective::new("shareable"),
+            ],
         );
+
+        let id = human.field_by_name("id").unwrap();
+        assert_eq!(
+            id.directives(),
+            [SchemaDirective::new("cost").argument("weight", InputValue::scalar(3))],
+        );
+
+        let home_planet = human.field_by_name("homePlanet").unwrap();
+        assert!(home_planet.directives().is_empty());
     }
 
     #[tokio::test]
-    async fn is_not_field() {
+    async fn exposes_directive_definitions_via_introspection() {
         const DOC: &str = r#"{
-            __type(name: "Human") {
-                fields {
+            __schema {
+                directives {
                     name
+                    isRepeatable
+                    locations
+                    args {
+                        name
+                    }
                 }
             }
         }"#;
 
-        let schema = schema(QueryRoot);
-
+        let schema = schema(QueryRoot).with_schema_directive(DirectiveType::new(
+            "key",
+            &[DirectiveLocation::Object, DirectiveLocation::Interface],
+            &[Argument::new("fields", Type::NonNullNamed("String".into()))],
+            true,
+        ));
+
+        let (res, errors) = execute(DOC, None, &schema, &graphql_vars! {}, &())
+            .await
+            .unwrap();
+        assert!(errors.is_empty(), "{errors:?}");
+
+        let key = res
+            .as_object_value()
+            .and_then(|o| o.get_field_value("__schema"))
+            .and_then(|s| s.as_object_value())
+            .and_then(|s| s.get_field_value("directives"))
+            .and_then(|d| d.as_list_value())
+            .unwrap()
+            .iter()
+            .find(|d| {
+                d.as_object_value().and_then(|d| d.get_field_value("name"))
+                    == Some(&graphql_value!("key"))
+            })
+            .unwrap();
         assert_eq!(
-            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
-            Ok((
-                graphql_value!({"__type": {"fields": [{"name": "id"}]}}),
-                vec![],
-            )),
+            key,
+            &graphql_value!({
+                "name": "key",
+                "isRepeatable": true,
+                "locations": ["OBJECT", "INTERFACE"],
+                "args": [{"name": "fields"}],
+            }),
         );
     }
 }
@@ -419,6 +919,117 @@ mod fallible_method {
     }
 }
 
+mod map_err_method {
+    use super::*;
+
+    /// Domain error not implementing [`IntoFieldError`].
+    enum DomainError {
+        NotFound(i32),
+    }
+
+    fn to_field_error(e: DomainError) -> String {
+        match e {
+            DomainError::NotFound(id) => format!("Human `{id}` not found"),
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        #[graphql(map_err = to_field_error)]
+        fn human(id: i32) -> Result<&'static str, DomainError> {
+            if id == 32 {
+                Ok("human-32")
+            } else {
+                Err(DomainError::NotFound(id))
+            }
+        }
+
+        #[graphql(map_err = |_| "Unavailable")]
+        async fn planet() -> Result<&'static str, DomainError> {
+            Err(DomainError::NotFound(0))
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_ok() {
+        const DOC: &str = r#"{ human(id: 32) }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"human": "human-32"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn converts_error_with_function() {
+        const DOC: &str = r#"{ human(id: 1) }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute(DOC, None, &schema, &graphql_vars! {}, &())
+            .await
+            .unwrap();
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].path(), ["human"]);
+        assert_eq!(errs[0].error().message(), "Human `1` not found");
+    }
+
+    #[tokio::test]
+    async fn converts_error_with_closure() {
+        const DOC: &str = r#"{ planet }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute(DOC, None, &schema, &graphql_vars! {}, &())
+            .await
+            .unwrap();
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].path(), ["planet"]);
+        assert_eq!(errs[0].error().message(), "Unavailable");
+    }
+
+    #[tokio::test]
+    async fn has_correct_graphql_type() {
+        const DOC: &str = r#"{
+            __type(name: "QueryRoot") {
+                fields {
+                    name
+                    type {
+                        kind
+                        ofType {
+                            name
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{
+                    "name": "human",
+                    "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                }, {
+                    "name": "planet",
+                    "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod generic {
     use super::*;
 
@@ -1007,6 +1618,117 @@ mod argument {
     }
 }
 
+mod borrowed_argument {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object]
+    impl Human {
+        fn id(arg: &str) -> String {
+            arg.into()
+        }
+
+        async fn home_planet(&self, name: &str, suffix: Option<&str>) -> String {
+            format!("{name}{}", suffix.unwrap_or_default())
+        }
+
+        fn greeting(#[graphql(default = "Hello")] word: &str) -> String {
+            format!("{word}!")
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"query($suffix: String) {
+            human {
+                id(arg: "human-32")
+                homePlanet(name: "earth", suffix: $suffix)
+                greeting
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "homePlanet": "earth",
+                    "greeting": "Hello!",
+                }}),
+                vec![],
+            )),
+        );
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {"suffix": "-2"}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "homePlanet": "earth-2",
+                    "greeting": "Hello!",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_string_types() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    args {
+                        name
+                        type {
+                            kind
+                            ofType {
+                                name
+                            }
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"args": [{
+                        "name": "arg",
+                        "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                    }]},
+                    {"args": [{
+                        "name": "name",
+                        "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                    }, {
+                        "name": "suffix",
+                        "type": {"kind": "SCALAR", "ofType": null},
+                    }]},
+                    {"args": [{
+                        "name": "word",
+                        "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                    }]},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod default_argument {
     use super::*;
 
@@ -1219,10 +1941,80 @@ mod description_from_doc_comment {
         assert_eq!(
             execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
             Ok((
-                graphql_value!({"__type": {
-                    "description": "Rust docs.",
-                    "fields": [{"description": "Rust `id` docs."}],
-                }}),
+                graphql_value!({"__type": {
+                    "description": "Rust docs.",
+                    "fields": [{"description": "Rust `id` docs."}],
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod argument_description_from_doc_comment {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object]
+    impl Human {
+        fn id(
+            /// Rust `prefix` docs.
+            prefix: String,
+            /// Ignored docs.
+            #[graphql(desc = "Explicit `suffix` description.")]
+            suffix: Option<String>,
+        ) -> String {
+            format!("{prefix}-human-32{}", suffix.unwrap_or_default())
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_id_field() {
+        const DOC: &str = r#"{
+            human {
+                id(prefix: "my")
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"human": {"id": "my-human-32"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_doc_comment_as_argument_description() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    args {
+                        description
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{"args": [
+                    {"description": "Rust `prefix` docs."},
+                    {"description": "Explicit `suffix` description."},
+                ]}]}}),
                 vec![],
             )),
         );
@@ -1346,6 +2138,101 @@ mod deprecation_from_attr {
     }
 }
 
+mod deprecated_argument {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object]
+    impl Human {
+        fn id(
+            #[graphql(default)] number: i32,
+            #[graphql(deprecated)] num: Option<i32>,
+            #[graphql(deprecated = "Use `number`.")] n: Option<i32>,
+        ) -> i32 {
+            n.or(num).unwrap_or(number)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_deprecated_arguments() {
+        const DOC: &str = r#"{
+            human {
+                id(num: 1)
+                other: id(n: 2)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"human": {"id": 1, "other": 2}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn omits_deprecated_arguments_by_default() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    args {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{"args": [{"name": "number"}]}]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn provides_deprecation_reason() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    args(includeDeprecated: true) {
+                        name
+                        isDeprecated
+                        deprecationReason
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{"args": [
+                    {"name": "number", "isDeprecated": false, "deprecationReason": null},
+                    {"name": "num", "isDeprecated": true, "deprecationReason": null},
+                    {"name": "n", "isDeprecated": true, "deprecationReason": "Use `number`."},
+                ]}]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod explicit_name_description_and_deprecation {
     use super::*;
 
@@ -1598,6 +2485,92 @@ mod renamed_all_fields_and_args {
     }
 }
 
+mod renamed_args {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object]
+    impl Human {
+        #[graphql(rename_args = "SCREAMING_SNAKE_CASE")]
+        fn home_planet(planet_name: String, orbit_index: i32) -> String {
+            format!("{planet_name} #{orbit_index}")
+        }
+
+        async fn ship_name(
+            #[graphql(rename = "none")] ship_class: String,
+            serial_number: i32,
+        ) -> String {
+            format!("{ship_class}-{serial_number}")
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                homePlanet(PLANET_NAME: "earth", ORBIT_INDEX: 3)
+                shipName(ship_class: "X-wing", serialNumber: 1)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "homePlanet": "earth #3",
+                    "shipName": "X-wing-1",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_correct_args_names() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                    args {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "homePlanet", "args": [
+                        {"name": "PLANET_NAME"},
+                        {"name": "ORBIT_INDEX"},
+                    ]},
+                    {"name": "shipName", "args": [
+                        {"name": "ship_class"},
+                        {"name": "serialNumber"},
+                    ]},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod explicit_scalar {
     use super::*;
 
@@ -2008,6 +2981,153 @@ mod executor {
     }
 }
 
+mod generic_method {
+    use crate::common::MyScalarValue;
+
+    use super::*;
+
+    struct Human {
+        name: String,
+    }
+
+    #[graphql_object]
+    impl Human {
+        fn name<S: ScalarValue>(&self, executor: &Executor<'_, '_, (), S>) -> FieldResult<&str, S> {
+            let _ = executor;
+            Ok(&self.name)
+        }
+
+        async fn home_planet<S>() -> Result<String, FieldError<S>>
+        where
+            S: ScalarValue,
+        {
+            Ok("earth".into())
+        }
+
+        #[graphql(generics(T = String, R = String))]
+        fn greeting<T: Into<String>, R: From<String>>(&self, to: T) -> R {
+            format!("Hello, {}! I'm {}.", to.into(), self.name).into()
+        }
+
+        #[graphql(generics(N = i32))]
+        async fn doubled<N, S>(n: N) -> FieldResult<N, S>
+        where
+            N: std::ops::Add<Output = N> + Copy,
+            S: ScalarValue,
+        {
+            Ok(n + n)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                name: "Luke".into(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human {
+                name
+                homePlanet
+                greeting(to: "Leia")
+                doubled(n: 21)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "name": "Luke",
+                    "homePlanet": "earth",
+                    "greeting": "Hello, Leia! I'm Luke.",
+                    "doubled": 42,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_with_custom_scalar() {
+        const DOC: &str = r#"{
+            human {
+                name
+                doubled(n: 2)
+            }
+        }"#;
+
+        let schema = schema_with_scalar::<MyScalarValue, _, _>(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {"name": "Luke", "doubled": 4}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_correct_graphql_type() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                    args {
+                        name
+                        type {
+                            ofType {
+                                name
+                            }
+                        }
+                    }
+                    type {
+                        ofType {
+                            name
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{
+                    "name": "name",
+                    "args": [],
+                    "type": {"ofType": {"name": "String"}},
+                }, {
+                    "name": "homePlanet",
+                    "args": [],
+                    "type": {"ofType": {"name": "String"}},
+                }, {
+                    "name": "greeting",
+                    "args": [{"name": "to", "type": {"ofType": {"name": "String"}}}],
+                    "type": {"ofType": {"name": "String"}},
+                }, {
+                    "name": "doubled",
+                    "args": [{"name": "n", "type": {"ofType": {"name": "Int"}}}],
+                    "type": {"ofType": {"name": "Int"}},
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod switched_context {
     use super::*;
 
@@ -215,6 +215,278 @@ mod ignored_field {
     }
 }
 
+mod flattened_field {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct Audit {
+        created_by: &'static str,
+        version: i32,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Human {
+        id: &'static str,
+        #[graphql(flatten)]
+        audit: Audit,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: "human-32",
+                audit: Audit {
+                    created_by: "admin",
+                    version: 2,
+                },
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human {
+                id
+                createdBy
+                version
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "createdBy": "admin",
+                    "version": 2,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_flattened_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id"},
+                    {"name": "createdBy"},
+                    {"name": "version"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn does_not_register_flattened_type() {
+        let schema = schema(QueryRoot);
+
+        assert!(schema.schema.concrete_type_by_name("Audit").is_none());
+    }
+}
+
+mod delegated_field {
+    use super::*;
+
+    mod remote {
+        use super::*;
+
+        #[derive(GraphQLObject)]
+        pub struct Human {
+            pub id: &'static str,
+            pub home_planet: &'static str,
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(name = "Human")]
+    struct Human(#[graphql(delegate)] remote::Human);
+
+    #[derive(GraphQLObject)]
+    struct Hero {
+        power: i32,
+        #[graphql(delegate)]
+        human: remote::Human,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human(remote::Human {
+                id: "human-32",
+                home_planet: "earth",
+            })
+        }
+
+        fn hero() -> Hero {
+            Hero {
+                power: 9,
+                human: remote::Human {
+                    id: "human-33",
+                    home_planet: "mars",
+                },
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_newtype() {
+        const DOC: &str = r#"{
+            human {
+                id
+                homePlanet
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "homePlanet": "earth"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_named_field() {
+        const DOC: &str = r#"{
+            hero {
+                power
+                id
+                homePlanet
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"hero": {
+                    "power": 9,
+                    "id": "human-33",
+                    "homePlanet": "mars",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_delegated_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Hero") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "power"},
+                    {"name": "id"},
+                    {"name": "homePlanet"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod remote_struct {
+    use super::*;
+
+    mod geo {
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+
+        pub struct Line {
+            pub from: Point,
+            pub to: Point,
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(name = "Point", remote = geo::Point)]
+    struct PointDef {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(name = "Line", remote = geo::Line)]
+    struct LineDef {
+        from: PointDef,
+        to: PointDef,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn line() -> LineDef {
+            geo::Line {
+                from: geo::Point { x: 1, y: 2 },
+                to: geo::Point { x: 3, y: 4 },
+            }
+            .into()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            line {
+                from { x y }
+                to { x y }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"line": {
+                    "from": {"x": 1, "y": 2},
+                    "to": {"x": 3, "y": 4},
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod generic {
     use super::*;
 
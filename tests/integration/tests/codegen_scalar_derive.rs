@@ -219,6 +219,71 @@ mod transparent_with_resolver {
     }
 }
 
+mod transparent_with_validation {
+    use super::*;
+
+    #[derive(GraphQLScalar)]
+    #[graphql(transparent, validate = validate_email)]
+    struct EmailAddress(String);
+
+    fn validate_email(email: &str) -> Result<(), String> {
+        if email.contains('@') {
+            Ok(())
+        } else {
+            Err(format!("Malformed email address: {email}"))
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn email(value: EmailAddress) -> EmailAddress {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_valid_input() {
+        const DOC: &str = r#"{ email(value: "john@example.com") }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"email": "john@example.com"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_literal() {
+        const DOC: &str = r#"{ email(value: "john") }"#;
+
+        let schema = schema(QueryRoot);
+
+        let err = execute(DOC, None, &schema, &graphql_vars! {}, &())
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#"Invalid value for argument "value", expected type "EmailAddress!""#));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_variable() {
+        const DOC: &str = r#"query($value: EmailAddress!) { email(value: $value) }"#;
+
+        let schema = schema(QueryRoot);
+
+        let err = execute(DOC, None, &schema, &graphql_vars! {"value": "john"}, &())
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Details: Malformed email address: john"));
+    }
+}
+
 mod all_custom_resolvers {
     use super::*;
 
@@ -298,6 +363,112 @@ mod all_custom_resolvers {
     }
 }
 
+mod serde_resolvers {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Deserialize, GraphQLScalar, Serialize)]
+    #[graphql(with = serde)]
+    #[serde(untagged)]
+    enum StringOrInt {
+        String(String),
+        Int(i32),
+    }
+
+    #[derive(Deserialize, GraphQLScalar, Serialize)]
+    #[graphql(with = serde)]
+    enum Direction {
+        North,
+        South,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn string_or_int(value: StringOrInt) -> StringOrInt {
+            value
+        }
+
+        fn direction(value: Direction) -> Direction {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn is_graphql_scalar() {
+        const DOC: &str = r#"{
+            __type(name: "StringOrInt") {
+                kind
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"__type": {"kind": "SCALAR"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_literals() {
+        const DOC: &str = r#"{
+            str: stringOrInt(value: "foo")
+            int: stringOrInt(value: 42)
+            direction(value: "South")
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"str": "foo", "int": 42, "direction": "South"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_variables() {
+        const DOC: &str = r#"query($value: StringOrInt!, $dir: Direction!) {
+            stringOrInt(value: $value)
+            direction(value: $dir)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {"value": 7, "dir": "North"},
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({"stringOrInt": 7, "direction": "North"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_variable() {
+        const DOC: &str = r#"query($dir: Direction!) { direction(value: $dir) }"#;
+
+        let schema = schema(QueryRoot);
+
+        let err = execute(DOC, None, &schema, &graphql_vars! {"dir": "West"}, &())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown variant `West`"));
+    }
+}
+
 mod explicit_name {
     use super::*;
 
@@ -1336,6 +1336,141 @@ mod full_featured_enum {
     }
 }
 
+mod struct_variants_enum {
+    use super::*;
+
+    #[derive(GraphQLUnion)]
+    #[graphql(context = CustomContext)]
+    enum Character {
+        /// Human being.
+        Human {
+            id: String,
+            #[graphql(name = "planet")]
+            home_planet: String,
+        },
+        Droid {
+            id: String,
+            #[graphql(ignore)]
+            _serial: u64,
+        },
+        Ewok(EwokCustomContext),
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = CustomContext)]
+    impl QueryRoot {
+        fn character(ctx: &CustomContext) -> Character {
+            match ctx {
+                CustomContext::Human => Character::Human {
+                    id: "human-32".into(),
+                    home_planet: "earth".into(),
+                },
+                CustomContext::Droid => Character::Droid {
+                    id: "droid-99".into(),
+                    _serial: 42,
+                },
+                CustomContext::Ewok => Character::Ewok(EwokCustomContext {
+                    id: "ewok-1".into(),
+                    funny: true,
+                }),
+            }
+        }
+    }
+
+    const DOC: &str = r#"{
+        character {
+            ... on Human {
+                humanId: id
+                planet
+            }
+            ... on Droid {
+                droidId: id
+            }
+            ... on EwokCustomContext {
+                ewokId: id
+                funny
+            }
+        }
+    }"#;
+
+    #[tokio::test]
+    async fn resolves_human() {
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &CustomContext::Human).await,
+            Ok((
+                graphql_value!({"character": {"humanId": "human-32", "planet": "earth"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_droid() {
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &CustomContext::Droid).await,
+            Ok((
+                graphql_value!({"character": {"droidId": "droid-99"}}),
+                vec![]
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_ewok() {
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &CustomContext::Ewok).await,
+            Ok((
+                graphql_value!({"character": {"ewokId": "ewok-1", "funny": true}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn generates_implicit_objects() {
+        const DOC: &str = r#"{
+            __type(name: "Character") {
+                possibleTypes {
+                    name
+                    description
+                    fields {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &CustomContext::Human).await,
+            Ok((
+                graphql_value!({"__type": {"possibleTypes": [{
+                    "name": "Human",
+                    "description": "Human being.",
+                    "fields": [{"name": "id"}, {"name": "planet"}],
+                }, {
+                    "name": "Droid",
+                    "description": null,
+                    "fields": [{"name": "id"}],
+                }, {
+                    "name": "EwokCustomContext",
+                    "description": null,
+                    "fields": [{"name": "id"}, {"name": "funny"}],
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod trivial_struct {
     use super::*;
 